@@ -0,0 +1,2 @@
+-- Add migration script here
+ALTER TABLE view_table ADD COLUMN IF NOT EXISTS parent_view_id TEXT;
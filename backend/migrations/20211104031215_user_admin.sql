@@ -0,0 +1,2 @@
+-- Add migration script here
+ALTER TABLE user_table ADD COLUMN IF NOT EXISTS is_admin BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,7 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS view_access_table(
+    user_id uuid NOT NULL,
+    view_id uuid NOT NULL,
+    last_accessed_at timestamptz NOT NULL,
+    PRIMARY KEY (user_id, view_id)
+);
@@ -0,0 +1,2 @@
+-- Add migration script here
+ALTER TABLE doc_table ADD COLUMN IF NOT EXISTS modified_time timestamptz NOT NULL DEFAULT now();
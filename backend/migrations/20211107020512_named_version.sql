@@ -0,0 +1,12 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS named_version_table(
+    id uuid NOT NULL,
+    PRIMARY KEY (id),
+    doc_id uuid NOT NULL REFERENCES doc_table(id) ON DELETE CASCADE,
+    rev_id bigint NOT NULL,
+    name TEXT NOT NULL,
+    data TEXT NOT NULL,
+    created_by TEXT NOT NULL,
+    created_at timestamptz NOT NULL,
+    UNIQUE (doc_id, name)
+);
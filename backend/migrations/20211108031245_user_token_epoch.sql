@@ -0,0 +1,2 @@
+-- Add migration script here
+ALTER TABLE user_table ADD COLUMN IF NOT EXISTS token_epoch BIGINT NOT NULL DEFAULT 0;
@@ -0,0 +1,8 @@
+-- Add migration script here
+ALTER TABLE user_table ADD COLUMN IF NOT EXISTS verified BOOLEAN NOT NULL DEFAULT true;
+CREATE TABLE IF NOT EXISTS user_verification_table(
+    token TEXT NOT NULL,
+    PRIMARY KEY (token),
+    user_id uuid NOT NULL REFERENCES user_table(id) ON DELETE CASCADE,
+    expires_at timestamptz NOT NULL
+);
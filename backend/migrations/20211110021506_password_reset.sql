@@ -0,0 +1,7 @@
+-- Add migration script here
+CREATE TABLE IF NOT EXISTS password_reset_table(
+    token TEXT NOT NULL,
+    PRIMARY KEY (token),
+    user_id uuid NOT NULL REFERENCES user_table(id) ON DELETE CASCADE,
+    expires_at timestamptz NOT NULL
+);
@@ -0,0 +1,7 @@
+-- Add migration script here
+ALTER TABLE view_table ADD COLUMN IF NOT EXISTS position INTEGER NOT NULL DEFAULT 0;
+-- The existing views keep the order they were created in.
+UPDATE view_table SET position = ordered.position FROM (
+    SELECT id, ROW_NUMBER() OVER (PARTITION BY belong_to_id, parent_view_id ORDER BY create_time) - 1 AS position
+    FROM view_table
+) AS ordered WHERE view_table.id = ordered.id;
@@ -0,0 +1,11 @@
+-- Add migration script here
+ALTER TABLE doc_table ADD COLUMN IF NOT EXISTS plaintext TEXT NOT NULL DEFAULT '';
+ALTER TABLE doc_table ADD COLUMN IF NOT EXISTS search_vector tsvector
+    GENERATED ALWAYS AS (to_tsvector('simple', plaintext)) STORED;
+CREATE INDEX IF NOT EXISTS doc_table_search_vector_idx ON doc_table USING GIN (search_vector);
+-- The plaintext of the existing documents is the text of their inserts, the
+-- embeds have no text.
+UPDATE doc_table SET plaintext = COALESCE((
+    SELECT string_agg(op->>'insert', '') FROM jsonb_array_elements(data::jsonb) AS op
+    WHERE jsonb_typeof(op->'insert') = 'string'
+), '') WHERE data LIKE '[%';
@@ -0,0 +1,2 @@
+-- Add migration script here
+ALTER TABLE app_table ADD COLUMN IF NOT EXISTS parent_id TEXT;
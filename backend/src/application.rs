@@ -1,33 +1,76 @@
-use std::{net::TcpListener, time::Duration};
+use std::{net::TcpListener, sync::Arc, time::Duration};
 
-use actix::Actor;
+use actix::{Actor, Addr};
 use actix_identity::{CookieIdentityPolicy, IdentityService};
-use actix_web::{dev::Server, middleware, web, web::Data, App, HttpServer, Scope};
+use actix_web::{
+    dev::{Server, ServerHandle, ServiceRequest},
+    http::StatusCode,
+    middleware,
+    web,
+    App,
+    HttpServer,
+    ResponseError,
+    Scope,
+};
+use backend_service::{
+    config::HEADER_TOKEN,
+    errors::{invalid_params, ServerError},
+};
 use sqlx::{postgres::PgPoolOptions, PgPool};
-use tokio::time::interval;
+use tokio::{
+    sync::Notify,
+    time::{interval, sleep, timeout_at, Instant},
+};
 
 use crate::{
     config::{
         env::{domain, secret, use_https},
         DatabaseSettings,
+        SessionSettings,
         Settings,
     },
     context::AppContext,
+    middleware::RateLimiter,
     service::{
+        admin::router as admin,
         app::router as app,
         doc::router as doc,
+        health,
+        metrics,
+        search::router as search,
         trash::router as trash,
-        user::router as user,
+        user::{router as user, LogMailer, AUTHORIZED_USERS},
         view::router as view,
         workspace::router as workspace,
         ws,
-        ws::WsServer,
+        ws::{
+            entities::{CloseAllSessions, ReapIdleSessions, SessionCount},
+            WsServer,
+        },
     },
 };
 
+const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
+// The default format of the Logger with the id of the request, see
+// RequestIdService. The request line is the one of `%r` with the tokens
+// redacted, see [redacted_request_line].
+const LOGGER_FORMAT: &str = r#"%a "%{request_line}xi" %s %b "%{Referer}i" "%{User-Agent}i" %T %{x-request-id}i"#;
+const REDACTED: &str = "<redacted>";
+
 pub struct Application {
     port: u16,
     server: Server,
+    ws_server: Addr<WsServer>,
+    shutdown: ShutdownHandle,
+    shutdown_timeout: Duration,
+}
+
+// Shuts the application down gracefully, as on SIGTERM or ctrl-c.
+#[derive(Clone)]
+pub struct ShutdownHandle(Arc<Notify>);
+
+impl ShutdownHandle {
+    pub fn shutdown(&self) { self.0.notify_one(); }
 }
 
 impl Application {
@@ -35,47 +78,191 @@ impl Application {
         let address = format!("{}:{}", configuration.application.host, configuration.application.port);
         let listener = TcpListener::bind(&address)?;
         let port = listener.local_addr().unwrap().port();
-        let server = run(listener, app_ctx)?;
-        Ok(Self { port, server })
+        let ws_server = app_ctx.ws_server.get_ref().clone();
+        let server = run(listener, app_ctx, &configuration)?;
+        Ok(Self {
+            port,
+            server,
+            ws_server,
+            shutdown: ShutdownHandle(Arc::new(Notify::new())),
+            shutdown_timeout: DEFAULT_SHUTDOWN_TIMEOUT,
+        })
     }
 
-    pub async fn run_until_stopped(self) -> Result<(), std::io::Error> { self.server.await }
+    // The longest time the shutdown waits for the WebSocket sessions to close
+    // and the in-flight requests to finish, the rest are dropped after it.
+    pub fn shutdown_timeout(mut self, timeout: Duration) -> Self {
+        self.shutdown_timeout = timeout;
+        self
+    }
+
+    pub fn shutdown_handle(&self) -> ShutdownHandle { self.shutdown.clone() }
+
+    pub async fn run_until_stopped(self) -> Result<(), std::io::Error> {
+        let stopping_server = self.server.handle();
+        let ws_server = self.ws_server;
+        let shutdown = self.shutdown;
+        let shutdown_timeout = self.shutdown_timeout;
+        tokio::spawn(async move {
+            tokio::select! {
+                _ = shutdown_signal() => {},
+                _ = shutdown.0.notified() => {},
+            }
+            graceful_shutdown(stopping_server, ws_server, shutdown_timeout).await;
+        });
+        self.server.await
+    }
 
     pub fn port(&self) -> u16 { self.port }
 }
 
-pub fn run(listener: TcpListener, app_ctx: AppContext) -> Result<Server, std::io::Error> {
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        let mut terminate = signal(SignalKind::terminate()).expect("Failed to install the SIGTERM handler");
+        tokio::select! {
+            _ = terminate.recv() => {},
+            _ = tokio::signal::ctrl_c() => {},
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = tokio::signal::ctrl_c().await;
+}
+
+async fn graceful_shutdown(server: ServerHandle, ws_server: Addr<WsServer>, timeout: Duration) {
+    tracing::info!("Shutting down the server within {:?}", timeout);
+    let deadline = Instant::now() + timeout;
+    // Stops accepting the new connections, the accepted ones are still served.
+    server.pause().await;
+
+    // The clients send their pending messages before the close frames, and
+    // disconnect from the WsServer after it.
+    if let Err(e) = ws_server.send(CloseAllSessions).await {
+        log::error!("Close the WebSocket sessions failed: {:?}", e);
+    }
+    let drain_sessions = async {
+        while ws_server.send(SessionCount).await.unwrap_or(0) > 0 {
+            sleep(Duration::from_millis(50)).await;
+        }
+    };
+    if timeout_at(deadline, drain_sessions).await.is_err() {
+        log::warn!("The WebSocket sessions are not closed within {:?}", timeout);
+    }
+
+    if timeout_at(deadline, server.stop(true)).await.is_err() {
+        log::warn!("The requests are not finished within {:?}", timeout);
+        server.stop(false).await;
+    }
+}
+
+pub fn run(listener: TcpListener, app_ctx: AppContext, configuration: &Settings) -> Result<Server, std::io::Error> {
     let domain = domain();
     let secret: String = secret();
-    actix_rt::spawn(period_check(app_ctx.pg_pool.clone()));
+    // The cookie can be secure if the server terminates TLS itself.
+    let secure = use_https() || configuration.tls.is_some();
+    let timeout = configuration.timeout.clone();
+    let max_json_bytes = configuration.application.max_json_bytes;
+    let rate_limiter = RateLimiter::new(&configuration.rate_limit);
+    let cors = configuration.cors.clone();
+    let session = web::Data::new(configuration.session.clone());
+    let search_settings = web::Data::new(configuration.search.clone());
+    actix_rt::spawn(period_check(
+        app_ctx.ws_server.get_ref().clone(),
+        rate_limiter.clone(),
+        configuration.session.clone(),
+    ));
 
     let server = HttpServer::new(move || {
         App::new()
-            .wrap(middleware::Logger::default())
-            .wrap(identify_service(&domain, &secret))
-            .wrap(crate::middleware::default_cors())
+            .wrap(crate::middleware::RequestTimeout::new(&timeout))
+            .wrap(middleware::Logger::new(LOGGER_FORMAT).custom_request_replace("request_line", redacted_request_line))
+            .wrap(identify_service(&domain, &secret, secure))
+            .wrap(crate::middleware::cors(&cors))
             .wrap(crate::middleware::AuthenticationService)
-            .app_data(web::JsonConfig::default().limit(4096))
+            .wrap(rate_limiter.clone())
+            .wrap(crate::middleware::HttpMetrics)
+            .wrap(crate::middleware::RequestIdService)
+            .app_data(json_config(max_json_bytes))
+            .app_data(query_config())
+            .app_data(path_config())
+            .configure(health::configure)
+            .configure(metrics::configure)
             .service(ws_scope())
             .service(user_scope())
             .app_data(app_ctx.ws_server.clone())
             .app_data(app_ctx.pg_pool.clone())
             .app_data(app_ctx.ws_bizs.clone())
             .app_data(app_ctx.doc_biz.clone())
+            .app_data(app_ctx.quota.clone())
+            .app_data(app_ctx.export_limiter.clone())
+            .app_data(app_ctx.jwt.clone())
+            .app_data(app_ctx.verification.clone())
+            .app_data(app_ctx.password_reset.clone())
+            .app_data(app_ctx.mailer.clone())
+            .app_data(session.clone())
+            .app_data(search_settings.clone())
     })
-    .listen(listener)?
-    .run();
-    Ok(server)
+    // The signals are handled by the Application to close the WebSocket
+    // sessions before stopping the server.
+    .disable_signals();
+
+    let server = match &configuration.tls {
+        None => server.listen(listener)?,
+        Some(tls) => server.listen_rustls(listener, tls.server_config()?)?,
+    };
+    Ok(server.run())
+}
+
+// Formats the request line like the `%r` of the Logger, but with the token
+// passed by the WebSocket clients in the query or in the path redacted.
+pub fn redacted_request_line(req: &ServiceRequest) -> String {
+    let path = match req.path().strip_prefix("/ws/") {
+        Some(token) if !token.is_empty() => format!("/ws/{}", REDACTED),
+        _ => req.path().to_owned(),
+    };
+    let query = req
+        .query_string()
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| match pair.split_once('=') {
+            Some((HEADER_TOKEN, _)) => format!("{}={}", HEADER_TOKEN, REDACTED),
+            _ => pair.to_owned(),
+        })
+        .collect::<Vec<String>>()
+        .join("&");
+    match query.is_empty() {
+        true => format!("{} {} {:?}", req.method(), path, req.version()),
+        false => format!("{} {}?{} {:?}", req.method(), path, query, req.version()),
+    }
 }
 
-async fn period_check(_pool: Data<PgPool>) {
-    let mut i = interval(Duration::from_secs(60));
+// Disconnects the WebSocket sessions without any heartbeat within the idle
+// timeout, forgets the logged out or expired users and prunes the idle rate
+// limit buckets.
+pub async fn period_check(ws_server: Addr<WsServer>, rate_limiter: RateLimiter, settings: SessionSettings) {
+    let idle_timeout = Duration::from_secs(settings.idle_timeout_secs);
+    let mut i = interval(Duration::from_secs(settings.check_interval_secs));
     loop {
         i.tick().await;
+        match ws_server.send(ReapIdleSessions { idle_timeout }).await {
+            Ok(sids) if !sids.is_empty() => tracing::info!("Reap {} idle sessions", sids.len()),
+            Ok(_) => {},
+            Err(e) => log::error!("Reap the idle sessions failed: {:?}", e),
+        }
+        let _ = AUTHORIZED_USERS.remove_expired();
+        let pruned = rate_limiter.prune();
+        if pruned > 0 {
+            tracing::debug!("Prune {} rate limit buckets", pruned);
+        }
     }
 }
 
-fn ws_scope() -> Scope { web::scope("/ws").service(ws::router::establish_ws_connection) }
+fn ws_scope() -> Scope {
+    web::scope("/ws")
+        .service(ws::router::establish_ws_connection)
+        .service(ws::router::establish_ws_connection_with_path_token)
+}
 
 fn user_scope() -> Scope {
     // https://developer.mozilla.org/en-US/docs/Web/HTTP
@@ -86,6 +273,12 @@ fn user_scope() -> Scope {
             .route(web::post().to(user::sign_in_handler))
             .route(web::delete().to(user::sign_out_handler))
         )
+        .service(web::resource("/auth/logout_all")
+            .route(web::post().to(user::logout_all_handler))
+        )
+        .service(web::resource("/auth/verify/resend")
+            .route(web::post().to(user::resend_verification_handler))
+        )
         .service(web::resource("/user")
             .route(web::patch().to(user::set_user_profile_handler))
             .route(web::get().to(user::get_user_profile_handler))
@@ -93,12 +286,22 @@ fn user_scope() -> Scope {
         .service(web::resource("/register")
             .route(web::post().to(user::register_handler))
         )
+        .service(web::resource("/register/verify")
+            .route(web::get().to(user::verify_email_handler))
+        )
+        .service(web::resource("/token/refresh")
+            .route(web::post().to(user::refresh_token_handler))
+        )
         .service(web::resource("/workspace")
             .route(web::post().to(workspace::create_handler))
             .route(web::delete().to(workspace::delete_handler))
             .route(web::get().to(workspace::read_handler))
             .route(web::patch().to(workspace::update_handler))
         )
+        .service(web::resource("/workspace/members")
+            .route(web::post().to(workspace::add_member_handler))
+            .route(web::delete().to(workspace::remove_member_handler))
+        )
         .service(web::resource("/workspace_list/{user_id}")
             .route(web::get().to(workspace::workspace_list))
         )
@@ -114,6 +317,39 @@ fn user_scope() -> Scope {
             .route(web::get().to(view::read_handler))
             .route(web::patch().to(view::update_handler))
         )
+        .service(web::resource("/view/move")
+            .route(web::patch().to(view::move_handler))
+        )
+        .service(web::resource("/view/duplicate")
+            .route(web::post().to(view::duplicate_handler))
+        )
+        .service(web::resource("/view/favorite")
+            .route(web::post().to(view::create_favorite_handler))
+            .route(web::delete().to(view::delete_favorite_handler))
+        )
+        .service(web::resource("/favorites")
+            .route(web::get().to(view::read_favorites_handler))
+        )
+        .service(web::resource("/view/reorder")
+            .route(web::patch().to(view::reorder_handler))
+        )
+        .service(web::resource("/view/{view_id}/print")
+            .route(web::get().to(view::print_handler))
+        )
+        .service(web::resource("/view/{view_id}/stats")
+            .route(web::get().to(view::stats_handler))
+        )
+        .service(web::resource("/view/{view_id}/versions")
+            .route(web::post().to(view::create_version_handler))
+            .route(web::get().to(view::read_versions_handler))
+        )
+        .service(web::resource("/view/{view_id}/versions/{version_id}")
+            .route(web::get().to(view::read_version_doc_handler))
+            .route(web::delete().to(view::delete_version_handler))
+        )
+        .service(web::resource("/view/{view_id}/touch")
+            .route(web::post().to(view::touch_handler))
+        )
         .service(web::resource("/doc")
             .route(web::post().to(doc::create_handler))
             .route(web::get().to(doc::read_handler))
@@ -124,10 +360,31 @@ fn user_scope() -> Scope {
             .route(web::delete().to(trash::delete_handler))
             .route(web::get().to(trash::read_handler))
         )
+        .service(web::resource("/trash/restore")
+            .route(web::post().to(trash::restore_handler))
+        )
+        .service(web::resource("/search")
+            .route(web::get().to(search::search_handler))
+        )
+        .service(web::resource("/admin/docs/{doc_id}/sessions")
+            .route(web::get().to(admin::read_doc_sessions_handler))
+        )
+        .service(web::resource("/admin/docs/{doc_id}/sessions/{session_id}")
+            .route(web::delete().to(admin::terminate_doc_session_handler))
+        )
+        .service(web::resource("/admin/workspace/{workspace_id}/reindex")
+            .route(web::post().to(admin::reindex_workspace_handler))
+        )
         // password
         .service(web::resource("/password_change")
             .route(web::post().to(user::change_password))
         )
+        .service(web::resource("/password/reset_request")
+            .route(web::post().to(user::reset_password_request_handler))
+        )
+        .service(web::resource("/password/reset_confirm")
+            .route(web::post().to(user::reset_password_confirm_handler))
+        )
 }
 
 pub async fn init_app_context(configuration: &Settings) -> AppContext {
@@ -139,23 +396,54 @@ pub async fn init_app_context(configuration: &Settings) -> AppContext {
         .unwrap_or_else(|_| panic!("Failed to connect to Postgres at {:?}.", configuration.database));
 
     let ws_server = WsServer::new().start();
-    AppContext::new(ws_server, pg_pool)
+    AppContext::new(ws_server, pg_pool, configuration, Arc::new(LogMailer::default()))
 }
 
-pub fn identify_service(domain: &str, secret: &str) -> IdentityService<CookieIdentityPolicy> {
+// The cookie is only sent over HTTPS if the secure is true.
+pub fn identify_service(domain: &str, secret: &str, secure: bool) -> IdentityService<CookieIdentityPolicy> {
     IdentityService::new(
         CookieIdentityPolicy::new(secret.as_bytes())
             .name("auth")
             .path("/")
             .domain(domain)
             .max_age_secs(24 * 3600)
-            .secure(use_https()),
+            .secure(secure),
     )
 }
 
+// Responds the JSON body that can't be extracted as invalid params, and 413 if
+// it's larger than `max_json_bytes`.
+pub fn json_config(max_json_bytes: usize) -> web::JsonConfig {
+    web::JsonConfig::default()
+        .limit(max_json_bytes)
+        .error_handler(move |err, _req| {
+            let error = match err.status_code() {
+                StatusCode::PAYLOAD_TOO_LARGE => ServerError::payload_overflow()
+                    .context(format!("The JSON body exceeds the limit of {} bytes", max_json_bytes)),
+                _ => invalid_params(err),
+            };
+            error.into()
+        })
+}
+
+// Responds the query string and the path that can't be extracted as invalid
+// params, like the other errors.
+pub fn query_config() -> web::QueryConfig {
+    web::QueryConfig::default().error_handler(|err, _req| invalid_params(err).into())
+}
+
+pub fn path_config() -> web::PathConfig {
+    web::PathConfig::default().error_handler(|err, _req| invalid_params(err).into())
+}
+
 pub async fn get_connection_pool(configuration: &DatabaseSettings) -> Result<PgPool, sqlx::Error> {
     PgPoolOptions::new()
-        .connect_timeout(std::time::Duration::from_secs(5))
+        .max_connections(configuration.max_connections)
+        .min_connections(configuration.min_connections)
+        .idle_timeout(Duration::from_secs(configuration.idle_timeout_secs))
+        // The connect_timeout of the PgPoolOptions is the time to wait for
+        // acquiring a connection.
+        .connect_timeout(Duration::from_secs(configuration.acquire_timeout_secs))
         .connect_with(configuration.with_db())
         .await
 }
@@ -1,11 +1,40 @@
 use serde_aux::field_attributes::deserialize_number_from_string;
 use sqlx::postgres::{PgConnectOptions, PgSslMode};
-use std::convert::{TryFrom, TryInto};
+use std::{
+    convert::{TryFrom, TryInto},
+    fs::File,
+    io::BufReader,
+};
 
 #[derive(serde::Deserialize, Clone, Debug)]
 pub struct Settings {
     pub database: DatabaseSettings,
     pub application: ApplicationSettings,
+    #[serde(default)]
+    pub quota: QuotaSettings,
+    #[serde(default)]
+    pub sync: SyncSettings,
+    #[serde(default)]
+    pub timeout: TimeoutSettings,
+    #[serde(default)]
+    pub export: ExportSettings,
+    #[serde(default)]
+    pub session: SessionSettings,
+    #[serde(default)]
+    pub rate_limit: RateLimitSettings,
+    #[serde(default)]
+    pub cors: CorsSettings,
+    #[serde(default)]
+    pub jwt: JwtSettings,
+    #[serde(default)]
+    pub verification: VerificationSettings,
+    #[serde(default)]
+    pub password_reset: PasswordResetSettings,
+    #[serde(default)]
+    pub search: SearchSettings,
+    // Terminates TLS in the server instead of a proxy if provided.
+    #[serde(default)]
+    pub tls: Option<TlsSettings>,
 }
 
 // We are using 127.0.0.1 as our host in address, we are instructing our
@@ -21,6 +50,259 @@ pub struct ApplicationSettings {
     #[serde(deserialize_with = "deserialize_number_from_string")]
     pub port: u16,
     pub host: String,
+    // A request with a JSON body larger than this number of bytes is rejected
+    // with 413.
+    #[serde(
+        default = "default_max_json_bytes",
+        deserialize_with = "deserialize_number_from_string"
+    )]
+    pub max_json_bytes: usize,
+}
+
+fn default_max_json_bytes() -> usize { 1024 * 1024 }
+
+#[derive(serde::Deserialize, Clone, Debug, Default)]
+pub struct QuotaSettings {
+    // The maximum number of views a user can own across all of their
+    // workspaces, items in the trash excluded. No limit if None.
+    pub max_documents_per_user: Option<i64>,
+}
+
+#[derive(serde::Deserialize, Clone, Debug, Default)]
+pub struct SyncSettings {
+    // A client more than this number of revisions behind the server head
+    // resyncs with the snapshot of the document and rebases its local
+    // revisions on it, instead of transforming them against the backlog.
+    // Never resyncs if None.
+    pub max_revisions_behind: Option<i64>,
+    // The least recently accessed documents beyond this number are dropped
+    // from memory, and reloaded from the database once accessed again. Keeps
+    // all the opened documents if None.
+    pub max_opened_docs: Option<usize>,
+    // The revisions of a session applied within this number of milliseconds of
+    // the first one are composed into a single broadcast to the other sessions.
+    // Broadcasts every revision if None.
+    pub broadcast_coalesce_ms: Option<u64>,
+}
+
+#[derive(serde::Deserialize, Clone, Debug, Default)]
+pub struct TimeoutSettings {
+    // A request handled longer than this number of milliseconds is cancelled
+    // and responded with 504. Never times out if None.
+    pub request_timeout_ms: Option<u64>,
+    // Overrides the timeout of the requests matching the route patterns, for
+    // the known-slow ones like printing a view.
+    #[serde(default)]
+    pub route_timeouts: Vec<RouteTimeoutSettings>,
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+pub struct RouteTimeoutSettings {
+    pub pattern: String,
+    pub timeout_ms: u64,
+}
+
+#[derive(serde::Deserialize, Clone, Debug, Default)]
+pub struct ExportSettings {
+    // The maximum number of exports, e.g. printing a view, running at once.
+    // The other ones wait for a running one to finish. No limit if None.
+    pub max_concurrent_exports: Option<usize>,
+    // An export waiting longer than this number of milliseconds is rejected
+    // with 429. Waits until it runs if None.
+    pub queue_timeout_ms: Option<u64>,
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct SessionSettings {
+    // The stale sessions are reaped every this number of seconds.
+    pub check_interval_secs: u64,
+    // A WebSocket session without any heartbeat within this number of seconds
+    // is disconnected.
+    pub idle_timeout_secs: u64,
+    // Each connection is pinged every this number of seconds.
+    pub heartbeat_interval_secs: u64,
+    // A connection without any frame, including the pongs, within this number
+    // of seconds is closed by its session.
+    pub heartbeat_timeout_secs: u64,
+}
+
+impl std::default::Default for SessionSettings {
+    fn default() -> Self {
+        Self {
+            check_interval_secs: 60,
+            idle_timeout_secs: 60,
+            heartbeat_interval_secs: 8,
+            heartbeat_timeout_secs: 60,
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct RateLimitSettings {
+    // The route patterns limited per IP, the other routes are never limited.
+    pub routes: Vec<String>,
+    // The number of requests an IP can send to one of the routes at once.
+    pub burst: u32,
+    // The number of requests an IP can send to one of the routes per minute
+    // after the burst.
+    pub per_minute: u32,
+}
+
+impl std::default::Default for RateLimitSettings {
+    fn default() -> Self {
+        Self {
+            routes: vec![
+                "/api/auth".to_owned(),
+                "/api/register".to_owned(),
+                "/api/password/reset_request".to_owned(),
+                "/api/auth/verify/resend".to_owned(),
+            ],
+            burst: 10,
+            per_minute: 10,
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct CorsSettings {
+    // The origins allowed to send the cross-origin requests, e.g.
+    // "https://appflowy.io". Denies all the origins if empty.
+    pub allowed_origins: Vec<String>,
+    pub allowed_methods: Vec<String>,
+    // Whether the cross-origin requests can carry the cookies. Can't be used
+    // with the "*" origin.
+    pub allow_credentials: bool,
+}
+
+impl CorsSettings {
+    // The browsers reject the credentialed responses that allow any origin,
+    // so the "*" origin with the credentials is rejected when it's loaded.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.allow_credentials && self.allowed_origins.iter().any(|origin| origin == "*") {
+            return Err("The \"*\" origin can't be allowed with the credentials".to_owned());
+        }
+        Ok(())
+    }
+}
+
+impl std::default::Default for CorsSettings {
+    fn default() -> Self {
+        Self {
+            allowed_origins: vec![],
+            allowed_methods: ["GET", "POST", "PUT", "PATCH", "DELETE"]
+                .iter()
+                .map(|method| method.to_string())
+                .collect(),
+            allow_credentials: false,
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct JwtSettings {
+    // Signs in with a short-lived access token and a refresh token, exchanged
+    // for a new pair at /api/token/refresh, instead of the long-lived token.
+    pub enabled: bool,
+    pub access_token_expiry_secs: i64,
+    pub refresh_token_expiry_secs: i64,
+}
+
+impl std::default::Default for JwtSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            access_token_expiry_secs: 3600,
+            refresh_token_expiry_secs: 30 * 24 * 3600,
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct VerificationSettings {
+    // The registered users can't sign in until their email addresses are
+    // verified at /api/register/verify with the token sent to them.
+    pub enabled: bool,
+    pub token_expiry_secs: i64,
+}
+
+impl std::default::Default for VerificationSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            token_expiry_secs: 24 * 3600,
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct PasswordResetSettings {
+    // The token sent to reset the password expires after this number of
+    // seconds.
+    pub token_expiry_secs: i64,
+}
+
+impl std::default::Default for PasswordResetSettings {
+    fn default() -> Self {
+        Self {
+            token_expiry_secs: 3600,
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct SearchSettings {
+    // Searches the words of the documents with their tsvector column, ranked
+    // by ts_rank. Falls back to matching the query as a substring with ILIKE
+    // if false.
+    pub full_text: bool,
+}
+
+impl std::default::Default for SearchSettings {
+    fn default() -> Self { Self { full_text: true } }
+}
+
+#[derive(serde::Deserialize, Clone, Debug)]
+pub struct TlsSettings {
+    // The PEM files of the certificate chain and its PKCS#8 or RSA private
+    // key.
+    pub cert_path: String,
+    pub key_path: String,
+}
+
+impl TlsSettings {
+    pub fn server_config(&self) -> Result<rustls::ServerConfig, std::io::Error> {
+        let invalid_data = |msg: String| std::io::Error::new(std::io::ErrorKind::InvalidData, msg);
+        let mut cert_reader = BufReader::new(File::open(&self.cert_path)?);
+        let certs = rustls_pemfile::certs(&mut cert_reader)?
+            .into_iter()
+            .map(rustls::Certificate)
+            .collect::<Vec<_>>();
+        if certs.is_empty() {
+            return Err(invalid_data(format!("No certificate in {}", self.cert_path)));
+        }
+
+        let mut key_reader = BufReader::new(File::open(&self.key_path)?);
+        let key = rustls_pemfile::read_all(&mut key_reader)?
+            .into_iter()
+            .find_map(|item| match item {
+                rustls_pemfile::Item::PKCS8Key(key) | rustls_pemfile::Item::RSAKey(key) => Some(key),
+                _ => None,
+            })
+            .ok_or_else(|| invalid_data(format!("No private key in {}", self.key_path)))?;
+
+        rustls::ServerConfig::builder()
+            .with_safe_defaults()
+            .with_no_client_auth()
+            .with_single_cert(certs, rustls::PrivateKey(key))
+            .map_err(|e| invalid_data(format!("{:?}", e)))
+    }
 }
 
 #[derive(serde::Deserialize, Clone, Debug)]
@@ -32,8 +314,37 @@ pub struct DatabaseSettings {
     pub host: String,
     pub database_name: String,
     pub require_ssl: bool,
+    // The pool keeps at most max_connections and at least min_connections
+    // connections open.
+    #[serde(
+        default = "default_max_connections",
+        deserialize_with = "deserialize_number_from_string"
+    )]
+    pub max_connections: u32,
+    #[serde(default, deserialize_with = "deserialize_number_from_string")]
+    pub min_connections: u32,
+    // A connection idle longer than this number of seconds is closed, unless
+    // the pool is at min_connections.
+    #[serde(
+        default = "default_idle_timeout_secs",
+        deserialize_with = "deserialize_number_from_string"
+    )]
+    pub idle_timeout_secs: u64,
+    // Acquiring a connection waits at most this number of seconds when all the
+    // connections are in use.
+    #[serde(
+        default = "default_acquire_timeout_secs",
+        deserialize_with = "deserialize_number_from_string"
+    )]
+    pub acquire_timeout_secs: u64,
 }
 
+fn default_max_connections() -> u32 { 10 }
+
+fn default_idle_timeout_secs() -> u64 { 600 }
+
+fn default_acquire_timeout_secs() -> u64 { 5 }
+
 impl DatabaseSettings {
     pub fn without_db(&self) -> PgConnectOptions {
         let ssl_mode = if self.require_ssl {
@@ -71,7 +382,9 @@ pub fn get_configuration() -> Result<Settings, config::ConfigError> {
     // `Settings.application.port`
     settings.merge(config::Environment::with_prefix("app").separator("__"))?;
 
-    settings.try_into()
+    let settings: Settings = settings.try_into()?;
+    settings.cors.validate().map_err(config::ConfigError::Message)?;
+    Ok(settings)
 }
 
 /// The possible runtime environment for our application.
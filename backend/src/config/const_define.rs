@@ -1,7 +1,13 @@
-use std::time::Duration;
-
-pub const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(8);
-pub const PING_TIMEOUT: Duration = Duration::from_secs(60);
 pub const MAX_PAYLOAD_SIZE: usize = 262_144; // max payload size is 256k
+pub const HEADER_REQUEST_ID: &str = "x-request-id";
 
-pub const IGNORE_ROUTES: [&str; 3] = ["/api/register", "/api/auth", "/ws"];
+pub const IGNORE_ROUTES: [&str; 8] = [
+    "/api/register",
+    "/api/auth",
+    "/api/token/refresh",
+    "/api/password/reset",
+    "/ws",
+    "/health",
+    "/ready",
+    "/metrics",
+];
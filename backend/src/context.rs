@@ -1,6 +1,11 @@
-use crate::service::{
-    doc::doc::DocBiz,
-    ws::{WsBizHandlers, WsServer},
+use crate::{
+    config::{JwtSettings, PasswordResetSettings, QuotaSettings, Settings, VerificationSettings},
+    service::{
+        doc::doc::DocBiz,
+        export::ExportLimiter,
+        user::Mailer,
+        ws::{WsBizHandlers, WsServer},
+    },
 };
 use actix::Addr;
 use actix_web::web::Data;
@@ -14,15 +19,21 @@ pub struct AppContext {
     pub pg_pool: Data<PgPool>,
     pub ws_bizs: Data<WsBizHandlers>,
     pub doc_biz: Data<Arc<DocBiz>>,
+    pub quota: Data<QuotaSettings>,
+    pub export_limiter: Data<ExportLimiter>,
+    pub jwt: Data<JwtSettings>,
+    pub verification: Data<VerificationSettings>,
+    pub password_reset: Data<PasswordResetSettings>,
+    pub mailer: Data<Arc<dyn Mailer>>,
 }
 
 impl AppContext {
-    pub fn new(ws_server: Addr<WsServer>, db_pool: PgPool) -> Self {
+    pub fn new(ws_server: Addr<WsServer>, db_pool: PgPool, configuration: &Settings, mailer: Arc<dyn Mailer>) -> Self {
         let ws_server = Data::new(ws_server);
         let pg_pool = Data::new(db_pool);
 
         let mut ws_bizs = WsBizHandlers::new();
-        let doc_biz = Arc::new(DocBiz::new(pg_pool.clone()));
+        let doc_biz = Arc::new(DocBiz::new(pg_pool.clone(), ws_server.get_ref().clone(), configuration.sync.clone()));
         ws_bizs.register(WsModule::Doc, doc_biz.clone());
 
         AppContext {
@@ -30,6 +41,12 @@ impl AppContext {
             pg_pool,
             ws_bizs: Data::new(ws_bizs),
             doc_biz: Data::new(doc_biz),
+            quota: Data::new(configuration.quota.clone()),
+            export_limiter: Data::new(ExportLimiter::new(&configuration.export)),
+            jwt: Data::new(configuration.jwt.clone()),
+            verification: Data::new(configuration.verification.clone()),
+            password_reset: Data::new(configuration.password_reset.clone()),
+            mailer: Data::new(mailer),
         }
     }
 }
@@ -1,12 +1,15 @@
-use flowy_document_infra::protobuf::Doc;
+use chrono::Utc;
+use flowy_document_infra::protobuf::{Doc, NamedVersion};
 
 pub(crate) const DOC_TABLE: &str = "doc_table";
+pub(crate) const NAMED_VERSION_TABLE: &str = "named_version_table";
 
 #[derive(Debug, Clone, sqlx::FromRow)]
 pub struct DocTable {
     pub(crate) id: uuid::Uuid,
     pub(crate) data: String,
     pub(crate) rev_id: i64,
+    pub(crate) modified_time: chrono::DateTime<Utc>,
 }
 
 impl std::convert::From<DocTable> for Doc {
@@ -18,3 +21,39 @@ impl std::convert::From<DocTable> for Doc {
         doc
     }
 }
+
+// The server keeps the latest document only, so the named version pins the
+// data of the document at its revision.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct NamedVersionTable {
+    pub(crate) id: uuid::Uuid,
+    pub(crate) doc_id: uuid::Uuid,
+    pub(crate) rev_id: i64,
+    pub(crate) name: String,
+    pub(crate) data: String,
+    pub(crate) created_by: String,
+    pub(crate) created_at: chrono::DateTime<Utc>,
+}
+
+impl std::convert::From<NamedVersionTable> for NamedVersion {
+    fn from(table: NamedVersionTable) -> Self {
+        let mut version = NamedVersion::new();
+        version.set_id(table.id.to_string());
+        version.set_doc_id(table.doc_id.to_string());
+        version.set_rev_id(table.rev_id);
+        version.set_name(table.name);
+        version.set_created_by(table.created_by);
+        version.set_created_at(table.created_at.timestamp());
+        version
+    }
+}
+
+impl std::convert::From<NamedVersionTable> for Doc {
+    fn from(table: NamedVersionTable) -> Self {
+        let mut doc = Doc::new();
+        doc.set_id(table.doc_id.to_string());
+        doc.set_data(table.data);
+        doc.set_rev_id(table.rev_id);
+        doc
+    }
+}
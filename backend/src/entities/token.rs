@@ -6,6 +6,9 @@ use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header,
 use serde::{Deserialize, Serialize};
 
 const DEFAULT_ALGORITHM: Algorithm = Algorithm::HS256;
+const AUTH_SUBJECT: &str = "auth";
+const ACCESS_SUBJECT: &str = "access";
+const REFRESH_SUBJECT: &str = "refresh";
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claim {
@@ -18,21 +21,32 @@ pub struct Claim {
     // expiry
     exp: i64,
     user_id: String,
+    // The token epoch of the user when it's issued, the token is invalidated
+    // once the epoch is increased by signing out everywhere.
+    #[serde(default)]
+    epoch: i64,
 }
 
 impl Claim {
-    pub fn with_user_id(user_id: &str) -> Self {
+    pub fn with_user_id(user_id: &str, epoch: i64) -> Self {
+        Self::new(user_id, epoch, AUTH_SUBJECT, Duration::days(EXPIRED_DURATION_DAYS))
+    }
+
+    fn new(user_id: &str, epoch: i64, sub: &str, expiry: Duration) -> Self {
         let domain = domain();
         Self {
             iss: domain,
-            sub: "auth".to_string(),
+            sub: sub.to_string(),
             user_id: user_id.to_string(),
             iat: Local::now().timestamp(),
-            exp: (Local::now() + Duration::days(EXPIRED_DURATION_DAYS)).timestamp(),
+            exp: (Local::now() + expiry).timestamp(),
+            epoch,
         }
     }
 
     pub fn user_id(self) -> String { self.user_id }
+
+    pub fn epoch(&self) -> i64 { self.epoch }
 }
 
 // impl From<Claim> for User {
@@ -42,8 +56,23 @@ impl Claim {
 #[derive(From, Into, Clone)]
 pub struct Token(pub String);
 impl Token {
-    pub fn create_token(user_id: &str) -> Result<Self, ServerError> {
-        let claims = Claim::with_user_id(&user_id);
+    pub fn create_token(user_id: &str, epoch: i64) -> Result<Self, ServerError> {
+        Self::encode_claim(&Claim::with_user_id(user_id, epoch))
+    }
+
+    // The short-lived token signing in with the JwtSettings enabled, it's used
+    // the same way as the token created by create_token.
+    pub fn create_access_token(user_id: &str, epoch: i64, expiry: Duration) -> Result<Self, ServerError> {
+        Self::encode_claim(&Claim::new(user_id, epoch, ACCESS_SUBJECT, expiry))
+    }
+
+    // Can only be exchanged for a new pair of tokens, it's rejected by the
+    // other requests.
+    pub fn create_refresh_token(user_id: &str, epoch: i64, expiry: Duration) -> Result<Self, ServerError> {
+        Self::encode_claim(&Claim::new(user_id, epoch, REFRESH_SUBJECT, expiry))
+    }
+
+    fn encode_claim(claims: &Claim) -> Result<Self, ServerError> {
         encode(
             &Header::new(DEFAULT_ALGORITHM),
             &claims,
@@ -54,6 +83,23 @@ impl Token {
     }
 
     pub fn decode_token(token: &Self) -> Result<Claim, ServerError> {
+        let claim = Self::decode_claim(token)?;
+        match claim.sub.as_str() {
+            REFRESH_SUBJECT => Err(ServerError::unauthorized().context("The refresh token can't be used as a token")),
+            _ => Ok(claim),
+        }
+    }
+
+    pub fn decode_refresh_token(token: &Self) -> Result<Claim, ServerError> {
+        let claim = Self::decode_claim(token)?;
+        match claim.sub.as_str() {
+            REFRESH_SUBJECT => Ok(claim),
+            _ => Err(ServerError::unauthorized().context("Expect a refresh token")),
+        }
+    }
+
+    // The expiry is validated too.
+    fn decode_claim(token: &Self) -> Result<Claim, ServerError> {
         decode::<Claim>(
             &token.0,
             &DecodingKey::from_secret(jwt_secret().as_ref()),
@@ -63,11 +109,20 @@ impl Token {
         .map_err(|err| ServerError::unauthorized().context(err))?
     }
 
+    // Reads the token from the token header, or from the Authorization header
+    // as a bearer token.
     pub fn parser_from_request(request: &HttpRequest) -> Result<Self, ServerError> {
-        match request.headers().get(HEADER_TOKEN) {
-            Some(header) => match header.to_str() {
+        if let Some(header) = request.headers().get(HEADER_TOKEN) {
+            return match header.to_str() {
                 Ok(val) => Ok(Token(val.to_owned())),
                 Err(_) => Err(ServerError::unauthorized()),
+            };
+        }
+
+        match request.headers().get(AUTHORIZATION) {
+            Some(header) => match header.to_str().ok().and_then(|val| val.strip_prefix("Bearer ")) {
+                Some(val) => Ok(Token(val.trim().to_owned())),
+                None => Err(ServerError::unauthorized()),
             },
             None => Err(ServerError::unauthorized()),
         }
@@ -75,7 +130,7 @@ impl Token {
 }
 
 use crate::service::user::EXPIRED_DURATION_DAYS;
-use actix_web::{dev::Payload, FromRequest, HttpRequest};
+use actix_web::{dev::Payload, http::header::AUTHORIZATION, FromRequest, HttpRequest};
 use backend_service::config::HEADER_TOKEN;
 use futures::future::{ready, Ready};
 
@@ -6,6 +6,9 @@ pub(crate) const WORKSPACE_TABLE: &str = "workspace_table";
 pub(crate) const APP_TABLE: &str = "app_table";
 pub(crate) const VIEW_TABLE: &str = "view_table";
 pub(crate) const TRASH_TABLE: &str = "trash_table";
+pub(crate) const VIEW_ACCESS_TABLE: &str = "view_access_table";
+pub(crate) const VIEW_FAVORITE_TABLE: &str = "view_favorite_table";
+pub(crate) const WORKSPACE_MEMBER_TABLE: &str = "workspace_member_table";
 
 #[derive(Debug, Clone, sqlx::FromRow)]
 pub struct WorkspaceTable {
@@ -39,6 +42,7 @@ pub struct AppTable {
     pub(crate) modified_time: chrono::DateTime<Utc>,
     pub(crate) create_time: chrono::DateTime<Utc>,
     pub(crate) user_id: String,
+    pub(crate) parent_id: Option<String>,
 }
 impl std::convert::From<AppTable> for App {
     fn from(table: AppTable) -> Self {
@@ -50,6 +54,9 @@ impl std::convert::From<AppTable> for App {
         app.set_belongings(RepeatedView::default());
         app.set_modified_time(table.modified_time.timestamp());
         app.set_create_time(table.create_time.timestamp());
+        if let Some(parent_id) = table.parent_id {
+            app.set_parent_id(parent_id);
+        }
 
         app
     }
@@ -79,6 +86,9 @@ pub struct ViewTable {
     pub(crate) create_time: chrono::DateTime<Utc>,
     pub(crate) thumbnail: String,
     pub(crate) view_type: i32,
+    pub(crate) parent_view_id: Option<String>,
+    // The order among the views sharing the parent, see reorder_view.
+    pub(crate) position: i32,
 }
 impl std::convert::From<ViewTable> for View {
     fn from(table: ViewTable) -> Self {
@@ -93,6 +103,9 @@ impl std::convert::From<ViewTable> for View {
         view.set_belongings(RepeatedView::default());
         view.set_create_time(table.create_time.timestamp());
         view.set_modified_time(table.modified_time.timestamp());
+        if let Some(parent_view_id) = table.parent_view_id {
+            view.set_parent_view_id(parent_view_id);
+        }
 
         view
     }
@@ -2,6 +2,6 @@ pub mod application;
 pub mod config;
 pub mod context;
 mod entities;
-mod middleware;
+pub mod middleware;
 pub mod service;
 mod sqlx_ext;
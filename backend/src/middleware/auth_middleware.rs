@@ -1,4 +1,4 @@
-use crate::service::user::{LoggedUser, AUTHORIZED_USERS};
+use crate::{entities::token::Token, service::user::authenticate};
 use actix_service::{Service, Transform};
 use actix_web::{
     dev::{ServiceRequest, ServiceResponse},
@@ -6,14 +6,15 @@ use actix_web::{
     HttpResponse,
     ResponseError,
 };
+use sqlx::PgPool;
 
 use crate::config::IGNORE_ROUTES;
-use actix_web::{body::AnyBody, dev::MessageBody};
-use backend_service::{config::HEADER_TOKEN, errors::ServerError};
+use actix_web::{body::AnyBody, dev::MessageBody, web::Data};
+use backend_service::errors::ServerError;
 use futures::future::{ok, LocalBoxFuture, Ready};
 use std::{
-    convert::TryInto,
     error::Error as StdError,
+    rc::Rc,
     task::{Context, Poll},
 };
 
@@ -32,10 +33,14 @@ where
     type InitError = ();
     type Future = Ready<Result<Self::Transform, Self::InitError>>;
 
-    fn new_transform(&self, service: S) -> Self::Future { ok(AuthenticationMiddleware { service }) }
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(AuthenticationMiddleware {
+            service: Rc::new(service),
+        })
+    }
 }
 pub struct AuthenticationMiddleware<S> {
-    service: S,
+    service: Rc<S>,
 }
 
 impl<S, B> Service<ServiceRequest> for AuthenticationMiddleware<S>
@@ -51,7 +56,7 @@ where
 
     fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> { self.service.poll_ready(cx) }
 
-    fn call(&self, req: ServiceRequest) -> Self::Future {
+    fn call(&self, mut req: ServiceRequest) -> Self::Future {
         let mut authenticate_pass: bool = false;
         for ignore_route in IGNORE_ROUTES.iter() {
             // tracing::info!("ignore: {}, path: {}", ignore_route, req.path());
@@ -61,37 +66,42 @@ where
             }
         }
 
-        if !authenticate_pass {
-            if let Some(header) = req.headers().get(HEADER_TOKEN) {
-                let result: Result<LoggedUser, ServerError> = header.try_into();
-                match result {
-                    Ok(logged_user) => {
-                        if cfg!(feature = "ignore_auth") {
-                            authenticate_pass = true;
-                            AUTHORIZED_USERS.store_auth(logged_user, true);
-                        } else {
-                            authenticate_pass = AUTHORIZED_USERS.is_authorized(&logged_user);
-                            if authenticate_pass {
-                                AUTHORIZED_USERS.store_auth(logged_user, true);
-                            }
-                        }
-                    },
-                    Err(e) => log::error!("{:?}", e),
-                }
-            } else {
-                tracing::debug!("Can't find any token from request: {:?}", req);
-            }
-        }
-
         if authenticate_pass {
             let fut = self.service.call(req);
-            Box::pin(async move {
+            return Box::pin(async move {
                 let res = fut.await?;
                 Ok(res.map_body(|_, body| AnyBody::from_message(body)))
-            })
-        } else {
-            Box::pin(async move { Ok(req.into_response(unauthorized_response())) })
+            });
         }
+
+        let token = match Token::parser_from_request(req.parts_mut().0) {
+            Ok(token) => token,
+            Err(_) => {
+                tracing::debug!("Can't find any token from request: {:?}", req);
+                return Box::pin(async move { Ok(req.into_response(unauthorized_response())) });
+            },
+        };
+
+        // The token epoch of the user is checked against the database, so the
+        // authentication has to run in the future.
+        let service = self.service.clone();
+        let pool = req.app_data::<Data<PgPool>>().cloned();
+        Box::pin(async move {
+            let result = match pool {
+                None => Err(ServerError::internal().context("The PgPool is not registered")),
+                Some(pool) => authenticate(pool.get_ref(), &token).await,
+            };
+            match result {
+                Ok(_) => {
+                    let res = service.call(req).await?;
+                    Ok(res.map_body(|_, body| AnyBody::from_message(body)))
+                },
+                Err(e) => {
+                    log::error!("{:?}", e);
+                    Ok(req.into_response(unauthorized_response()))
+                },
+            }
+        })
     }
 }
 
@@ -1,16 +1,31 @@
+use crate::config::CorsSettings;
 use actix_cors::Cors;
 use actix_web::http;
+use backend_service::config::HEADER_TOKEN;
 
 // https://javascript.info/fetch-crossorigin#cors-for-safe-requests
 // https://docs.rs/actix-cors/0.5.4/actix_cors/index.html
 // http://www.ruanyifeng.com/blog/2016/04/cors.html
 // Cors short for Cross-Origin Resource Sharing.
-pub fn default_cors() -> Cors {
-    Cors::default() // allowed_origin return access-control-allow-origin: * by default
-        // .allowed_origin("http://127.0.0.1:8080")
-        .send_wildcard()
-        .allowed_methods(vec!["GET", "POST", "PUT", "DELETE"])
-        .allowed_headers(vec![http::header::ACCEPT])
+//
+// Only the configured origins are allowed, the requests and the preflight
+// requests from the other ones get no CORS headers. "*" allows any origin, but
+// not with the credentials, see [CorsSettings::validate].
+pub fn cors(settings: &CorsSettings) -> Cors {
+    let mut cors = Cors::default()
+        .allowed_methods(settings.allowed_methods.iter().map(|method| method.as_str()))
+        .allowed_headers(vec![http::header::ACCEPT, http::header::AUTHORIZATION])
         .allowed_header(http::header::CONTENT_TYPE)
-        .max_age(3600)
+        .allowed_header(HEADER_TOKEN)
+        .max_age(3600);
+    for origin in &settings.allowed_origins {
+        cors = match origin.as_str() {
+            "*" => cors.allow_any_origin().send_wildcard(),
+            origin => cors.allowed_origin(origin),
+        };
+    }
+    if settings.allow_credentials {
+        cors = cors.supports_credentials();
+    }
+    cors
 }
@@ -0,0 +1,67 @@
+use crate::service::metrics::{HTTP_REQUESTS, HTTP_REQUEST_DURATION};
+use actix_service::{Service, Transform};
+use actix_web::{
+    body::AnyBody,
+    dev::{MessageBody, ServiceRequest, ServiceResponse},
+    Error,
+};
+use futures::future::{ok, LocalBoxFuture, Ready};
+use std::{
+    error::Error as StdError,
+    task::{Context, Poll},
+    time::Instant,
+};
+
+// Records the number and the latency of the HTTP requests by route and status.
+// The requests not matching any route are recorded as the "unmatched" route.
+pub struct HttpMetrics;
+
+impl<S, B> Transform<S, ServiceRequest> for HttpMetrics
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+    B::Error: StdError,
+{
+    type Response = ServiceResponse;
+    type Error = Error;
+    type Transform = HttpMetricsMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future { ok(HttpMetricsMiddleware { service }) }
+}
+
+pub struct HttpMetricsMiddleware<S> {
+    service: S,
+}
+
+impl<S, B> Service<ServiceRequest> for HttpMetricsMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+    B::Error: StdError,
+{
+    type Response = ServiceResponse;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> { self.service.poll_ready(cx) }
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let start = Instant::now();
+        let method = req.method().to_string();
+        let fut = self.service.call(req);
+        Box::pin(async move {
+            let res = fut.await?;
+            let route = res.request().match_pattern().unwrap_or_else(|| "unmatched".to_owned());
+            let status = res.status().as_u16().to_string();
+            HTTP_REQUESTS.with_label_values(&[&method, &route, &status]).inc();
+            HTTP_REQUEST_DURATION
+                .with_label_values(&[&method, &route])
+                .observe(start.elapsed().as_secs_f64());
+            Ok(res.map_body(|_, body| AnyBody::from_message(body)))
+        })
+    }
+}
@@ -1,5 +1,13 @@
 mod auth_middleware;
 mod cors_middleware;
+mod metrics_middleware;
+mod rate_limit_middleware;
+mod request_id_middleware;
+mod timeout_middleware;
 
 pub use auth_middleware::*;
 pub use cors_middleware::*;
+pub use metrics_middleware::*;
+pub use rate_limit_middleware::*;
+pub use request_id_middleware::*;
+pub use timeout_middleware::*;
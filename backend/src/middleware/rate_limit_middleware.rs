@@ -0,0 +1,174 @@
+use crate::config::RateLimitSettings;
+use actix_service::{Service, Transform};
+use actix_web::{
+    body::AnyBody,
+    dev::{MessageBody, ResourceDef, ServiceRequest, ServiceResponse},
+    http::header::{HeaderValue, RETRY_AFTER},
+    Error,
+    ResponseError,
+};
+use backend_service::errors::ServerError;
+use dashmap::DashMap;
+use futures::future::{ok, LocalBoxFuture, Ready};
+use std::{
+    error::Error as StdError,
+    net::IpAddr,
+    rc::Rc,
+    sync::Arc,
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+// Limits the requests from an IP to each of the configured routes with a token
+// bucket, the request finding the bucket empty is responded with 429 and the
+// seconds to wait in the Retry-After header. The buckets are shared by all the
+// workers, so it's created once and cloned into each of them, and pruned
+// periodically, see [RateLimiter::prune].
+#[derive(Clone)]
+pub struct RateLimiter {
+    settings: RateLimitSettings,
+    buckets: Arc<DashMap<(IpAddr, usize), TokenBucket>>,
+}
+
+impl RateLimiter {
+    pub fn new(settings: &RateLimitSettings) -> Self {
+        Self {
+            settings: settings.clone(),
+            buckets: Arc::new(DashMap::new()),
+        }
+    }
+
+    // Removes the buckets refilled to the full since their last request, which
+    // behave the same as the missing ones. Returns the number of the buckets
+    // removed.
+    pub fn prune(&self) -> usize {
+        let burst = self.settings.burst as f64;
+        let refill_per_sec = self.settings.per_minute as f64 / 60.0;
+        let now = Instant::now();
+        let len = self.buckets.len();
+        self.buckets.retain(|_, bucket| {
+            let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+            bucket.tokens + elapsed * refill_per_sec < burst
+        });
+        len.saturating_sub(self.buckets.len())
+    }
+}
+
+struct TokenBucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+struct RateLimits {
+    routes: Vec<ResourceDef>,
+    burst: f64,
+    refill_per_sec: f64,
+    buckets: Arc<DashMap<(IpAddr, usize), TokenBucket>>,
+}
+
+impl RateLimits {
+    // Takes a token from the bucket of the IP and the route, or returns the
+    // time to wait for the next token.
+    fn acquire(&self, ip: IpAddr, path: &str) -> Result<(), Duration> {
+        let route = match self.routes.iter().position(|resource| resource.is_match(path)) {
+            None => return Ok(()),
+            Some(route) => route,
+        };
+
+        let now = Instant::now();
+        let mut bucket = self.buckets.entry((ip, route)).or_insert(TokenBucket {
+            tokens: self.burst,
+            refilled_at: now,
+        });
+        let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.refill_per_sec).min(self.burst);
+        bucket.refilled_at = now;
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            return Ok(());
+        }
+
+        match self.refill_per_sec > 0.0 {
+            true => Err(Duration::from_secs_f64((1.0 - bucket.tokens) / self.refill_per_sec)),
+            false => Err(Duration::from_secs(60)),
+        }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RateLimiter
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+    B::Error: StdError,
+{
+    type Response = ServiceResponse;
+    type Error = Error;
+    type Transform = RateLimitMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        let limits = RateLimits {
+            routes: self
+                .settings
+                .routes
+                .iter()
+                .map(|pattern| ResourceDef::new(pattern.as_str()))
+                .collect(),
+            burst: self.settings.burst as f64,
+            refill_per_sec: self.settings.per_minute as f64 / 60.0,
+            buckets: self.buckets.clone(),
+        };
+        ok(RateLimitMiddleware {
+            service,
+            limits: Rc::new(limits),
+        })
+    }
+}
+
+pub struct RateLimitMiddleware<S> {
+    service: S,
+    limits: Rc<RateLimits>,
+}
+
+impl<S, B> Service<ServiceRequest> for RateLimitMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+    B::Error: StdError,
+{
+    type Response = ServiceResponse;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> { self.service.poll_ready(cx) }
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        // The peer address is used rather than the forwarded one, which can be
+        // forged by the client.
+        let acquired = match req.peer_addr() {
+            None => Ok(()),
+            Some(addr) => self.limits.acquire(addr.ip(), req.path()),
+        };
+
+        match acquired {
+            Ok(_) => {
+                let fut = self.service.call(req);
+                Box::pin(async move {
+                    let res = fut.await?;
+                    Ok(res.map_body(|_, body| AnyBody::from_message(body)))
+                })
+            },
+            Err(retry_after) => {
+                tracing::warn!("{} is rate limited for {:?}", req.path(), retry_after);
+                let error = ServerError::too_many_requests().context(retry_after);
+                let mut response = error.error_response();
+                let secs = retry_after.as_secs_f64().ceil().max(1.0) as u64;
+                response.headers_mut().insert(RETRY_AFTER, HeaderValue::from(secs));
+                Box::pin(async move { Ok(req.into_response(response)) })
+            },
+        }
+    }
+}
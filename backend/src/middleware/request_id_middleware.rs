@@ -0,0 +1,117 @@
+use crate::config::HEADER_REQUEST_ID;
+use actix_service::{Service, Transform};
+use actix_web::{
+    body::AnyBody,
+    dev::{MessageBody, Payload, ServiceRequest, ServiceResponse},
+    http::header::{HeaderName, HeaderValue},
+    Error,
+    FromRequest,
+    HttpMessage,
+    HttpRequest,
+};
+use backend_service::errors::ServerError;
+use futures::future::{ok, ready, LocalBoxFuture, Ready};
+use std::{
+    error::Error as StdError,
+    task::{Context, Poll},
+};
+use tracing_futures::Instrument;
+
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+// The id correlating the log lines of a request, read from the X-Request-Id
+// header or generated.
+#[derive(Clone, Debug)]
+pub struct RequestId(pub String);
+
+impl RequestId {
+    // The provided id is replaced if it's empty, too long or not printable, so
+    // it can't break the log lines.
+    fn from_header(value: Option<&HeaderValue>) -> Self {
+        let provided = value
+            .and_then(|value| value.to_str().ok())
+            .filter(|id| !id.is_empty() && id.len() <= MAX_REQUEST_ID_LEN)
+            .filter(|id| id.chars().all(|c| c.is_ascii_graphic()));
+        match provided {
+            Some(id) => RequestId(id.to_owned()),
+            None => RequestId(uuid::Uuid::new_v4().to_string()),
+        }
+    }
+}
+
+impl FromRequest for RequestId {
+    type Error = ServerError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(request: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        match request.extensions().get::<RequestId>() {
+            Some(request_id) => ready(Ok(request_id.clone())),
+            None => ready(Err(
+                ServerError::internal().context("The RequestIdService is not registered")
+            )),
+        }
+    }
+}
+
+// Stores the RequestId in the request extensions and echoes it in the response
+// header. The request is handled in a span with the request_id field, so the
+// field is attached to all the log lines of the request, including the ones
+// of the WebSocket handshake.
+pub struct RequestIdService;
+
+impl<S, B> Transform<S, ServiceRequest> for RequestIdService
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+    B::Error: StdError,
+{
+    type Response = ServiceResponse;
+    type Error = Error;
+    type Transform = RequestIdMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future { ok(RequestIdMiddleware { service }) }
+}
+
+pub struct RequestIdMiddleware<S> {
+    service: S,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestIdMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+    B::Error: StdError,
+{
+    type Response = ServiceResponse;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> { self.service.poll_ready(cx) }
+
+    fn call(&self, mut req: ServiceRequest) -> Self::Future {
+        let request_id = RequestId::from_header(req.headers().get(HEADER_REQUEST_ID));
+        // Always a valid header value as it's printable ASCII.
+        let header_value = HeaderValue::from_str(&request_id.0).unwrap();
+        // The generated id is also set in the request, so the Logger can log it
+        // with %{X-Request-Id}i.
+        req.headers_mut()
+            .insert(HeaderName::from_static(HEADER_REQUEST_ID), header_value.clone());
+        req.extensions_mut().insert(request_id.clone());
+
+        let span = tracing::info_span!("request", request_id = %request_id.0, path = %req.path());
+        let fut = span.in_scope(|| self.service.call(req));
+        Box::pin(
+            async move {
+                let mut res = fut.await?;
+                res.headers_mut()
+                    .insert(HeaderName::from_static(HEADER_REQUEST_ID), header_value);
+                Ok(res.map_body(|_, body| AnyBody::from_message(body)))
+            }
+            .instrument(span),
+        )
+    }
+}
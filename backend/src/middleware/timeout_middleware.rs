@@ -0,0 +1,118 @@
+use crate::config::TimeoutSettings;
+use actix_service::{Service, Transform};
+use actix_web::{
+    body::AnyBody,
+    dev::{MessageBody, ResourceDef, ServiceRequest, ServiceResponse},
+    Error,
+};
+use backend_service::errors::ServerError;
+use futures::future::{ok, LocalBoxFuture, Ready};
+use std::{
+    error::Error as StdError,
+    rc::Rc,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+// Responds with 504 to the request handled longer than its timeout. The
+// handler future is dropped then, which cancels its pending SQL queries, but
+// the work spawned as blocking tasks runs to the end.
+pub struct RequestTimeout {
+    timeouts: Rc<RequestTimeouts>,
+}
+
+impl RequestTimeout {
+    pub fn new(settings: &TimeoutSettings) -> Self {
+        let routes = settings
+            .route_timeouts
+            .iter()
+            .map(|route| {
+                (
+                    ResourceDef::new(route.pattern.as_str()),
+                    Duration::from_millis(route.timeout_ms),
+                )
+            })
+            .collect::<Vec<(ResourceDef, Duration)>>();
+        let timeouts = RequestTimeouts {
+            default: settings.request_timeout_ms.map(Duration::from_millis),
+            routes,
+        };
+        Self {
+            timeouts: Rc::new(timeouts),
+        }
+    }
+}
+
+struct RequestTimeouts {
+    default: Option<Duration>,
+    routes: Vec<(ResourceDef, Duration)>,
+}
+
+impl RequestTimeouts {
+    fn timeout_of(&self, path: &str) -> Option<Duration> {
+        match self.routes.iter().find(|(resource, _)| resource.is_match(path)) {
+            Some((_, timeout)) => Some(*timeout),
+            None => self.default,
+        }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RequestTimeout
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+    B::Error: StdError,
+{
+    type Response = ServiceResponse;
+    type Error = Error;
+    type Transform = RequestTimeoutMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(RequestTimeoutMiddleware {
+            service,
+            timeouts: self.timeouts.clone(),
+        })
+    }
+}
+
+pub struct RequestTimeoutMiddleware<S> {
+    service: S,
+    timeouts: Rc<RequestTimeouts>,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestTimeoutMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: MessageBody + 'static,
+    B::Error: StdError,
+{
+    type Response = ServiceResponse;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> { self.service.poll_ready(cx) }
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let timeout = self.timeouts.timeout_of(req.path());
+        let path = req.path().to_owned();
+        let fut = self.service.call(req);
+        Box::pin(async move {
+            let result = match timeout {
+                None => fut.await,
+                Some(timeout) => match tokio::time::timeout(timeout, fut).await {
+                    Ok(result) => result,
+                    Err(_) => {
+                        tracing::warn!("{} timed out after {:?}", path, timeout);
+                        return Err(ServerError::request_timeout().context(timeout).into());
+                    },
+                },
+            };
+            let res = result?;
+            Ok(res.map_body(|_, body| AnyBody::from_message(body)))
+        })
+    }
+}
@@ -0,0 +1,23 @@
+use crate::service::user::LoggedUser;
+use backend_service::errors::ServerError;
+use sqlx::{PgPool, Row};
+
+pub(crate) async fn check_admin(pool: &PgPool, logged_user: &LoggedUser) -> Result<(), ServerError> {
+    let user_id = logged_user.as_uuid()?;
+    let row = sqlx::query(r#"SELECT is_admin FROM user_table WHERE id = $1"#)
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|err| ServerError::internal().context(err))?;
+
+    let is_admin = match row {
+        None => false,
+        Some(row) => row.try_get::<bool, _>("is_admin").unwrap_or(false),
+    };
+
+    if is_admin {
+        Ok(())
+    } else {
+        Err(ServerError::permission_denied().context(format!("{} is not an admin", logged_user.user_id)))
+    }
+}
@@ -0,0 +1,5 @@
+#![allow(clippy::module_inception)]
+mod admin;
+pub mod router;
+
+pub(crate) use admin::*;
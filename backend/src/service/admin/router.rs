@@ -0,0 +1,97 @@
+use crate::service::{
+    admin::check_admin,
+    doc::doc::DocBiz,
+    search::reindex_workspace,
+    user::LoggedUser,
+    workspace::sql_builder::check_workspace_id,
+    ws::{entities::TerminateSession, WsServer},
+};
+use actix::Addr;
+use actix_web::{
+    web::{Data, Path},
+    HttpResponse,
+};
+use anyhow::Context;
+use backend_service::{
+    errors::{internal_error, ServerError},
+    response::FlowyResponse,
+};
+use flowy_document_infra::protobuf::RepeatedDocSession;
+use flowy_workspace_infra::protobuf::SearchReindexResult;
+use sqlx::PgPool;
+use std::sync::Arc;
+
+#[tracing::instrument(skip(pool, doc_biz, logged_user), err)]
+pub async fn read_doc_sessions_handler(
+    doc_id: Path<String>,
+    pool: Data<PgPool>,
+    doc_biz: Data<Arc<DocBiz>>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let _ = check_admin(pool.get_ref(), &logged_user).await?;
+    let sessions = match doc_biz.manager.get_opened(&doc_id) {
+        None => vec![],
+        Some(handle) => handle.sessions().await?,
+    };
+
+    let mut repeated_session = RepeatedDocSession::default();
+    repeated_session.set_items(sessions.into());
+    let response = FlowyResponse::success().pb(repeated_session)?;
+    Ok(response.into())
+}
+
+#[tracing::instrument(skip(pool, doc_biz, server, logged_user), err)]
+pub async fn terminate_doc_session_handler(
+    path: Path<(String, String)>,
+    pool: Data<PgPool>,
+    doc_biz: Data<Arc<DocBiz>>,
+    server: Data<Addr<WsServer>>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let _ = check_admin(pool.get_ref(), &logged_user).await?;
+    let (doc_id, session_id) = path.into_inner();
+    let is_removed = match doc_biz.manager.get_opened(&doc_id) {
+        None => false,
+        Some(handle) => handle.remove_user(&session_id).await?,
+    };
+
+    if !is_removed {
+        return Err(
+            ServerError::record_not_found().context(format!("Session {} of doc {} not found", session_id, doc_id))
+        );
+    }
+
+    let _ = server
+        .send(TerminateSession { sid: session_id.into() })
+        .await
+        .map_err(internal_error)??;
+
+    Ok(FlowyResponse::success().into())
+}
+
+// Rebuilds the search index of the workspace, e.g. once the index drifts from
+// the docs or the indexing changes.
+#[tracing::instrument(skip(pool, logged_user), err)]
+pub async fn reindex_workspace_handler(
+    workspace_id: Path<String>,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let _ = check_admin(pool.get_ref(), &logged_user).await?;
+    let workspace_id = check_workspace_id(workspace_id.into_inner())?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to reindex workspace")?;
+
+    let indexed_count = reindex_workspace(&mut transaction, &workspace_id.to_string()).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to reindex workspace.")?;
+
+    let mut result = SearchReindexResult::new();
+    result.set_indexed_count(indexed_count);
+    Ok(FlowyResponse::success().pb(result)?.into())
+}
@@ -1,6 +1,11 @@
 use crate::{
-    entities::workspace::{AppTable, APP_TABLE},
-    service::{app::sql_builder::*, user::LoggedUser, view::read_view_belong_to_id},
+    entities::workspace::{AppTable, APP_TABLE, TRASH_TABLE, VIEW_TABLE},
+    service::{
+        app::sql_builder::*,
+        user::LoggedUser,
+        view::{delete_view, read_view_belong_to_id},
+        workspace::{check_workspace_role, sql_builder::check_workspace_id},
+    },
     sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
 };
 
@@ -12,9 +17,10 @@ use flowy_workspace_infra::{
         app::{AppDesc, AppName},
         workspace::WorkspaceId,
     },
-    protobuf::{App, CreateAppParams, RepeatedView},
+    protobuf::{App, CreateAppParams, RepeatedApp, RepeatedView, WorkspaceRole},
 };
-use sqlx::{postgres::PgArguments, Postgres};
+use sqlx::{postgres::PgArguments, Postgres, Row};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 pub(crate) async fn create_app(
@@ -26,12 +32,29 @@ pub(crate) async fn create_app(
     let workspace_id = WorkspaceId::parse(params.take_workspace_id()).map_err(invalid_params)?;
     let user_id = logged_user.as_uuid()?.to_string();
     let desc = AppDesc::parse(params.take_desc()).map_err(invalid_params)?;
+    // The workspace must exist, and the Viewers of it can't create apps
+    let _ = check_workspace_role(
+        transaction,
+        check_workspace_id(workspace_id.0.clone())?,
+        &logged_user,
+        WorkspaceRole::Editor,
+    )
+    .await?;
 
-    let (sql, args, app) = NewAppSqlBuilder::new(&user_id, workspace_id.as_ref())
+    let mut builder = NewAppSqlBuilder::new(&user_id, workspace_id.as_ref())
         .name(name.as_ref())
         .desc(desc.as_ref())
-        .color_style(params.take_color_style())
-        .build()?;
+        .color_style(params.take_color_style());
+
+    if params.has_parent_id() {
+        let parent = read_app_table(check_app_id(params.take_parent_id())?, transaction).await?;
+        if parent.workspace_id != workspace_id.as_ref() {
+            return Err(ServerError::params_invalid().context("The parent app belongs to another workspace"));
+        }
+        builder = builder.parent_id(&parent.id.to_string());
+    }
+
+    let (sql, args, app) = builder.build()?;
 
     let _ = sqlx::query_with(&sql, args)
         .execute(transaction)
@@ -46,24 +69,103 @@ pub(crate) async fn read_app(
     user: &LoggedUser,
 ) -> Result<App, ServerError> {
     let table = read_app_table(app_id, transaction).await?;
-
-    let read_trash_ids = read_trash_ids(user, transaction).await?;
-    if read_trash_ids.contains(&table.id.to_string()) {
+    let _ = check_workspace_role(
+        transaction,
+        Uuid::parse_str(&table.workspace_id)?,
+        user,
+        WorkspaceRole::Viewer,
+    )
+    .await?;
+    if !read_trash_ids(transaction, vec![table.id]).await?.is_empty() {
         return Err(ServerError::record_not_found());
     }
 
+    let app_id = table.id.to_string();
     let mut views = RepeatedView::default();
     views.set_items(
-        read_view_belong_to_id(&table.id.to_string(), user, transaction as &mut DBTransaction<'_>)
+        read_view_belong_to_id(&app_id, transaction as &mut DBTransaction<'_>)
+            .await?
+            .into(),
+    );
+
+    let mut apps = RepeatedApp::default();
+    apps.set_items(
+        read_app_tree(transaction, &table.workspace_id, Some(&app_id))
             .await?
             .into(),
     );
 
     let mut app: App = table.into();
     app.set_belongings(views);
+    app.set_apps(apps);
     Ok(app)
 }
 
+// Returns the apps nested under the parent_id, or the top level apps of the
+// workspace if it's None. Each app carries its nested apps in the apps and its
+// views in the belongings.
+pub(crate) async fn read_app_tree(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: &str,
+    parent_id: Option<&str>,
+) -> Result<Vec<App>, ServerError> {
+    let (sql, args) = SqlBuilder::select(APP_TABLE)
+        .add_field("*")
+        .and_where_eq("workspace_id", workspace_id)
+        .build()?;
+
+    let mut tables = sqlx::query_as_with::<Postgres, AppTable, PgArguments>(&sql, args)
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let ids = tables.iter().map(|table| table.id).collect::<Vec<Uuid>>();
+    let trash_ids = read_trash_ids(transaction, ids).await?;
+    tables.retain(|table| !trash_ids.contains(&table.id));
+    // The apps of the tree keep the order they were created in.
+    tables.sort_by_key(|table| table.create_time);
+
+    // Only the views of the apps in the tree are read.
+    let mut tree_ids = vec![];
+    collect_app_tree_ids(parent_id, &tables, &mut tree_ids);
+    let mut views = HashMap::new();
+    for app_id in tree_ids {
+        let mut repeated_view = RepeatedView::default();
+        repeated_view.set_items(
+            read_view_belong_to_id(&app_id, transaction as &mut DBTransaction<'_>)
+                .await?
+                .into(),
+        );
+        views.insert(app_id, repeated_view);
+    }
+
+    Ok(make_app_tree(parent_id, &tables, &mut views))
+}
+
+fn collect_app_tree_ids(parent_id: Option<&str>, tables: &[AppTable], ids: &mut Vec<String>) {
+    for table in tables.iter().filter(|table| table.parent_id.as_deref() == parent_id) {
+        let app_id = table.id.to_string();
+        collect_app_tree_ids(Some(&app_id), tables, ids);
+        ids.push(app_id);
+    }
+}
+
+fn make_app_tree(parent_id: Option<&str>, tables: &[AppTable], views: &mut HashMap<String, RepeatedView>) -> Vec<App> {
+    tables
+        .iter()
+        .filter(|table| table.parent_id.as_deref() == parent_id)
+        .map(|table| {
+            let app_id = table.id.to_string();
+            let mut apps = RepeatedApp::default();
+            apps.set_items(make_app_tree(Some(&app_id), tables, views).into());
+            let mut app: App = table.clone().into();
+            app.set_belongings(views.remove(&app_id).unwrap_or_default());
+            app.set_apps(apps);
+            app
+        })
+        .collect::<Vec<App>>()
+}
+
 pub(crate) async fn read_app_table(app_id: Uuid, transaction: &mut DBTransaction<'_>) -> Result<AppTable, ServerError> {
     let (sql, args) = SqlBuilder::select(APP_TABLE)
         .add_field("*")
@@ -101,13 +203,106 @@ pub(crate) async fn update_app(
     Ok(())
 }
 
+// Moves the app under the parent_id. The app is moved to the top of its
+// workspace if the parent_id is None.
 #[tracing::instrument(skip(transaction), err)]
-pub(crate) async fn delete_app(transaction: &mut DBTransaction<'_>, app_id: Uuid) -> Result<(), ServerError> {
-    let (sql, args) = SqlBuilder::delete(APP_TABLE).and_where_eq("id", app_id).build()?;
-    let _ = sqlx::query_with(&sql, args)
+pub(crate) async fn move_app(
+    transaction: &mut DBTransaction<'_>,
+    app_id: Uuid,
+    parent_id: Option<Uuid>,
+) -> Result<(), ServerError> {
+    let app = read_app_table(app_id, transaction).await?;
+    if let Some(parent_id) = parent_id {
+        let parent = read_app_table(parent_id, transaction).await?;
+        if parent.workspace_id != app.workspace_id {
+            return Err(ServerError::params_invalid().context("The parent app belongs to another workspace"));
+        }
+
+        // The app can't be moved under itself or one of its descendants
+        let mut ancestor = Some(parent);
+        while let Some(table) = ancestor {
+            if table.id == app_id {
+                return Err(ServerError::params_invalid().context("Moving the app under itself causes a cycle"));
+            }
+            ancestor = match table.parent_id {
+                None => None,
+                Some(id) => Some(read_app_table(check_app_id(id)?, transaction).await?),
+            };
+        }
+    }
+
+    let (sql, args) = SqlBuilder::update(APP_TABLE)
+        .add_arg("parent_id", parent_id.map(|id| id.to_string()))
+        .add_arg("modified_time", Utc::now())
+        .and_where_eq("id", app_id)
+        .build()?;
+
+    sqlx::query_with(&sql, args)
         .execute(transaction)
         .await
         .map_err(map_sqlx_error)?;
 
     Ok(())
 }
+
+// Reads the ids of all the apps nested under the app, recursively.
+pub(crate) async fn read_descendant_app_ids(
+    transaction: &mut DBTransaction<'_>,
+    app_id: Uuid,
+) -> Result<Vec<Uuid>, ServerError> {
+    let sql = format!(
+        r#"
+        WITH RECURSIVE descendants AS (
+            SELECT id FROM {app_table} WHERE parent_id = $1
+            UNION
+            SELECT app.id FROM {app_table} app INNER JOIN descendants ON app.parent_id = descendants.id::text
+        )
+        SELECT id FROM descendants
+        "#,
+        app_table = APP_TABLE,
+    );
+
+    let ids = sqlx::query(&sql)
+        .bind(app_id.to_string())
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?
+        .into_iter()
+        .map(|row| row.get::<Uuid, _>("id"))
+        .collect::<Vec<Uuid>>();
+
+    Ok(ids)
+}
+
+// Deletes the app along with its nested apps, which would be left without a
+// parent otherwise, and the views of them all. The nested apps are moved to the
+// trash with their parent, so their trash rows are deleted too.
+#[tracing::instrument(skip(transaction), err)]
+pub(crate) async fn delete_app(transaction: &mut DBTransaction<'_>, app_id: Uuid) -> Result<(), ServerError> {
+    let mut app_ids = read_descendant_app_ids(transaction, app_id).await?;
+    app_ids.push(app_id);
+
+    let sql = format!("SELECT id FROM {} WHERE belong_to_id = ANY($1)", VIEW_TABLE);
+    let view_ids = sqlx::query_scalar::<_, Uuid>(&sql)
+        .bind(app_ids.iter().map(|id| id.to_string()).collect::<Vec<String>>())
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+    let _ = delete_view(transaction, view_ids).await?;
+
+    let sql = format!("DELETE FROM {} WHERE id = ANY($1)", TRASH_TABLE);
+    let _ = sqlx::query(&sql)
+        .bind(&app_ids)
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let sql = format!("DELETE FROM {} WHERE id = ANY($1)", APP_TABLE);
+    let _ = sqlx::query(&sql)
+        .bind(app_ids)
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(())
+}
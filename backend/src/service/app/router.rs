@@ -3,17 +3,18 @@ use actix_web::{
     HttpResponse,
 };
 use backend_service::errors::{invalid_params, ServerError};
-use flowy_workspace_infra::protobuf::{AppIdentifier, CreateAppParams, UpdateAppParams};
+use flowy_workspace_infra::protobuf::{AppIdentifier, CreateAppParams, UpdateAppParams, WorkspaceRole};
 use protobuf::Message;
 use sqlx::PgPool;
 
 use crate::service::{
     app::{
-        app::{create_app, delete_app, read_app, update_app},
+        app::{create_app, delete_app, move_app, read_app, update_app},
         sql_builder::check_app_id,
     },
     user::LoggedUser,
     util::parse_from_payload,
+    workspace::check_app_role,
 };
 use anyhow::Context;
 use backend_service::response::FlowyResponse;
@@ -57,7 +58,11 @@ pub async fn read_handler(payload: Payload, pool: Data<PgPool>, user: LoggedUser
     Ok(FlowyResponse::success().pb(app)?.into())
 }
 
-pub async fn update_handler(payload: Payload, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
+pub async fn update_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
     let params: UpdateAppParams = parse_from_payload(payload).await?;
     let app_id = check_app_id(params.get_app_id().to_string())?;
     let name = match params.has_name() {
@@ -78,12 +83,28 @@ pub async fn update_handler(payload: Payload, pool: Data<PgPool>) -> Result<Http
         true => Some(AppDesc::parse(params.get_desc().to_owned()).map_err(invalid_params)?.0),
     };
 
+    // An empty parent_id moves the app to the top of its workspace
+    let parent_id = match params.has_parent_id() {
+        false => None,
+        true => match params.get_parent_id() {
+            "" => Some(None),
+            parent_id => Some(Some(check_app_id(parent_id.to_owned())?)),
+        },
+    };
+
     let mut transaction = pool
         .begin()
         .await
         .context("Failed to acquire a Postgres connection to update app")?;
 
+    let _ = check_app_role(&mut transaction, &app_id.to_string(), &user, WorkspaceRole::Editor).await?;
+    if let Some(Some(parent_id)) = parent_id {
+        let _ = check_app_role(&mut transaction, &parent_id.to_string(), &user, WorkspaceRole::Editor).await?;
+    }
     let _ = update_app(&mut transaction, app_id, name, desc, color_style).await?;
+    if let Some(parent_id) = parent_id {
+        let _ = move_app(&mut transaction, app_id, parent_id).await?;
+    }
 
     transaction
         .commit()
@@ -92,7 +113,11 @@ pub async fn update_handler(payload: Payload, pool: Data<PgPool>) -> Result<Http
     Ok(FlowyResponse::success().into())
 }
 
-pub async fn delete_handler(payload: Payload, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
+pub async fn delete_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
     let params: AppIdentifier = parse_from_payload(payload).await?;
     let app_id = check_app_id(params.app_id.to_owned())?;
     let mut transaction = pool
@@ -100,6 +125,7 @@ pub async fn delete_handler(payload: Payload, pool: Data<PgPool>) -> Result<Http
         .await
         .context("Failed to acquire a Postgres connection to delete app")?;
 
+    let _ = check_app_role(&mut transaction, &app_id.to_string(), &user, WorkspaceRole::Editor).await?;
     let _ = delete_app(&mut transaction, app_id).await?;
 
     transaction
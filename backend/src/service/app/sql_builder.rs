@@ -31,12 +31,17 @@ impl NewAppSqlBuilder {
             modified_time: time,
             create_time: time,
             user_id: user_id.to_string(),
+            parent_id: None,
         };
 
         Self { table }
     }
 
-    pub fn from_app(user_id: &str, app: App) -> Result<Self, ServerError> {
+    pub fn from_app(user_id: &str, mut app: App) -> Result<Self, ServerError> {
+        let parent_id = match app.has_parent_id() {
+            false => None,
+            true => Some(app.take_parent_id()),
+        };
         let app_id = check_app_id(app.id)?;
         let create_time = DateTime::<Utc>::from_utc(NaiveDateTime::from_timestamp(app.create_time, 0), Utc);
         let modified_time = DateTime::<Utc>::from_utc(NaiveDateTime::from_timestamp(app.modified_time, 0), Utc);
@@ -51,6 +56,7 @@ impl NewAppSqlBuilder {
             modified_time,
             create_time,
             user_id: user_id.to_string(),
+            parent_id,
         };
 
         Ok(Self { table })
@@ -72,6 +78,11 @@ impl NewAppSqlBuilder {
         self
     }
 
+    pub fn parent_id(mut self, parent_id: &str) -> Self {
+        self.table.parent_id = Some(parent_id.to_owned());
+        self
+    }
+
     pub fn color_style(mut self, color_style: ColorStyle) -> Self {
         self.table.color_style = color_style.write_to_bytes().unwrap_or_else(|_| default_color_style());
         self
@@ -89,6 +100,7 @@ impl NewAppSqlBuilder {
             .add_arg("modified_time", self.table.modified_time)
             .add_arg("create_time", self.table.create_time)
             .add_arg("user_id", self.table.user_id)
+            .add_arg("parent_id", self.table.parent_id)
             .build()?;
 
         Ok((sql, args, app))
@@ -0,0 +1,99 @@
+use crate::service::{
+    doc::edit::mk_push_message,
+    util::md5,
+    ws::{
+        entities::{Broadcast, SessionId},
+        WsServer,
+    },
+};
+use actix::Addr;
+use backend_service::errors::{internal_error, ServerError};
+use dashmap::DashMap;
+use flowy_document_infra::protobuf::Revision;
+use lib_ot::core::{Delta, OperationTransformable};
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering::SeqCst},
+        Arc,
+    },
+    time::Duration,
+};
+
+// Composes the revisions of a document applied within the window of the first
+// one into a single broadcast, so the other sessions aren't flooded by rapid
+// typing. The revisions are still saved one by one once applied. Only the
+// revisions of the same session are composed, as the broadcast excludes its
+// sender: a revision of another session sends the pending broadcast first.
+pub struct BroadcastCoalescer {
+    window: Duration,
+    ws_server: Addr<WsServer>,
+    pending: Arc<DashMap<String, PendingBroadcast>>,
+    next_id: AtomicU64,
+}
+
+struct PendingBroadcast {
+    // Tells the pending broadcast of the timer from the ones replacing it.
+    id: u64,
+    sid: SessionId,
+    revision: Revision,
+}
+
+impl BroadcastCoalescer {
+    pub fn new(window: Duration, ws_server: Addr<WsServer>) -> Self {
+        Self {
+            window,
+            ws_server,
+            pending: Arc::new(DashMap::new()),
+            next_id: AtomicU64::new(0),
+        }
+    }
+
+    pub fn push(&self, sid: SessionId, revision: Revision) -> Result<(), ServerError> {
+        let doc_id = revision.doc_id.clone();
+        if let Some(mut pending) = self.pending.get_mut(&doc_id) {
+            if pending.sid == sid {
+                return compose_revision(&mut pending.revision, &revision);
+            }
+        }
+
+        let id = self.next_id.fetch_add(1, SeqCst);
+        if let Some(pending) = self
+            .pending
+            .insert(doc_id.clone(), PendingBroadcast { id, sid, revision })
+        {
+            send_broadcast(&self.ws_server, pending);
+        }
+
+        let pending = self.pending.clone();
+        let ws_server = self.ws_server.clone();
+        let window = self.window;
+        tokio::spawn(async move {
+            tokio::time::sleep(window).await;
+            if let Some((_, pending)) = pending.remove_if(&doc_id, |_, pending| pending.id == id) {
+                send_broadcast(&ws_server, pending);
+            }
+        });
+        Ok(())
+    }
+}
+
+// The composed revision goes from the base of the pending one to the rev_id of
+// the new one.
+fn compose_revision(pending: &mut Revision, revision: &Revision) -> Result<(), ServerError> {
+    let delta = Delta::from_bytes(&pending.delta_data).map_err(internal_error)?;
+    let other = Delta::from_bytes(&revision.delta_data).map_err(internal_error)?;
+    let composed = delta.compose(&other).map_err(internal_error)?;
+    pending.delta_data = composed.to_bytes().to_vec();
+    pending.md5 = md5(&pending.delta_data);
+    pending.rev_id = revision.rev_id;
+    Ok(())
+}
+
+fn send_broadcast(ws_server: &Addr<WsServer>, pending: PendingBroadcast) {
+    let doc_id = pending.revision.doc_id.clone();
+    ws_server.do_send(Broadcast {
+        message: mk_push_message(&doc_id, pending.revision),
+        doc_id,
+        exclude: Some(pending.sid),
+    });
+}
@@ -1,10 +1,12 @@
 use crate::{
-    entities::doc::{DocTable, DOC_TABLE},
+    entities::doc::{DocTable, NamedVersionTable, DOC_TABLE, NAMED_VERSION_TABLE},
     sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
 };
 use anyhow::Context;
 use backend_service::errors::ServerError;
+use chrono::Utc;
 use flowy_document_infra::protobuf::{CreateDocParams, Doc, DocIdentifier, UpdateDocParams};
+use lib_ot::core::{Attributes, Delta, NEW_LINE};
 use sqlx::{postgres::PgArguments, PgPool, Postgres};
 use uuid::Uuid;
 
@@ -26,6 +28,12 @@ pub(crate) async fn create_doc(
 #[tracing::instrument(level = "debug", skip(pool), err)]
 pub(crate) async fn read_doc(pool: &PgPool, params: DocIdentifier) -> Result<Doc, ServerError> {
     let doc_id = Uuid::parse_str(&params.doc_id)?;
+    let doc: Doc = read_doc_table(pool, doc_id).await?.into();
+    Ok(doc)
+}
+
+#[tracing::instrument(level = "debug", skip(pool), err)]
+pub(crate) async fn read_doc_table(pool: &PgPool, doc_id: Uuid) -> Result<DocTable, ServerError> {
     let mut transaction = pool
         .begin()
         .await
@@ -35,18 +43,17 @@ pub(crate) async fn read_doc(pool: &PgPool, params: DocIdentifier) -> Result<Doc
 
     let (sql, args) = builder.build()?;
     // TODO: benchmark the speed of different documents with different size
-    let doc: Doc = sqlx::query_as_with::<Postgres, DocTable, PgArguments>(&sql, args)
+    let table = sqlx::query_as_with::<Postgres, DocTable, PgArguments>(&sql, args)
         .fetch_one(&mut transaction)
         .await
-        .map_err(map_sqlx_error)?
-        .into();
+        .map_err(map_sqlx_error)?;
 
     transaction
         .commit()
         .await
         .context("Failed to commit SQL transaction to read doc.")?;
 
-    Ok(doc)
+    Ok(table)
 }
 
 #[tracing::instrument(level = "debug", skip(pool, params), fields(delta), err)]
@@ -61,9 +68,12 @@ pub async fn update_doc(pool: &PgPool, mut params: UpdateDocParams) -> Result<()
 
     tracing::Span::current().record("result", &data.as_ref().unwrap_or(&"".to_owned()).as_str());
 
+    let plaintext = data.as_deref().map(doc_plaintext);
     let (sql, args) = SqlBuilder::update(DOC_TABLE)
         .add_some_arg("data", data)
+        .add_some_arg("plaintext", plaintext)
         .add_arg("rev_id", params.rev_id)
+        .add_arg("modified_time", Utc::now())
         .and_where_eq("id", doc_id)
         .build()?;
 
@@ -80,6 +90,68 @@ pub async fn update_doc(pool: &PgPool, mut params: UpdateDocParams) -> Result<()
     Ok(())
 }
 
+// Documents saved by older versions may miss the trailing newline, which makes
+// the last block lose its attributes. Appends the newline to each of them as a
+// new revision and returns the ids of the repaired documents. Running it again
+// has no effect. It bypasses the opened documents of DocManager, so run it
+// before the server starts serving documents.
+#[tracing::instrument(level = "debug", skip(pool), err)]
+pub async fn repair_docs_trailing_newline(pool: &PgPool) -> Result<Vec<String>, ServerError> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to repair docs")?;
+
+    let (sql, args) = SqlBuilder::select(DOC_TABLE).add_field("*").build()?;
+    let tables = sqlx::query_as_with::<Postgres, DocTable, PgArguments>(&sql, args)
+        .fetch_all(&mut transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let mut repaired_doc_ids = vec![];
+    for table in tables {
+        let mut delta = match Delta::from_json(&table.data) {
+            Ok(delta) => delta,
+            Err(e) => {
+                log::error!("Skip repairing doc {}, deserialize failed: {:?}", table.id, e);
+                continue;
+            },
+        };
+        if delta.has_trailing_newline() {
+            continue;
+        }
+
+        delta.insert(NEW_LINE, Attributes::default());
+        let rev_id = table.rev_id + 1;
+        let (sql, args) = SqlBuilder::update(DOC_TABLE)
+            .add_arg("data", delta.to_json())
+            .add_arg("plaintext", delta.to_plain_text())
+            .add_arg("rev_id", rev_id)
+            .add_arg("modified_time", Utc::now())
+            .and_where_eq("id", table.id)
+            .build()?;
+
+        sqlx::query_with(&sql, args)
+            .execute(&mut transaction)
+            .await
+            .map_err(map_sqlx_error)?;
+
+        tracing::info!(
+            "Repair the trailing newline of doc {} with revision {}",
+            table.id,
+            rev_id
+        );
+        repaired_doc_ids.push(table.id.to_string());
+    }
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to repair docs.")?;
+
+    Ok(repaired_doc_ids)
+}
+
 #[tracing::instrument(level = "debug", skip(transaction), err)]
 pub(crate) async fn delete_doc(transaction: &mut DBTransaction<'_>, doc_id: Uuid) -> Result<(), ServerError> {
     let (sql, args) = SqlBuilder::delete(DOC_TABLE).and_where_eq("id", doc_id).build()?;
@@ -92,6 +164,110 @@ pub(crate) async fn delete_doc(transaction: &mut DBTransaction<'_>, doc_id: Uuid
     Ok(())
 }
 
+#[tracing::instrument(level = "debug", skip(transaction, data), err)]
+pub(crate) async fn create_named_version(
+    transaction: &mut DBTransaction<'_>,
+    doc_id: Uuid,
+    rev_id: i64,
+    name: &str,
+    data: String,
+    created_by: &str,
+) -> Result<NamedVersionTable, ServerError> {
+    let table = NamedVersionTable {
+        id: Uuid::new_v4(),
+        doc_id,
+        rev_id,
+        name: name.to_owned(),
+        data,
+        created_by: created_by.to_owned(),
+        created_at: Utc::now(),
+    };
+    let (sql, args) = SqlBuilder::create(NAMED_VERSION_TABLE)
+        .add_arg("id", table.id)
+        .add_arg("doc_id", table.doc_id)
+        .add_arg("rev_id", table.rev_id)
+        .add_arg("name", table.name.clone())
+        .add_arg("data", table.data.clone())
+        .add_arg("created_by", table.created_by.clone())
+        .add_arg("created_at", table.created_at)
+        .build()?;
+
+    let _ = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(table)
+}
+
+// The named versions of the doc, in the order of creation.
+#[tracing::instrument(level = "debug", skip(transaction), err)]
+pub(crate) async fn read_named_versions(
+    transaction: &mut DBTransaction<'_>,
+    doc_id: Uuid,
+) -> Result<Vec<NamedVersionTable>, ServerError> {
+    let (sql, args) = SqlBuilder::select(NAMED_VERSION_TABLE)
+        .add_field("*")
+        .and_where_eq("doc_id", doc_id)
+        .build()?;
+
+    let mut tables = sqlx::query_as_with::<Postgres, NamedVersionTable, PgArguments>(&sql, args)
+        .fetch_all(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    tables.sort_by_key(|table| table.created_at);
+    Ok(tables)
+}
+
+#[tracing::instrument(level = "debug", skip(transaction), err)]
+pub(crate) async fn read_named_version(
+    transaction: &mut DBTransaction<'_>,
+    doc_id: Uuid,
+    version_id: Uuid,
+) -> Result<NamedVersionTable, ServerError> {
+    let (sql, args) = SqlBuilder::select(NAMED_VERSION_TABLE)
+        .add_field("*")
+        .and_where_eq("id", version_id)
+        .and_where_eq("doc_id", doc_id)
+        .build()?;
+
+    let table = sqlx::query_as_with::<Postgres, NamedVersionTable, PgArguments>(&sql, args)
+        .fetch_one(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(table)
+}
+
+#[tracing::instrument(level = "debug", skip(transaction), err)]
+pub(crate) async fn delete_named_version(
+    transaction: &mut DBTransaction<'_>,
+    doc_id: Uuid,
+    version_id: Uuid,
+) -> Result<(), ServerError> {
+    let (sql, args) = SqlBuilder::delete(NAMED_VERSION_TABLE)
+        .and_where_eq("id", version_id)
+        .and_where_eq("doc_id", doc_id)
+        .build()?;
+
+    let result = sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    if result.rows_affected() == 0 {
+        return Err(ServerError::record_not_found().context(format!("Named version {} not found", version_id)));
+    }
+    Ok(())
+}
+
+// The plain text of the document, kept next to its data for searching. A doc
+// that can't be deserialized has no text.
+pub(crate) fn doc_plaintext(data: &str) -> String {
+    Delta::from_json(data)
+        .map(|delta| delta.to_plain_text())
+        .unwrap_or_default()
+}
+
 pub struct NewDocSqlBuilder {
     table: DocTable,
 }
@@ -102,6 +278,7 @@ impl NewDocSqlBuilder {
             id,
             data: "".to_owned(),
             rev_id: 0,
+            modified_time: Utc::now(),
         };
         Self { table }
     }
@@ -114,8 +291,10 @@ impl NewDocSqlBuilder {
     pub fn build(self) -> Result<(String, PgArguments), ServerError> {
         let (sql, args) = SqlBuilder::create(DOC_TABLE)
             .add_arg("id", self.table.id)
+            .add_arg("plaintext", doc_plaintext(&self.table.data))
             .add_arg("data", self.table.data)
             .add_arg("rev_id", self.table.rev_id)
+            .add_arg("modified_time", self.table.modified_time)
             .build()?;
 
         Ok((sql, args))
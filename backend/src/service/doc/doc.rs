@@ -1,17 +1,28 @@
-use crate::service::{
-    doc::{
-        edit::DocHandle,
-        read_doc,
-        ws_actor::{DocWsActor, DocWsMsg},
+use crate::{
+    config::SyncSettings,
+    service::{
+        doc::{
+            broadcast::BroadcastCoalescer,
+            edit::DocHandle,
+            read_doc,
+            ws_actor::{DocWsActor, DocWsMsg},
+        },
+        ws::{WsBizHandler, WsClientData, WsServer},
     },
-    ws::{WsBizHandler, WsClientData},
 };
+use actix::Addr;
 use actix_web::web::Data;
 use backend_service::errors::{internal_error, ServerError};
 use dashmap::DashMap;
 use flowy_document_infra::protobuf::DocIdentifier;
 use sqlx::PgPool;
-use std::sync::Arc;
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering::SeqCst},
+        Arc,
+    },
+    time::Duration,
+};
 use tokio::{
     sync::{mpsc, oneshot},
     task::spawn_blocking,
@@ -24,10 +35,13 @@ pub struct DocBiz {
 }
 
 impl DocBiz {
-    pub fn new(pg_pool: Data<PgPool>) -> Self {
-        let manager = Arc::new(DocManager::new());
+    pub fn new(pg_pool: Data<PgPool>, ws_server: Addr<WsServer>, sync: SyncSettings) -> Self {
+        let coalescer = sync
+            .broadcast_coalesce_ms
+            .map(|ms| BroadcastCoalescer::new(Duration::from_millis(ms), ws_server.clone()));
+        let manager = Arc::new(DocManager::new(sync));
         let (tx, rx) = mpsc::channel(100);
-        let actor = DocWsActor::new(rx, manager.clone());
+        let actor = DocWsActor::new(rx, manager.clone(), ws_server, coalescer);
         tokio::task::spawn(actor.run());
         Self {
             manager,
@@ -57,37 +71,89 @@ impl WsBizHandler for DocBiz {
     }
 }
 
+struct OpenedDoc {
+    handle: Arc<DocHandle>,
+    last_access: AtomicU64,
+}
+
 pub struct DocManager {
-    docs_map: DashMap<String, Arc<DocHandle>>,
+    docs_map: DashMap<String, OpenedDoc>,
+    access_counter: AtomicU64,
+    sync: SyncSettings,
 }
 
-impl std::default::Default for DocManager {
-    fn default() -> Self {
+impl DocManager {
+    pub fn new(sync: SyncSettings) -> Self {
         Self {
             docs_map: DashMap::new(),
+            access_counter: AtomicU64::new(0),
+            sync,
         }
     }
-}
-
-impl DocManager {
-    pub fn new() -> Self { DocManager::default() }
 
     pub async fn get(&self, doc_id: &str, pg_pool: Data<PgPool>) -> Result<Option<Arc<DocHandle>>, ServerError> {
-        match self.docs_map.get(doc_id) {
+        let opened = self.docs_map.get(doc_id).map(|doc| {
+            doc.last_access.store(self.access_counter.fetch_add(1, SeqCst), SeqCst);
+            doc.handle.clone()
+        });
+        match opened {
             None => {
                 let params = DocIdentifier {
                     doc_id: doc_id.to_string(),
                     ..Default::default()
                 };
                 let doc = read_doc(pg_pool.get_ref(), params).await?;
-                let handle = spawn_blocking(|| DocHandle::new(doc, pg_pool))
+                let sync = self.sync.clone();
+                let handle = spawn_blocking(|| DocHandle::new(doc, pg_pool, sync))
                     .await
                     .map_err(internal_error)?;
                 let handle = Arc::new(handle?);
-                self.docs_map.insert(doc_id.to_string(), handle.clone());
+                let opened_doc = OpenedDoc {
+                    handle: handle.clone(),
+                    last_access: AtomicU64::new(self.access_counter.fetch_add(1, SeqCst)),
+                };
+                self.docs_map.insert(doc_id.to_string(), opened_doc);
+                self.evict_cold_docs();
                 Ok(Some(handle))
             },
-            Some(ctx) => Ok(Some(ctx.clone())),
+            Some(handle) => Ok(Some(handle)),
+        }
+    }
+
+    // Returns the handle of the doc only if it's opened by some users.
+    pub fn get_opened(&self, doc_id: &str) -> Option<Arc<DocHandle>> {
+        self.docs_map.get(doc_id).map(|doc| doc.handle.clone())
+    }
+
+    pub fn opened_doc_count(&self) -> usize { self.docs_map.len() }
+
+    // Drops the least recently accessed docs beyond the max_opened_docs. The
+    // documents are saved once changed, so an evicted doc reloads from the
+    // database. The docs whose handles are still held elsewhere, e.g. applying a
+    // revision, are kept, or two actors would edit the same doc. So are the docs
+    // the users are still connected to, which would lose their users and
+    // awareness.
+    fn evict_cold_docs(&self) {
+        let max_opened_docs = match self.sync.max_opened_docs {
+            None => return,
+            Some(max_opened_docs) => max_opened_docs,
+        };
+
+        while self.docs_map.len() > max_opened_docs {
+            let cold_doc_id = self
+                .docs_map
+                .iter()
+                .filter(|doc| Arc::strong_count(&doc.handle) == 1 && !doc.handle.has_active_users())
+                .min_by_key(|doc| doc.last_access.load(SeqCst))
+                .map(|doc| doc.key().clone());
+            match cold_doc_id {
+                None => break,
+                Some(doc_id) => {
+                    tracing::debug!("Evict cold doc: {}", doc_id);
+                    self.docs_map
+                        .remove_if(&doc_id, |_, doc| Arc::strong_count(&doc.handle) == 1);
+                },
+            }
         }
     }
 }
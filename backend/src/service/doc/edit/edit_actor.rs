@@ -1,11 +1,16 @@
-use crate::service::{
-    doc::edit::ServerEditDoc,
-    ws::{entities::Socket, WsUser},
+use crate::{
+    config::SyncSettings,
+    service::{
+        doc::edit::ServerEditDoc,
+        user::LoggedUser,
+        ws::{entities::Socket, WsUser},
+    },
 };
 use actix_web::web::Data;
 use async_stream::stream;
 use backend_service::errors::{internal_error, Result as DocResult, ServerError};
-use flowy_document_infra::protobuf::{Doc, Revision};
+use chrono::Utc;
+use flowy_document_infra::protobuf::{Doc, DocAwareness, DocSession, Revision};
 use futures::stream::StreamExt;
 use sqlx::PgPool;
 use std::sync::{atomic::Ordering::SeqCst, Arc};
@@ -18,10 +23,25 @@ use tokio::{
 pub struct EditUser {
     user: Arc<WsUser>,
     pub(crate) socket: Socket,
+    pub(crate) last_activity_time: i64,
+    pub(crate) has_unsynced: bool,
 }
 
 impl EditUser {
+    pub fn new(user: Arc<WsUser>, socket: Socket) -> Self {
+        Self {
+            user,
+            socket,
+            last_activity_time: Utc::now().timestamp(),
+            has_unsynced: false,
+        }
+    }
+
     pub fn id(&self) -> String { self.user.id().to_string() }
+
+    pub fn session_id(&self) -> String { self.user.session_id().to_string() }
+
+    pub fn logged_user(&self) -> &LoggedUser { self.user.logged_user() }
 }
 
 #[derive(Debug)]
@@ -30,7 +50,7 @@ pub enum EditMsg {
         user: Arc<WsUser>,
         socket: Socket,
         revision: Revision,
-        ret: oneshot::Sender<DocResult<()>>,
+        ret: oneshot::Sender<DocResult<bool>>,
     },
     DocumentJson {
         ret: oneshot::Sender<DocResult<String>>,
@@ -44,6 +64,19 @@ pub enum EditMsg {
         rev_id: i64,
         ret: oneshot::Sender<DocResult<()>>,
     },
+    Awareness {
+        user: Arc<WsUser>,
+        socket: Socket,
+        awareness: DocAwareness,
+        ret: oneshot::Sender<DocResult<()>>,
+    },
+    DocumentSessions {
+        ret: oneshot::Sender<DocResult<Vec<DocSession>>>,
+    },
+    RemoveDocUser {
+        session_id: String,
+        ret: oneshot::Sender<DocResult<bool>>,
+    },
 }
 
 pub struct EditDocActor {
@@ -53,8 +86,13 @@ pub struct EditDocActor {
 }
 
 impl EditDocActor {
-    pub fn new(receiver: mpsc::Receiver<EditMsg>, doc: Doc, pg_pool: Data<PgPool>) -> Result<Self, ServerError> {
-        let edit_doc = Arc::new(ServerEditDoc::new(doc)?);
+    pub fn new(
+        receiver: mpsc::Receiver<EditMsg>,
+        doc: Doc,
+        pg_pool: Data<PgPool>,
+        sync: SyncSettings,
+    ) -> Result<Self, ServerError> {
+        let edit_doc = Arc::new(ServerEditDoc::new(doc, sync.max_revisions_behind)?);
         Ok(Self {
             receiver: Some(receiver),
             edit_doc,
@@ -62,6 +100,8 @@ impl EditDocActor {
         })
     }
 
+    pub fn edit_doc(&self) -> Arc<ServerEditDoc> { self.edit_doc.clone() }
+
     pub async fn run(mut self) {
         let mut receiver = self
             .receiver
@@ -87,10 +127,7 @@ impl EditDocActor {
                 revision,
                 ret,
             } => {
-                let user = EditUser {
-                    user: user.clone(),
-                    socket: socket.clone(),
-                };
+                let user = EditUser::new(user, socket);
                 let _ = ret.send(self.edit_doc.apply_revision(user, revision, self.pg_pool.clone()).await);
             },
             EditMsg::DocumentJson { ret } => {
@@ -110,12 +147,24 @@ impl EditDocActor {
                 rev_id,
                 ret,
             } => {
-                let user = EditUser {
-                    user: user.clone(),
-                    socket: socket.clone(),
-                };
+                let user = EditUser::new(user, socket);
                 let _ = ret.send(self.edit_doc.new_doc_user(user, rev_id).await);
             },
+            EditMsg::Awareness {
+                user,
+                socket,
+                awareness,
+                ret,
+            } => {
+                let user = EditUser::new(user, socket);
+                let _ = ret.send(self.edit_doc.update_awareness(user, awareness.has_unsynced));
+            },
+            EditMsg::DocumentSessions { ret } => {
+                let _ = ret.send(Ok(self.edit_doc.sessions()));
+            },
+            EditMsg::RemoveDocUser { session_id, ret } => {
+                let _ = ret.send(Ok(self.edit_doc.remove_user(&session_id)));
+            },
         }
     }
 }
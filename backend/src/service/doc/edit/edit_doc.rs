@@ -1,16 +1,19 @@
 use crate::service::{
     doc::{edit::edit_actor::EditUser, update_doc},
     util::md5,
+    workspace::check_view_role,
     ws::{entities::Socket, WsMessageAdaptor},
 };
 use actix_web::web::Data;
+use anyhow::Context;
 use backend_service::errors::{internal_error, ServerError};
 use dashmap::DashMap;
 use flowy_document_infra::{
     core::Document,
     entities::ws::{WsDataType, WsDocumentData},
-    protobuf::{Doc, RevId, RevType, Revision, RevisionRange, UpdateDocParams},
+    protobuf::{Doc, DocAwareness, DocSession, RevAck, RevType, Revision, RevisionRange, UpdateDocParams},
 };
+use flowy_workspace_infra::protobuf::WorkspaceRole;
 use lib_ot::core::{Delta, OperationTransformable};
 use parking_lot::RwLock;
 use protobuf::Message;
@@ -23,16 +26,18 @@ use std::{
     },
     time::Duration,
 };
+use uuid::Uuid;
 
 pub struct ServerEditDoc {
     pub doc_id: String,
     pub rev_id: AtomicI64,
     document: Arc<RwLock<Document>>,
     users: DashMap<String, EditUser>,
+    max_revisions_behind: Option<i64>,
 }
 
 impl ServerEditDoc {
-    pub fn new(doc: Doc) -> Result<Self, ServerError> {
+    pub fn new(doc: Doc, max_revisions_behind: Option<i64>) -> Result<Self, ServerError> {
         let delta = Delta::from_bytes(&doc.data).map_err(internal_error)?;
         let document = Arc::new(RwLock::new(Document::from_delta(delta)));
         let users = DashMap::new();
@@ -41,6 +46,7 @@ impl ServerEditDoc {
             rev_id: AtomicI64::new(doc.rev_id),
             document,
             users,
+            max_revisions_behind,
         })
     }
 
@@ -53,7 +59,7 @@ impl ServerEditDoc {
         )
     )]
     pub async fn new_doc_user(&self, user: EditUser, rev_id: i64) -> Result<(), ServerError> {
-        self.users.insert(user.id(), user.clone());
+        self.add_user(user.clone());
         let cur_rev_id = self.rev_id.load(SeqCst);
         match cur_rev_id.cmp(&rev_id) {
             Ordering::Less => {
@@ -65,14 +71,19 @@ impl ServerEditDoc {
             Ordering::Greater => {
                 let doc_delta = self.document.read().delta().clone();
                 let cli_revision = self.mk_revision(rev_id, doc_delta);
-                let ws_cli_revision = mk_push_message(&self.doc_id, cli_revision);
-                user.socket.do_send(ws_cli_revision).map_err(internal_error)?;
+                let msg = match self.is_far_behind(rev_id) {
+                    true => mk_resync_message(&self.doc_id, cli_revision),
+                    false => mk_push_message(&self.doc_id, cli_revision),
+                };
+                user.socket.do_send(msg).map_err(internal_error)?;
             },
         }
 
         Ok(())
     }
 
+    // Returns true if the revision is composed into the document, so it's
+    // broadcast to the other users of the document.
     #[tracing::instrument(
         level = "debug",
         skip(self, user, pg_pool, revision),
@@ -88,8 +99,21 @@ impl ServerEditDoc {
         user: EditUser,
         revision: Revision,
         pg_pool: Data<PgPool>,
-    ) -> Result<(), ServerError> {
-        self.users.insert(user.id(), user.clone());
+    ) -> Result<bool, ServerError> {
+        // Only the Editors of the workspace change its docs, the Viewers just follow
+        // them
+        let doc_id = Uuid::parse_str(&self.doc_id)?;
+        let mut transaction = pg_pool
+            .begin()
+            .await
+            .context("Failed to acquire a Postgres connection to apply revision")?;
+        let _ = check_view_role(&mut transaction, doc_id, user.logged_user(), WorkspaceRole::Editor).await?;
+        transaction
+            .commit()
+            .await
+            .context("Failed to commit SQL transaction to apply revision.")?;
+
+        self.add_user(user.clone());
         let cur_rev_id = self.rev_id.load(SeqCst);
         match cur_rev_id.cmp(&revision.rev_id) {
             Ordering::Less => {
@@ -98,6 +122,7 @@ impl ServerEditDoc {
                     // The rev is in the right order, just compose it.
                     let _ = self.compose_revision(&revision, pg_pool).await?;
                     let _ = send_acked_msg(&user.socket, &revision)?;
+                    return Ok(true);
                 } else {
                     // The server document is outdated, pull the missing revision from the client.
                     let _ = send_pull_message(&user.socket, &self.doc_id, next_rev_id, revision.rev_id)?;
@@ -107,24 +132,102 @@ impl ServerEditDoc {
                 // Do nothing
                 log::warn!("Applied revision rev_id is the same as cur_rev_id");
             },
+            Ordering::Greater if self.is_far_behind(revision.base_rev_id) => {
+                // The client document is too outdated to transform the revision against the
+                // backlog. Drop the revision and send the snapshot of the document instead.
+                // Client should rebase its local revisions on the snapshot.
+                let doc_delta = self.document.read().delta().clone();
+                let cli_revision = self.mk_revision(revision.base_rev_id, doc_delta);
+                let _ = send_resync_message(&user.socket, &self.doc_id, cli_revision)?;
+            },
             Ordering::Greater => {
                 // The client document is outdated. Transform the client revision delta and then
                 // send the prime delta to the client. Client should compose the this prime
                 // delta.
                 let cli_revision = self.transform_revision(&revision)?;
+                let _ = self.save_document(cur_rev_id, pg_pool).await?;
                 let _ = send_push_message(&user.socket, &self.doc_id, cli_revision)?;
             },
         }
+        Ok(false)
+    }
+
+    // Records whether the user has unsynced local revisions and relays it to the
+    // other users of the document.
+    #[tracing::instrument(level = "debug", skip(self, user), fields(user_id = %user.id()), err)]
+    pub fn update_awareness(&self, mut user: EditUser, has_unsynced: bool) -> Result<(), ServerError> {
+        user.has_unsynced = has_unsynced;
+        let user_id = user.id();
+        let session_id = user.session_id();
+        self.users.insert(session_id.clone(), user);
+
+        let awareness = DocAwareness {
+            doc_id: self.doc_id.clone(),
+            user_id,
+            has_unsynced,
+            ..Default::default()
+        };
+        let sockets = self
+            .users
+            .iter()
+            .filter(|other| other.session_id() != session_id)
+            .map(|other| other.socket.clone())
+            .collect::<Vec<Socket>>();
+        for socket in sockets {
+            if let Err(e) = socket.do_send(mk_awareness_message(&awareness)) {
+                log::error!("Send awareness failed: {:?}", e);
+            }
+        }
         Ok(())
     }
 
     pub fn document_json(&self) -> String { self.document.read().to_json() }
 
+    pub fn sessions(&self) -> Vec<DocSession> {
+        self.users
+            .iter()
+            .map(|user| DocSession {
+                session_id: user.session_id(),
+                user_id: user.id(),
+                last_activity_time: user.last_activity_time,
+                has_unsynced: user.has_unsynced,
+                ..Default::default()
+            })
+            .collect::<Vec<DocSession>>()
+    }
+
+    pub fn has_active_users(&self) -> bool { self.users.iter().any(|user| user.socket.connected()) }
+
+    pub fn remove_user(&self, session_id: &str) -> bool { self.users.remove(session_id).is_some() }
+
+    // Adds the session of the user, keeping the awareness it reported. The
+    // sessions whose connections are closed are dropped, and the user who
+    // reconnects with a new session keeps the awareness of the closed one.
+    fn add_user(&self, mut user: EditUser) {
+        let session_id = user.session_id();
+        let has_unsynced = self.users.get(&session_id).map(|old| old.has_unsynced).or_else(|| {
+            self.users
+                .iter()
+                .find(|old| old.id() == user.id() && !old.socket.connected())
+                .map(|old| old.has_unsynced)
+        });
+        self.users.retain(|_, old| old.socket.connected());
+        user.has_unsynced = has_unsynced.unwrap_or(false);
+        self.users.insert(session_id, user);
+    }
+
+    fn is_far_behind(&self, rev_id: i64) -> bool {
+        match self.max_revisions_behind {
+            None => false,
+            Some(max_revisions_behind) => self.rev_id.load(SeqCst) - rev_id > max_revisions_behind,
+        }
+    }
+
     async fn compose_revision(&self, revision: &Revision, pg_pool: Data<PgPool>) -> Result<(), ServerError> {
         let delta = Delta::from_bytes(&revision.delta_data).map_err(internal_error)?;
         let _ = self.compose_delta(delta)?;
         let _ = self.rev_id.fetch_update(SeqCst, SeqCst, |_e| Some(revision.rev_id));
-        let _ = self.save_revision(&revision, pg_pool).await?;
+        let _ = self.save_document(revision.rev_id, pg_pool).await?;
         Ok(())
     }
 
@@ -182,13 +285,15 @@ impl ServerEditDoc {
         Ok(())
     }
 
-    #[tracing::instrument(level = "debug", skip(self, revision, pg_pool), err)]
-    async fn save_revision(&self, revision: &Revision, pg_pool: Data<PgPool>) -> Result<(), ServerError> {
+    // Saves the document as of the rev_id, so the doc reloads from the database
+    // as it is once it's evicted.
+    #[tracing::instrument(level = "debug", skip(self, pg_pool), err)]
+    async fn save_document(&self, rev_id: i64, pg_pool: Data<PgPool>) -> Result<(), ServerError> {
         // Opti: save with multiple revisions
         let mut params = UpdateDocParams::new();
         params.set_doc_id(self.doc_id.clone());
         params.set_data(self.document.read().to_json());
-        params.set_rev_id(revision.rev_id);
+        params.set_rev_id(rev_id);
         let _ = update_doc(pg_pool.get_ref(), params).await?;
         Ok(())
     }
@@ -200,7 +305,7 @@ fn send_push_message(socket: &Socket, doc_id: &str, revision: Revision) -> Resul
     socket.try_send(msg).map_err(internal_error)
 }
 
-fn mk_push_message(doc_id: &str, revision: Revision) -> WsMessageAdaptor {
+pub(crate) fn mk_push_message(doc_id: &str, revision: Revision) -> WsMessageAdaptor {
     let bytes = revision.write_to_bytes().unwrap();
     let data = WsDocumentData {
         doc_id: doc_id.to_string(),
@@ -210,6 +315,32 @@ fn mk_push_message(doc_id: &str, revision: Revision) -> WsMessageAdaptor {
     data.into()
 }
 
+#[tracing::instrument(level = "debug", skip(socket, doc_id, revision), err)]
+fn send_resync_message(socket: &Socket, doc_id: &str, revision: Revision) -> Result<(), ServerError> {
+    let msg = mk_resync_message(doc_id, revision);
+    socket.try_send(msg).map_err(internal_error)
+}
+
+fn mk_awareness_message(awareness: &DocAwareness) -> WsMessageAdaptor {
+    let bytes = awareness.write_to_bytes().unwrap();
+    let data = WsDocumentData {
+        doc_id: awareness.doc_id.clone(),
+        ty: WsDataType::Awareness,
+        data: bytes,
+    };
+    data.into()
+}
+
+fn mk_resync_message(doc_id: &str, revision: Revision) -> WsMessageAdaptor {
+    let bytes = revision.write_to_bytes().unwrap();
+    let data = WsDocumentData {
+        doc_id: doc_id.to_string(),
+        ty: WsDataType::Conflict,
+        data: bytes,
+    };
+    data.into()
+}
+
 #[tracing::instrument(level = "debug", skip(socket, doc_id), err)]
 fn send_pull_message(socket: &Socket, doc_id: &str, from_rev_id: i64, to_rev_id: i64) -> Result<(), ServerError> {
     let msg = mk_pull_message(doc_id, from_rev_id, to_rev_id);
@@ -239,12 +370,14 @@ fn send_acked_msg(socket: &Socket, revision: &Revision) -> Result<(), ServerErro
     socket.try_send(msg).map_err(internal_error)
 }
 
+// The ack carries the md5 of the received revision, for the client to check
+// it against the one it sent.
 fn mk_acked_message(revision: &Revision) -> WsMessageAdaptor {
-    // let mut wtr = vec![];
-    // let _ = wtr.write_i64::<BigEndian>(revision.rev_id);
-    let mut rev_id = RevId::new();
-    rev_id.set_value(revision.rev_id);
-    let data = rev_id.write_to_bytes().unwrap();
+    let mut ack = RevAck::new();
+    ack.set_rev_id(revision.rev_id);
+    ack.set_base_rev_id(revision.base_rev_id);
+    ack.set_md5(revision.md5.clone());
+    let data = ack.write_to_bytes().unwrap();
 
     let data = WsDocumentData {
         doc_id: revision.doc_id.clone(),
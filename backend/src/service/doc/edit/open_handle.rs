@@ -1,26 +1,37 @@
-use crate::service::{
-    doc::edit::edit_actor::{EditDocActor, EditMsg},
-    ws::{entities::Socket, WsUser},
+use crate::{
+    config::SyncSettings,
+    service::{
+        doc::edit::{
+            edit_actor::{EditDocActor, EditMsg},
+            ServerEditDoc,
+        },
+        ws::{entities::Socket, WsUser},
+    },
 };
 use actix_web::web::Data;
 use backend_service::errors::{internal_error, Result as DocResult, ServerError};
-use flowy_document_infra::protobuf::{Doc, Revision};
+use flowy_document_infra::protobuf::{Doc, DocAwareness, DocSession, Revision};
 use sqlx::PgPool;
 use std::sync::Arc;
 use tokio::sync::{mpsc, oneshot};
 
 pub struct DocHandle {
     pub sender: mpsc::Sender<EditMsg>,
+    edit_doc: Arc<ServerEditDoc>,
 }
 
 impl DocHandle {
-    pub fn new(doc: Doc, pg_pool: Data<PgPool>) -> Result<Self, ServerError> {
+    pub fn new(doc: Doc, pg_pool: Data<PgPool>, sync: SyncSettings) -> Result<Self, ServerError> {
         let (sender, receiver) = mpsc::channel(100);
-        let actor = EditDocActor::new(receiver, doc, pg_pool)?;
+        let actor = EditDocActor::new(receiver, doc, pg_pool, sync)?;
+        let edit_doc = actor.edit_doc();
         tokio::task::spawn(actor.run());
-        Ok(Self { sender })
+        Ok(Self { sender, edit_doc })
     }
 
+    // Whether any user of the doc is still connected.
+    pub fn has_active_users(&self) -> bool { self.edit_doc.has_active_users() }
+
     pub async fn handle_new_user(&self, user: Arc<WsUser>, rev_id: i64, socket: Socket) -> Result<(), ServerError> {
         let (ret, rx) = oneshot::channel();
         let msg = EditMsg::NewDocUser {
@@ -38,7 +49,7 @@ impl DocHandle {
         user: Arc<WsUser>,
         socket: Socket,
         revision: Revision,
-    ) -> Result<(), ServerError> {
+    ) -> Result<bool, ServerError> {
         let (ret, rx) = oneshot::channel();
         let msg = EditMsg::Revision {
             user,
@@ -46,6 +57,22 @@ impl DocHandle {
             revision,
             ret,
         };
+        self.send(msg, rx).await?
+    }
+
+    pub async fn update_awareness(
+        &self,
+        user: Arc<WsUser>,
+        socket: Socket,
+        awareness: DocAwareness,
+    ) -> Result<(), ServerError> {
+        let (ret, rx) = oneshot::channel();
+        let msg = EditMsg::Awareness {
+            user,
+            socket,
+            awareness,
+            ret,
+        };
         let _ = self.send(msg, rx).await?;
         Ok(())
     }
@@ -62,6 +89,21 @@ impl DocHandle {
         self.send(msg, rx).await?
     }
 
+    pub async fn sessions(&self) -> DocResult<Vec<DocSession>> {
+        let (ret, rx) = oneshot::channel();
+        let msg = EditMsg::DocumentSessions { ret };
+        self.send(msg, rx).await?
+    }
+
+    pub async fn remove_user(&self, session_id: &str) -> DocResult<bool> {
+        let (ret, rx) = oneshot::channel();
+        let msg = EditMsg::RemoveDocUser {
+            session_id: session_id.to_owned(),
+            ret,
+        };
+        self.send(msg, rx).await?
+    }
+
     pub(crate) async fn send<T>(&self, msg: EditMsg, rx: oneshot::Receiver<T>) -> DocResult<T> {
         let _ = self.sender.send(msg).await.map_err(internal_error)?;
         let result = rx.await?;
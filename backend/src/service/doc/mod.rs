@@ -2,6 +2,7 @@
 pub(crate) use crud::*;
 pub use router::*;
 
+mod broadcast;
 pub mod crud;
 pub mod doc;
 mod edit;
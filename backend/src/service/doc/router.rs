@@ -1,15 +1,22 @@
 use crate::service::{
     doc::{create_doc, read_doc, update_doc},
+    user::LoggedUser,
     util::parse_from_payload,
+    workspace::check_view_role,
 };
 use actix_web::{
     web::{Data, Payload},
     HttpResponse,
 };
 use anyhow::Context;
-use backend_service::{errors::ServerError, response::FlowyResponse};
+use backend_service::{
+    errors::{invalid_params, ServerError},
+    response::FlowyResponse,
+};
 use flowy_document_infra::protobuf::{CreateDocParams, DocIdentifier, UpdateDocParams};
+use flowy_workspace_infra::protobuf::WorkspaceRole;
 use sqlx::PgPool;
+use uuid::Uuid;
 
 pub async fn create_handler(payload: Payload, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
     let params: CreateDocParams = parse_from_payload(payload).await?;
@@ -37,8 +44,23 @@ pub async fn read_handler(payload: Payload, pool: Data<PgPool>) -> Result<HttpRe
     Ok(response.into())
 }
 
-pub async fn update_handler(payload: Payload, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
+pub async fn update_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
     let params: UpdateDocParams = parse_from_payload(payload).await?;
+    let doc_id = Uuid::parse_str(&params.doc_id).map_err(invalid_params)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to update doc")?;
+    let _ = check_view_role(&mut transaction, doc_id, &user, WorkspaceRole::Editor).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to update doc.")?;
+
     let _ = update_doc(pool.get_ref(), params).await?;
     Ok(FlowyResponse::success().into())
 }
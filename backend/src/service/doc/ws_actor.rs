@@ -1,13 +1,23 @@
 use crate::service::{
-    doc::{doc::DocManager, edit::DocHandle},
+    doc::{
+        broadcast::BroadcastCoalescer,
+        doc::DocManager,
+        edit::{mk_push_message, DocHandle},
+    },
     util::{md5, parse_from_bytes},
-    ws::{entities::Socket, WsClientData, WsUser},
+    ws::{
+        entities::{Broadcast, JoinDoc, Socket},
+        WsClientData,
+        WsServer,
+        WsUser,
+    },
 };
+use actix::Addr;
 use actix_rt::task::spawn_blocking;
 use actix_web::web::Data;
 use async_stream::stream;
 use backend_service::errors::{internal_error, Result as DocResult, ServerError};
-use flowy_document_infra::protobuf::{NewDocUser, Revision, WsDataType, WsDocumentData};
+use flowy_document_infra::protobuf::{DocAwareness, NewDocUser, RevType, Revision, WsDataType, WsDocumentData};
 use futures::stream::StreamExt;
 use sqlx::PgPool;
 use std::sync::Arc;
@@ -24,13 +34,22 @@ pub enum DocWsMsg {
 pub struct DocWsActor {
     receiver: Option<mpsc::Receiver<DocWsMsg>>,
     doc_manager: Arc<DocManager>,
+    ws_server: Addr<WsServer>,
+    coalescer: Option<BroadcastCoalescer>,
 }
 
 impl DocWsActor {
-    pub fn new(receiver: mpsc::Receiver<DocWsMsg>, manager: Arc<DocManager>) -> Self {
+    pub fn new(
+        receiver: mpsc::Receiver<DocWsMsg>,
+        manager: Arc<DocManager>,
+        ws_server: Addr<WsServer>,
+        coalescer: Option<BroadcastCoalescer>,
+    ) -> Self {
         Self {
             receiver: Some(receiver),
             doc_manager: manager,
+            ws_server,
+            coalescer,
         }
     }
 
@@ -77,6 +96,7 @@ impl DocWsActor {
             WsDataType::NewDocUser => self.handle_new_doc_user(user, socket, data, pool).await,
             WsDataType::PullRev => Ok(()),
             WsDataType::Conflict => Ok(()),
+            WsDataType::Awareness => self.handle_awareness(user, socket, data, pool).await,
         }
     }
 
@@ -93,6 +113,7 @@ impl DocWsActor {
         })
         .await
         .map_err(internal_error)??;
+        self.join_doc(&user, &doc_user.doc_id);
         if let Some(handle) = self.doc_handle(&doc_user.doc_id, pool).await {
             handle.handle_new_user(user, doc_user.rev_id, socket).await?;
         }
@@ -113,12 +134,64 @@ impl DocWsActor {
         })
         .await
         .map_err(internal_error)??;
+        self.join_doc(&user, &revision.doc_id);
         if let Some(handle) = self.doc_handle(&revision.doc_id, pool).await {
-            handle.apply_revision(user, socket, revision).await?;
+            if handle.apply_revision(user.clone(), socket, revision.clone()).await? {
+                self.broadcast_revision(&user, revision);
+            }
         }
         Ok(())
     }
 
+    async fn handle_awareness(
+        &self,
+        user: Arc<WsUser>,
+        socket: Socket,
+        data: Vec<u8>,
+        pool: Data<PgPool>,
+    ) -> DocResult<()> {
+        let awareness = spawn_blocking(move || {
+            let awareness: DocAwareness = parse_from_bytes(&data)?;
+            DocResult::Ok(awareness)
+        })
+        .await
+        .map_err(internal_error)??;
+        self.join_doc(&user, &awareness.doc_id);
+        if let Some(handle) = self.doc_handle(&awareness.doc_id, pool).await {
+            handle.update_awareness(user, socket, awareness).await?;
+        }
+        Ok(())
+    }
+
+    // Registers the session of the user as an editor of the document in the
+    // WsServer. JoinDoc is idempotent, so it's sent with every message of the
+    // session rather than tracking the documents the session has joined.
+    fn join_doc(&self, user: &WsUser, doc_id: &str) {
+        self.ws_server.do_send(JoinDoc {
+            sid: user.session_id().clone(),
+            doc_id: doc_id.to_owned(),
+        });
+    }
+
+    // Sends the applied revision to the other sessions editing the document, or
+    // hands it to the coalescer if the broadcasts are coalesced.
+    fn broadcast_revision(&self, user: &WsUser, mut revision: Revision) {
+        revision.set_ty(RevType::Remote);
+        if let Some(coalescer) = &self.coalescer {
+            if let Err(e) = coalescer.push(user.session_id().clone(), revision) {
+                log::error!("Coalesce the broadcast failed: {:?}", e);
+            }
+            return;
+        }
+
+        let doc_id = revision.doc_id.clone();
+        self.ws_server.do_send(Broadcast {
+            message: mk_push_message(&doc_id, revision),
+            doc_id,
+            exclude: Some(user.session_id().clone()),
+        });
+    }
+
     async fn doc_handle(&self, doc_id: &str, pool: Data<PgPool>) -> Option<Arc<DocHandle>> {
         match self.doc_manager.get(doc_id, pool).await {
             Ok(Some(edit_doc)) => Some(edit_doc),
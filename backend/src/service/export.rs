@@ -0,0 +1,47 @@
+use crate::config::ExportSettings;
+use backend_service::errors::{internal_error, ServerError};
+use std::{sync::Arc, time::Duration};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+// Bounds the number of the CPU and IO heavy exports running at once, so that
+// they can't starve the editing requests. It's separate from the workers of
+// the HttpServer, the other requests are never limited by it.
+pub struct ExportLimiter {
+    semaphore: Option<Arc<Semaphore>>,
+    queue_timeout: Option<Duration>,
+}
+
+// Keeps the slot of a running export until it's dropped.
+pub struct ExportPermit(Option<OwnedSemaphorePermit>);
+
+impl ExportLimiter {
+    pub fn new(settings: &ExportSettings) -> Self {
+        Self {
+            semaphore: settings
+                .max_concurrent_exports
+                .map(|max_concurrent_exports| Arc::new(Semaphore::new(max_concurrent_exports))),
+            queue_timeout: settings.queue_timeout_ms.map(Duration::from_millis),
+        }
+    }
+
+    // Waits for a slot to run the export in, or fails with too many requests
+    // if no slot is freed within the queue timeout.
+    pub async fn acquire(&self) -> Result<ExportPermit, ServerError> {
+        let semaphore = match &self.semaphore {
+            None => return Ok(ExportPermit(None)),
+            Some(semaphore) => semaphore.clone(),
+        };
+
+        let permit = match self.queue_timeout {
+            None => semaphore.acquire_owned().await,
+            Some(queue_timeout) => match tokio::time::timeout(queue_timeout, semaphore.acquire_owned()).await {
+                Ok(permit) => permit,
+                Err(_) => {
+                    let msg = format!("No export slot is freed within {:?}", queue_timeout);
+                    return Err(ServerError::too_many_requests().context(msg));
+                },
+            },
+        };
+        Ok(ExportPermit(Some(permit.map_err(internal_error)?)))
+    }
+}
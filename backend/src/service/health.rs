@@ -0,0 +1,27 @@
+use actix_web::{
+    web::{self, Data, ServiceConfig},
+    HttpResponse,
+};
+use serde_json::json;
+use sqlx::PgPool;
+
+// The routes are checked by the load balancer and the orchestrator without any
+// token, so they are listed in the IGNORE_ROUTES of the authentication.
+pub fn configure(cfg: &mut ServiceConfig) {
+    cfg.service(web::resource("/health").route(web::get().to(health_handler)))
+        .service(web::resource("/ready").route(web::get().to(ready_handler)));
+}
+
+// The server is up and serving the requests.
+pub async fn health_handler() -> HttpResponse { HttpResponse::Ok().json(json!({ "status": "ok" })) }
+
+// The server can serve the requests that need the database.
+pub async fn ready_handler(pool: Data<PgPool>) -> HttpResponse {
+    match sqlx::query("SELECT 1").execute(pool.get_ref()).await {
+        Ok(_) => HttpResponse::Ok().json(json!({ "status": "ok" })),
+        Err(e) => {
+            log::error!("Readiness check failed: {:?}", e);
+            HttpResponse::ServiceUnavailable().json(json!({ "status": "unavailable" }))
+        },
+    }
+}
@@ -0,0 +1,82 @@
+use crate::service::{
+    doc::doc::DocBiz,
+    ws::{entities::SessionCount, WsServer},
+};
+use actix::Addr;
+use actix_web::{
+    web::{self, Data, ServiceConfig},
+    HttpResponse,
+};
+use lazy_static::lazy_static;
+use prometheus::{
+    register_histogram_vec,
+    register_int_counter_vec,
+    register_int_gauge,
+    Encoder,
+    HistogramVec,
+    IntCounterVec,
+    IntGauge,
+    TextEncoder,
+};
+use sqlx::PgPool;
+use std::sync::Arc;
+
+lazy_static! {
+    // Labeled with the route pattern instead of the path, so the ids in the
+    // paths don't make a time series each.
+    pub static ref HTTP_REQUESTS: IntCounterVec = register_int_counter_vec!(
+        "http_requests_total",
+        "The number of the HTTP requests",
+        &["method", "route", "status"]
+    )
+    .unwrap();
+    pub static ref HTTP_REQUEST_DURATION: HistogramVec = register_histogram_vec!(
+        "http_request_duration_seconds",
+        "The latency of the HTTP requests in seconds",
+        &["method", "route"]
+    )
+    .unwrap();
+    static ref WS_SESSIONS: IntGauge =
+        register_int_gauge!("ws_sessions", "The number of the active WebSocket sessions").unwrap();
+    static ref OPENED_DOCS: IntGauge =
+        register_int_gauge!("opened_docs", "The number of the docs opened in memory").unwrap();
+    static ref DB_POOL_CONNECTIONS: IntGauge =
+        register_int_gauge!("db_pool_connections", "The number of the connections opened by the pool").unwrap();
+    static ref DB_POOL_IDLE_CONNECTIONS: IntGauge = register_int_gauge!(
+        "db_pool_idle_connections",
+        "The number of the idle connections in the pool"
+    )
+    .unwrap();
+}
+
+// Scraped by Prometheus without any token, so it's listed in the IGNORE_ROUTES
+// of the authentication.
+pub fn configure(cfg: &mut ServiceConfig) {
+    cfg.service(web::resource("/metrics").route(web::get().to(metrics_handler)));
+}
+
+// The gauges are sampled from the WsServer, the DocManager and the pool on
+// every scrape.
+pub async fn metrics_handler(
+    ws_server: Data<Addr<WsServer>>,
+    doc_biz: Data<Arc<DocBiz>>,
+    pool: Data<PgPool>,
+) -> HttpResponse {
+    match ws_server.send(SessionCount).await {
+        Ok(count) => WS_SESSIONS.set(count as i64),
+        Err(e) => log::error!("Read the session count failed: {:?}", e),
+    }
+    OPENED_DOCS.set(doc_biz.manager.opened_doc_count() as i64);
+    DB_POOL_CONNECTIONS.set(pool.size() as i64);
+    DB_POOL_IDLE_CONNECTIONS.set(pool.num_idle() as i64);
+
+    let encoder = TextEncoder::new();
+    let mut buffer = vec![];
+    match encoder.encode(&prometheus::gather(), &mut buffer) {
+        Ok(_) => HttpResponse::Ok().content_type(encoder.format_type()).body(buffer),
+        Err(e) => {
+            log::error!("Encode the metrics failed: {:?}", e);
+            HttpResponse::InternalServerError().finish()
+        },
+    }
+}
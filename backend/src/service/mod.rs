@@ -1,6 +1,11 @@
+pub mod admin;
 pub mod app;
 pub mod doc;
+pub mod export;
+pub mod health;
 pub(crate) mod log;
+pub mod metrics;
+pub mod search;
 pub mod trash;
 pub mod user;
 pub(crate) mod util;
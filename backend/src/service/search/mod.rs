@@ -0,0 +1,5 @@
+#![allow(clippy::module_inception)]
+pub mod router;
+mod search;
+
+pub(crate) use search::*;
@@ -0,0 +1,40 @@
+use crate::{
+    config::SearchSettings,
+    service::{search::search_views, user::LoggedUser, workspace::sql_builder::check_workspace_id},
+};
+use actix_web::{
+    web::{Data, Query},
+    HttpResponse,
+};
+use anyhow::Context;
+use backend_service::{errors::ServerError, response::FlowyResponse};
+use sqlx::PgPool;
+
+#[derive(serde::Deserialize)]
+pub struct SearchQuery {
+    q: String,
+    workspace_id: String,
+}
+
+pub async fn search_handler(
+    query: Query<SearchQuery>,
+    pool: Data<PgPool>,
+    settings: Data<SearchSettings>,
+    user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let SearchQuery { q, workspace_id } = query.into_inner();
+    let workspace_id = check_workspace_id(workspace_id)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to search")?;
+
+    let results = search_views(&mut transaction, &user, &workspace_id.to_string(), &q, &settings).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to search.")?;
+
+    Ok(FlowyResponse::success().pb(results)?.into())
+}
@@ -0,0 +1,237 @@
+use crate::{
+    config::SearchSettings,
+    entities::{
+        doc::DOC_TABLE,
+        workspace::{APP_TABLE, TRASH_TABLE, VIEW_TABLE, WORKSPACE_MEMBER_TABLE, WORKSPACE_TABLE},
+    },
+    service::{doc::doc_plaintext, user::LoggedUser},
+    sqlx_ext::{map_sqlx_error, DBTransaction},
+};
+use backend_service::errors::ServerError;
+use flowy_workspace_infra::protobuf::{RepeatedSearchResult, SearchMatch, SearchResult};
+use lib_ot::core::count_utf16_code_units;
+use sqlx::Row;
+use uuid::Uuid;
+
+const MAX_SEARCH_RESULTS: i64 = 50;
+// The number of chars kept around the first match in the snippet.
+const SNIPPET_CONTEXT: usize = 30;
+
+// Searches the plain text of the documents and the names of the views in the
+// workspace the user is a member of, the views in the trash excluded. The views
+// whose names match come first, then the ones ranked higher. The rank is the
+// ts_rank of the document with the full text search, or its number of matches
+// with ILIKE.
+#[tracing::instrument(skip(transaction, user, settings), err)]
+pub(crate) async fn search_views(
+    transaction: &mut DBTransaction<'_>,
+    user: &LoggedUser,
+    workspace_id: &str,
+    query: &str,
+    settings: &SearchSettings,
+) -> Result<RepeatedSearchResult, ServerError> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Err(ServerError::params_invalid().context("The search query is empty"));
+    }
+
+    let (rank, doc_condition) = match settings.full_text {
+        true => (
+            "ts_rank(doc.search_vector, plainto_tsquery('simple', $4))",
+            "doc.search_vector @@ plainto_tsquery('simple', $4)",
+        ),
+        false => ("0::real", "doc.plaintext ILIKE $3"),
+    };
+    let sql = format!(
+        r#"
+        SELECT view.id::text AS id, view.name, doc.plaintext, {rank} AS rank
+        FROM {view_table} view INNER JOIN {doc_table} doc ON doc.id = view.id
+        WHERE view.belong_to_id IN (
+            SELECT id::text FROM {app_table}
+            WHERE workspace_id = $1 AND id NOT IN (SELECT id FROM {trash_table})
+        )
+        AND $1 IN (
+            SELECT id::text FROM {workspace_table} WHERE user_id = $2
+            UNION
+            SELECT workspace_id::text FROM {member_table} WHERE user_id::text = $2
+        )
+        AND view.id NOT IN (SELECT id FROM {trash_table})
+        AND (view.name ILIKE $3 OR {doc_condition})
+        ORDER BY rank DESC, view.modified_time DESC
+        LIMIT {limit}
+        "#,
+        rank = rank,
+        doc_condition = doc_condition,
+        view_table = VIEW_TABLE,
+        doc_table = DOC_TABLE,
+        app_table = APP_TABLE,
+        trash_table = TRASH_TABLE,
+        workspace_table = WORKSPACE_TABLE,
+        member_table = WORKSPACE_MEMBER_TABLE,
+        limit = MAX_SEARCH_RESULTS,
+    );
+
+    let mut sql_query = sqlx::query(&sql)
+        .bind(workspace_id)
+        .bind(&user.user_id)
+        .bind(format!("%{}%", escape_like(query)));
+    if settings.full_text {
+        sql_query = sql_query.bind(query);
+    }
+    let rows = sql_query
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let terms = match settings.full_text {
+        true => query.split_whitespace().collect::<Vec<&str>>(),
+        false => vec![query],
+    };
+    let mut ranked_results = rows
+        .into_iter()
+        .map(|row| {
+            let name: String = row.get("name");
+            let plaintext: String = row.get("plaintext");
+            let rank: f32 = row.get("rank");
+            let name_matched = !find_matches(&name, &terms, settings.full_text).is_empty();
+            let matches = find_matches(&plaintext, &terms, settings.full_text);
+
+            let mut result = SearchResult::new();
+            result.set_view_id(row.get("id"));
+            result.set_snippet(make_snippet(&plaintext, matches.first()));
+            result.set_matches(
+                matches
+                    .iter()
+                    .map(|(start, end)| {
+                        let mut search_match = SearchMatch::new();
+                        search_match.set_start(count_utf16_code_units(&plaintext[..*start]) as i64);
+                        search_match.set_end(count_utf16_code_units(&plaintext[..*end]) as i64);
+                        search_match
+                    })
+                    .collect::<Vec<SearchMatch>>()
+                    .into(),
+            );
+            result.set_view_name(name);
+            (name_matched, rank, result)
+        })
+        .collect::<Vec<_>>();
+    ranked_results.sort_by(|(a_name_matched, a_rank, a), (b_name_matched, b_rank, b)| {
+        b_name_matched
+            .cmp(a_name_matched)
+            .then(b_rank.partial_cmp(a_rank).unwrap_or(std::cmp::Ordering::Equal))
+            .then(b.matches.len().cmp(&a.matches.len()))
+    });
+
+    let mut results = RepeatedSearchResult::new();
+    results.set_items(
+        ranked_results
+            .into_iter()
+            .map(|(_, _, result)| result)
+            .collect::<Vec<SearchResult>>()
+            .into(),
+    );
+    Ok(results)
+}
+
+// Rebuilds the search index of the docs in the workspace from their data, and
+// returns the number of the docs indexed. The search_vector is generated from
+// the plaintext, so it's rebuilt along with it.
+#[tracing::instrument(skip(transaction), err)]
+pub(crate) async fn reindex_workspace(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: &str,
+) -> Result<i64, ServerError> {
+    let sql = format!(
+        r#"
+        SELECT doc.id, doc.data FROM {doc_table} doc
+        WHERE doc.id IN (
+            SELECT id FROM {view_table} WHERE belong_to_id IN (SELECT id::text FROM {app_table} WHERE workspace_id = $1)
+        )
+        "#,
+        doc_table = DOC_TABLE,
+        view_table = VIEW_TABLE,
+        app_table = APP_TABLE,
+    );
+    let docs = sqlx::query_as::<_, (Uuid, String)>(&sql)
+        .bind(workspace_id)
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let sql = format!("UPDATE {} SET plaintext = $1 WHERE id = $2", DOC_TABLE);
+    for (doc_id, data) in &docs {
+        let _ = sqlx::query(&sql)
+            .bind(doc_plaintext(data))
+            .bind(doc_id)
+            .execute(transaction as &mut DBTransaction<'_>)
+            .await
+            .map_err(map_sqlx_error)?;
+    }
+    Ok(docs.len() as i64)
+}
+
+fn escape_like(query: &str) -> String { query.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_") }
+
+// Returns the byte ranges of the case-insensitive matches of the terms in the
+// text, in order and without overlaps. The full text search matches the whole
+// words only.
+fn find_matches(text: &str, terms: &[&str], whole_word: bool) -> Vec<(usize, usize)> {
+    let mut matches = vec![];
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, _)) = chars.next() {
+        let is_word_start = !whole_word || !text[..start].chars().last().map_or(false, char::is_alphanumeric);
+        let end = terms
+            .iter()
+            .filter(|_| is_word_start)
+            .filter_map(|term| match_len(&text[start..], term).map(|len| start + len))
+            .filter(|end| !whole_word || !text[*end..].chars().next().map_or(false, char::is_alphanumeric))
+            .max();
+        if let Some(end) = end {
+            matches.push((start, end));
+            while chars.peek().map_or(false, |(i, _)| *i < end) {
+                let _ = chars.next();
+            }
+        }
+    }
+    matches
+}
+
+// Returns the byte length of the prefix of the text matching the term, ignoring
+// the case.
+fn match_len(text: &str, term: &str) -> Option<usize> {
+    let mut text_chars = text.char_indices();
+    for term_char in term.chars() {
+        let (_, c) = text_chars.next()?;
+        if !c.to_lowercase().eq(term_char.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(text_chars.next().map_or(text.len(), |(i, _)| i))
+}
+
+// The text around the first match on one line, or the beginning of the text if
+// only the name of the view matches.
+fn make_snippet(text: &str, first_match: Option<&(usize, usize)>) -> String {
+    let (start, end, context_after) = match first_match {
+        None => (0, 0, SNIPPET_CONTEXT * 2),
+        Some((start, end)) => (*start, *end, SNIPPET_CONTEXT),
+    };
+    let snippet_start = text[..start]
+        .char_indices()
+        .rev()
+        .nth(SNIPPET_CONTEXT - 1)
+        .map_or(0, |(i, _)| i);
+    let snippet_end = text[end..]
+        .char_indices()
+        .nth(context_after)
+        .map_or(text.len(), |(i, _)| end + i);
+
+    let mut snippet = text[snippet_start..snippet_end].replace('\n', " ").trim().to_owned();
+    if snippet_start > 0 {
+        snippet.insert(0, '…');
+    }
+    if snippet_end < text.len() {
+        snippet.push('…');
+    }
+    snippet
+}
@@ -1,7 +1,10 @@
-use crate::service::{
-    trash::{create_trash, delete_all_trash, delete_trash, read_trash},
-    user::LoggedUser,
-    util::parse_from_payload,
+use crate::{
+    service::{
+        trash::{check_trash_role, create_trash, delete_all_trash, delete_trash, read_trash, restore_trash},
+        user::LoggedUser,
+        util::parse_from_payload,
+    },
+    sqlx_ext::DBTransaction,
 };
 use ::protobuf::ProtobufEnum;
 use actix_web::{
@@ -13,7 +16,10 @@ use backend_service::{
     errors::{invalid_params, ServerError},
     response::FlowyResponse,
 };
-use flowy_workspace_infra::{parser::trash::TrashId, protobuf::TrashIdentifiers};
+use flowy_workspace_infra::{
+    parser::trash::TrashId,
+    protobuf::{TrashIdentifiers, WorkspaceRole},
+};
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -29,7 +35,9 @@ pub async fn create_handler(
         .await
         .context("Failed to acquire a Postgres connection to create trash")?;
 
-    let _ = create_trash(&mut transaction, make_records(params)?, logged_user).await?;
+    let records = make_records(params)?;
+    let _ = check_records_role(&mut transaction, &records, &logged_user).await?;
+    let _ = create_trash(&mut transaction, records, logged_user).await?;
 
     transaction
         .commit()
@@ -56,6 +64,7 @@ pub async fn delete_handler(
         let _ = delete_all_trash(&mut transaction, &logged_user).await?;
     } else {
         let records = make_records(params)?;
+        let _ = check_records_role(&mut transaction, &records, &logged_user).await?;
         let _ = delete_trash(&mut transaction, records).await?;
     }
 
@@ -67,6 +76,30 @@ pub async fn delete_handler(
     Ok(FlowyResponse::success().into())
 }
 
+#[tracing::instrument(skip(payload, pool, logged_user), err)]
+pub async fn restore_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: TrashIdentifiers = parse_from_payload(payload).await?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to restore trash")?;
+
+    let records = make_records(params)?;
+    let _ = check_records_role(&mut transaction, &records, &logged_user).await?;
+    let _ = restore_trash(&mut transaction, records).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to restore trash.")?;
+
+    Ok(FlowyResponse::success().into())
+}
+
 pub async fn read_handler(pool: Data<PgPool>, logged_user: LoggedUser) -> Result<HttpResponse, ServerError> {
     let mut transaction = pool
         .begin()
@@ -83,6 +116,19 @@ pub async fn read_handler(pool: Data<PgPool>, logged_user: LoggedUser) -> Result
     Ok(FlowyResponse::success().pb(repeated_trash)?.into())
 }
 
+// Moving to, restoring from and deleting the trash need the Editor role in the
+// workspace of each item.
+async fn check_records_role(
+    transaction: &mut DBTransaction<'_>,
+    records: &[(Uuid, i32)],
+    logged_user: &LoggedUser,
+) -> Result<(), ServerError> {
+    for (trash_id, ty) in records {
+        let _ = check_trash_role(transaction, *trash_id, *ty, logged_user, WorkspaceRole::Editor).await?;
+    }
+    Ok(())
+}
+
 fn check_trash_id(id: String) -> Result<Uuid, ServerError> {
     let trash_id = TrashId::parse(id).map_err(invalid_params)?;
     let trash_id = Uuid::parse_str(trash_id.as_ref())?;
@@ -1,16 +1,17 @@
 use crate::{
-    entities::workspace::{TrashTable, TRASH_TABLE},
+    entities::workspace::{TrashTable, APP_TABLE, TRASH_TABLE, VIEW_TABLE, WORKSPACE_MEMBER_TABLE, WORKSPACE_TABLE},
     service::{
-        app::app::{delete_app, read_app_table},
+        app::app::{delete_app, read_app_table, read_descendant_app_ids},
         user::LoggedUser,
-        view::{delete_view, read_view_table},
+        view::{delete_view, read_descendant_view_ids, read_view_table},
+        workspace::{check_app_role, check_view_role},
     },
     sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
 };
 use ::protobuf::ProtobufEnum;
 use backend_service::errors::ServerError;
-use flowy_workspace_infra::protobuf::{RepeatedTrash, Trash, TrashType};
-use sqlx::{postgres::PgArguments, Postgres, Row};
+use flowy_workspace_infra::protobuf::{RepeatedTrash, Trash, TrashType, WorkspaceRole};
+use sqlx::{postgres::PgArguments, Postgres};
 use uuid::Uuid;
 
 #[tracing::instrument(skip(transaction, user), err)]
@@ -19,7 +20,30 @@ pub(crate) async fn create_trash(
     records: Vec<(Uuid, i32)>,
     user: LoggedUser,
 ) -> Result<(), ServerError> {
+    let mut all_records = vec![];
     for (trash_id, ty) in records {
+        all_records.push((trash_id, ty));
+        // The nested views and apps are moved to the trash along with their parent
+        if ty == TrashType::View.value() {
+            for view_id in read_descendant_view_ids(transaction, trash_id).await? {
+                all_records.push((view_id, ty));
+            }
+        }
+        if ty == TrashType::App.value() {
+            for app_id in read_descendant_app_ids(transaction, trash_id).await? {
+                all_records.push((app_id, ty));
+            }
+        }
+    }
+
+    let ids = all_records.iter().map(|(id, _)| *id).collect::<Vec<Uuid>>();
+    let mut trash_ids = read_trash_ids(transaction, ids).await?;
+    for (trash_id, ty) in all_records {
+        if trash_ids.contains(&trash_id) {
+            continue;
+        }
+        trash_ids.push(trash_id);
+
         let (sql, args) = SqlBuilder::create(TRASH_TABLE)
             .add_arg("id", trash_id)
             .add_arg("user_id", &user.user_id)
@@ -35,34 +59,56 @@ pub(crate) async fn create_trash(
     Ok(())
 }
 
+// Moves the trash back to where it was deleted from. The nested views and apps
+// are restored along with their parent, as they are moved to the trash with it.
+#[tracing::instrument(skip(transaction), err)]
+pub(crate) async fn restore_trash(
+    transaction: &mut DBTransaction<'_>,
+    records: Vec<(Uuid, i32)>,
+) -> Result<(), ServerError> {
+    for (trash_id, ty) in records {
+        let mut trash_ids = vec![trash_id];
+        if ty == TrashType::View.value() {
+            trash_ids.extend(read_descendant_view_ids(transaction, trash_id).await?);
+        }
+        if ty == TrashType::App.value() {
+            trash_ids.extend(read_descendant_app_ids(transaction, trash_id).await?);
+        }
+
+        for trash_id in trash_ids {
+            let (sql, args) = SqlBuilder::delete(TRASH_TABLE).and_where_eq("id", trash_id).build()?;
+            let _ = sqlx::query_with(&sql, args)
+                .execute(transaction as &mut DBTransaction<'_>)
+                .await
+                .map_err(map_sqlx_error)?;
+        }
+    }
+    Ok(())
+}
+
+// Deletes the trash of the workspaces the user can edit.
 #[tracing::instrument(skip(transaction, user), fields(delete_rows), err)]
 pub(crate) async fn delete_all_trash(
     transaction: &mut DBTransaction<'_>,
     user: &LoggedUser,
 ) -> Result<(), ServerError> {
-    let (sql, args) = SqlBuilder::select(TRASH_TABLE)
-        .and_where_eq("user_id", &user.user_id)
-        .build()?;
-    let rows = sqlx::query_with(&sql, args)
-        .fetch_all(transaction as &mut DBTransaction<'_>)
-        .await
-        .map_err(map_sqlx_error)?
+    let rows = read_workspace_trash_tables(transaction, user, WorkspaceRole::Editor)
+        .await?
         .into_iter()
-        .map(|row| (row.get("id"), row.get("ty")))
+        .map(|table| (table.id, table.ty))
         .collect::<Vec<(Uuid, i32)>>();
     tracing::Span::current().record("delete_rows", &format!("{:?}", rows).as_str());
-    let affected_row_count = rows.len();
+    let ids = rows.iter().map(|(id, _)| *id).collect::<Vec<Uuid>>();
     let _ = delete_trash_targets(transaction as &mut DBTransaction<'_>, rows).await?;
 
-    let (sql, args) = SqlBuilder::delete(TRASH_TABLE)
-        .and_where_eq("user_id", &user.user_id)
-        .build()?;
-    let result = sqlx::query_with(&sql, args)
+    // The trash of the nested views and apps is deleted along with their parent
+    let sql = format!("DELETE FROM {} WHERE id = ANY($1)", TRASH_TABLE);
+    let result = sqlx::query(&sql)
+        .bind(ids)
         .execute(transaction as &mut DBTransaction<'_>)
         .await
         .map_err(map_sqlx_error)?;
     tracing::Span::current().record("affected_row", &result.rows_affected());
-    debug_assert_eq!(affected_row_count as u64, result.rows_affected());
 
     Ok(())
 }
@@ -79,10 +125,15 @@ pub(crate) async fn delete_trash(
             .and_where_eq("id", trash_id)
             .build()?;
 
-        let trash_table = sqlx::query_as_with::<Postgres, TrashTable, PgArguments>(&sql, args)
-            .fetch_one(transaction as &mut DBTransaction<'_>)
+        // The trash is gone if it was nested in the trash deleted before it
+        let trash_table = match sqlx::query_as_with::<Postgres, TrashTable, PgArguments>(&sql, args)
+            .fetch_optional(transaction as &mut DBTransaction<'_>)
             .await
-            .map_err(map_sqlx_error)?;
+            .map_err(map_sqlx_error)?
+        {
+            None => continue,
+            Some(trash_table) => trash_table,
+        };
 
         let _ = delete_trash_targets(
             transaction as &mut DBTransaction<'_>,
@@ -123,17 +174,20 @@ async fn delete_trash_targets(
     Ok(())
 }
 
+// Returns the ids that are in the trash. The trash is shared by the members of
+// the workspace, so the items are found whoever moved them there.
 pub(crate) async fn read_trash_ids(
-    user: &LoggedUser,
     transaction: &mut DBTransaction<'_>,
-) -> Result<Vec<String>, ServerError> {
-    let repeated_trash = read_trash(transaction, user).await?.take_items().into_vec();
-    let ids = repeated_trash
-        .into_iter()
-        .map(|trash| trash.id)
-        .collect::<Vec<String>>();
+    ids: Vec<Uuid>,
+) -> Result<Vec<Uuid>, ServerError> {
+    let sql = format!("SELECT id FROM {} WHERE id = ANY($1)", TRASH_TABLE);
+    let trash_ids = sqlx::query_scalar::<_, Uuid>(&sql)
+        .bind(ids)
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
 
-    Ok(ids)
+    Ok(trash_ids)
 }
 
 #[tracing::instrument(skip(transaction, user), err)]
@@ -141,16 +195,7 @@ pub(crate) async fn read_trash(
     transaction: &mut DBTransaction<'_>,
     user: &LoggedUser,
 ) -> Result<RepeatedTrash, ServerError> {
-    let (sql, args) = SqlBuilder::select(TRASH_TABLE)
-        .add_field("*")
-        .and_where_eq("user_id", &user.user_id)
-        .build()?;
-
-    let tables = sqlx::query_as_with::<Postgres, TrashTable, PgArguments>(&sql, args)
-        .fetch_all(transaction as &mut DBTransaction<'_>)
-        .await
-        .map_err(map_sqlx_error)?;
-
+    let tables = read_workspace_trash_tables(transaction, user, WorkspaceRole::Viewer).await?;
     let mut trash: Vec<Trash> = vec![];
     for table in tables {
         match TrashType::from_i32(table.ty) {
@@ -172,3 +217,56 @@ pub(crate) async fn read_trash(
 
     Ok(repeated_trash)
 }
+
+// Checks the user has at least the role in the workspace of the trash.
+pub(crate) async fn check_trash_role(
+    transaction: &mut DBTransaction<'_>,
+    trash_id: Uuid,
+    ty: i32,
+    user: &LoggedUser,
+    role: WorkspaceRole,
+) -> Result<(), ServerError> {
+    match TrashType::from_i32(ty) {
+        Some(TrashType::View) => check_view_role(transaction, trash_id, user, role).await,
+        Some(TrashType::App) => check_app_role(transaction, &trash_id.to_string(), user, role).await,
+        _ => Err(ServerError::params_invalid().context(format!("Invalid trash type: {}", ty))),
+    }
+}
+
+// Reads the trash of the workspaces the user has at least the role in. The
+// trash is shared by the members of the workspace, so the items are read
+// whoever moved them there.
+async fn read_workspace_trash_tables(
+    transaction: &mut DBTransaction<'_>,
+    user: &LoggedUser,
+    role: WorkspaceRole,
+) -> Result<Vec<TrashTable>, ServerError> {
+    let sql = format!(
+        r#"
+        WITH workspaces AS (
+            SELECT id::text FROM {workspace_table} WHERE user_id = $1
+            UNION
+            SELECT workspace_id::text FROM {member_table} WHERE user_id = $2 AND role >= $3
+        ), apps AS (
+            SELECT id FROM {app_table} WHERE workspace_id IN (SELECT id FROM workspaces)
+        )
+        SELECT * FROM {trash_table}
+        WHERE id IN (SELECT id FROM apps)
+        OR id IN (SELECT id FROM {view_table} WHERE belong_to_id IN (SELECT id::text FROM apps))
+        "#,
+        workspace_table = WORKSPACE_TABLE,
+        member_table = WORKSPACE_MEMBER_TABLE,
+        app_table = APP_TABLE,
+        view_table = VIEW_TABLE,
+        trash_table = TRASH_TABLE,
+    );
+    let tables = sqlx::query_as::<Postgres, TrashTable>(&sql)
+        .bind(&user.user_id)
+        .bind(user.as_uuid()?)
+        .bind(role.value())
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(tables)
+}
@@ -1,6 +1,7 @@
 use crate::{
+    config::{JwtSettings, VerificationSettings},
     entities::{token::Token, user::UserTable},
-    service::user::{hash_password, verify_password, LoggedUser},
+    service::user::{create_verification_token, hash_password, verify_password, LoggedUser, Mail, Mailer},
     sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
 };
 use anyhow::Context;
@@ -8,17 +9,26 @@ use backend_service::{
     errors::{invalid_params, ErrorCode, ServerError},
     response::FlowyResponse,
 };
-use chrono::Utc;
+use chrono::{Duration, Utc};
 use flowy_user_infra::{
     parser::{UserEmail, UserName, UserPassword},
-    protobuf::{SignInParams, SignInResponse, SignUpParams, SignUpResponse, UpdateUserParams, UserProfile},
+    protobuf::{
+        RefreshTokenParams,
+        RefreshTokenResponse,
+        SignInParams,
+        SignInResponse,
+        SignUpParams,
+        SignUpResponse,
+        UpdateUserParams,
+        UserProfile,
+    },
 };
 use sqlx::{PgPool, Postgres};
 
 use super::AUTHORIZED_USERS;
 use crate::service::user::user_default::create_default_workspace;
 
-pub async fn sign_in(pool: &PgPool, params: SignInParams) -> Result<SignInResponse, ServerError> {
+pub async fn sign_in(pool: &PgPool, params: SignInParams, jwt: &JwtSettings) -> Result<SignInResponse, ServerError> {
     let email = UserEmail::parse(params.email).map_err(|e| ServerError::params_invalid().context(e))?;
     let password = UserPassword::parse(params.password).map_err(|e| ServerError::params_invalid().context(e))?;
 
@@ -28,30 +38,120 @@ pub async fn sign_in(pool: &PgPool, params: SignInParams) -> Result<SignInRespon
         .context("Failed to acquire a Postgres connection to sign in")?;
 
     let user = check_user_password(&mut transaction, email.as_ref(), password.as_ref()).await?;
+    if !user.verified {
+        return Err(ServerError::user_unverified().context(format!("{} is not verified", user.email)));
+    }
     transaction
         .commit()
         .await
         .context("Failed to commit SQL transaction to sign in.")?;
 
-    let token = Token::create_token(&user.id.to_string())?;
     let logged_user = LoggedUser::new(&user.id.to_string());
-
+    let epoch = token_epoch(pool, &logged_user).await?;
     AUTHORIZED_USERS.store_auth(logged_user, true);
     let mut response_data = SignInResponse::default();
+    if jwt.enabled {
+        let (token, refresh_token) = create_token_pair(&user.id.to_string(), epoch, jwt)?;
+        response_data.set_token(token.into());
+        response_data.set_refresh_token(refresh_token.into());
+    } else {
+        response_data.set_token(Token::create_token(&user.id.to_string(), epoch)?.into());
+    }
     response_data.set_user_id(user.id.to_string());
     response_data.set_name(user.name);
     response_data.set_email(user.email);
-    response_data.set_token(token.into());
 
     Ok(response_data)
 }
 
+// Exchanges the refresh token for a new pair of tokens. The user signed out
+// can't refresh even if the refresh token isn't expired.
+pub async fn refresh_token(
+    pool: &PgPool,
+    params: RefreshTokenParams,
+    jwt: &JwtSettings,
+) -> Result<RefreshTokenResponse, ServerError> {
+    if !jwt.enabled {
+        return Err(ServerError::params_invalid().context("The refresh tokens are not enabled"));
+    }
+
+    let claim = Token::decode_refresh_token(&Token(params.refresh_token))?;
+    let claim_epoch = claim.epoch();
+    let logged_user: LoggedUser = claim.into();
+    let epoch = token_epoch(pool, &logged_user).await?;
+    if !AUTHORIZED_USERS.is_authorized(&logged_user) || claim_epoch != epoch {
+        return Err(ServerError::unauthorized());
+    }
+
+    let (token, refresh_token) = create_token_pair(&logged_user.user_id, epoch, jwt)?;
+    AUTHORIZED_USERS.store_auth(logged_user, true);
+    let mut response_data = RefreshTokenResponse::default();
+    response_data.set_token(token.into());
+    response_data.set_refresh_token(refresh_token.into());
+    Ok(response_data)
+}
+
+fn create_token_pair(user_id: &str, epoch: i64, jwt: &JwtSettings) -> Result<(Token, Token), ServerError> {
+    let token = Token::create_access_token(user_id, epoch, Duration::seconds(jwt.access_token_expiry_secs))?;
+    let refresh_token = Token::create_refresh_token(user_id, epoch, Duration::seconds(jwt.refresh_token_expiry_secs))?;
+    Ok((token, refresh_token))
+}
+
 pub async fn sign_out(logged_user: LoggedUser) -> Result<FlowyResponse, ServerError> {
     AUTHORIZED_USERS.store_auth(logged_user, false);
     Ok(FlowyResponse::success())
 }
 
-pub async fn register_user(pool: &PgPool, params: SignUpParams) -> Result<FlowyResponse, ServerError> {
+// Signs the user out of all the sessions by increasing the token epoch of the
+// user, all the tokens issued before are rejected then.
+pub async fn logout_all(pool: &PgPool, logged_user: LoggedUser) -> Result<FlowyResponse, ServerError> {
+    let epoch: i64 =
+        sqlx::query_scalar("UPDATE user_table SET token_epoch = token_epoch + 1 WHERE id = $1 RETURNING token_epoch")
+            .bind(logged_user.as_uuid()?)
+            .fetch_one(pool)
+            .await
+            .map_err(map_sqlx_error)?;
+
+    tracing::debug!("The token epoch of {} is increased to {}", logged_user.user_id, epoch);
+    AUTHORIZED_USERS.store_auth(logged_user, false);
+    Ok(FlowyResponse::success())
+}
+
+// Validates the token of the request: it's not expired, its user is signed in
+// and it's issued in the current token epoch of the user.
+pub async fn authenticate(pool: &PgPool, token: &Token) -> Result<LoggedUser, ServerError> {
+    let claim = Token::decode_token(token)?;
+    let claim_epoch = claim.epoch();
+    let logged_user: LoggedUser = claim.into();
+    if cfg!(feature = "ignore_auth") {
+        AUTHORIZED_USERS.store_auth(logged_user.clone(), true);
+        return Ok(logged_user);
+    }
+
+    if !AUTHORIZED_USERS.is_authorized(&logged_user) || claim_epoch != token_epoch(pool, &logged_user).await? {
+        return Err(ServerError::unauthorized());
+    }
+    AUTHORIZED_USERS.store_auth(logged_user.clone(), true);
+    Ok(logged_user)
+}
+
+// Read from the database on every check rather than cached in the process, so
+// a logout_all handled by another instance of the server applies at once.
+async fn token_epoch(pool: &PgPool, logged_user: &LoggedUser) -> Result<i64, ServerError> {
+    let epoch: i64 = sqlx::query_scalar("SELECT token_epoch FROM user_table WHERE id = $1")
+        .bind(logged_user.as_uuid()?)
+        .fetch_one(pool)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(epoch)
+}
+
+pub async fn register_user(
+    pool: &PgPool,
+    params: SignUpParams,
+    verification: &VerificationSettings,
+    mailer: &dyn Mailer,
+) -> Result<FlowyResponse, ServerError> {
     let name = UserName::parse(params.name).map_err(|e| ServerError::params_invalid().context(e))?;
     let email = UserEmail::parse(params.email).map_err(|e| ServerError::params_invalid().context(e))?;
     let password = UserPassword::parse(params.password).map_err(|e| ServerError::params_invalid().context(e))?;
@@ -62,12 +162,22 @@ pub async fn register_user(pool: &PgPool, params: SignUpParams) -> Result<FlowyR
         .context("Failed to acquire a Postgres connection to register user")?;
 
     let _ = is_email_exist(&mut transaction, email.as_ref()).await?;
-    let response_data = insert_new_user(&mut transaction, name.as_ref(), email.as_ref(), password.as_ref())
+    let mut response_data = insert_new_user(&mut transaction, name.as_ref(), email.as_ref(), password.as_ref())
         .await
         .context("Failed to insert user")?;
 
     let logged_user = LoggedUser::new(&response_data.user_id);
-    AUTHORIZED_USERS.store_auth(logged_user, true);
+    // The unverified user signs in after verifying the email address.
+    let verification_token = match verification.enabled {
+        false => {
+            AUTHORIZED_USERS.store_auth(logged_user.clone(), true);
+            None
+        },
+        true => {
+            response_data.set_token("".to_owned());
+            Some(create_verification_token(&mut transaction, &logged_user.as_uuid()?, verification).await?)
+        },
+    };
     let _ = create_default_workspace(&mut transaction, response_data.get_user_id()).await?;
 
     transaction
@@ -75,6 +185,13 @@ pub async fn register_user(pool: &PgPool, params: SignUpParams) -> Result<FlowyR
         .await
         .context("Failed to commit SQL transaction to register user.")?;
 
+    if let Some(token) = verification_token {
+        let _ = mailer.send(Mail::Verification {
+            to: email.as_ref().to_owned(),
+            token,
+        })?;
+    }
+
     FlowyResponse::success().pb(response_data)
 }
 
@@ -172,10 +289,10 @@ async fn is_email_exist(transaction: &mut DBTransaction<'_>, email: &str) -> Res
         .map_err(|err| ServerError::internal().context(err))?;
 
     match result {
-        Some(_) => Err(ServerError {
-            code: ErrorCode::EmailAlreadyExists,
-            msg: format!("{} already exists", email),
-        }),
+        Some(_) => Err(ServerError::new(
+            format!("{} already exists", email),
+            ErrorCode::EmailAlreadyExists,
+        )),
         None => Ok(()),
     }
 }
@@ -185,13 +302,16 @@ async fn check_user_password(
     email: &str,
     password: &str,
 ) -> Result<UserTable, ServerError> {
+    // An unknown email fails like a wrong password, so it doesn't tell which
+    // emails are registered
     let user = sqlx::query_as::<Postgres, UserTable>("SELECT * FROM user_table WHERE email = $1")
         .bind(email)
-        .fetch_one(transaction)
+        .fetch_optional(transaction)
         .await
-        .map_err(|err| ServerError::internal().context(err))?;
+        .map_err(|err| ServerError::internal().context(err))?
+        .ok_or_else(ServerError::password_not_match)?;
 
-    match verify_password(&password, &user.password) {
+    match verify_password(password, &user.password) {
         Ok(true) => Ok(user),
         _ => Err(ServerError::password_not_match()),
     }
@@ -204,7 +324,7 @@ async fn insert_new_user(
     password: &str,
 ) -> Result<SignUpResponse, ServerError> {
     let uuid = uuid::Uuid::new_v4();
-    let token = Token::create_token(&uuid.to_string())?;
+    let token = Token::create_token(&uuid.to_string(), 0)?;
     let password = hash_password(password)?;
     let _ = sqlx::query!(
         r#"
@@ -101,6 +101,18 @@ impl AuthorizedUsers {
         }
     }
 
+    // Removes the users that logged out or whose authorization expired, they
+    // are not authorized either way. Returns the number of the removed ones.
+    pub fn remove_expired(&self) -> usize {
+        let count = self.0.len();
+        let current_time = Utc::now();
+        self.0.retain(|_, status| match *status {
+            AuthStatus::Authorized(last_time) => (current_time - last_time).num_days() < EXPIRED_DURATION_DAYS,
+            AuthStatus::NotAuthorized => false,
+        });
+        count - self.0.len()
+    }
+
     pub fn store_auth(&self, user: LoggedUser, is_auth: bool) {
         let status = if is_auth {
             AuthStatus::Authorized(Utc::now())
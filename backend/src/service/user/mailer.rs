@@ -0,0 +1,70 @@
+use backend_service::errors::ServerError;
+use parking_lot::Mutex;
+
+#[derive(Clone)]
+pub enum Mail {
+    Verification { to: String, token: String },
+    PasswordReset { to: String, token: String },
+}
+
+impl Mail {
+    pub fn to(&self) -> &str {
+        match self {
+            Mail::Verification { to, .. } => to,
+            Mail::PasswordReset { to, .. } => to,
+        }
+    }
+
+    pub fn token(&self) -> &str {
+        match self {
+            Mail::Verification { token, .. } => token,
+            Mail::PasswordReset { token, .. } => token,
+        }
+    }
+}
+
+pub trait Mailer: Send + Sync {
+    fn send(&self, mail: Mail) -> Result<(), ServerError>;
+}
+
+// There is no mail service yet. The token is a credential, so only the
+// recipient is logged.
+#[derive(Default)]
+pub struct LogMailer {}
+
+impl Mailer for LogMailer {
+    fn send(&self, mail: Mail) -> Result<(), ServerError> {
+        match &mail {
+            Mail::Verification { to, .. } => tracing::info!("Send the verification mail to {}", to),
+            Mail::PasswordReset { to, .. } => tracing::info!("Send the password reset mail to {}", to),
+        }
+        Ok(())
+    }
+}
+
+// Keeps the sent mails in memory, so the tests can read the tokens.
+#[derive(Default)]
+pub struct MemoryMailer {
+    mails: Mutex<Vec<Mail>>,
+}
+
+impl MemoryMailer {
+    pub fn mails(&self) -> Vec<Mail> { self.mails.lock().clone() }
+
+    // The token of the last mail sent to the address.
+    pub fn last_token(&self, to: &str) -> Option<String> {
+        self.mails
+            .lock()
+            .iter()
+            .rev()
+            .find(|mail| mail.to() == to)
+            .map(|mail| mail.token().to_owned())
+    }
+}
+
+impl Mailer for MemoryMailer {
+    fn send(&self, mail: Mail) -> Result<(), ServerError> {
+        self.mails.lock().push(mail);
+        Ok(())
+    }
+}
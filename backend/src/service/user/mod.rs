@@ -1,9 +1,15 @@
 pub use auth::*;
 pub use logged_user::*;
+pub use mailer::*;
+pub use password_reset::*;
 pub use utils::*;
+pub use verification::*;
 
 mod auth;
 mod logged_user;
+mod mailer;
+mod password_reset;
 pub mod router;
 pub mod user_default;
 mod utils;
+mod verification;
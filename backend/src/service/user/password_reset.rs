@@ -0,0 +1,122 @@
+use crate::{
+    config::PasswordResetSettings,
+    service::user::{hash_password, logout_all, LoggedUser, Mail, Mailer},
+    sqlx_ext::map_sqlx_error,
+};
+use anyhow::Context;
+use backend_service::{
+    errors::{invalid_params, ServerError},
+    response::FlowyResponse,
+};
+use chrono::{DateTime, Duration, Utc};
+use flowy_user_infra::{
+    parser::{UserEmail, UserPassword},
+    protobuf::{ResetPasswordConfirmParams, ResetPasswordRequestParams},
+};
+use sqlx::PgPool;
+
+// Creates the token resetting the password of the user, expired after the
+// token_expiry_secs, and mails it to the user. The previous tokens of the user
+// are dropped. Responds with success even if there's no such user, so the
+// registered emails can't be probed.
+pub async fn request_password_reset(
+    pool: &PgPool,
+    params: ResetPasswordRequestParams,
+    settings: &PasswordResetSettings,
+    mailer: &dyn Mailer,
+) -> Result<FlowyResponse, ServerError> {
+    let email = UserEmail::parse(params.email).map_err(invalid_params)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to request the password reset")?;
+
+    let user_id: Option<uuid::Uuid> = sqlx::query_scalar("SELECT id FROM user_table WHERE email = $1")
+        .bind(email.as_ref())
+        .fetch_optional(&mut transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let user_id = match user_id {
+        None => {
+            tracing::debug!("Request the password reset of an unknown email {}", email.as_ref());
+            return Ok(FlowyResponse::success());
+        },
+        Some(user_id) => user_id,
+    };
+
+    let _ = sqlx::query("DELETE FROM password_reset_table WHERE user_id = $1")
+        .bind(user_id)
+        .execute(&mut transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let token = uuid::Uuid::new_v4().to_string();
+    let expires_at = Utc::now() + Duration::seconds(settings.token_expiry_secs);
+    let _ = sqlx::query("INSERT INTO password_reset_table (token, user_id, expires_at) VALUES ($1, $2, $3)")
+        .bind(&token)
+        .bind(user_id)
+        .bind(expires_at)
+        .execute(&mut transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to request the password reset.")?;
+
+    let _ = mailer.send(Mail::PasswordReset {
+        to: email.as_ref().to_owned(),
+        token,
+    })?;
+    Ok(FlowyResponse::success())
+}
+
+// Sets the new password of the user if the token is valid. The token can only
+// be used once, and all the sessions of the user are signed out after the
+// reset.
+pub async fn confirm_password_reset(
+    pool: &PgPool,
+    params: ResetPasswordConfirmParams,
+) -> Result<FlowyResponse, ServerError> {
+    let password = UserPassword::parse(params.password).map_err(invalid_params)?;
+    let password = hash_password(password.as_ref())?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to reset the password")?;
+
+    let row: Option<(uuid::Uuid, DateTime<Utc>)> =
+        sqlx::query_as("DELETE FROM password_reset_table WHERE token = $1 RETURNING user_id, expires_at")
+            .bind(&params.token)
+            .fetch_optional(&mut transaction)
+            .await
+            .map_err(map_sqlx_error)?;
+
+    let user_id = match row {
+        None => return Err(ServerError::params_invalid().context("The password reset token is invalid")),
+        Some((_, expires_at)) if expires_at < Utc::now() => {
+            transaction
+                .commit()
+                .await
+                .context("Failed to commit SQL transaction to reset the password.")?;
+            return Err(ServerError::params_invalid().context("The password reset token is expired"));
+        },
+        Some((user_id, _)) => user_id,
+    };
+
+    let _ = sqlx::query("UPDATE user_table SET password = $1 WHERE id = $2")
+        .bind(password)
+        .bind(user_id)
+        .execute(&mut transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to reset the password.")?;
+
+    logout_all(pool, LoggedUser::new(&user_id.to_string())).await
+}
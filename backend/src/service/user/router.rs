@@ -1,30 +1,74 @@
+use actix::Addr;
 use actix_identity::Identity;
 use actix_web::{
-    web::{Data, Payload},
+    web::{Data, Payload, Query},
     HttpRequest,
     HttpResponse,
 };
 use sqlx::PgPool;
+use std::sync::Arc;
 
 use backend_service::{errors::ServerError, response::FlowyResponse};
-use flowy_user_infra::protobuf::{SignInParams, SignUpParams, UpdateUserParams};
+use flowy_user_infra::protobuf::{
+    RefreshTokenParams,
+    ResendVerificationParams,
+    ResetPasswordConfirmParams,
+    ResetPasswordRequestParams,
+    SignInParams,
+    SignUpParams,
+    UpdateUserParams,
+};
 
 use crate::{
+    config::{JwtSettings, PasswordResetSettings, VerificationSettings},
     entities::token::Token,
     service::{
-        user::{get_user_profile, register_user, set_user_profile, sign_in, sign_out, LoggedUser},
+        user::{
+            authenticate,
+            confirm_password_reset,
+            get_user_profile,
+            logout_all,
+            refresh_token,
+            register_user,
+            request_password_reset,
+            resend_verification,
+            set_user_profile,
+            sign_in,
+            sign_out,
+            verify_email,
+            LoggedUser,
+            Mailer,
+            VerifyEmailParams,
+        },
         util::parse_from_payload,
+        ws::{entities::CloseUserSessions, WsServer},
     },
 };
 
-pub async fn sign_in_handler(payload: Payload, id: Identity, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
+pub async fn sign_in_handler(
+    payload: Payload,
+    id: Identity,
+    pool: Data<PgPool>,
+    jwt: Data<JwtSettings>,
+) -> Result<HttpResponse, ServerError> {
     let params: SignInParams = parse_from_payload(payload).await?;
-    let data = sign_in(pool.get_ref(), params).await?;
+    let data = sign_in(pool.get_ref(), params, jwt.get_ref()).await?;
     id.remember(data.token.clone());
     let response = FlowyResponse::success().pb(data)?;
     Ok(response.into())
 }
 
+pub async fn refresh_token_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    jwt: Data<JwtSettings>,
+) -> Result<HttpResponse, ServerError> {
+    let params: RefreshTokenParams = parse_from_payload(payload).await?;
+    let data = refresh_token(pool.get_ref(), params, jwt.get_ref()).await?;
+    let response = FlowyResponse::success().pb(data)?;
+    Ok(response.into())
+}
+
 pub async fn sign_out_handler(logged_user: LoggedUser, id: Identity) -> Result<HttpResponse, ServerError> {
     id.forget();
 
@@ -32,6 +76,27 @@ pub async fn sign_out_handler(logged_user: LoggedUser, id: Identity) -> Result<H
     Ok(response.into())
 }
 
+// The AuthenticationService ignores the routes under /api/auth, so the token is
+// authenticated here. The WebSocket sessions of the user are closed too, they
+// were authenticated with the tokens rejected from now on.
+pub async fn logout_all_handler(
+    token: Token,
+    id: Identity,
+    pool: Data<PgPool>,
+    ws_server: Data<Addr<WsServer>>,
+) -> Result<HttpResponse, ServerError> {
+    let logged_user = authenticate(pool.get_ref(), &token).await?;
+    id.forget();
+
+    let user_id = logged_user.user_id.clone();
+    let response = logout_all(pool.get_ref(), logged_user).await?;
+    match ws_server.send(CloseUserSessions { user_id }).await {
+        Ok(count) => tracing::debug!("Close {} WebSocket sessions", count),
+        Err(e) => log::error!("Close the WebSocket sessions failed: {:?}", e),
+    }
+    Ok(response.into())
+}
+
 pub async fn get_user_profile_handler(
     token: Token,
     logged_user: LoggedUser,
@@ -51,10 +116,46 @@ pub async fn set_user_profile_handler(
     Ok(response.into())
 }
 
-pub async fn register_handler(payload: Payload, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
+pub async fn register_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    verification: Data<VerificationSettings>,
+    mailer: Data<Arc<dyn Mailer>>,
+) -> Result<HttpResponse, ServerError> {
     let params: SignUpParams = parse_from_payload(payload).await?;
-    let resp = register_user(pool.get_ref(), params).await?;
+    let resp = register_user(
+        pool.get_ref(),
+        params,
+        verification.get_ref(),
+        mailer.get_ref().as_ref(),
+    )
+    .await?;
+
+    Ok(resp.into())
+}
 
+pub async fn verify_email_handler(
+    params: Query<VerifyEmailParams>,
+    pool: Data<PgPool>,
+) -> Result<HttpResponse, ServerError> {
+    let resp = verify_email(pool.get_ref(), params.into_inner()).await?;
+    Ok(resp.into())
+}
+
+pub async fn resend_verification_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    verification: Data<VerificationSettings>,
+    mailer: Data<Arc<dyn Mailer>>,
+) -> Result<HttpResponse, ServerError> {
+    let params: ResendVerificationParams = parse_from_payload(payload).await?;
+    let resp = resend_verification(
+        pool.get_ref(),
+        params,
+        verification.get_ref(),
+        mailer.get_ref().as_ref(),
+    )
+    .await?;
     Ok(resp.into())
 }
 
@@ -65,3 +166,20 @@ pub async fn change_password(
 ) -> Result<HttpResponse, ServerError> {
     unimplemented!()
 }
+
+pub async fn reset_password_request_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    settings: Data<PasswordResetSettings>,
+    mailer: Data<Arc<dyn Mailer>>,
+) -> Result<HttpResponse, ServerError> {
+    let params: ResetPasswordRequestParams = parse_from_payload(payload).await?;
+    let resp = request_password_reset(pool.get_ref(), params, settings.get_ref(), mailer.get_ref().as_ref()).await?;
+    Ok(resp.into())
+}
+
+pub async fn reset_password_confirm_handler(payload: Payload, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
+    let params: ResetPasswordConfirmParams = parse_from_payload(payload).await?;
+    let resp = confirm_password_reset(pool.get_ref(), params).await?;
+    Ok(resp.into())
+}
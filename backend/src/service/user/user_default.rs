@@ -39,8 +39,8 @@ pub async fn create_default_workspace(
             .await
             .map_err(map_sqlx_error)?;
 
-        for view in views.take_items() {
-            let (sql, args, view) = NewViewSqlBuilder::from_view(view)?.build()?;
+        for (position, view) in views.take_items().into_iter().enumerate() {
+            let (sql, args, view) = NewViewSqlBuilder::from_view(view)?.position(position as i32).build()?;
             let _ = create_view_with_args(transaction, sql, args, view, doc_initial_string()).await?;
         }
     }
@@ -0,0 +1,138 @@
+use crate::{
+    config::VerificationSettings,
+    service::user::{Mail, Mailer},
+    sqlx_ext::{map_sqlx_error, DBTransaction},
+};
+use anyhow::Context;
+use backend_service::{
+    errors::{invalid_params, ServerError},
+    response::FlowyResponse,
+};
+use chrono::{DateTime, Duration, Utc};
+use flowy_user_infra::{parser::UserEmail, protobuf::ResendVerificationParams};
+use sqlx::PgPool;
+
+#[derive(serde::Deserialize)]
+pub struct VerifyEmailParams {
+    pub token: String,
+}
+
+// Marks the registered user unverified and creates the token verifying the
+// email address of the user, expired after the token_expiry_secs. The previous
+// tokens of the user are dropped.
+pub(crate) async fn create_verification_token(
+    transaction: &mut DBTransaction<'_>,
+    user_id: &uuid::Uuid,
+    settings: &VerificationSettings,
+) -> Result<String, ServerError> {
+    let _ = sqlx::query("UPDATE user_table SET verified = false WHERE id = $1")
+        .bind(user_id)
+        .execute(&mut *transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let _ = sqlx::query("DELETE FROM user_verification_table WHERE user_id = $1")
+        .bind(user_id)
+        .execute(&mut *transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let token = uuid::Uuid::new_v4().to_string();
+    let expires_at = Utc::now() + Duration::seconds(settings.token_expiry_secs);
+    let _ = sqlx::query("INSERT INTO user_verification_table (token, user_id, expires_at) VALUES ($1, $2, $3)")
+        .bind(&token)
+        .bind(user_id)
+        .bind(expires_at)
+        .execute(&mut *transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(token)
+}
+
+// Mails a new verification token to the unverified user, so the user whose
+// token is expired can still verify the email address. Responds with success
+// even if there's no such user or the user is verified, so the registered
+// emails can't be probed.
+pub async fn resend_verification(
+    pool: &PgPool,
+    params: ResendVerificationParams,
+    settings: &VerificationSettings,
+    mailer: &dyn Mailer,
+) -> Result<FlowyResponse, ServerError> {
+    let email = UserEmail::parse(params.email).map_err(invalid_params)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to resend the verification")?;
+
+    let user_id: Option<uuid::Uuid> =
+        sqlx::query_scalar("SELECT id FROM user_table WHERE email = $1 AND verified = false")
+            .bind(email.as_ref())
+            .fetch_optional(&mut transaction)
+            .await
+            .map_err(map_sqlx_error)?;
+
+    let user_id = match user_id {
+        None => {
+            tracing::debug!(
+                "Resend the verification of an unknown or verified email {}",
+                email.as_ref()
+            );
+            return Ok(FlowyResponse::success());
+        },
+        Some(user_id) => user_id,
+    };
+
+    let token = create_verification_token(&mut transaction, &user_id, settings).await?;
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to resend the verification.")?;
+
+    let _ = mailer.send(Mail::Verification {
+        to: email.as_ref().to_owned(),
+        token,
+    })?;
+    Ok(FlowyResponse::success())
+}
+
+pub async fn verify_email(pool: &PgPool, params: VerifyEmailParams) -> Result<FlowyResponse, ServerError> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to verify the email")?;
+
+    // The token can only be used once.
+    let row: Option<(uuid::Uuid, DateTime<Utc>)> =
+        sqlx::query_as("DELETE FROM user_verification_table WHERE token = $1 RETURNING user_id, expires_at")
+            .bind(&params.token)
+            .fetch_optional(&mut transaction)
+            .await
+            .map_err(map_sqlx_error)?;
+
+    let user_id = match row {
+        None => return Err(ServerError::params_invalid().context("The verification token is invalid")),
+        Some((_, expires_at)) if expires_at < Utc::now() => {
+            transaction
+                .commit()
+                .await
+                .context("Failed to commit SQL transaction to verify the email.")?;
+            return Err(ServerError::params_invalid().context("The verification token is expired"));
+        },
+        Some((user_id, _)) => user_id,
+    };
+
+    let _ = sqlx::query("UPDATE user_table SET verified = true WHERE id = $1")
+        .bind(user_id)
+        .execute(&mut transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to verify the email.")?;
+
+    Ok(FlowyResponse::success())
+}
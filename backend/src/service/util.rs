@@ -1,6 +1,6 @@
 use crate::config::MAX_PAYLOAD_SIZE;
 use actix_web::web;
-use backend_service::errors::{ErrorCode, ServerError};
+use backend_service::errors::{invalid_params, ErrorCode, ServerError};
 use futures::StreamExt;
 use protobuf::{Message, ProtobufResult};
 
@@ -22,10 +22,10 @@ pub fn md5<T: AsRef<[u8]>>(data: T) -> String {
 }
 
 pub fn parse_from_bytes<T: Message>(bytes: &[u8]) -> Result<T, ServerError> {
-    let result: ProtobufResult<T> = Message::parse_from_bytes(&bytes);
+    let result: ProtobufResult<T> = Message::parse_from_bytes(bytes);
     match result {
         Ok(data) => Ok(data),
-        Err(e) => Err(e.into()),
+        Err(e) => Err(invalid_params(e)),
     }
 }
 
@@ -0,0 +1,107 @@
+use crate::{
+    entities::workspace::{ViewTable, APP_TABLE, TRASH_TABLE, VIEW_FAVORITE_TABLE, VIEW_TABLE, WORKSPACE_MEMBER_TABLE},
+    service::{user::LoggedUser, workspace::check_view_role},
+    sqlx_ext::{map_sqlx_error, DBTransaction},
+};
+use backend_service::errors::ServerError;
+use chrono::Utc;
+use flowy_workspace_infra::protobuf::{RepeatedView, View, WorkspaceRole};
+use sqlx::Postgres;
+use uuid::Uuid;
+
+// Stars the view for the user, starring it again has no effect.
+#[tracing::instrument(skip(transaction, user), err)]
+pub(crate) async fn create_favorite(
+    transaction: &mut DBTransaction<'_>,
+    user: &LoggedUser,
+    view_id: Uuid,
+) -> Result<(), ServerError> {
+    let _ = check_view_role(transaction, view_id, user, WorkspaceRole::Viewer).await?;
+    let sql = format!(
+        "INSERT INTO {} (user_id, view_id, created_at) VALUES ($1, $2, $3) ON CONFLICT (user_id, view_id) DO NOTHING",
+        VIEW_FAVORITE_TABLE
+    );
+    sqlx::query(&sql)
+        .bind(user.as_uuid()?)
+        .bind(view_id)
+        .bind(Utc::now())
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(())
+}
+
+#[tracing::instrument(skip(transaction, user), err)]
+pub(crate) async fn delete_favorite(
+    transaction: &mut DBTransaction<'_>,
+    user: &LoggedUser,
+    view_id: Uuid,
+) -> Result<(), ServerError> {
+    let _ = check_view_role(transaction, view_id, user, WorkspaceRole::Viewer).await?;
+    let sql = format!(
+        "DELETE FROM {} WHERE user_id = $1 AND view_id = $2",
+        VIEW_FAVORITE_TABLE
+    );
+    sqlx::query(&sql)
+        .bind(user.as_uuid()?)
+        .bind(view_id)
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(())
+}
+
+// Forgets the view starred by any user, once the view is deleted.
+pub(crate) async fn delete_view_favorites(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+) -> Result<(), ServerError> {
+    let sql = format!("DELETE FROM {} WHERE view_id = $1", VIEW_FAVORITE_TABLE);
+    sqlx::query(&sql)
+        .bind(view_id)
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(())
+}
+
+// Reads the views starred by the user across the workspaces they own or are a
+// member of, in the order they were starred. The views in the trash, or
+// belonging to an app in the trash or deleted, are excluded. The views carry no
+// belongings.
+pub(crate) async fn read_favorites(
+    transaction: &mut DBTransaction<'_>,
+    user: &LoggedUser,
+) -> Result<RepeatedView, ServerError> {
+    let sql = format!(
+        r#"
+        SELECT view.* FROM {view_table} view INNER JOIN {favorite_table} favorite ON favorite.view_id = view.id
+        WHERE favorite.user_id = $1
+        AND view.id NOT IN (SELECT id FROM {trash_table})
+        AND view.belong_to_id IN (
+            SELECT id::text FROM {app_table}
+            WHERE (user_id = $2 OR workspace_id IN (SELECT workspace_id::text FROM {member_table} WHERE user_id = $1))
+            AND id NOT IN (SELECT id FROM {trash_table})
+        )
+        ORDER BY favorite.created_at
+        "#,
+        view_table = VIEW_TABLE,
+        favorite_table = VIEW_FAVORITE_TABLE,
+        app_table = APP_TABLE,
+        trash_table = TRASH_TABLE,
+        member_table = WORKSPACE_MEMBER_TABLE,
+    );
+    let tables = sqlx::query_as::<Postgres, ViewTable>(&sql)
+        .bind(user.as_uuid()?)
+        .bind(&user.user_id)
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let mut views = RepeatedView::default();
+    views.set_items(tables.into_iter().map(View::from).collect::<Vec<View>>().into());
+    Ok(views)
+}
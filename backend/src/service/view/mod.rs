@@ -1,6 +1,8 @@
 #![allow(clippy::module_inception)]
+mod favorite;
 pub mod router;
 pub mod sql_builder;
 mod view;
 
+pub(crate) use favorite::*;
 pub(crate) use view::*;
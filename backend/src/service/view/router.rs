@@ -1,29 +1,74 @@
-use crate::service::{
-    doc::doc::DocBiz,
-    user::LoggedUser,
-    util::parse_from_payload,
-    view::{create_view, delete_view, read_view, sql_builder::check_view_ids, update_view},
+use crate::{
+    config::QuotaSettings,
+    service::{
+        doc::{
+            create_named_version,
+            delete_named_version,
+            doc::DocBiz,
+            read_doc,
+            read_doc_table,
+            read_named_version,
+            read_named_versions,
+        },
+        export::ExportLimiter,
+        trash::create_trash,
+        user::LoggedUser,
+        util::parse_from_payload,
+        view::{
+            create_favorite,
+            create_view,
+            delete_favorite,
+            delete_view,
+            duplicate_view,
+            move_view,
+            read_favorites,
+            read_view,
+            reorder_view,
+            sql_builder::{check_view_id, check_view_ids},
+            touch_view,
+            update_view,
+        },
+        workspace::{check_app_role, check_view_role},
+    },
 };
+use ::protobuf::ProtobufEnum;
 use actix_web::{
-    web::{Data, Payload},
+    web::{Data, Path, Payload, Query},
     HttpResponse,
 };
 use anyhow::Context;
 use backend_service::{
-    errors::{invalid_params, ServerError},
+    errors::{internal_error, invalid_params, ServerError},
     response::FlowyResponse,
 };
+use flowy_document_infra::{
+    core::{char_count, delta_to_standalone_html, line_count, word_count},
+    protobuf::{CreateNamedVersionParams, Doc, DocIdentifier, DocStats, NamedVersion, RepeatedNamedVersion},
+};
 use flowy_workspace_infra::{
     parser::view::{ViewDesc, ViewName, ViewThumbnail},
-    protobuf::{CreateViewParams, QueryViewRequest, UpdateViewParams, ViewIdentifier},
+    protobuf::{
+        CreateViewParams,
+        MoveViewParams,
+        QueryViewRequest,
+        ReorderViewParams,
+        TrashType,
+        UpdateViewParams,
+        ViewIdentifier,
+        WorkspaceRole,
+    },
 };
+use lib_ot::core::Delta;
 use sqlx::PgPool;
 use std::sync::Arc;
+use uuid::Uuid;
 
 pub async fn create_handler(
     payload: Payload,
     pool: Data<PgPool>,
     _doc_biz: Data<Arc<DocBiz>>,
+    user: LoggedUser,
+    quota: Data<QuotaSettings>,
 ) -> Result<HttpResponse, ServerError> {
     let params: CreateViewParams = parse_from_payload(payload).await?;
     let mut transaction = pool
@@ -31,7 +76,9 @@ pub async fn create_handler(
         .await
         .context("Failed to acquire a Postgres connection to create view")?;
 
-    let view = create_view(&mut transaction, params).await?;
+    let app_id = params.get_belong_to_id().to_owned();
+    let _ = check_app_role(&mut transaction, &app_id, &user, WorkspaceRole::Editor).await?;
+    let view = create_view(&mut transaction, params, &user, &quota).await?;
     transaction
         .commit()
         .await
@@ -41,6 +88,40 @@ pub async fn create_handler(
     Ok(resp.into())
 }
 
+// Copies the view with its document. The opened document is read from its
+// DocHandle, which may be ahead of the one saved in the database.
+pub async fn duplicate_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    doc_biz: Data<Arc<DocBiz>>,
+    user: LoggedUser,
+    quota: Data<QuotaSettings>,
+) -> Result<HttpResponse, ServerError> {
+    let params: ViewIdentifier = parse_from_payload(payload).await?;
+    let view_id = check_view_id(params.view_id)?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to duplicate view")?;
+    let _ = read_view(&user, view_id, &mut transaction).await?;
+    let _ = check_view_role(&mut transaction, view_id, &user, WorkspaceRole::Editor).await?;
+
+    let table = read_doc_table(pool.get_ref(), view_id).await?;
+    let data = match doc_biz.manager.get_opened(&view_id.to_string()) {
+        None => table.data,
+        Some(handle) => handle.document_json().await?,
+    };
+    let view = duplicate_view(&mut transaction, view_id, data, &user, &quota).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to duplicate view.")?;
+
+    Ok(FlowyResponse::success().pb(view)?.into())
+}
+
 pub async fn read_handler(payload: Payload, pool: Data<PgPool>, user: LoggedUser) -> Result<HttpResponse, ServerError> {
     let params: ViewIdentifier = parse_from_payload(payload).await?;
     let view_id = check_view_ids(vec![params.view_id])?.pop().unwrap();
@@ -58,7 +139,273 @@ pub async fn read_handler(payload: Payload, pool: Data<PgPool>, user: LoggedUser
     Ok(FlowyResponse::success().pb(view)?.into())
 }
 
-pub async fn update_handler(payload: Payload, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
+// Renders the document of the view as a standalone HTML page for printing. It
+// runs within the limit of the concurrent exports.
+pub async fn print_handler(
+    view_id: Path<String>,
+    pool: Data<PgPool>,
+    export_limiter: Data<ExportLimiter>,
+    user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let view_id = check_view_id(view_id.into_inner())?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to print view")?;
+    let view = read_view(&user, view_id, &mut transaction).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to print view.")?;
+
+    let _permit = export_limiter.acquire().await?;
+    let mut params = DocIdentifier::new();
+    params.set_doc_id(view_id.to_string());
+    let doc = read_doc(pool.get_ref(), params).await?;
+    let delta = Delta::from_json(&doc.data).map_err(internal_error)?;
+    let html = delta_to_standalone_html(&delta, &view.name);
+    Ok(HttpResponse::Ok().content_type("text/html; charset=utf-8").body(html))
+}
+
+// Computes the stats of the document of the view. The opened document is read
+// from its DocHandle, which may be ahead of the one saved in the database.
+pub async fn stats_handler(
+    view_id: Path<String>,
+    pool: Data<PgPool>,
+    doc_biz: Data<Arc<DocBiz>>,
+    user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let view_id = check_view_id(view_id.into_inner())?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read view stats")?;
+    let _ = read_view(&user, view_id, &mut transaction).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read view stats.")?;
+
+    let table = read_doc_table(pool.get_ref(), view_id).await?;
+    let (data, rev_id) = match doc_biz.manager.get_opened(&view_id.to_string()) {
+        None => (table.data, table.rev_id),
+        Some(handle) => (handle.document_json().await?, handle.rev_id().await?),
+    };
+
+    let delta = Delta::from_json(&data).map_err(internal_error)?;
+    let mut stats = DocStats::new();
+    stats.set_doc_id(view_id.to_string());
+    stats.set_word_count(word_count(&delta) as i64);
+    stats.set_char_count(char_count(&delta) as i64);
+    stats.set_line_count(line_count(&delta) as i64);
+    stats.set_rev_count(rev_id);
+    stats.set_modified_time(table.modified_time.timestamp());
+    Ok(FlowyResponse::success().pb(stats)?.into())
+}
+
+// Pins the current revision of the document of the view as a named version.
+pub async fn create_version_handler(
+    view_id: Path<String>,
+    payload: Payload,
+    pool: Data<PgPool>,
+    doc_biz: Data<Arc<DocBiz>>,
+    user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let view_id = check_view_id(view_id.into_inner())?;
+    let params: CreateNamedVersionParams = parse_from_payload(payload).await?;
+    let name = params.get_name().trim();
+    if name.is_empty() {
+        return Err(ServerError::params_invalid().context("The name of the version is empty"));
+    }
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to create named version")?;
+    let _ = check_view_role(&mut transaction, view_id, &user, WorkspaceRole::Editor).await?;
+
+    let table = read_doc_table(pool.get_ref(), view_id).await?;
+    let (data, rev_id) = match doc_biz.manager.get_opened(&view_id.to_string()) {
+        None => (table.data, table.rev_id),
+        Some(handle) => (handle.document_json().await?, handle.rev_id().await?),
+    };
+    let version = create_named_version(&mut transaction, view_id, rev_id, name, data, &user.user_id).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to create named version.")?;
+
+    let version: NamedVersion = version.into();
+    Ok(FlowyResponse::success().pb(version)?.into())
+}
+
+pub async fn read_versions_handler(
+    view_id: Path<String>,
+    pool: Data<PgPool>,
+    user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let view_id = check_view_id(view_id.into_inner())?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read named versions")?;
+    let _ = read_view(&user, view_id, &mut transaction).await?;
+    let versions = read_named_versions(&mut transaction, view_id).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read named versions.")?;
+
+    let mut repeated_version = RepeatedNamedVersion::default();
+    repeated_version.set_items(
+        versions
+            .into_iter()
+            .map(|version| version.into())
+            .collect::<Vec<NamedVersion>>()
+            .into(),
+    );
+    Ok(FlowyResponse::success().pb(repeated_version)?.into())
+}
+
+// Opens the document of the view as of the named version.
+pub async fn read_version_doc_handler(
+    path: Path<(String, String)>,
+    pool: Data<PgPool>,
+    user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let (view_id, version_id) = path.into_inner();
+    let view_id = check_view_id(view_id)?;
+    let version_id = Uuid::parse_str(&version_id).map_err(invalid_params)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read named version")?;
+    let _ = read_view(&user, view_id, &mut transaction).await?;
+    let version = read_named_version(&mut transaction, view_id, version_id).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read named version.")?;
+
+    let doc: Doc = version.into();
+    Ok(FlowyResponse::success().pb(doc)?.into())
+}
+
+pub async fn delete_version_handler(
+    path: Path<(String, String)>,
+    pool: Data<PgPool>,
+    user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let (view_id, version_id) = path.into_inner();
+    let view_id = check_view_id(view_id)?;
+    let version_id = Uuid::parse_str(&version_id).map_err(invalid_params)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to delete named version")?;
+    let _ = check_view_role(&mut transaction, view_id, &user, WorkspaceRole::Editor).await?;
+    let _ = delete_named_version(&mut transaction, view_id, version_id).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to delete named version.")?;
+
+    Ok(FlowyResponse::success().into())
+}
+
+pub async fn touch_handler(
+    view_id: Path<String>,
+    pool: Data<PgPool>,
+    user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let view_id = check_view_id(view_id.into_inner())?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to touch view")?;
+
+    let _ = check_view_role(&mut transaction, view_id, &user, WorkspaceRole::Viewer).await?;
+    let _ = touch_view(&mut transaction, &user, view_id).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to touch view.")?;
+
+    Ok(FlowyResponse::success().into())
+}
+
+pub async fn create_favorite_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: ViewIdentifier = parse_from_payload(payload).await?;
+    let view_id = check_view_id(params.view_id)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to create favorite")?;
+
+    let _ = create_favorite(&mut transaction, &user, view_id).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to create favorite.")?;
+
+    Ok(FlowyResponse::success().into())
+}
+
+pub async fn delete_favorite_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: ViewIdentifier = parse_from_payload(payload).await?;
+    let view_id = check_view_id(params.view_id)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to delete favorite")?;
+
+    let _ = delete_favorite(&mut transaction, &user, view_id).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to delete favorite.")?;
+
+    Ok(FlowyResponse::success().into())
+}
+
+pub async fn read_favorites_handler(pool: Data<PgPool>, user: LoggedUser) -> Result<HttpResponse, ServerError> {
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to read favorites")?;
+
+    let views = read_favorites(&mut transaction, &user).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to read favorites.")?;
+
+    Ok(FlowyResponse::success().pb(views)?.into())
+}
+
+pub async fn update_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
     let params: UpdateViewParams = parse_from_payload(payload).await?;
     let view_id = check_view_ids(vec![params.view_id.clone()])?.pop().unwrap();
     let name = match params.has_name() {
@@ -85,6 +432,7 @@ pub async fn update_handler(payload: Payload, pool: Data<PgPool>) -> Result<Http
         .await
         .context("Failed to acquire a Postgres connection to update app")?;
 
+    let _ = check_view_role(&mut transaction, view_id, &user, WorkspaceRole::Editor).await?;
     let _ = update_view(&mut transaction, view_id, name, desc, thumbnail).await?;
 
     transaction
@@ -95,7 +443,68 @@ pub async fn update_handler(payload: Payload, pool: Data<PgPool>) -> Result<Http
     Ok(FlowyResponse::success().into())
 }
 
-pub async fn delete_handler(payload: Payload, pool: Data<PgPool>) -> Result<HttpResponse, ServerError> {
+pub async fn move_handler(payload: Payload, pool: Data<PgPool>, user: LoggedUser) -> Result<HttpResponse, ServerError> {
+    let params: MoveViewParams = parse_from_payload(payload).await?;
+    let view_id = check_view_id(params.view_id.clone())?;
+    let parent_view_id = match params.has_parent_view_id() {
+        false => None,
+        true => Some(check_view_id(params.get_parent_view_id().to_owned())?),
+    };
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to move view")?;
+
+    let _ = check_view_role(&mut transaction, view_id, &user, WorkspaceRole::Editor).await?;
+    let _ = move_view(&mut transaction, view_id, parent_view_id).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to move view.")?;
+
+    Ok(FlowyResponse::success().into())
+}
+
+pub async fn reorder_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: ReorderViewParams = parse_from_payload(payload).await?;
+    let view_id = check_view_id(params.view_id.clone())?;
+
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to reorder view")?;
+
+    let _ = check_view_role(&mut transaction, view_id, &user, WorkspaceRole::Editor).await?;
+    let _ = reorder_view(&mut transaction, view_id, params.target_index).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to reorder view.")?;
+
+    Ok(FlowyResponse::success().into())
+}
+
+#[derive(serde::Deserialize)]
+pub struct DeleteViewQuery {
+    #[serde(default)]
+    permanent: bool,
+}
+
+// Moves the views to the trash, where they can be restored from, unless the
+// `permanent` query param is true.
+pub async fn delete_handler(
+    payload: Payload,
+    query: Query<DeleteViewQuery>,
+    pool: Data<PgPool>,
+    user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
     let params: QueryViewRequest = parse_from_payload(payload).await?;
     let view_ids = check_view_ids(params.view_ids.to_vec())?;
     let mut transaction = pool
@@ -103,7 +512,18 @@ pub async fn delete_handler(payload: Payload, pool: Data<PgPool>) -> Result<Http
         .await
         .context("Failed to acquire a Postgres connection to delete view")?;
 
-    let _ = delete_view(&mut transaction, view_ids).await?;
+    for view_id in &view_ids {
+        let _ = check_view_role(&mut transaction, *view_id, &user, WorkspaceRole::Editor).await?;
+    }
+    if query.permanent {
+        let _ = delete_view(&mut transaction, view_ids).await?;
+    } else {
+        let records = view_ids
+            .into_iter()
+            .map(|view_id| (view_id, TrashType::View.value()))
+            .collect();
+        let _ = create_trash(&mut transaction, records, user).await?;
+    }
 
     transaction
         .commit()
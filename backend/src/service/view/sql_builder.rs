@@ -30,12 +30,18 @@ impl NewViewSqlBuilder {
             create_time: time,
             thumbnail: "".to_string(),
             view_type: ViewType::Doc.value(),
+            parent_view_id: None,
+            position: 0,
         };
 
         Self { table }
     }
 
-    pub fn from_view(view: View) -> Result<Self, ServerError> {
+    pub fn from_view(mut view: View) -> Result<Self, ServerError> {
+        let parent_view_id = match view.has_parent_view_id() {
+            false => None,
+            true => Some(view.take_parent_view_id()),
+        };
         let view_id = ViewId::parse(view.id).map_err(invalid_params)?;
         let view_id = Uuid::parse_str(view_id.as_ref())?;
         let create_time = DateTime::<Utc>::from_utc(NaiveDateTime::from_timestamp(view.create_time, 0), Utc);
@@ -50,6 +56,8 @@ impl NewViewSqlBuilder {
             create_time,
             thumbnail: "".to_string(),
             view_type: view.view_type.value(),
+            parent_view_id,
+            position: 0,
         };
         Ok(Self { table })
     }
@@ -74,6 +82,16 @@ impl NewViewSqlBuilder {
         self
     }
 
+    pub fn parent_view_id(mut self, parent_view_id: &str) -> Self {
+        self.table.parent_view_id = Some(parent_view_id.to_owned());
+        self
+    }
+
+    pub fn position(mut self, position: i32) -> Self {
+        self.table.position = position;
+        self
+    }
+
     pub fn build(self) -> Result<(String, PgArguments, View), ServerError> {
         let view: View = self.table.clone().into();
 
@@ -86,18 +104,24 @@ impl NewViewSqlBuilder {
             .add_arg("create_time", self.table.create_time)
             .add_arg("thumbnail", self.table.thumbnail)
             .add_arg("view_type", self.table.view_type)
+            .add_arg("parent_view_id", self.table.parent_view_id)
+            .add_arg("position", self.table.position)
             .build()?;
 
         Ok((sql, args, view))
     }
 }
 
+pub(crate) fn check_view_id(id: String) -> Result<Uuid, ServerError> {
+    let view_id = ViewId::parse(id).map_err(invalid_params)?;
+    let view_id = Uuid::parse_str(view_id.as_ref())?;
+    Ok(view_id)
+}
+
 pub(crate) fn check_view_ids(ids: Vec<String>) -> Result<Vec<Uuid>, ServerError> {
     let mut view_ids = vec![];
     for id in ids {
-        let view_id = ViewId::parse(id).map_err(invalid_params)?;
-        let view_id = Uuid::parse_str(view_id.as_ref())?;
-        view_ids.push(view_id);
+        view_ids.push(check_view_id(id)?);
     }
     Ok(view_ids)
 }
@@ -1,10 +1,12 @@
 use crate::{
-    entities::workspace::{ViewTable, VIEW_TABLE},
+    config::QuotaSettings,
+    entities::workspace::{ViewTable, APP_TABLE, TRASH_TABLE, VIEW_ACCESS_TABLE, VIEW_TABLE},
     service::{
         doc::{create_doc, delete_doc},
         trash::read_trash_ids,
         user::LoggedUser,
-        view::sql_builder::*,
+        view::{delete_view_favorites, sql_builder::*},
+        workspace::check_app_role,
     },
     sqlx_ext::{map_sqlx_error, DBTransaction, SqlBuilder},
 };
@@ -16,9 +18,10 @@ use flowy_workspace_infra::{
         app::AppId,
         view::{ViewDesc, ViewName, ViewThumbnail},
     },
-    protobuf::{CreateViewParams, RepeatedView, View},
+    protobuf::{CreateViewParams, RepeatedView, View, ViewType, WorkspaceRole},
 };
-use sqlx::{postgres::PgArguments, Postgres};
+use protobuf::ProtobufEnum;
+use sqlx::{postgres::PgArguments, Postgres, Row};
 use uuid::Uuid;
 
 pub(crate) async fn update_view(
@@ -44,9 +47,195 @@ pub(crate) async fn update_view(
     Ok(())
 }
 
+// Records that the user accessed the view now. It only touches the per-user
+// last_accessed_at, so neither the view's modified_time nor its doc changes.
+#[tracing::instrument(skip(transaction, user), err)]
+pub(crate) async fn touch_view(
+    transaction: &mut DBTransaction<'_>,
+    user: &LoggedUser,
+    view_id: Uuid,
+) -> Result<(), ServerError> {
+    // Make sure the view exists
+    let _ = read_view_table(view_id, transaction).await?;
+
+    let sql = format!(
+        "INSERT INTO {} (user_id, view_id, last_accessed_at) VALUES ($1, $2, $3) ON CONFLICT (user_id, view_id) DO \
+         UPDATE SET last_accessed_at = EXCLUDED.last_accessed_at",
+        VIEW_ACCESS_TABLE
+    );
+    sqlx::query(&sql)
+        .bind(user.as_uuid()?)
+        .bind(view_id)
+        .bind(Utc::now())
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(())
+}
+
+// Moves the view under the parent_view_id. The view is moved to the top of its
+// app if the parent_view_id is None.
+#[tracing::instrument(skip(transaction), err)]
+pub(crate) async fn move_view(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    parent_view_id: Option<Uuid>,
+) -> Result<(), ServerError> {
+    let view = read_view_table(view_id, transaction).await?;
+    if let Some(parent_view_id) = parent_view_id {
+        let parent = read_view_table(parent_view_id, transaction).await?;
+        if parent.belong_to_id != view.belong_to_id {
+            return Err(ServerError::params_invalid().context("The parent view belongs to another app"));
+        }
+
+        // The view can't be moved under itself or one of its descendants
+        let mut ancestor = Some(parent);
+        while let Some(table) = ancestor {
+            if table.id == view_id {
+                return Err(ServerError::params_invalid().context("Moving the view under itself causes a cycle"));
+            }
+            ancestor = match table.parent_view_id {
+                None => None,
+                Some(id) => Some(read_view_table(check_view_id(id)?, transaction).await?),
+            };
+        }
+    }
+
+    // The view goes after the views of its new parent.
+    let parent_view_id = parent_view_id.map(|id| id.to_string());
+    let position = next_view_position(transaction, &view.belong_to_id, parent_view_id.as_deref()).await?;
+    let (sql, args) = SqlBuilder::update(VIEW_TABLE)
+        .add_arg("parent_view_id", parent_view_id)
+        .add_arg("position", position)
+        .add_arg("modified_time", Utc::now())
+        .and_where_eq("id", view_id)
+        .build()?;
+
+    sqlx::query_with(&sql, args)
+        .execute(transaction)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(())
+}
+
+// Moves the view to the target_index among the views sharing its parent, and
+// renumbers their positions from 0 in the new order.
+#[tracing::instrument(skip(transaction), err)]
+pub(crate) async fn reorder_view(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    target_index: i64,
+) -> Result<(), ServerError> {
+    if target_index < 0 {
+        return Err(ServerError::params_invalid().context("The target index can't be negative"));
+    }
+    let view = read_view_table(view_id, transaction).await?;
+    let sql = format!(
+        r#"
+        SELECT id FROM {view_table}
+        WHERE belong_to_id = $1 AND parent_view_id IS NOT DISTINCT FROM $2 AND id != $3
+        ORDER BY position, create_time
+        "#,
+        view_table = VIEW_TABLE,
+    );
+    let mut sibling_ids = sqlx::query(&sql)
+        .bind(&view.belong_to_id)
+        .bind(&view.parent_view_id)
+        .bind(view_id)
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?
+        .into_iter()
+        .map(|row| row.get::<Uuid, _>("id"))
+        .collect::<Vec<Uuid>>();
+
+    let index = (target_index as usize).min(sibling_ids.len());
+    sibling_ids.insert(index, view_id);
+    for (position, id) in sibling_ids.into_iter().enumerate() {
+        let (sql, args) = SqlBuilder::update(VIEW_TABLE)
+            .add_arg("position", position as i32)
+            .and_where_eq("id", id)
+            .build()?;
+        let _ = sqlx::query_with(&sql, args)
+            .execute(transaction as &mut DBTransaction<'_>)
+            .await
+            .map_err(map_sqlx_error)?;
+    }
+    Ok(())
+}
+
+// The position after the last view sharing the parent.
+async fn next_view_position(
+    transaction: &mut DBTransaction<'_>,
+    belong_to_id: &str,
+    parent_view_id: Option<&str>,
+) -> Result<i32, ServerError> {
+    let sql = format!(
+        "SELECT COALESCE(MAX(position) + 1, 0) FROM {view_table} WHERE belong_to_id = $1 AND parent_view_id IS NOT \
+         DISTINCT FROM $2",
+        view_table = VIEW_TABLE,
+    );
+    let position = sqlx::query_scalar::<_, i32>(&sql)
+        .bind(belong_to_id)
+        .bind(parent_view_id)
+        .fetch_one(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+    Ok(position)
+}
+
+// Reads the ids of all the views nested under the view, recursively.
+pub(crate) async fn read_descendant_view_ids(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+) -> Result<Vec<Uuid>, ServerError> {
+    let sql = format!(
+        r#"
+        WITH RECURSIVE descendants AS (
+            SELECT id FROM {view_table} WHERE parent_view_id = $1
+            UNION
+            SELECT view.id FROM {view_table} view INNER JOIN descendants ON view.parent_view_id = descendants.id::text
+        )
+        SELECT id FROM descendants
+        "#,
+        view_table = VIEW_TABLE,
+    );
+
+    let ids = sqlx::query(&sql)
+        .bind(view_id.to_string())
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?
+        .into_iter()
+        .map(|row| row.get::<Uuid, _>("id"))
+        .collect::<Vec<Uuid>>();
+
+    Ok(ids)
+}
+
+// Deletes the views along with their nested views, which would be left with a
+// dangling parent otherwise. The nested views are moved to the trash with
+// their parent, so their trash rows are deleted too.
 #[tracing::instrument(skip(transaction), err)]
 pub(crate) async fn delete_view(transaction: &mut DBTransaction<'_>, view_ids: Vec<Uuid>) -> Result<(), ServerError> {
+    let mut all_view_ids = vec![];
     for view_id in view_ids {
+        all_view_ids.push(view_id);
+        all_view_ids.extend(read_descendant_view_ids(transaction, view_id).await?);
+    }
+    all_view_ids.sort();
+    all_view_ids.dedup();
+
+    let sql = format!("DELETE FROM {} WHERE id = ANY($1)", TRASH_TABLE);
+    let _ = sqlx::query(&sql)
+        .bind(&all_view_ids)
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    for view_id in all_view_ids {
         let (sql, args) = SqlBuilder::delete(VIEW_TABLE).and_where_eq("id", &view_id).build()?;
         let _ = sqlx::query_with(&sql, args)
             .execute(transaction as &mut DBTransaction<'_>)
@@ -54,31 +243,135 @@ pub(crate) async fn delete_view(transaction: &mut DBTransaction<'_>, view_ids: V
             .map_err(map_sqlx_error)?;
 
         let _ = delete_doc(transaction, view_id).await?;
+        let _ = delete_view_favorites(transaction, view_id).await?;
     }
     Ok(())
 }
 
-#[tracing::instrument(name = "create_view", level = "debug", skip(transaction), err)]
+#[tracing::instrument(name = "create_view", level = "debug", skip(transaction, user, quota), err)]
 pub(crate) async fn create_view(
     transaction: &mut DBTransaction<'_>,
-    params: CreateViewParams,
+    mut params: CreateViewParams,
+    user: &LoggedUser,
+    quota: &QuotaSettings,
 ) -> Result<View, ServerError> {
+    let _ = check_view_quota(transaction, user, quota).await?;
+    let parent_view_id = match params.has_parent_view_id() {
+        false => None,
+        true => Some(check_view_id(params.take_parent_view_id())?),
+    };
     let name = ViewName::parse(params.name).map_err(invalid_params)?;
     let belong_to_id = AppId::parse(params.belong_to_id).map_err(invalid_params)?;
     let thumbnail = ViewThumbnail::parse(params.thumbnail).map_err(invalid_params)?;
     let desc = ViewDesc::parse(params.desc).map_err(invalid_params)?;
 
-    let (sql, args, view) = NewViewSqlBuilder::new(belong_to_id.as_ref())
+    let mut builder = NewViewSqlBuilder::new(belong_to_id.as_ref())
         .name(name.as_ref())
         .desc(desc.as_ref())
         .thumbnail(thumbnail.as_ref())
-        .view_type(params.view_type)
-        .build()?;
+        .view_type(params.view_type);
+
+    let mut parent_id = None;
+    if let Some(parent_view_id) = parent_view_id {
+        let parent = read_view_table(parent_view_id, transaction).await?;
+        if parent.belong_to_id != belong_to_id.as_ref() {
+            return Err(ServerError::params_invalid().context("The parent view belongs to another app"));
+        }
+        parent_id = Some(parent.id.to_string());
+    }
+    if let Some(parent_id) = &parent_id {
+        builder = builder.parent_view_id(parent_id);
+    }
+    // The new view goes after the views sharing its parent.
+    let position = next_view_position(transaction, belong_to_id.as_ref(), parent_id.as_deref()).await?;
+    builder = builder.position(position);
+
+    let (sql, args, view) = builder.build()?;
 
     let view = create_view_with_args(transaction, sql, args, view, params.data).await?;
     Ok(view)
 }
 
+// Copies the view into the same app and under the same parent, named "… copy".
+// The copy gets a new id and a fresh document initialized from the data, so it
+// doesn't share the revisions of the view.
+#[tracing::instrument(skip(transaction, data, user, quota), err)]
+pub(crate) async fn duplicate_view(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    data: String,
+    user: &LoggedUser,
+    quota: &QuotaSettings,
+) -> Result<View, ServerError> {
+    let table = read_view_table(view_id, transaction).await?;
+    let mut params = CreateViewParams::new();
+    params.set_belong_to_id(table.belong_to_id);
+    params.set_name(format!("{} copy", table.name));
+    params.set_desc(table.description);
+    params.set_thumbnail(table.thumbnail);
+    params.set_view_type(ViewType::from_i32(table.view_type).unwrap_or(ViewType::Doc));
+    params.set_data(data);
+    if let Some(parent_view_id) = table.parent_view_id {
+        params.set_parent_view_id(parent_view_id);
+    }
+
+    create_view(transaction, params, user, quota).await
+}
+
+pub(crate) async fn check_view_quota(
+    transaction: &mut DBTransaction<'_>,
+    user: &LoggedUser,
+    quota: &QuotaSettings,
+) -> Result<(), ServerError> {
+    let limit = match quota.max_documents_per_user {
+        None => return Ok(()),
+        Some(limit) => limit,
+    };
+
+    let count = count_user_views(transaction, user).await?;
+    if count >= limit {
+        tracing::warn!("{} exceeds the document quota: {}/{}", user.user_id, count, limit);
+        return Err(ServerError::quota_exceeded(count, limit));
+    }
+    Ok(())
+}
+
+// Counts the views of all the user's apps, including nested views. The views
+// in the trash, or nested in an app or view in the trash, are excluded: the
+// recursion starts from the top level views and doesn't descend into the
+// trashed ones.
+pub(crate) async fn count_user_views(
+    transaction: &mut DBTransaction<'_>,
+    user: &LoggedUser,
+) -> Result<i64, ServerError> {
+    let sql = format!(
+        r#"
+        WITH RECURSIVE user_views AS (
+            SELECT id FROM {view_table}
+            WHERE parent_view_id IS NULL AND id NOT IN (SELECT id FROM {trash_table}) AND belong_to_id IN (
+                SELECT id::text FROM {app_table}
+                WHERE user_id = $1 AND id NOT IN (SELECT id FROM {trash_table})
+            )
+            UNION
+            SELECT view.id FROM {view_table} view INNER JOIN user_views ON view.parent_view_id = user_views.id::text
+            WHERE view.id NOT IN (SELECT id FROM {trash_table})
+        )
+        SELECT COUNT(*) FROM user_views
+        "#,
+        view_table = VIEW_TABLE,
+        app_table = APP_TABLE,
+        trash_table = TRASH_TABLE,
+    );
+
+    let row = sqlx::query(&sql)
+        .bind(&user.user_id)
+        .fetch_one(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(row.get::<i64, _>(0))
+}
+
 pub(crate) async fn create_view_with_args(
     transaction: &mut DBTransaction<'_>,
     sql: String,
@@ -104,18 +397,16 @@ pub(crate) async fn read_view(
     transaction: &mut DBTransaction<'_>,
 ) -> Result<View, ServerError> {
     let table = read_view_table(view_id, transaction as &mut DBTransaction<'_>).await?;
+    // The Viewers of the workspace can read its views
+    let _ = check_app_role(transaction, &table.belong_to_id, user, WorkspaceRole::Viewer).await?;
 
-    let read_trash_ids = read_trash_ids(user, transaction).await?;
-    if read_trash_ids.contains(&table.id.to_string()) {
+    if !read_trash_ids(transaction, vec![table.id]).await?.is_empty() {
         return Err(ServerError::record_not_found());
     }
 
+    let tables = read_view_tables_belong_to_id(&table.belong_to_id, transaction).await?;
     let mut views = RepeatedView::default();
-    views.set_items(
-        read_view_belong_to_id(&table.id.to_string(), &user, transaction)
-            .await?
-            .into(),
-    );
+    views.set_items(make_view_tree(Some(&table.id.to_string()), &tables).into());
     let mut view: View = table.into();
     view.set_belongings(views);
     Ok(view)
@@ -138,12 +429,20 @@ pub(crate) async fn read_view_table(
     Ok(table)
 }
 
-// transaction must be commit from caller
+// Returns the top level views of the app, each view carries its nested views in
+// the belongings. transaction must be commit from caller
 pub(crate) async fn read_view_belong_to_id<'c>(
     id: &str,
-    user: &LoggedUser,
     transaction: &mut DBTransaction<'_>,
 ) -> Result<Vec<View>, ServerError> {
+    let tables = read_view_tables_belong_to_id(id, transaction).await?;
+    Ok(make_view_tree(None, &tables))
+}
+
+async fn read_view_tables_belong_to_id(
+    id: &str,
+    transaction: &mut DBTransaction<'_>,
+) -> Result<Vec<ViewTable>, ServerError> {
     // TODO: add index for app_table
     let (sql, args) = SqlBuilder::select(VIEW_TABLE)
         .add_field("*")
@@ -155,10 +454,25 @@ pub(crate) async fn read_view_belong_to_id<'c>(
         .await
         .map_err(map_sqlx_error)?;
 
-    let read_trash_ids = read_trash_ids(user, transaction).await?;
-    tables.retain(|table| !read_trash_ids.contains(&table.id.to_string()));
+    let ids = tables.iter().map(|table| table.id).collect::<Vec<Uuid>>();
+    let trash_ids = read_trash_ids(transaction, ids).await?;
+    tables.retain(|table| !trash_ids.contains(&table.id));
+    // The views of the tree keep this order.
+    tables.sort_by_key(|table| (table.position, table.create_time));
 
-    let views = tables.into_iter().map(|table| table.into()).collect::<Vec<View>>();
+    Ok(tables)
+}
 
-    Ok(views)
+fn make_view_tree(parent_view_id: Option<&str>, tables: &[ViewTable]) -> Vec<View> {
+    tables
+        .iter()
+        .filter(|table| table.parent_view_id.as_deref() == parent_view_id)
+        .map(|table| {
+            let mut belongings = RepeatedView::default();
+            belongings.set_items(make_view_tree(Some(&table.id.to_string()), tables).into());
+            let mut view: View = table.clone().into();
+            view.set_belongings(belongings);
+            view
+        })
+        .collect::<Vec<View>>()
 }
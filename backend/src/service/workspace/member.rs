@@ -0,0 +1,195 @@
+use crate::{
+    entities::workspace::{APP_TABLE, WORKSPACE_MEMBER_TABLE, WORKSPACE_TABLE},
+    service::{user::LoggedUser, view::read_view_table},
+    sqlx_ext::{map_sqlx_error, DBTransaction},
+};
+use ::protobuf::ProtobufEnum;
+use backend_service::errors::{invalid_params, ServerError};
+use chrono::Utc;
+use flowy_workspace_infra::protobuf::{AddWorkspaceMemberParams, WorkspaceMember, WorkspaceRole};
+use sqlx::Row;
+use uuid::Uuid;
+
+// Adds the user, found by the user_id or the email, to the workspace. Inviting
+// a member again updates their role. Only the Owners can add members.
+#[tracing::instrument(skip(transaction, user), err)]
+pub(crate) async fn add_workspace_member(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: Uuid,
+    params: AddWorkspaceMemberParams,
+    user: &LoggedUser,
+) -> Result<WorkspaceMember, ServerError> {
+    let _ = check_workspace_role(transaction, workspace_id, user, WorkspaceRole::Owner).await?;
+    let row = match (params.has_user_id(), params.has_email()) {
+        (true, _) => {
+            let user_id = Uuid::parse_str(params.get_user_id()).map_err(invalid_params)?;
+            sqlx::query("SELECT id, email FROM user_table WHERE id = $1")
+                .bind(user_id)
+                .fetch_optional(transaction as &mut DBTransaction<'_>)
+                .await
+        },
+        (false, true) => {
+            sqlx::query("SELECT id, email FROM user_table WHERE email = $1")
+                .bind(params.get_email())
+                .fetch_optional(transaction as &mut DBTransaction<'_>)
+                .await
+        },
+        (false, false) => return Err(ServerError::params_invalid().context("The member's user_id or email is missing")),
+    }
+    .map_err(map_sqlx_error)?
+    .ok_or_else(|| ServerError::record_not_found().context("The invited user doesn't exist"))?;
+    let member_id: Uuid = row.get("id");
+
+    if read_workspace_owner(transaction, workspace_id).await? == member_id.to_string() {
+        return Err(ServerError::params_invalid().context("The owner of the workspace can't be invited"));
+    }
+
+    let sql = format!(
+        "INSERT INTO {} (workspace_id, user_id, role, created_at) VALUES ($1, $2, $3, $4) ON CONFLICT (workspace_id, \
+         user_id) DO UPDATE SET role = EXCLUDED.role",
+        WORKSPACE_MEMBER_TABLE
+    );
+    sqlx::query(&sql)
+        .bind(workspace_id)
+        .bind(member_id)
+        .bind(params.get_role().value())
+        .bind(Utc::now())
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    let mut member = WorkspaceMember::default();
+    member.set_workspace_id(workspace_id.to_string());
+    member.set_user_id(member_id.to_string());
+    member.set_email(row.get("email"));
+    member.set_role(params.get_role());
+    Ok(member)
+}
+
+// Removes the member from the workspace. The Owners can remove any member, and
+// the members can leave the workspace by removing themselves.
+#[tracing::instrument(skip(transaction, user), err)]
+pub(crate) async fn remove_workspace_member(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: Uuid,
+    member_id: Uuid,
+    user: &LoggedUser,
+) -> Result<(), ServerError> {
+    if user.as_uuid()? != member_id {
+        let _ = check_workspace_role(transaction, workspace_id, user, WorkspaceRole::Owner).await?;
+    }
+
+    let sql = format!(
+        "DELETE FROM {} WHERE workspace_id = $1 AND user_id = $2",
+        WORKSPACE_MEMBER_TABLE
+    );
+    let result = sqlx::query(&sql)
+        .bind(workspace_id)
+        .bind(member_id)
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    if result.rows_affected() == 0 {
+        return Err(ServerError::record_not_found().context(format!("{} isn't a member of the workspace", member_id)));
+    }
+    Ok(())
+}
+
+// Forgets the members of the workspace, once the workspace is deleted.
+pub(crate) async fn delete_workspace_members(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: Uuid,
+) -> Result<(), ServerError> {
+    let sql = format!("DELETE FROM {} WHERE workspace_id = $1", WORKSPACE_MEMBER_TABLE);
+    sqlx::query(&sql)
+        .bind(workspace_id)
+        .execute(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(())
+}
+
+// Returns the role of the user in the workspace, or None if they aren't a
+// member of it. The user who created the workspace is its Owner.
+pub(crate) async fn read_workspace_role(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: Uuid,
+    user: &LoggedUser,
+) -> Result<Option<WorkspaceRole>, ServerError> {
+    if read_workspace_owner(transaction, workspace_id).await? == user.user_id {
+        return Ok(Some(WorkspaceRole::Owner));
+    }
+
+    let sql = format!(
+        "SELECT role FROM {} WHERE workspace_id = $1 AND user_id = $2",
+        WORKSPACE_MEMBER_TABLE
+    );
+    let role = sqlx::query_scalar::<_, i32>(&sql)
+        .bind(workspace_id)
+        .bind(user.as_uuid()?)
+        .fetch_optional(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
+
+    Ok(role.and_then(WorkspaceRole::from_i32))
+}
+
+// Checks the user has at least the role in the workspace.
+pub(crate) async fn check_workspace_role(
+    transaction: &mut DBTransaction<'_>,
+    workspace_id: Uuid,
+    user: &LoggedUser,
+    role: WorkspaceRole,
+) -> Result<(), ServerError> {
+    match read_workspace_role(transaction, workspace_id, user).await? {
+        Some(user_role) if user_role.value() >= role.value() => Ok(()),
+        _ => Err(ServerError::permission_denied().context(format!(
+            "{} isn't a {:?} of the workspace {}",
+            user.user_id, role, workspace_id
+        ))),
+    }
+}
+
+// Checks the user has at least the role in the workspace of the app.
+pub(crate) async fn check_app_role(
+    transaction: &mut DBTransaction<'_>,
+    app_id: &str,
+    user: &LoggedUser,
+    role: WorkspaceRole,
+) -> Result<(), ServerError> {
+    let sql = format!("SELECT workspace_id FROM {} WHERE id::text = $1", APP_TABLE);
+    let workspace_id = sqlx::query_scalar::<_, String>(&sql)
+        .bind(app_id)
+        .fetch_optional(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?
+        .ok_or_else(ServerError::record_not_found)?;
+
+    let workspace_id = Uuid::parse_str(&workspace_id)?;
+    check_workspace_role(transaction, workspace_id, user, role).await
+}
+
+// Checks the user has at least the role in the workspace of the view.
+pub(crate) async fn check_view_role(
+    transaction: &mut DBTransaction<'_>,
+    view_id: Uuid,
+    user: &LoggedUser,
+    role: WorkspaceRole,
+) -> Result<(), ServerError> {
+    let view = read_view_table(view_id, transaction).await?;
+    check_app_role(transaction, &view.belong_to_id, user, role).await
+}
+
+async fn read_workspace_owner(transaction: &mut DBTransaction<'_>, workspace_id: Uuid) -> Result<String, ServerError> {
+    let sql = format!("SELECT user_id FROM {} WHERE id = $1", WORKSPACE_TABLE);
+    let owner = sqlx::query_scalar::<_, String>(&sql)
+        .bind(workspace_id)
+        .fetch_optional(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?
+        .ok_or_else(ServerError::record_not_found)?;
+
+    Ok(owner)
+}
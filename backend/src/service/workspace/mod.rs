@@ -1,6 +1,8 @@
 #![allow(clippy::module_inception)]
+mod member;
 pub mod router;
 pub mod sql_builder;
 mod workspace;
 
+pub(crate) use member::*;
 pub use workspace::*;
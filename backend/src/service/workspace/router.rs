@@ -2,15 +2,20 @@ use crate::service::{
     user::LoggedUser,
     util::parse_from_payload,
     workspace::{
+        add_workspace_member,
+        check_workspace_role,
         create_workspace,
         delete_workspace,
+        read_workspace_page,
         read_workspaces,
+        remove_workspace_member,
         sql_builder::check_workspace_id,
         update_workspace,
+        WorkspaceListParams,
     },
 };
 use actix_web::{
-    web::{Data, Payload},
+    web::{Data, Payload, Query},
     HttpResponse,
 };
 use anyhow::Context;
@@ -20,9 +25,17 @@ use backend_service::{
 };
 use flowy_workspace_infra::{
     parser::workspace::{WorkspaceDesc, WorkspaceName},
-    protobuf::{CreateWorkspaceParams, UpdateWorkspaceParams, WorkspaceIdentifier},
+    protobuf::{
+        AddWorkspaceMemberParams,
+        CreateWorkspaceParams,
+        RemoveWorkspaceMemberParams,
+        UpdateWorkspaceParams,
+        WorkspaceIdentifier,
+        WorkspaceRole,
+    },
 };
 use sqlx::PgPool;
+use uuid::Uuid;
 
 pub async fn create_handler(
     payload: Payload,
@@ -74,7 +87,7 @@ pub async fn read_handler(
 pub async fn delete_handler(
     payload: Payload,
     pool: Data<PgPool>,
-    _logged_user: LoggedUser,
+    logged_user: LoggedUser,
 ) -> Result<HttpResponse, ServerError> {
     let params: WorkspaceIdentifier = parse_from_payload(payload).await?;
     let workspace_id = check_workspace_id(params.get_workspace_id().to_owned())?;
@@ -83,6 +96,7 @@ pub async fn delete_handler(
         .await
         .context("Failed to acquire a Postgres connection to delete workspace")?;
 
+    let _ = check_workspace_role(&mut transaction, workspace_id, &logged_user, WorkspaceRole::Owner).await?;
     let _ = delete_workspace(&mut transaction, workspace_id).await?;
     transaction
         .commit()
@@ -95,7 +109,7 @@ pub async fn delete_handler(
 pub async fn update_handler(
     payload: Payload,
     pool: Data<PgPool>,
-    _logged_user: LoggedUser,
+    logged_user: LoggedUser,
 ) -> Result<HttpResponse, ServerError> {
     let params: UpdateWorkspaceParams = parse_from_payload(payload).await?;
     let workspace_id = check_workspace_id(params.get_id().to_owned())?;
@@ -124,6 +138,7 @@ pub async fn update_handler(
         .await
         .context("Failed to acquire a Postgres connection to update workspace")?;
 
+    let _ = check_workspace_role(&mut transaction, workspace_id, &logged_user, WorkspaceRole::Owner).await?;
     let _ = update_workspace(&mut transaction, workspace_id, name, desc).await?;
 
     transaction
@@ -134,13 +149,17 @@ pub async fn update_handler(
     Ok(FlowyResponse::success().into())
 }
 
-pub async fn workspace_list(pool: Data<PgPool>, logged_user: LoggedUser) -> Result<HttpResponse, ServerError> {
+pub async fn workspace_list(
+    params: Query<WorkspaceListParams>,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
     let mut transaction = pool
         .begin()
         .await
         .context("Failed to acquire a Postgres connection to read workspaces")?;
 
-    let repeated_workspace = read_workspaces(&mut transaction, None, logged_user).await?;
+    let repeated_workspace = read_workspace_page(&mut transaction, params.into_inner(), logged_user).await?;
     transaction
         .commit()
         .await
@@ -148,3 +167,48 @@ pub async fn workspace_list(pool: Data<PgPool>, logged_user: LoggedUser) -> Resu
 
     Ok(FlowyResponse::success().pb(repeated_workspace)?.into())
 }
+
+pub async fn add_member_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: AddWorkspaceMemberParams = parse_from_payload(payload).await?;
+    let workspace_id = check_workspace_id(params.get_workspace_id().to_owned())?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to add workspace member")?;
+
+    let member = add_workspace_member(&mut transaction, workspace_id, params, &logged_user).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to add workspace member.")?;
+
+    Ok(FlowyResponse::success().pb(member)?.into())
+}
+
+pub async fn remove_member_handler(
+    payload: Payload,
+    pool: Data<PgPool>,
+    logged_user: LoggedUser,
+) -> Result<HttpResponse, ServerError> {
+    let params: RemoveWorkspaceMemberParams = parse_from_payload(payload).await?;
+    let workspace_id = check_workspace_id(params.get_workspace_id().to_owned())?;
+    let member_id = Uuid::parse_str(params.get_user_id()).map_err(invalid_params)?;
+    let mut transaction = pool
+        .begin()
+        .await
+        .context("Failed to acquire a Postgres connection to remove workspace member")?;
+
+    let _ = remove_workspace_member(&mut transaction, workspace_id, member_id, &logged_user).await?;
+
+    transaction
+        .commit()
+        .await
+        .context("Failed to commit SQL transaction to remove workspace member.")?;
+
+    Ok(FlowyResponse::success().into())
+}
@@ -1,7 +1,11 @@
 use super::sql_builder::NewWorkspaceBuilder;
 use crate::{
-    entities::workspace::{AppTable, WorkspaceTable, WORKSPACE_TABLE},
-    service::{app::app::read_app, user::LoggedUser, workspace::sql_builder::*},
+    entities::workspace::{WorkspaceTable, WORKSPACE_MEMBER_TABLE, WORKSPACE_TABLE},
+    service::{
+        app::app::read_app_tree,
+        user::LoggedUser,
+        workspace::{delete_workspace_members, sql_builder::*},
+    },
     sqlx_ext::*,
 };
 use anyhow::Context;
@@ -10,7 +14,7 @@ use flowy_workspace_infra::{
     parser::workspace::WorkspaceId,
     protobuf::{RepeatedApp, RepeatedWorkspace, Workspace},
 };
-use sqlx::{postgres::PgArguments, Postgres};
+use sqlx::Postgres;
 use uuid::Uuid;
 
 pub(crate) async fn create_workspace(
@@ -59,10 +63,11 @@ pub(crate) async fn delete_workspace(
         .build()?;
 
     let _ = sqlx::query_with(&sql, args)
-        .execute(transaction)
+        .execute(transaction as &mut DBTransaction<'_>)
         .await
         .map_err(map_sqlx_error)?;
 
+    let _ = delete_workspace_members(transaction, workspace_id).await?;
     Ok(())
 }
 
@@ -72,35 +77,110 @@ pub async fn read_workspaces(
     workspace_id: Option<String>,
     logged_user: LoggedUser,
 ) -> Result<RepeatedWorkspace, ServerError> {
-    let user_id = logged_user.as_uuid()?.to_string();
+    let user_id = logged_user.as_uuid()?;
+    let workspace_id = match workspace_id {
+        None => None,
+        Some(workspace_id) => Some(check_workspace_id(workspace_id)?),
+    };
+
+    // The workspaces the user is a member of are read along with their own
+    let sql = format!(
+        r#"
+        SELECT * FROM {workspace_table}
+        WHERE (user_id = $1 OR id IN (SELECT workspace_id FROM {member_table} WHERE user_id = $2))
+        AND ($3::uuid IS NULL OR id = $3)
+        "#,
+        workspace_table = WORKSPACE_TABLE,
+        member_table = WORKSPACE_MEMBER_TABLE,
+    );
+    let tables = sqlx::query_as::<Postgres, WorkspaceTable>(&sql)
+        .bind(user_id.to_string())
+        .bind(user_id)
+        .bind(workspace_id)
+        .fetch_all(transaction as &mut DBTransaction<'_>)
+        .await
+        .map_err(map_sqlx_error)?;
 
-    let mut builder = SqlBuilder::select(WORKSPACE_TABLE)
-        .add_field("*")
-        .and_where_eq("user_id", &user_id);
+    make_repeated_workspace(transaction, tables).await
+}
 
-    if let Some(workspace_id) = workspace_id {
-        let workspace_id = check_workspace_id(workspace_id)?;
-        builder = builder.and_where_eq("id", workspace_id);
-    }
+// The page size of the workspace list when the limit is missing, and the
+// largest one it can be.
+const MAX_WORKSPACE_PAGE_SIZE: i64 = 100;
 
-    let (sql, args) = builder.build()?;
-    let tables = sqlx::query_as_with::<Postgres, WorkspaceTable, PgArguments>(&sql, args)
+#[derive(serde::Deserialize)]
+pub struct WorkspaceListParams {
+    pub after: Option<String>,
+    pub limit: Option<i64>,
+}
+
+// Reads the page of the user's workspaces, ordered by their ids, that comes
+// after the cursor. The next_cursor is set if there are more workspaces.
+#[tracing::instrument(skip(transaction, params, logged_user), err)]
+pub async fn read_workspace_page(
+    transaction: &mut DBTransaction<'_>,
+    params: WorkspaceListParams,
+    logged_user: LoggedUser,
+) -> Result<RepeatedWorkspace, ServerError> {
+    let user_id = logged_user.as_uuid()?;
+    let after = match params.after {
+        None => None,
+        Some(after) => Some(Uuid::parse_str(&after).map_err(invalid_params)?),
+    };
+    let limit = match params.limit {
+        None => MAX_WORKSPACE_PAGE_SIZE,
+        Some(limit) if limit > 0 => limit.min(MAX_WORKSPACE_PAGE_SIZE),
+        Some(limit) => return Err(ServerError::params_invalid().context(format!("Invalid page size: {}", limit))),
+    };
+
+    // One more workspace is read to tell if there is a next page
+    let sql = format!(
+        r#"
+        SELECT * FROM {workspace_table}
+        WHERE (user_id = $1 OR id IN (SELECT workspace_id FROM {member_table} WHERE user_id = $2))
+        AND ($3::uuid IS NULL OR id > $3)
+        ORDER BY id
+        LIMIT $4
+        "#,
+        workspace_table = WORKSPACE_TABLE,
+        member_table = WORKSPACE_MEMBER_TABLE,
+    );
+    let mut tables = sqlx::query_as::<Postgres, WorkspaceTable>(&sql)
+        .bind(user_id.to_string())
+        .bind(user_id)
+        .bind(after)
+        .bind(limit + 1)
         .fetch_all(transaction as &mut DBTransaction<'_>)
         .await
         .map_err(map_sqlx_error)?;
 
+    let next_cursor = match tables.len() as i64 > limit {
+        false => None,
+        true => {
+            tables.truncate(limit as usize);
+            tables.last().map(|table| table.id.to_string())
+        },
+    };
+
+    let mut repeated_workspace = make_repeated_workspace(transaction, tables).await?;
+    if let Some(next_cursor) = next_cursor {
+        repeated_workspace.set_next_cursor(next_cursor);
+    }
+    Ok(repeated_workspace)
+}
+
+async fn make_repeated_workspace(
+    transaction: &mut DBTransaction<'_>,
+    tables: Vec<WorkspaceTable>,
+) -> Result<RepeatedWorkspace, ServerError> {
     let mut repeated_workspace = RepeatedWorkspace::default();
     let mut workspaces = vec![];
     // Opti: combine the query
     for table in tables {
-        let apps = read_workspace_apps(
-            &logged_user,
-            transaction as &mut DBTransaction<'_>,
-            &table.id.to_string(),
-        )
-        .await
-        .context("Get workspace app")
-        .unwrap_or_default();
+        let apps = read_workspace_apps(transaction as &mut DBTransaction<'_>, &table.id.to_string())
+            .await
+            .context("Get workspace app")
+            .unwrap_or_default();
 
         let mut workspace: Workspace = table.into();
         workspace.set_apps(apps);
@@ -111,29 +191,15 @@ pub async fn read_workspaces(
     Ok(repeated_workspace)
 }
 
-#[tracing::instrument(skip(transaction, user), fields(app_count), err)]
+#[tracing::instrument(skip(transaction), fields(app_count), err)]
 async fn read_workspace_apps<'c>(
-    user: &LoggedUser,
     transaction: &mut DBTransaction<'_>,
     workspace_id: &str,
 ) -> Result<RepeatedApp, ServerError> {
     let workspace_id = WorkspaceId::parse(workspace_id.to_owned()).map_err(invalid_params)?;
-    let (sql, args) = SqlBuilder::select("app_table")
-        .add_field("*")
-        .and_where_eq("workspace_id", workspace_id.0)
-        .build()?;
-
-    let app_tables = sqlx::query_as_with::<Postgres, AppTable, PgArguments>(&sql, args)
-        .fetch_all(transaction as &mut DBTransaction<'_>)
-        .await
-        .map_err(map_sqlx_error)?;
-
-    tracing::Span::current().record("app_count", &app_tables.len());
-    let mut apps = vec![];
-    for table in app_tables {
-        let app = read_app(transaction, table.id, user).await?;
-        apps.push(app);
-    }
+    // The nested apps are carried by their parents.
+    let apps = read_app_tree(transaction, workspace_id.as_ref(), None).await?;
+    tracing::Span::current().record("app_count", &apps.len());
 
     let mut repeated_app = RepeatedApp::default();
     repeated_app.set_items(apps.into());
@@ -1,8 +1,10 @@
 use crate::service::ws::WsMessageAdaptor;
 use actix::{Message, Recipient};
+use actix_web_actors::ws::CloseCode;
 use backend_service::errors::ServerError;
 use serde::{Deserialize, Serialize};
-use std::fmt::Formatter;
+use std::{fmt::Formatter, time::Duration};
+use tokio::time::Instant;
 
 pub type Socket = Recipient<WsMessageAdaptor>;
 
@@ -16,13 +18,17 @@ impl<T: AsRef<str>> std::convert::From<T> for SessionId {
 impl std::fmt::Display for SessionId {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let desc = &self.0.to_string();
-        f.write_str(&desc)
+        f.write_str(desc)
     }
 }
 
 pub struct Session {
     pub id: SessionId,
     pub socket: Socket,
+    pub closer: Recipient<CloseSession>,
+    // The authenticated user of the connection, for scoping the broadcasts.
+    pub user_id: String,
+    pub last_heartbeat: Instant,
 }
 
 impl std::convert::From<Connect> for Session {
@@ -30,6 +36,9 @@ impl std::convert::From<Connect> for Session {
         Self {
             id: c.sid,
             socket: c.socket,
+            closer: c.closer,
+            user_id: c.user_id,
+            last_heartbeat: Instant::now(),
         }
     }
 }
@@ -38,7 +47,9 @@ impl std::convert::From<Connect> for Session {
 #[rtype(result = "Result<(), ServerError>")]
 pub struct Connect {
     pub socket: Socket,
+    pub closer: Recipient<CloseSession>,
     pub sid: SessionId,
+    pub user_id: String,
 }
 
 #[derive(Debug, Message, Clone)]
@@ -46,3 +57,71 @@ pub struct Connect {
 pub struct Disconnect {
     pub sid: SessionId,
 }
+
+// Sent by the client on receiving a ping or pong, keeps the session alive.
+#[derive(Debug, Message, Clone)]
+#[rtype(result = "()")]
+pub struct Heartbeat {
+    pub sid: SessionId,
+}
+
+// Closes the sessions without any heartbeat within the idle timeout, returns
+// the ids of the closed ones.
+#[derive(Debug, Message, Clone)]
+#[rtype(result = "Vec<SessionId>")]
+pub struct ReapIdleSessions {
+    pub idle_timeout: Duration,
+}
+
+// Forcibly closes the session, e.g. an admin terminates it.
+#[derive(Debug, Message, Clone)]
+#[rtype(result = "Result<(), ServerError>")]
+pub struct TerminateSession {
+    pub sid: SessionId,
+}
+
+// Closes the sessions of the user, e.g. the user signs out everywhere, returns
+// the number of the closed ones.
+#[derive(Debug, Message, Clone)]
+#[rtype(result = "usize")]
+pub struct CloseUserSessions {
+    pub user_id: String,
+}
+
+// Closes all the sessions when the server shuts down, the clients flush the
+// pending messages before closing their connections.
+#[derive(Debug, Message, Clone)]
+#[rtype(result = "()")]
+pub struct CloseAllSessions;
+
+// Returns the number of the sessions that are not disconnected yet.
+#[derive(Debug, Message, Clone)]
+#[rtype(result = "usize")]
+pub struct SessionCount;
+
+// Registers the session as an editor of the document, so it receives the
+// broadcasts of the document, see DocWsActor. The sessions are unregistered
+// from all their documents when they disconnect.
+#[derive(Debug, Message, Clone)]
+#[rtype(result = "()")]
+pub struct JoinDoc {
+    pub sid: SessionId,
+    pub doc_id: String,
+}
+
+// Sends the message to the sessions editing the document except the excluded
+// one, usually the sender, returns the number of the sessions sent to.
+#[derive(Debug, Message, Clone)]
+#[rtype(result = "usize")]
+pub struct Broadcast {
+    pub doc_id: String,
+    pub message: WsMessageAdaptor,
+    pub exclude: Option<SessionId>,
+}
+
+// Sent to the client to close its connection with the code.
+#[derive(Debug, Message, Clone)]
+#[rtype(result = "()")]
+pub struct CloseSession {
+    pub code: CloseCode,
+}
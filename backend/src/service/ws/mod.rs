@@ -4,7 +4,7 @@ pub use ws_client::*;
 pub use ws_server::*;
 
 mod biz_handler;
-pub(crate) mod entities;
+pub mod entities;
 pub mod router;
 mod ws_client;
 mod ws_server;
@@ -1,30 +1,87 @@
-use crate::service::{
-    user::LoggedUser,
-    ws::{WsBizHandlers, WsClient, WsServer, WsUser},
+use crate::{
+    config::SessionSettings,
+    entities::token::Token,
+    service::{
+        user::{authenticate, LoggedUser},
+        ws::{WsBizHandlers, WsClient, WsServer, WsUser},
+    },
 };
 use actix::Addr;
+use actix_identity::Identity;
 use actix_web::{
     get,
-    web::{Data, Path, Payload},
+    web::{Data, Path, Payload, Query},
     Error,
     HttpRequest,
     HttpResponse,
 };
 use actix_web_actors::ws;
+use backend_service::errors::ServerError;
+use sqlx::PgPool;
 
-#[get("/{token}")]
+#[derive(serde::Deserialize)]
+pub struct WsCredentials {
+    token: Option<String>,
+}
+
+// Authenticates the handshake with the `token` query param, or the identity
+// cookie if there's no such param.
+#[get("")]
+#[allow(clippy::too_many_arguments)]
 pub async fn establish_ws_connection(
+    request: HttpRequest,
+    payload: Payload,
+    credentials: Query<WsCredentials>,
+    id: Identity,
+    pool: Data<PgPool>,
+    server: Data<Addr<WsServer>>,
+    biz_handlers: Data<WsBizHandlers>,
+    settings: Data<SessionSettings>,
+) -> Result<HttpResponse, Error> {
+    let token = credentials.into_inner().token.or_else(|| id.identity());
+    start_ws_connection(request, payload, token, pool, server, biz_handlers, settings).await
+}
+
+// Kept for the clients passing the token in the path.
+#[get("/{token}")]
+pub async fn establish_ws_connection_with_path_token(
     request: HttpRequest,
     payload: Payload,
     token: Path<String>,
+    pool: Data<PgPool>,
     server: Data<Addr<WsServer>>,
     biz_handlers: Data<WsBizHandlers>,
+    settings: Data<SessionSettings>,
+) -> Result<HttpResponse, Error> {
+    start_ws_connection(
+        request,
+        payload,
+        Some(token.into_inner()),
+        pool,
+        server,
+        biz_handlers,
+        settings,
+    )
+    .await
+}
+
+// The connection is upgraded only if the token passes the same checks as the
+// ones of the HTTP requests, see [authenticate], otherwise it's rejected with
+// 401.
+async fn start_ws_connection(
+    request: HttpRequest,
+    payload: Payload,
+    token: Option<String>,
+    pool: Data<PgPool>,
+    server: Data<Addr<WsServer>>,
+    biz_handlers: Data<WsBizHandlers>,
+    settings: Data<SessionSettings>,
 ) -> Result<HttpResponse, Error> {
     tracing::info!("establish_ws_connection");
-    match LoggedUser::from_token(token.clone()) {
+    match authenticate_ws(pool.get_ref(), token).await {
         Ok(user) => {
             let ws_user = WsUser::new(user);
-            let client = WsClient::new(ws_user, server.get_ref().clone(), biz_handlers);
+            let client = WsClient::new(ws_user, server.get_ref().clone(), biz_handlers, &settings);
             let result = ws::start(client, &request, payload);
             match result {
                 Ok(response) => Ok(response),
@@ -34,12 +91,11 @@ pub async fn establish_ws_connection(
                 },
             }
         },
-        Err(e) => {
-            if e.is_unauthorized() {
-                Ok(HttpResponse::Unauthorized().json(e))
-            } else {
-                Ok(HttpResponse::BadRequest().json(e))
-            }
-        },
+        Err(e) => Ok(HttpResponse::Unauthorized().json(e)),
     }
 }
+
+async fn authenticate_ws(pool: &PgPool, token: Option<String>) -> Result<LoggedUser, ServerError> {
+    let token = token.ok_or_else(|| ServerError::unauthorized().context("Can't find any token"))?;
+    authenticate(pool, &Token(token)).await
+}
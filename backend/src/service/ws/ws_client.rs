@@ -1,9 +1,9 @@
 use crate::{
-    config::{HEARTBEAT_INTERVAL, PING_TIMEOUT},
+    config::SessionSettings,
     service::{
         user::LoggedUser,
         ws::{
-            entities::{Connect, Disconnect, Socket},
+            entities::{CloseSession, Connect, Disconnect, Heartbeat, SessionId, Socket},
             WsBizHandlers,
             WsMessageAdaptor,
             WsServer,
@@ -12,20 +12,40 @@ use crate::{
 };
 use actix::*;
 use actix_web::web::Data;
-use actix_web_actors::{ws, ws::Message::Text};
+use actix_web_actors::{
+    ws,
+    ws::{CloseCode, Message::Text},
+};
 use bytes::Bytes;
 use lib_ws::WsMessage;
-use std::{convert::TryFrom, sync::Arc, time::Instant};
+use std::{
+    convert::TryFrom,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use uuid::Uuid;
 
+// The user of the connection. Each connection gets its own session id, so the
+// connections of the same user are told apart.
 #[derive(Debug)]
 pub struct WsUser {
     inner: LoggedUser,
+    session_id: SessionId,
 }
 
 impl WsUser {
-    pub fn new(inner: LoggedUser) -> Self { Self { inner } }
+    pub fn new(inner: LoggedUser) -> Self {
+        Self {
+            inner,
+            session_id: Uuid::new_v4().to_string().into(),
+        }
+    }
 
     pub fn id(&self) -> &str { &self.inner.user_id }
+
+    pub fn session_id(&self) -> &SessionId { &self.session_id }
+
+    pub fn logged_user(&self) -> &LoggedUser { &self.inner }
 }
 
 pub struct WsClientData {
@@ -39,24 +59,41 @@ pub struct WsClient {
     server: Addr<WsServer>,
     biz_handlers: Data<WsBizHandlers>,
     hb: Instant,
+    heartbeat_interval: Duration,
+    heartbeat_timeout: Duration,
 }
 
 impl WsClient {
-    pub fn new(user: WsUser, server: Addr<WsServer>, biz_handlers: Data<WsBizHandlers>) -> Self {
+    pub fn new(
+        user: WsUser,
+        server: Addr<WsServer>,
+        biz_handlers: Data<WsBizHandlers>,
+        settings: &SessionSettings,
+    ) -> Self {
         Self {
             user: Arc::new(user),
             server,
             biz_handlers,
             hb: Instant::now(),
+            heartbeat_interval: Duration::from_secs(settings.heartbeat_interval_secs),
+            heartbeat_timeout: Duration::from_secs(settings.heartbeat_timeout_secs),
         }
     }
 
+    // Pings the client every heartbeat interval, and closes the connection if
+    // no frame is received within the heartbeat timeout.
     fn hb(&self, ctx: &mut ws::WebsocketContext<Self>) {
-        ctx.run_interval(HEARTBEAT_INTERVAL, |client, ctx| {
-            if Instant::now().duration_since(client.hb) > PING_TIMEOUT {
+        ctx.run_interval(self.heartbeat_interval, |client, ctx| {
+            if Instant::now().duration_since(client.hb) > client.heartbeat_timeout {
+                tracing::debug!(
+                    "[{}]: No heartbeat within {:?}",
+                    client.user.id(),
+                    client.heartbeat_timeout
+                );
                 client.server.do_send(Disconnect {
-                    sid: client.user.id().into(),
+                    sid: client.user.session_id().clone(),
                 });
+                ctx.close(Some(CloseCode::Away.into()));
                 ctx.stop();
             } else {
                 ctx.ping(b"");
@@ -64,9 +101,23 @@ impl WsClient {
         });
     }
 
+    fn heartbeat(&mut self) {
+        self.hb = Instant::now();
+        self.server.do_send(Heartbeat {
+            sid: self.user.session_id().clone(),
+        });
+    }
+
+    // The malformed frames are dropped, the connection stays open for the
+    // following ones.
     fn handle_binary_message(&self, bytes: Bytes, socket: Socket) {
-        // TODO: ok to unwrap?
-        let message: WsMessage = WsMessage::try_from(bytes).unwrap();
+        let message = match WsMessage::try_from(bytes) {
+            Ok(message) => message,
+            Err(e) => {
+                log::error!("[{}]: Parse the binary message failed: {:?}", self.user.id(), e);
+                return;
+            },
+        };
         match self.biz_handlers.get(&message.module) {
             None => {
                 log::error!("Can't find the handler for {:?}", message.module);
@@ -85,14 +136,17 @@ impl WsClient {
 
 impl StreamHandler<Result<ws::Message, ws::ProtocolError>> for WsClient {
     fn handle(&mut self, msg: Result<ws::Message, ws::ProtocolError>, ctx: &mut Self::Context) {
+        // Any frame from the client shows the connection is alive.
+        if msg.is_ok() {
+            self.heartbeat();
+        }
         match msg {
             Ok(ws::Message::Ping(msg)) => {
-                self.hb = Instant::now();
                 ctx.pong(&msg);
             },
             Ok(ws::Message::Pong(_msg)) => {
-                // tracing::debug!("Receive {} pong {:?}", &self.session_id, &msg);
-                self.hb = Instant::now();
+                // tracing::debug!("Receive {} pong {:?}", &self.session_id,
+                // &msg);
             },
             Ok(ws::Message::Binary(bytes)) => {
                 let socket = ctx.address().recipient();
@@ -121,15 +175,27 @@ impl Handler<WsMessageAdaptor> for WsClient {
     fn handle(&mut self, msg: WsMessageAdaptor, ctx: &mut Self::Context) { ctx.binary(msg.0); }
 }
 
+impl Handler<CloseSession> for WsClient {
+    type Result = ();
+
+    fn handle(&mut self, msg: CloseSession, ctx: &mut Self::Context) {
+        ctx.close(Some(msg.code.into()));
+        ctx.stop();
+    }
+}
+
 impl Actor for WsClient {
     type Context = ws::WebsocketContext<Self>;
 
     fn started(&mut self, ctx: &mut Self::Context) {
         self.hb(ctx);
         let socket = ctx.address().recipient();
+        let closer = ctx.address().recipient();
         let connect = Connect {
             socket,
-            sid: self.user.id().into(),
+            closer,
+            sid: self.user.session_id().clone(),
+            user_id: self.user.id().to_owned(),
         };
         self.server
             .send(connect)
@@ -147,7 +213,7 @@ impl Actor for WsClient {
 
     fn stopping(&mut self, _: &mut Self::Context) -> Running {
         self.server.do_send(Disconnect {
-            sid: self.user.id().into(),
+            sid: self.user.session_id().clone(),
         });
 
         Running::Stop
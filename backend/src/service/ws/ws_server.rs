@@ -1,19 +1,42 @@
 use crate::service::ws::{
-    entities::{Connect, Disconnect, Session, SessionId},
+    entities::{
+        Broadcast,
+        CloseAllSessions,
+        CloseSession,
+        CloseUserSessions,
+        Connect,
+        Disconnect,
+        Heartbeat,
+        JoinDoc,
+        ReapIdleSessions,
+        Session,
+        SessionCount,
+        SessionId,
+        TerminateSession,
+    },
     WsMessageAdaptor,
 };
 use actix::{Actor, Context, Handler};
-use backend_service::errors::ServerError;
+use actix_web_actors::ws::CloseCode;
+use backend_service::errors::{internal_error, ServerError};
 use dashmap::DashMap;
+use std::collections::HashSet;
+use tokio::time::Instant;
 
 pub struct WsServer {
     sessions: DashMap<SessionId, Session>,
+    // The sessions of each user, see CloseUserSessions.
+    user_sessions: DashMap<String, HashSet<SessionId>>,
+    // The sessions editing each document, see JoinDoc.
+    doc_sessions: DashMap<String, HashSet<SessionId>>,
 }
 
 impl std::default::Default for WsServer {
     fn default() -> Self {
         Self {
             sessions: DashMap::new(),
+            user_sessions: DashMap::new(),
+            doc_sessions: DashMap::new(),
         }
     }
 }
@@ -21,6 +44,22 @@ impl WsServer {
     pub fn new() -> Self { WsServer::default() }
 
     pub fn send(&self, _msg: WsMessageAdaptor) { unimplemented!() }
+
+    // Removes the session and unregisters it from its user and the documents it
+    // edits.
+    fn remove_session(&self, sid: &SessionId) -> Option<Session> {
+        self.doc_sessions.retain(|_, sids| {
+            sids.remove(sid);
+            !sids.is_empty()
+        });
+        let (_, session) = self.sessions.remove(sid)?;
+        if let Some(mut sids) = self.user_sessions.get_mut(&session.user_id) {
+            sids.remove(sid);
+        }
+        self.user_sessions
+            .remove_if(&session.user_id, |_, sids| sids.is_empty());
+        Some(session)
+    }
 }
 
 impl Actor for WsServer {
@@ -32,6 +71,10 @@ impl Handler<Connect> for WsServer {
     type Result = Result<(), ServerError>;
     fn handle(&mut self, msg: Connect, _ctx: &mut Context<Self>) -> Self::Result {
         let session: Session = msg.into();
+        self.user_sessions
+            .entry(session.user_id.clone())
+            .or_insert_with(HashSet::new)
+            .insert(session.id.clone());
         self.sessions.insert(session.id.clone(), session);
 
         Ok(())
@@ -41,11 +84,123 @@ impl Handler<Connect> for WsServer {
 impl Handler<Disconnect> for WsServer {
     type Result = Result<(), ServerError>;
     fn handle(&mut self, msg: Disconnect, _: &mut Context<Self>) -> Self::Result {
-        self.sessions.remove(&msg.sid);
+        let _ = self.remove_session(&msg.sid);
         Ok(())
     }
 }
 
+impl Handler<Heartbeat> for WsServer {
+    type Result = ();
+    fn handle(&mut self, msg: Heartbeat, _: &mut Context<Self>) -> Self::Result {
+        if let Some(mut session) = self.sessions.get_mut(&msg.sid) {
+            session.last_heartbeat = Instant::now();
+        }
+    }
+}
+
+impl Handler<ReapIdleSessions> for WsServer {
+    type Result = Vec<SessionId>;
+    fn handle(&mut self, msg: ReapIdleSessions, _: &mut Context<Self>) -> Self::Result {
+        let idle_sids = self
+            .sessions
+            .iter()
+            .filter(|session| session.last_heartbeat.elapsed() > msg.idle_timeout)
+            .map(|session| session.id.clone())
+            .collect::<Vec<SessionId>>();
+
+        for sid in &idle_sids {
+            if let Some(session) = self.remove_session(sid) {
+                tracing::debug!("Reap the idle session {}", sid);
+                let _ = session.closer.do_send(CloseSession { code: CloseCode::Away });
+            }
+        }
+        idle_sids
+    }
+}
+
+impl Handler<TerminateSession> for WsServer {
+    type Result = Result<(), ServerError>;
+    fn handle(&mut self, msg: TerminateSession, _: &mut Context<Self>) -> Self::Result {
+        match self.remove_session(&msg.sid) {
+            None => Err(ServerError::record_not_found().context(format!("Session {} not found", msg.sid))),
+            Some(session) => session
+                .closer
+                .do_send(CloseSession {
+                    code: CloseCode::Policy,
+                })
+                .map_err(internal_error),
+        }
+    }
+}
+
+impl Handler<CloseUserSessions> for WsServer {
+    type Result = usize;
+    fn handle(&mut self, msg: CloseUserSessions, _: &mut Context<Self>) -> Self::Result {
+        let sids = match self.user_sessions.get(&msg.user_id) {
+            None => return 0,
+            Some(sids) => sids.iter().cloned().collect::<Vec<SessionId>>(),
+        };
+
+        for sid in &sids {
+            if let Some(session) = self.remove_session(sid) {
+                let _ = session.closer.do_send(CloseSession {
+                    code: CloseCode::Policy,
+                });
+            }
+        }
+        sids.len()
+    }
+}
+
+impl Handler<CloseAllSessions> for WsServer {
+    type Result = ();
+    fn handle(&mut self, _: CloseAllSessions, _: &mut Context<Self>) -> Self::Result {
+        // The sessions are removed once the clients disconnect, see SessionCount.
+        for session in self.sessions.iter() {
+            if let Err(e) = session.closer.do_send(CloseSession { code: CloseCode::Away }) {
+                log::error!("Close session {} failed: {:?}", session.id, e);
+            }
+        }
+    }
+}
+
+impl Handler<JoinDoc> for WsServer {
+    type Result = ();
+    fn handle(&mut self, msg: JoinDoc, _: &mut Context<Self>) -> Self::Result {
+        if self.sessions.contains_key(&msg.sid) {
+            self.doc_sessions
+                .entry(msg.doc_id)
+                .or_insert_with(HashSet::new)
+                .insert(msg.sid);
+        }
+    }
+}
+
+impl Handler<Broadcast> for WsServer {
+    type Result = usize;
+    fn handle(&mut self, msg: Broadcast, _: &mut Context<Self>) -> Self::Result {
+        let sids = match self.doc_sessions.get(&msg.doc_id) {
+            None => return 0,
+            Some(sids) => sids.clone(),
+        };
+        let mut count = 0;
+        for sid in sids.iter().filter(|sid| Some(*sid) != msg.exclude.as_ref()) {
+            if let Some(session) = self.sessions.get(sid) {
+                match session.socket.do_send(msg.message.clone()) {
+                    Ok(_) => count += 1,
+                    Err(e) => log::error!("Broadcast to session {} failed: {:?}", sid, e),
+                }
+            }
+        }
+        count
+    }
+}
+
+impl Handler<SessionCount> for WsServer {
+    type Result = usize;
+    fn handle(&mut self, _: SessionCount, _: &mut Context<Self>) -> Self::Result { self.sessions.len() }
+}
+
 impl Handler<WsMessageAdaptor> for WsServer {
     type Result = ();
 
@@ -1,5 +1,5 @@
 use crate::util::helper::{spawn_user_server, TestUserServer};
-use backend_service::errors::ErrorCode;
+use backend_service::{errors::ErrorCode, user_request::get_user_profile_request};
 use flowy_user_infra::entities::{SignInParams, SignUpParams, SignUpResponse, UpdateUserParams};
 
 #[actix_rt::test]
@@ -51,6 +51,51 @@ async fn user_sign_out() {
     server.get_user_profile().await;
 }
 
+#[actix_rt::test]
+async fn user_logout_all() {
+    let server = TestUserServer::new().await;
+    let first_token = sign_in(&server).await;
+    let second_token = sign_in(&server).await;
+    let _ = server.logout_all(&first_token).await.unwrap();
+
+    let url = format!("{}/api/user", server.http_addr());
+    for token in &[server.user_token(), &first_token, &second_token] {
+        let error = get_user_profile_request(token, &url).await.unwrap_err();
+        assert_eq!(error.code, ErrorCode::UserUnauthorized);
+    }
+
+    // The tokens issued after signing out everywhere are valid.
+    let token = sign_in(&server).await;
+    let _ = get_user_profile_request(&token, &url).await.unwrap();
+}
+
+#[actix_rt::test]
+async fn user_logout_all_by_another_server() {
+    let server = TestUserServer::new().await;
+    let token = sign_in(&server).await;
+    let url = format!("{}/api/user", server.http_addr());
+    let _ = get_user_profile_request(&token, &url).await.unwrap();
+
+    // The epoch increased by another server is read from the database
+    let _ = sqlx::query("UPDATE user_table SET token_epoch = token_epoch + 1 WHERE id = $1")
+        .bind(uuid::Uuid::parse_str(server.user_id()).unwrap())
+        .execute(&server.pg_pool)
+        .await
+        .unwrap();
+    let error = get_user_profile_request(&token, &url).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+}
+
+#[actix_rt::test]
+async fn user_logout_all_with_invalid_token() {
+    let server = TestUserServer::new().await;
+    let error = server.logout_all("invalid token").await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+
+    // The user is still signed in.
+    let _ = server.get_user_profile().await;
+}
+
 #[actix_rt::test]
 async fn user_get_detail() {
     let server = TestUserServer::new().await;
@@ -88,7 +133,7 @@ async fn user_update_name() {
     let server = TestUserServer::new().await;
 
     let name = "tom".to_string();
-    let params = UpdateUserParams::new(&server.user_id()).name(&name);
+    let params = UpdateUserParams::new(server.user_id()).name(&name);
     server.update_user_profile(params).await.unwrap();
 
     let user = server.get_user_profile().await;
@@ -106,6 +151,15 @@ async fn user_update_email() {
     assert_eq!(user.email, email);
 }
 
+async fn sign_in(server: &TestUserServer) -> String {
+    let params = SignInParams {
+        email: "annie@appflowy.io".to_string(),
+        password: "HelloAppFlowy123!".to_string(),
+        name: "annie".to_string(),
+    };
+    server.sign_in(params).await.unwrap().token
+}
+
 #[allow(dead_code)]
 async fn sign_up_user(server: &TestUserServer) -> SignUpResponse {
     let email = "annie@appflowy.io";
@@ -0,0 +1,149 @@
+use actix_web::{
+    http::{
+        header::{
+            ACCESS_CONTROL_ALLOW_HEADERS,
+            ACCESS_CONTROL_ALLOW_ORIGIN,
+            ACCESS_CONTROL_REQUEST_HEADERS,
+            ACCESS_CONTROL_REQUEST_METHOD,
+            ORIGIN,
+        },
+        StatusCode,
+    },
+    test,
+    web,
+    App,
+    HttpResponse,
+};
+use backend::{config::CorsSettings, middleware::cors};
+
+async fn ok_handler() -> HttpResponse { HttpResponse::Ok().finish() }
+
+fn cors_settings() -> CorsSettings {
+    CorsSettings {
+        allowed_origins: vec!["https://appflowy.io".to_owned()],
+        allowed_methods: vec!["GET".to_owned(), "POST".to_owned()],
+        allow_credentials: true,
+    }
+}
+
+#[actix_rt::test]
+async fn cors_allowed_origin() {
+    let app = test::init_service(
+        App::new()
+            .wrap(cors(&cors_settings()))
+            .route("/api/user", web::get().to(ok_handler)),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/api/user")
+        .insert_header((ORIGIN, "https://appflowy.io"))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        resp.headers()
+            .get(ACCESS_CONTROL_ALLOW_ORIGIN)
+            .unwrap()
+            .to_str()
+            .unwrap(),
+        "https://appflowy.io"
+    );
+
+    let req = test::TestRequest::default()
+        .method(actix_web::http::Method::OPTIONS)
+        .uri("/api/user")
+        .insert_header((ORIGIN, "https://appflowy.io"))
+        .insert_header((ACCESS_CONTROL_REQUEST_METHOD, "POST"))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        resp.headers()
+            .get(ACCESS_CONTROL_ALLOW_ORIGIN)
+            .unwrap()
+            .to_str()
+            .unwrap(),
+        "https://appflowy.io"
+    );
+}
+
+#[actix_rt::test]
+async fn cors_disallowed_origin() {
+    let app = test::init_service(
+        App::new()
+            .wrap(cors(&cors_settings()))
+            .route("/api/user", web::get().to(ok_handler)),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .uri("/api/user")
+        .insert_header((ORIGIN, "https://unknown.io"))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.headers().contains_key(ACCESS_CONTROL_ALLOW_ORIGIN), false);
+
+    let req = test::TestRequest::default()
+        .method(actix_web::http::Method::OPTIONS)
+        .uri("/api/user")
+        .insert_header((ORIGIN, "https://unknown.io"))
+        .insert_header((ACCESS_CONTROL_REQUEST_METHOD, "POST"))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.headers().contains_key(ACCESS_CONTROL_ALLOW_ORIGIN), false);
+
+    // The preflight request of a method that's not configured is rejected too
+    let req = test::TestRequest::default()
+        .method(actix_web::http::Method::OPTIONS)
+        .uri("/api/user")
+        .insert_header((ORIGIN, "https://appflowy.io"))
+        .insert_header((ACCESS_CONTROL_REQUEST_METHOD, "DELETE"))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.headers().contains_key(ACCESS_CONTROL_ALLOW_ORIGIN), false);
+}
+
+#[actix_rt::test]
+async fn cors_allowed_auth_headers() {
+    let app = test::init_service(
+        App::new()
+            .wrap(cors(&cors_settings()))
+            .route("/api/user", web::get().to(ok_handler)),
+    )
+    .await;
+
+    let req = test::TestRequest::default()
+        .method(actix_web::http::Method::OPTIONS)
+        .uri("/api/user")
+        .insert_header((ORIGIN, "https://appflowy.io"))
+        .insert_header((ACCESS_CONTROL_REQUEST_METHOD, "GET"))
+        .insert_header((ACCESS_CONTROL_REQUEST_HEADERS, "token, authorization"))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let allowed_headers = resp
+        .headers()
+        .get(ACCESS_CONTROL_ALLOW_HEADERS)
+        .unwrap()
+        .to_str()
+        .unwrap();
+    assert_eq!(allowed_headers.contains("token"), true);
+    assert_eq!(allowed_headers.contains("authorization"), true);
+}
+
+#[actix_rt::test]
+async fn cors_wildcard_origin_with_credentials() {
+    let settings = CorsSettings {
+        allowed_origins: vec!["*".to_owned()],
+        ..cors_settings()
+    };
+    assert_eq!(settings.validate().is_err(), true);
+
+    let settings = CorsSettings {
+        allow_credentials: false,
+        ..settings
+    };
+    assert_eq!(settings.validate().is_ok(), true);
+    assert_eq!(cors_settings().validate().is_ok(), true);
+}
@@ -0,0 +1,40 @@
+use backend::{
+    application::get_connection_pool,
+    config::{get_configuration, DatabaseSettings},
+};
+
+fn database_settings() -> DatabaseSettings {
+    let mut settings = get_configuration().expect("Failed to read configuration.").database;
+    // Always exists, the pool doesn't need the database of the server.
+    settings.database_name = "postgres".to_owned();
+    settings.max_connections = 3;
+    settings.min_connections = 2;
+    settings.acquire_timeout_secs = 1;
+    settings
+}
+
+#[actix_rt::test]
+async fn connection_pool_min_connections() {
+    let pool = get_connection_pool(&database_settings()).await.unwrap();
+    assert_eq!(pool.size() >= 2, true);
+}
+
+#[actix_rt::test]
+async fn connection_pool_max_connections() {
+    let pool = get_connection_pool(&database_settings()).await.unwrap();
+    let mut connections = vec![];
+    for _ in 0..3 {
+        connections.push(pool.acquire().await.unwrap());
+    }
+    assert_eq!(pool.size(), 3);
+
+    // All the connections are in use, acquiring one more times out.
+    match pool.acquire().await {
+        Err(sqlx::Error::PoolTimedOut) => {},
+        Err(e) => panic!("Unexpected error: {:?}", e),
+        Ok(_) => panic!("The pool exceeds the max_connections"),
+    }
+
+    drop(connections);
+    let _ = pool.acquire().await.unwrap();
+}
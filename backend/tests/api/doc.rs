@@ -1,5 +1,6 @@
 use crate::util::helper::ViewTest;
-use flowy_document_infra::entities::doc::DocIdentifier;
+use backend::service::doc::crud::{repair_docs_trailing_newline, update_doc};
+use flowy_document_infra::{entities::doc::DocIdentifier, protobuf::UpdateDocParams};
 use flowy_workspace_infra::entities::view::ViewIdentifiers;
 
 #[actix_rt::test]
@@ -20,7 +21,7 @@ async fn doc_delete() {
     let delete_params = ViewIdentifiers {
         view_ids: vec![test.view.id.clone()],
     };
-    test.server.delete_view(delete_params).await;
+    test.server.delete_view_permanently(delete_params).await;
 
     let params = DocIdentifier {
         doc_id: test.view.id.clone(),
@@ -28,3 +29,154 @@ async fn doc_delete() {
     let doc = test.server.read_doc(params).await;
     assert_eq!(doc.is_none(), true);
 }
+
+#[actix_rt::test]
+async fn doc_repair_trailing_newline() {
+    let test = ViewTest::new().await;
+    let params = DocIdentifier {
+        doc_id: test.view.id.clone(),
+    };
+    let doc = test.server.read_doc(params.clone()).await.unwrap();
+
+    let mut update_params = UpdateDocParams::new();
+    update_params.set_doc_id(test.view.id.clone());
+    update_params.set_data(r#"[{"insert":"123"},{"insert":"456","attributes":{"bold":true}}]"#.to_owned());
+    update_params.set_rev_id(doc.rev_id);
+    update_doc(&test.server.pg_pool, update_params).await.unwrap();
+
+    let repaired_doc_ids = repair_docs_trailing_newline(&test.server.pg_pool).await.unwrap();
+    assert_eq!(repaired_doc_ids.contains(&test.view.id), true);
+
+    let repaired_doc = test.server.read_doc(params.clone()).await.unwrap();
+    assert_eq!(
+        repaired_doc.data,
+        r#"[{"insert":"123"},{"insert":"456","attributes":{"bold":true}},{"insert":"\n"}]"#
+    );
+    assert_eq!(repaired_doc.rev_id, doc.rev_id + 1);
+
+    // Repairing again changes nothing
+    let repaired_doc_ids = repair_docs_trailing_newline(&test.server.pg_pool).await.unwrap();
+    assert_eq!(repaired_doc_ids.contains(&test.view.id), false);
+    assert_eq!(test.server.read_doc(params).await.unwrap(), repaired_doc);
+}
+
+#[actix_rt::test]
+async fn doc_stats() {
+    let test = ViewTest::new().await;
+    let params = DocIdentifier {
+        doc_id: test.view.id.clone(),
+    };
+    let doc = test.server.read_doc(params).await.unwrap();
+    let stats = test.server.read_view_stats(&test.view.id).await;
+
+    let mut update_params = UpdateDocParams::new();
+    update_params.set_doc_id(test.view.id.clone());
+    update_params.set_data(
+        r#"[{"insert":"Hello wor"},{"insert":"ld","attributes":{"bold":true}},{"insert":"\nfoo bar baz\n"}]"#
+            .to_owned(),
+    );
+    update_params.set_rev_id(doc.rev_id + 1);
+    update_doc(&test.server.pg_pool, update_params).await.unwrap();
+
+    let updated_stats = test.server.read_view_stats(&test.view.id).await;
+    assert_eq!(updated_stats.doc_id, test.view.id);
+    assert_eq!(updated_stats.word_count, 5);
+    assert_eq!(updated_stats.char_count, 22);
+    assert_eq!(updated_stats.line_count, 2);
+    assert_eq!(updated_stats.rev_count, doc.rev_id + 1);
+    assert!(updated_stats.modified_time >= stats.modified_time);
+}
+
+#[actix_rt::test]
+async fn doc_sessions_read_without_admin() {
+    let test = ViewTest::new().await;
+    let error = test.server.read_doc_sessions(&test.view.id).await.unwrap_err();
+    assert_eq!(error.is_permission_denied(), true);
+}
+
+#[actix_rt::test]
+async fn doc_session_terminate_without_admin() {
+    let test = ViewTest::new().await;
+    let user_id = test.server.user_id().to_owned();
+    let error = test
+        .server
+        .terminate_doc_session(&test.view.id, &user_id)
+        .await
+        .unwrap_err();
+    assert_eq!(error.is_permission_denied(), true);
+}
+
+#[actix_rt::test]
+async fn doc_sessions_read_with_admin() {
+    let test = ViewTest::new().await;
+    test.server.promote_to_admin().await;
+
+    // The doc isn't opened by anyone
+    let sessions = test.server.read_doc_sessions(&test.view.id).await.unwrap();
+    assert_eq!(sessions.items.is_empty(), true);
+
+    let user_id = test.server.user_id().to_owned();
+    let error = test
+        .server
+        .terminate_doc_session(&test.view.id, &user_id)
+        .await
+        .unwrap_err();
+    assert_eq!(error.is_record_not_found(), true);
+}
+
+#[actix_rt::test]
+async fn doc_named_version() {
+    let test = ViewTest::new().await;
+    let params = DocIdentifier {
+        doc_id: test.view.id.clone(),
+    };
+    let doc = test.server.read_doc(params.clone()).await.unwrap();
+    let version = test.server.create_named_version(&test.view.id, "v1.0").await.unwrap();
+    assert_eq!(version.doc_id, test.view.id);
+    assert_eq!(version.rev_id, doc.rev_id);
+    assert_eq!(version.name, "v1.0");
+    assert_eq!(version.created_by, test.server.user_id());
+
+    // The document moves on, but the version keeps the pinned revision
+    let mut update_params = UpdateDocParams::new();
+    update_params.set_doc_id(test.view.id.clone());
+    update_params.set_data(r#"[{"insert":"123\n"}]"#.to_owned());
+    update_params.set_rev_id(doc.rev_id + 1);
+    update_doc(&test.server.pg_pool, update_params).await.unwrap();
+
+    let version_doc = test
+        .server
+        .read_named_version_doc(&test.view.id, &version.id)
+        .await
+        .unwrap();
+    assert_eq!(version_doc.id, test.view.id);
+    assert_eq!(version_doc.data, doc.data);
+    assert_eq!(version_doc.rev_id, doc.rev_id);
+    assert_eq!(test.server.read_doc(params).await.unwrap().rev_id, doc.rev_id + 1);
+
+    let versions = test.server.read_named_versions(&test.view.id).await;
+    assert_eq!(versions.items.len(), 1);
+    assert_eq!(versions.items[0].id, version.id);
+
+    test.server
+        .delete_named_version(&test.view.id, &version.id)
+        .await
+        .unwrap();
+    assert_eq!(
+        test.server.read_named_versions(&test.view.id).await.items.is_empty(),
+        true
+    );
+    assert!(test
+        .server
+        .read_named_version_doc(&test.view.id, &version.id)
+        .await
+        .is_err());
+}
+
+#[actix_rt::test]
+async fn doc_named_version_with_duplicate_name() {
+    let test = ViewTest::new().await;
+    let _ = test.server.create_named_version(&test.view.id, "v1.0").await.unwrap();
+    assert!(test.server.create_named_version(&test.view.id, "v1.0").await.is_err());
+    assert!(test.server.create_named_version(&test.view.id, " ").await.is_err());
+}
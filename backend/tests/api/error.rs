@@ -0,0 +1,76 @@
+use crate::util::helper::TestUserServer;
+use backend_service::{
+    config::HEADER_TOKEN,
+    errors::{ErrorCode, ServerError},
+};
+use bytes::Bytes;
+use flowy_user_infra::entities::SignInParams;
+use flowy_workspace_infra::entities::prelude::*;
+use std::convert::TryInto;
+
+async fn post(url: &str, token: Option<&str>, body: Bytes) -> (reqwest::StatusCode, ServerError) {
+    let mut builder = reqwest::Client::new().post(url).body(body);
+    if let Some(token) = token {
+        builder = builder.header(HEADER_TOKEN, token);
+    }
+    let response = builder.send().await.unwrap();
+    let status = response.status();
+    let error: ServerError = serde_json::from_slice(&response.bytes().await.unwrap()).unwrap();
+    (status, error)
+}
+
+#[actix_rt::test]
+async fn error_sign_in_with_wrong_password() {
+    let server = TestUserServer::new().await;
+    let params = SignInParams {
+        email: "annie@appflowy.io".to_string(),
+        password: "WrongPassword123!".to_string(),
+        name: "annie".to_string(),
+    };
+    let url = format!("{}/api/auth", server.http_addr());
+    let (status, error) = post(&url, None, params.try_into().unwrap()).await;
+    assert_eq!(status, reqwest::StatusCode::UNAUTHORIZED);
+    assert_eq!(error.code, ErrorCode::PasswordNotMatch);
+}
+
+#[actix_rt::test]
+async fn error_sign_in_with_unknown_email() {
+    let server = TestUserServer::new().await;
+    let params = SignInParams {
+        email: "nobody@appflowy.io".to_string(),
+        password: "HelloAppFlowy123!".to_string(),
+        name: "nobody".to_string(),
+    };
+    let url = format!("{}/api/auth", server.http_addr());
+    let (status, error) = post(&url, None, params.try_into().unwrap()).await;
+    // The same error as a wrong password, so the registered emails aren't leaked
+    assert_eq!(status, reqwest::StatusCode::UNAUTHORIZED);
+    assert_eq!(error.code, ErrorCode::PasswordNotMatch);
+}
+
+#[actix_rt::test]
+async fn error_create_app_in_missing_workspace() {
+    let server = TestUserServer::new().await;
+    let params = CreateAppParams {
+        workspace_id: uuid::Uuid::new_v4().to_string(),
+        name: "My first app".to_string(),
+        desc: "This is my first app".to_string(),
+        color_style: ColorStyle::default(),
+        parent_id: None,
+    };
+    let url = format!("{}/api/app", server.http_addr());
+    let (status, error) = post(&url, Some(server.user_token()), params.try_into().unwrap()).await;
+    assert_eq!(status, reqwest::StatusCode::NOT_FOUND);
+    assert_eq!(error.is_record_not_found(), true);
+}
+
+#[actix_rt::test]
+async fn error_malformed_payload() {
+    let server = TestUserServer::new().await;
+    let url = format!("{}/api/app", server.http_addr());
+    let body = Bytes::from_static(&[0xff, 0xff, 0xff]);
+    let (status, error) = post(&url, Some(server.user_token()), body).await;
+    assert_eq!(status, reqwest::StatusCode::BAD_REQUEST);
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+    assert_eq!(error.msg.is_empty(), false);
+}
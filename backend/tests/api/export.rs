@@ -0,0 +1,85 @@
+use actix_web::{http::StatusCode, test, web, App, HttpResponse};
+use backend::{config::ExportSettings, service::export::ExportLimiter};
+use backend_service::errors::ServerError;
+use futures::future::join_all;
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+#[derive(Default)]
+struct Running {
+    current: AtomicUsize,
+    max: AtomicUsize,
+}
+
+async fn export_handler(
+    limiter: web::Data<ExportLimiter>,
+    running: web::Data<Arc<Running>>,
+) -> Result<HttpResponse, ServerError> {
+    let _permit = limiter.acquire().await?;
+    let current = running.current.fetch_add(1, Ordering::SeqCst) + 1;
+    running.max.fetch_max(current, Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    running.current.fetch_sub(1, Ordering::SeqCst);
+    Ok(HttpResponse::Ok().finish())
+}
+
+async fn fire_exports(settings: ExportSettings, count: usize) -> (Vec<StatusCode>, usize) {
+    let running = Arc::new(Running::default());
+    let app = test::init_service(
+        App::new()
+            .app_data(web::Data::new(ExportLimiter::new(&settings)))
+            .app_data(web::Data::new(running.clone()))
+            .route("/export", web::get().to(export_handler)),
+    )
+    .await;
+
+    let responses = join_all((0..count).map(|_| {
+        let req = test::TestRequest::get().uri("/export").to_request();
+        test::call_service(&app, req)
+    }))
+    .await;
+    let mut statuses = vec![];
+    for response in responses {
+        let status = response.status();
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            let error: ServerError = test::read_body_json(response).await;
+            assert_eq!(error.is_too_many_requests(), true);
+        }
+        statuses.push(status);
+    }
+    (statuses, running.max.load(Ordering::SeqCst))
+}
+
+#[actix_rt::test]
+async fn export_beyond_limit_rejected() {
+    let settings = ExportSettings {
+        max_concurrent_exports: Some(2),
+        queue_timeout_ms: Some(50),
+    };
+    let (statuses, max_running) = fire_exports(settings, 5).await;
+    assert_eq!(statuses.iter().filter(|status| **status == StatusCode::OK).count(), 2);
+    assert_eq!(
+        statuses
+            .iter()
+            .filter(|status| **status == StatusCode::TOO_MANY_REQUESTS)
+            .count(),
+        3
+    );
+    assert_eq!(max_running, 2);
+}
+
+#[actix_rt::test]
+async fn export_beyond_limit_queued() {
+    let settings = ExportSettings {
+        max_concurrent_exports: Some(2),
+        queue_timeout_ms: None,
+    };
+    let (statuses, max_running) = fire_exports(settings, 5).await;
+    assert_eq!(statuses.iter().all(|status| *status == StatusCode::OK), true);
+    assert_eq!(max_running, 2);
+}
@@ -0,0 +1,41 @@
+use actix_web::{http::StatusCode, test, web, App, HttpResponse};
+use backend::application::json_config;
+use backend_service::errors::ServerError;
+use serde_json::{json, Value};
+
+async fn echo_handler(body: web::Json<Value>) -> HttpResponse { HttpResponse::Ok().json(body.into_inner()) }
+
+#[actix_rt::test]
+async fn json_body_within_limit() {
+    let app = test::init_service(
+        App::new()
+            .app_data(json_config(64))
+            .route("/echo", web::post().to(echo_handler)),
+    )
+    .await;
+
+    let body = json!({ "name": "AppFlowy" });
+    let req = test::TestRequest::post().uri("/echo").set_json(&body).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let echo: Value = test::read_body_json(resp).await;
+    assert_eq!(echo, body);
+}
+
+#[actix_rt::test]
+async fn json_body_over_limit() {
+    let app = test::init_service(
+        App::new()
+            .app_data(json_config(64))
+            .route("/echo", web::post().to(echo_handler)),
+    )
+    .await;
+
+    let body = json!({ "name": "a".repeat(128) });
+    let req = test::TestRequest::post().uri("/echo").set_json(&body).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    let error: ServerError = test::read_body_json(resp).await;
+    assert_eq!(error.is_payload_overflow(), true);
+    assert_eq!(error.msg.contains("exceeds the limit of 64 bytes"), true);
+}
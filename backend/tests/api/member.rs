@@ -0,0 +1,413 @@
+use crate::util::helper::*;
+use bytes::Bytes;
+use flowy_document_infra::entities::{
+    doc::{DocIdentifier, NewDocUser, RevType, Revision, UpdateDocParams},
+    ws::{WsDataType, WsDocumentData},
+};
+use flowy_workspace_infra::entities::{
+    app::{AppIdentifier, UpdateAppParams},
+    trash::TrashIdentifiers,
+    view::ViewIdentifier,
+    workspace::{
+        AddWorkspaceMemberParams,
+        RemoveWorkspaceMemberParams,
+        UpdateWorkspaceParams,
+        WorkspaceIdentifier,
+        WorkspaceRole,
+    },
+};
+use futures_util::{Sink, SinkExt, Stream, StreamExt};
+use lib_ot::core::{Delta, DeltaBuilder};
+use lib_ws::{WsMessage, WsModule};
+use std::{
+    convert::{TryFrom, TryInto},
+    time::Duration,
+};
+use tokio_tungstenite::tungstenite::{Error, Message};
+
+#[actix_rt::test]
+async fn workspace_member_invite() {
+    let test = ViewTest::new().await;
+    let other = test.server.register_other_user("nathan@appflowy.io", "nathan").await;
+
+    let params = AddWorkspaceMemberParams::with_email(&test.workspace.id, "nathan@appflowy.io", WorkspaceRole::Editor);
+    let member = test.server.add_workspace_member(params).await;
+    assert_eq!(&member.user_id, other.user_id.as_ref().unwrap());
+    assert_eq!(member.role, WorkspaceRole::Editor);
+
+    // The shared workspace is listed along with the member's own one
+    let workspaces = other.read_workspaces(WorkspaceIdentifier::new(None)).await;
+    assert_eq!(workspaces.len(), 2);
+    assert!(workspaces.iter().any(|workspace| workspace.id == test.workspace.id));
+
+    let view = other.create_view(test_view_params(&test.app.id)).await;
+    assert_eq!(view.belong_to_id, test.app.id);
+}
+
+#[actix_rt::test]
+async fn workspace_member_invite_by_owner_only() {
+    let test = WorkspaceTest::new().await;
+    let editor = test.server.register_other_user("nathan@appflowy.io", "nathan").await;
+    let other = test.server.register_other_user("lucas@appflowy.io", "lucas").await;
+    let params = AddWorkspaceMemberParams::with_user_id(
+        &test.workspace.id,
+        editor.user_id.as_ref().unwrap(),
+        WorkspaceRole::Editor,
+    );
+    let _ = test.server.add_workspace_member(params).await;
+
+    let params = AddWorkspaceMemberParams::with_user_id(
+        &test.workspace.id,
+        other.user_id.as_ref().unwrap(),
+        WorkspaceRole::Owner,
+    );
+    let error = editor.try_add_workspace_member(params).await.unwrap_err();
+    assert_eq!(error.is_permission_denied(), true);
+
+    let params = AddWorkspaceMemberParams::with_email(&test.workspace.id, "unknown@appflowy.io", WorkspaceRole::Viewer);
+    let error = test.server.try_add_workspace_member(params).await.unwrap_err();
+    assert_eq!(error.is_record_not_found(), true);
+}
+
+#[actix_rt::test]
+async fn workspace_viewer_create_view() {
+    let test = ViewTest::new().await;
+    let viewer = test.server.register_other_user("nathan@appflowy.io", "nathan").await;
+    let params = AddWorkspaceMemberParams::with_user_id(
+        &test.workspace.id,
+        viewer.user_id.as_ref().unwrap(),
+        WorkspaceRole::Viewer,
+    );
+    let _ = test.server.add_workspace_member(params).await;
+
+    // The Viewers can read the views but not change them
+    let view = viewer.read_view(test.view.id.clone().into()).await.unwrap();
+    assert_eq!(view.id, test.view.id);
+    let error = viewer
+        .try_create_view(test_view_params(&test.app.id))
+        .await
+        .unwrap_err();
+    assert_eq!(error.is_permission_denied(), true);
+
+    // Promoting the Viewer to an Editor lets them create views
+    let params = AddWorkspaceMemberParams::with_user_id(
+        &test.workspace.id,
+        viewer.user_id.as_ref().unwrap(),
+        WorkspaceRole::Editor,
+    );
+    let _ = test.server.add_workspace_member(params).await;
+    let _ = viewer.create_view(test_view_params(&test.app.id)).await;
+}
+
+#[actix_rt::test]
+async fn workspace_non_member_create_view() {
+    let test = ViewTest::new().await;
+    let other = test.server.register_other_user("nathan@appflowy.io", "nathan").await;
+
+    let error = other.try_create_view(test_view_params(&test.app.id)).await.unwrap_err();
+    assert_eq!(error.is_permission_denied(), true);
+    let params: ViewIdentifier = test.view.id.clone().into();
+    let error = other.try_read_view(params).await.unwrap_err();
+    assert_eq!(error.is_permission_denied(), true);
+}
+
+#[actix_rt::test]
+async fn workspace_member_remove() {
+    let test = ViewTest::new().await;
+    let other = test.server.register_other_user("nathan@appflowy.io", "nathan").await;
+    let other_id = other.user_id.clone().unwrap();
+    let params = AddWorkspaceMemberParams::with_user_id(&test.workspace.id, &other_id, WorkspaceRole::Editor);
+    let _ = test.server.add_workspace_member(params).await;
+
+    test.server
+        .remove_workspace_member(RemoveWorkspaceMemberParams::new(&test.workspace.id, &other_id))
+        .await;
+    let workspaces = other.read_workspaces(WorkspaceIdentifier::new(None)).await;
+    assert!(workspaces.iter().all(|workspace| workspace.id != test.workspace.id));
+
+    let params: ViewIdentifier = test.view.id.clone().into();
+    let error = other.try_read_view(params).await.unwrap_err();
+    assert_eq!(error.is_permission_denied(), true);
+
+    // Removing them again finds no member
+    let params = RemoveWorkspaceMemberParams::new(&test.workspace.id, &other_id);
+    let error = test.server.try_remove_workspace_member(params).await.unwrap_err();
+    assert_eq!(error.is_record_not_found(), true);
+}
+
+// Registers another user and adds them to the workspace of the test with the
+// role.
+async fn add_member(test: &ViewTest, email: &str, role: WorkspaceRole) -> TestUserServer {
+    let member = test.server.register_other_user(email, "nathan").await;
+    let params = AddWorkspaceMemberParams::with_user_id(&test.workspace.id, member.user_id(), role);
+    let _ = test.server.add_workspace_member(params).await;
+    member
+}
+
+#[actix_rt::test]
+async fn workspace_viewer_update_doc() {
+    let test = ViewTest::new().await;
+    let viewer = add_member(&test, "nathan@appflowy.io", WorkspaceRole::Viewer).await;
+    let params = UpdateDocParams {
+        doc_id: test.view.id.clone(),
+        data: DeltaBuilder::new().insert("Hello\n").build().to_json(),
+        rev_id: 1,
+    };
+    let error = viewer.try_update_doc(params.clone()).await.unwrap_err();
+    assert_eq!(error.is_permission_denied(), true);
+
+    let editor = add_member(&test, "lucas@appflowy.io", WorkspaceRole::Editor).await;
+    let _ = editor.try_update_doc(params).await.unwrap();
+}
+
+// Pushes the revision inserting the text at the start of the doc over the ws
+// connection of the user, and gives the server the time to apply it.
+async fn push_revision(server: &TestUserServer, doc_id: &str, text: &str) {
+    let (mut socket, _) = tokio_tungstenite::connect_async(server.ws_addr()).await.unwrap();
+    let delta = DeltaBuilder::new().insert(text).retain(1).build();
+    let revision = Revision::new(0, 1, delta.to_bytes(), doc_id, RevType::Local);
+    let data: Bytes = WsDocumentData::from(revision).try_into().unwrap();
+    let msg = WsMessage {
+        module: WsModule::Doc,
+        data: data.to_vec(),
+    };
+    socket.send(msg.into()).await.unwrap();
+    tokio::time::sleep(Duration::from_secs(1)).await;
+}
+
+#[actix_rt::test]
+async fn workspace_viewer_push_revision() {
+    let test = ViewTest::new().await;
+    let viewer = add_member(&test, "nathan@appflowy.io", WorkspaceRole::Viewer).await;
+    let params = DocIdentifier {
+        doc_id: test.view.id.clone(),
+    };
+
+    push_revision(&viewer, &test.view.id, "Hello").await;
+    let doc = test.server.read_doc(params.clone()).await.unwrap();
+    assert_eq!(doc.rev_id, 0);
+
+    push_revision(&test.server, &test.view.id, "Hello").await;
+    let doc = test.server.read_doc(params).await.unwrap();
+    assert_eq!(doc.rev_id, 1);
+    assert_eq!(doc.data, r#"[{"insert":"Hello\n"}]"#);
+}
+
+async fn send_doc_data<S>(socket: &mut S, data: WsDocumentData)
+where
+    S: Sink<Message> + Unpin,
+    S::Error: std::fmt::Debug,
+{
+    let data: Bytes = data.try_into().unwrap();
+    let msg = WsMessage {
+        module: WsModule::Doc,
+        data: data.to_vec(),
+    };
+    socket.send(msg.into()).await.unwrap();
+}
+
+// Reads the doc messages received by the socket until it's idle for a second.
+async fn read_doc_data<S>(socket: &mut S) -> Vec<WsDocumentData>
+where
+    S: Stream<Item = Result<Message, Error>> + Unpin,
+{
+    let mut items = vec![];
+    while let Ok(Some(Ok(msg))) = tokio::time::timeout(Duration::from_secs(1), socket.next()).await {
+        if let Message::Binary(bytes) = msg {
+            let msg = WsMessage::try_from(Bytes::from(bytes)).unwrap();
+            items.push(WsDocumentData::try_from(Bytes::from(msg.data)).unwrap());
+        }
+    }
+    items
+}
+
+fn open_doc_data(server: &TestUserServer, doc_id: &str) -> WsDocumentData {
+    let user = NewDocUser {
+        user_id: server.user_id().to_owned(),
+        rev_id: 0,
+        doc_id: doc_id.to_owned(),
+    };
+    user.into()
+}
+
+#[actix_rt::test]
+async fn workspace_member_receives_applied_revision() {
+    let test = ViewTest::new().await;
+    let other_view = create_test_view(&test.server, &test.app.id).await;
+    let editor = add_member(&test, "lucas@appflowy.io", WorkspaceRole::Editor).await;
+    let viewer = add_member(&test, "nathan@appflowy.io", WorkspaceRole::Viewer).await;
+
+    let (mut editor_socket, _) = tokio_tungstenite::connect_async(editor.ws_addr()).await.unwrap();
+    send_doc_data(&mut editor_socket, open_doc_data(&editor, &test.view.id)).await;
+    let (mut viewer_socket, _) = tokio_tungstenite::connect_async(viewer.ws_addr()).await.unwrap();
+    send_doc_data(&mut viewer_socket, open_doc_data(&viewer, &other_view.id)).await;
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
+    let (mut socket, _) = tokio_tungstenite::connect_async(test.server.ws_addr()).await.unwrap();
+    let delta = DeltaBuilder::new().insert("Hello").retain(1).build();
+    let revision = Revision::new(0, 1, delta.to_bytes(), &test.view.id, RevType::Local);
+    send_doc_data(&mut socket, revision.into()).await;
+
+    // The sender receives the ack instead of its own revision
+    let items = read_doc_data(&mut socket).await;
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].ty, WsDataType::Acked);
+
+    let items = read_doc_data(&mut editor_socket).await;
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].ty, WsDataType::PushRev);
+    let revision = Revision::try_from(Bytes::from(items[0].data.clone())).unwrap();
+    assert_eq!(revision.rev_id, 1);
+    assert_eq!(revision.ty, RevType::Remote);
+    assert_eq!(Delta::from_bytes(&revision.delta_data).unwrap(), delta);
+
+    assert!(read_doc_data(&mut viewer_socket).await.is_empty());
+}
+
+#[actix_rt::test]
+async fn workspace_member_connections_are_separate_sessions() {
+    let test = ViewTest::new().await;
+    test.server.promote_to_admin().await;
+    let (mut other_socket, _) = tokio_tungstenite::connect_async(test.server.ws_addr()).await.unwrap();
+    send_doc_data(&mut other_socket, open_doc_data(&test.server, &test.view.id)).await;
+    let (mut socket, _) = tokio_tungstenite::connect_async(test.server.ws_addr()).await.unwrap();
+    send_doc_data(&mut socket, open_doc_data(&test.server, &test.view.id)).await;
+    tokio::time::sleep(Duration::from_secs(1)).await;
+
+    let sessions = test.server.read_doc_sessions(&test.view.id).await.unwrap();
+    assert_eq!(sessions.items.len(), 2);
+    assert_ne!(sessions.items[0].session_id, sessions.items[1].session_id);
+    assert!(sessions
+        .items
+        .iter()
+        .all(|session| session.user_id == test.server.user_id()));
+
+    // The other connection of the same user receives the revision
+    let delta = DeltaBuilder::new().insert("Hello").retain(1).build();
+    let revision = Revision::new(0, 1, delta.to_bytes(), &test.view.id, RevType::Local);
+    send_doc_data(&mut socket, revision.into()).await;
+    let items = read_doc_data(&mut other_socket).await;
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].ty, WsDataType::PushRev);
+}
+
+#[actix_rt::test]
+async fn workspace_viewer_update_app() {
+    let test = ViewTest::new().await;
+    let viewer = add_member(&test, "nathan@appflowy.io", WorkspaceRole::Viewer).await;
+    let error = viewer
+        .try_update_app(UpdateAppParams::new(&test.app.id).name("renamed"))
+        .await
+        .unwrap_err();
+    assert_eq!(error.is_permission_denied(), true);
+
+    let app = test.server.read_app(AppIdentifier::new(&test.app.id)).await.unwrap();
+    assert_eq!(app.name, test.app.name);
+}
+
+#[actix_rt::test]
+async fn workspace_viewer_delete_app() {
+    let test = ViewTest::new().await;
+    let viewer = add_member(&test, "nathan@appflowy.io", WorkspaceRole::Viewer).await;
+    let error = viewer
+        .try_delete_app(AppIdentifier::new(&test.app.id))
+        .await
+        .unwrap_err();
+    assert_eq!(error.is_permission_denied(), true);
+    assert!(test.server.read_app(AppIdentifier::new(&test.app.id)).await.is_some());
+}
+
+#[actix_rt::test]
+async fn workspace_editor_update_workspace() {
+    let test = ViewTest::new().await;
+    let editor = add_member(&test, "lucas@appflowy.io", WorkspaceRole::Editor).await;
+    let params = UpdateWorkspaceParams {
+        id: test.workspace.id.clone(),
+        name: Some("renamed".to_string()),
+        desc: None,
+    };
+    // Only the Owners change the workspace itself
+    let error = editor.try_update_workspace(params).await.unwrap_err();
+    assert_eq!(error.is_permission_denied(), true);
+}
+
+#[actix_rt::test]
+async fn workspace_editor_delete_workspace() {
+    let test = ViewTest::new().await;
+    let editor = add_member(&test, "lucas@appflowy.io", WorkspaceRole::Editor).await;
+    let params = WorkspaceIdentifier::new(Some(test.workspace.id.clone()));
+    let error = editor.try_delete_workspace(params.clone()).await.unwrap_err();
+    assert_eq!(error.is_permission_denied(), true);
+
+    let workspaces = test.server.read_workspaces(params).await;
+    assert_eq!(workspaces.len(), 1);
+}
+
+#[actix_rt::test]
+async fn workspace_member_read_trashed_view() {
+    let test = ViewTest::new().await;
+    let editor = add_member(&test, "lucas@appflowy.io", WorkspaceRole::Editor).await;
+
+    // The view trashed by the owner is gone for the members too
+    test.server.create_view_trash(&test.view.id).await;
+    let params: ViewIdentifier = test.view.id.clone().into();
+    assert_eq!(editor.read_view(params).await.is_none(), true);
+    let app = editor.read_app(AppIdentifier::new(&test.app.id)).await.unwrap();
+    assert_eq!(app.belongings.len(), 0);
+}
+
+#[actix_rt::test]
+async fn workspace_viewer_restore_trash() {
+    let test = ViewTest::new().await;
+    let viewer = add_member(&test, "nathan@appflowy.io", WorkspaceRole::Viewer).await;
+    let editor = add_member(&test, "lucas@appflowy.io", WorkspaceRole::Editor).await;
+    let error = viewer.try_create_view_trash(&test.view.id).await.unwrap_err();
+    assert_eq!(error.is_permission_denied(), true);
+
+    // The trash is shared by the members, but only the Editors restore it
+    test.server.create_view_trash(&test.view.id).await;
+    assert_eq!(viewer.read_trash().await.items.len(), 1);
+    let error = viewer.try_restore_view_trash(&test.view.id).await.unwrap_err();
+    assert_eq!(error.is_permission_denied(), true);
+
+    editor.restore_view_trash(&test.view.id).await;
+    assert_eq!(test.server.read_trash().await.is_empty(), true);
+}
+
+#[actix_rt::test]
+async fn workspace_non_member_delete_trash() {
+    let test = ViewTest::new().await;
+    let other = test.server.register_other_user("nathan@appflowy.io", "nathan").await;
+    test.server.create_view_trash(&test.view.id).await;
+    assert_eq!(other.read_trash().await.is_empty(), true);
+
+    // Deleting all the trash leaves the trash of the others' workspaces alone
+    other.delete_view_trash(TrashIdentifiers::all()).await;
+    assert_eq!(test.server.read_trash().await.items.len(), 1);
+}
+
+#[actix_rt::test]
+async fn workspace_viewer_create_named_version() {
+    let test = ViewTest::new().await;
+    let viewer = add_member(&test, "nathan@appflowy.io", WorkspaceRole::Viewer).await;
+    let error = viewer.create_named_version(&test.view.id, "draft").await.unwrap_err();
+    assert_eq!(error.is_permission_denied(), true);
+
+    // The Viewers read the versions, but only the Editors delete them
+    let version = test.server.create_named_version(&test.view.id, "draft").await.unwrap();
+    assert_eq!(viewer.read_named_versions(&test.view.id).await.items.len(), 1);
+    let error = viewer
+        .delete_named_version(&test.view.id, &version.id)
+        .await
+        .unwrap_err();
+    assert_eq!(error.is_permission_denied(), true);
+}
+
+#[actix_rt::test]
+async fn workspace_non_member_touch_view() {
+    let test = ViewTest::new().await;
+    let other = test.server.register_other_user("nathan@appflowy.io", "nathan").await;
+    let error = other.try_touch_view(&test.view.id).await.unwrap_err();
+    assert_eq!(error.is_permission_denied(), true);
+    assert_eq!(other.read_view_last_accessed_time(&test.view.id).await.is_none(), true);
+}
@@ -0,0 +1,49 @@
+use crate::util::helper::spawn_server;
+
+async fn scrape(http_addr: &str) -> String {
+    let response = reqwest::Client::new()
+        .get(&format!("{}/metrics", http_addr))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    response.text().await.unwrap()
+}
+
+#[actix_rt::test]
+async fn metrics_scrape() {
+    let server = spawn_server().await;
+    let http_addr = format!("http://{}", server.host);
+    let response = reqwest::get(&format!("{}/health", http_addr)).await.unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+    let metrics = scrape(&http_addr).await;
+    for name in [
+        "http_requests_total",
+        "http_request_duration_seconds_bucket",
+        "ws_sessions",
+        "opened_docs",
+        "db_pool_connections",
+        "db_pool_idle_connections",
+    ]
+    .iter()
+    {
+        assert!(metrics.contains(name), "{} is missing in:\n{}", name, metrics);
+    }
+    assert!(metrics.contains(r#"route="/health""#));
+    assert!(metrics.contains(r#"status="200""#));
+}
+
+#[actix_rt::test]
+async fn metrics_label_unmatched_requests() {
+    let server = spawn_server().await;
+    let http_addr = format!("http://{}", server.host);
+    let response = reqwest::get(&format!("{}/health/{}", http_addr, uuid::Uuid::new_v4()))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+
+    // The path isn't used as the label, so the unknown paths share one series.
+    let metrics = scrape(&http_addr).await;
+    assert!(metrics.contains(r#"method="GET",route="unmatched",status="404""#));
+}
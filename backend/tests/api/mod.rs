@@ -1,3 +1,22 @@
 mod auth;
+mod cors;
+mod database;
 mod doc;
+mod error;
+mod export;
+mod health;
+mod json_limit;
+mod member;
+mod metrics;
+mod password_reset;
+mod rate_limit;
+mod request_id;
+mod search;
+mod session;
+mod shutdown;
+mod timeout;
+mod tls;
+mod token;
+mod verification;
 mod workspace;
+mod ws;
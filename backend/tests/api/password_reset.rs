@@ -0,0 +1,100 @@
+use crate::util::helper::{spawn_server_with_configuration, TestUserServer};
+use backend_service::{
+    errors::{ErrorCode, ServerError},
+    user_request::{get_user_profile_request, reset_password_confirm_request, reset_password_request},
+};
+use flowy_user_infra::entities::{ResetPasswordConfirmParams, ResetPasswordRequestParams, SignInParams};
+
+const EMAIL: &str = "annie@appflowy.io";
+const NEW_PASSWORD: &str = "HelloAppFlowy456!";
+
+async fn password_reset_server(token_expiry_secs: i64) -> TestUserServer {
+    let server = spawn_server_with_configuration(|c| c.password_reset.token_expiry_secs = token_expiry_secs).await;
+    let mut server: TestUserServer = server.into();
+    let response = server.register_user().await;
+    server.user_token = Some(response.token);
+    server.user_id = Some(response.user_id);
+    server
+}
+
+async fn request_reset_token(server: &TestUserServer) -> String {
+    let url = format!("{}/api/password/reset_request", server.http_addr());
+    let params = ResetPasswordRequestParams {
+        email: EMAIL.to_string(),
+    };
+    let _ = reset_password_request(params, &url).await.unwrap();
+    server.mailer.last_token(EMAIL).unwrap()
+}
+
+async fn confirm_reset(server: &TestUserServer, token: &str, password: &str) -> Result<(), ServerError> {
+    let url = format!("{}/api/password/reset_confirm", server.http_addr());
+    let params = ResetPasswordConfirmParams {
+        token: token.to_string(),
+        password: password.to_string(),
+    };
+    reset_password_confirm_request(params, &url).await
+}
+
+async fn sign_in(server: &TestUserServer, password: &str) -> Result<(), ServerError> {
+    let params = SignInParams {
+        email: EMAIL.to_string(),
+        password: password.to_string(),
+        name: "annie".to_string(),
+    };
+    let _ = server.sign_in(params).await?;
+    Ok(())
+}
+
+#[actix_rt::test]
+async fn reset_password() {
+    let server = password_reset_server(3600).await;
+    let token = request_reset_token(&server).await;
+    let _ = confirm_reset(&server, &token, NEW_PASSWORD).await.unwrap();
+
+    let error = sign_in(&server, "HelloAppFlowy123!").await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::PasswordNotMatch);
+    let _ = sign_in(&server, NEW_PASSWORD).await.unwrap();
+}
+
+#[actix_rt::test]
+async fn reset_password_signs_out_all_sessions() {
+    let server = password_reset_server(3600).await;
+    let token = request_reset_token(&server).await;
+    let _ = confirm_reset(&server, &token, NEW_PASSWORD).await.unwrap();
+
+    let url = format!("{}/api/user", server.http_addr());
+    let error = get_user_profile_request(server.user_token(), &url).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::UserUnauthorized);
+}
+
+#[actix_rt::test]
+async fn reset_password_with_expired_token() {
+    let server = password_reset_server(0).await;
+    let token = request_reset_token(&server).await;
+    let error = confirm_reset(&server, &token, NEW_PASSWORD).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+
+    let _ = sign_in(&server, "HelloAppFlowy123!").await.unwrap();
+}
+
+#[actix_rt::test]
+async fn reset_password_with_used_token() {
+    let server = password_reset_server(3600).await;
+    let token = request_reset_token(&server).await;
+    let _ = confirm_reset(&server, &token, NEW_PASSWORD).await.unwrap();
+
+    let error = confirm_reset(&server, &token, "HelloAppFlowy789!").await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+    let _ = sign_in(&server, NEW_PASSWORD).await.unwrap();
+}
+
+#[actix_rt::test]
+async fn reset_password_of_unknown_email() {
+    let server = password_reset_server(3600).await;
+    let url = format!("{}/api/password/reset_request", server.http_addr());
+    let params = ResetPasswordRequestParams {
+        email: "nobody@appflowy.io".to_string(),
+    };
+    let _ = reset_password_request(params, &url).await.unwrap();
+    assert_eq!(server.mailer.last_token("nobody@appflowy.io"), None);
+}
@@ -0,0 +1,104 @@
+use actix_web::{
+    http::{header::RETRY_AFTER, StatusCode},
+    test,
+    web,
+    App,
+    HttpResponse,
+};
+use backend::{config::RateLimitSettings, middleware::RateLimiter};
+use backend_service::errors::ServerError;
+use std::net::SocketAddr;
+
+async fn ok_handler() -> HttpResponse { HttpResponse::Ok().finish() }
+
+fn rate_limit_settings() -> RateLimitSettings {
+    RateLimitSettings {
+        routes: vec!["/api/auth".to_owned()],
+        burst: 3,
+        per_minute: 1,
+    }
+}
+
+fn request_from(uri: &str, addr: &str) -> actix_http::Request {
+    let addr: SocketAddr = addr.parse().unwrap();
+    test::TestRequest::post().uri(uri).peer_addr(addr).to_request()
+}
+
+#[actix_rt::test]
+async fn rate_limit_auth() {
+    let app = test::init_service(
+        App::new()
+            .wrap(RateLimiter::new(&rate_limit_settings()))
+            .route("/api/auth", web::post().to(ok_handler)),
+    )
+    .await;
+
+    for _ in 0..3 {
+        let resp = test::call_service(&app, request_from("/api/auth", "127.0.0.1:10000")).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    for _ in 0..5 {
+        let resp = test::call_service(&app, request_from("/api/auth", "127.0.0.1:10001")).await;
+        assert_eq!(resp.status(), StatusCode::TOO_MANY_REQUESTS);
+        let retry_after: u64 = resp
+            .headers()
+            .get(RETRY_AFTER)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert_eq!(retry_after > 0 && retry_after <= 60, true);
+        let error: ServerError = test::read_body_json(resp).await;
+        assert_eq!(error.is_too_many_requests(), true);
+    }
+
+    // The requests from another IP are limited separately
+    let resp = test::call_service(&app, request_from("/api/auth", "10.0.0.1:10000")).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+}
+
+#[actix_rt::test]
+async fn rate_limit_ignore_other_routes() {
+    let app = test::init_service(
+        App::new()
+            .wrap(RateLimiter::new(&rate_limit_settings()))
+            .route("/api/auth", web::post().to(ok_handler))
+            .route("/api/user", web::post().to(ok_handler)),
+    )
+    .await;
+
+    for _ in 0..3 {
+        let resp = test::call_service(&app, request_from("/api/auth", "127.0.0.1:10000")).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+    let resp = test::call_service(&app, request_from("/api/auth", "127.0.0.1:10000")).await;
+    assert_eq!(resp.status(), StatusCode::TOO_MANY_REQUESTS);
+
+    for _ in 0..10 {
+        let resp = test::call_service(&app, request_from("/api/user", "127.0.0.1:10000")).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+}
+
+#[actix_rt::test]
+async fn rate_limit_prune_refilled_buckets() {
+    let slow_limiter = RateLimiter::new(&rate_limit_settings());
+    let fast_limiter = RateLimiter::new(&RateLimitSettings {
+        per_minute: 6000,
+        ..rate_limit_settings()
+    });
+    for limiter in vec![slow_limiter.clone(), fast_limiter.clone()] {
+        let app = test::init_service(App::new().wrap(limiter).route("/api/auth", web::post().to(ok_handler))).await;
+        let resp = test::call_service(&app, request_from("/api/auth", "127.0.0.1:10000")).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    // The fast bucket is refilled within the sleep, the slow one only after a
+    // minute
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    assert_eq!(slow_limiter.prune(), 0);
+    assert_eq!(fast_limiter.prune(), 1);
+    assert_eq!(fast_limiter.prune(), 0);
+}
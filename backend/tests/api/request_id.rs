@@ -0,0 +1,54 @@
+use actix_web::{http::StatusCode, test, web, App, HttpResponse};
+use backend::{
+    config::HEADER_REQUEST_ID,
+    middleware::{RequestId, RequestIdService},
+};
+
+async fn request_id_handler(request_id: RequestId) -> HttpResponse { HttpResponse::Ok().body(request_id.0) }
+
+async fn call(request: test::TestRequest) -> (String, String) {
+    let app = test::init_service(
+        App::new()
+            .wrap(RequestIdService)
+            .route("/request_id", web::get().to(request_id_handler)),
+    )
+    .await;
+    let resp = test::call_service(&app, request.uri("/request_id").to_request()).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let header = resp
+        .headers()
+        .get(HEADER_REQUEST_ID)
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_owned();
+    let body = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+    (header, body)
+}
+
+#[actix_rt::test]
+async fn request_id_echoed() {
+    let request = test::TestRequest::get().insert_header(("X-Request-Id", "a7f4c1b2-appflowy"));
+    let (header, request_id) = call(request).await;
+    assert_eq!(header, "a7f4c1b2-appflowy");
+    assert_eq!(request_id, "a7f4c1b2-appflowy");
+}
+
+#[actix_rt::test]
+async fn request_id_generated() {
+    let (header, request_id) = call(test::TestRequest::get()).await;
+    assert_eq!(uuid::Uuid::parse_str(&header).is_ok(), true);
+    assert_eq!(header, request_id);
+
+    // Every request gets its own id.
+    let (other_header, _) = call(test::TestRequest::get()).await;
+    assert_ne!(header, other_header);
+}
+
+#[actix_rt::test]
+async fn request_id_replaced_if_invalid() {
+    let request = test::TestRequest::get().insert_header((HEADER_REQUEST_ID, "a".repeat(256)));
+    let (header, request_id) = call(request).await;
+    assert_eq!(uuid::Uuid::parse_str(&header).is_ok(), true);
+    assert_eq!(header, request_id);
+}
@@ -0,0 +1,123 @@
+use crate::util::helper::*;
+use flowy_workspace_infra::entities::{
+    search::{RepeatedSearchResult, SearchMatch},
+    view::View,
+    workspace::{AddWorkspaceMemberParams, WorkspaceRole},
+};
+use lib_ot::core::DeltaBuilder;
+
+async fn create_view_with_text(test: &ViewTest, name: &str, text: &str) -> View {
+    let mut params = test_view_params(&test.app.id);
+    params.name = name.to_owned();
+    params.data = DeltaBuilder::new().insert(text).build().to_json();
+    test.server.create_view(params).await
+}
+
+fn view_ids(results: &RepeatedSearchResult) -> Vec<String> {
+    let mut view_ids = results
+        .items
+        .iter()
+        .map(|result| result.view_id.clone())
+        .collect::<Vec<_>>();
+    view_ids.sort();
+    view_ids
+}
+
+#[actix_rt::test]
+async fn search_doc_text() {
+    let test = ViewTest::new().await;
+    let travel = create_view_with_text(&test, "Travel", "Visit Kyoto in the spring\n").await;
+    let recipe = create_view_with_text(&test, "Recipes", "Bake the bread at dawn, then visit the market\n").await;
+
+    let results = test.server.search("kyoto", &test.workspace.id).await;
+    assert_eq!(view_ids(&results), vec![travel.id.clone()]);
+    let result = &results.items[0];
+    assert_eq!(result.view_name, "Travel");
+    assert_eq!(result.snippet, "Visit Kyoto in the spring");
+    assert_eq!(result.matches, vec![SearchMatch { start: 6, end: 11 }]);
+
+    let results = test.server.search("visit", &test.workspace.id).await;
+    let mut expected = vec![travel.id.clone(), recipe.id.clone()];
+    expected.sort();
+    assert_eq!(view_ids(&results), expected);
+
+    // The full text search matches the whole words only
+    let results = test.server.search("yot", &test.workspace.id).await;
+    assert_eq!(results.items.is_empty(), true);
+}
+
+#[actix_rt::test]
+async fn search_view_name() {
+    let test = ViewTest::new().await;
+    let recipe = create_view_with_text(&test, "Recipes", "Bake the bread at dawn\n").await;
+    let _ = create_view_with_text(&test, "Travel", "Look for the recipes of Kyoto\n").await;
+
+    // The view whose name matches comes first
+    let results = test.server.search("recipes", &test.workspace.id).await;
+    assert_eq!(results.items.len(), 2);
+    assert_eq!(results.items[0].view_id, recipe.id);
+    assert_eq!(results.items[0].snippet, "Bake the bread at dawn");
+    assert_eq!(results.items[0].matches.is_empty(), true);
+    assert_eq!(results.items[1].matches, vec![SearchMatch { start: 13, end: 20 }]);
+}
+
+#[actix_rt::test]
+async fn search_skip_trash() {
+    let test = ViewTest::new().await;
+    let travel = create_view_with_text(&test, "Travel", "Visit Kyoto in the spring\n").await;
+    test.server.create_view_trash(&travel.id).await;
+
+    let results = test.server.search("kyoto", &test.workspace.id).await;
+    assert_eq!(results.items.is_empty(), true);
+}
+
+#[actix_rt::test]
+async fn search_with_ilike() {
+    let server = TestUserServer::new_with_configuration(|c| c.search.full_text = false).await;
+    let test = ViewTest::new_with_server(server).await;
+    let travel = create_view_with_text(&test, "Travel", "Visit Kyoto in the spring\n").await;
+
+    let results = test.server.search("yot", &test.workspace.id).await;
+    assert_eq!(view_ids(&results), vec![travel.id.clone()]);
+    assert_eq!(results.items[0].matches, vec![SearchMatch { start: 7, end: 10 }]);
+}
+
+#[actix_rt::test]
+async fn search_shared_workspace() {
+    let test = ViewTest::new().await;
+    let travel = create_view_with_text(&test, "Travel", "Visit Kyoto in the spring\n").await;
+    let member = test.server.register_other_user("nathan@appflowy.io", "nathan").await;
+    let other = test.server.register_other_user("lucas@appflowy.io", "lucas").await;
+    let params = AddWorkspaceMemberParams::with_user_id(&test.workspace.id, member.user_id(), WorkspaceRole::Viewer);
+    let _ = test.server.add_workspace_member(params).await;
+
+    // The members search the views of the others, the non-members don't
+    let results = member.search("kyoto", &test.workspace.id).await;
+    assert_eq!(view_ids(&results), vec![travel.id.clone()]);
+    let results = other.search("kyoto", &test.workspace.id).await;
+    assert_eq!(results.items.is_empty(), true);
+}
+
+#[actix_rt::test]
+async fn search_after_reindex() {
+    let test = ViewTest::new().await;
+    let travel = create_view_with_text(&test, "Travel", "Visit Kyoto in the spring\n").await;
+    let error = test.server.reindex_workspace(&test.workspace.id).await.unwrap_err();
+    assert_eq!(error.is_permission_denied(), true);
+
+    // Clears the index, so the doc isn't found until it's rebuilt
+    sqlx::query("UPDATE doc_table SET plaintext = ''")
+        .execute(&test.server.pg_pool)
+        .await
+        .unwrap();
+    assert_eq!(
+        test.server.search("kyoto", &test.workspace.id).await.items.is_empty(),
+        true
+    );
+
+    test.server.promote_to_admin().await;
+    let result = test.server.reindex_workspace(&test.workspace.id).await.unwrap();
+    assert_eq!(result.indexed_count, 2);
+    let results = test.server.search("kyoto", &test.workspace.id).await;
+    assert_eq!(view_ids(&results), vec![travel.id.clone()]);
+}
@@ -0,0 +1,134 @@
+use actix::{Actor, Addr, Context, Handler};
+use backend::{
+    application::period_check,
+    config::{RateLimitSettings, SessionSettings},
+    middleware::RateLimiter,
+    service::ws::{
+        entities::{Broadcast, CloseSession, Connect, Disconnect, Heartbeat, JoinDoc, SessionCount},
+        WsMessageAdaptor,
+        WsServer,
+    },
+};
+use bytes::Bytes;
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::time::sleep;
+
+#[derive(Default)]
+struct ClientState {
+    closed: AtomicBool,
+    received: AtomicUsize,
+}
+
+#[derive(Default)]
+struct FakeClient {
+    state: Arc<ClientState>,
+}
+
+impl Actor for FakeClient {
+    type Context = Context<Self>;
+}
+
+impl Handler<WsMessageAdaptor> for FakeClient {
+    type Result = ();
+    fn handle(&mut self, _: WsMessageAdaptor, _: &mut Context<Self>) {
+        self.state.received.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+impl Handler<CloseSession> for FakeClient {
+    type Result = ();
+    fn handle(&mut self, _: CloseSession, _: &mut Context<Self>) { self.state.closed.store(true, Ordering::SeqCst); }
+}
+
+async fn connect(server: &Addr<WsServer>, sid: &str) -> Arc<ClientState> {
+    let client = FakeClient::default();
+    let state = client.state.clone();
+    let client = client.start();
+    let connect = Connect {
+        socket: client.clone().recipient(),
+        closer: client.recipient(),
+        sid: sid.into(),
+        user_id: sid.to_owned(),
+    };
+    server.send(connect).await.unwrap().unwrap();
+    state
+}
+
+#[actix_rt::test]
+async fn period_check_reap_idle_session() {
+    // The clock only advances when all the tasks are idle
+    tokio::time::pause();
+    let server = WsServer::new().start();
+    let stale = connect(&server, "stale").await;
+    let alive = connect(&server, "alive").await;
+    let settings = SessionSettings {
+        check_interval_secs: 10,
+        idle_timeout_secs: 30,
+        ..Default::default()
+    };
+    actix_rt::spawn(period_check(
+        server.clone(),
+        RateLimiter::new(&RateLimitSettings::default()),
+        settings,
+    ));
+
+    sleep(Duration::from_secs(25)).await;
+    server.send(Heartbeat { sid: "alive".into() }).await.unwrap();
+    assert_eq!(server.send(SessionCount).await.unwrap(), 2);
+
+    // The stale session is reaped by the check at the 40th second
+    sleep(Duration::from_secs(20)).await;
+    assert_eq!(server.send(SessionCount).await.unwrap(), 1);
+    assert_eq!(stale.closed.load(Ordering::SeqCst), true);
+    assert_eq!(alive.closed.load(Ordering::SeqCst), false);
+}
+
+async fn join_doc(server: &Addr<WsServer>, sid: &str, doc_id: &str) {
+    let join = JoinDoc {
+        sid: sid.into(),
+        doc_id: doc_id.to_owned(),
+    };
+    server.send(join).await.unwrap();
+}
+
+fn broadcast(doc_id: &str, exclude: &str) -> Broadcast {
+    Broadcast {
+        doc_id: doc_id.to_owned(),
+        message: WsMessageAdaptor(Bytes::from_static(b"revision")),
+        exclude: Some(exclude.into()),
+    }
+}
+
+#[actix_rt::test]
+async fn broadcast_to_co_editing_sessions() {
+    let server = WsServer::new().start();
+    let sender = connect(&server, "sender").await;
+    let co_editor = connect(&server, "co_editor").await;
+    let other_editor = connect(&server, "other_editor").await;
+    join_doc(&server, "sender", "doc_1").await;
+    join_doc(&server, "co_editor", "doc_1").await;
+    join_doc(&server, "other_editor", "doc_2").await;
+
+    assert_eq!(server.send(broadcast("doc_1", "sender")).await.unwrap(), 1);
+    // The messages are delivered to the clients in the background.
+    sleep(Duration::from_millis(50)).await;
+    assert_eq!(sender.received.load(Ordering::SeqCst), 0);
+    assert_eq!(co_editor.received.load(Ordering::SeqCst), 1);
+    assert_eq!(other_editor.received.load(Ordering::SeqCst), 0);
+
+    // The disconnected session is unregistered from its documents.
+    server
+        .send(Disconnect {
+            sid: "co_editor".into(),
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(server.send(broadcast("doc_1", "sender")).await.unwrap(), 0);
+}
@@ -0,0 +1,29 @@
+use crate::util::helper::{spawn_server, TestUserServer};
+use futures_util::StreamExt;
+use std::time::Duration;
+use tokio_tungstenite::tungstenite::{protocol::frame::coding::CloseCode, Message};
+
+#[actix_rt::test]
+async fn shutdown_closes_ws_connection() {
+    let server = spawn_server().await;
+    let shutdown = server.shutdown.clone();
+    let mut server: TestUserServer = server.into();
+    let response = server.register_user().await;
+    server.user_token = Some(response.token);
+
+    let (mut socket, _) = tokio_tungstenite::connect_async(server.ws_addr()).await.unwrap();
+    shutdown.shutdown();
+
+    let close_frame = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            match socket.next().await {
+                Some(Ok(Message::Close(frame))) => return frame,
+                Some(Ok(_)) => continue,
+                other => panic!("Expect a close frame, but receive {:?}", other),
+            }
+        }
+    })
+    .await
+    .expect("The connection isn't closed by the shutdown");
+    assert_eq!(close_frame.unwrap().code, CloseCode::Away);
+}
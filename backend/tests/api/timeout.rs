@@ -0,0 +1,72 @@
+use actix_service::Service;
+use actix_web::{http::StatusCode, test, web, App, HttpResponse};
+use backend::{
+    config::{RouteTimeoutSettings, TimeoutSettings},
+    middleware::RequestTimeout,
+};
+use backend_service::errors::ServerError;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+struct Finished(AtomicBool);
+
+async fn slow_handler(finished: web::Data<Arc<Finished>>) -> HttpResponse {
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    finished.0.store(true, Ordering::SeqCst);
+    HttpResponse::Ok().finish()
+}
+
+fn timeout_settings() -> TimeoutSettings {
+    TimeoutSettings {
+        request_timeout_ms: Some(50),
+        route_timeouts: vec![RouteTimeoutSettings {
+            pattern: "/view/{view_id}/print".to_owned(),
+            timeout_ms: 1000,
+        }],
+    }
+}
+
+#[actix_rt::test]
+async fn request_timeout() {
+    let finished = Arc::new(Finished(AtomicBool::new(false)));
+    let app = test::init_service(
+        App::new()
+            .wrap(RequestTimeout::new(&timeout_settings()))
+            .app_data(web::Data::new(finished.clone()))
+            .route("/slow", web::get().to(slow_handler)),
+    )
+    .await;
+
+    let req = test::TestRequest::get().uri("/slow").to_request();
+    // The timeout is returned as an error, which is rendered as the response
+    let error = app.call(req).await.err().unwrap();
+    assert_eq!(error.as_response_error().status_code(), StatusCode::GATEWAY_TIMEOUT);
+    let error = error.as_error::<ServerError>().unwrap();
+    assert_eq!(error.is_request_timeout(), true);
+
+    // The handler is cancelled instead of running to the end
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(finished.0.load(Ordering::SeqCst), false);
+}
+
+#[actix_rt::test]
+async fn request_timeout_overridden_by_route() {
+    let finished = Arc::new(Finished(AtomicBool::new(false)));
+    let app = test::init_service(
+        App::new()
+            .wrap(RequestTimeout::new(&timeout_settings()))
+            .app_data(web::Data::new(finished.clone()))
+            .route("/view/{view_id}/print", web::get().to(slow_handler)),
+    )
+    .await;
+
+    let req = test::TestRequest::get().uri("/view/1/print").to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(finished.0.load(Ordering::SeqCst), true);
+}
@@ -0,0 +1,46 @@
+use crate::util::helper::spawn_server_with_configuration;
+use backend::config::TlsSettings;
+
+fn tls_settings() -> TlsSettings {
+    TlsSettings {
+        cert_path: "tests/fixtures/cert.pem".to_owned(),
+        key_path: "tests/fixtures/key.pem".to_owned(),
+    }
+}
+
+#[actix_rt::test]
+async fn tls_handshake() {
+    let server = spawn_server_with_configuration(|c| c.tls = Some(tls_settings())).await;
+    // The certificate is self-signed.
+    let client = reqwest::Client::builder()
+        .danger_accept_invalid_certs(true)
+        .build()
+        .unwrap();
+    let response = client
+        .get(&format!("https://{}/health", server.host))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+    // The plain HTTP requests can't be served.
+    let result = client.get(&format!("http://{}/health", server.host)).send().await;
+    assert_eq!(result.is_err(), true);
+}
+
+#[test]
+fn tls_settings_with_invalid_key() {
+    assert_eq!(tls_settings().server_config().is_ok(), true);
+
+    let settings = TlsSettings {
+        key_path: "tests/fixtures/cert.pem".to_owned(),
+        ..tls_settings()
+    };
+    assert_eq!(settings.server_config().is_err(), true);
+
+    let settings = TlsSettings {
+        cert_path: "tests/fixtures/not_exist.pem".to_owned(),
+        ..tls_settings()
+    };
+    assert_eq!(settings.server_config().is_err(), true);
+}
@@ -0,0 +1,96 @@
+use crate::util::helper::{spawn_server_with_configuration, TestUserServer};
+use backend::config::JwtSettings;
+use backend_service::{errors::ServerError, request::HttpRequestBuilder, user_request::refresh_token_request};
+use flowy_user_infra::entities::{RefreshTokenParams, SignInParams, SignInResponse, UserProfile};
+use std::time::Duration;
+
+async fn jwt_server(access_token_expiry_secs: i64) -> TestUserServer {
+    let server = spawn_server_with_configuration(|c| {
+        c.jwt = JwtSettings {
+            enabled: true,
+            access_token_expiry_secs,
+            refresh_token_expiry_secs: 3600,
+        };
+    })
+    .await;
+    let server: TestUserServer = server.into();
+    let _ = server.register_user().await;
+    server
+}
+
+async fn sign_in(server: &TestUserServer) -> SignInResponse {
+    let params = SignInParams {
+        email: "annie@appflowy.io".to_string(),
+        password: "HelloAppFlowy123!".to_string(),
+        name: "annie".to_string(),
+    };
+    server.sign_in(params).await.unwrap()
+}
+
+async fn read_user_profile_with_bearer(server: &TestUserServer, token: &str) -> Result<UserProfile, ServerError> {
+    let url = format!("{}/api/user", server.http_addr());
+    HttpRequestBuilder::new()
+        .get(&url)
+        .header("authorization", &format!("Bearer {}", token))
+        .response()
+        .await
+}
+
+async fn refresh_token(server: &TestUserServer, refresh_token: &str) -> Result<(String, String), ServerError> {
+    let url = format!("{}/api/token/refresh", server.http_addr());
+    let params = RefreshTokenParams {
+        refresh_token: refresh_token.to_owned(),
+    };
+    let response = refresh_token_request(params, &url).await?;
+    Ok((response.token, response.refresh_token))
+}
+
+#[actix_rt::test]
+async fn jwt_sign_in_issue_tokens() {
+    let server = jwt_server(3600).await;
+    let response = sign_in(&server).await;
+    assert_eq!(response.token.is_empty(), false);
+    assert_eq!(response.refresh_token.is_empty(), false);
+    assert_ne!(response.token, response.refresh_token);
+}
+
+#[actix_rt::test]
+async fn jwt_bearer_authenticated_request() {
+    let server = jwt_server(3600).await;
+    let response = sign_in(&server).await;
+    let user_profile = read_user_profile_with_bearer(&server, &response.token).await.unwrap();
+    assert_eq!(user_profile.email, "annie@appflowy.io");
+
+    // The refresh token is only accepted by the refresh route
+    let error = read_user_profile_with_bearer(&server, &response.refresh_token)
+        .await
+        .unwrap_err();
+    assert_eq!(error.is_unauthorized(), true);
+}
+
+#[actix_rt::test]
+async fn jwt_expired_token_rejected() {
+    let server = jwt_server(1).await;
+    let response = sign_in(&server).await;
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    let error = read_user_profile_with_bearer(&server, &response.token)
+        .await
+        .unwrap_err();
+    assert_eq!(error.is_unauthorized(), true);
+}
+
+#[actix_rt::test]
+async fn jwt_refresh_token() {
+    let server = jwt_server(1).await;
+    let response = sign_in(&server).await;
+    tokio::time::sleep(Duration::from_secs(2)).await;
+
+    let (token, new_refresh_token) = refresh_token(&server, &response.refresh_token).await.unwrap();
+    let user_profile = read_user_profile_with_bearer(&server, &token).await.unwrap();
+    assert_eq!(user_profile.email, "annie@appflowy.io");
+    assert_eq!(new_refresh_token.is_empty(), false);
+
+    // The access token can't be used to refresh
+    let error = refresh_token(&server, &token).await.unwrap_err();
+    assert_eq!(error.is_unauthorized(), true);
+}
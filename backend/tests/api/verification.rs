@@ -0,0 +1,126 @@
+use crate::util::helper::{spawn_server_with_configuration, TestUserServer};
+use backend::config::VerificationSettings;
+use backend_service::{
+    errors::{ErrorCode, ServerError},
+    request::HttpRequestBuilder,
+    user_request::resend_verification_request,
+};
+use flowy_user_infra::entities::{ResendVerificationParams, SignInParams, SignInResponse};
+
+const EMAIL: &str = "annie@appflowy.io";
+
+async fn verification_server(token_expiry_secs: i64) -> TestUserServer {
+    let server = spawn_server_with_configuration(|c| {
+        c.verification = VerificationSettings {
+            enabled: true,
+            token_expiry_secs,
+        };
+    })
+    .await;
+    let server: TestUserServer = server.into();
+    let response = server.register_user().await;
+    assert_eq!(response.token, "");
+    server
+}
+
+fn verification_token(server: &TestUserServer) -> String { server.mailer.last_token(EMAIL).unwrap() }
+
+async fn resend_verification(server: &TestUserServer, email: &str) {
+    let url = format!("{}/api/auth/verify/resend", server.http_addr());
+    let params = ResendVerificationParams {
+        email: email.to_string(),
+    };
+    let _ = resend_verification_request(params, &url).await.unwrap();
+}
+
+async fn verify_email(server: &TestUserServer, token: &str) -> Result<(), ServerError> {
+    let url = format!("{}/api/register/verify?token={}", server.http_addr(), token);
+    HttpRequestBuilder::new().get(&url).send().await
+}
+
+async fn sign_in(server: &TestUserServer) -> Result<SignInResponse, ServerError> {
+    let params = SignInParams {
+        email: EMAIL.to_string(),
+        password: "HelloAppFlowy123!".to_string(),
+        name: "annie".to_string(),
+    };
+    server.sign_in(params).await
+}
+
+#[actix_rt::test]
+async fn sign_in_after_verifying_email() {
+    let server = verification_server(3600).await;
+    let token = verification_token(&server);
+    let _ = verify_email(&server, &token).await.unwrap();
+
+    let response = sign_in(&server).await.unwrap();
+    assert!(!response.token.is_empty());
+}
+
+#[actix_rt::test]
+async fn sign_in_before_verifying_email() {
+    let server = verification_server(3600).await;
+    let error = sign_in(&server).await.unwrap_err();
+    assert!(error.is_user_unverified());
+}
+
+#[actix_rt::test]
+async fn verify_email_with_used_token() {
+    let server = verification_server(3600).await;
+    let token = verification_token(&server);
+    let _ = verify_email(&server, &token).await.unwrap();
+
+    let error = verify_email(&server, &token).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+}
+
+#[actix_rt::test]
+async fn verify_email_with_expired_token() {
+    let server = verification_server(0).await;
+    let token = verification_token(&server);
+    let error = verify_email(&server, &token).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+
+    let error = sign_in(&server).await.unwrap_err();
+    assert!(error.is_user_unverified());
+}
+
+#[actix_rt::test]
+async fn verify_email_with_resent_token() {
+    let server = verification_server(3600).await;
+    let expired_token = verification_token(&server);
+    let _ = sqlx::query("UPDATE user_verification_table SET expires_at = NOW() - INTERVAL '1 second'")
+        .execute(&server.pg_pool)
+        .await
+        .unwrap();
+
+    resend_verification(&server, EMAIL).await;
+    let token = verification_token(&server);
+    assert_ne!(token, expired_token);
+    let _ = verify_email(&server, &token).await.unwrap();
+
+    let response = sign_in(&server).await.unwrap();
+    assert!(!response.token.is_empty());
+}
+
+#[actix_rt::test]
+async fn verify_email_with_replaced_token() {
+    let server = verification_server(3600).await;
+    let replaced_token = verification_token(&server);
+    resend_verification(&server, EMAIL).await;
+
+    let error = verify_email(&server, &replaced_token).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+}
+
+#[actix_rt::test]
+async fn resend_verification_of_verified_or_unknown_email() {
+    let server = verification_server(3600).await;
+    let token = verification_token(&server);
+    let _ = verify_email(&server, &token).await.unwrap();
+
+    resend_verification(&server, EMAIL).await;
+    resend_verification(&server, "nobody@appflowy.io").await;
+    assert_eq!(server.mailer.mails().len(), 1);
+    assert_eq!(server.mailer.last_token("nobody@appflowy.io"), None);
+}
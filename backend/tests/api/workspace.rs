@@ -1,11 +1,19 @@
 #![allow(clippy::all)]
 use crate::util::helper::*;
+use backend::config::QuotaSettings;
+use backend_service::{
+    config::HEADER_TOKEN,
+    errors::{ErrorCode, QuotaUsage},
+};
+use flowy_document_infra::entities::doc::DocIdentifier;
 use flowy_workspace_infra::entities::{
     app::{AppIdentifier, UpdateAppParams},
     trash::{TrashIdentifier, TrashIdentifiers, TrashType},
-    view::{UpdateViewParams, ViewIdentifier},
+    view::{MoveViewParams, ReorderViewParams, UpdateViewParams, ViewIdentifier, ViewIdentifiers},
     workspace::{CreateWorkspaceParams, UpdateWorkspaceParams, WorkspaceIdentifier},
 };
+use lib_ot::core::{Delta, DeltaBuilder};
+use tokio::time::{sleep, Duration};
 
 #[actix_rt::test]
 async fn workspace_create() {
@@ -133,6 +141,118 @@ async fn app_delete() {
     assert_eq!(test.server.read_app(read_params).await.is_none(), true);
 }
 
+#[actix_rt::test]
+async fn app_delete_with_nested_apps() {
+    let test = AppTest::new().await;
+    let child = test.create_child_app(&test.app.id).await;
+    let grandchild = test.create_child_app(&child.id).await;
+
+    // The nested apps are deleted along with their parent
+    test.server.delete_app(AppIdentifier::new(&test.app.id)).await;
+    assert_eq!(
+        test.server.read_app(AppIdentifier::new(&child.id)).await.is_none(),
+        true
+    );
+    assert_eq!(
+        test.server.read_app(AppIdentifier::new(&grandchild.id)).await.is_none(),
+        true
+    );
+}
+
+#[actix_rt::test]
+async fn app_purge_with_nested_apps() {
+    let test = AppTest::new().await;
+    let child = test.create_child_app(&test.app.id).await;
+    let view = create_test_view(&test.server, &child.id).await;
+
+    // The trash, views and docs of the nested apps are deleted along with their
+    // parent
+    test.server.create_app_trash(&test.app.id).await;
+    assert_eq!(test.server.read_trash().await.items.len(), 2);
+    let identifier = TrashIdentifier {
+        id: test.app.id.clone(),
+        ty: TrashType::App,
+    };
+    test.server.delete_view_trash(vec![identifier].into()).await;
+    assert_eq!(test.server.read_trash().await.is_empty(), true);
+
+    let read_params: ViewIdentifier = view.id.clone().into();
+    assert_eq!(test.server.read_view(read_params).await.is_none(), true);
+    let doc_params = DocIdentifier {
+        doc_id: view.id.clone(),
+    };
+    assert_eq!(test.server.read_doc(doc_params).await.is_none(), true);
+}
+
+#[actix_rt::test]
+async fn app_nested_tree() {
+    let test = AppTest::new().await;
+    let child = test.create_child_app(&test.app.id).await;
+    let grandchild = test.create_child_app(&child.id).await;
+    assert_eq!(child.parent_id, Some(test.app.id.clone()));
+
+    let app = test.server.read_app(AppIdentifier::new(&test.app.id)).await.unwrap();
+    assert_eq!(app.apps.len(), 1);
+    assert_eq!(app.apps[0].id, child.id);
+    assert_eq!(app.apps[0].apps.len(), 1);
+    assert_eq!(app.apps[0].apps[0].id, grandchild.id);
+    assert_eq!(app.apps[0].apps[0].parent_id, Some(child.id.clone()));
+
+    // The nested apps are carried by their parents
+    let read_params = WorkspaceIdentifier::new(Some(test.workspace.id.clone()));
+    let workspaces = test.server.read_workspaces(read_params).await;
+    let workspace = workspaces.items.first().unwrap();
+    assert_eq!(workspace.apps.len(), 1);
+    assert_eq!(workspace.apps[0].apps[0].apps[0].id, grandchild.id);
+}
+
+#[actix_rt::test]
+async fn app_move_subtree() {
+    let test = AppTest::new().await;
+    let other_app = create_test_app(&test.server, &test.workspace.id).await;
+    let child = test.create_child_app(&test.app.id).await;
+    let grandchild = test.create_child_app(&child.id).await;
+
+    // The child moves under the other app along with its own child
+    test.server
+        .update_app(UpdateAppParams::new(&child.id).parent_id(&other_app.id))
+        .await;
+    let app = test.server.read_app(AppIdentifier::new(&test.app.id)).await.unwrap();
+    assert!(app.apps.is_empty());
+    let app = test.server.read_app(AppIdentifier::new(&other_app.id)).await.unwrap();
+    assert_eq!(app.apps[0].id, child.id);
+    assert_eq!(app.apps[0].apps[0].id, grandchild.id);
+
+    // An empty parent_id moves the child to the top of the workspace
+    test.server
+        .update_app(UpdateAppParams::new(&child.id).parent_id(""))
+        .await;
+    let app = test.server.read_app(AppIdentifier::new(&child.id)).await.unwrap();
+    assert_eq!(app.parent_id, None);
+    assert_eq!(app.apps[0].id, grandchild.id);
+
+    let read_params = WorkspaceIdentifier::new(Some(test.workspace.id.clone()));
+    let workspaces = test.server.read_workspaces(read_params).await;
+    assert_eq!(workspaces.items.first().unwrap().apps.len(), 3);
+}
+
+#[actix_rt::test]
+async fn app_move_under_descendant() {
+    let test = AppTest::new().await;
+    let child = test.create_child_app(&test.app.id).await;
+    let grandchild = test.create_child_app(&child.id).await;
+
+    let params = UpdateAppParams::new(&test.app.id).parent_id(&grandchild.id);
+    assert!(test.server.try_update_app(params).await.is_err());
+    let params = UpdateAppParams::new(&test.app.id).parent_id(&test.app.id);
+    assert!(test.server.try_update_app(params).await.is_err());
+
+    let app = test.server.read_app(AppIdentifier::new(&test.app.id)).await.unwrap();
+    assert_eq!(app.parent_id, None);
+    assert_eq!(app.apps[0].id, child.id);
+    assert_eq!(app.apps[0].apps[0].id, grandchild.id);
+}
+
 #[actix_rt::test]
 async fn view_create() {
     let test = ViewTest::new().await;
@@ -154,6 +274,77 @@ async fn view_update() {
     assert_eq!(&view.name, new_name);
 }
 
+#[actix_rt::test]
+async fn view_touch() {
+    let test = ViewTest::new().await;
+    let read_params: ViewIdentifier = test.view.id.clone().into();
+    let doc_params = DocIdentifier {
+        doc_id: test.view.id.clone(),
+    };
+    let view = test.server.read_view(read_params.clone()).await.unwrap();
+    let doc = test.server.read_doc(doc_params.clone()).await.unwrap();
+    assert_eq!(test.server.read_view_last_accessed_time(&test.view.id).await, None);
+
+    test.server.touch_view(&test.view.id).await;
+    let first_accessed_time = test.server.read_view_last_accessed_time(&test.view.id).await.unwrap();
+
+    sleep(Duration::from_millis(10)).await;
+    test.server.touch_view(&test.view.id).await;
+    let last_accessed_time = test.server.read_view_last_accessed_time(&test.view.id).await.unwrap();
+    assert!(last_accessed_time > first_accessed_time);
+
+    // Touching neither edits the view nor creates a revision of the doc
+    let touched_view = test.server.read_view(read_params).await.unwrap();
+    assert_eq!(touched_view.modified_time, view.modified_time);
+    assert_eq!(test.server.read_doc(doc_params).await.unwrap(), doc);
+}
+
+#[actix_rt::test]
+async fn view_print() {
+    let test = ViewTest::new().await;
+    let url = format!("{}/api/view/{}/print", test.server.http_addr(), test.view.id);
+    let response = reqwest::Client::new()
+        .get(&url)
+        .header(HEADER_TOKEN, test.server.user_token())
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"].to_str().unwrap(),
+        "text/html; charset=utf-8"
+    );
+
+    let html = response.text().await.unwrap();
+    assert_eq!(html.starts_with("<!DOCTYPE html>\n<html>\n<head>"), true);
+    assert_eq!(html.contains(&format!("<title>{}</title>", test.view.name)), true);
+    assert_eq!(html.contains("<body style="), true);
+    assert_eq!(html.trim_end().ends_with("</body>\n</html>"), true);
+}
+
+#[actix_rt::test]
+async fn view_duplicate() {
+    let test = ViewTest::new().await;
+    let mut params = test_view_params(&test.app.id).parent_view_id(&test.view.id);
+    params.data = DeltaBuilder::new().insert("Hello AppFlowy\n").build().to_json();
+    let view = test.server.create_view(params).await;
+
+    let copy = test.server.duplicate_view(view.id.clone().into()).await;
+    assert_ne!(copy.id, view.id);
+    assert_eq!(copy.name, format!("{} copy", view.name));
+    assert_eq!(copy.belong_to_id, view.belong_to_id);
+    assert_eq!(copy.parent_view_id, Some(test.view.id.clone()));
+
+    // The copy has its own document with the same text
+    let doc = test.server.read_doc(view.id.clone().into()).await.unwrap();
+    let copy_doc = test.server.read_doc(copy.id.clone().into()).await.unwrap();
+    assert_ne!(copy_doc.id, doc.id);
+    assert_eq!(copy_doc.rev_id, 0);
+    let text = Delta::from_json(&doc.data).unwrap().to_plain_text();
+    assert_eq!(Delta::from_json(&copy_doc.data).unwrap().to_plain_text(), text);
+    assert_eq!(text, "Hello AppFlowy\n");
+}
+
 #[actix_rt::test]
 async fn view_delete() {
     let test = ViewTest::new().await;
@@ -175,6 +366,242 @@ async fn view_delete() {
     assert_eq!(trash_ids.contains(&test.view.id), true);
 }
 
+#[actix_rt::test]
+async fn view_create_exceed_quota() {
+    let limit = 5;
+    let server = TestUserServer::new_with_quota(QuotaSettings {
+        max_documents_per_user: Some(limit),
+    })
+    .await;
+    let workspace = create_test_workspace(&server).await;
+    let app = create_test_app(&server, &workspace.id).await;
+
+    // The views of the default workspace count toward the quota too.
+    let mut views = vec![];
+    let error = loop {
+        match server.try_create_view(test_view_params(&app.id)).await {
+            Ok(view) => views.push(view),
+            Err(error) => break error,
+        }
+        assert!(views.len() as i64 <= limit);
+    };
+    assert_eq!(error.is_quota_exceeded(), true);
+    assert_eq!(error.msg, format!("count: {}, limit: {}", limit, limit));
+    assert_eq!(error.quota, Some(QuotaUsage { count: limit, limit }));
+
+    // The views in the trash are not counted
+    let view = views.pop().unwrap();
+    server.create_view_trash(&view.id).await;
+    let _ = server.create_view(test_view_params(&app.id)).await;
+    assert_eq!(server.try_create_view(test_view_params(&app.id)).await.is_err(), true);
+
+    server.delete_view_trash(TrashIdentifiers::all()).await;
+    assert_eq!(server.try_create_view(test_view_params(&app.id)).await.is_err(), true);
+
+    let view = views.pop().unwrap();
+    server.create_view_trash(&view.id).await;
+    server.delete_view_trash(TrashIdentifiers::all()).await;
+    let _ = server.create_view(test_view_params(&app.id)).await;
+}
+
+#[actix_rt::test]
+async fn view_quota_excludes_trashed_descendants() {
+    let server = TestUserServer::new_with_quota(QuotaSettings {
+        max_documents_per_user: Some(5),
+    })
+    .await;
+    let workspace = create_test_workspace(&server).await;
+    let app = create_test_app(&server, &workspace.id).await;
+    let mut views = vec![];
+    while let Ok(view) = server.try_create_view(test_view_params(&app.id)).await {
+        views.push(view);
+    }
+
+    // Takes the place of the trashed view under another one
+    let view = views.pop().unwrap();
+    server.create_view_trash(&view.id).await;
+    let parent = views.pop().unwrap();
+    let _ = server
+        .create_view(test_view_params(&app.id).parent_view_id(&parent.id))
+        .await;
+    assert_eq!(server.try_create_view(test_view_params(&app.id)).await.is_err(), true);
+
+    // Trashing the parent frees the place of its child too
+    server.create_view_trash(&parent.id).await;
+    let _ = server.create_view(test_view_params(&app.id)).await;
+    let _ = server.create_view(test_view_params(&app.id)).await;
+    assert_eq!(server.try_create_view(test_view_params(&app.id)).await.is_err(), true);
+}
+
+#[actix_rt::test]
+async fn view_create_nested() {
+    let test = ViewTest::new().await;
+    let child = test
+        .server
+        .create_view(test_view_params(&test.app.id).parent_view_id(&test.view.id))
+        .await;
+    let grandchild = test
+        .server
+        .create_view(test_view_params(&test.app.id).parent_view_id(&child.id))
+        .await;
+    assert_eq!(child.parent_view_id, Some(test.view.id.clone()));
+
+    let read_params = AppIdentifier::new(&test.app.id);
+    let app = test.server.read_app(read_params).await.unwrap();
+    assert_eq!(app.belongings.len(), 1);
+    let view = &app.belongings[0];
+    assert_eq!(view.id, test.view.id);
+    assert_eq!(view.belongings.len(), 1);
+    assert_eq!(view.belongings[0].id, child.id);
+    assert_eq!(view.belongings[0].belongings[0].id, grandchild.id);
+
+    let read_params: ViewIdentifier = child.id.clone().into();
+    let view = test.server.read_view(read_params).await.unwrap();
+    assert_eq!(view.belongings.len(), 1);
+    assert_eq!(view.belongings[0].id, grandchild.id);
+}
+
+#[actix_rt::test]
+async fn view_move_into_descendant() {
+    let test = ViewTest::new().await;
+    let child = test
+        .server
+        .create_view(test_view_params(&test.app.id).parent_view_id(&test.view.id))
+        .await;
+    let grandchild = test
+        .server
+        .create_view(test_view_params(&test.app.id).parent_view_id(&child.id))
+        .await;
+
+    let params = MoveViewParams::new(&test.view.id).parent_view_id(&grandchild.id);
+    assert_eq!(test.server.try_move_view(params).await.is_err(), true);
+
+    let params = MoveViewParams::new(&test.view.id).parent_view_id(&test.view.id);
+    assert_eq!(test.server.try_move_view(params).await.is_err(), true);
+
+    // Moving the grandchild to the top of the app is allowed
+    test.server.move_view(MoveViewParams::new(&grandchild.id)).await;
+    let read_params = AppIdentifier::new(&test.app.id);
+    let app = test.server.read_app(read_params).await.unwrap();
+    assert_eq!(app.belongings.len(), 2);
+}
+
+#[actix_rt::test]
+async fn view_reorder() {
+    let test = ViewTest::new().await;
+    let mut view_ids = vec![test.view.id.clone()];
+    for _ in 0..3 {
+        let view = test.server.create_view(test_view_params(&test.app.id)).await;
+        view_ids.push(view.id);
+    }
+    let read_belongings = || async {
+        let app = test.server.read_app(AppIdentifier::new(&test.app.id)).await.unwrap();
+        app.belongings
+            .iter()
+            .map(|view| view.id.clone())
+            .collect::<Vec<String>>()
+    };
+    assert_eq!(read_belongings().await, view_ids);
+
+    // Moves the last view to the front
+    test.server.reorder_view(ReorderViewParams::new(&view_ids[3], 0)).await;
+    let expected = vec![&view_ids[3], &view_ids[0], &view_ids[1], &view_ids[2]];
+    assert_eq!(read_belongings().await.iter().collect::<Vec<_>>(), expected);
+
+    // Moves the front view to the middle
+    test.server.reorder_view(ReorderViewParams::new(&view_ids[3], 2)).await;
+    let expected = vec![&view_ids[0], &view_ids[1], &view_ids[3], &view_ids[2]];
+    assert_eq!(read_belongings().await.iter().collect::<Vec<_>>(), expected);
+
+    // An index past the end moves the view to the end
+    test.server.reorder_view(ReorderViewParams::new(&view_ids[0], 10)).await;
+    let expected = vec![&view_ids[1], &view_ids[3], &view_ids[2], &view_ids[0]];
+    assert_eq!(read_belongings().await.iter().collect::<Vec<_>>(), expected);
+
+    // A new view goes to the end
+    let view = test.server.create_view(test_view_params(&test.app.id)).await;
+    assert_eq!(read_belongings().await.last(), Some(&view.id));
+}
+
+#[actix_rt::test]
+async fn view_delete_with_nested_views() {
+    let test = ViewTest::new().await;
+    let child = test
+        .server
+        .create_view(test_view_params(&test.app.id).parent_view_id(&test.view.id))
+        .await;
+    let grandchild = test
+        .server
+        .create_view(test_view_params(&test.app.id).parent_view_id(&child.id))
+        .await;
+
+    test.server.create_view_trash(&test.view.id).await;
+    let trash_ids = test
+        .server
+        .read_trash()
+        .await
+        .items
+        .into_iter()
+        .map(|item| item.id)
+        .collect::<Vec<String>>();
+    assert_eq!(trash_ids.len(), 3);
+    assert_eq!(trash_ids.contains(&child.id), true);
+    assert_eq!(trash_ids.contains(&grandchild.id), true);
+
+    let read_params: ViewIdentifier = grandchild.id.clone().into();
+    assert_eq!(test.server.read_view(read_params).await.is_none(), true);
+}
+
+#[actix_rt::test]
+async fn view_delete_permanently_with_nested_views() {
+    let test = ViewTest::new().await;
+    let child = test
+        .server
+        .create_view(test_view_params(&test.app.id).parent_view_id(&test.view.id))
+        .await;
+    let grandchild = test
+        .server
+        .create_view(test_view_params(&test.app.id).parent_view_id(&child.id))
+        .await;
+
+    // The nested views are deleted along with their parent
+    let delete_params = ViewIdentifiers {
+        view_ids: vec![test.view.id.clone()],
+    };
+    test.server.delete_view_permanently(delete_params).await;
+    let read_params = AppIdentifier::new(&test.app.id);
+    assert_eq!(
+        test.server.read_app(read_params).await.unwrap().belongings.is_empty(),
+        true
+    );
+    let doc_params = DocIdentifier {
+        doc_id: grandchild.id.clone(),
+    };
+    assert_eq!(test.server.read_doc(doc_params).await.is_none(), true);
+}
+
+#[actix_rt::test]
+async fn trash_delete_with_nested_views() {
+    let test = ViewTest::new().await;
+    let child = test
+        .server
+        .create_view(test_view_params(&test.app.id).parent_view_id(&test.view.id))
+        .await;
+    let _ = test
+        .server
+        .create_view(test_view_params(&test.app.id).parent_view_id(&child.id))
+        .await;
+
+    // The trash of the nested views is deleted along with their parent
+    test.server.create_view_trash(&test.view.id).await;
+    let identifier = TrashIdentifier {
+        id: test.view.id.clone(),
+        ty: TrashType::View,
+    };
+    test.server.delete_view_trash(vec![identifier].into()).await;
+    assert_eq!(test.server.read_trash().await.is_empty(), true);
+}
+
 #[actix_rt::test]
 async fn trash_delete() {
     let test = ViewTest::new().await;
@@ -198,6 +625,118 @@ async fn trash_delete_all() {
     assert_eq!(test.server.read_trash().await.is_empty(), true);
 }
 
+#[actix_rt::test]
+async fn view_delete_to_trash_and_restore() {
+    let test = ViewTest::new().await;
+    let delete_params = ViewIdentifiers {
+        view_ids: vec![test.view.id.clone()],
+    };
+    test.server.delete_view(delete_params).await;
+
+    let read_params: ViewIdentifier = test.view.id.clone().into();
+    assert_eq!(test.server.read_view(read_params.clone()).await.is_none(), true);
+    let trash_ids = test
+        .server
+        .read_trash()
+        .await
+        .items
+        .into_iter()
+        .map(|item| item.id)
+        .collect::<Vec<String>>();
+    assert_eq!(trash_ids, vec![test.view.id.clone()]);
+
+    test.server.restore_view_trash(&test.view.id).await;
+    assert_eq!(test.server.read_trash().await.is_empty(), true);
+    assert_eq!(test.server.read_view(read_params).await.is_some(), true);
+}
+
+#[actix_rt::test]
+async fn view_delete_then_purge_trash() {
+    let test = ViewTest::new().await;
+    let delete_params = ViewIdentifiers {
+        view_ids: vec![test.view.id.clone()],
+    };
+    test.server.delete_view(delete_params).await;
+    test.server.delete_view_trash(TrashIdentifiers::all()).await;
+    assert_eq!(test.server.read_trash().await.is_empty(), true);
+
+    // The purged view can't be restored
+    let error = test.server.try_restore_view_trash(&test.view.id).await.unwrap_err();
+    assert_eq!(error.is_record_not_found(), true);
+    let read_params: ViewIdentifier = test.view.id.clone().into();
+    assert_eq!(test.server.read_view(read_params).await.is_none(), true);
+    let doc_params = DocIdentifier {
+        doc_id: test.view.id.clone(),
+    };
+    assert_eq!(test.server.read_doc(doc_params).await.is_none(), true);
+}
+
+#[actix_rt::test]
+async fn view_favorite_toggle() {
+    let test = ViewTest::new().await;
+    let view = test.server.create_view(test_view_params(&test.app.id)).await;
+    assert_eq!(test.server.read_favorites().await.is_empty(), true);
+
+    test.server.favorite_view(&view.id).await;
+    test.server.favorite_view(&test.view.id).await;
+    // Starring the view again has no effect
+    test.server.favorite_view(&view.id).await;
+    let favorites = test.server.read_favorites().await;
+    let favorite_ids = favorites.iter().map(|view| view.id.clone()).collect::<Vec<_>>();
+    assert_eq!(favorite_ids, vec![view.id.clone(), test.view.id.clone()]);
+
+    test.server.unfavorite_view(&view.id).await;
+    let favorites = test.server.read_favorites().await;
+    assert_eq!(favorites.len(), 1);
+    assert_eq!(favorites[0].id, test.view.id);
+}
+
+#[actix_rt::test]
+async fn view_favorite_across_workspaces() {
+    let test = ViewTest::new().await;
+    let workspace = create_test_workspace(&test.server).await;
+    let app = create_test_app(&test.server, &workspace.id).await;
+    let view = create_test_view(&test.server, &app.id).await;
+
+    test.server.favorite_view(&test.view.id).await;
+    test.server.favorite_view(&view.id).await;
+    assert_eq!(test.server.read_favorites().await.len(), 2);
+
+    // The views in the trash are hidden
+    test.server.create_view_trash(&view.id).await;
+    let favorites = test.server.read_favorites().await;
+    assert_eq!(favorites.len(), 1);
+    assert_eq!(favorites[0].id, test.view.id);
+}
+
+#[actix_rt::test]
+async fn view_favorite_without_access() {
+    let test = ViewTest::new().await;
+    let other = test.server.register_other_user("nathan@appflowy.io", "nathan").await;
+
+    let error = other.try_favorite_view(&test.view.id).await.unwrap_err();
+    assert_eq!(error.is_permission_denied(), true);
+    assert_eq!(other.read_favorites().await.is_empty(), true);
+}
+
+#[actix_rt::test]
+async fn view_favorite_removed_with_view() {
+    let test = ViewTest::new().await;
+    test.server.favorite_view(&test.view.id).await;
+    let delete_params = ViewIdentifiers {
+        view_ids: vec![test.view.id.clone()],
+    };
+    test.server.delete_view_permanently(delete_params).await;
+    assert_eq!(test.server.read_favorites().await.is_empty(), true);
+
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM view_favorite_table WHERE view_id = $1")
+        .bind(uuid::Uuid::parse_str(&test.view.id).unwrap())
+        .fetch_one(&test.server.pg_pool)
+        .await
+        .unwrap();
+    assert_eq!(count, 0);
+}
+
 #[actix_rt::test]
 async fn workspace_list_read() {
     let mut server = spawn_user_server().await;
@@ -215,3 +754,49 @@ async fn workspace_list_read() {
     let workspaces = server.read_workspaces(read_params).await;
     assert_eq!(workspaces.len(), 4);
 }
+
+#[actix_rt::test]
+async fn workspace_list_paginate() {
+    let server = TestUserServer::new().await;
+    for i in 0..6 {
+        let params = CreateWorkspaceParams {
+            name: format!("{} workspace", i),
+            desc: format!("This is my {} workspace", i),
+        };
+        let _ = server.create_workspace(params).await;
+    }
+
+    // Without the params, all the workspaces are read in one page
+    let all = server.read_workspace_list(None, None).await;
+    assert_eq!(all.len(), 7);
+    assert_eq!(all.next_cursor, None);
+
+    let mut ids = vec![];
+    let mut after: Option<String> = None;
+    loop {
+        let page = server.read_workspace_list(after.as_deref(), Some(3)).await;
+        assert_eq!(page.len() <= 3, true);
+        ids.extend(page.iter().map(|workspace| workspace.id.clone()));
+        match page.next_cursor {
+            None => break,
+            Some(next_cursor) => after = Some(next_cursor),
+        }
+    }
+
+    let mut all_ids = all
+        .iter()
+        .map(|workspace| workspace.id.clone())
+        .collect::<Vec<String>>();
+    all_ids.sort();
+    assert_eq!(ids, all_ids);
+}
+
+#[actix_rt::test]
+async fn workspace_list_with_invalid_params() {
+    let server = TestUserServer::new().await;
+    let error = server.try_read_workspace_list(None, Some(0)).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+
+    let error = server.try_read_workspace_list(Some("123"), None).await.unwrap_err();
+    assert_eq!(error.code, ErrorCode::ParamsInvalid);
+}
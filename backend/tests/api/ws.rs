@@ -0,0 +1,141 @@
+use crate::util::helper::{spawn_server_with_configuration, TestUserServer};
+use actix::Addr;
+use backend::{
+    application::redacted_request_line,
+    service::ws::{entities::SessionCount, WsServer},
+};
+use futures_util::{SinkExt, Stream, StreamExt};
+use std::time::Duration;
+use tokio_tungstenite::tungstenite::{http::StatusCode, protocol::frame::coding::CloseCode, Error, Message};
+
+fn assert_unauthorized(result: Result<impl std::fmt::Debug, Error>) {
+    match result {
+        Err(Error::Http(response)) => assert_eq!(response.status(), StatusCode::UNAUTHORIZED),
+        other => panic!("Expect the handshake to be rejected with 401, but receive {:?}", other),
+    }
+}
+
+#[actix_rt::test]
+async fn ws_connect_without_credentials() {
+    let server = TestUserServer::new().await;
+    let url = format!("ws://{}/ws", server.host);
+    assert_unauthorized(tokio_tungstenite::connect_async(url).await);
+
+    let url = format!("ws://{}/ws?token=invalid", server.host);
+    assert_unauthorized(tokio_tungstenite::connect_async(url).await);
+}
+
+#[actix_rt::test]
+async fn ws_connect_with_token() {
+    let server = TestUserServer::new().await;
+    let url = format!("ws://{}/ws?token={}", server.host, server.user_token());
+    let (_, response) = tokio_tungstenite::connect_async(url).await.unwrap();
+    assert_eq!(response.status(), StatusCode::SWITCHING_PROTOCOLS);
+
+    // The token in the path is still accepted
+    let (_, response) = tokio_tungstenite::connect_async(server.ws_addr()).await.unwrap();
+    assert_eq!(response.status(), StatusCode::SWITCHING_PROTOCOLS);
+}
+
+async fn spawn_heartbeat_server() -> (TestUserServer, Addr<WsServer>) {
+    let server = spawn_server_with_configuration(|c| {
+        c.session.heartbeat_interval_secs = 1;
+        c.session.heartbeat_timeout_secs = 2;
+    })
+    .await;
+    let ws_server = server.app_ctx.ws_server.get_ref().clone();
+    let mut server: TestUserServer = server.into();
+    let response = server.register_user().await;
+    server.user_token = Some(response.token);
+    (server, ws_server)
+}
+
+#[actix_rt::test]
+async fn ws_silent_client_disconnected() {
+    let (server, ws_server) = spawn_heartbeat_server().await;
+    let (mut socket, _) = tokio_tungstenite::connect_async(server.ws_addr()).await.unwrap();
+    assert_eq!(ws_server.send(SessionCount).await.unwrap(), 1);
+
+    // The pings aren't answered while the socket isn't read.
+    tokio::time::sleep(Duration::from_secs(4)).await;
+    assert_eq!(next_close_code(&mut socket).await, CloseCode::Away);
+    assert_eq!(ws_server.send(SessionCount).await.unwrap(), 0);
+}
+
+#[actix_rt::test]
+async fn ws_logout_all_closes_sessions() {
+    let (server, ws_server) = spawn_heartbeat_server().await;
+    let (mut socket, _) = tokio_tungstenite::connect_async(server.ws_addr()).await.unwrap();
+    assert_eq!(ws_server.send(SessionCount).await.unwrap(), 1);
+
+    server.logout_all(server.user_token()).await.unwrap();
+    assert_eq!(next_close_code(&mut socket).await, CloseCode::Policy);
+    assert_eq!(ws_server.send(SessionCount).await.unwrap(), 0);
+
+    // The token is rejected by the handshake like by the HTTP requests
+    assert_unauthorized(tokio_tungstenite::connect_async(server.ws_addr()).await);
+}
+
+async fn next_close_code<S>(socket: &mut S) -> CloseCode
+where
+    S: Stream<Item = Result<Message, Error>> + Unpin,
+{
+    let close_frame = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            match socket.next().await {
+                Some(Ok(Message::Close(frame))) => return frame,
+                Some(Ok(_)) => continue,
+                other => panic!("Expect a close frame, but receive {:?}", other),
+            }
+        }
+    })
+    .await
+    .expect("The connection isn't closed");
+    close_frame.unwrap().code
+}
+
+#[actix_rt::test]
+async fn ws_active_client_kept() {
+    let (server, ws_server) = spawn_heartbeat_server().await;
+    let (mut socket, _) = tokio_tungstenite::connect_async(server.ws_addr()).await.unwrap();
+
+    // Any frame from the client keeps it alive, the pings here.
+    for _ in 0..8 {
+        socket.send(Message::Ping(vec![])).await.unwrap();
+        match tokio::time::timeout(Duration::from_millis(500), socket.next()).await {
+            Err(_) | Ok(Some(Ok(Message::Ping(_)))) | Ok(Some(Ok(Message::Pong(_)))) => {},
+            Ok(other) => panic!("Expect the connection to be kept, but receive {:?}", other),
+        }
+    }
+    assert_eq!(ws_server.send(SessionCount).await.unwrap(), 1);
+}
+
+#[actix_rt::test]
+async fn ws_malformed_message_dropped() {
+    let (server, ws_server) = spawn_heartbeat_server().await;
+    let (mut socket, _) = tokio_tungstenite::connect_async(server.ws_addr()).await.unwrap();
+    socket.send(Message::Binary(vec![0xff; 4])).await.unwrap();
+
+    // The connection is kept for the following frames
+    socket.send(Message::Ping(vec![])).await.unwrap();
+    loop {
+        match tokio::time::timeout(Duration::from_secs(2), socket.next()).await {
+            Ok(Some(Ok(Message::Pong(_)))) => break,
+            Ok(Some(Ok(Message::Ping(_)))) => continue,
+            other => panic!("Expect the pong, but receive {:?}", other),
+        }
+    }
+    assert_eq!(ws_server.send(SessionCount).await.unwrap(), 1);
+}
+
+#[test]
+fn ws_token_redacted_from_request_line() {
+    let request_line = |uri: &str| redacted_request_line(&actix_web::test::TestRequest::with_uri(uri).to_srv_request());
+    assert_eq!(request_line("/ws?token=secret"), "GET /ws?token=<redacted> HTTP/1.1");
+    assert_eq!(
+        request_line("/ws?a=1&token=secret"),
+        "GET /ws?a=1&token=<redacted> HTTP/1.1"
+    );
+    assert_eq!(request_line("/ws/secret"), "GET /ws/<redacted> HTTP/1.1");
+    assert_eq!(request_line("/api/user?a=1"), "GET /api/user?a=1 HTTP/1.1");
+}
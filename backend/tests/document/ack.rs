@@ -0,0 +1,76 @@
+use crate::util::helper::{spawn_server, TestUserServer, ViewTest};
+use actix::{Actor, Context, Handler};
+use actix_web::web::Data;
+use backend::service::{
+    user::LoggedUser,
+    ws::{WsMessageAdaptor, WsUser},
+};
+use flowy_document_infra::{
+    protobuf::{RevAck, RevType, Revision, WsDataType, WsDocumentData},
+    util::md5,
+};
+use lib_ot::core::DeltaBuilder;
+use lib_ws::protobuf::WsMessage;
+use parking_lot::RwLock;
+use protobuf::Message;
+use std::sync::Arc;
+use tokio::time::{sleep, Duration};
+
+#[actix_rt::test]
+async fn doc_revision_acked_with_md5() {
+    // The revisions are only applied by the Editors of the doc's workspace
+    let server = spawn_server().await;
+    let manager = server.app_ctx.doc_biz.manager.clone();
+    let mut server: TestUserServer = server.into();
+    let response = server.register_user().await;
+    server.user_token = Some(response.token);
+    server.user_id = Some(response.user_id.clone());
+    let test = ViewTest::new_with_server(server).await;
+    let doc_id = test.view.id.clone();
+    let pool = Data::new(test.server.pg_pool.clone());
+    let handle = manager.get(&doc_id, pool).await.unwrap().unwrap();
+
+    let user = Arc::new(WsUser::new(LoggedUser::new(&response.user_id)));
+    let received = Received::default();
+    let socket = AckCollector(received.clone()).start().recipient();
+    handle.handle_new_user(user.clone(), 0, socket.clone()).await.unwrap();
+
+    let delta_data = DeltaBuilder::new().insert("abc").retain(1).build().to_bytes().to_vec();
+    let mut revision = Revision::new();
+    revision.set_base_rev_id(0);
+    revision.set_rev_id(1);
+    revision.set_md5(md5(&delta_data));
+    revision.set_delta_data(delta_data);
+    revision.set_doc_id(doc_id.clone());
+    revision.set_ty(RevType::Local);
+    handle.apply_revision(user, socket, revision.clone()).await.unwrap();
+    sleep(Duration::from_millis(100)).await;
+
+    let received = received.read().clone();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].rev_id, 1);
+    assert_eq!(received[0].base_rev_id, 0);
+    assert_eq!(received[0].md5, revision.md5);
+    assert_eq!(handle.document_json().await.unwrap(), r#"[{"insert":"abc\n"}]"#);
+}
+
+type Received = Arc<RwLock<Vec<RevAck>>>;
+
+// Collects the ack messages sent to the socket of a user.
+struct AckCollector(Received);
+
+impl Actor for AckCollector {
+    type Context = Context<Self>;
+}
+
+impl Handler<WsMessageAdaptor> for AckCollector {
+    type Result = ();
+
+    fn handle(&mut self, msg: WsMessageAdaptor, _ctx: &mut Self::Context) {
+        let message = WsMessage::parse_from_bytes(&msg.0).unwrap();
+        let data = WsDocumentData::parse_from_bytes(&message.data).unwrap();
+        if data.ty == WsDataType::Acked {
+            self.0.write().push(RevAck::parse_from_bytes(&data.data).unwrap());
+        }
+    }
+}
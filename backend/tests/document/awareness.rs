@@ -0,0 +1,80 @@
+use crate::{document::helper::create_test_doc, util::helper::spawn_server};
+use actix::{Actor, Context, Handler};
+use actix_web::web::Data;
+use backend::service::{
+    user::LoggedUser,
+    ws::{WsMessageAdaptor, WsUser},
+};
+use flowy_document_infra::protobuf::{DocAwareness, WsDataType, WsDocumentData};
+use lib_ws::protobuf::WsMessage;
+use parking_lot::RwLock;
+use protobuf::Message;
+use std::sync::Arc;
+use tokio::time::{sleep, Duration};
+
+#[actix_rt::test]
+async fn doc_awareness_relayed_to_other_users() {
+    let server = spawn_server().await;
+    let doc_id = create_test_doc(&server.pg_pool, r#"[{"insert":"\n"}]"#).await;
+    let pool = Data::new(server.pg_pool.clone());
+    let handle = server
+        .app_ctx
+        .doc_biz
+        .manager
+        .get(&doc_id, pool)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let (bob, bob_received) = (Arc::new(WsUser::new(LoggedUser::new("bob"))), Received::default());
+    let (alice, alice_received) = (Arc::new(WsUser::new(LoggedUser::new("alice"))), Received::default());
+    let bob_socket = AwarenessCollector(bob_received.clone()).start().recipient();
+    let alice_socket = AwarenessCollector(alice_received.clone()).start().recipient();
+    handle
+        .handle_new_user(bob.clone(), 0, bob_socket.clone())
+        .await
+        .unwrap();
+    handle.handle_new_user(alice, 0, alice_socket).await.unwrap();
+
+    let awareness = DocAwareness {
+        doc_id: doc_id.clone(),
+        user_id: "bob".to_owned(),
+        has_unsynced: true,
+        ..Default::default()
+    };
+    handle.update_awareness(bob, bob_socket, awareness).await.unwrap();
+    sleep(Duration::from_millis(100)).await;
+
+    let received = alice_received.read().clone();
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].user_id, "bob");
+    assert_eq!(received[0].has_unsynced, true);
+    assert_eq!(bob_received.read().is_empty(), true);
+
+    let sessions = handle.sessions().await.unwrap();
+    let bob_session = sessions.iter().find(|session| session.user_id == "bob").unwrap();
+    assert_eq!(bob_session.has_unsynced, true);
+    let alice_session = sessions.iter().find(|session| session.user_id == "alice").unwrap();
+    assert_eq!(alice_session.has_unsynced, false);
+}
+
+type Received = Arc<RwLock<Vec<DocAwareness>>>;
+
+// Collects the awareness messages sent to the socket of a user.
+struct AwarenessCollector(Received);
+
+impl Actor for AwarenessCollector {
+    type Context = Context<Self>;
+}
+
+impl Handler<WsMessageAdaptor> for AwarenessCollector {
+    type Result = ();
+
+    fn handle(&mut self, msg: WsMessageAdaptor, _ctx: &mut Self::Context) {
+        let message = WsMessage::parse_from_bytes(&msg.0).unwrap();
+        let data = WsDocumentData::parse_from_bytes(&message.data).unwrap();
+        if data.ty == WsDataType::Awareness {
+            self.0.write().push(DocAwareness::parse_from_bytes(&data.data).unwrap());
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::util::helper::{TestUserServer, ViewTest};
+use bytes::Bytes;
+use flowy_document_infra::{
+    entities::{
+        doc::{NewDocUser, RevType, Revision},
+        ws::WsDocumentData,
+    },
+    protobuf::{Revision as RevisionPB, WsDataType, WsDocumentData as WsDocumentDataPB},
+};
+use futures_util::{SinkExt, StreamExt};
+use lib_ot::core::{Delta, DeltaBuilder};
+use lib_ws::{protobuf::WsMessage as WsMessagePB, WsMessage, WsModule};
+use protobuf::Message;
+use std::convert::TryInto;
+use tokio::{
+    net::TcpStream,
+    time::{sleep, timeout, Duration},
+};
+use tokio_tungstenite::{tungstenite::Message as TMessage, MaybeTlsStream, WebSocketStream};
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+#[actix_rt::test]
+async fn doc_broadcast_coalesce_revisions() {
+    let server = TestUserServer::new_with_configuration(|c| c.sync.broadcast_coalesce_ms = Some(500)).await;
+    let test = ViewTest::new_with_server(server).await;
+    let doc_id = test.view.id.clone();
+    let mut sender = open_doc(&test.server, &doc_id).await;
+    let mut receiver = open_doc(&test.server, &doc_id).await;
+    sleep(Duration::from_millis(200)).await;
+
+    let deltas = vec![
+        DeltaBuilder::new().insert("a").retain(1).build(),
+        DeltaBuilder::new().retain(1).insert("b").retain(1).build(),
+        DeltaBuilder::new().retain(2).insert("c").retain(1).build(),
+    ];
+    for (base_rev_id, delta) in deltas.into_iter().enumerate() {
+        let base_rev_id = base_rev_id as i64;
+        let revision = Revision::new(base_rev_id, base_rev_id + 1, delta.to_bytes(), &doc_id, RevType::Local);
+        send(&mut sender, WsDocumentData::from(revision)).await;
+    }
+
+    // The three revisions of the session are received as one
+    let revisions = read_pushed_revisions(&mut receiver, Duration::from_secs(2)).await;
+    assert_eq!(revisions.len(), 1);
+    assert_eq!(revisions[0].base_rev_id, 0);
+    assert_eq!(revisions[0].rev_id, 3);
+    let delta = Delta::from_bytes(&revisions[0].delta_data).unwrap();
+    assert_eq!(delta.apply("\n").unwrap(), "abc\n");
+}
+
+async fn open_doc(server: &TestUserServer, doc_id: &str) -> Socket {
+    let (mut socket, _) = tokio_tungstenite::connect_async(server.ws_addr()).await.unwrap();
+    let user = NewDocUser {
+        user_id: server.user_id().to_owned(),
+        rev_id: 0,
+        doc_id: doc_id.to_owned(),
+    };
+    send(&mut socket, WsDocumentData::from(user)).await;
+    socket
+}
+
+async fn send(socket: &mut Socket, document_data: WsDocumentData) {
+    let data: Bytes = document_data.try_into().unwrap();
+    let msg = WsMessage {
+        module: WsModule::Doc,
+        data: data.to_vec(),
+    };
+    socket.send(msg.into()).await.unwrap();
+}
+
+// Reads the revisions pushed to the socket until it stays idle for the
+// duration.
+async fn read_pushed_revisions(socket: &mut Socket, idle: Duration) -> Vec<RevisionPB> {
+    let mut revisions = vec![];
+    while let Ok(Some(msg)) = timeout(idle, socket.next()).await {
+        if let TMessage::Binary(bytes) = msg.unwrap() {
+            let message = WsMessagePB::parse_from_bytes(&bytes).unwrap();
+            let data = WsDocumentDataPB::parse_from_bytes(&message.data).unwrap();
+            if data.ty == WsDataType::PushRev {
+                revisions.push(RevisionPB::parse_from_bytes(&data.data).unwrap());
+            }
+        }
+    }
+    revisions
+}
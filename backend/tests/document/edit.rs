@@ -1,4 +1,5 @@
 use crate::document::helper::{DocScript, DocumentTest};
+use backend::config::SyncSettings;
 use flowy_document_infra::core::{Document, FlowyDoc};
 use lib_ot::core::{Attribute, Interval};
 
@@ -201,3 +202,64 @@ async fn delta_sync_while_local_rev_greater_than_server_rev() {
     ])
     .await;
 }
+
+#[rustfmt::skip]
+//                         ┌─────────┐       ┌─────────┐
+//                         │ Server  │       │ Client  │
+//                         └─────────┘       └─────────┘
+//                              │                 │
+//                              ◀── http request ─┤ Open doc
+//                              │                 │  ┌───────────────┐
+//                              ├──http response──┼─▶│ops: [] rev: 0 │
+//         ┌───────────────────┐│                 │  └───────────────┘
+//         │ops: ["123"] rev: 3││                 │  ┌────────────────────┐
+//         └───────────────────┘│                 │  │ops: ["abc"] rev: 1 │
+//                              │                 │  └────────────────────┘
+//                              ◀─────────────────┤ notify with rev: 1
+//                              │                 │
+//                              ├────Conflict─────▶ the client is 2 revs behind, rebase
+//                              │                 │ ┌──────────────────────────┐
+//                              │                 │ │ops: ["abc123"] rev: 4    │
+//                              │                 │ └──────────────────────────┘
+//                     compose  ◀────Push Rev─────┤
+// ┌──────────────────────────┐ │                 │
+// │ops: ["abc123"] rev: 4    │ │                 │
+// └──────────────────────────┘ │                 │
+#[actix_rt::test]
+async fn delta_sync_while_local_rev_far_behind_server_rev() {
+    let test = DocumentTest::new_with_sync(SyncSettings {
+        max_revisions_behind: Some(1),
+        ..Default::default()
+    })
+    .await;
+    let mut document = Document::new::<FlowyDoc>();
+    document.insert(0, "123").unwrap();
+    let json = document.to_json();
+
+    // Unlike delta_sync_while_local_rev_less_than_server_rev, the local revision is
+    // rebased on the snapshot instead of being transformed.
+    test.run_scripts(vec![
+        DocScript::ClientOpenDoc,
+        DocScript::ServerSaveDocument(json, 3),
+        DocScript::ClientInsertText(0, "abc"),
+        DocScript::ClientConnectWs,
+        DocScript::AssertClient(r#"[{"insert":"abc123\n"}]"#),
+        DocScript::AssertServer(r#"[{"insert":"abc123\n"}]"#, 4),
+    ])
+    .await;
+}
+
+#[actix_rt::test]
+async fn admin_terminate_doc_session() {
+    let test = DocumentTest::new().await;
+    test.run_scripts(vec![
+        DocScript::ClientConnectWs,
+        DocScript::ClientOpenDoc,
+        DocScript::ClientInsertText(0, "abc"),
+        DocScript::AssertServer(r#"[{"insert":"abc\n"}]"#, 1),
+        DocScript::AdminAssertSessions(1),
+        DocScript::AdminTerminateSessions,
+        DocScript::AdminAssertSessions(0),
+    ])
+    .await;
+}
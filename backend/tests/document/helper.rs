@@ -1,5 +1,8 @@
 use actix_web::web::Data;
-use backend::service::doc::{crud::update_doc, doc::DocManager};
+use backend::{
+    config::{QuotaSettings, SyncSettings},
+    service::doc::{crud::update_doc, doc::DocManager},
+};
 use backend_service::config::ServerConfig;
 use flowy_document::services::doc::ClientEditDoc as ClientEditDocContext;
 use flowy_test::{workspace::ViewTest, FlowyTest};
@@ -9,7 +12,13 @@ use sqlx::PgPool;
 use std::sync::Arc;
 use tokio::time::{sleep, Duration};
 // use crate::helper::*;
-use crate::util::helper::{spawn_server, TestServer};
+use crate::util::helper::{
+    read_doc_sessions_request,
+    spawn_server,
+    spawn_server_with_settings,
+    terminate_doc_session_request,
+    TestServer,
+};
 use flowy_document_infra::{entities::doc::DocIdentifier, protobuf::UpdateDocParams};
 use lib_ot::core::{Attribute, Delta, Interval};
 use parking_lot::RwLock;
@@ -27,11 +36,18 @@ pub enum DocScript {
     AssertClient(&'static str),
     AssertServer(&'static str, i64),
     ServerSaveDocument(String, i64), // delta_json, rev_id
+    AdminAssertSessions(usize),
+    AdminTerminateSessions,
 }
 
 impl DocumentTest {
-    pub async fn new() -> Self {
-        let server = spawn_server().await;
+    pub async fn new() -> Self { Self::with_server(spawn_server().await) }
+
+    pub async fn new_with_sync(sync: SyncSettings) -> Self {
+        Self::with_server(spawn_server_with_settings(QuotaSettings::default(), sync).await)
+    }
+
+    fn with_server(server: TestServer) -> Self {
         let server_config = ServerConfig::new(&server.host, "http", "ws");
         let flowy_test = FlowyTest::setup_with(server_config);
         Self { server, flowy_test }
@@ -53,6 +69,7 @@ struct ScriptContext {
     client_user_session: Arc<UserSession>,
     server_doc_manager: Arc<DocManager>,
     server_pg_pool: Data<PgPool>,
+    server_host: String,
     doc_id: String,
 }
 
@@ -67,6 +84,7 @@ impl ScriptContext {
             client_user_session: user_session,
             server_doc_manager: server.app_ctx.doc_biz.manager.clone(),
             server_pg_pool: Data::new(server.pg_pool.clone()),
+            server_host: server.host.clone(),
             doc_id,
         }
     }
@@ -80,6 +98,19 @@ impl ScriptContext {
     }
 
     fn client_edit_context(&self) -> Arc<ClientEditDocContext> { self.client_edit_context.as_ref().unwrap().clone() }
+
+    fn sessions_url(&self) -> String { format!("http://{}/api/admin/docs/{}/sessions", self.server_host, self.doc_id) }
+
+    // Promotes the client user to admin, so it can manage the sessions of the doc.
+    async fn admin_token(&self) -> String {
+        let user_id = self.client_user_session.user_id().unwrap();
+        sqlx::query("UPDATE user_table SET is_admin = true WHERE id = $1")
+            .bind(uuid::Uuid::parse_str(&user_id).unwrap())
+            .execute(self.server_pg_pool.get_ref())
+            .await
+            .unwrap();
+        self.client_user_session.token().unwrap()
+    }
 }
 
 impl Drop for ScriptContext {
@@ -133,6 +164,25 @@ async fn run_scripts(context: Arc<RwLock<ScriptContext>>, scripts: Vec<DocScript
                     let pg_pool = context.read().server_pg_pool.clone();
                     save_doc(&doc_id, json, rev_id, pg_pool).await;
                 },
+                DocScript::AdminAssertSessions(count) => {
+                    sleep(Duration::from_millis(100)).await;
+                    let context = context.read().clone();
+                    let token = context.admin_token().await;
+                    let sessions = read_doc_sessions_request(&token, &context.sessions_url())
+                        .await
+                        .unwrap();
+                    assert_eq!(sessions.items.len(), count);
+                },
+                DocScript::AdminTerminateSessions => {
+                    let context = context.read().clone();
+                    let token = context.admin_token().await;
+                    let url = context.sessions_url();
+                    let sessions = read_doc_sessions_request(&token, &url).await.unwrap();
+                    for session in sessions.items {
+                        let url = format!("{}/{}", url, session.session_id);
+                        terminate_doc_session_request(&token, &url).await.unwrap();
+                    }
+                },
             }
         };
         fut_scripts.push(fut);
@@ -169,3 +219,16 @@ async fn save_doc(doc_id: &str, json: String, rev_id: i64, pool: Data<PgPool>) {
     params.set_rev_id(rev_id);
     let _ = update_doc(pool.get_ref(), params).await.unwrap();
 }
+
+// Creates a doc without a view, for the tests working with the doc manager of
+// the server directly.
+pub async fn create_test_doc(pool: &PgPool, data: &str) -> String {
+    let doc_id = uuid::Uuid::new_v4();
+    sqlx::query("INSERT INTO doc_table(id, data, rev_id) VALUES ($1, $2, 0)")
+        .bind(doc_id)
+        .bind(data)
+        .execute(pool)
+        .await
+        .unwrap();
+    doc_id.to_string()
+}
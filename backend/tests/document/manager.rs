@@ -0,0 +1,103 @@
+use crate::{
+    document::helper::create_test_doc,
+    util::helper::{spawn_server_with_settings, TestUserServer},
+};
+use actix_web::web::Data;
+use backend::config::{QuotaSettings, SyncSettings};
+use bytes::Bytes;
+use flowy_document_infra::entities::{doc::NewDocUser, ws::WsDocumentData};
+use futures_util::SinkExt;
+use lib_ws::{WsMessage, WsModule};
+use std::convert::TryInto;
+use tokio::time::{sleep, Duration};
+
+#[actix_rt::test]
+async fn doc_manager_evict_cold_docs() {
+    let sync = SyncSettings {
+        max_opened_docs: Some(2),
+        ..Default::default()
+    };
+    let server = spawn_server_with_settings(QuotaSettings::default(), sync).await;
+    let manager = server.app_ctx.doc_biz.manager.clone();
+    let pool = Data::new(server.pg_pool.clone());
+
+    let mut doc_ids = vec![];
+    for i in 0..3 {
+        let data = format!(r#"[{{"insert":"{}\n"}}]"#, i);
+        let doc_id = create_test_doc(&server.pg_pool, &data).await;
+        let _ = manager.get(&doc_id, pool.clone()).await.unwrap().unwrap();
+        doc_ids.push(doc_id);
+    }
+    assert_eq!(manager.opened_doc_count(), 2);
+    assert!(manager.get_opened(&doc_ids[0]).is_none());
+    assert!(manager.get_opened(&doc_ids[1]).is_some());
+    assert!(manager.get_opened(&doc_ids[2]).is_some());
+
+    // Accessing the second doc makes the third one the coldest
+    let _ = manager.get(&doc_ids[1], pool.clone()).await.unwrap();
+    let handle = manager.get(&doc_ids[0], pool.clone()).await.unwrap().unwrap();
+    assert_eq!(handle.document_json().await.unwrap(), r#"[{"insert":"0\n"}]"#);
+    assert_eq!(manager.opened_doc_count(), 2);
+    assert!(manager.get_opened(&doc_ids[1]).is_some());
+    assert!(manager.get_opened(&doc_ids[2]).is_none());
+}
+
+#[actix_rt::test]
+async fn doc_manager_keep_docs_in_use() {
+    let sync = SyncSettings {
+        max_opened_docs: Some(1),
+        ..Default::default()
+    };
+    let server = spawn_server_with_settings(QuotaSettings::default(), sync).await;
+    let manager = server.app_ctx.doc_biz.manager.clone();
+    let pool = Data::new(server.pg_pool.clone());
+
+    let doc_id_1 = create_test_doc(&server.pg_pool, r#"[{"insert":"1\n"}]"#).await;
+    let doc_id_2 = create_test_doc(&server.pg_pool, r#"[{"insert":"2\n"}]"#).await;
+    let handle_1 = manager.get(&doc_id_1, pool.clone()).await.unwrap().unwrap();
+    let _handle_2 = manager.get(&doc_id_2, pool.clone()).await.unwrap().unwrap();
+
+    // The first doc is still held, so it's not evicted
+    assert_eq!(manager.opened_doc_count(), 2);
+    drop(handle_1);
+}
+
+#[actix_rt::test]
+async fn doc_manager_keep_docs_with_connected_users() {
+    let sync = SyncSettings {
+        max_opened_docs: Some(1),
+        ..Default::default()
+    };
+    let server = spawn_server_with_settings(QuotaSettings::default(), sync).await;
+    let manager = server.app_ctx.doc_biz.manager.clone();
+    let pool = Data::new(server.pg_pool.clone());
+    let doc_id_1 = create_test_doc(&server.pg_pool, r#"[{"insert":"1\n"}]"#).await;
+    let doc_id_2 = create_test_doc(&server.pg_pool, r#"[{"insert":"2\n"}]"#).await;
+    let doc_id_3 = create_test_doc(&server.pg_pool, r#"[{"insert":"3\n"}]"#).await;
+    let server: TestUserServer = server.into();
+    let server = server.register_other_user("annie@appflowy.io", "annie").await;
+
+    // The first doc is opened by the connected user, so it's not evicted
+    let (mut socket, _) = tokio_tungstenite::connect_async(server.ws_addr()).await.unwrap();
+    let user = NewDocUser {
+        user_id: server.user_id().to_owned(),
+        rev_id: 0,
+        doc_id: doc_id_1.clone(),
+    };
+    let data: Bytes = WsDocumentData::from(user).try_into().unwrap();
+    let msg = WsMessage {
+        module: WsModule::Doc,
+        data: data.to_vec(),
+    };
+    socket.send(msg.into()).await.unwrap();
+    sleep(Duration::from_secs(1)).await;
+    let _ = manager.get(&doc_id_2, pool.clone()).await.unwrap().unwrap();
+    assert_eq!(manager.opened_doc_count(), 2);
+
+    // It's evicted once the user disconnects
+    socket.close(None).await.unwrap();
+    sleep(Duration::from_secs(1)).await;
+    let _ = manager.get(&doc_id_3, pool.clone()).await.unwrap().unwrap();
+    assert_eq!(manager.opened_doc_count(), 1);
+    assert!(manager.get_opened(&doc_id_1).is_none());
+}
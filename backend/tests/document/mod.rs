@@ -1,2 +1,6 @@
+mod ack;
+mod awareness;
+mod broadcast;
 mod edit;
 mod helper;
+mod manager;
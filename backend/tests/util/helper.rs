@@ -1,14 +1,33 @@
+use actix_web::web::Data;
 use backend::{
-    application::{get_connection_pool, init_app_context, Application},
-    config::{get_configuration, DatabaseSettings},
+    application::{get_connection_pool, init_app_context, Application, ShutdownHandle},
+    config::{get_configuration, DatabaseSettings, QuotaSettings, Settings, SyncSettings},
     context::AppContext,
+    service::user::{Mailer, MemoryMailer},
 };
-use backend_service::{errors::ServerError, user_request::*, workspace_request::*};
+use backend_service::{
+    config::HEADER_TOKEN,
+    errors::ServerError,
+    request::HttpRequestBuilder,
+    user_request::*,
+    workspace_request::*,
+};
+use chrono::{DateTime, Utc};
 use flowy_document::services::server::read_doc_request;
-use flowy_document_infra::entities::doc::{Doc, DocIdentifier};
+use flowy_document_infra::entities::doc::{
+    CreateNamedVersionParams,
+    Doc,
+    DocIdentifier,
+    DocStats,
+    NamedVersion,
+    RepeatedDocSession,
+    RepeatedNamedVersion,
+    UpdateDocParams,
+};
 use flowy_user_infra::entities::*;
 use flowy_workspace_infra::entities::prelude::*;
-use sqlx::{Connection, Executor, PgConnection, PgPool};
+use sqlx::{Connection, Executor, PgConnection, PgPool, Row};
+use std::{sync::Arc, time::Duration};
 use uuid::Uuid;
 
 pub struct TestUserServer {
@@ -17,11 +36,18 @@ pub struct TestUserServer {
     pub pg_pool: PgPool,
     pub user_token: Option<String>,
     pub user_id: Option<String>,
+    pub mailer: Arc<MemoryMailer>,
 }
 
 impl TestUserServer {
-    pub async fn new() -> Self {
-        let mut server: TestUserServer = spawn_server().await.into();
+    pub async fn new() -> Self { Self::new_with_quota(QuotaSettings::default()).await }
+
+    pub async fn new_with_quota(quota: QuotaSettings) -> Self {
+        Self::new_with_configuration(|c| c.quota = quota).await
+    }
+
+    pub async fn new_with_configuration<F: FnOnce(&mut Settings)>(configure: F) -> Self {
+        let mut server: TestUserServer = spawn_server_with_configuration(configure).await.into();
         let response = server.register_user().await;
         server.user_token = Some(response.token);
         server.user_id = Some(response.user_id);
@@ -39,6 +65,11 @@ impl TestUserServer {
         let _ = user_sign_out_request(self.user_token(), &url).await.unwrap();
     }
 
+    pub async fn logout_all(&self, token: &str) -> Result<(), ServerError> {
+        let url = format!("{}/api/auth/logout_all", self.http_addr());
+        user_logout_all_request(token, &url).await
+    }
+
     pub fn user_token(&self) -> &str { self.user_token.as_ref().expect("must call register_user first ") }
 
     pub fn user_id(&self) -> &str { self.user_id.as_ref().expect("must call register_user first ") }
@@ -67,14 +98,68 @@ impl TestUserServer {
         workspaces
     }
 
+    pub async fn read_workspace_list(&self, after: Option<&str>, limit: Option<i64>) -> RepeatedWorkspace {
+        self.try_read_workspace_list(after, limit).await.unwrap()
+    }
+
+    pub async fn try_read_workspace_list(
+        &self,
+        after: Option<&str>,
+        limit: Option<i64>,
+    ) -> Result<RepeatedWorkspace, ServerError> {
+        let mut query = vec![];
+        if let Some(after) = after {
+            query.push(format!("after={}", after));
+        }
+        if let Some(limit) = limit {
+            query.push(format!("limit={}", limit));
+        }
+        let url = format!(
+            "{}/api/workspace_list/{}?{}",
+            self.http_addr(),
+            self.user_id(),
+            query.join("&")
+        );
+        read_workspace_list_request(self.user_token(), &url).await
+    }
+
     pub async fn update_workspace(&self, params: UpdateWorkspaceParams) {
+        self.try_update_workspace(params).await.unwrap()
+    }
+
+    pub async fn try_update_workspace(&self, params: UpdateWorkspaceParams) -> Result<(), ServerError> {
         let url = format!("{}/api/workspace", self.http_addr());
-        update_workspace_request(self.user_token(), params, &url).await.unwrap();
+        update_workspace_request(self.user_token(), params, &url).await
     }
 
     pub async fn delete_workspace(&self, params: WorkspaceIdentifier) {
+        self.try_delete_workspace(params).await.unwrap()
+    }
+
+    pub async fn try_delete_workspace(&self, params: WorkspaceIdentifier) -> Result<(), ServerError> {
         let url = format!("{}/api/workspace", self.http_addr());
-        delete_workspace_request(self.user_token(), params, &url).await.unwrap();
+        delete_workspace_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn add_workspace_member(&self, params: AddWorkspaceMemberParams) -> WorkspaceMember {
+        self.try_add_workspace_member(params).await.unwrap()
+    }
+
+    pub async fn try_add_workspace_member(
+        &self,
+        params: AddWorkspaceMemberParams,
+    ) -> Result<WorkspaceMember, ServerError> {
+        let url = format!("{}/api/workspace/members", self.http_addr());
+        add_workspace_member_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn remove_workspace_member(&self, params: RemoveWorkspaceMemberParams) {
+        self.try_remove_workspace_member(params).await.unwrap()
+    }
+
+    pub async fn try_remove_workspace_member(&self, params: RemoveWorkspaceMemberParams) -> Result<(), ServerError> {
+        let url = format!("{}/api/workspace/members", self.http_addr());
+        remove_workspace_member_request(self.user_token(), params, &url).await
     }
 
     pub async fn create_app(&self, params: CreateAppParams) -> App {
@@ -89,26 +174,38 @@ impl TestUserServer {
         app
     }
 
-    pub async fn update_app(&self, params: UpdateAppParams) {
+    pub async fn update_app(&self, params: UpdateAppParams) { self.try_update_app(params).await.unwrap() }
+
+    pub async fn try_update_app(&self, params: UpdateAppParams) -> Result<(), ServerError> {
         let url = format!("{}/api/app", self.http_addr());
-        update_app_request(self.user_token(), params, &url).await.unwrap();
+        update_app_request(self.user_token(), params, &url).await
     }
 
-    pub async fn delete_app(&self, params: AppIdentifier) {
+    pub async fn delete_app(&self, params: AppIdentifier) { self.try_delete_app(params).await.unwrap() }
+
+    pub async fn try_delete_app(&self, params: AppIdentifier) -> Result<(), ServerError> {
         let url = format!("{}/api/app", self.http_addr());
-        delete_app_request(self.user_token(), params, &url).await.unwrap();
+        delete_app_request(self.user_token(), params, &url).await
     }
 
-    pub async fn create_view(&self, params: CreateViewParams) -> View {
+    pub async fn create_view(&self, params: CreateViewParams) -> View { self.try_create_view(params).await.unwrap() }
+
+    pub async fn try_create_view(&self, params: CreateViewParams) -> Result<View, ServerError> {
         let url = format!("{}/api/view", self.http_addr());
-        let view = create_view_request(self.user_token(), params, &url).await.unwrap();
-        view
+        let view = create_view_request(self.user_token(), params, &url).await?;
+        Ok(view)
+    }
+
+    pub async fn duplicate_view(&self, params: ViewIdentifier) -> View {
+        let url = format!("{}/api/view/duplicate", self.http_addr());
+        duplicate_view_request(self.user_token(), params, &url).await.unwrap()
     }
 
-    pub async fn read_view(&self, params: ViewIdentifier) -> Option<View> {
+    pub async fn read_view(&self, params: ViewIdentifier) -> Option<View> { self.try_read_view(params).await.unwrap() }
+
+    pub async fn try_read_view(&self, params: ViewIdentifier) -> Result<Option<View>, ServerError> {
         let url = format!("{}/api/view", self.http_addr());
-        let view = read_view_request(self.user_token(), params, &url).await.unwrap();
-        view
+        read_view_request(self.user_token(), params, &url).await
     }
 
     pub async fn update_view(&self, params: UpdateViewParams) {
@@ -116,17 +213,81 @@ impl TestUserServer {
         update_view_request(self.user_token(), params, &url).await.unwrap();
     }
 
+    pub async fn move_view(&self, params: MoveViewParams) { self.try_move_view(params).await.unwrap() }
+
+    pub async fn try_move_view(&self, params: MoveViewParams) -> Result<(), ServerError> {
+        let url = format!("{}/api/view/move", self.http_addr());
+        move_view_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn reorder_view(&self, params: ReorderViewParams) {
+        let url = format!("{}/api/view/reorder", self.http_addr());
+        reorder_view_request(self.user_token(), params, &url).await.unwrap();
+    }
+
+    pub async fn favorite_view(&self, view_id: &str) { self.try_favorite_view(view_id).await.unwrap() }
+
+    pub async fn try_favorite_view(&self, view_id: &str) -> Result<(), ServerError> {
+        let url = format!("{}/api/view/favorite", self.http_addr());
+        create_favorite_request(self.user_token(), view_id.to_owned().into(), &url).await
+    }
+
+    pub async fn unfavorite_view(&self, view_id: &str) {
+        let url = format!("{}/api/view/favorite", self.http_addr());
+        delete_favorite_request(self.user_token(), view_id.to_owned().into(), &url)
+            .await
+            .unwrap();
+    }
+
+    pub async fn read_favorites(&self) -> Vec<View> {
+        let url = format!("{}/api/favorites", self.http_addr());
+        read_favorites_request(self.user_token(), &url).await.unwrap().items
+    }
+
+    pub async fn touch_view(&self, view_id: &str) { self.try_touch_view(view_id).await.unwrap() }
+
+    pub async fn try_touch_view(&self, view_id: &str) -> Result<(), ServerError> {
+        let url = format!("{}/api/view/{}/touch", self.http_addr(), view_id);
+        touch_view_request(self.user_token(), &url).await
+    }
+
+    pub async fn read_view_last_accessed_time(&self, view_id: &str) -> Option<DateTime<Utc>> {
+        sqlx::query("SELECT last_accessed_at FROM view_access_table WHERE user_id = $1 AND view_id = $2")
+            .bind(Uuid::parse_str(self.user_id()).unwrap())
+            .bind(Uuid::parse_str(view_id).unwrap())
+            .fetch_optional(&self.pg_pool)
+            .await
+            .unwrap()
+            .map(|row| row.get("last_accessed_at"))
+    }
+
     pub async fn delete_view(&self, params: ViewIdentifiers) {
         let url = format!("{}/api/view", self.http_addr());
         delete_view_request(self.user_token(), params, &url).await.unwrap();
     }
 
-    pub async fn create_view_trash(&self, view_id: &str) {
+    pub async fn delete_view_permanently(&self, params: ViewIdentifiers) {
+        let url = format!("{}/api/view?permanent=true", self.http_addr());
+        delete_view_request(self.user_token(), params, &url).await.unwrap();
+    }
+
+    pub async fn create_view_trash(&self, view_id: &str) { self.try_create_view_trash(view_id).await.unwrap() }
+
+    pub async fn try_create_view_trash(&self, view_id: &str) -> Result<(), ServerError> {
         let identifier = TrashIdentifier {
             id: view_id.to_string(),
             ty: TrashType::View,
         };
         let url = format!("{}/api/trash", self.http_addr());
+        create_trash_request(self.user_token(), vec![identifier].into(), &url).await
+    }
+
+    pub async fn create_app_trash(&self, app_id: &str) {
+        let identifier = TrashIdentifier {
+            id: app_id.to_string(),
+            ty: TrashType::App,
+        };
+        let url = format!("{}/api/trash", self.http_addr());
         create_trash_request(self.user_token(), vec![identifier].into(), &url)
             .await
             .unwrap();
@@ -140,6 +301,17 @@ impl TestUserServer {
             .unwrap();
     }
 
+    pub async fn restore_view_trash(&self, view_id: &str) { self.try_restore_view_trash(view_id).await.unwrap() }
+
+    pub async fn try_restore_view_trash(&self, view_id: &str) -> Result<(), ServerError> {
+        let identifier = TrashIdentifier {
+            id: view_id.to_string(),
+            ty: TrashType::View,
+        };
+        let url = format!("{}/api/trash/restore", self.http_addr());
+        restore_trash_request(self.user_token(), vec![identifier].into(), &url).await
+    }
+
     pub async fn read_trash(&self) -> RepeatedTrash {
         let url = format!("{}/api/trash", self.http_addr());
         read_trash_request(self.user_token(), &url).await.unwrap()
@@ -151,6 +323,70 @@ impl TestUserServer {
         doc
     }
 
+    pub async fn try_update_doc(&self, params: UpdateDocParams) -> Result<(), ServerError> {
+        let url = format!("{}/api/doc", self.http_addr());
+        update_doc_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn read_view_stats(&self, view_id: &str) -> DocStats {
+        let url = format!("{}/api/view/{}/stats", self.http_addr(), view_id);
+        read_view_stats_request(self.user_token(), &url).await.unwrap()
+    }
+
+    pub async fn create_named_version(&self, view_id: &str, name: &str) -> Result<NamedVersion, ServerError> {
+        let url = format!("{}/api/view/{}/versions", self.http_addr(), view_id);
+        let params = CreateNamedVersionParams { name: name.to_owned() };
+        create_named_version_request(self.user_token(), params, &url).await
+    }
+
+    pub async fn read_named_versions(&self, view_id: &str) -> RepeatedNamedVersion {
+        let url = format!("{}/api/view/{}/versions", self.http_addr(), view_id);
+        read_named_versions_request(self.user_token(), &url).await.unwrap()
+    }
+
+    pub async fn read_named_version_doc(&self, view_id: &str, version_id: &str) -> Result<Doc, ServerError> {
+        let url = format!("{}/api/view/{}/versions/{}", self.http_addr(), view_id, version_id);
+        read_named_version_doc_request(self.user_token(), &url).await
+    }
+
+    pub async fn delete_named_version(&self, view_id: &str, version_id: &str) -> Result<(), ServerError> {
+        let url = format!("{}/api/view/{}/versions/{}", self.http_addr(), view_id, version_id);
+        delete_named_version_request(self.user_token(), &url).await
+    }
+
+    pub async fn promote_to_admin(&self) {
+        sqlx::query("UPDATE user_table SET is_admin = true WHERE id = $1")
+            .bind(Uuid::parse_str(self.user_id()).unwrap())
+            .execute(&self.pg_pool)
+            .await
+            .unwrap();
+    }
+
+    pub async fn search(&self, query: &str, workspace_id: &str) -> RepeatedSearchResult {
+        let url = format!(
+            "{}/api/search?q={}&workspace_id={}",
+            self.http_addr(),
+            query,
+            workspace_id
+        );
+        search_request(self.user_token(), &url).await.unwrap()
+    }
+
+    pub async fn reindex_workspace(&self, workspace_id: &str) -> Result<SearchReindexResult, ServerError> {
+        let url = format!("{}/api/admin/workspace/{}/reindex", self.http_addr(), workspace_id);
+        reindex_workspace_request(self.user_token(), &url).await
+    }
+
+    pub async fn read_doc_sessions(&self, doc_id: &str) -> Result<RepeatedDocSession, ServerError> {
+        let url = format!("{}/api/admin/docs/{}/sessions", self.http_addr(), doc_id);
+        read_doc_sessions_request(self.user_token(), &url).await
+    }
+
+    pub async fn terminate_doc_session(&self, doc_id: &str, session_id: &str) -> Result<(), ServerError> {
+        let url = format!("{}/api/admin/docs/{}/sessions/{}", self.http_addr(), doc_id, session_id);
+        terminate_doc_session_request(self.user_token(), &url).await
+    }
+
     pub async fn register_user(&self) -> SignUpResponse {
         let params = SignUpParams {
             email: "annie@appflowy.io".to_string(),
@@ -167,6 +403,24 @@ impl TestUserServer {
         response
     }
 
+    // Registers another user on the same server, signed in as that user.
+    pub async fn register_other_user(&self, email: &str, name: &str) -> TestUserServer {
+        let params = SignUpParams {
+            email: email.to_string(),
+            name: name.to_string(),
+            password: "HelloAppFlowy123!".to_string(),
+        };
+        let response = self.register(params).await;
+        TestUserServer {
+            host: self.host.clone(),
+            port: self.port,
+            pg_pool: self.pg_pool.clone(),
+            user_token: Some(response.token),
+            user_id: Some(response.user_id),
+            mailer: self.mailer.clone(),
+        }
+    }
+
     pub fn http_addr(&self) -> String { format!("http://{}", self.host) }
 
     pub fn ws_addr(&self) -> String { format!("ws://{}/ws/{}", self.host, self.user_token.as_ref().unwrap()) }
@@ -180,10 +434,107 @@ impl std::convert::From<TestServer> for TestUserServer {
             pg_pool: server.pg_pool,
             user_token: None,
             user_id: None,
+            mailer: server.mailer,
         }
     }
 }
 
+pub async fn read_view_stats_request(token: &str, url: &str) -> Result<DocStats, ServerError> {
+    let stats = HttpRequestBuilder::new()
+        .get(url)
+        .header(HEADER_TOKEN, token)
+        .response::<DocStats>()
+        .await?;
+    Ok(stats)
+}
+
+pub async fn read_doc_sessions_request(token: &str, url: &str) -> Result<RepeatedDocSession, ServerError> {
+    let sessions = HttpRequestBuilder::new()
+        .get(url)
+        .header(HEADER_TOKEN, token)
+        .response::<RepeatedDocSession>()
+        .await?;
+    Ok(sessions)
+}
+
+pub async fn update_doc_request(token: &str, params: UpdateDocParams, url: &str) -> Result<(), ServerError> {
+    let _ = HttpRequestBuilder::new()
+        .patch(url)
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn search_request(token: &str, url: &str) -> Result<RepeatedSearchResult, ServerError> {
+    let results = HttpRequestBuilder::new()
+        .get(url)
+        .header(HEADER_TOKEN, token)
+        .response::<RepeatedSearchResult>()
+        .await?;
+    Ok(results)
+}
+
+pub async fn reindex_workspace_request(token: &str, url: &str) -> Result<SearchReindexResult, ServerError> {
+    let result = HttpRequestBuilder::new()
+        .post(url)
+        .header(HEADER_TOKEN, token)
+        .response::<SearchReindexResult>()
+        .await?;
+    Ok(result)
+}
+
+pub async fn terminate_doc_session_request(token: &str, url: &str) -> Result<(), ServerError> {
+    let _ = HttpRequestBuilder::new()
+        .delete(url)
+        .header(HEADER_TOKEN, token)
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn create_named_version_request(
+    token: &str,
+    params: CreateNamedVersionParams,
+    url: &str,
+) -> Result<NamedVersion, ServerError> {
+    let version = HttpRequestBuilder::new()
+        .post(url)
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<NamedVersion>()
+        .await?;
+    Ok(version)
+}
+
+pub async fn read_named_versions_request(token: &str, url: &str) -> Result<RepeatedNamedVersion, ServerError> {
+    let versions = HttpRequestBuilder::new()
+        .get(url)
+        .header(HEADER_TOKEN, token)
+        .response::<RepeatedNamedVersion>()
+        .await?;
+    Ok(versions)
+}
+
+pub async fn read_named_version_doc_request(token: &str, url: &str) -> Result<Doc, ServerError> {
+    let doc = HttpRequestBuilder::new()
+        .get(url)
+        .header(HEADER_TOKEN, token)
+        .response::<Doc>()
+        .await?;
+    Ok(doc)
+}
+
+pub async fn delete_named_version_request(token: &str, url: &str) -> Result<(), ServerError> {
+    let _ = HttpRequestBuilder::new()
+        .delete(url)
+        .header(HEADER_TOKEN, token)
+        .send()
+        .await?;
+    Ok(())
+}
+
 pub async fn spawn_user_server() -> TestUserServer {
     let server: TestUserServer = spawn_server().await.into();
     server
@@ -194,27 +545,50 @@ pub struct TestServer {
     pub port: u16,
     pub pg_pool: PgPool,
     pub app_ctx: AppContext,
+    pub mailer: Arc<MemoryMailer>,
+    pub shutdown: ShutdownHandle,
+}
+
+pub async fn spawn_server() -> TestServer { spawn_server_with_quota(QuotaSettings::default()).await }
+
+pub async fn spawn_server_with_quota(quota: QuotaSettings) -> TestServer {
+    spawn_server_with_settings(quota, SyncSettings::default()).await
 }
 
-pub async fn spawn_server() -> TestServer {
+pub async fn spawn_server_with_settings(quota: QuotaSettings, sync: SyncSettings) -> TestServer {
+    spawn_server_with_configuration(|c| {
+        c.quota = quota;
+        c.sync = sync;
+    })
+    .await
+}
+
+pub async fn spawn_server_with_configuration<F: FnOnce(&mut Settings)>(configure: F) -> TestServer {
     let database_name = Uuid::new_v4().to_string();
     let configuration = {
         let mut c = get_configuration().expect("Failed to read configuration.");
         c.database.database_name = database_name.clone();
         // Use a random OS port
         c.application.port = 0;
+        configure(&mut c);
         c
     };
 
     let _ = configure_database(&configuration.database).await;
-    let app_ctx = init_app_context(&configuration).await;
+    let mut app_ctx = init_app_context(&configuration).await;
+    // The mails are kept in memory so the tests can read the tokens
+    let mailer = Arc::new(MemoryMailer::default());
+    let dyn_mailer: Arc<dyn Mailer> = mailer.clone();
+    app_ctx.mailer = Data::new(dyn_mailer);
     let application = Application::build(configuration.clone(), app_ctx.clone())
         .await
-        .expect("Failed to build application.");
+        .expect("Failed to build application.")
+        .shutdown_timeout(Duration::from_secs(5));
     let application_port = application.port();
+    let shutdown = application.shutdown_handle();
 
     let _ = tokio::spawn(async {
-        let _ = application.run_until_stopped();
+        let _ = application.run_until_stopped().await;
         // drop_test_database(database_name).await;
     });
 
@@ -225,6 +599,8 @@ pub async fn spawn_server() -> TestServer {
             .await
             .expect("Failed to connect to the database"),
         app_ctx,
+        mailer,
+        shutdown,
     }
 }
 
@@ -287,6 +663,7 @@ pub async fn create_test_app(server: &TestUserServer, workspace_id: &str) -> App
         name: "My first app".to_string(),
         desc: "This is my first app".to_string(),
         color_style: ColorStyle::default(),
+        parent_id: None,
     };
 
     let app = server.create_app(params).await;
@@ -294,13 +671,16 @@ pub async fn create_test_app(server: &TestUserServer, workspace_id: &str) -> App
 }
 
 pub async fn create_test_view(application: &TestUserServer, app_id: &str) -> View {
+    let app = application.create_view(test_view_params(app_id)).await;
+    app
+}
+
+pub fn test_view_params(app_id: &str) -> CreateViewParams {
     let name = "My first view".to_string();
     let desc = "This is my first view".to_string();
     let thumbnail = "http://1.png".to_string();
 
-    let params = CreateViewParams::new(app_id.to_owned(), name, desc, ViewType::Doc, thumbnail);
-    let app = application.create_view(params).await;
-    app
+    CreateViewParams::new(app_id.to_owned(), name, desc, ViewType::Doc, thumbnail)
 }
 
 pub struct WorkspaceTest {
@@ -331,6 +711,17 @@ impl AppTest {
         let app = create_test_app(&server, &workspace.id).await;
         Self { server, workspace, app }
     }
+
+    pub async fn create_child_app(&self, parent_id: &str) -> App {
+        let params = CreateAppParams {
+            workspace_id: self.workspace.id.clone(),
+            name: "My child app".to_string(),
+            desc: "This is my child app".to_string(),
+            color_style: ColorStyle::default(),
+            parent_id: Some(parent_id.to_owned()),
+        };
+        self.server.create_app(params).await
+    }
 }
 
 pub struct ViewTest {
@@ -341,8 +732,9 @@ pub struct ViewTest {
 }
 
 impl ViewTest {
-    pub async fn new() -> Self {
-        let server = TestUserServer::new().await;
+    pub async fn new() -> Self { Self::new_with_server(TestUserServer::new().await).await }
+
+    pub async fn new_with_server(server: TestUserServer) -> Self {
         let workspace = create_test_workspace(&server).await;
         let app = create_test_app(&server, &workspace.id).await;
         let view = create_test_view(&server, &app.id).await;
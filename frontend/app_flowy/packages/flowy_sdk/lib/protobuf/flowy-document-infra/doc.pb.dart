@@ -371,6 +371,81 @@ class NewDocUser extends $pb.GeneratedMessage {
   void clearDocId() => clearField(3);
 }
 
+class DocAwareness extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'DocAwareness', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'docId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'userId')
+    ..aOB(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'hasUnsynced')
+    ..hasRequiredFields = false
+  ;
+
+  DocAwareness._() : super();
+  factory DocAwareness({
+    $core.String? docId,
+    $core.String? userId,
+    $core.bool? hasUnsynced,
+  }) {
+    final _result = create();
+    if (docId != null) {
+      _result.docId = docId;
+    }
+    if (userId != null) {
+      _result.userId = userId;
+    }
+    if (hasUnsynced != null) {
+      _result.hasUnsynced = hasUnsynced;
+    }
+    return _result;
+  }
+  factory DocAwareness.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory DocAwareness.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  DocAwareness clone() => DocAwareness()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  DocAwareness copyWith(void Function(DocAwareness) updates) => super.copyWith((message) => updates(message as DocAwareness)) as DocAwareness; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static DocAwareness create() => DocAwareness._();
+  DocAwareness createEmptyInstance() => create();
+  static $pb.PbList<DocAwareness> createRepeated() => $pb.PbList<DocAwareness>();
+  @$core.pragma('dart2js:noInline')
+  static DocAwareness getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<DocAwareness>(create);
+  static DocAwareness? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get docId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set docId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasDocId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearDocId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get userId => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set userId($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasUserId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearUserId() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.bool get hasUnsynced => $_getBF(2);
+  @$pb.TagNumber(3)
+  set hasUnsynced($core.bool v) { $_setBool(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasHasUnsynced() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearHasUnsynced() => clearField(3);
+}
+
 class DocIdentifier extends $pb.GeneratedMessage {
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'DocIdentifier', createEmptyInstance: create)
     ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'docId')
@@ -418,3 +493,455 @@ class DocIdentifier extends $pb.GeneratedMessage {
   void clearDocId() => clearField(1);
 }
 
+class DocSession extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'DocSession', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'sessionId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'userId')
+    ..aInt64(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'lastActivityTime')
+    ..aOB(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'hasUnsynced')
+    ..hasRequiredFields = false
+  ;
+
+  DocSession._() : super();
+  factory DocSession({
+    $core.String? sessionId,
+    $core.String? userId,
+    $fixnum.Int64? lastActivityTime,
+    $core.bool? hasUnsynced,
+  }) {
+    final _result = create();
+    if (sessionId != null) {
+      _result.sessionId = sessionId;
+    }
+    if (userId != null) {
+      _result.userId = userId;
+    }
+    if (lastActivityTime != null) {
+      _result.lastActivityTime = lastActivityTime;
+    }
+    if (hasUnsynced != null) {
+      _result.hasUnsynced = hasUnsynced;
+    }
+    return _result;
+  }
+  factory DocSession.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory DocSession.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  DocSession clone() => DocSession()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  DocSession copyWith(void Function(DocSession) updates) => super.copyWith((message) => updates(message as DocSession)) as DocSession; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static DocSession create() => DocSession._();
+  DocSession createEmptyInstance() => create();
+  static $pb.PbList<DocSession> createRepeated() => $pb.PbList<DocSession>();
+  @$core.pragma('dart2js:noInline')
+  static DocSession getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<DocSession>(create);
+  static DocSession? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get sessionId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set sessionId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasSessionId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearSessionId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get userId => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set userId($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasUserId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearUserId() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $fixnum.Int64 get lastActivityTime => $_getI64(2);
+  @$pb.TagNumber(3)
+  set lastActivityTime($fixnum.Int64 v) { $_setInt64(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasLastActivityTime() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearLastActivityTime() => clearField(3);
+
+  @$pb.TagNumber(4)
+  $core.bool get hasUnsynced => $_getBF(3);
+  @$pb.TagNumber(4)
+  set hasUnsynced($core.bool v) { $_setBool(3, v); }
+  @$pb.TagNumber(4)
+  $core.bool hasHasUnsynced() => $_has(3);
+  @$pb.TagNumber(4)
+  void clearHasUnsynced() => clearField(4);
+}
+
+class RepeatedDocSession extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RepeatedDocSession', createEmptyInstance: create)
+    ..pc<DocSession>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'items', $pb.PbFieldType.PM, subBuilder: DocSession.create)
+    ..hasRequiredFields = false
+  ;
+
+  RepeatedDocSession._() : super();
+  factory RepeatedDocSession({
+    $core.Iterable<DocSession>? items,
+  }) {
+    final _result = create();
+    if (items != null) {
+      _result.items.addAll(items);
+    }
+    return _result;
+  }
+  factory RepeatedDocSession.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RepeatedDocSession.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RepeatedDocSession clone() => RepeatedDocSession()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RepeatedDocSession copyWith(void Function(RepeatedDocSession) updates) => super.copyWith((message) => updates(message as RepeatedDocSession)) as RepeatedDocSession; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RepeatedDocSession create() => RepeatedDocSession._();
+  RepeatedDocSession createEmptyInstance() => create();
+  static $pb.PbList<RepeatedDocSession> createRepeated() => $pb.PbList<RepeatedDocSession>();
+  @$core.pragma('dart2js:noInline')
+  static RepeatedDocSession getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RepeatedDocSession>(create);
+  static RepeatedDocSession? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.List<DocSession> get items => $_getList(0);
+}
+
+class DocStats extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'DocStats', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'docId')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'wordCount')
+    ..aInt64(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'charCount')
+    ..aInt64(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'lineCount')
+    ..aInt64(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'revCount')
+    ..aInt64(6, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'modifiedTime')
+    ..hasRequiredFields = false
+  ;
+
+  DocStats._() : super();
+  factory DocStats({
+    $core.String? docId,
+    $fixnum.Int64? wordCount,
+    $fixnum.Int64? charCount,
+    $fixnum.Int64? lineCount,
+    $fixnum.Int64? revCount,
+    $fixnum.Int64? modifiedTime,
+  }) {
+    final _result = create();
+    if (docId != null) {
+      _result.docId = docId;
+    }
+    if (wordCount != null) {
+      _result.wordCount = wordCount;
+    }
+    if (charCount != null) {
+      _result.charCount = charCount;
+    }
+    if (lineCount != null) {
+      _result.lineCount = lineCount;
+    }
+    if (revCount != null) {
+      _result.revCount = revCount;
+    }
+    if (modifiedTime != null) {
+      _result.modifiedTime = modifiedTime;
+    }
+    return _result;
+  }
+  factory DocStats.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory DocStats.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  DocStats clone() => DocStats()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  DocStats copyWith(void Function(DocStats) updates) => super.copyWith((message) => updates(message as DocStats)) as DocStats; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static DocStats create() => DocStats._();
+  DocStats createEmptyInstance() => create();
+  static $pb.PbList<DocStats> createRepeated() => $pb.PbList<DocStats>();
+  @$core.pragma('dart2js:noInline')
+  static DocStats getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<DocStats>(create);
+  static DocStats? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get docId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set docId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasDocId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearDocId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get wordCount => $_getI64(1);
+  @$pb.TagNumber(2)
+  set wordCount($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasWordCount() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearWordCount() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $fixnum.Int64 get charCount => $_getI64(2);
+  @$pb.TagNumber(3)
+  set charCount($fixnum.Int64 v) { $_setInt64(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasCharCount() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearCharCount() => clearField(3);
+
+  @$pb.TagNumber(4)
+  $fixnum.Int64 get lineCount => $_getI64(3);
+  @$pb.TagNumber(4)
+  set lineCount($fixnum.Int64 v) { $_setInt64(3, v); }
+  @$pb.TagNumber(4)
+  $core.bool hasLineCount() => $_has(3);
+  @$pb.TagNumber(4)
+  void clearLineCount() => clearField(4);
+
+  @$pb.TagNumber(5)
+  $fixnum.Int64 get revCount => $_getI64(4);
+  @$pb.TagNumber(5)
+  set revCount($fixnum.Int64 v) { $_setInt64(4, v); }
+  @$pb.TagNumber(5)
+  $core.bool hasRevCount() => $_has(4);
+  @$pb.TagNumber(5)
+  void clearRevCount() => clearField(5);
+
+  @$pb.TagNumber(6)
+  $fixnum.Int64 get modifiedTime => $_getI64(5);
+  @$pb.TagNumber(6)
+  set modifiedTime($fixnum.Int64 v) { $_setInt64(5, v); }
+  @$pb.TagNumber(6)
+  $core.bool hasModifiedTime() => $_has(5);
+  @$pb.TagNumber(6)
+  void clearModifiedTime() => clearField(6);
+}
+
+class CreateNamedVersionParams extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'CreateNamedVersionParams', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
+    ..hasRequiredFields = false
+  ;
+
+  CreateNamedVersionParams._() : super();
+  factory CreateNamedVersionParams({
+    $core.String? name,
+  }) {
+    final _result = create();
+    if (name != null) {
+      _result.name = name;
+    }
+    return _result;
+  }
+  factory CreateNamedVersionParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory CreateNamedVersionParams.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  CreateNamedVersionParams clone() => CreateNamedVersionParams()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  CreateNamedVersionParams copyWith(void Function(CreateNamedVersionParams) updates) => super.copyWith((message) => updates(message as CreateNamedVersionParams)) as CreateNamedVersionParams; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static CreateNamedVersionParams create() => CreateNamedVersionParams._();
+  CreateNamedVersionParams createEmptyInstance() => create();
+  static $pb.PbList<CreateNamedVersionParams> createRepeated() => $pb.PbList<CreateNamedVersionParams>();
+  @$core.pragma('dart2js:noInline')
+  static CreateNamedVersionParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<CreateNamedVersionParams>(create);
+  static CreateNamedVersionParams? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get name => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set name($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasName() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearName() => clearField(1);
+}
+
+class NamedVersion extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'NamedVersion', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'id')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'docId')
+    ..aInt64(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'revId')
+    ..aOS(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
+    ..aOS(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'createdBy')
+    ..aInt64(6, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'createdAt')
+    ..hasRequiredFields = false
+  ;
+
+  NamedVersion._() : super();
+  factory NamedVersion({
+    $core.String? id,
+    $core.String? docId,
+    $fixnum.Int64? revId,
+    $core.String? name,
+    $core.String? createdBy,
+    $fixnum.Int64? createdAt,
+  }) {
+    final _result = create();
+    if (id != null) {
+      _result.id = id;
+    }
+    if (docId != null) {
+      _result.docId = docId;
+    }
+    if (revId != null) {
+      _result.revId = revId;
+    }
+    if (name != null) {
+      _result.name = name;
+    }
+    if (createdBy != null) {
+      _result.createdBy = createdBy;
+    }
+    if (createdAt != null) {
+      _result.createdAt = createdAt;
+    }
+    return _result;
+  }
+  factory NamedVersion.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory NamedVersion.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  NamedVersion clone() => NamedVersion()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  NamedVersion copyWith(void Function(NamedVersion) updates) => super.copyWith((message) => updates(message as NamedVersion)) as NamedVersion; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static NamedVersion create() => NamedVersion._();
+  NamedVersion createEmptyInstance() => create();
+  static $pb.PbList<NamedVersion> createRepeated() => $pb.PbList<NamedVersion>();
+  @$core.pragma('dart2js:noInline')
+  static NamedVersion getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<NamedVersion>(create);
+  static NamedVersion? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get id => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set id($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get docId => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set docId($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasDocId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearDocId() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $fixnum.Int64 get revId => $_getI64(2);
+  @$pb.TagNumber(3)
+  set revId($fixnum.Int64 v) { $_setInt64(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasRevId() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearRevId() => clearField(3);
+
+  @$pb.TagNumber(4)
+  $core.String get name => $_getSZ(3);
+  @$pb.TagNumber(4)
+  set name($core.String v) { $_setString(3, v); }
+  @$pb.TagNumber(4)
+  $core.bool hasName() => $_has(3);
+  @$pb.TagNumber(4)
+  void clearName() => clearField(4);
+
+  @$pb.TagNumber(5)
+  $core.String get createdBy => $_getSZ(4);
+  @$pb.TagNumber(5)
+  set createdBy($core.String v) { $_setString(4, v); }
+  @$pb.TagNumber(5)
+  $core.bool hasCreatedBy() => $_has(4);
+  @$pb.TagNumber(5)
+  void clearCreatedBy() => clearField(5);
+
+  @$pb.TagNumber(6)
+  $fixnum.Int64 get createdAt => $_getI64(5);
+  @$pb.TagNumber(6)
+  set createdAt($fixnum.Int64 v) { $_setInt64(5, v); }
+  @$pb.TagNumber(6)
+  $core.bool hasCreatedAt() => $_has(5);
+  @$pb.TagNumber(6)
+  void clearCreatedAt() => clearField(6);
+}
+
+class RepeatedNamedVersion extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RepeatedNamedVersion', createEmptyInstance: create)
+    ..pc<NamedVersion>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'items', $pb.PbFieldType.PM, subBuilder: NamedVersion.create)
+    ..hasRequiredFields = false
+  ;
+
+  RepeatedNamedVersion._() : super();
+  factory RepeatedNamedVersion({
+    $core.Iterable<NamedVersion>? items,
+  }) {
+    final _result = create();
+    if (items != null) {
+      _result.items.addAll(items);
+    }
+    return _result;
+  }
+  factory RepeatedNamedVersion.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RepeatedNamedVersion.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RepeatedNamedVersion clone() => RepeatedNamedVersion()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RepeatedNamedVersion copyWith(void Function(RepeatedNamedVersion) updates) => super.copyWith((message) => updates(message as RepeatedNamedVersion)) as RepeatedNamedVersion; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RepeatedNamedVersion create() => RepeatedNamedVersion._();
+  RepeatedNamedVersion createEmptyInstance() => create();
+  static $pb.PbList<RepeatedNamedVersion> createRepeated() => $pb.PbList<RepeatedNamedVersion>();
+  @$core.pragma('dart2js:noInline')
+  static RepeatedNamedVersion getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RepeatedNamedVersion>(create);
+  static RepeatedNamedVersion? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.List<NamedVersion> get items => $_getList(0);
+}
+
@@ -67,6 +67,18 @@ const NewDocUser$json = const {
 
 /// Descriptor for `NewDocUser`. Decode as a `google.protobuf.DescriptorProto`.
 final $typed_data.Uint8List newDocUserDescriptor = $convert.base64Decode('CgpOZXdEb2NVc2VyEhcKB3VzZXJfaWQYASABKAlSBnVzZXJJZBIVCgZyZXZfaWQYAiABKANSBXJldklkEhUKBmRvY19pZBgDIAEoCVIFZG9jSWQ=');
+@$core.Deprecated('Use docAwarenessDescriptor instead')
+const DocAwareness$json = const {
+  '1': 'DocAwareness',
+  '2': const [
+    const {'1': 'doc_id', '3': 1, '4': 1, '5': 9, '10': 'docId'},
+    const {'1': 'user_id', '3': 2, '4': 1, '5': 9, '10': 'userId'},
+    const {'1': 'has_unsynced', '3': 3, '4': 1, '5': 8, '10': 'hasUnsynced'},
+  ],
+};
+
+/// Descriptor for `DocAwareness`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List docAwarenessDescriptor = $convert.base64Decode('CgxEb2NBd2FyZW5lc3MSFQoGZG9jX2lkGAEgASgJUgVkb2NJZBIXCgd1c2VyX2lkGAIgASgJUgZ1c2VySWQSIQoMaGFzX3Vuc3luY2VkGAMgASgIUgtoYXNVbnN5bmNlZA==');
 @$core.Deprecated('Use docIdentifierDescriptor instead')
 const DocIdentifier$json = const {
   '1': 'DocIdentifier',
@@ -77,3 +89,76 @@ const DocIdentifier$json = const {
 
 /// Descriptor for `DocIdentifier`. Decode as a `google.protobuf.DescriptorProto`.
 final $typed_data.Uint8List docIdentifierDescriptor = $convert.base64Decode('Cg1Eb2NJZGVudGlmaWVyEhUKBmRvY19pZBgBIAEoCVIFZG9jSWQ=');
+@$core.Deprecated('Use docSessionDescriptor instead')
+const DocSession$json = const {
+  '1': 'DocSession',
+  '2': const [
+    const {'1': 'session_id', '3': 1, '4': 1, '5': 9, '10': 'sessionId'},
+    const {'1': 'user_id', '3': 2, '4': 1, '5': 9, '10': 'userId'},
+    const {'1': 'last_activity_time', '3': 3, '4': 1, '5': 3, '10': 'lastActivityTime'},
+    const {'1': 'has_unsynced', '3': 4, '4': 1, '5': 8, '10': 'hasUnsynced'},
+  ],
+};
+
+/// Descriptor for `DocSession`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List docSessionDescriptor = $convert.base64Decode('CgpEb2NTZXNzaW9uEh0KCnNlc3Npb25faWQYASABKAlSCXNlc3Npb25JZBIXCgd1c2VyX2lkGAIgASgJUgZ1c2VySWQSLAoSbGFzdF9hY3Rpdml0eV90aW1lGAMgASgDUhBsYXN0QWN0aXZpdHlUaW1lEiEKDGhhc191bnN5bmNlZBgEIAEoCFILaGFzVW5zeW5jZWQ=');
+@$core.Deprecated('Use repeatedDocSessionDescriptor instead')
+const RepeatedDocSession$json = const {
+  '1': 'RepeatedDocSession',
+  '2': const [
+    const {'1': 'items', '3': 1, '4': 3, '5': 11, '6': '.DocSession', '10': 'items'},
+  ],
+};
+
+/// Descriptor for `RepeatedDocSession`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List repeatedDocSessionDescriptor = $convert.base64Decode('ChJSZXBlYXRlZERvY1Nlc3Npb24SIQoFaXRlbXMYASADKAsyCy5Eb2NTZXNzaW9uUgVpdGVtcw==');
+@$core.Deprecated('Use docStatsDescriptor instead')
+const DocStats$json = const {
+  '1': 'DocStats',
+  '2': const [
+    const {'1': 'doc_id', '3': 1, '4': 1, '5': 9, '10': 'docId'},
+    const {'1': 'word_count', '3': 2, '4': 1, '5': 3, '10': 'wordCount'},
+    const {'1': 'char_count', '3': 3, '4': 1, '5': 3, '10': 'charCount'},
+    const {'1': 'line_count', '3': 4, '4': 1, '5': 3, '10': 'lineCount'},
+    const {'1': 'rev_count', '3': 5, '4': 1, '5': 3, '10': 'revCount'},
+    const {'1': 'modified_time', '3': 6, '4': 1, '5': 3, '10': 'modifiedTime'},
+  ],
+};
+
+/// Descriptor for `DocStats`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List docStatsDescriptor = $convert.base64Decode('CghEb2NTdGF0cxIVCgZkb2NfaWQYASABKAlSBWRvY0lkEh0KCndvcmRfY291bnQYAiABKANSCXdvcmRDb3VudBIdCgpjaGFyX2NvdW50GAMgASgDUgljaGFyQ291bnQSHQoKbGluZV9jb3VudBgEIAEoA1IJbGluZUNvdW50EhsKCXJldl9jb3VudBgFIAEoA1IIcmV2Q291bnQSIwoNbW9kaWZpZWRfdGltZRgGIAEoA1IMbW9kaWZpZWRUaW1l');
+@$core.Deprecated('Use createNamedVersionParamsDescriptor instead')
+const CreateNamedVersionParams$json = const {
+  '1': 'CreateNamedVersionParams',
+  '2': const [
+    const {'1': 'name', '3': 1, '4': 1, '5': 9, '10': 'name'},
+  ],
+};
+
+/// Descriptor for `CreateNamedVersionParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List createNamedVersionParamsDescriptor = $convert.base64Decode('ChhDcmVhdGVOYW1lZFZlcnNpb25QYXJhbXMSEgoEbmFtZRgBIAEoCVIEbmFtZQ==');
+@$core.Deprecated('Use namedVersionDescriptor instead')
+const NamedVersion$json = const {
+  '1': 'NamedVersion',
+  '2': const [
+    const {'1': 'id', '3': 1, '4': 1, '5': 9, '10': 'id'},
+    const {'1': 'doc_id', '3': 2, '4': 1, '5': 9, '10': 'docId'},
+    const {'1': 'rev_id', '3': 3, '4': 1, '5': 3, '10': 'revId'},
+    const {'1': 'name', '3': 4, '4': 1, '5': 9, '10': 'name'},
+    const {'1': 'created_by', '3': 5, '4': 1, '5': 9, '10': 'createdBy'},
+    const {'1': 'created_at', '3': 6, '4': 1, '5': 3, '10': 'createdAt'},
+  ],
+};
+
+/// Descriptor for `NamedVersion`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List namedVersionDescriptor = $convert.base64Decode('CgxOYW1lZFZlcnNpb24SDgoCaWQYASABKAlSAmlkEhUKBmRvY19pZBgCIAEoCVIFZG9jSWQSFQoGcmV2X2lkGAMgASgDUgVyZXZJZBISCgRuYW1lGAQgASgJUgRuYW1lEh0KCmNyZWF0ZWRfYnkYBSABKAlSCWNyZWF0ZWRCeRIdCgpjcmVhdGVkX2F0GAYgASgDUgljcmVhdGVkQXQ=');
+@$core.Deprecated('Use repeatedNamedVersionDescriptor instead')
+const RepeatedNamedVersion$json = const {
+  '1': 'RepeatedNamedVersion',
+  '2': const [
+    const {'1': 'items', '3': 1, '4': 3, '5': 11, '6': '.NamedVersion', '10': 'items'},
+  ],
+};
+
+/// Descriptor for `RepeatedNamedVersion`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List repeatedNamedVersionDescriptor = $convert.base64Decode('ChRSZXBlYXRlZE5hbWVkVmVyc2lvbhIjCgVpdGVtcxgBIAMoCzINLk5hbWVkVmVyc2lvblIFaXRlbXM=');
@@ -61,6 +61,81 @@ class RevId extends $pb.GeneratedMessage {
   void clearValue() => clearField(1);
 }
 
+class RevAck extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RevAck', createEmptyInstance: create)
+    ..aInt64(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'revId')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'baseRevId')
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'md5')
+    ..hasRequiredFields = false
+  ;
+
+  RevAck._() : super();
+  factory RevAck({
+    $fixnum.Int64? revId,
+    $fixnum.Int64? baseRevId,
+    $core.String? md5,
+  }) {
+    final _result = create();
+    if (revId != null) {
+      _result.revId = revId;
+    }
+    if (baseRevId != null) {
+      _result.baseRevId = baseRevId;
+    }
+    if (md5 != null) {
+      _result.md5 = md5;
+    }
+    return _result;
+  }
+  factory RevAck.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RevAck.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RevAck clone() => RevAck()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RevAck copyWith(void Function(RevAck) updates) => super.copyWith((message) => updates(message as RevAck)) as RevAck; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RevAck create() => RevAck._();
+  RevAck createEmptyInstance() => create();
+  static $pb.PbList<RevAck> createRepeated() => $pb.PbList<RevAck>();
+  @$core.pragma('dart2js:noInline')
+  static RevAck getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RevAck>(create);
+  static RevAck? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $fixnum.Int64 get revId => $_getI64(0);
+  @$pb.TagNumber(1)
+  set revId($fixnum.Int64 v) { $_setInt64(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasRevId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearRevId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get baseRevId => $_getI64(1);
+  @$pb.TagNumber(2)
+  set baseRevId($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasBaseRevId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearBaseRevId() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get md5 => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set md5($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasMd5() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearMd5() => clearField(3);
+}
+
 class Revision extends $pb.GeneratedMessage {
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'Revision', createEmptyInstance: create)
     ..aInt64(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'baseRevId')
@@ -69,6 +144,7 @@ class Revision extends $pb.GeneratedMessage {
     ..aOS(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'md5')
     ..aOS(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'docId')
     ..e<RevType>(6, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'ty', $pb.PbFieldType.OE, defaultOrMaker: RevType.Local, valueOf: RevType.valueOf, enumValues: RevType.values)
+    ..aInt64(7, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'createdAt')
     ..hasRequiredFields = false
   ;
 
@@ -80,6 +156,7 @@ class Revision extends $pb.GeneratedMessage {
     $core.String? md5,
     $core.String? docId,
     RevType? ty,
+    $fixnum.Int64? createdAt,
   }) {
     final _result = create();
     if (baseRevId != null) {
@@ -100,6 +177,9 @@ class Revision extends $pb.GeneratedMessage {
     if (ty != null) {
       _result.ty = ty;
     }
+    if (createdAt != null) {
+      _result.createdAt = createdAt;
+    }
     return _result;
   }
   factory Revision.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -176,6 +256,15 @@ class Revision extends $pb.GeneratedMessage {
   $core.bool hasTy() => $_has(5);
   @$pb.TagNumber(6)
   void clearTy() => clearField(6);
+
+  @$pb.TagNumber(7)
+  $fixnum.Int64 get createdAt => $_getI64(6);
+  @$pb.TagNumber(7)
+  set createdAt($fixnum.Int64 v) { $_setInt64(6, v); }
+  @$pb.TagNumber(7)
+  $core.bool hasCreatedAt() => $_has(6);
+  @$pb.TagNumber(7)
+  void clearCreatedAt() => clearField(7);
 }
 
 class RevisionRange extends $pb.GeneratedMessage {
@@ -29,6 +29,18 @@ const RevId$json = const {
 
 /// Descriptor for `RevId`. Decode as a `google.protobuf.DescriptorProto`.
 final $typed_data.Uint8List revIdDescriptor = $convert.base64Decode('CgVSZXZJZBIUCgV2YWx1ZRgBIAEoA1IFdmFsdWU=');
+@$core.Deprecated('Use revAckDescriptor instead')
+const RevAck$json = const {
+  '1': 'RevAck',
+  '2': const [
+    const {'1': 'rev_id', '3': 1, '4': 1, '5': 3, '10': 'revId'},
+    const {'1': 'base_rev_id', '3': 2, '4': 1, '5': 3, '10': 'baseRevId'},
+    const {'1': 'md5', '3': 3, '4': 1, '5': 9, '10': 'md5'},
+  ],
+};
+
+/// Descriptor for `RevAck`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List revAckDescriptor = $convert.base64Decode('CgZSZXZBY2sSFQoGcmV2X2lkGAEgASgDUgVyZXZJZBIeCgtiYXNlX3Jldl9pZBgCIAEoA1IJYmFzZVJldklkEhAKA21kNRgDIAEoCVIDbWQ1');
 @$core.Deprecated('Use revisionDescriptor instead')
 const Revision$json = const {
   '1': 'Revision',
@@ -39,11 +51,12 @@ const Revision$json = const {
     const {'1': 'md5', '3': 4, '4': 1, '5': 9, '10': 'md5'},
     const {'1': 'doc_id', '3': 5, '4': 1, '5': 9, '10': 'docId'},
     const {'1': 'ty', '3': 6, '4': 1, '5': 14, '6': '.RevType', '10': 'ty'},
+    const {'1': 'created_at', '3': 7, '4': 1, '5': 3, '10': 'createdAt'},
   ],
 };
 
 /// Descriptor for `Revision`. Decode as a `google.protobuf.DescriptorProto`.
-final $typed_data.Uint8List revisionDescriptor = $convert.base64Decode('CghSZXZpc2lvbhIeCgtiYXNlX3Jldl9pZBgBIAEoA1IJYmFzZVJldklkEhUKBnJldl9pZBgCIAEoA1IFcmV2SWQSHQoKZGVsdGFfZGF0YRgDIAEoDFIJZGVsdGFEYXRhEhAKA21kNRgEIAEoCVIDbWQ1EhUKBmRvY19pZBgFIAEoCVIFZG9jSWQSGAoCdHkYBiABKA4yCC5SZXZUeXBlUgJ0eQ==');
+final $typed_data.Uint8List revisionDescriptor = $convert.base64Decode('CghSZXZpc2lvbhIeCgtiYXNlX3Jldl9pZBgBIAEoA1IJYmFzZVJldklkEhUKBnJldl9pZBgCIAEoA1IFcmV2SWQSHQoKZGVsdGFfZGF0YRgDIAEoDFIJZGVsdGFEYXRhEhAKA21kNRgEIAEoCVIDbWQ1EhUKBmRvY19pZBgFIAEoCVIFZG9jSWQSGAoCdHkYBiABKA4yCC5SZXZUeXBlUgJ0eRIdCgpjcmVhdGVkX2F0GAcgASgDUgljcmVhdGVkQXQ=');
 @$core.Deprecated('Use revisionRangeDescriptor instead')
 const RevisionRange$json = const {
   '1': 'RevisionRange',
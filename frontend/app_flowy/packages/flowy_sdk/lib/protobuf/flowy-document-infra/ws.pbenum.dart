@@ -15,6 +15,7 @@ class WsDataType extends $pb.ProtobufEnum {
   static const WsDataType PullRev = WsDataType._(2, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'PullRev');
   static const WsDataType Conflict = WsDataType._(3, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Conflict');
   static const WsDataType NewDocUser = WsDataType._(4, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'NewDocUser');
+  static const WsDataType Awareness = WsDataType._(5, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Awareness');
 
   static const $core.List<WsDataType> values = <WsDataType> [
     Acked,
@@ -22,6 +23,7 @@ class WsDataType extends $pb.ProtobufEnum {
     PullRev,
     Conflict,
     NewDocUser,
+    Awareness,
   ];
 
   static final $core.Map<$core.int, WsDataType> _byValue = $pb.ProtobufEnum.initByValue(values);
@@ -13,6 +13,7 @@ class ErrorCode extends $pb.ProtobufEnum {
   static const ErrorCode WsConnectError = ErrorCode._(0, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'WsConnectError');
   static const ErrorCode DocNotfound = ErrorCode._(1, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'DocNotfound');
   static const ErrorCode DuplicateRevision = ErrorCode._(2, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'DuplicateRevision');
+  static const ErrorCode CorruptedRevision = ErrorCode._(3, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'CorruptedRevision');
   static const ErrorCode UserUnauthorized = ErrorCode._(10, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UserUnauthorized');
   static const ErrorCode InternalError = ErrorCode._(1000, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'InternalError');
 
@@ -20,6 +21,7 @@ class ErrorCode extends $pb.ProtobufEnum {
     WsConnectError,
     DocNotfound,
     DuplicateRevision,
+    CorruptedRevision,
     UserUnauthorized,
     InternalError,
   ];
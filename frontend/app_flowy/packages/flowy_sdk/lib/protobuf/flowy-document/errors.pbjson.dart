@@ -15,13 +15,14 @@ const ErrorCode$json = const {
     const {'1': 'WsConnectError', '2': 0},
     const {'1': 'DocNotfound', '2': 1},
     const {'1': 'DuplicateRevision', '2': 2},
+    const {'1': 'CorruptedRevision', '2': 3},
     const {'1': 'UserUnauthorized', '2': 10},
     const {'1': 'InternalError', '2': 1000},
   ],
 };
 
 /// Descriptor for `ErrorCode`. Decode as a `google.protobuf.EnumDescriptorProto`.
-final $typed_data.Uint8List errorCodeDescriptor = $convert.base64Decode('CglFcnJvckNvZGUSEgoOV3NDb25uZWN0RXJyb3IQABIPCgtEb2NOb3Rmb3VuZBABEhUKEUR1cGxpY2F0ZVJldmlzaW9uEAISFAoQVXNlclVuYXV0aG9yaXplZBAKEhIKDUludGVybmFsRXJyb3IQ6Ac=');
+final $typed_data.Uint8List errorCodeDescriptor = $convert.base64Decode('CglFcnJvckNvZGUSEgoOV3NDb25uZWN0RXJyb3IQABIPCgtEb2NOb3Rmb3VuZBABEhUKEUR1cGxpY2F0ZVJldmlzaW9uEAISFQoRQ29ycnVwdGVkUmV2aXNpb24QAxIUChBVc2VyVW5hdXRob3JpemVkEAoSEgoNSW50ZXJuYWxFcnJvchDoBw==');
 @$core.Deprecated('Use docErrorDescriptor instead')
 const DocError$json = const {
   '1': 'DocError',
@@ -11,9 +11,11 @@ import 'package:protobuf/protobuf.dart' as $pb;
 
 class DocObservable extends $pb.ProtobufEnum {
   static const DocObservable UserCreateDoc = DocObservable._(0, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'UserCreateDoc');
+  static const DocObservable DocAwarenessChanged = DocObservable._(1, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'DocAwarenessChanged');
 
   static const $core.List<DocObservable> values = <DocObservable> [
     UserCreateDoc,
+    DocAwarenessChanged,
   ];
 
   static final $core.Map<$core.int, DocObservable> _byValue = $pb.ProtobufEnum.initByValue(values);
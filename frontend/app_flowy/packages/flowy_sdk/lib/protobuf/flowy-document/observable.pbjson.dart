@@ -13,8 +13,9 @@ const DocObservable$json = const {
   '1': 'DocObservable',
   '2': const [
     const {'1': 'UserCreateDoc', '2': 0},
+    const {'1': 'DocAwarenessChanged', '2': 1},
   ],
 };
 
 /// Descriptor for `DocObservable`. Decode as a `google.protobuf.EnumDescriptorProto`.
-final $typed_data.Uint8List docObservableDescriptor = $convert.base64Decode('Cg1Eb2NPYnNlcnZhYmxlEhEKDVVzZXJDcmVhdGVEb2MQAA==');
+final $typed_data.Uint8List docObservableDescriptor = $convert.base64Decode('Cg1Eb2NPYnNlcnZhYmxlEhEKDVVzZXJDcmVhdGVEb2MQABIXChNEb2NBd2FyZW5lc3NDaGFuZ2VkEAE=');
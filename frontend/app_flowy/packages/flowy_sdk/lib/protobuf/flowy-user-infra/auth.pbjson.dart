@@ -40,11 +40,12 @@ const SignInResponse$json = const {
     const {'1': 'name', '3': 2, '4': 1, '5': 9, '10': 'name'},
     const {'1': 'email', '3': 3, '4': 1, '5': 9, '10': 'email'},
     const {'1': 'token', '3': 4, '4': 1, '5': 9, '10': 'token'},
+    const {'1': 'refresh_token', '3': 5, '4': 1, '5': 9, '10': 'refreshToken'},
   ],
 };
 
 /// Descriptor for `SignInResponse`. Decode as a `google.protobuf.DescriptorProto`.
-final $typed_data.Uint8List signInResponseDescriptor = $convert.base64Decode('Cg5TaWduSW5SZXNwb25zZRIXCgd1c2VyX2lkGAEgASgJUgZ1c2VySWQSEgoEbmFtZRgCIAEoCVIEbmFtZRIUCgVlbWFpbBgDIAEoCVIFZW1haWwSFAoFdG9rZW4YBCABKAlSBXRva2Vu');
+final $typed_data.Uint8List signInResponseDescriptor = $convert.base64Decode('Cg5TaWduSW5SZXNwb25zZRIXCgd1c2VyX2lkGAEgASgJUgZ1c2VySWQSEgoEbmFtZRgCIAEoCVIEbmFtZRIUCgVlbWFpbBgDIAEoCVIFZW1haWwSFAoFdG9rZW4YBCABKAlSBXRva2VuEiMKDXJlZnJlc2hfdG9rZW4YBSABKAlSDHJlZnJlc2hUb2tlbg==');
 @$core.Deprecated('Use signUpRequestDescriptor instead')
 const SignUpRequest$json = const {
   '1': 'SignUpRequest',
@@ -82,3 +83,55 @@ const SignUpResponse$json = const {
 
 /// Descriptor for `SignUpResponse`. Decode as a `google.protobuf.DescriptorProto`.
 final $typed_data.Uint8List signUpResponseDescriptor = $convert.base64Decode('Cg5TaWduVXBSZXNwb25zZRIXCgd1c2VyX2lkGAEgASgJUgZ1c2VySWQSEgoEbmFtZRgCIAEoCVIEbmFtZRIUCgVlbWFpbBgDIAEoCVIFZW1haWwSFAoFdG9rZW4YBCABKAlSBXRva2Vu');
+@$core.Deprecated('Use refreshTokenParamsDescriptor instead')
+const RefreshTokenParams$json = const {
+  '1': 'RefreshTokenParams',
+  '2': const [
+    const {'1': 'refresh_token', '3': 1, '4': 1, '5': 9, '10': 'refreshToken'},
+  ],
+};
+
+/// Descriptor for `RefreshTokenParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List refreshTokenParamsDescriptor = $convert.base64Decode('ChJSZWZyZXNoVG9rZW5QYXJhbXMSIwoNcmVmcmVzaF90b2tlbhgBIAEoCVIMcmVmcmVzaFRva2Vu');
+@$core.Deprecated('Use refreshTokenResponseDescriptor instead')
+const RefreshTokenResponse$json = const {
+  '1': 'RefreshTokenResponse',
+  '2': const [
+    const {'1': 'token', '3': 1, '4': 1, '5': 9, '10': 'token'},
+    const {'1': 'refresh_token', '3': 2, '4': 1, '5': 9, '10': 'refreshToken'},
+  ],
+};
+
+/// Descriptor for `RefreshTokenResponse`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List refreshTokenResponseDescriptor = $convert.base64Decode('ChRSZWZyZXNoVG9rZW5SZXNwb25zZRIUCgV0b2tlbhgBIAEoCVIFdG9rZW4SIwoNcmVmcmVzaF90b2tlbhgCIAEoCVIMcmVmcmVzaFRva2Vu');
+@$core.Deprecated('Use resetPasswordRequestParamsDescriptor instead')
+const ResetPasswordRequestParams$json = const {
+  '1': 'ResetPasswordRequestParams',
+  '2': const [
+    const {'1': 'email', '3': 1, '4': 1, '5': 9, '10': 'email'},
+  ],
+};
+
+/// Descriptor for `ResetPasswordRequestParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List resetPasswordRequestParamsDescriptor = $convert.base64Decode('ChpSZXNldFBhc3N3b3JkUmVxdWVzdFBhcmFtcxIUCgVlbWFpbBgBIAEoCVIFZW1haWw=');
+@$core.Deprecated('Use resetPasswordConfirmParamsDescriptor instead')
+const ResetPasswordConfirmParams$json = const {
+  '1': 'ResetPasswordConfirmParams',
+  '2': const [
+    const {'1': 'token', '3': 1, '4': 1, '5': 9, '10': 'token'},
+    const {'1': 'password', '3': 2, '4': 1, '5': 9, '10': 'password'},
+  ],
+};
+
+/// Descriptor for `ResetPasswordConfirmParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List resetPasswordConfirmParamsDescriptor = $convert.base64Decode('ChpSZXNldFBhc3N3b3JkQ29uZmlybVBhcmFtcxIUCgV0b2tlbhgBIAEoCVIFdG9rZW4SGgoIcGFzc3dvcmQYAiABKAlSCHBhc3N3b3Jk');
+@$core.Deprecated('Use resendVerificationParamsDescriptor instead')
+const ResendVerificationParams$json = const {
+  '1': 'ResendVerificationParams',
+  '2': const [
+    const {'1': 'email', '3': 1, '4': 1, '5': 9, '10': 'email'},
+  ],
+};
+
+/// Descriptor for `ResendVerificationParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List resendVerificationParamsDescriptor = $convert.base64Decode('ChhSZXNlbmRWZXJpZmljYXRpb25QYXJhbXMSFAoFZW1haWwYASABKAlSBWVtYWls');
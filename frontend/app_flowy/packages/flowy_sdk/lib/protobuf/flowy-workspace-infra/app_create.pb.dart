@@ -12,12 +12,23 @@ import 'package:protobuf/protobuf.dart' as $pb;
 
 import 'view_create.pb.dart' as $0;
 
+enum CreateAppRequest_OneOfParentId {
+  parentId, 
+  notSet
+}
+
 class CreateAppRequest extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, CreateAppRequest_OneOfParentId> _CreateAppRequest_OneOfParentIdByTag = {
+    5 : CreateAppRequest_OneOfParentId.parentId,
+    0 : CreateAppRequest_OneOfParentId.notSet
+  };
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'CreateAppRequest', createEmptyInstance: create)
+    ..oo(0, [5])
     ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'workspaceId')
     ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
     ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'desc')
     ..aOM<ColorStyle>(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'colorStyle', subBuilder: ColorStyle.create)
+    ..aOS(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'parentId')
     ..hasRequiredFields = false
   ;
 
@@ -27,6 +38,7 @@ class CreateAppRequest extends $pb.GeneratedMessage {
     $core.String? name,
     $core.String? desc,
     ColorStyle? colorStyle,
+    $core.String? parentId,
   }) {
     final _result = create();
     if (workspaceId != null) {
@@ -41,6 +53,9 @@ class CreateAppRequest extends $pb.GeneratedMessage {
     if (colorStyle != null) {
       _result.colorStyle = colorStyle;
     }
+    if (parentId != null) {
+      _result.parentId = parentId;
+    }
     return _result;
   }
   factory CreateAppRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -64,6 +79,9 @@ class CreateAppRequest extends $pb.GeneratedMessage {
   static CreateAppRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<CreateAppRequest>(create);
   static CreateAppRequest? _defaultInstance;
 
+  CreateAppRequest_OneOfParentId whichOneOfParentId() => _CreateAppRequest_OneOfParentIdByTag[$_whichOneof(0)]!;
+  void clearOneOfParentId() => clearField($_whichOneof(0));
+
   @$pb.TagNumber(1)
   $core.String get workspaceId => $_getSZ(0);
   @$pb.TagNumber(1)
@@ -101,6 +119,15 @@ class CreateAppRequest extends $pb.GeneratedMessage {
   void clearColorStyle() => clearField(4);
   @$pb.TagNumber(4)
   ColorStyle ensureColorStyle() => $_ensure(3);
+
+  @$pb.TagNumber(5)
+  $core.String get parentId => $_getSZ(4);
+  @$pb.TagNumber(5)
+  set parentId($core.String v) { $_setString(4, v); }
+  @$pb.TagNumber(5)
+  $core.bool hasParentId() => $_has(4);
+  @$pb.TagNumber(5)
+  void clearParentId() => clearField(5);
 }
 
 class ColorStyle extends $pb.GeneratedMessage {
@@ -150,12 +177,23 @@ class ColorStyle extends $pb.GeneratedMessage {
   void clearThemeColor() => clearField(1);
 }
 
+enum CreateAppParams_OneOfParentId {
+  parentId, 
+  notSet
+}
+
 class CreateAppParams extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, CreateAppParams_OneOfParentId> _CreateAppParams_OneOfParentIdByTag = {
+    5 : CreateAppParams_OneOfParentId.parentId,
+    0 : CreateAppParams_OneOfParentId.notSet
+  };
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'CreateAppParams', createEmptyInstance: create)
+    ..oo(0, [5])
     ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'workspaceId')
     ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
     ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'desc')
     ..aOM<ColorStyle>(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'colorStyle', subBuilder: ColorStyle.create)
+    ..aOS(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'parentId')
     ..hasRequiredFields = false
   ;
 
@@ -165,6 +203,7 @@ class CreateAppParams extends $pb.GeneratedMessage {
     $core.String? name,
     $core.String? desc,
     ColorStyle? colorStyle,
+    $core.String? parentId,
   }) {
     final _result = create();
     if (workspaceId != null) {
@@ -179,6 +218,9 @@ class CreateAppParams extends $pb.GeneratedMessage {
     if (colorStyle != null) {
       _result.colorStyle = colorStyle;
     }
+    if (parentId != null) {
+      _result.parentId = parentId;
+    }
     return _result;
   }
   factory CreateAppParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -202,6 +244,9 @@ class CreateAppParams extends $pb.GeneratedMessage {
   static CreateAppParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<CreateAppParams>(create);
   static CreateAppParams? _defaultInstance;
 
+  CreateAppParams_OneOfParentId whichOneOfParentId() => _CreateAppParams_OneOfParentIdByTag[$_whichOneof(0)]!;
+  void clearOneOfParentId() => clearField($_whichOneof(0));
+
   @$pb.TagNumber(1)
   $core.String get workspaceId => $_getSZ(0);
   @$pb.TagNumber(1)
@@ -239,10 +284,29 @@ class CreateAppParams extends $pb.GeneratedMessage {
   void clearColorStyle() => clearField(4);
   @$pb.TagNumber(4)
   ColorStyle ensureColorStyle() => $_ensure(3);
+
+  @$pb.TagNumber(5)
+  $core.String get parentId => $_getSZ(4);
+  @$pb.TagNumber(5)
+  set parentId($core.String v) { $_setString(4, v); }
+  @$pb.TagNumber(5)
+  $core.bool hasParentId() => $_has(4);
+  @$pb.TagNumber(5)
+  void clearParentId() => clearField(5);
+}
+
+enum App_OneOfParentId {
+  parentId, 
+  notSet
 }
 
 class App extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, App_OneOfParentId> _App_OneOfParentIdByTag = {
+    10 : App_OneOfParentId.parentId,
+    0 : App_OneOfParentId.notSet
+  };
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'App', createEmptyInstance: create)
+    ..oo(0, [10])
     ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'id')
     ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'workspaceId')
     ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
@@ -251,6 +315,8 @@ class App extends $pb.GeneratedMessage {
     ..aInt64(6, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'version')
     ..aInt64(7, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'modifiedTime')
     ..aInt64(8, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'createTime')
+    ..aOM<RepeatedApp>(9, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'apps', subBuilder: RepeatedApp.create)
+    ..aOS(10, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'parentId')
     ..hasRequiredFields = false
   ;
 
@@ -264,6 +330,8 @@ class App extends $pb.GeneratedMessage {
     $fixnum.Int64? version,
     $fixnum.Int64? modifiedTime,
     $fixnum.Int64? createTime,
+    RepeatedApp? apps,
+    $core.String? parentId,
   }) {
     final _result = create();
     if (id != null) {
@@ -290,6 +358,12 @@ class App extends $pb.GeneratedMessage {
     if (createTime != null) {
       _result.createTime = createTime;
     }
+    if (apps != null) {
+      _result.apps = apps;
+    }
+    if (parentId != null) {
+      _result.parentId = parentId;
+    }
     return _result;
   }
   factory App.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -313,6 +387,9 @@ class App extends $pb.GeneratedMessage {
   static App getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<App>(create);
   static App? _defaultInstance;
 
+  App_OneOfParentId whichOneOfParentId() => _App_OneOfParentIdByTag[$_whichOneof(0)]!;
+  void clearOneOfParentId() => clearField($_whichOneof(0));
+
   @$pb.TagNumber(1)
   $core.String get id => $_getSZ(0);
   @$pb.TagNumber(1)
@@ -386,6 +463,26 @@ class App extends $pb.GeneratedMessage {
   $core.bool hasCreateTime() => $_has(7);
   @$pb.TagNumber(8)
   void clearCreateTime() => clearField(8);
+
+  @$pb.TagNumber(9)
+  RepeatedApp get apps => $_getN(8);
+  @$pb.TagNumber(9)
+  set apps(RepeatedApp v) { setField(9, v); }
+  @$pb.TagNumber(9)
+  $core.bool hasApps() => $_has(8);
+  @$pb.TagNumber(9)
+  void clearApps() => clearField(9);
+  @$pb.TagNumber(9)
+  RepeatedApp ensureApps() => $_ensure(8);
+
+  @$pb.TagNumber(10)
+  $core.String get parentId => $_getSZ(9);
+  @$pb.TagNumber(10)
+  set parentId($core.String v) { $_setString(9, v); }
+  @$pb.TagNumber(10)
+  $core.bool hasParentId() => $_has(9);
+  @$pb.TagNumber(10)
+  void clearParentId() => clearField(10);
 }
 
 class RepeatedApp extends $pb.GeneratedMessage {
@@ -16,11 +16,15 @@ const CreateAppRequest$json = const {
     const {'1': 'name', '3': 2, '4': 1, '5': 9, '10': 'name'},
     const {'1': 'desc', '3': 3, '4': 1, '5': 9, '10': 'desc'},
     const {'1': 'color_style', '3': 4, '4': 1, '5': 11, '6': '.ColorStyle', '10': 'colorStyle'},
+    const {'1': 'parent_id', '3': 5, '4': 1, '5': 9, '9': 0, '10': 'parentId'},
+  ],
+  '8': const [
+    const {'1': 'one_of_parent_id'},
   ],
 };
 
 /// Descriptor for `CreateAppRequest`. Decode as a `google.protobuf.DescriptorProto`.
-final $typed_data.Uint8List createAppRequestDescriptor = $convert.base64Decode('ChBDcmVhdGVBcHBSZXF1ZXN0EiEKDHdvcmtzcGFjZV9pZBgBIAEoCVILd29ya3NwYWNlSWQSEgoEbmFtZRgCIAEoCVIEbmFtZRISCgRkZXNjGAMgASgJUgRkZXNjEiwKC2NvbG9yX3N0eWxlGAQgASgLMgsuQ29sb3JTdHlsZVIKY29sb3JTdHlsZQ==');
+final $typed_data.Uint8List createAppRequestDescriptor = $convert.base64Decode('ChBDcmVhdGVBcHBSZXF1ZXN0EiEKDHdvcmtzcGFjZV9pZBgBIAEoCVILd29ya3NwYWNlSWQSEgoEbmFtZRgCIAEoCVIEbmFtZRISCgRkZXNjGAMgASgJUgRkZXNjEiwKC2NvbG9yX3N0eWxlGAQgASgLMgsuQ29sb3JTdHlsZVIKY29sb3JTdHlsZRIdCglwYXJlbnRfaWQYBSABKAlIAFIIcGFyZW50SWRCEgoQb25lX29mX3BhcmVudF9pZA==');
 @$core.Deprecated('Use colorStyleDescriptor instead')
 const ColorStyle$json = const {
   '1': 'ColorStyle',
@@ -39,11 +43,15 @@ const CreateAppParams$json = const {
     const {'1': 'name', '3': 2, '4': 1, '5': 9, '10': 'name'},
     const {'1': 'desc', '3': 3, '4': 1, '5': 9, '10': 'desc'},
     const {'1': 'color_style', '3': 4, '4': 1, '5': 11, '6': '.ColorStyle', '10': 'colorStyle'},
+    const {'1': 'parent_id', '3': 5, '4': 1, '5': 9, '9': 0, '10': 'parentId'},
+  ],
+  '8': const [
+    const {'1': 'one_of_parent_id'},
   ],
 };
 
 /// Descriptor for `CreateAppParams`. Decode as a `google.protobuf.DescriptorProto`.
-final $typed_data.Uint8List createAppParamsDescriptor = $convert.base64Decode('Cg9DcmVhdGVBcHBQYXJhbXMSIQoMd29ya3NwYWNlX2lkGAEgASgJUgt3b3Jrc3BhY2VJZBISCgRuYW1lGAIgASgJUgRuYW1lEhIKBGRlc2MYAyABKAlSBGRlc2MSLAoLY29sb3Jfc3R5bGUYBCABKAsyCy5Db2xvclN0eWxlUgpjb2xvclN0eWxl');
+final $typed_data.Uint8List createAppParamsDescriptor = $convert.base64Decode('Cg9DcmVhdGVBcHBQYXJhbXMSIQoMd29ya3NwYWNlX2lkGAEgASgJUgt3b3Jrc3BhY2VJZBISCgRuYW1lGAIgASgJUgRuYW1lEhIKBGRlc2MYAyABKAlSBGRlc2MSLAoLY29sb3Jfc3R5bGUYBCABKAsyCy5Db2xvclN0eWxlUgpjb2xvclN0eWxlEh0KCXBhcmVudF9pZBgFIAEoCUgAUghwYXJlbnRJZEISChBvbmVfb2ZfcGFyZW50X2lk');
 @$core.Deprecated('Use appDescriptor instead')
 const App$json = const {
   '1': 'App',
@@ -56,11 +64,16 @@ const App$json = const {
     const {'1': 'version', '3': 6, '4': 1, '5': 3, '10': 'version'},
     const {'1': 'modified_time', '3': 7, '4': 1, '5': 3, '10': 'modifiedTime'},
     const {'1': 'create_time', '3': 8, '4': 1, '5': 3, '10': 'createTime'},
+    const {'1': 'apps', '3': 9, '4': 1, '5': 11, '6': '.RepeatedApp', '10': 'apps'},
+    const {'1': 'parent_id', '3': 10, '4': 1, '5': 9, '9': 0, '10': 'parentId'},
+  ],
+  '8': const [
+    const {'1': 'one_of_parent_id'},
   ],
 };
 
 /// Descriptor for `App`. Decode as a `google.protobuf.DescriptorProto`.
-final $typed_data.Uint8List appDescriptor = $convert.base64Decode('CgNBcHASDgoCaWQYASABKAlSAmlkEiEKDHdvcmtzcGFjZV9pZBgCIAEoCVILd29ya3NwYWNlSWQSEgoEbmFtZRgDIAEoCVIEbmFtZRISCgRkZXNjGAQgASgJUgRkZXNjEi0KCmJlbG9uZ2luZ3MYBSABKAsyDS5SZXBlYXRlZFZpZXdSCmJlbG9uZ2luZ3MSGAoHdmVyc2lvbhgGIAEoA1IHdmVyc2lvbhIjCg1tb2RpZmllZF90aW1lGAcgASgDUgxtb2RpZmllZFRpbWUSHwoLY3JlYXRlX3RpbWUYCCABKANSCmNyZWF0ZVRpbWU=');
+final $typed_data.Uint8List appDescriptor = $convert.base64Decode('CgNBcHASDgoCaWQYASABKAlSAmlkEiEKDHdvcmtzcGFjZV9pZBgCIAEoCVILd29ya3NwYWNlSWQSEgoEbmFtZRgDIAEoCVIEbmFtZRISCgRkZXNjGAQgASgJUgRkZXNjEi0KCmJlbG9uZ2luZ3MYBSABKAsyDS5SZXBlYXRlZFZpZXdSCmJlbG9uZ2luZ3MSGAoHdmVyc2lvbhgGIAEoA1IHdmVyc2lvbhIjCg1tb2RpZmllZF90aW1lGAcgASgDUgxtb2RpZmllZFRpbWUSHwoLY3JlYXRlX3RpbWUYCCABKANSCmNyZWF0ZVRpbWUSIAoEYXBwcxgJIAEoCzIMLlJlcGVhdGVkQXBwUgRhcHBzEh0KCXBhcmVudF9pZBgKIAEoCUgAUghwYXJlbnRJZEISChBvbmVfb2ZfcGFyZW50X2lk');
 @$core.Deprecated('Use repeatedAppDescriptor instead')
 const RepeatedApp$json = const {
   '1': 'RepeatedApp',
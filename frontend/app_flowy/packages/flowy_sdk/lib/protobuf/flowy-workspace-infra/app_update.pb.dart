@@ -31,6 +31,11 @@ enum UpdateAppRequest_OneOfIsTrash {
   notSet
 }
 
+enum UpdateAppRequest_OneOfParentId {
+  parentId, 
+  notSet
+}
+
 class UpdateAppRequest extends $pb.GeneratedMessage {
   static const $core.Map<$core.int, UpdateAppRequest_OneOfName> _UpdateAppRequest_OneOfNameByTag = {
     2 : UpdateAppRequest_OneOfName.name,
@@ -48,16 +53,22 @@ class UpdateAppRequest extends $pb.GeneratedMessage {
     5 : UpdateAppRequest_OneOfIsTrash.isTrash,
     0 : UpdateAppRequest_OneOfIsTrash.notSet
   };
+  static const $core.Map<$core.int, UpdateAppRequest_OneOfParentId> _UpdateAppRequest_OneOfParentIdByTag = {
+    6 : UpdateAppRequest_OneOfParentId.parentId,
+    0 : UpdateAppRequest_OneOfParentId.notSet
+  };
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'UpdateAppRequest', createEmptyInstance: create)
     ..oo(0, [2])
     ..oo(1, [3])
     ..oo(2, [4])
     ..oo(3, [5])
+    ..oo(4, [6])
     ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'appId')
     ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
     ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'desc')
     ..aOM<$0.ColorStyle>(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'colorStyle', subBuilder: $0.ColorStyle.create)
     ..aOB(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'isTrash')
+    ..aOS(6, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'parentId')
     ..hasRequiredFields = false
   ;
 
@@ -68,6 +79,7 @@ class UpdateAppRequest extends $pb.GeneratedMessage {
     $core.String? desc,
     $0.ColorStyle? colorStyle,
     $core.bool? isTrash,
+    $core.String? parentId,
   }) {
     final _result = create();
     if (appId != null) {
@@ -85,6 +97,9 @@ class UpdateAppRequest extends $pb.GeneratedMessage {
     if (isTrash != null) {
       _result.isTrash = isTrash;
     }
+    if (parentId != null) {
+      _result.parentId = parentId;
+    }
     return _result;
   }
   factory UpdateAppRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -120,6 +135,9 @@ class UpdateAppRequest extends $pb.GeneratedMessage {
   UpdateAppRequest_OneOfIsTrash whichOneOfIsTrash() => _UpdateAppRequest_OneOfIsTrashByTag[$_whichOneof(3)]!;
   void clearOneOfIsTrash() => clearField($_whichOneof(3));
 
+  UpdateAppRequest_OneOfParentId whichOneOfParentId() => _UpdateAppRequest_OneOfParentIdByTag[$_whichOneof(4)]!;
+  void clearOneOfParentId() => clearField($_whichOneof(4));
+
   @$pb.TagNumber(1)
   $core.String get appId => $_getSZ(0);
   @$pb.TagNumber(1)
@@ -166,6 +184,15 @@ class UpdateAppRequest extends $pb.GeneratedMessage {
   $core.bool hasIsTrash() => $_has(4);
   @$pb.TagNumber(5)
   void clearIsTrash() => clearField(5);
+
+  @$pb.TagNumber(6)
+  $core.String get parentId => $_getSZ(5);
+  @$pb.TagNumber(6)
+  set parentId($core.String v) { $_setString(5, v); }
+  @$pb.TagNumber(6)
+  $core.bool hasParentId() => $_has(5);
+  @$pb.TagNumber(6)
+  void clearParentId() => clearField(6);
 }
 
 enum UpdateAppParams_OneOfName {
@@ -188,6 +215,11 @@ enum UpdateAppParams_OneOfIsTrash {
   notSet
 }
 
+enum UpdateAppParams_OneOfParentId {
+  parentId, 
+  notSet
+}
+
 class UpdateAppParams extends $pb.GeneratedMessage {
   static const $core.Map<$core.int, UpdateAppParams_OneOfName> _UpdateAppParams_OneOfNameByTag = {
     2 : UpdateAppParams_OneOfName.name,
@@ -205,16 +237,22 @@ class UpdateAppParams extends $pb.GeneratedMessage {
     5 : UpdateAppParams_OneOfIsTrash.isTrash,
     0 : UpdateAppParams_OneOfIsTrash.notSet
   };
+  static const $core.Map<$core.int, UpdateAppParams_OneOfParentId> _UpdateAppParams_OneOfParentIdByTag = {
+    6 : UpdateAppParams_OneOfParentId.parentId,
+    0 : UpdateAppParams_OneOfParentId.notSet
+  };
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'UpdateAppParams', createEmptyInstance: create)
     ..oo(0, [2])
     ..oo(1, [3])
     ..oo(2, [4])
     ..oo(3, [5])
+    ..oo(4, [6])
     ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'appId')
     ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
     ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'desc')
     ..aOM<$0.ColorStyle>(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'colorStyle', subBuilder: $0.ColorStyle.create)
     ..aOB(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'isTrash')
+    ..aOS(6, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'parentId')
     ..hasRequiredFields = false
   ;
 
@@ -225,6 +263,7 @@ class UpdateAppParams extends $pb.GeneratedMessage {
     $core.String? desc,
     $0.ColorStyle? colorStyle,
     $core.bool? isTrash,
+    $core.String? parentId,
   }) {
     final _result = create();
     if (appId != null) {
@@ -242,6 +281,9 @@ class UpdateAppParams extends $pb.GeneratedMessage {
     if (isTrash != null) {
       _result.isTrash = isTrash;
     }
+    if (parentId != null) {
+      _result.parentId = parentId;
+    }
     return _result;
   }
   factory UpdateAppParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -277,6 +319,9 @@ class UpdateAppParams extends $pb.GeneratedMessage {
   UpdateAppParams_OneOfIsTrash whichOneOfIsTrash() => _UpdateAppParams_OneOfIsTrashByTag[$_whichOneof(3)]!;
   void clearOneOfIsTrash() => clearField($_whichOneof(3));
 
+  UpdateAppParams_OneOfParentId whichOneOfParentId() => _UpdateAppParams_OneOfParentIdByTag[$_whichOneof(4)]!;
+  void clearOneOfParentId() => clearField($_whichOneof(4));
+
   @$pb.TagNumber(1)
   $core.String get appId => $_getSZ(0);
   @$pb.TagNumber(1)
@@ -323,5 +368,14 @@ class UpdateAppParams extends $pb.GeneratedMessage {
   $core.bool hasIsTrash() => $_has(4);
   @$pb.TagNumber(5)
   void clearIsTrash() => clearField(5);
+
+  @$pb.TagNumber(6)
+  $core.String get parentId => $_getSZ(5);
+  @$pb.TagNumber(6)
+  set parentId($core.String v) { $_setString(5, v); }
+  @$pb.TagNumber(6)
+  $core.bool hasParentId() => $_has(5);
+  @$pb.TagNumber(6)
+  void clearParentId() => clearField(6);
 }
 
@@ -12,3 +12,5 @@ export './app_update.pb.dart';
 export './view_query.pb.dart';
 export './trash_create.pb.dart';
 export './export.pb.dart';
+export './search_query.pb.dart';
+export './workspace_member.pb.dart';
@@ -0,0 +1,244 @@
+///
+//  Generated code. Do not modify.
+//  source: search_query.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+import 'dart:core' as $core;
+
+import 'package:fixnum/fixnum.dart' as $fixnum;
+import 'package:protobuf/protobuf.dart' as $pb;
+
+class SearchMatch extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'SearchMatch', createEmptyInstance: create)
+    ..aInt64(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'start')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'end')
+    ..hasRequiredFields = false
+  ;
+
+  SearchMatch._() : super();
+  factory SearchMatch({
+    $fixnum.Int64? start,
+    $fixnum.Int64? end,
+  }) {
+    final _result = create();
+    if (start != null) {
+      _result.start = start;
+    }
+    if (end != null) {
+      _result.end = end;
+    }
+    return _result;
+  }
+  factory SearchMatch.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory SearchMatch.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  SearchMatch clone() => SearchMatch()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  SearchMatch copyWith(void Function(SearchMatch) updates) => super.copyWith((message) => updates(message as SearchMatch)) as SearchMatch; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static SearchMatch create() => SearchMatch._();
+  SearchMatch createEmptyInstance() => create();
+  static $pb.PbList<SearchMatch> createRepeated() => $pb.PbList<SearchMatch>();
+  @$core.pragma('dart2js:noInline')
+  static SearchMatch getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<SearchMatch>(create);
+  static SearchMatch? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $fixnum.Int64 get start => $_getI64(0);
+  @$pb.TagNumber(1)
+  set start($fixnum.Int64 v) { $_setInt64(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasStart() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearStart() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get end => $_getI64(1);
+  @$pb.TagNumber(2)
+  set end($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasEnd() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearEnd() => clearField(2);
+}
+
+class SearchResult extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'SearchResult', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewName')
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'snippet')
+    ..pc<SearchMatch>(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'matches', $pb.PbFieldType.PM, subBuilder: SearchMatch.create)
+    ..hasRequiredFields = false
+  ;
+
+  SearchResult._() : super();
+  factory SearchResult({
+    $core.String? viewId,
+    $core.String? viewName,
+    $core.String? snippet,
+    $core.Iterable<SearchMatch>? matches,
+  }) {
+    final _result = create();
+    if (viewId != null) {
+      _result.viewId = viewId;
+    }
+    if (viewName != null) {
+      _result.viewName = viewName;
+    }
+    if (snippet != null) {
+      _result.snippet = snippet;
+    }
+    if (matches != null) {
+      _result.matches.addAll(matches);
+    }
+    return _result;
+  }
+  factory SearchResult.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory SearchResult.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  SearchResult clone() => SearchResult()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  SearchResult copyWith(void Function(SearchResult) updates) => super.copyWith((message) => updates(message as SearchResult)) as SearchResult; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static SearchResult create() => SearchResult._();
+  SearchResult createEmptyInstance() => create();
+  static $pb.PbList<SearchResult> createRepeated() => $pb.PbList<SearchResult>();
+  @$core.pragma('dart2js:noInline')
+  static SearchResult getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<SearchResult>(create);
+  static SearchResult? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get viewId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set viewId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasViewId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearViewId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get viewName => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set viewName($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasViewName() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearViewName() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get snippet => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set snippet($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasSnippet() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearSnippet() => clearField(3);
+
+  @$pb.TagNumber(4)
+  $core.List<SearchMatch> get matches => $_getList(3);
+}
+
+class RepeatedSearchResult extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RepeatedSearchResult', createEmptyInstance: create)
+    ..pc<SearchResult>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'items', $pb.PbFieldType.PM, subBuilder: SearchResult.create)
+    ..hasRequiredFields = false
+  ;
+
+  RepeatedSearchResult._() : super();
+  factory RepeatedSearchResult({
+    $core.Iterable<SearchResult>? items,
+  }) {
+    final _result = create();
+    if (items != null) {
+      _result.items.addAll(items);
+    }
+    return _result;
+  }
+  factory RepeatedSearchResult.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RepeatedSearchResult.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RepeatedSearchResult clone() => RepeatedSearchResult()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RepeatedSearchResult copyWith(void Function(RepeatedSearchResult) updates) => super.copyWith((message) => updates(message as RepeatedSearchResult)) as RepeatedSearchResult; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RepeatedSearchResult create() => RepeatedSearchResult._();
+  RepeatedSearchResult createEmptyInstance() => create();
+  static $pb.PbList<RepeatedSearchResult> createRepeated() => $pb.PbList<RepeatedSearchResult>();
+  @$core.pragma('dart2js:noInline')
+  static RepeatedSearchResult getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RepeatedSearchResult>(create);
+  static RepeatedSearchResult? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.List<SearchResult> get items => $_getList(0);
+}
+
+class SearchReindexResult extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'SearchReindexResult', createEmptyInstance: create)
+    ..aInt64(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'indexedCount')
+    ..hasRequiredFields = false
+  ;
+
+  SearchReindexResult._() : super();
+  factory SearchReindexResult({
+    $fixnum.Int64? indexedCount,
+  }) {
+    final _result = create();
+    if (indexedCount != null) {
+      _result.indexedCount = indexedCount;
+    }
+    return _result;
+  }
+  factory SearchReindexResult.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory SearchReindexResult.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  SearchReindexResult clone() => SearchReindexResult()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  SearchReindexResult copyWith(void Function(SearchReindexResult) updates) => super.copyWith((message) => updates(message as SearchReindexResult)) as SearchReindexResult; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static SearchReindexResult create() => SearchReindexResult._();
+  SearchReindexResult createEmptyInstance() => create();
+  static $pb.PbList<SearchReindexResult> createRepeated() => $pb.PbList<SearchReindexResult>();
+  @$core.pragma('dart2js:noInline')
+  static SearchReindexResult getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<SearchReindexResult>(create);
+  static SearchReindexResult? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $fixnum.Int64 get indexedCount => $_getI64(0);
+  @$pb.TagNumber(1)
+  set indexedCount($fixnum.Int64 v) { $_setInt64(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasIndexedCount() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearIndexedCount() => clearField(1);
+}
+
@@ -0,0 +1,54 @@
+///
+//  Generated code. Do not modify.
+//  source: search_query.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use searchMatchDescriptor instead')
+const SearchMatch$json = const {
+  '1': 'SearchMatch',
+  '2': const [
+    const {'1': 'start', '3': 1, '4': 1, '5': 3, '10': 'start'},
+    const {'1': 'end', '3': 2, '4': 1, '5': 3, '10': 'end'},
+  ],
+};
+
+/// Descriptor for `SearchMatch`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List searchMatchDescriptor = $convert.base64Decode('CgtTZWFyY2hNYXRjaBIUCgVzdGFydBgBIAEoA1IFc3RhcnQSEAoDZW5kGAIgASgDUgNlbmQ=');
+@$core.Deprecated('Use searchResultDescriptor instead')
+const SearchResult$json = const {
+  '1': 'SearchResult',
+  '2': const [
+    const {'1': 'view_id', '3': 1, '4': 1, '5': 9, '10': 'viewId'},
+    const {'1': 'view_name', '3': 2, '4': 1, '5': 9, '10': 'viewName'},
+    const {'1': 'snippet', '3': 3, '4': 1, '5': 9, '10': 'snippet'},
+    const {'1': 'matches', '3': 4, '4': 3, '5': 11, '6': '.SearchMatch', '10': 'matches'},
+  ],
+};
+
+/// Descriptor for `SearchResult`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List searchResultDescriptor = $convert.base64Decode('CgxTZWFyY2hSZXN1bHQSFwoHdmlld19pZBgBIAEoCVIGdmlld0lkEhsKCXZpZXdfbmFtZRgCIAEoCVIIdmlld05hbWUSGAoHc25pcHBldBgDIAEoCVIHc25pcHBldBImCgdtYXRjaGVzGAQgAygLMgwuU2VhcmNoTWF0Y2hSB21hdGNoZXM=');
+@$core.Deprecated('Use repeatedSearchResultDescriptor instead')
+const RepeatedSearchResult$json = const {
+  '1': 'RepeatedSearchResult',
+  '2': const [
+    const {'1': 'items', '3': 1, '4': 3, '5': 11, '6': '.SearchResult', '10': 'items'},
+  ],
+};
+
+/// Descriptor for `RepeatedSearchResult`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List repeatedSearchResultDescriptor = $convert.base64Decode('ChRSZXBlYXRlZFNlYXJjaFJlc3VsdBIjCgVpdGVtcxgBIAMoCzINLlNlYXJjaFJlc3VsdFIFaXRlbXM=');
+@$core.Deprecated('Use searchReindexResultDescriptor instead')
+const SearchReindexResult$json = const {
+  '1': 'SearchReindexResult',
+  '2': const [
+    const {'1': 'indexed_count', '3': 1, '4': 1, '5': 3, '10': 'indexedCount'},
+  ],
+};
+
+/// Descriptor for `SearchReindexResult`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List searchReindexResultDescriptor = $convert.base64Decode('ChNTZWFyY2hSZWluZGV4UmVzdWx0EiMKDWluZGV4ZWRfY291bnQYASABKANSDGluZGV4ZWRDb3VudA==');
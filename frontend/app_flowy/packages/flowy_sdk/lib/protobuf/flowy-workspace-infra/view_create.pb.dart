@@ -19,18 +19,29 @@ enum CreateViewRequest_OneOfThumbnail {
   notSet
 }
 
+enum CreateViewRequest_OneOfParentViewId {
+  parentViewId, 
+  notSet
+}
+
 class CreateViewRequest extends $pb.GeneratedMessage {
   static const $core.Map<$core.int, CreateViewRequest_OneOfThumbnail> _CreateViewRequest_OneOfThumbnailByTag = {
     4 : CreateViewRequest_OneOfThumbnail.thumbnail,
     0 : CreateViewRequest_OneOfThumbnail.notSet
   };
+  static const $core.Map<$core.int, CreateViewRequest_OneOfParentViewId> _CreateViewRequest_OneOfParentViewIdByTag = {
+    6 : CreateViewRequest_OneOfParentViewId.parentViewId,
+    0 : CreateViewRequest_OneOfParentViewId.notSet
+  };
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'CreateViewRequest', createEmptyInstance: create)
     ..oo(0, [4])
+    ..oo(1, [6])
     ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'belongToId')
     ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
     ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'desc')
     ..aOS(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'thumbnail')
     ..e<ViewType>(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewType', $pb.PbFieldType.OE, defaultOrMaker: ViewType.Blank, valueOf: ViewType.valueOf, enumValues: ViewType.values)
+    ..aOS(6, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'parentViewId')
     ..hasRequiredFields = false
   ;
 
@@ -41,6 +52,7 @@ class CreateViewRequest extends $pb.GeneratedMessage {
     $core.String? desc,
     $core.String? thumbnail,
     ViewType? viewType,
+    $core.String? parentViewId,
   }) {
     final _result = create();
     if (belongToId != null) {
@@ -58,6 +70,9 @@ class CreateViewRequest extends $pb.GeneratedMessage {
     if (viewType != null) {
       _result.viewType = viewType;
     }
+    if (parentViewId != null) {
+      _result.parentViewId = parentViewId;
+    }
     return _result;
   }
   factory CreateViewRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -84,6 +99,9 @@ class CreateViewRequest extends $pb.GeneratedMessage {
   CreateViewRequest_OneOfThumbnail whichOneOfThumbnail() => _CreateViewRequest_OneOfThumbnailByTag[$_whichOneof(0)]!;
   void clearOneOfThumbnail() => clearField($_whichOneof(0));
 
+  CreateViewRequest_OneOfParentViewId whichOneOfParentViewId() => _CreateViewRequest_OneOfParentViewIdByTag[$_whichOneof(1)]!;
+  void clearOneOfParentViewId() => clearField($_whichOneof(1));
+
   @$pb.TagNumber(1)
   $core.String get belongToId => $_getSZ(0);
   @$pb.TagNumber(1)
@@ -128,16 +146,36 @@ class CreateViewRequest extends $pb.GeneratedMessage {
   $core.bool hasViewType() => $_has(4);
   @$pb.TagNumber(5)
   void clearViewType() => clearField(5);
+
+  @$pb.TagNumber(6)
+  $core.String get parentViewId => $_getSZ(5);
+  @$pb.TagNumber(6)
+  set parentViewId($core.String v) { $_setString(5, v); }
+  @$pb.TagNumber(6)
+  $core.bool hasParentViewId() => $_has(5);
+  @$pb.TagNumber(6)
+  void clearParentViewId() => clearField(6);
+}
+
+enum CreateViewParams_OneOfParentViewId {
+  parentViewId, 
+  notSet
 }
 
 class CreateViewParams extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, CreateViewParams_OneOfParentViewId> _CreateViewParams_OneOfParentViewIdByTag = {
+    7 : CreateViewParams_OneOfParentViewId.parentViewId,
+    0 : CreateViewParams_OneOfParentViewId.notSet
+  };
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'CreateViewParams', createEmptyInstance: create)
+    ..oo(0, [7])
     ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'belongToId')
     ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
     ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'desc')
     ..aOS(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'thumbnail')
     ..e<ViewType>(5, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewType', $pb.PbFieldType.OE, defaultOrMaker: ViewType.Blank, valueOf: ViewType.valueOf, enumValues: ViewType.values)
     ..aOS(6, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'data')
+    ..aOS(7, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'parentViewId')
     ..hasRequiredFields = false
   ;
 
@@ -149,6 +187,7 @@ class CreateViewParams extends $pb.GeneratedMessage {
     $core.String? thumbnail,
     ViewType? viewType,
     $core.String? data,
+    $core.String? parentViewId,
   }) {
     final _result = create();
     if (belongToId != null) {
@@ -169,6 +208,9 @@ class CreateViewParams extends $pb.GeneratedMessage {
     if (data != null) {
       _result.data = data;
     }
+    if (parentViewId != null) {
+      _result.parentViewId = parentViewId;
+    }
     return _result;
   }
   factory CreateViewParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -192,6 +234,9 @@ class CreateViewParams extends $pb.GeneratedMessage {
   static CreateViewParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<CreateViewParams>(create);
   static CreateViewParams? _defaultInstance;
 
+  CreateViewParams_OneOfParentViewId whichOneOfParentViewId() => _CreateViewParams_OneOfParentViewIdByTag[$_whichOneof(0)]!;
+  void clearOneOfParentViewId() => clearField($_whichOneof(0));
+
   @$pb.TagNumber(1)
   $core.String get belongToId => $_getSZ(0);
   @$pb.TagNumber(1)
@@ -245,10 +290,29 @@ class CreateViewParams extends $pb.GeneratedMessage {
   $core.bool hasData() => $_has(5);
   @$pb.TagNumber(6)
   void clearData() => clearField(6);
+
+  @$pb.TagNumber(7)
+  $core.String get parentViewId => $_getSZ(6);
+  @$pb.TagNumber(7)
+  set parentViewId($core.String v) { $_setString(6, v); }
+  @$pb.TagNumber(7)
+  $core.bool hasParentViewId() => $_has(6);
+  @$pb.TagNumber(7)
+  void clearParentViewId() => clearField(7);
+}
+
+enum View_OneOfParentViewId {
+  parentViewId, 
+  notSet
 }
 
 class View extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, View_OneOfParentViewId> _View_OneOfParentViewIdByTag = {
+    10 : View_OneOfParentViewId.parentViewId,
+    0 : View_OneOfParentViewId.notSet
+  };
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'View', createEmptyInstance: create)
+    ..oo(0, [10])
     ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'id')
     ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'belongToId')
     ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'name')
@@ -258,6 +322,7 @@ class View extends $pb.GeneratedMessage {
     ..aOM<RepeatedView>(7, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'belongings', subBuilder: RepeatedView.create)
     ..aInt64(8, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'modifiedTime')
     ..aInt64(9, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'createTime')
+    ..aOS(10, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'parentViewId')
     ..hasRequiredFields = false
   ;
 
@@ -272,6 +337,7 @@ class View extends $pb.GeneratedMessage {
     RepeatedView? belongings,
     $fixnum.Int64? modifiedTime,
     $fixnum.Int64? createTime,
+    $core.String? parentViewId,
   }) {
     final _result = create();
     if (id != null) {
@@ -301,6 +367,9 @@ class View extends $pb.GeneratedMessage {
     if (createTime != null) {
       _result.createTime = createTime;
     }
+    if (parentViewId != null) {
+      _result.parentViewId = parentViewId;
+    }
     return _result;
   }
   factory View.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -324,6 +393,9 @@ class View extends $pb.GeneratedMessage {
   static View getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<View>(create);
   static View? _defaultInstance;
 
+  View_OneOfParentViewId whichOneOfParentViewId() => _View_OneOfParentViewIdByTag[$_whichOneof(0)]!;
+  void clearOneOfParentViewId() => clearField($_whichOneof(0));
+
   @$pb.TagNumber(1)
   $core.String get id => $_getSZ(0);
   @$pb.TagNumber(1)
@@ -406,6 +478,15 @@ class View extends $pb.GeneratedMessage {
   $core.bool hasCreateTime() => $_has(8);
   @$pb.TagNumber(9)
   void clearCreateTime() => clearField(9);
+
+  @$pb.TagNumber(10)
+  $core.String get parentViewId => $_getSZ(9);
+  @$pb.TagNumber(10)
+  set parentViewId($core.String v) { $_setString(9, v); }
+  @$pb.TagNumber(10)
+  $core.bool hasParentViewId() => $_has(9);
+  @$pb.TagNumber(10)
+  void clearParentViewId() => clearField(10);
 }
 
 class RepeatedView extends $pb.GeneratedMessage {
@@ -7,6 +7,7 @@
 
 import 'dart:core' as $core;
 
+import 'package:fixnum/fixnum.dart' as $fixnum;
 import 'package:protobuf/protobuf.dart' as $pb;
 
 enum UpdateViewRequest_OneOfName {
@@ -265,3 +266,212 @@ class UpdateViewParams extends $pb.GeneratedMessage {
   void clearThumbnail() => clearField(4);
 }
 
+enum MoveViewRequest_OneOfParentViewId {
+  parentViewId, 
+  notSet
+}
+
+class MoveViewRequest extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, MoveViewRequest_OneOfParentViewId> _MoveViewRequest_OneOfParentViewIdByTag = {
+    2 : MoveViewRequest_OneOfParentViewId.parentViewId,
+    0 : MoveViewRequest_OneOfParentViewId.notSet
+  };
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'MoveViewRequest', createEmptyInstance: create)
+    ..oo(0, [2])
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'parentViewId')
+    ..hasRequiredFields = false
+  ;
+
+  MoveViewRequest._() : super();
+  factory MoveViewRequest({
+    $core.String? viewId,
+    $core.String? parentViewId,
+  }) {
+    final _result = create();
+    if (viewId != null) {
+      _result.viewId = viewId;
+    }
+    if (parentViewId != null) {
+      _result.parentViewId = parentViewId;
+    }
+    return _result;
+  }
+  factory MoveViewRequest.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory MoveViewRequest.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  MoveViewRequest clone() => MoveViewRequest()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  MoveViewRequest copyWith(void Function(MoveViewRequest) updates) => super.copyWith((message) => updates(message as MoveViewRequest)) as MoveViewRequest; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static MoveViewRequest create() => MoveViewRequest._();
+  MoveViewRequest createEmptyInstance() => create();
+  static $pb.PbList<MoveViewRequest> createRepeated() => $pb.PbList<MoveViewRequest>();
+  @$core.pragma('dart2js:noInline')
+  static MoveViewRequest getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<MoveViewRequest>(create);
+  static MoveViewRequest? _defaultInstance;
+
+  MoveViewRequest_OneOfParentViewId whichOneOfParentViewId() => _MoveViewRequest_OneOfParentViewIdByTag[$_whichOneof(0)]!;
+  void clearOneOfParentViewId() => clearField($_whichOneof(0));
+
+  @$pb.TagNumber(1)
+  $core.String get viewId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set viewId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasViewId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearViewId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get parentViewId => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set parentViewId($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasParentViewId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearParentViewId() => clearField(2);
+}
+
+enum MoveViewParams_OneOfParentViewId {
+  parentViewId, 
+  notSet
+}
+
+class MoveViewParams extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, MoveViewParams_OneOfParentViewId> _MoveViewParams_OneOfParentViewIdByTag = {
+    2 : MoveViewParams_OneOfParentViewId.parentViewId,
+    0 : MoveViewParams_OneOfParentViewId.notSet
+  };
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'MoveViewParams', createEmptyInstance: create)
+    ..oo(0, [2])
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'parentViewId')
+    ..hasRequiredFields = false
+  ;
+
+  MoveViewParams._() : super();
+  factory MoveViewParams({
+    $core.String? viewId,
+    $core.String? parentViewId,
+  }) {
+    final _result = create();
+    if (viewId != null) {
+      _result.viewId = viewId;
+    }
+    if (parentViewId != null) {
+      _result.parentViewId = parentViewId;
+    }
+    return _result;
+  }
+  factory MoveViewParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory MoveViewParams.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  MoveViewParams clone() => MoveViewParams()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  MoveViewParams copyWith(void Function(MoveViewParams) updates) => super.copyWith((message) => updates(message as MoveViewParams)) as MoveViewParams; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static MoveViewParams create() => MoveViewParams._();
+  MoveViewParams createEmptyInstance() => create();
+  static $pb.PbList<MoveViewParams> createRepeated() => $pb.PbList<MoveViewParams>();
+  @$core.pragma('dart2js:noInline')
+  static MoveViewParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<MoveViewParams>(create);
+  static MoveViewParams? _defaultInstance;
+
+  MoveViewParams_OneOfParentViewId whichOneOfParentViewId() => _MoveViewParams_OneOfParentViewIdByTag[$_whichOneof(0)]!;
+  void clearOneOfParentViewId() => clearField($_whichOneof(0));
+
+  @$pb.TagNumber(1)
+  $core.String get viewId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set viewId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasViewId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearViewId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get parentViewId => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set parentViewId($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasParentViewId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearParentViewId() => clearField(2);
+}
+
+class ReorderViewParams extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'ReorderViewParams', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'viewId')
+    ..aInt64(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'targetIndex')
+    ..hasRequiredFields = false
+  ;
+
+  ReorderViewParams._() : super();
+  factory ReorderViewParams({
+    $core.String? viewId,
+    $fixnum.Int64? targetIndex,
+  }) {
+    final _result = create();
+    if (viewId != null) {
+      _result.viewId = viewId;
+    }
+    if (targetIndex != null) {
+      _result.targetIndex = targetIndex;
+    }
+    return _result;
+  }
+  factory ReorderViewParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory ReorderViewParams.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  ReorderViewParams clone() => ReorderViewParams()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  ReorderViewParams copyWith(void Function(ReorderViewParams) updates) => super.copyWith((message) => updates(message as ReorderViewParams)) as ReorderViewParams; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static ReorderViewParams create() => ReorderViewParams._();
+  ReorderViewParams createEmptyInstance() => create();
+  static $pb.PbList<ReorderViewParams> createRepeated() => $pb.PbList<ReorderViewParams>();
+  @$core.pragma('dart2js:noInline')
+  static ReorderViewParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<ReorderViewParams>(create);
+  static ReorderViewParams? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get viewId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set viewId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasViewId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearViewId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $fixnum.Int64 get targetIndex => $_getI64(1);
+  @$pb.TagNumber(2)
+  set targetIndex($fixnum.Int64 v) { $_setInt64(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasTargetIndex() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearTargetIndex() => clearField(2);
+}
+
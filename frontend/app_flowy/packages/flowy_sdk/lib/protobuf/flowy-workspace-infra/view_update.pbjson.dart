@@ -44,3 +44,42 @@ const UpdateViewParams$json = const {
 
 /// Descriptor for `UpdateViewParams`. Decode as a `google.protobuf.DescriptorProto`.
 final $typed_data.Uint8List updateViewParamsDescriptor = $convert.base64Decode('ChBVcGRhdGVWaWV3UGFyYW1zEhcKB3ZpZXdfaWQYASABKAlSBnZpZXdJZBIUCgRuYW1lGAIgASgJSABSBG5hbWUSFAoEZGVzYxgDIAEoCUgBUgRkZXNjEh4KCXRodW1ibmFpbBgEIAEoCUgCUgl0aHVtYm5haWxCDQoLb25lX29mX25hbWVCDQoLb25lX29mX2Rlc2NCEgoQb25lX29mX3RodW1ibmFpbA==');
+@$core.Deprecated('Use moveViewRequestDescriptor instead')
+const MoveViewRequest$json = const {
+  '1': 'MoveViewRequest',
+  '2': const [
+    const {'1': 'view_id', '3': 1, '4': 1, '5': 9, '10': 'viewId'},
+    const {'1': 'parent_view_id', '3': 2, '4': 1, '5': 9, '9': 0, '10': 'parentViewId'},
+  ],
+  '8': const [
+    const {'1': 'one_of_parent_view_id'},
+  ],
+};
+
+/// Descriptor for `MoveViewRequest`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List moveViewRequestDescriptor = $convert.base64Decode('Cg9Nb3ZlVmlld1JlcXVlc3QSFwoHdmlld19pZBgBIAEoCVIGdmlld0lkEiYKDnBhcmVudF92aWV3X2lkGAIgASgJSABSDHBhcmVudFZpZXdJZEIXChVvbmVfb2ZfcGFyZW50X3ZpZXdfaWQ=');
+@$core.Deprecated('Use moveViewParamsDescriptor instead')
+const MoveViewParams$json = const {
+  '1': 'MoveViewParams',
+  '2': const [
+    const {'1': 'view_id', '3': 1, '4': 1, '5': 9, '10': 'viewId'},
+    const {'1': 'parent_view_id', '3': 2, '4': 1, '5': 9, '9': 0, '10': 'parentViewId'},
+  ],
+  '8': const [
+    const {'1': 'one_of_parent_view_id'},
+  ],
+};
+
+/// Descriptor for `MoveViewParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List moveViewParamsDescriptor = $convert.base64Decode('Cg5Nb3ZlVmlld1BhcmFtcxIXCgd2aWV3X2lkGAEgASgJUgZ2aWV3SWQSJgoOcGFyZW50X3ZpZXdfaWQYAiABKAlIAFIMcGFyZW50Vmlld0lkQhcKFW9uZV9vZl9wYXJlbnRfdmlld19pZA==');
+@$core.Deprecated('Use reorderViewParamsDescriptor instead')
+const ReorderViewParams$json = const {
+  '1': 'ReorderViewParams',
+  '2': const [
+    const {'1': 'view_id', '3': 1, '4': 1, '5': 9, '10': 'viewId'},
+    const {'1': 'target_index', '3': 2, '4': 1, '5': 3, '10': 'targetIndex'},
+  ],
+};
+
+/// Descriptor for `ReorderViewParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List reorderViewParamsDescriptor = $convert.base64Decode('ChFSZW9yZGVyVmlld1BhcmFtcxIXCgd2aWV3X2lkGAEgASgJUgZ2aWV3SWQSIQoMdGFyZ2V0X2luZGV4GAIgASgDUgt0YXJnZXRJbmRleA==');
@@ -253,20 +253,35 @@ class Workspace extends $pb.GeneratedMessage {
   void clearCreateTime() => clearField(6);
 }
 
+enum RepeatedWorkspace_OneOfNextCursor {
+  nextCursor, 
+  notSet
+}
+
 class RepeatedWorkspace extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, RepeatedWorkspace_OneOfNextCursor> _RepeatedWorkspace_OneOfNextCursorByTag = {
+    2 : RepeatedWorkspace_OneOfNextCursor.nextCursor,
+    0 : RepeatedWorkspace_OneOfNextCursor.notSet
+  };
   static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RepeatedWorkspace', createEmptyInstance: create)
+    ..oo(0, [2])
     ..pc<Workspace>(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'items', $pb.PbFieldType.PM, subBuilder: Workspace.create)
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'nextCursor')
     ..hasRequiredFields = false
   ;
 
   RepeatedWorkspace._() : super();
   factory RepeatedWorkspace({
     $core.Iterable<Workspace>? items,
+    $core.String? nextCursor,
   }) {
     final _result = create();
     if (items != null) {
       _result.items.addAll(items);
     }
+    if (nextCursor != null) {
+      _result.nextCursor = nextCursor;
+    }
     return _result;
   }
   factory RepeatedWorkspace.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
@@ -290,7 +305,19 @@ class RepeatedWorkspace extends $pb.GeneratedMessage {
   static RepeatedWorkspace getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RepeatedWorkspace>(create);
   static RepeatedWorkspace? _defaultInstance;
 
+  RepeatedWorkspace_OneOfNextCursor whichOneOfNextCursor() => _RepeatedWorkspace_OneOfNextCursorByTag[$_whichOneof(0)]!;
+  void clearOneOfNextCursor() => clearField($_whichOneof(0));
+
   @$pb.TagNumber(1)
   $core.List<Workspace> get items => $_getList(0);
+
+  @$pb.TagNumber(2)
+  $core.String get nextCursor => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set nextCursor($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasNextCursor() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearNextCursor() => clearField(2);
 }
 
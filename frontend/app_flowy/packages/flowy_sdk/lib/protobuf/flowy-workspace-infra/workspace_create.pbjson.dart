@@ -50,8 +50,12 @@ const RepeatedWorkspace$json = const {
   '1': 'RepeatedWorkspace',
   '2': const [
     const {'1': 'items', '3': 1, '4': 3, '5': 11, '6': '.Workspace', '10': 'items'},
+    const {'1': 'next_cursor', '3': 2, '4': 1, '5': 9, '9': 0, '10': 'nextCursor'},
+  ],
+  '8': const [
+    const {'1': 'one_of_next_cursor'},
   ],
 };
 
 /// Descriptor for `RepeatedWorkspace`. Decode as a `google.protobuf.DescriptorProto`.
-final $typed_data.Uint8List repeatedWorkspaceDescriptor = $convert.base64Decode('ChFSZXBlYXRlZFdvcmtzcGFjZRIgCgVpdGVtcxgBIAMoCzIKLldvcmtzcGFjZVIFaXRlbXM=');
+final $typed_data.Uint8List repeatedWorkspaceDescriptor = $convert.base64Decode('ChFSZXBlYXRlZFdvcmtzcGFjZRIgCgVpdGVtcxgBIAMoCzIKLldvcmtzcGFjZVIFaXRlbXMSIQoLbmV4dF9jdXJzb3IYAiABKAlIAFIKbmV4dEN1cnNvckIUChJvbmVfb2ZfbmV4dF9jdXJzb3I=');
@@ -0,0 +1,280 @@
+///
+//  Generated code. Do not modify.
+//  source: workspace_member.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+import 'dart:core' as $core;
+
+import 'package:protobuf/protobuf.dart' as $pb;
+
+import 'workspace_member.pbenum.dart';
+
+export 'workspace_member.pbenum.dart';
+
+enum AddWorkspaceMemberParams_OneOfUserId {
+  userId, 
+  notSet
+}
+
+enum AddWorkspaceMemberParams_OneOfEmail {
+  email, 
+  notSet
+}
+
+class AddWorkspaceMemberParams extends $pb.GeneratedMessage {
+  static const $core.Map<$core.int, AddWorkspaceMemberParams_OneOfUserId> _AddWorkspaceMemberParams_OneOfUserIdByTag = {
+    2 : AddWorkspaceMemberParams_OneOfUserId.userId,
+    0 : AddWorkspaceMemberParams_OneOfUserId.notSet
+  };
+  static const $core.Map<$core.int, AddWorkspaceMemberParams_OneOfEmail> _AddWorkspaceMemberParams_OneOfEmailByTag = {
+    3 : AddWorkspaceMemberParams_OneOfEmail.email,
+    0 : AddWorkspaceMemberParams_OneOfEmail.notSet
+  };
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'AddWorkspaceMemberParams', createEmptyInstance: create)
+    ..oo(0, [2])
+    ..oo(1, [3])
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'workspaceId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'userId')
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'email')
+    ..e<WorkspaceRole>(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'role', $pb.PbFieldType.OE, defaultOrMaker: WorkspaceRole.Viewer, valueOf: WorkspaceRole.valueOf, enumValues: WorkspaceRole.values)
+    ..hasRequiredFields = false
+  ;
+
+  AddWorkspaceMemberParams._() : super();
+  factory AddWorkspaceMemberParams({
+    $core.String? workspaceId,
+    $core.String? userId,
+    $core.String? email,
+    WorkspaceRole? role,
+  }) {
+    final _result = create();
+    if (workspaceId != null) {
+      _result.workspaceId = workspaceId;
+    }
+    if (userId != null) {
+      _result.userId = userId;
+    }
+    if (email != null) {
+      _result.email = email;
+    }
+    if (role != null) {
+      _result.role = role;
+    }
+    return _result;
+  }
+  factory AddWorkspaceMemberParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory AddWorkspaceMemberParams.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  AddWorkspaceMemberParams clone() => AddWorkspaceMemberParams()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  AddWorkspaceMemberParams copyWith(void Function(AddWorkspaceMemberParams) updates) => super.copyWith((message) => updates(message as AddWorkspaceMemberParams)) as AddWorkspaceMemberParams; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static AddWorkspaceMemberParams create() => AddWorkspaceMemberParams._();
+  AddWorkspaceMemberParams createEmptyInstance() => create();
+  static $pb.PbList<AddWorkspaceMemberParams> createRepeated() => $pb.PbList<AddWorkspaceMemberParams>();
+  @$core.pragma('dart2js:noInline')
+  static AddWorkspaceMemberParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<AddWorkspaceMemberParams>(create);
+  static AddWorkspaceMemberParams? _defaultInstance;
+
+  AddWorkspaceMemberParams_OneOfUserId whichOneOfUserId() => _AddWorkspaceMemberParams_OneOfUserIdByTag[$_whichOneof(0)]!;
+  void clearOneOfUserId() => clearField($_whichOneof(0));
+
+  AddWorkspaceMemberParams_OneOfEmail whichOneOfEmail() => _AddWorkspaceMemberParams_OneOfEmailByTag[$_whichOneof(1)]!;
+  void clearOneOfEmail() => clearField($_whichOneof(1));
+
+  @$pb.TagNumber(1)
+  $core.String get workspaceId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set workspaceId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasWorkspaceId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearWorkspaceId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get userId => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set userId($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasUserId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearUserId() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get email => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set email($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasEmail() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearEmail() => clearField(3);
+
+  @$pb.TagNumber(4)
+  WorkspaceRole get role => $_getN(3);
+  @$pb.TagNumber(4)
+  set role(WorkspaceRole v) { setField(4, v); }
+  @$pb.TagNumber(4)
+  $core.bool hasRole() => $_has(3);
+  @$pb.TagNumber(4)
+  void clearRole() => clearField(4);
+}
+
+class RemoveWorkspaceMemberParams extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'RemoveWorkspaceMemberParams', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'workspaceId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'userId')
+    ..hasRequiredFields = false
+  ;
+
+  RemoveWorkspaceMemberParams._() : super();
+  factory RemoveWorkspaceMemberParams({
+    $core.String? workspaceId,
+    $core.String? userId,
+  }) {
+    final _result = create();
+    if (workspaceId != null) {
+      _result.workspaceId = workspaceId;
+    }
+    if (userId != null) {
+      _result.userId = userId;
+    }
+    return _result;
+  }
+  factory RemoveWorkspaceMemberParams.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory RemoveWorkspaceMemberParams.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  RemoveWorkspaceMemberParams clone() => RemoveWorkspaceMemberParams()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  RemoveWorkspaceMemberParams copyWith(void Function(RemoveWorkspaceMemberParams) updates) => super.copyWith((message) => updates(message as RemoveWorkspaceMemberParams)) as RemoveWorkspaceMemberParams; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static RemoveWorkspaceMemberParams create() => RemoveWorkspaceMemberParams._();
+  RemoveWorkspaceMemberParams createEmptyInstance() => create();
+  static $pb.PbList<RemoveWorkspaceMemberParams> createRepeated() => $pb.PbList<RemoveWorkspaceMemberParams>();
+  @$core.pragma('dart2js:noInline')
+  static RemoveWorkspaceMemberParams getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<RemoveWorkspaceMemberParams>(create);
+  static RemoveWorkspaceMemberParams? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get workspaceId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set workspaceId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasWorkspaceId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearWorkspaceId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get userId => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set userId($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasUserId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearUserId() => clearField(2);
+}
+
+class WorkspaceMember extends $pb.GeneratedMessage {
+  static final $pb.BuilderInfo _i = $pb.BuilderInfo(const $core.bool.fromEnvironment('protobuf.omit_message_names') ? '' : 'WorkspaceMember', createEmptyInstance: create)
+    ..aOS(1, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'workspaceId')
+    ..aOS(2, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'userId')
+    ..aOS(3, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'email')
+    ..e<WorkspaceRole>(4, const $core.bool.fromEnvironment('protobuf.omit_field_names') ? '' : 'role', $pb.PbFieldType.OE, defaultOrMaker: WorkspaceRole.Viewer, valueOf: WorkspaceRole.valueOf, enumValues: WorkspaceRole.values)
+    ..hasRequiredFields = false
+  ;
+
+  WorkspaceMember._() : super();
+  factory WorkspaceMember({
+    $core.String? workspaceId,
+    $core.String? userId,
+    $core.String? email,
+    WorkspaceRole? role,
+  }) {
+    final _result = create();
+    if (workspaceId != null) {
+      _result.workspaceId = workspaceId;
+    }
+    if (userId != null) {
+      _result.userId = userId;
+    }
+    if (email != null) {
+      _result.email = email;
+    }
+    if (role != null) {
+      _result.role = role;
+    }
+    return _result;
+  }
+  factory WorkspaceMember.fromBuffer($core.List<$core.int> i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromBuffer(i, r);
+  factory WorkspaceMember.fromJson($core.String i, [$pb.ExtensionRegistry r = $pb.ExtensionRegistry.EMPTY]) => create()..mergeFromJson(i, r);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.deepCopy] instead. '
+  'Will be removed in next major version')
+  WorkspaceMember clone() => WorkspaceMember()..mergeFromMessage(this);
+  @$core.Deprecated(
+  'Using this can add significant overhead to your binary. '
+  'Use [GeneratedMessageGenericExtensions.rebuild] instead. '
+  'Will be removed in next major version')
+  WorkspaceMember copyWith(void Function(WorkspaceMember) updates) => super.copyWith((message) => updates(message as WorkspaceMember)) as WorkspaceMember; // ignore: deprecated_member_use
+  $pb.BuilderInfo get info_ => _i;
+  @$core.pragma('dart2js:noInline')
+  static WorkspaceMember create() => WorkspaceMember._();
+  WorkspaceMember createEmptyInstance() => create();
+  static $pb.PbList<WorkspaceMember> createRepeated() => $pb.PbList<WorkspaceMember>();
+  @$core.pragma('dart2js:noInline')
+  static WorkspaceMember getDefault() => _defaultInstance ??= $pb.GeneratedMessage.$_defaultFor<WorkspaceMember>(create);
+  static WorkspaceMember? _defaultInstance;
+
+  @$pb.TagNumber(1)
+  $core.String get workspaceId => $_getSZ(0);
+  @$pb.TagNumber(1)
+  set workspaceId($core.String v) { $_setString(0, v); }
+  @$pb.TagNumber(1)
+  $core.bool hasWorkspaceId() => $_has(0);
+  @$pb.TagNumber(1)
+  void clearWorkspaceId() => clearField(1);
+
+  @$pb.TagNumber(2)
+  $core.String get userId => $_getSZ(1);
+  @$pb.TagNumber(2)
+  set userId($core.String v) { $_setString(1, v); }
+  @$pb.TagNumber(2)
+  $core.bool hasUserId() => $_has(1);
+  @$pb.TagNumber(2)
+  void clearUserId() => clearField(2);
+
+  @$pb.TagNumber(3)
+  $core.String get email => $_getSZ(2);
+  @$pb.TagNumber(3)
+  set email($core.String v) { $_setString(2, v); }
+  @$pb.TagNumber(3)
+  $core.bool hasEmail() => $_has(2);
+  @$pb.TagNumber(3)
+  void clearEmail() => clearField(3);
+
+  @$pb.TagNumber(4)
+  WorkspaceRole get role => $_getN(3);
+  @$pb.TagNumber(4)
+  set role(WorkspaceRole v) { setField(4, v); }
+  @$pb.TagNumber(4)
+  $core.bool hasRole() => $_has(3);
+  @$pb.TagNumber(4)
+  void clearRole() => clearField(4);
+}
+
@@ -0,0 +1,28 @@
+///
+//  Generated code. Do not modify.
+//  source: workspace_member.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields
+
+// ignore_for_file: UNDEFINED_SHOWN_NAME
+import 'dart:core' as $core;
+import 'package:protobuf/protobuf.dart' as $pb;
+
+class WorkspaceRole extends $pb.ProtobufEnum {
+  static const WorkspaceRole Viewer = WorkspaceRole._(0, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Viewer');
+  static const WorkspaceRole Editor = WorkspaceRole._(1, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Editor');
+  static const WorkspaceRole Owner = WorkspaceRole._(2, const $core.bool.fromEnvironment('protobuf.omit_enum_names') ? '' : 'Owner');
+
+  static const $core.List<WorkspaceRole> values = <WorkspaceRole> [
+    Viewer,
+    Editor,
+    Owner,
+  ];
+
+  static final $core.Map<$core.int, WorkspaceRole> _byValue = $pb.ProtobufEnum.initByValue(values);
+  static WorkspaceRole? valueOf($core.int value) => _byValue[value];
+
+  const WorkspaceRole._($core.int v, $core.String n) : super(v, n);
+}
+
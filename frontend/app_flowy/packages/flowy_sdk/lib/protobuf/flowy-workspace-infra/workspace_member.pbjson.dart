@@ -0,0 +1,63 @@
+///
+//  Generated code. Do not modify.
+//  source: workspace_member.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+import 'dart:core' as $core;
+import 'dart:convert' as $convert;
+import 'dart:typed_data' as $typed_data;
+@$core.Deprecated('Use workspaceRoleDescriptor instead')
+const WorkspaceRole$json = const {
+  '1': 'WorkspaceRole',
+  '2': const [
+    const {'1': 'Viewer', '2': 0},
+    const {'1': 'Editor', '2': 1},
+    const {'1': 'Owner', '2': 2},
+  ],
+};
+
+/// Descriptor for `WorkspaceRole`. Decode as a `google.protobuf.EnumDescriptorProto`.
+final $typed_data.Uint8List workspaceRoleDescriptor = $convert.base64Decode('Cg1Xb3Jrc3BhY2VSb2xlEgoKBlZpZXdlchAAEgoKBkVkaXRvchABEgkKBU93bmVyEAI=');
+@$core.Deprecated('Use addWorkspaceMemberParamsDescriptor instead')
+const AddWorkspaceMemberParams$json = const {
+  '1': 'AddWorkspaceMemberParams',
+  '2': const [
+    const {'1': 'workspace_id', '3': 1, '4': 1, '5': 9, '10': 'workspaceId'},
+    const {'1': 'user_id', '3': 2, '4': 1, '5': 9, '9': 0, '10': 'userId'},
+    const {'1': 'email', '3': 3, '4': 1, '5': 9, '9': 1, '10': 'email'},
+    const {'1': 'role', '3': 4, '4': 1, '5': 14, '6': '.WorkspaceRole', '10': 'role'},
+  ],
+  '8': const [
+    const {'1': 'one_of_user_id'},
+    const {'1': 'one_of_email'},
+  ],
+};
+
+/// Descriptor for `AddWorkspaceMemberParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List addWorkspaceMemberParamsDescriptor = $convert.base64Decode('ChhBZGRXb3Jrc3BhY2VNZW1iZXJQYXJhbXMSIQoMd29ya3NwYWNlX2lkGAEgASgJUgt3b3Jrc3BhY2VJZBIZCgd1c2VyX2lkGAIgASgJSABSBnVzZXJJZBIWCgVlbWFpbBgDIAEoCUgBUgVlbWFpbBIiCgRyb2xlGAQgASgOMg4uV29ya3NwYWNlUm9sZVIEcm9sZUIQCg5vbmVfb2ZfdXNlcl9pZEIOCgxvbmVfb2ZfZW1haWw=');
+@$core.Deprecated('Use removeWorkspaceMemberParamsDescriptor instead')
+const RemoveWorkspaceMemberParams$json = const {
+  '1': 'RemoveWorkspaceMemberParams',
+  '2': const [
+    const {'1': 'workspace_id', '3': 1, '4': 1, '5': 9, '10': 'workspaceId'},
+    const {'1': 'user_id', '3': 2, '4': 1, '5': 9, '10': 'userId'},
+  ],
+};
+
+/// Descriptor for `RemoveWorkspaceMemberParams`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List removeWorkspaceMemberParamsDescriptor = $convert.base64Decode('ChtSZW1vdmVXb3Jrc3BhY2VNZW1iZXJQYXJhbXMSIQoMd29ya3NwYWNlX2lkGAEgASgJUgt3b3Jrc3BhY2VJZBIXCgd1c2VyX2lkGAIgASgJUgZ1c2VySWQ=');
+@$core.Deprecated('Use workspaceMemberDescriptor instead')
+const WorkspaceMember$json = const {
+  '1': 'WorkspaceMember',
+  '2': const [
+    const {'1': 'workspace_id', '3': 1, '4': 1, '5': 9, '10': 'workspaceId'},
+    const {'1': 'user_id', '3': 2, '4': 1, '5': 9, '10': 'userId'},
+    const {'1': 'email', '3': 3, '4': 1, '5': 9, '10': 'email'},
+    const {'1': 'role', '3': 4, '4': 1, '5': 14, '6': '.WorkspaceRole', '10': 'role'},
+  ],
+};
+
+/// Descriptor for `WorkspaceMember`. Decode as a `google.protobuf.DescriptorProto`.
+final $typed_data.Uint8List workspaceMemberDescriptor = $convert.base64Decode('Cg9Xb3Jrc3BhY2VNZW1iZXISIQoMd29ya3NwYWNlX2lkGAEgASgJUgt3b3Jrc3BhY2VJZBIXCgd1c2VyX2lkGAIgASgJUgZ1c2VySWQSFAoFZW1haWwYAyABKAlSBWVtYWlsEiIKBHJvbGUYBCABKA4yDi5Xb3Jrc3BhY2VSb2xlUgRyb2xl');
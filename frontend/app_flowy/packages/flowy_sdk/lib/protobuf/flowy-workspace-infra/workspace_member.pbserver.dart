@@ -0,0 +1,9 @@
+///
+//  Generated code. Do not modify.
+//  source: workspace_member.proto
+//
+// @dart = 2.12
+// ignore_for_file: annotate_overrides,camel_case_types,unnecessary_const,non_constant_identifier_names,library_prefixes,unused_import,unused_shown_name,return_of_invalid_type,unnecessary_this,prefer_final_fields,deprecated_member_use_from_same_package
+
+export 'workspace_member.pb.dart';
+
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE rev_table ADD COLUMN md5 TEXT NOT NULL DEFAULT '';
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS rev_table_doc_id_rev_id ON rev_table (doc_id, rev_id);
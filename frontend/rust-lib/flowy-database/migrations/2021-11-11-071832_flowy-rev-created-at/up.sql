@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE rev_table ADD COLUMN created_at BIGINT NOT NULL DEFAULT 0;
@@ -30,6 +30,8 @@ table! {
         data -> Binary,
         state -> Integer,
         ty -> Integer,
+        md5 -> Text,
+        created_at -> BigInt,
     }
 }
 
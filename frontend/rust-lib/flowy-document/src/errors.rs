@@ -43,11 +43,16 @@ impl DocError {
 
     pub fn is_record_not_found(&self) -> bool { self.code == ErrorCode::DocNotfound }
 
+    pub fn is_corrupted_rev(&self) -> bool { self.code == ErrorCode::CorruptedRevision }
+
+    pub fn is_duplicate_rev(&self) -> bool { self.code == ErrorCode::DuplicateRevision }
+
     static_doc_error!(ws, ErrorCode::WsConnectError);
     static_doc_error!(internal, ErrorCode::InternalError);
     static_doc_error!(unauthorized, ErrorCode::UserUnauthorized);
     static_doc_error!(record_not_found, ErrorCode::DocNotfound);
     static_doc_error!(duplicate_rev, ErrorCode::DuplicateRevision);
+    static_doc_error!(corrupted_rev, ErrorCode::CorruptedRevision);
 }
 
 pub fn internal_error<T>(e: T) -> DocError
@@ -68,6 +73,9 @@ pub enum ErrorCode {
     #[display(fmt = "Duplicate revision")]
     DuplicateRevision = 2,
 
+    #[display(fmt = "Corrupted revision")]
+    CorruptedRevision = 3,
+
     #[display(fmt = "UserUnauthorized")]
     UserUnauthorized  = 10,
 
@@ -26,6 +26,7 @@
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum DocObservable {
     UserCreateDoc = 0,
+    DocAwarenessChanged = 1,
 }
 
 impl ::protobuf::ProtobufEnum for DocObservable {
@@ -36,6 +37,7 @@ impl ::protobuf::ProtobufEnum for DocObservable {
     fn from_i32(value: i32) -> ::std::option::Option<DocObservable> {
         match value {
             0 => ::std::option::Option::Some(DocObservable::UserCreateDoc),
+            1 => ::std::option::Option::Some(DocObservable::DocAwarenessChanged),
             _ => ::std::option::Option::None
         }
     }
@@ -43,6 +45,7 @@ impl ::protobuf::ProtobufEnum for DocObservable {
     fn values() -> &'static [Self] {
         static values: &'static [DocObservable] = &[
             DocObservable::UserCreateDoc,
+            DocObservable::DocAwarenessChanged,
         ];
         values
     }
@@ -71,12 +74,14 @@ impl ::protobuf::reflect::ProtobufValue for DocObservable {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x10observable.proto*\"\n\rDocObservable\x12\x11\n\rUserCreateDoc\x10\
-    \0JS\n\x06\x12\x04\0\0\x04\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\
-    \x05\0\x12\x04\x02\0\x04\x01\n\n\n\x03\x05\0\x01\x12\x03\x02\x05\x12\n\
-    \x0b\n\x04\x05\0\x02\0\x12\x03\x03\x04\x16\n\x0c\n\x05\x05\0\x02\0\x01\
-    \x12\x03\x03\x04\x11\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x03\x14\x15b\
-    \x06proto3\
+    \n\x10observable.proto*;\n\rDocObservable\x12\x11\n\rUserCreateDoc\x10\0\
+    \x12\x17\n\x13DocAwarenessChanged\x10\x01J|\n\x06\x12\x04\0\0\x05\x01\n\
+    \x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x05\0\x12\x04\x02\0\x05\x01\n\n\
+    \n\x03\x05\0\x01\x12\x03\x02\x05\x12\n\x0b\n\x04\x05\0\x02\0\x12\x03\x03\
+    \x04\x16\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x03\x04\x11\n\x0c\n\x05\x05\
+    \0\x02\0\x02\x12\x03\x03\x14\x15\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x04\
+    \x04\x1c\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x04\x04\x17\n\x0c\n\x05\
+    \x05\0\x02\x01\x02\x12\x03\x04\x1a\x1bb\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
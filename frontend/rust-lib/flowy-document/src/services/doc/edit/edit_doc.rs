@@ -1,6 +1,7 @@
 use crate::{
     errors::{internal_error, DocError, DocResult},
     module::DocumentUser,
+    notify::{dart_notify, DocObservable},
     services::{
         doc::{DocumentActor, DocumentMsg, OpenDocAction, RevisionManager, RevisionServer, TransformDeltas},
         ws::{DocumentWebSocket, WsDocumentHandler},
@@ -11,15 +12,21 @@ use flowy_database::ConnectionPool;
 use flowy_document_infra::{
     core::history::UndoResult,
     entities::{
-        doc::{DocDelta, RevId, RevType, Revision, RevisionRange},
+        doc::{DocAwareness, DocDelta, RevAck, RevId, RevType, Revision, RevisionRange},
         ws::{WsDataType, WsDocumentData},
     },
     errors::DocumentResult,
 };
 use lib_infra::retry::{ExponentialBackoff, Retry};
-use lib_ot::core::{Attribute, Delta, Interval};
+use lib_ot::core::{Attribute, Attributes, Delta, Interval, OperationTransformable};
 use lib_ws::WsState;
-use std::{convert::TryFrom, sync::Arc};
+use std::{
+    convert::TryFrom,
+    sync::{
+        atomic::{AtomicBool, Ordering::SeqCst},
+        Arc,
+    },
+};
 use tokio::sync::{mpsc, mpsc::UnboundedSender, oneshot};
 
 pub type DocId = String;
@@ -30,6 +37,7 @@ pub struct ClientEditDoc {
     document: UnboundedSender<DocumentMsg>,
     ws: Arc<dyn DocumentWebSocket>,
     user: Arc<dyn DocumentUser>,
+    has_unsynced: AtomicBool,
 }
 
 impl ClientEditDoc {
@@ -54,6 +62,7 @@ impl ClientEditDoc {
             document,
             ws,
             user,
+            has_unsynced: AtomicBool::new(false),
         };
         edit_doc.notify_open_doc();
         Ok(edit_doc)
@@ -155,6 +164,7 @@ impl ClientEditDoc {
         let delta_data = delta_data.to_vec();
         let revision = Revision::new(base_rev_id, rev_id, delta_data, &self.doc_id, RevType::Local);
         let _ = self.rev_manager.add_revision(&revision).await?;
+        self.notify_awareness(false).await;
         Ok(rev_id.into())
     }
 
@@ -199,6 +209,27 @@ impl ClientEditDoc {
         }
     }
 
+    // Tells the other users whether the document has local revisions that the
+    // server didn't acknowledge yet. Only the changes are sent unless `force` is
+    // set, e.g. the server lost the awareness after reconnecting.
+    async fn notify_awareness(&self, force: bool) {
+        let has_unsynced = self.rev_manager.has_pending_revisions().await;
+        if self.has_unsynced.swap(has_unsynced, SeqCst) == has_unsynced && !force {
+            return;
+        }
+
+        if let Ok(user_id) = self.user.user_id() {
+            let awareness = DocAwareness {
+                doc_id: self.doc_id.clone(),
+                user_id,
+                has_unsynced,
+            };
+            if let Err(e) = self.ws.send(awareness.into()) {
+                log::error!("Send awareness failed: {:?}", e);
+            }
+        }
+    }
+
     #[tracing::instrument(level = "debug", skip(self))]
     async fn handle_push_rev(&self, bytes: Bytes) -> DocResult<()> {
         // Transform the revision
@@ -251,6 +282,77 @@ impl ClientEditDoc {
         Ok(())
     }
 
+    // The server sends the snapshot of the document instead of transforming the
+    // revisions of the client far behind it. Replaces the document with the
+    // snapshot and rebases the pending revisions on it, the local inserts are kept
+    // while the local edits of the replaced content are dropped.
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn handle_resync(&self, bytes: Bytes) -> DocResult<()> {
+        let revision = Revision::try_from(bytes)?;
+        let snapshot = Delta::from_bytes(&revision.delta_data)?;
+        let pending_revisions = self.rev_manager.pending_revisions().await?;
+        let is_rebased = match pending_revisions.first() {
+            None => self.rev_manager.rev_id() >= revision.rev_id,
+            Some(pending) => pending.base_rev_id >= revision.rev_id,
+        };
+        if is_rebased {
+            // Ignore the snapshot if the local revisions are based on it already
+            return Ok(());
+        }
+
+        let mut local_delta = match pending_revisions.first() {
+            None => {
+                let mut delta = Delta::new();
+                delta.retain(self.document_delta().await?.target_len, Attributes::default());
+                delta
+            },
+            Some(pending) => Delta::from_bytes(&pending.delta_data)?,
+        };
+        for pending in pending_revisions.iter().skip(1) {
+            local_delta = local_delta.compose(&Delta::from_bytes(&pending.delta_data)?)?;
+        }
+        // The trailing retain of the revisions is trimmed, restores it so the whole
+        // document is replaced.
+        let document_len = self.document_delta().await?.target_len;
+        if local_delta.target_len < document_len {
+            local_delta.retain(document_len - local_delta.target_len, Attributes::default());
+        }
+
+        // Replaces the base of the local revisions with the snapshot
+        let mut replace_delta = Delta::new();
+        replace_delta.delete(local_delta.base_len);
+        snapshot.ops.into_iter().for_each(|op| replace_delta.add(op));
+        let (local_prime, replace_prime) = local_delta.transform(&replace_delta)?;
+
+        let (ret, rx) = oneshot::channel::<DocumentResult<()>>();
+        let msg = DocumentMsg::Delta {
+            delta: replace_prime,
+            ret,
+        };
+        let _ = self.document.send(msg);
+        let _ = rx.await.map_err(internal_error)??;
+
+        // The server dropped the pending revisions, resend them as one revision
+        // based on the snapshot.
+        if let Some(pending) = pending_revisions.last() {
+            let _ = self.rev_manager.ack_revision(pending.rev_id.into()).await?;
+        }
+        self.rev_manager.update_rev_id_counter_value(revision.rev_id);
+        if !local_prime.is_noop() {
+            let _ = self.save_local_delta(local_prime).await?;
+        }
+        Ok(())
+    }
+
+    async fn document_delta(&self) -> DocResult<Delta> {
+        let (ret, rx) = oneshot::channel::<DocumentResult<String>>();
+        let msg = DocumentMsg::Doc { ret };
+        let _ = self.document.send(msg);
+        let json = rx.await.map_err(internal_error)??;
+        let delta = Delta::from_json(&json)?;
+        Ok(delta)
+    }
+
     async fn handle_ws_message(&self, doc_data: WsDocumentData) -> DocResult<()> {
         let bytes = Bytes::from(doc_data.data);
         match doc_data.ty {
@@ -264,10 +366,19 @@ impl ClientEditDoc {
             },
             WsDataType::NewDocUser => {},
             WsDataType::Acked => {
-                let rev_id = RevId::try_from(bytes)?;
-                let _ = self.rev_manager.ack_revision(rev_id).await?;
+                let ack = RevAck::try_from(bytes)?;
+                let _ = self.rev_manager.handle_rev_ack(ack).await?;
+                self.notify_awareness(false).await;
+            },
+            WsDataType::Conflict => {
+                let _ = self.handle_resync(bytes).await?;
+            },
+            WsDataType::Awareness => {
+                let awareness = DocAwareness::try_from(bytes)?;
+                dart_notify(&self.doc_id, DocObservable::DocAwarenessChanged)
+                    .payload(awareness)
+                    .send();
             },
-            WsDataType::Conflict => {},
         }
         Ok(())
     }
@@ -288,7 +399,11 @@ impl WsDocumentHandler for EditDocWsHandler {
     fn state_changed(&self, state: &WsState) {
         match state {
             WsState::Init => {},
-            WsState::Connected(_) => self.0.notify_open_doc(),
+            WsState::Connected(_) => {
+                self.0.notify_open_doc();
+                let edit_doc = self.0.clone();
+                tokio::spawn(async move { edit_doc.notify_awareness(true).await });
+            },
             WsState::Disconnected(_e) => {},
         }
     }
@@ -4,7 +4,7 @@ use crate::{
 };
 use flowy_database::ConnectionPool;
 use flowy_document_infra::{
-    entities::doc::{Doc, RevId, RevType, Revision, RevisionRange},
+    entities::doc::{Doc, RevAck, RevId, RevType, Revision, RevisionRange},
     util::RevIdCounter,
 };
 use lib_infra::future::ResultFuture;
@@ -54,6 +54,24 @@ impl RevisionManager {
         Ok(())
     }
 
+    // Acks the revision unless the md5 in the ack doesn't match the one of the
+    // revision sent, which is kept pending to be sent again.
+    pub async fn handle_rev_ack(&self, ack: RevAck) -> Result<(), DocError> {
+        if let Some(md5) = self.rev_store.revision_md5(ack.rev_id) {
+            if !ack.md5.is_empty() && ack.md5 != md5 {
+                return Err(DocError::corrupted_rev().context(format!(
+                    "The md5 of the acked revision {} is {}, expected {}",
+                    ack.rev_id, ack.md5, md5
+                )));
+            }
+        }
+        self.ack_revision(ack.rev_id.into()).await
+    }
+
+    pub async fn pending_revisions(&self) -> DocResult<Vec<Revision>> { self.rev_store.pending_revisions().await }
+
+    pub async fn has_pending_revisions(&self) -> bool { self.rev_store.has_pending_revisions().await }
+
     pub fn rev_id(&self) -> i64 { self.rev_id_counter.value() }
 
     pub fn next_rev_id(&self) -> (i64, i64) {
@@ -24,6 +24,13 @@ impl RevisionRecord {
             state: RevState::Local,
         }
     }
+
+    pub fn acked(revision: Revision) -> Self {
+        Self {
+            revision,
+            state: RevState::Acked,
+        }
+    }
 }
 
 pub(crate) struct PendingRevId {
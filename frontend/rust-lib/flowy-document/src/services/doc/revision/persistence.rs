@@ -6,18 +6,44 @@ use crate::{
 use async_stream::stream;
 use dashmap::DashMap;
 use flowy_database::{ConnectionPool, SqliteConnection};
-use flowy_document_infra::entities::doc::{revision_from_doc, Doc, RevId, RevType, Revision, RevisionRange};
+use flowy_document_infra::entities::doc::{
+    replay_revisions,
+    revision_from_doc,
+    Doc,
+    RevId,
+    RevType,
+    Revision,
+    RevisionRange,
+};
 use futures::stream::StreamExt;
 use lib_infra::future::ResultFuture;
-use lib_ot::core::{Delta, Operation, OperationTransformable};
+use lib_ot::core::{Delta, Operation};
 use std::{collections::VecDeque, sync::Arc, time::Duration};
 use tokio::{
     sync::{broadcast, mpsc, RwLock},
     task::{spawn_blocking, JoinHandle},
 };
 
+/// How the revisions read from the local database are checked when the document
+/// is reconstructed from them. A revision whose data doesn't match its stored
+/// md5 aborts the reconstruction in both modes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReconstructMode {
+    /// The unknown states or types of the revisions fall back to Local.
+    #[allow(dead_code)]
+    Lenient,
+    /// The unknown states or types of the revisions abort the reconstruction
+    /// with a corrupted revision error.
+    Strict,
+}
+
+impl std::default::Default for ReconstructMode {
+    fn default() -> Self { ReconstructMode::Strict }
+}
+
 pub struct RevisionStore {
     doc_id: String,
+    reconstruct_mode: ReconstructMode,
     persistence: Arc<Persistence>,
     revs_map: Arc<DashMap<i64, RevisionRecord>>,
     pending_tx: PendingSender,
@@ -41,6 +67,7 @@ impl RevisionStore {
 
         let store = Arc::new(Self {
             doc_id,
+            reconstruct_mode: ReconstructMode::default(),
             persistence,
             revs_map,
             pending_revs,
@@ -60,6 +87,14 @@ impl RevisionStore {
             return Err(DocError::duplicate_rev().context(format!("Duplicate revision id: {}", revision.rev_id)));
         }
 
+        if !revision.ty.is_local() {
+            // The remote revision comes from the server, so it's acked already and must
+            // not be sent back as a local one.
+            self.revs_map.insert(revision.rev_id, RevisionRecord::acked(revision));
+            self.save_revisions().await;
+            return Ok(());
+        }
+
         let (sender, receiver) = broadcast::channel(2);
         let revs_map = self.revs_map.clone();
         let mut rx = sender.subscribe();
@@ -81,6 +116,10 @@ impl RevisionStore {
         Ok(())
     }
 
+    pub fn revision_md5(&self, rev_id: i64) -> Option<String> {
+        self.revs_map.get(&rev_id).map(|record| record.revision.md5.clone())
+    }
+
     #[tracing::instrument(level = "debug", skip(self, rev_id), fields(rev_id = %rev_id.as_ref()))]
     pub async fn ack_revision(&self, rev_id: RevId) {
         let rev_id = rev_id.value;
@@ -146,10 +185,34 @@ impl RevisionStore {
         }
     }
 
+    pub async fn has_pending_revisions(&self) -> bool { !self.pending_revs.read().await.is_empty() }
+
+    // The revisions not acked by the server yet, in the order of rev_id.
+    pub async fn pending_revisions(&self) -> DocResult<Vec<Revision>> {
+        let rev_ids = self
+            .pending_revs
+            .read()
+            .await
+            .iter()
+            .map(|pending| pending.rev_id)
+            .collect::<Vec<i64>>();
+
+        let mut revisions = vec![];
+        for rev_id in rev_ids {
+            match self.revs_map.get(&rev_id) {
+                None => revisions.extend(self.persistence.read_rev(&self.doc_id, &rev_id)?),
+                Some(rev) => revisions.push(rev.revision.clone()),
+            }
+        }
+        Ok(revisions)
+    }
+
     pub async fn fetch_document(&self) -> DocResult<Doc> {
-        let result = fetch_from_local(&self.doc_id, self.persistence.clone()).await;
-        if result.is_ok() {
-            return result;
+        match fetch_from_local(&self.doc_id, self.persistence.clone(), self.reconstruct_mode).await {
+            Ok(doc) => return Ok(doc),
+            // The local revisions are corrupted, fetching the document from the server would hide it.
+            Err(e) if e.is_corrupted_rev() => return Err(e),
+            Err(_) => {},
         }
 
         let doc = self.server.fetch_document_from_remote(&self.doc_id).await?;
@@ -177,28 +240,24 @@ impl RevisionIterator for RevisionStore {
     }
 }
 
-async fn fetch_from_local(doc_id: &str, persistence: Arc<Persistence>) -> DocResult<Doc> {
+async fn fetch_from_local(doc_id: &str, persistence: Arc<Persistence>, mode: ReconstructMode) -> DocResult<Doc> {
     let doc_id = doc_id.to_owned();
     spawn_blocking(move || {
         let conn = &*persistence.pool.get().map_err(internal_error)?;
-        let revisions = persistence.rev_sql.read_rev_tables(&doc_id, conn)?;
+        let revisions = match mode {
+            ReconstructMode::Lenient => persistence.rev_sql.read_rev_tables(&doc_id, conn)?,
+            ReconstructMode::Strict => persistence.rev_sql.read_rev_tables_strictly(&doc_id, conn)?,
+        };
         if revisions.is_empty() {
             return Err(DocError::record_not_found().context("Local doesn't have this document"));
         }
 
         let base_rev_id: RevId = revisions.last().unwrap().base_rev_id.into();
         let rev_id: RevId = revisions.last().unwrap().rev_id.into();
-        let mut delta = Delta::new();
-        for (_, revision) in revisions.into_iter().enumerate() {
-            match Delta::from_bytes(revision.delta_data) {
-                Ok(local_delta) => {
-                    delta = delta.compose(&local_delta)?;
-                },
-                Err(e) => {
-                    log::error!("Deserialize delta from revision failed: {}", e);
-                },
-            }
-        }
+        #[cfg(feature = "metrics")]
+        let _timer = lib_ot::metrics::LatencyTimer::new(lib_ot::metrics::RECONSTRUCT, revisions.len());
+
+        let mut delta = replay_revisions(&revisions)?;
 
         #[cfg(debug_assertions)]
         validate_delta(&doc_id, persistence, conn, &delta);
@@ -361,6 +420,10 @@ impl RevisionStream {
     async fn prepare_next_pending_rev(&self, mut ret: RevIdReceiver) -> DocResult<()> {
         match self.revisions.next().await? {
             None => Ok(()),
+            Some(revision) if !revision.ty.is_local() => {
+                tracing::warn!("Skip sending the remote revision {}", revision.rev_id);
+                Ok(())
+            },
             Some(revision) => {
                 let _ = self.ws_revision_sender.send(revision).map_err(internal_error);
                 let _ = tokio::time::timeout(Duration::from_millis(2000), ret.recv()).await;
@@ -369,3 +432,104 @@ impl RevisionStream {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flowy_database::{insert_into, prelude::*, schema::rev_table::dsl};
+
+    #[tokio::test]
+    async fn strict_reconstruct_rejects_unknown_rev_state() {
+        let storage_path = std::env::temp_dir().join(format!("flowy-document-strict-{}", std::process::id()));
+        let database = flowy_database::init(storage_path.to_str().unwrap()).unwrap();
+        let persistence = Arc::new(Persistence::new(database.get_pool()));
+        let doc_id = "strict_reconstruct_doc";
+        {
+            let conn = &*persistence.pool.get().unwrap();
+            let _ = insert_into(dsl::rev_table)
+                .values((
+                    dsl::doc_id.eq(doc_id),
+                    dsl::base_rev_id.eq(0_i64),
+                    dsl::rev_id.eq(1_i64),
+                    dsl::data.eq(Delta::from_plain_text("123").to_bytes().to_vec()),
+                    dsl::state.eq(7),
+                    dsl::ty.eq(0),
+                ))
+                .execute(conn)
+                .unwrap();
+        }
+
+        let doc = fetch_from_local(doc_id, persistence.clone(), ReconstructMode::Lenient)
+            .await
+            .unwrap();
+        assert_eq!(doc.data, r#"[{"insert":"123\n"}]"#);
+
+        let error = fetch_from_local(doc_id, persistence, ReconstructMode::Strict)
+            .await
+            .unwrap_err();
+        assert!(error.is_corrupted_rev());
+        let _ = std::fs::remove_dir_all(storage_path);
+    }
+
+    #[tokio::test]
+    async fn compressed_rev_round_trip() {
+        let storage_path = std::env::temp_dir().join(format!("flowy-document-compressed-{}", std::process::id()));
+        let database = flowy_database::init(storage_path.to_str().unwrap()).unwrap();
+        let persistence = Persistence::new(database.get_pool());
+        let doc_id = "compressed_rev_doc";
+        let delta = Delta::from_plain_text(&"AppFlowy is an open source alternative to Notion. ".repeat(2000));
+        let revision = Revision::new(0, 1, delta.to_bytes().to_vec(), doc_id, RevType::Local);
+        let _ = persistence
+            .create_revs(vec![(revision.clone(), RevState::Local)])
+            .unwrap();
+
+        let stored: Vec<u8> = {
+            let conn = &*persistence.pool.get().unwrap();
+            dsl::rev_table
+                .filter(dsl::doc_id.eq(doc_id))
+                .select(dsl::data)
+                .first(conn)
+                .unwrap()
+        };
+        assert!(stored.len() < revision.delta_data.len() / 10);
+
+        let read = persistence.read_rev(doc_id, &1).unwrap().unwrap();
+        assert_eq!(read.delta_data, revision.delta_data);
+        assert_eq!(read.md5, revision.md5);
+        let _ = std::fs::remove_dir_all(storage_path);
+    }
+
+    #[tokio::test]
+    async fn tampered_rev_detected() {
+        let storage_path = std::env::temp_dir().join(format!("flowy-document-tampered-{}", std::process::id()));
+        let database = flowy_database::init(storage_path.to_str().unwrap()).unwrap();
+        let persistence = Arc::new(Persistence::new(database.get_pool()));
+        let doc_id = "tampered_rev_doc";
+        let revision = Revision::new(
+            0,
+            1,
+            Delta::from_plain_text("123").to_bytes().to_vec(),
+            doc_id,
+            RevType::Local,
+        );
+        let _ = persistence.create_revs(vec![(revision, RevState::Local)]).unwrap();
+        let _ = persistence.read_rev(doc_id, &1).unwrap().unwrap();
+
+        {
+            let conn = &*persistence.pool.get().unwrap();
+            let tampered = Delta::from_plain_text("456").to_bytes().to_vec();
+            let _ = diesel::update(dsl::rev_table.filter(dsl::doc_id.eq(doc_id)))
+                .set(dsl::data.eq(tampered))
+                .execute(conn)
+                .unwrap();
+        }
+
+        let error = persistence.read_rev(doc_id, &1).unwrap_err();
+        assert!(error.is_corrupted_rev());
+        for mode in [ReconstructMode::Lenient, ReconstructMode::Strict].iter() {
+            let error = fetch_from_local(doc_id, persistence.clone(), *mode).await.unwrap_err();
+            assert!(error.is_corrupted_rev());
+        }
+        let _ = std::fs::remove_dir_all(storage_path);
+    }
+}
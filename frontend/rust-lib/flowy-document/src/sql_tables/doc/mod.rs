@@ -1,5 +1,7 @@
+mod rev_data;
 mod rev_sql;
 mod rev_table;
 
+pub(crate) use rev_data::*;
 pub(crate) use rev_sql::*;
 pub(crate) use rev_table::*;
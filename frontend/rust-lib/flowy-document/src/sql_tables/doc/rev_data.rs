@@ -0,0 +1,60 @@
+use crate::errors::{internal_error, DocError};
+
+// The rev_table data written before the compression is the raw delta, which
+// starts with the '[' of the JSON array. The compressed data starts with this
+// flag byte instead, followed by the zstd frame.
+const ZSTD_FLAG: u8 = 0x01;
+// The data shorter than this number of bytes is stored as it is, the zstd
+// frame of a small delta is barely smaller than the delta.
+const COMPRESS_THRESHOLD: usize = 256;
+const COMPRESS_LEVEL: i32 = zstd::DEFAULT_COMPRESSION_LEVEL;
+
+/// Encodes the delta data of a revision to be stored in the rev_table.
+pub(crate) fn compress_rev_data(data: &[u8]) -> Result<Vec<u8>, DocError> {
+    if data.len() < COMPRESS_THRESHOLD {
+        return Ok(data.to_vec());
+    }
+
+    let mut compressed = vec![ZSTD_FLAG];
+    let frame = zstd::encode_all(data, COMPRESS_LEVEL).map_err(internal_error)?;
+    compressed.extend(frame);
+    Ok(compressed)
+}
+
+/// Decodes the data read from the rev_table back to the delta data, the
+/// uncompressed rows are returned as they are.
+pub(crate) fn decompress_rev_data(data: Vec<u8>) -> Result<Vec<u8>, DocError> {
+    match data.first() {
+        Some(&ZSTD_FLAG) => zstd::decode_all(&data[1..])
+            .map_err(|e| DocError::corrupted_rev().context(format!("Decompress the revision data failed: {:?}", e))),
+        _ => Ok(data),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rev_data_round_trip() {
+        let data = format!(r#"[{{"insert":"{}\n"}}]"#, "AppFlowy ".repeat(1000)).into_bytes();
+        let compressed = compress_rev_data(&data).unwrap();
+        assert_eq!(compressed[0], ZSTD_FLAG);
+        assert!(compressed.len() < data.len());
+        assert_eq!(decompress_rev_data(compressed).unwrap(), data);
+    }
+
+    #[test]
+    fn rev_data_small_or_uncompressed() {
+        let data = br#"[{"insert":"123\n"}]"#.to_vec();
+        assert_eq!(compress_rev_data(&data).unwrap(), data);
+        assert_eq!(decompress_rev_data(data.clone()).unwrap(), data);
+        assert_eq!(decompress_rev_data(vec![]).unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn rev_data_corrupted_frame() {
+        let error = decompress_rev_data(vec![ZSTD_FLAG, 1, 2, 3]).unwrap_err();
+        assert!(error.is_corrupted_rev());
+    }
+}
@@ -1,13 +1,29 @@
 use crate::{
     errors::DocError,
-    sql_tables::{doc::RevTable, RevChangeset, RevState, RevTableType},
+    sql_tables::{
+        doc::{compress_rev_data, RawRevTable, RevTable},
+        RevChangeset,
+        RevState,
+        RevTableType,
+    },
 };
 use diesel::update;
-use flowy_database::{insert_or_ignore_into, prelude::*, schema::rev_table::dsl, SqliteConnection};
-use flowy_document_infra::entities::doc::{Revision, RevisionRange};
+use flowy_database::{insert_or_ignore_into, prelude::*, schema::rev_table::dsl, Connection, SqliteConnection};
+use flowy_document_infra::{
+    entities::doc::{Revision, RevisionRange},
+    util::md5,
+};
+use lib_ot::core::{Delta, OperationTransformable};
+use std::convert::{TryFrom, TryInto};
 
 pub struct RevTableSql {}
 
+#[derive(Debug)]
+pub(crate) struct RevisionPage {
+    pub(crate) revisions: Vec<Revision>,
+    pub(crate) has_more: bool,
+}
+
 impl RevTableSql {
     pub(crate) fn create_rev_table(
         &self,
@@ -15,25 +31,53 @@ impl RevTableSql {
         conn: &SqliteConnection,
     ) -> Result<(), DocError> {
         // Batch insert: https://diesel.rs/guides/all-about-inserts.html
-        let records = revisions
-            .into_iter()
-            .map(|(revision, new_state)| {
-                let rev_ty: RevTableType = revision.ty.into();
-                (
-                    dsl::doc_id.eq(revision.doc_id),
-                    dsl::base_rev_id.eq(revision.base_rev_id),
-                    dsl::rev_id.eq(revision.rev_id),
-                    dsl::data.eq(revision.delta_data),
-                    dsl::state.eq(new_state),
-                    dsl::ty.eq(rev_ty),
-                )
-            })
-            .collect::<Vec<_>>();
+        let mut records = vec![];
+        for (revision, new_state) in revisions {
+            let rev_ty: RevTableType = revision.ty.into();
+            let data = compress_rev_data(&revision.delta_data)?;
+            let md5 = md5(&revision.delta_data);
+            let created_at = chrono::Utc::now().timestamp();
+            records.push((
+                dsl::doc_id.eq(revision.doc_id),
+                dsl::base_rev_id.eq(revision.base_rev_id),
+                dsl::rev_id.eq(revision.rev_id),
+                dsl::data.eq(data),
+                dsl::state.eq(new_state),
+                dsl::ty.eq(rev_ty),
+                dsl::md5.eq(md5),
+                dsl::created_at.eq(created_at),
+            ));
+        }
 
         let _ = insert_or_ignore_into(dsl::rev_table).values(&records).execute(conn)?;
         Ok(())
     }
 
+    /// Inserts the rows in one transaction, rolled back entirely if any of them
+    /// fails. The rev_id of each row must be greater than the rev_ids of the
+    /// document stored before it, so the rows are kept in the order of their
+    /// base_rev_id and rev_id.
+    #[allow(dead_code)]
+    pub(crate) fn insert_batch(&self, revs: &[RevTable], conn: &SqliteConnection) -> Result<(), DocError> {
+        conn.transaction::<_, DocError, _>(|| {
+            for rev in revs {
+                let last_rev_id: Option<i64> = dsl::rev_table
+                    .filter(dsl::doc_id.eq(&rev.doc_id))
+                    .select(diesel::dsl::max(dsl::rev_id))
+                    .first(conn)?;
+                if let Some(last_rev_id) = last_rev_id.filter(|last_rev_id| rev.rev_id <= *last_rev_id) {
+                    return Err(DocError::duplicate_rev().context(format!(
+                        "The revision {} of {} is not after the revision {}",
+                        rev.rev_id, rev.doc_id, last_rev_id
+                    )));
+                }
+
+                let _ = insert_rev_table(rev, conn)?;
+            }
+            Ok(())
+        })
+    }
+
     #[allow(dead_code)]
     pub(crate) fn update_rev_table(&self, changeset: RevChangeset, conn: &SqliteConnection) -> Result<(), DocError> {
         let filter = dsl::rev_table
@@ -52,8 +96,26 @@ impl RevTableSql {
         let rev_tables = filter.load::<RevTable>(conn)?;
         let revisions = rev_tables
             .into_iter()
-            .map(|table| table.into())
-            .collect::<Vec<Revision>>();
+            .map(|table| table.try_into())
+            .collect::<Result<Vec<Revision>, DocError>>()?;
+        Ok(revisions)
+    }
+
+    // Same as read_rev_tables, but fails with a corrupted revision error if any
+    // row has an unknown state or type instead of falling back to Local.
+    pub(crate) fn read_rev_tables_strictly(
+        &self,
+        doc_id: &str,
+        conn: &SqliteConnection,
+    ) -> Result<Vec<Revision>, DocError> {
+        let raw_tables = dsl::rev_table
+            .filter(dsl::doc_id.eq(doc_id))
+            .order(dsl::rev_id.asc())
+            .load::<RawRevTable>(conn)?;
+        let mut revisions = vec![];
+        for raw_table in raw_tables {
+            revisions.push(RevTable::try_from(raw_table)?.try_into()?);
+        }
         Ok(revisions)
     }
 
@@ -71,7 +133,7 @@ impl RevTableSql {
         if Err(diesel::NotFound) == result {
             Ok(None)
         } else {
-            Ok(Some(result?.into()))
+            Ok(Some(result?.try_into()?))
         }
     }
 
@@ -90,11 +152,40 @@ impl RevTableSql {
 
         let revisions = rev_tables
             .into_iter()
-            .map(|table| table.into())
-            .collect::<Vec<Revision>>();
+            .map(|table| table.try_into())
+            .collect::<Result<Vec<Revision>, DocError>>()?;
         Ok(revisions)
     }
 
+    /// Returns up to `limit` revisions of the document after `after_rev_id`,
+    /// ordered by their rev_id. Pass the rev_id of the last revision of a page
+    /// to read the next one, or 0 to start from the first revision. The query
+    /// is served by the index on (doc_id, rev_id).
+    #[allow(dead_code)]
+    pub(crate) fn list_revisions(
+        &self,
+        doc_id_s: &str,
+        after_rev_id: i64,
+        limit: usize,
+        conn: &SqliteConnection,
+    ) -> Result<RevisionPage, DocError> {
+        // One more row than the limit is read to know if there is another page.
+        let mut rev_tables = dsl::rev_table
+            .filter(dsl::doc_id.eq(doc_id_s))
+            .filter(dsl::rev_id.gt(after_rev_id))
+            .order(dsl::rev_id.asc())
+            .limit(limit as i64 + 1)
+            .load::<RevTable>(conn)?;
+        let has_more = rev_tables.len() > limit;
+        rev_tables.truncate(limit);
+
+        let revisions = rev_tables
+            .into_iter()
+            .map(|table| table.try_into())
+            .collect::<Result<Vec<Revision>, DocError>>()?;
+        Ok(RevisionPage { revisions, has_more })
+    }
+
     #[allow(dead_code)]
     pub(crate) fn delete_rev_table(
         &self,
@@ -109,4 +200,286 @@ impl RevTableSql {
         debug_assert_eq!(affected_row, 1);
         Ok(())
     }
+
+    /// Composes the consecutive Acked revisions of the document, from the first
+    /// one up to the revisions below `watermark`, into one Synced snapshot
+    /// revision that replaces them. The snapshot takes the base_rev_id of the
+    /// first revision and the rev_id of the last one, so the revisions after it
+    /// still follow it, and the snapshot of a previous compaction is composed
+    /// into the next one. The run stops at the first revision that is neither
+    /// Acked nor Synced, the Local revisions are never compacted. Returns the
+    /// number of the revisions removed.
+    #[allow(dead_code)]
+    pub(crate) fn compact_rev_tables(
+        &self,
+        doc_id_s: &str,
+        watermark: i64,
+        conn: &SqliteConnection,
+    ) -> Result<usize, DocError> {
+        conn.transaction::<_, DocError, _>(|| {
+            let rev_tables = dsl::rev_table
+                .filter(dsl::doc_id.eq(doc_id_s))
+                .filter(dsl::rev_id.lt(watermark))
+                .order(dsl::rev_id.asc())
+                .load::<RevTable>(conn)?;
+            let acked_tables = rev_tables
+                .into_iter()
+                .take_while(|table| matches!(table.state, RevState::Acked | RevState::Synced))
+                .collect::<Vec<RevTable>>();
+            if acked_tables.len() < 2 {
+                return Ok(0);
+            }
+
+            let ids = acked_tables.iter().map(|table| table.id).collect::<Vec<i32>>();
+            let mut revisions = acked_tables
+                .into_iter()
+                .map(|table| table.try_into())
+                .collect::<Result<Vec<Revision>, DocError>>()?
+                .into_iter();
+            let first = revisions.next().unwrap();
+            let (base_rev_id, first_rev_id) = (first.base_rev_id, first.rev_id);
+            let mut snapshot = Delta::from_bytes(&first.delta_data)?;
+            let mut last = first;
+            for revision in revisions {
+                snapshot = snapshot.compose(&Delta::from_bytes(&revision.delta_data)?)?;
+                last = revision;
+            }
+
+            let _ = diesel::delete(dsl::rev_table.filter(dsl::id.eq_any(&ids))).execute(conn)?;
+            let revision = Revision::new(base_rev_id, last.rev_id, snapshot.to_bytes(), doc_id_s, last.ty);
+            let state = RevState::Acked.transition(RevState::Synced)?;
+            let _ = insert_rev_table(&RevTable::new(&revision, state)?, conn)?;
+            tracing::debug!(
+                "Compact the revisions {} to {} of {}",
+                first_rev_id,
+                revision.rev_id,
+                doc_id_s
+            );
+            Ok(ids.len())
+        })
+    }
+}
+
+fn insert_rev_table(rev: &RevTable, conn: &SqliteConnection) -> Result<(), DocError> {
+    let _ = diesel::insert_into(dsl::rev_table)
+        .values((
+            dsl::doc_id.eq(&rev.doc_id),
+            dsl::base_rev_id.eq(rev.base_rev_id),
+            dsl::rev_id.eq(rev.rev_id),
+            dsl::data.eq(&rev.data),
+            dsl::state.eq(rev.state),
+            dsl::ty.eq(rev.ty),
+            dsl::md5.eq(&rev.md5),
+            dsl::created_at.eq(rev.created_at),
+        ))
+        .execute(conn)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flowy_document_infra::entities::doc::RevType;
+    use lib_ot::core::DeltaBuilder;
+
+    fn revisions(doc_id: &str, rev_ids: std::ops::Range<i64>) -> Vec<RevTable> {
+        rev_ids
+            .map(|rev_id| {
+                let delta = Delta::from_plain_text(&format!("{}", rev_id));
+                let revision = Revision::new(rev_id - 1, rev_id, delta.to_bytes().to_vec(), doc_id, RevType::Local);
+                RevTable::new(&revision, RevState::Local).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn insert_batch_in_order() {
+        let storage_path = std::env::temp_dir().join(format!("flowy-document-batch-{}", std::process::id()));
+        let database = flowy_database::init(storage_path.to_str().unwrap()).unwrap();
+        let conn = &*database.get_pool().get().unwrap();
+        let doc_id = "insert_batch_doc";
+        let rev_sql = RevTableSql {};
+        let _ = rev_sql.insert_batch(&revisions(doc_id, 1..101), conn).unwrap();
+
+        let revisions = rev_sql.read_rev_tables(doc_id, conn).unwrap();
+        assert_eq!(revisions.len(), 100);
+        for (i, revision) in revisions.iter().enumerate() {
+            assert_eq!(revision.rev_id, i as i64 + 1);
+            assert_eq!(revision.base_rev_id, i as i64);
+            assert_eq!(
+                revision.delta_data,
+                Delta::from_plain_text(&format!("{}", i + 1)).to_bytes().to_vec()
+            );
+        }
+        let _ = std::fs::remove_dir_all(storage_path);
+    }
+
+    #[test]
+    fn insert_batch_rolled_back() {
+        let storage_path = std::env::temp_dir().join(format!("flowy-document-batch-rollback-{}", std::process::id()));
+        let database = flowy_database::init(storage_path.to_str().unwrap()).unwrap();
+        let conn = &*database.get_pool().get().unwrap();
+        let doc_id = "insert_batch_rollback_doc";
+        let rev_sql = RevTableSql {};
+
+        // The 50th revision repeats the rev_id of the 49th one.
+        let mut batch = revisions(doc_id, 1..101);
+        batch[49] = revisions(doc_id, 49..50).remove(0);
+        let error = rev_sql.insert_batch(&batch, conn).unwrap_err();
+        assert!(error.is_duplicate_rev());
+        assert!(rev_sql.read_rev_tables(doc_id, conn).unwrap().is_empty());
+        let _ = std::fs::remove_dir_all(storage_path);
+    }
+
+    // The revisions typing the rev_ids one after another, every third one
+    // deleting the first character too.
+    fn edits(doc_id: &str, rev_ids: std::ops::Range<i64>, state: RevState) -> Vec<RevTable> {
+        let mut len = 0;
+        rev_ids
+            .map(|rev_id| {
+                let text = format!("{}", rev_id);
+                let delta = match rev_id % 3 == 0 {
+                    true => DeltaBuilder::new().delete(1).retain(len - 1).insert(&text).build(),
+                    false => DeltaBuilder::new().retain(len).insert(&text).build(),
+                };
+                len = delta.target_len;
+                let revision = Revision::new(rev_id - 1, rev_id, delta.to_bytes(), doc_id, RevType::Local);
+                RevTable::new(&revision, state).unwrap()
+            })
+            .collect()
+    }
+
+    fn apply(revisions: &[Revision]) -> String {
+        revisions.iter().fold(String::new(), |s, revision| {
+            Delta::from_bytes(&revision.delta_data).unwrap().apply(&s).unwrap()
+        })
+    }
+
+    #[test]
+    fn compact_acked_revisions() {
+        let storage_path = std::env::temp_dir().join(format!("flowy-document-compact-{}", std::process::id()));
+        let database = flowy_database::init(storage_path.to_str().unwrap()).unwrap();
+        let conn = &*database.get_pool().get().unwrap();
+        let doc_id = "compact_doc";
+        let rev_sql = RevTableSql {};
+        let mut batch = edits(doc_id, 1..21, RevState::Acked);
+        batch
+            .iter_mut()
+            .skip(15)
+            .for_each(|table| table.state = RevState::Local);
+        let _ = rev_sql.insert_batch(&batch, conn).unwrap();
+        let originals = rev_sql.read_rev_tables(doc_id, conn).unwrap();
+
+        // The revisions 1 to 10 are below the watermark.
+        assert_eq!(rev_sql.compact_rev_tables(doc_id, 11, conn).unwrap(), 10);
+        let revisions = rev_sql.read_rev_tables(doc_id, conn).unwrap();
+        assert_eq!(revisions.len(), 11);
+        assert_eq!(revisions[0].base_rev_id, 0);
+        assert_eq!(revisions[0].rev_id, 10);
+        assert_eq!(apply(&revisions[..1]), apply(&originals[..10]));
+        assert_eq!(revisions[1].base_rev_id, revisions[0].rev_id);
+        assert_eq!(apply(&revisions), apply(&originals));
+        let snapshot = dsl::rev_table
+            .filter(dsl::doc_id.eq(doc_id))
+            .filter(dsl::rev_id.eq(10))
+            .first::<RevTable>(conn)
+            .unwrap();
+        assert_eq!(snapshot.state, RevState::Synced);
+
+        // The next compaction composes the snapshot with the revisions 11 to 15.
+        assert_eq!(rev_sql.compact_rev_tables(doc_id, 16, conn).unwrap(), 6);
+        let revisions = rev_sql.read_rev_tables(doc_id, conn).unwrap();
+        assert_eq!(revisions.len(), 6);
+        assert_eq!(revisions[0].rev_id, 15);
+        assert_eq!(apply(&revisions), apply(&originals));
+        let _ = std::fs::remove_dir_all(storage_path);
+    }
+
+    #[test]
+    fn compact_keeps_local_revisions() {
+        let storage_path = std::env::temp_dir().join(format!("flowy-document-compact-local-{}", std::process::id()));
+        let database = flowy_database::init(storage_path.to_str().unwrap()).unwrap();
+        let conn = &*database.get_pool().get().unwrap();
+        let doc_id = "compact_local_doc";
+        let rev_sql = RevTableSql {};
+        // The revisions 1 to 5 are acked, 6 is local and 7 to 10 are acked
+        // after it, so only the first run is compacted.
+        let mut batch = edits(doc_id, 1..11, RevState::Acked);
+        batch[5].state = RevState::Local;
+        let _ = rev_sql.insert_batch(&batch, conn).unwrap();
+        let originals = rev_sql.read_rev_tables(doc_id, conn).unwrap();
+
+        assert_eq!(rev_sql.compact_rev_tables(doc_id, 11, conn).unwrap(), 5);
+        let revisions = rev_sql.read_rev_tables(doc_id, conn).unwrap();
+        assert_eq!(
+            revisions.iter().map(|revision| revision.rev_id).collect::<Vec<i64>>(),
+            vec![5, 6, 7, 8, 9, 10]
+        );
+        let local = rev_sql.read_rev_table(doc_id, &6, conn).unwrap().unwrap();
+        assert_eq!(local.delta_data, originals[5].delta_data);
+        assert_eq!(apply(&revisions), apply(&originals));
+
+        // Nothing is compacted once a local revision comes first.
+        let _ = rev_sql.delete_rev_table(doc_id, 5, conn).unwrap();
+        assert_eq!(rev_sql.compact_rev_tables(doc_id, 11, conn).unwrap(), 0);
+        assert_eq!(rev_sql.read_rev_tables(doc_id, conn).unwrap().len(), 5);
+        let _ = std::fs::remove_dir_all(storage_path);
+    }
+
+    #[test]
+    fn list_revisions_in_pages() {
+        let storage_path = std::env::temp_dir().join(format!("flowy-document-list-{}", std::process::id()));
+        let database = flowy_database::init(storage_path.to_str().unwrap()).unwrap();
+        let conn = &*database.get_pool().get().unwrap();
+        let doc_id = "list_revisions_doc";
+        let rev_sql = RevTableSql {};
+        let _ = rev_sql.insert_batch(&revisions(doc_id, 1..51), conn).unwrap();
+        let _ = rev_sql.insert_batch(&revisions("other_doc", 1..11), conn).unwrap();
+
+        let mut rev_ids = vec![];
+        let mut after_rev_id = 0;
+        loop {
+            let page = rev_sql.list_revisions(doc_id, after_rev_id, 10, conn).unwrap();
+            assert_eq!(page.revisions.len(), 10);
+            rev_ids.extend(page.revisions.iter().map(|revision| revision.rev_id));
+            after_rev_id = page.revisions.last().unwrap().rev_id;
+            if !page.has_more {
+                break;
+            }
+        }
+        assert_eq!(rev_ids, (1..51).collect::<Vec<i64>>());
+
+        let page = rev_sql.list_revisions(doc_id, after_rev_id, 10, conn).unwrap();
+        assert!(page.revisions.is_empty());
+        assert!(!page.has_more);
+        let _ = std::fs::remove_dir_all(storage_path);
+    }
+
+    #[test]
+    fn revisions_created_in_order() {
+        let storage_path = std::env::temp_dir().join(format!("flowy-document-created-at-{}", std::process::id()));
+        let database = flowy_database::init(storage_path.to_str().unwrap()).unwrap();
+        let conn = &*database.get_pool().get().unwrap();
+        let doc_id = "created_at_doc";
+        let rev_sql = RevTableSql {};
+        let start = chrono::Utc::now().timestamp();
+        for rev_id in 1..21 {
+            let _ = rev_sql
+                .insert_batch(&revisions(doc_id, rev_id..rev_id + 1), conn)
+                .unwrap();
+        }
+        let delta = Delta::from_plain_text("21");
+        let revision = Revision::new(20, 21, delta.to_bytes(), doc_id, RevType::Local);
+        let _ = rev_sql
+            .create_rev_table(vec![(revision, RevState::Local)], conn)
+            .unwrap();
+
+        let revisions = rev_sql.read_rev_tables(doc_id, conn).unwrap();
+        assert_eq!(revisions.len(), 21);
+        assert!(revisions[0].created_at >= start);
+        assert!(revisions
+            .windows(2)
+            .all(|revisions| revisions[0].created_at <= revisions[1].created_at));
+        let _ = std::fs::remove_dir_all(storage_path);
+    }
 }
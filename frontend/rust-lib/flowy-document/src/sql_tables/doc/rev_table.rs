@@ -1,3 +1,7 @@
+use crate::{
+    errors::DocError,
+    sql_tables::doc::{compress_rev_data, decompress_rev_data},
+};
 use diesel::sql_types::Integer;
 use flowy_database::schema::rev_table;
 use flowy_document_infra::{
@@ -8,21 +12,50 @@ use flowy_document_infra::{
 #[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
 #[table_name = "rev_table"]
 pub(crate) struct RevTable {
-    id: i32,
+    pub(crate) id: i32,
     pub(crate) doc_id: String,
     pub(crate) base_rev_id: i64,
     pub(crate) rev_id: i64,
     pub(crate) data: Vec<u8>,
     pub(crate) state: RevState,
     pub(crate) ty: RevTableType,
+    // The md5 of the uncompressed data when it's written, empty for the rows
+    // written before the md5 is stored.
+    pub(crate) md5: String,
+    // The time the row is inserted, in seconds since the epoch, 0 for the rows
+    // inserted before the time is stored.
+    pub(crate) created_at: i64,
+}
+
+impl RevTable {
+    // The row to be inserted for the revision, with the compressed data and the
+    // md5 of the uncompressed data, created now. The id is assigned by the
+    // database.
+    pub(crate) fn new(revision: &Revision, state: RevState) -> Result<Self, DocError> {
+        Ok(RevTable {
+            id: 0,
+            doc_id: revision.doc_id.clone(),
+            base_rev_id: revision.base_rev_id,
+            rev_id: revision.rev_id,
+            data: compress_rev_data(&revision.delta_data)?,
+            state,
+            ty: revision.ty.clone().into(),
+            md5: md5(&revision.delta_data),
+            created_at: chrono::Utc::now().timestamp(),
+        })
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Hash, FromSqlRow, AsExpression)]
 #[repr(i32)]
 #[sql_type = "Integer"]
 pub enum RevState {
-    Local = 0,
-    Acked = 1,
+    Local    = 0,
+    Acked    = 1,
+    // Acked and compacted into the snapshot of the document.
+    Synced   = 2,
+    // Rejected by the server because it conflicts with the other revisions.
+    Conflict = 3,
 }
 
 impl std::default::Default for RevState {
@@ -31,11 +64,10 @@ impl std::default::Default for RevState {
 
 impl std::convert::From<i32> for RevState {
     fn from(value: i32) -> Self {
-        match value {
-            0 => RevState::Local,
-            1 => RevState::Acked,
-            o => {
-                log::error!("Unsupported rev state {}, fallback to RevState::Local", o);
+        match RevState::from_value(value) {
+            Some(state) => state,
+            None => {
+                log::error!("Unsupported rev state {}, fallback to RevState::Local", value);
                 RevState::Local
             },
         }
@@ -43,20 +75,100 @@ impl std::convert::From<i32> for RevState {
 }
 impl RevState {
     pub fn value(&self) -> i32 { *self as i32 }
+
+    pub fn from_value(value: i32) -> Option<RevState> {
+        match value {
+            0 => Some(RevState::Local),
+            1 => Some(RevState::Acked),
+            2 => Some(RevState::Synced),
+            3 => Some(RevState::Conflict),
+            _ => None,
+        }
+    }
+
+    /// Moves the revision to the state `to`. The valid moves are Local→Acked,
+    /// Acked→Synced and Local→Conflict, anything else including staying in
+    /// the same state is rejected.
+    pub fn transition(self, to: RevState) -> Result<RevState, DocError> {
+        match (self, to) {
+            (RevState::Local, RevState::Acked)
+            | (RevState::Acked, RevState::Synced)
+            | (RevState::Local, RevState::Conflict) => Ok(to),
+            _ => Err(DocError::internal().context(format!("Invalid rev state transition {:?} -> {:?}", self, to))),
+        }
+    }
 }
 impl_sql_integer_expression!(RevState);
 
-impl std::convert::From<RevTable> for Revision {
-    fn from(table: RevTable) -> Self {
-        let md5 = md5(&table.data);
-        Revision {
+// The row of the rev_table with the state and the type kept as they are stored.
+// Converting it into RevTable rejects the unknown values instead of falling
+// back to Local, which is what reading a RevTable directly does.
+#[derive(Clone, Debug, Queryable)]
+pub(crate) struct RawRevTable {
+    id: i32,
+    doc_id: String,
+    base_rev_id: i64,
+    rev_id: i64,
+    data: Vec<u8>,
+    state: i32,
+    ty: i32,
+    md5: String,
+    created_at: i64,
+}
+
+impl std::convert::TryFrom<RawRevTable> for RevTable {
+    type Error = DocError;
+
+    fn try_from(raw: RawRevTable) -> Result<Self, Self::Error> {
+        let state = RevState::from_value(raw.state).ok_or_else(|| {
+            DocError::corrupted_rev().context(format!(
+                "Unsupported rev state {} of revision {}",
+                raw.state, raw.rev_id
+            ))
+        })?;
+        let ty = RevTableType::from_value(raw.ty).ok_or_else(|| {
+            DocError::corrupted_rev().context(format!("Unsupported rev type {} of revision {}", raw.ty, raw.rev_id))
+        })?;
+        Ok(RevTable {
+            id: raw.id,
+            doc_id: raw.doc_id,
+            base_rev_id: raw.base_rev_id,
+            rev_id: raw.rev_id,
+            data: raw.data,
+            state,
+            ty,
+            md5: raw.md5,
+            created_at: raw.created_at,
+        })
+    }
+}
+
+// Fails with a corrupted revision error if the data can't be decompressed, or
+// its md5 doesn't match the stored one.
+impl std::convert::TryFrom<RevTable> for Revision {
+    type Error = DocError;
+
+    fn try_from(table: RevTable) -> Result<Self, Self::Error> {
+        // The md5 is computed over the uncompressed data, so it's the same as
+        // the md5 of the revision before it's stored.
+        let data = decompress_rev_data(table.data)?;
+        let md5 = md5(&data);
+        if !table.md5.is_empty() && table.md5 != md5 {
+            return Err(DocError::corrupted_rev().context(format!(
+                "The md5 of revision {} is {}, expected {}",
+                table.rev_id, md5, table.md5
+            )));
+        }
+
+        Ok(Revision {
             base_rev_id: table.base_rev_id,
             rev_id: table.rev_id,
-            delta_data: table.data,
+            delta_data: data,
             md5,
             doc_id: table.doc_id,
             ty: table.ty.into(),
-        }
+            created_at: table.created_at,
+        })
     }
 }
 
@@ -92,11 +204,10 @@ impl std::default::Default for RevTableType {
 
 impl std::convert::From<i32> for RevTableType {
     fn from(value: i32) -> Self {
-        match value {
-            0 => RevTableType::Local,
-            1 => RevTableType::Remote,
-            o => {
-                log::error!("Unsupported rev type {}, fallback to RevTableType::Local", o);
+        match RevTableType::from_value(value) {
+            Some(ty) => ty,
+            None => {
+                log::error!("Unsupported rev type {}, fallback to RevTableType::Local", value);
                 RevTableType::Local
             },
         }
@@ -104,6 +215,14 @@ impl std::convert::From<i32> for RevTableType {
 }
 impl RevTableType {
     pub fn value(&self) -> i32 { *self as i32 }
+
+    pub fn from_value(value: i32) -> Option<RevTableType> {
+        match value {
+            0 => Some(RevTableType::Local),
+            1 => Some(RevTableType::Remote),
+            _ => None,
+        }
+    }
 }
 impl_sql_integer_expression!(RevTableType);
 
@@ -113,3 +232,46 @@ pub(crate) struct RevChangeset {
     pub(crate) rev_id: RevId,
     pub(crate) state: RevState,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STATES: [RevState; 4] = [RevState::Local, RevState::Acked, RevState::Synced, RevState::Conflict];
+
+    #[test]
+    fn rev_state_value() {
+        for state in STATES.iter() {
+            assert_eq!(RevState::from(state.value()), *state);
+        }
+        assert_eq!(RevState::from(4), RevState::Local);
+        assert_eq!(RevState::from(-1), RevState::Local);
+    }
+
+    #[test]
+    fn rev_state_valid_transition() {
+        assert_eq!(RevState::Local.transition(RevState::Acked).unwrap(), RevState::Acked);
+        assert_eq!(RevState::Acked.transition(RevState::Synced).unwrap(), RevState::Synced);
+        assert_eq!(
+            RevState::Local.transition(RevState::Conflict).unwrap(),
+            RevState::Conflict
+        );
+    }
+
+    #[test]
+    fn rev_state_invalid_transition() {
+        let valid = [
+            (RevState::Local, RevState::Acked),
+            (RevState::Acked, RevState::Synced),
+            (RevState::Local, RevState::Conflict),
+        ];
+        for from in STATES.iter() {
+            for to in STATES.iter() {
+                if valid.contains(&(*from, *to)) {
+                    continue;
+                }
+                assert!(from.transition(*to).is_err(), "{:?} -> {:?} is invalid", from, to);
+            }
+        }
+    }
+}
@@ -1,8 +1,7 @@
 #![cfg_attr(rustfmt, rustfmt::skip)]
 use crate::editor::{TestBuilder, TestOp::*};
-use flowy_document_infra::core::{FlowyDoc, PlainDoc};
-use lib_ot::core::{Delta, Interval, OperationTransformable, NEW_LINE, WHITESPACE, FlowyStr};
-use unicode_segmentation::UnicodeSegmentation;
+use flowy_document_infra::core::{delta_to_markdown, list_ordinal_at, Document, FlowyDoc, PlainDoc};
+use lib_ot::core::{Attribute, Attributes, Delta, DeltaBuilder, Interval, OperationTransformable, NEW_LINE, WHITESPACE, FlowyStr};
 
 #[test]
 fn attributes_bold_added() {
@@ -376,6 +375,45 @@ fn attributes_replace_with_text() {
     TestBuilder::new().run_script::<PlainDoc>(ops);
 }
 
+#[test]
+fn attributes_replace_selection_inherit_attributes() {
+    let ops = vec![
+        InsertBold(0, "123456", Interval::new(0, 3)),
+        AssertDocJson(0, r#"[{"insert":"123","attributes":{"bold":"true"}},{"insert":"456"}]"#),
+        Replace(0, Interval::new(3, 5), "ab"),
+        AssertDocJson(0, r#"[{"insert":"123ab","attributes":{"bold":"true"}},{"insert":"6"}]"#),
+    ];
+
+    TestBuilder::new().run_script::<PlainDoc>(ops);
+}
+
+#[test]
+fn attributes_replace_selection_delta() {
+    let mut document = Document::new::<PlainDoc>();
+    document.insert(0, "123456").unwrap();
+    document.format(Interval::new(0, 3), Attribute::Bold(true)).unwrap();
+    let delta = document.replace(Interval::new(3, 5), "ab").unwrap();
+    assert_eq!(
+        delta.to_json(),
+        r#"[{"retain":3},{"insert":"ab","attributes":{"bold":true}},{"delete":2}]"#
+    );
+}
+
+#[test]
+fn attributes_replace_selection_with_auto_format() {
+    let site = "https://appflowy.io";
+    let ops = vec![
+        Insert(0, "https://appflowy.io123", 0),
+        Replace(0, Interval::new(site.len(), site.len() + 3), " "),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"https://appflowy.io","attributes":{"link":"https://appflowy.io/"}},{"insert":" \n"}]"#,
+        ),
+    ];
+
+    TestBuilder::new().run_script::<FlowyDoc>(ops);
+}
+
 #[test]
 fn attributes_header_insert_newline_at_middle() {
     let ops = vec![
@@ -574,7 +612,7 @@ fn attributes_link_auto_format_exist() {
         Insert(0, WHITESPACE, site.len()),
         AssertDocJson(
             0,
-            r#"[{"insert":"https://appflowy.io","attributes":{"link":"https://appflowy.io/"}},{"insert":" \n"}]"#,
+            r#"[{"insert":"https://appflowy.io","attributes":{"link":"https://appflowy.io"}},{"insert":" \n"}]"#,
         ),
     ];
 
@@ -597,6 +635,63 @@ fn attributes_link_auto_format_exist2() {
     TestBuilder::new().run_script::<FlowyDoc>(ops);
 }
 
+#[test]
+fn attributes_link_auto_format_last_word() {
+    let ops = vec![
+        Insert(0, "see https://a.com", 0),
+        Insert(0, WHITESPACE, 17),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"see "},{"insert":"https://a.com","attributes":{"link":"https://a.com/"}},{"insert":" \n"}]"#,
+        ),
+    ];
+
+    TestBuilder::new().run_script::<FlowyDoc>(ops);
+}
+
+#[test]
+fn attributes_link_auto_format_on_newline() {
+    let ops = vec![
+        Insert(0, "123 https://a.com", 0),
+        Insert(0, NEW_LINE, 17),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"123 "},{"insert":"https://a.com","attributes":{"link":"https://a.com/"}},{"insert":"\n\n"}]"#,
+        ),
+    ];
+
+    TestBuilder::new().run_script::<FlowyDoc>(ops);
+}
+
+#[test]
+fn attributes_link_auto_format_not_url() {
+    let ops = vec![
+        Insert(0, "see notaurl", 0),
+        Insert(0, WHITESPACE, 11),
+        AssertDocJson(0, r#"[{"insert":"see notaurl \n"}]"#),
+        Insert(0, "a.com", 12),
+        Insert(0, NEW_LINE, 17),
+        AssertDocJson(0, r#"[{"insert":"see notaurl a.com\n\n"}]"#),
+    ];
+
+    TestBuilder::new().run_script::<FlowyDoc>(ops);
+}
+
+#[test]
+fn attributes_link_auto_format_not_relink() {
+    let ops = vec![
+        Insert(0, "https://a.com", 0),
+        Link(0, Interval::new(0, 13), "https://b.com"),
+        Insert(0, WHITESPACE, 13),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"https://a.com","attributes":{"link":"https://b.com"}},{"insert":" \n"}]"#,
+        ),
+    ];
+
+    TestBuilder::new().run_script::<FlowyDoc>(ops);
+}
+
 #[test]
 fn attributes_bullet_added() {
     let ops = vec![
@@ -725,7 +820,7 @@ fn attributes_format_emoji() {
     let s: FlowyStr = emoji_s.into();
     let len = s.count_utf16_code_units();
     assert_eq!(3, len);
-    assert_eq!(2, s.graphemes(true).count());
+    assert_eq!(2, s.grapheme_count());
     let ops = vec![
         Insert(0, emoji_s, 0),
         AssertDocJson(0, r#"[{"insert":"👋 \n"}]"#),
@@ -796,3 +891,206 @@ fn delta_compose() {
 
     TestBuilder::new().run_script::<FlowyDoc>(ops);
 }
+
+#[test]
+fn attributes_continue_bullet_list_on_newline() {
+    let ops = vec![
+        Insert(0, "123456", 0),
+        Bullet(0, Interval::new(0, 6), true),
+        Insert(0, NEW_LINE, 6),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"123456"},{"insert":"\n\n","attributes":{"list":"bullet"}}]"#,
+        ),
+        Insert(0, "7", 7),
+        Insert(0, NEW_LINE, 2),
+        AssertDocJson(
+            0,
+            r#"[{"insert":"12"},{"insert":"\n","attributes":{"list":"bullet"}},{"insert":"3456"},{"insert":"\n","attributes":{"list":"bullet"}},{"insert":"7"},{"insert":"\n","attributes":{"list":"bullet"}}]"#,
+        ),
+    ];
+    TestBuilder::new().run_script::<FlowyDoc>(ops);
+}
+
+#[test]
+fn attributes_continue_ordered_list_on_newline() {
+    let delta = DeltaBuilder::new()
+        .insert("one")
+        .insert_with_attributes("\n", Attribute::Ordered(true).into())
+        .insert("two")
+        .insert_with_attributes("\n", Attribute::Ordered(true).into())
+        .insert("three")
+        .insert_with_attributes("\n", Attribute::Ordered(true).into())
+        .build();
+    let mut document = Document::from_delta(delta);
+    assert_eq!(list_ordinal_at(document.delta(), 13), Some(3));
+
+    document.insert(13, NEW_LINE).unwrap();
+    document.insert(14, "four").unwrap();
+    assert_eq!(
+        document.to_json(),
+        r#"[{"insert":"one"},{"insert":"\n","attributes":{"list":"ordered"}},{"insert":"two"},{"insert":"\n","attributes":{"list":"ordered"}},{"insert":"three"},{"insert":"\n","attributes":{"list":"ordered"}},{"insert":"four"},{"insert":"\n","attributes":{"list":"ordered"}}]"#
+    );
+    assert_eq!(list_ordinal_at(document.delta(), 14), Some(4));
+    assert_eq!(delta_to_markdown(document.delta()), "1. one\n2. two\n3. three\n4. four");
+}
+
+#[test]
+fn attributes_continue_list_keeps_block_attributes() {
+    let mut attributes = Attributes::new();
+    attributes.add(Attribute::Bullet(true));
+    attributes.add(Attribute::Indent(1));
+    attributes.add(Attribute::Align("center".to_owned()));
+    let delta = DeltaBuilder::new()
+        .insert("123456")
+        .insert_with_attributes("\n", attributes.clone())
+        .build();
+    let mut document = Document::from_delta(delta);
+    document.insert(3, NEW_LINE).unwrap();
+
+    let expected = DeltaBuilder::new()
+        .insert("123")
+        .insert_with_attributes("\n", attributes.clone())
+        .insert("456")
+        .insert_with_attributes("\n", attributes)
+        .build();
+    assert_eq!(document.delta(), &expected);
+}
+
+#[test]
+fn attributes_newline_without_list_not_continue_list() {
+    let ops = vec![
+        Insert(0, "123456", 0),
+        Insert(0, NEW_LINE, 3),
+        AssertDocJson(0, r#"[{"insert":"123\n456\n"}]"#),
+    ];
+    TestBuilder::new().run_script::<FlowyDoc>(ops);
+
+    let document = Document::from_delta(DeltaBuilder::new().insert("123\n").build());
+    assert_eq!(list_ordinal_at(document.delta(), 1), None);
+}
+
+#[test]
+fn attributes_toggle_bold_over_selection() {
+    let mut document = Document::from_delta(DeltaBuilder::new().insert("123456\n").build());
+    document.toggle_format(Interval::new(0, 3), Attribute::Bold(true)).unwrap();
+    assert_eq!(
+        document.to_json(),
+        r#"[{"insert":"123","attributes":{"bold":true}},{"insert":"456\n"}]"#
+    );
+
+    document.toggle_format(Interval::new(0, 3), Attribute::Bold(true)).unwrap();
+    assert_eq!(document.to_json(), r#"[{"insert":"123456\n"}]"#);
+}
+
+#[test]
+fn attributes_toggle_bold_over_partially_bold_selection() {
+    let mut document = Document::from_delta(DeltaBuilder::new().insert("123456\n").build());
+    document.format(Interval::new(2, 4), Attribute::Bold(true)).unwrap();
+    document.toggle_format(Interval::new(0, 6), Attribute::Bold(true)).unwrap();
+    assert_eq!(
+        document.to_json(),
+        r#"[{"insert":"123456","attributes":{"bold":true}},{"insert":"\n"}]"#
+    );
+}
+
+#[test]
+fn attributes_toggle_bold_across_lines() {
+    let mut document = Document::from_delta(DeltaBuilder::new().insert("123\n456\n").build());
+    document.toggle_format(Interval::new(0, 7), Attribute::Bold(true)).unwrap();
+    assert_eq!(
+        document.to_json(),
+        r#"[{"insert":"123","attributes":{"bold":true}},{"insert":"\n"},{"insert":"456","attributes":{"bold":true}},{"insert":"\n"}]"#
+    );
+
+    document.toggle_format(Interval::new(0, 7), Attribute::Bold(true)).unwrap();
+    assert_eq!(document.to_json(), r#"[{"insert":"123\n456\n"}]"#);
+}
+
+#[test]
+fn attributes_toggle_bold_with_collapsed_selection() {
+    let mut document = Document::from_delta(DeltaBuilder::new().insert("123456\n").build());
+    let delta = document.toggle_format(Interval::new(2, 2), Attribute::Bold(true)).unwrap();
+    assert!(delta.is_empty());
+    assert_eq!(document.to_json(), r#"[{"insert":"123456\n"}]"#);
+}
+
+#[test]
+fn attributes_header_shortcut_on_space() {
+    let ops = vec![
+        Insert(0, "#123", 0),
+        Insert(0, WHITESPACE, 1),
+        AssertDocJson(0, r#"[{"insert":"123"},{"insert":"\n","attributes":{"header":1}}]"#),
+    ];
+    TestBuilder::new().run_script::<FlowyDoc>(ops);
+
+    let ops = vec![
+        Insert(0, "123\n######", 0),
+        Insert(0, WHITESPACE, 10),
+        AssertDocJson(0, r#"[{"insert":"123\n"},{"insert":"\n","attributes":{"header":6}}]"#),
+    ];
+    TestBuilder::new().run_script::<FlowyDoc>(ops);
+}
+
+#[test]
+fn attributes_header_shortcut_rejected() {
+    let ops = vec![
+        Insert(0, "#text", 0),
+        Insert(0, WHITESPACE, 5),
+        AssertDocJson(0, r##"[{"insert":"#text \n"}]"##),
+    ];
+    TestBuilder::new().run_script::<FlowyDoc>(ops);
+
+    let ops = vec![
+        Insert(0, "12#", 0),
+        Insert(0, WHITESPACE, 3),
+        AssertDocJson(0, r##"[{"insert":"12# \n"}]"##),
+    ];
+    TestBuilder::new().run_script::<FlowyDoc>(ops);
+
+    let ops = vec![
+        Insert(0, "#######", 0),
+        Insert(0, WHITESPACE, 7),
+        AssertDocJson(0, "[{\"insert\":\"####### \\n\"}]"),
+    ];
+    TestBuilder::new().run_script::<FlowyDoc>(ops);
+}
+
+#[test]
+fn attributes_header_shortcut_on_header_line() {
+    let ops = vec![
+        Insert(0, "#123", 0),
+        Header(0, Interval::new(0, 4), 2),
+        Insert(0, WHITESPACE, 1),
+        AssertDocJson(0, r##"[{"insert":"# 123"},{"insert":"\n","attributes":{"header":2}}]"##),
+    ];
+    TestBuilder::new().run_script::<FlowyDoc>(ops);
+}
+
+#[test]
+fn attributes_diff_added() {
+    let bold: Attributes = Attribute::Bold(true).into();
+    let mut bold_italic = bold.clone();
+    bold_italic.add(Attribute::Italic(true));
+
+    let diff = bold.diff(&bold_italic);
+    let italic: Attributes = Attribute::Italic(true).into();
+    assert_eq!(diff, italic);
+    assert_eq!(bold.compose(&diff).unwrap(), bold_italic);
+}
+
+#[test]
+fn attributes_diff_removed() {
+    let bold: Attributes = Attribute::Bold(true).into();
+    let diff = bold.diff(&Attributes::new());
+    let mut removed_bold = bold;
+    removed_bold.mark_all_as_removed_except(None);
+    assert_eq!(diff, removed_bold);
+}
+
+#[test]
+fn attributes_diff_unchanged() {
+    let bold: Attributes = Attribute::Bold(true).into();
+    assert_eq!(bold.diff(&bold).is_empty(), true);
+    assert_eq!(Attributes::new().diff(&Attributes::new()).is_empty(), true);
+}
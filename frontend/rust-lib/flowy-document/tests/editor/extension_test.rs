@@ -0,0 +1,175 @@
+use flowy_document_infra::{
+    core::{Document, ExtConflict, ExtensionRegistry, InsertExt},
+    errors::ErrorCode,
+};
+use lib_ot::core::*;
+
+// Replaces the typed "*" with its replacement.
+struct ReplaceStarExt {
+    name: &'static str,
+    replacement: &'static str,
+}
+
+impl InsertExt for ReplaceStarExt {
+    fn ext_name(&self) -> &str { self.name }
+
+    fn trigger(&self) -> Option<&str> { Some("*") }
+
+    fn apply(&self, _delta: &Delta, replace_len: usize, text: &str, index: usize) -> Option<Delta> {
+        if text != "*" {
+            return None;
+        }
+        Some(
+            DeltaBuilder::new()
+                .retain(index)
+                .delete(replace_len)
+                .insert(self.replacement)
+                .build(),
+        )
+    }
+}
+
+fn conflicting_registry() -> ExtensionRegistry {
+    let mut registry = ExtensionRegistry::new();
+    registry.register(
+        Box::new(ReplaceStarExt {
+            name: "StarToDot",
+            replacement: "•",
+        }),
+        0,
+    );
+    registry.register(
+        Box::new(ReplaceStarExt {
+            name: "StarToArrow",
+            replacement: "→",
+        }),
+        0,
+    );
+    registry
+}
+
+#[test]
+fn extension_registry_conflict_test() {
+    let registry = conflicting_registry();
+    assert_eq!(
+        registry.conflicts(),
+        vec![ExtConflict {
+            trigger: "*".to_owned(),
+            ext_names: vec!["StarToDot".to_owned(), "StarToArrow".to_owned()],
+        }]
+    );
+
+    // Only warns on the conflict, the first registered one wins
+    assert_eq!(registry.validate().is_ok(), true);
+    let mut document = Document::from_delta_with_registry(Delta::new(), registry).unwrap();
+    document.insert(0, "*").unwrap();
+    assert_eq!(document.to_plain_string(), "•");
+}
+
+#[test]
+fn extension_registry_conflict_in_strict_mode_test() {
+    let registry = conflicting_registry().strict(true);
+    let error = registry.validate().unwrap_err();
+    assert_eq!(error.code, ErrorCode::ExtensionConflict);
+
+    let error = Document::from_delta_with_registry(Delta::new(), conflicting_registry().strict(true))
+        .err()
+        .unwrap();
+    assert_eq!(error.code, ErrorCode::ExtensionConflict);
+}
+
+#[test]
+fn extension_registry_resolve_order_test() {
+    let mut registry = conflicting_registry().strict(true);
+    registry.order("*", vec!["StarToArrow", "StarToDot"]);
+    assert_eq!(registry.conflicts().is_empty(), true);
+
+    let mut document = Document::from_delta_with_registry(Delta::new(), registry).unwrap();
+    document.insert(0, "*").unwrap();
+    assert_eq!(document.to_plain_string(), "→");
+
+    // The other inserts fall through to the built-in extensions
+    document.insert(1, "abc").unwrap();
+    assert_eq!(document.to_plain_string(), "→abc");
+}
+
+#[test]
+fn extension_registry_priority_test() {
+    let mut registry = ExtensionRegistry::new().strict(true);
+    registry.register(
+        Box::new(ReplaceStarExt {
+            name: "StarToDot",
+            replacement: "•",
+        }),
+        0,
+    );
+    registry.register(
+        Box::new(ReplaceStarExt {
+            name: "StarToArrow",
+            replacement: "→",
+        }),
+        10,
+    );
+    assert_eq!(registry.conflicts().is_empty(), true);
+
+    let delta = DeltaBuilder::new().insert("\n").build();
+    assert_eq!(
+        registry.apply_insert(&delta, 0, "*", 0).unwrap(),
+        DeltaBuilder::new().insert("→").build()
+    );
+
+    // The inserts not applied by the registered extensions fall through to the
+    // built-in extensions
+    assert_eq!(
+        registry.apply_insert(&delta, 0, "abc", 0).unwrap(),
+        DeltaBuilder::new().insert("abc").build()
+    );
+
+    let mut document = Document::from_delta_with_registry(Delta::new(), registry).unwrap();
+    document.insert(0, "*").unwrap();
+    assert_eq!(document.to_plain_string(), "→");
+}
+
+// Bolds the text before the typed whitespace, leaving the whitespace to the
+// other extensions.
+struct BoldBeforeWhitespaceExt {}
+
+impl InsertExt for BoldBeforeWhitespaceExt {
+    fn ext_name(&self) -> &str { "BoldBeforeWhitespace" }
+
+    fn is_format_only(&self) -> bool { true }
+
+    fn apply(&self, _delta: &Delta, _replace_len: usize, text: &str, index: usize) -> Option<Delta> {
+        if text != WHITESPACE {
+            return None;
+        }
+        Some(
+            DeltaBuilder::new()
+                .retain_with_attributes(index, Attribute::Bold(true).into())
+                .build(),
+        )
+    }
+}
+
+#[test]
+fn extension_registry_format_only_test() {
+    let mut registry = ExtensionRegistry::new();
+    registry.register(Box::new(BoldBeforeWhitespaceExt {}), 0);
+
+    let delta = DeltaBuilder::new().insert("\n").build();
+    let mut document = Document::from_delta_with_registry(delta, registry).unwrap();
+    document.insert(0, "abc").unwrap();
+    document.insert(3, WHITESPACE).unwrap();
+    assert_eq!(
+        document.to_json(),
+        r#"[{"insert":"abc","attributes":{"bold":true}},{"insert":" \n"}]"#
+    );
+
+    // Composed with the built-in link format too
+    document.insert(4, "https://appflowy.io").unwrap();
+    document.insert(23, WHITESPACE).unwrap();
+    assert_eq!(
+        document.to_json(),
+        r#"[{"insert":"abc ","attributes":{"bold":true}},{"insert":"https://appflowy.io","attributes":{"bold":true,"link":"https://appflowy.io/"}},{"insert":" \n"}]"#
+    );
+}
@@ -0,0 +1,288 @@
+use flowy_document_infra::core::{delta_to_html, delta_to_standalone_html, diff_to_html, html_to_delta};
+use lib_ot::core::*;
+
+fn assert_round_trip(html: &str) {
+    let delta = html_to_delta(html).unwrap();
+    assert_eq!(delta_to_html(&delta), html);
+}
+
+#[test]
+fn html_to_delta_paragraph_test() {
+    let delta = html_to_delta("<p>Hello <strong>world</strong></p>").unwrap();
+    let expected = DeltaBuilder::new()
+        .insert("Hello ")
+        .insert_with_attributes("world", Attribute::Bold(true).into())
+        .insert("\n")
+        .build();
+    assert_eq!(delta, expected);
+}
+
+#[test]
+fn html_to_delta_header_test() {
+    let delta = html_to_delta("<h1>Title</h1><p>body</p>").unwrap();
+    assert_eq!(
+        delta.to_json(),
+        r#"[{"insert":"Title"},{"insert":"\n","attributes":{"header":1}},{"insert":"body\n"}]"#
+    );
+}
+
+#[test]
+fn html_to_delta_list_test() {
+    let delta = html_to_delta("<ul><li>a</li><li>b</li></ul><ol><li>c</li></ol>").unwrap();
+    let expected = DeltaBuilder::new()
+        .insert("a")
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .insert("b")
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .insert("c")
+        .insert_with_attributes("\n", Attribute::Ordered(true).into())
+        .build();
+    assert_eq!(delta, expected);
+}
+
+#[test]
+fn html_to_delta_link_and_code_test() {
+    let delta = html_to_delta(r#"<p>see <a href="https://appflowy.io">docs</a> or <code>cargo</code></p>"#).unwrap();
+    let expected = DeltaBuilder::new()
+        .insert("see ")
+        .insert_with_attributes("docs", Attribute::Link("https://appflowy.io").into())
+        .insert(" or ")
+        .insert_with_attributes("cargo", Attribute::InlineCode(true).into())
+        .insert("\n")
+        .build();
+    assert_eq!(delta, expected);
+}
+
+#[test]
+fn html_to_delta_non_ascii_test() {
+    let delta = html_to_delta("<p>日本語</p><p>😀 <em>é</em></p>").unwrap();
+    let expected = DeltaBuilder::new()
+        .insert("日本語\n😀 ")
+        .insert_with_attributes("é", Attribute::Italic(true).into())
+        .insert("\n")
+        .build();
+    assert_eq!(delta, expected);
+}
+
+#[test]
+fn html_to_delta_quoted_attribute_test() {
+    let delta = html_to_delta(r#"<p><a title="a > b" href="https://appflowy.io">docs</a></p>"#).unwrap();
+    let expected = DeltaBuilder::new()
+        .insert_with_attributes("docs", Attribute::Link("https://appflowy.io").into())
+        .insert("\n")
+        .build();
+    assert_eq!(delta, expected);
+}
+
+#[test]
+fn html_to_delta_unsafe_link_test() {
+    let delta =
+        html_to_delta(r#"<p><a href="javascript:alert(1)">click</a> <a href="mailto:a@b.io">mail</a></p>"#).unwrap();
+    let expected = DeltaBuilder::new()
+        .insert("click ")
+        .insert_with_attributes("mail", Attribute::Link("mailto:a@b.io").into())
+        .insert("\n")
+        .build();
+    assert_eq!(delta, expected);
+}
+
+#[test]
+fn html_to_delta_code_block_test() {
+    let delta = html_to_delta("<pre><code>fn main() {\n    1 &lt; 2\n}</code></pre>").unwrap();
+    let expected = DeltaBuilder::new()
+        .insert("fn main() {")
+        .insert_with_attributes("\n", Attribute::CodeBlock(true).into())
+        .insert("    1 < 2")
+        .insert_with_attributes("\n", Attribute::CodeBlock(true).into())
+        .insert("}")
+        .insert_with_attributes("\n", Attribute::CodeBlock(true).into())
+        .build();
+    assert_eq!(delta, expected);
+}
+
+#[test]
+fn html_to_delta_unknown_tag_test() {
+    let html = r#"<!-- copied --><div class="note"><span style="color:red">Hello</span>
+        <font>  world</font></div><script>alert(1)</script>"#;
+    let delta = html_to_delta(html).unwrap();
+    assert_eq!(delta.to_json(), r#"[{"insert":"Hello world\n"}]"#);
+}
+
+#[test]
+fn html_to_delta_line_break_test() {
+    let delta = html_to_delta("<p>a<br>b</p><p><br></p>").unwrap();
+    assert_eq!(delta.to_json(), r#"[{"insert":"a\nb\n\n"}]"#);
+}
+
+#[test]
+fn html_to_delta_round_trip_test() {
+    assert_round_trip("<h2>Notes</h2><p>Hello <strong>bold</strong> and <em>italic</em></p>");
+    assert_round_trip("<ul><li>one</li><li><a href=\"https://appflowy.io\">two</a></li></ul><ol><li>three</li></ol>");
+    assert_round_trip("<pre><code>let a = 1;\nlet b = &amp;a;</code></pre><p><br></p>");
+    assert_round_trip("<blockquote>quoted</blockquote><p><s>gone</s> <u>under</u></p>");
+}
+
+#[test]
+fn delta_to_standalone_html_test() {
+    let delta = html_to_delta(
+        "<h1>Title</h1><p>Hello <strong>bold</strong> &amp; <a href=\"https://appflowy.io\">link</a></p>",
+    )
+    .unwrap();
+    let html = delta_to_standalone_html(&delta, "<Notes> & more");
+    assert!(html.starts_with("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n"));
+    assert!(html.contains("<title>&lt;Notes&gt; &amp; more</title>"));
+    assert!(html.trim_end().ends_with("</body>\n</html>"));
+    assert!(html.contains(r#"<h1 style="margin: 0 0 8px 0; font-size: 2em; font-weight: bold;">Title</h1>"#));
+    assert!(html.contains(r#"<strong style="font-weight: bold;">bold</strong> &amp; "#));
+    assert!(
+        html.contains(r#"<a style="color: #00b5ff; text-decoration: underline;" href="https://appflowy.io">link</a>"#)
+    );
+    assert_eq!(html.contains("class="), false);
+
+    // every opened tag is closed
+    for tag in &["html", "head", "title", "body", "h1", "p", "strong", "a"] {
+        let opened = html.matches(&format!("<{}>", tag)).count() + html.matches(&format!("<{} ", tag)).count();
+        assert_eq!(
+            opened,
+            html.matches(&format!("</{}>", tag)).count(),
+            "unbalanced <{}>",
+            tag
+        );
+    }
+}
+
+#[test]
+fn delta_to_standalone_html_escape_test() {
+    let delta = DeltaBuilder::new().insert("<script>alert(1)</script>\n").build();
+    let html = delta_to_standalone_html(&delta, "title");
+    assert_eq!(html.contains("<script>"), false);
+    assert!(html.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+}
+
+#[test]
+fn delta_to_html_footnote_test() {
+    let delta = DeltaBuilder::new()
+        .insert("Rust")
+        .insert_footnote(&Footnote::new("rust", "A systems language."))
+        .insert(" and Dart")
+        .insert_footnote(&Footnote::new("dart", "Used by <Flutter>."))
+        .insert("\n")
+        .build();
+    assert_eq!(
+        delta_to_html(&delta),
+        concat!(
+            r##"<p>Rust<sup><a href="#fn-rust" id="fnref-rust">1</a></sup> and Dart"##,
+            r##"<sup><a href="#fn-dart" id="fnref-dart">2</a></sup></p>"##,
+            r##"<section class="footnotes"><ol>"##,
+            r##"<li id="fn-rust">A systems language. <a href="#fnref-rust">&#8617;</a></li>"##,
+            r##"<li id="fn-dart">Used by &lt;Flutter&gt;. <a href="#fnref-dart">&#8617;</a></li>"##,
+            r##"</ol></section>"##
+        )
+    );
+}
+
+#[test]
+fn diff_to_html_test() {
+    let from = DeltaBuilder::new().insert("Hello world\n").build();
+    let revision = DeltaBuilder::new()
+        .retain(6)
+        .delete(5)
+        .insert("there")
+        .retain(1)
+        .build();
+    let to = from.compose(&revision).unwrap();
+    assert_eq!(
+        diff_to_html(&from, &to),
+        "<p>Hello <del>wo</del><ins>the</ins>r<del>ld</del><ins>e</ins></p>"
+    );
+    assert_eq!(diff_to_html(&to, &to), delta_to_html(&to));
+}
+
+#[test]
+fn diff_to_html_with_lines_test() {
+    let from = DeltaBuilder::new().insert("abc\n123\n").build();
+    let to = DeltaBuilder::new().insert("abc\nxyz\n123\n").build();
+    assert_eq!(diff_to_html(&from, &to), "<p>abc</p><p><ins>xyz</ins></p><p>123</p>");
+    assert_eq!(diff_to_html(&to, &from), "<p>abc</p><p><del>xyz</del></p><p>123</p>");
+}
+
+#[test]
+fn diff_to_html_format_only_test() {
+    let from = DeltaBuilder::new().insert("Hello world\n").build();
+    let revision = DeltaBuilder::new()
+        .retain_with_attributes(5, Attribute::Bold(true).into())
+        .retain(6)
+        .retain_with_attributes(1, Attribute::Header(1).into())
+        .build();
+    let to = from.compose(&revision).unwrap();
+    assert_eq!(
+        diff_to_html(&from, &to),
+        "<h1><span class=\"format-changed\"><span class=\"format-changed\"><strong>Hello</strong></span> world</span></h1>"
+    );
+}
+
+#[test]
+fn delta_to_html_overlapping_inline_test() {
+    let mut bold_italic = Attributes::new();
+    bold_italic.add(Attribute::Bold(true));
+    bold_italic.add(Attribute::Italic(true));
+    let delta = DeltaBuilder::new()
+        .insert_with_attributes("a", Attribute::Bold(true).into())
+        .insert_with_attributes("b", bold_italic)
+        .insert_with_attributes("c", Attribute::Italic(true).into())
+        .insert("\n")
+        .build();
+    assert_eq!(
+        delta_to_html(&delta),
+        "<p><strong>a</strong><strong><em>b</em></strong><em>c</em></p>"
+    );
+}
+
+#[test]
+fn delta_to_html_header_and_list_test() {
+    let delta = DeltaBuilder::new()
+        .insert("Title")
+        .insert_with_attributes("\n", Attribute::Header(2).into())
+        .insert("one ")
+        .insert_with_attributes("bold", Attribute::Bold(true).into())
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .insert_with_attributes("two", Attribute::Link("https://appflowy.io").into())
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .insert("three")
+        .insert_with_attributes("\n", Attribute::Ordered(true).into())
+        .insert("end\n")
+        .build();
+    assert_eq!(
+        delta_to_html(&delta),
+        concat!(
+            "<h2>Title</h2>",
+            "<ul><li>one <strong>bold</strong></li><li><a href=\"https://appflowy.io\">two</a></li></ul>",
+            "<ol><li>three</li></ol>",
+            "<p>end</p>"
+        )
+    );
+}
+
+#[test]
+fn delta_to_html_escape_test() {
+    let delta = DeltaBuilder::new()
+        .insert("1 < 2 & \"3\" > 0")
+        .insert_with_attributes("x", Attribute::Link("https://a.com/?a=1&b=\"2\"").into())
+        .insert("\n")
+        .build();
+    assert_eq!(
+        delta_to_html(&delta),
+        "<p>1 &lt; 2 &amp; &quot;3&quot; &gt; 0<a href=\"https://a.com/?a=1&amp;b=&quot;2&quot;\">x</a></p>"
+    );
+}
+
+#[test]
+fn delta_to_html_unsafe_link_test() {
+    let delta = DeltaBuilder::new()
+        .insert_with_attributes("click", Attribute::Link("JavaScript:alert(1)").into())
+        .insert(" ")
+        .insert_with_attributes("top", Attribute::Link("#top").into())
+        .insert("\n")
+        .build();
+    assert_eq!(delta_to_html(&delta), "<p>click <a href=\"#top\">top</a></p>");
+}
@@ -0,0 +1,195 @@
+use flowy_document_infra::core::{delta_to_markdown, markdown_to_delta};
+use lib_ot::core::*;
+
+#[test]
+fn delta_to_markdown_block_test() {
+    let delta = DeltaBuilder::new()
+        .insert("Title")
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .insert("a")
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .insert("b")
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .insert("one")
+        .insert_with_attributes("\n", Attribute::Ordered(true).into())
+        .insert("two")
+        .insert_with_attributes("\n", Attribute::Ordered(true).into())
+        .insert("done")
+        .insert_with_attributes("\n", Attribute::Checked(true).into())
+        .insert("quote")
+        .insert_with_attributes("\n", Attribute::BlockQuote(true).into())
+        .insert("let a_b = 1;")
+        .insert_with_attributes("\n", Attribute::CodeBlock(true).into())
+        .insert("a_b + 1")
+        .insert_with_attributes("\n", Attribute::CodeBlock(true).into())
+        .insert("end\n")
+        .build();
+    assert_eq!(
+        delta_to_markdown(&delta),
+        "# Title\n\n- a\n- b\n1. one\n2. two\n- [x] done\n\n> quote\n\n```\nlet a_b = 1;\na_b + 1\n```\n\nend"
+    );
+}
+
+#[test]
+fn delta_to_markdown_inline_test() {
+    let delta = DeltaBuilder::new()
+        .insert_with_attributes("bold", Attribute::Bold(true).into())
+        .insert(" ")
+        .insert_with_attributes("italic", Attribute::Italic(true).into())
+        .insert(" ")
+        .insert_with_attributes("cargo", Attribute::InlineCode(true).into())
+        .insert(" ")
+        .insert_with_attributes("docs", Attribute::Link("https://appflowy.io").into())
+        .insert(" 2*3\n")
+        .build();
+    assert_eq!(
+        delta_to_markdown(&delta),
+        "**bold** _italic_ `cargo` [docs](https://appflowy.io) 2\\*3"
+    );
+}
+
+#[test]
+fn delta_to_markdown_footnote_test() {
+    let delta = DeltaBuilder::new()
+        .insert("Rust")
+        .insert_footnote(&Footnote::new("rust", "A systems language."))
+        .insert(" and Dart")
+        .insert_footnote(&Footnote::new("dart", "Used by Flutter."))
+        .insert("\nRust again")
+        .insert_footnote(&Footnote::new("rust", "A systems language."))
+        .insert("\n")
+        .build();
+    assert_eq!(
+        delta_to_markdown(&delta),
+        "Rust[^rust] and Dart[^dart]\n\nRust again[^rust]\n\n[^rust]: A systems language.\n[^dart]: Used by Flutter."
+    );
+    assert_eq!(Delta::from_json(&delta.to_json()).unwrap(), delta);
+}
+
+#[test]
+fn footnote_survives_transform_test() {
+    let footnote = Footnote::new("1", "note");
+    let doc = DeltaBuilder::new()
+        .insert("ab")
+        .insert_footnote(&footnote)
+        .insert("\n")
+        .build();
+    assert_eq!(doc.target_len, 4);
+
+    // insert before the footnote concurrently with formatting it and the text
+    // around.
+    let a = DeltaBuilder::new().retain(1).insert("x").retain(3).build();
+    let b = DeltaBuilder::new()
+        .retain(1)
+        .retain_with_attributes(2, Attribute::Bold(true).into())
+        .retain(1)
+        .build();
+    let (a_prime, b_prime) = a.transform(&b).unwrap();
+    let doc_a = doc.compose(&a).unwrap().compose(&b_prime).unwrap();
+    let doc_b = doc.compose(&b).unwrap().compose(&a_prime).unwrap();
+    assert_eq!(doc_a, doc_b);
+
+    let embeds = doc_a
+        .ops
+        .iter()
+        .filter_map(|op| match op {
+            Operation::Insert(insert) => Footnote::from_attributes(&insert.attributes).map(|f| (insert.s.clone(), f)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(embeds.len(), 1);
+    assert_eq!(embeds[0].0.as_str(), EMBED);
+    assert_eq!(embeds[0].1, footnote);
+}
+
+#[test]
+fn markdown_to_delta_block_test() {
+    let delta = markdown_to_delta(
+        "# Title\n\n- a\n- b\n1. one\n2. two\n- [x] done\n\n> quote\n\n```\nlet a_b = 1;\na_b + 1\n```\n\nsome\ntext",
+    )
+    .unwrap();
+    let expected = DeltaBuilder::new()
+        .insert("Title")
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .insert("a")
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .insert("b")
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .insert("one")
+        .insert_with_attributes("\n", Attribute::Ordered(true).into())
+        .insert("two")
+        .insert_with_attributes("\n", Attribute::Ordered(true).into())
+        .insert("done")
+        .insert_with_attributes("\n", Attribute::Checked(true).into())
+        .insert("quote")
+        .insert_with_attributes("\n", Attribute::BlockQuote(true).into())
+        .insert("let a_b = 1;")
+        .insert_with_attributes("\n", Attribute::CodeBlock(true).into())
+        .insert("a_b + 1")
+        .insert_with_attributes("\n", Attribute::CodeBlock(true).into())
+        .insert("some text\n")
+        .build();
+    assert_eq!(delta, expected);
+}
+
+#[test]
+fn markdown_to_delta_inline_test() {
+    let delta = markdown_to_delta(
+        "**bold** _italic_ `a*b` [docs](https://appflowy.io) 2\\*3 **[😀 bold link](https://a.com)**",
+    )
+    .unwrap();
+    let mut bold_link = Attributes::new();
+    bold_link.add(Attribute::Bold(true));
+    bold_link.add(Attribute::Link("https://a.com"));
+    let expected = DeltaBuilder::new()
+        .insert_with_attributes("bold", Attribute::Bold(true).into())
+        .insert(" ")
+        .insert_with_attributes("italic", Attribute::Italic(true).into())
+        .insert(" ")
+        .insert_with_attributes("a*b", Attribute::InlineCode(true).into())
+        .insert(" ")
+        .insert_with_attributes("docs", Attribute::Link("https://appflowy.io").into())
+        .insert(" 2*3 ")
+        .insert_with_attributes("😀 bold link", bold_link)
+        .insert("\n")
+        .build();
+    assert_eq!(delta, expected);
+    // The emoji takes two UTF-16 code units
+    assert_eq!(delta.target_len, 38);
+}
+
+#[test]
+fn markdown_to_delta_unclosed_delimiter_test() {
+    let delta = markdown_to_delta("**not bold and [not](a link\n#not header").unwrap();
+    assert_eq!(
+        delta.to_json(),
+        r##"[{"insert":"**not bold and [not](a link #not header\n"}]"##
+    );
+    assert_eq!(markdown_to_delta("").unwrap().to_json(), r#"[{"insert":"\n"}]"#);
+}
+
+#[test]
+fn markdown_to_delta_non_flanking_delimiter_test() {
+    let delta = markdown_to_delta("snake_case_name here, 2 * 3 * 4 and a * b*").unwrap();
+    assert_eq!(
+        delta.to_json(),
+        r#"[{"insert":"snake_case_name here, 2 * 3 * 4 and a * b*\n"}]"#
+    );
+
+    let delta = markdown_to_delta("*a * b* and _c_d_").unwrap();
+    let expected = DeltaBuilder::new()
+        .insert_with_attributes("a * b", Attribute::Italic(true).into())
+        .insert(" and ")
+        .insert_with_attributes("c_d", Attribute::Italic(true).into())
+        .insert("\n")
+        .build();
+    assert_eq!(delta, expected);
+}
+
+#[test]
+fn markdown_round_trip_test() {
+    let markdown = "## Notes\n\n**bold** and _italic_ [docs](https://appflowy.io) 2\\*3\n\n- a\n- b\n1. one\n2. two\n\n> quote\n\n```\nlet a_b = 1;\n```\n\nend";
+    let delta = markdown_to_delta(markdown).unwrap();
+    assert_eq!(delta_to_markdown(&delta), markdown);
+    assert_eq!(markdown_to_delta(&delta_to_markdown(&delta)).unwrap(), delta);
+}
@@ -1,7 +1,15 @@
 #![allow(clippy::module_inception)]
 mod attribute_test;
+mod extension_test;
+mod html_test;
+mod markdown_test;
 mod op_test;
+mod plain_test;
+mod revision_test;
 mod serde_test;
+mod stats_test;
+mod summary_test;
+mod transform_test;
 mod undo_redo_test;
 
 use derive_more::Display;
@@ -292,6 +300,30 @@ impl Rng {
             .collect()
     }
 
+    // Picks from a few chars only, ASCII, CJK and outside the BMP, so that the
+    // strings share a lot of text.
+    pub fn gen_unicode_string(&mut self, len: usize) -> String {
+        let chars = ['a', 'b', '\n', '日', '本', '語', '😀', '👋'];
+        (0..len).map(|_| chars[self.0.gen_range(0, chars.len())]).collect()
+    }
+
+    // Randomly deletes, replaces and inserts chars of `s`.
+    pub fn gen_unicode_edit(&mut self, s: &str) -> String {
+        let mut edited = String::new();
+        for c in s.chars() {
+            match self.0.gen_range(0.0, 1.0) {
+                f if f < 0.1 => {},
+                f if f < 0.2 => edited.push_str(&self.gen_unicode_string(1)),
+                f if f < 0.3 => {
+                    edited.push(c);
+                    edited.push_str(&self.gen_unicode_string(3));
+                },
+                _ => edited.push(c),
+            }
+        }
+        edited
+    }
+
     pub fn gen_delta(&mut self, s: &str) -> Delta {
         let mut delta = Delta::default();
         loop {
@@ -321,4 +353,21 @@ impl Rng {
         }
         delta
     }
+
+    // Generates an operation applicable at the start of a text of `len` characters.
+    pub fn gen_operation(&mut self, len: usize) -> Operation {
+        let n = self.0.gen_range(0, len + 1);
+        let attributes = match self.0.gen_range(0.0, 1.0) {
+            f if f < 0.3 => Attribute::Bold(true).into(),
+            f if f < 0.5 => Attribute::Italic(true).into(),
+            _ => Attributes::default(),
+        };
+        match self.0.gen_range(0.0, 1.0) {
+            f if f < 0.4 => OpBuilder::insert(&self.gen_string(1 + n))
+                .attributes(attributes)
+                .build(),
+            f if f < 0.7 => OpBuilder::delete(n).build(),
+            _ => OpBuilder::retain(n).attributes(attributes).build(),
+        }
+    }
 }
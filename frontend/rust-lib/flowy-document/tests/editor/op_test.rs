@@ -1,6 +1,6 @@
 #![allow(clippy::all)]
 use crate::editor::{Rng, TestBuilder, TestOp::*};
-use flowy_document_infra::core::{FlowyDoc, PlainDoc};
+use flowy_document_infra::core::{delta_to_html, FlowyDoc, PlainDoc};
 use lib_ot::core::*;
 
 #[test]
@@ -246,6 +246,42 @@ fn delta_seek_5() {
     );
 }
 
+#[test]
+fn delta_peek_then_next() {
+    let mut delta = Delta::default();
+    delta.add(OpBuilder::insert("12345").build());
+    delta.add(OpBuilder::insert("67").attributes(Attribute::Bold(true).into()).build());
+
+    let mut iter = DeltaIter::new(&delta);
+    let peeked = iter.peek();
+    assert_eq!(peeked, Some(OpBuilder::insert("12345").build()));
+    assert_eq!(iter.next_op(), peeked);
+    assert_eq!(iter.peek(), iter.peek());
+    assert_eq!(iter.peek(), iter.next_op());
+    assert_eq!(iter.peek(), None);
+    assert_eq!(iter.next_op(), None);
+
+    let mut iter = DeltaIter::from_interval(&delta, Interval::new(1, 3));
+    let peeked = iter.peek();
+    assert_eq!(peeked, Some(OpBuilder::insert("23").build()));
+    assert_eq!(iter.next_op(), peeked);
+}
+
+#[test]
+fn delta_seek_into_the_middle_of_insert() {
+    let mut delta = Delta::default();
+    delta.add(OpBuilder::insert("12345").build());
+    delta.add(OpBuilder::retain(3).build());
+
+    let mut iter = DeltaIter::from_offset(&delta, 2);
+    iter.seek::<CharMetric>(1);
+    assert_eq!(iter.peek(), Some(OpBuilder::insert("45").build()));
+    assert_eq!(iter.next_op(), Some(OpBuilder::insert("45").build()));
+
+    iter.seek::<CharMetric>(1);
+    assert_eq!(iter.next_op(), Some(OpBuilder::retain(2).build()));
+}
+
 #[test]
 fn delta_next_op_len_test() {
     let mut delta = Delta::default();
@@ -310,6 +346,367 @@ fn lengths() {
     assert_eq!(delta.base_len, 9);
     assert_eq!(delta.target_len, 10);
 }
+
+#[test]
+fn delta_retain_merges_exact_attributes_only() {
+    let mut delta = Delta::default();
+    delta.retain(2, AttributeBuilder::new().add_attr(Attribute::Bold(true)).build());
+    delta.retain(3, AttributeBuilder::new().add_attr(Attribute::Bold(true)).build());
+    delta.retain(1, Attributes::default());
+    assert_eq!(
+        delta,
+        DeltaBuilder::new()
+            .retain_with_attributes(5, Attribute::Bold(true).into())
+            .retain(1)
+            .build()
+    );
+
+    // Merging the bold retain into the bold and italic one would italicize "01"
+    let delta = Delta::from_json(
+        r#"[{"retain":2,"attributes":{"bold":true}},{"retain":3,"attributes":{"bold":true,"italic":true}},{"retain":5}]"#,
+    )
+    .unwrap();
+    assert_eq!(delta.ops.len(), 3);
+    let doc = DeltaBuilder::new().insert("0123456789").build().compose(&delta).unwrap();
+    assert_eq!(
+        doc.to_json(),
+        r#"[{"insert":"01","attributes":{"bold":true}},{"insert":"234","attributes":{"bold":true,"italic":true}},{"insert":"56789"}]"#
+    );
+}
+
+#[test]
+fn delta_map_attributes_rename_key() {
+    // the old documents format with bold, the new ones with italic
+    let delta = DeltaBuilder::new()
+        .insert_with_attributes("Hello", Attribute::Bold(true).into())
+        .insert(" ")
+        .insert_with_attributes(
+            "world",
+            AttributeBuilder::new()
+                .add_attr(Attribute::Bold(true))
+                .add_attr(Attribute::Underline(true))
+                .build(),
+        )
+        .insert("\n")
+        .build();
+    let mapped = delta.map_attributes(|key, value| match key {
+        AttributeKey::Bold => Some((AttributeKey::Italic, value.clone())),
+        _ => Some((key.clone(), value.clone())),
+    });
+    let expected = DeltaBuilder::new()
+        .insert_with_attributes("Hello", Attribute::Italic(true).into())
+        .insert(" ")
+        .insert_with_attributes(
+            "world",
+            AttributeBuilder::new()
+                .add_attr(Attribute::Italic(true))
+                .add_attr(Attribute::Underline(true))
+                .build(),
+        )
+        .insert("\n")
+        .build();
+    assert_eq!(mapped, expected);
+    assert_eq!(delta_to_html(&mapped), delta_to_html(&expected));
+    assert_eq!(mapped.target_len, delta.target_len);
+}
+
+#[test]
+fn delta_map_attributes_drop_key() {
+    let delta = DeltaBuilder::new()
+        .insert_with_attributes("ab", Attribute::Bold(true).into())
+        .insert_with_attributes(
+            "cd",
+            AttributeBuilder::new()
+                .add_attr(Attribute::Bold(true))
+                .add_attr(Attribute::Underline(true))
+                .build(),
+        )
+        .retain_with_attributes(2, Attribute::Underline(true).into())
+        .delete(1)
+        .build();
+    let mapped = delta.map_attributes(|key, value| match key {
+        AttributeKey::Underline => None,
+        _ => Some((key.clone(), value.clone())),
+    });
+    // the inserts have the same attributes after dropping underline, so are merged
+    let expected = DeltaBuilder::new()
+        .insert_with_attributes("abcd", Attribute::Bold(true).into())
+        .retain(2)
+        .delete(1)
+        .build();
+    assert_eq!(mapped, expected);
+}
+
+#[test]
+fn delta_map_attributes_of_delete() {
+    let mut delta = Delta::new();
+    delta.retain(2, Attributes::default());
+    delta.add(OpBuilder::delete(1).attributes(Attribute::Header(1).into()).build());
+    let mapped = delta.map_attributes(|key, _| match key {
+        AttributeKey::Header => Some((key.clone(), Attribute::Header(2).value)),
+        _ => None,
+    });
+    assert_eq!(
+        mapped.ops[1],
+        OpBuilder::delete(1).attributes(Attribute::Header(2).into()).build()
+    );
+    assert!(delta.text_structure_eq(&mapped));
+}
+
+#[test]
+fn delta_portable_round_trip() {
+    let delta = DeltaBuilder::new()
+        .insert_with_attributes("Hello", Attribute::Bold(true).into())
+        .insert(" world")
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .build();
+    let bytes = delta.export_portable();
+    let portable = String::from_utf8(bytes.clone()).unwrap();
+    assert!(portable.starts_with(&format!(r#"{{"version":{},"#, PORTABLE_SCHEMA_VERSION)));
+    assert!(portable.contains(r#""attribute_keys":["bold","header"]"#));
+    assert_eq!(Delta::import_portable(&bytes).unwrap(), delta);
+
+    let tampered = portable.replace("world", "World");
+    assert!(Delta::import_portable(tampered.as_bytes()).is_err());
+
+    let newer = portable.replacen(
+        &format!(r#""version":{}"#, PORTABLE_SCHEMA_VERSION),
+        &format!(r#""version":{}"#, PORTABLE_SCHEMA_VERSION + 1),
+        1,
+    );
+    assert!(Delta::import_portable(newer.as_bytes()).is_err());
+}
+
+#[test]
+fn delta_portable_import_older_version() {
+    // the documents exported before version 1 used bold where italic is used now
+    let migrations = vec![PortableMigration {
+        version: 1,
+        map: |key, value| match key {
+            AttributeKey::Bold => Some((AttributeKey::Italic, value.clone())),
+            _ => Some((key.clone(), value.clone())),
+        },
+    }];
+    let delta = DeltaBuilder::new()
+        .insert_with_attributes("Hello", Attribute::Bold(true).into())
+        .insert(" world\n")
+        .build();
+    let current = delta.export_portable();
+    let older = String::from_utf8(current.clone())
+        .unwrap()
+        .replacen(r#""version":1"#, r#""version":0"#, 1);
+
+    let expected = DeltaBuilder::new()
+        .insert_with_attributes("Hello", Attribute::Italic(true).into())
+        .insert(" world\n")
+        .build();
+    assert_eq!(
+        Delta::import_portable_with_migrations(older.as_bytes(), &migrations).unwrap(),
+        expected
+    );
+    // the delta exported with the current version skips the migrations
+    assert_eq!(
+        Delta::import_portable_with_migrations(&current, &migrations).unwrap(),
+        delta
+    );
+}
+
+#[test]
+fn delta_op_lengths() {
+    let delta = Delta::from_json(
+        r#"[{"retain":3},{"insert":"abc","attributes":{"bold":true}},{"delete":2},{"retain":1,"attributes":{"italic":true}},{"insert":"😁"},{"delete":1}]"#,
+    )
+    .unwrap();
+    assert_eq!(delta.retain_len(), 4);
+    // the emoji takes two UTF-16 code units
+    assert_eq!(delta.insert_len(), 5);
+    assert_eq!(delta.delete_len(), 3);
+
+    let delta = Delta::default();
+    assert_eq!(delta.retain_len(), 0);
+    assert_eq!(delta.insert_len(), 0);
+    assert_eq!(delta.delete_len(), 0);
+}
+#[test]
+fn delta_merge_inserts_with_attributes_in_different_order() {
+    let bold_italic = AttributeBuilder::new()
+        .add_attr(Attribute::Bold(true))
+        .add_attr(Attribute::Italic(true))
+        .build();
+    let italic_bold = AttributeBuilder::new()
+        .add_attr(Attribute::Italic(true))
+        .add_attr(Attribute::Bold(true))
+        .build();
+    assert_eq!(
+        serde_json::to_string(&bold_italic).unwrap(),
+        serde_json::to_string(&italic_bold).unwrap()
+    );
+
+    let mut delta = Delta::default();
+    delta.insert("123", bold_italic.clone());
+    delta.insert("456", italic_bold);
+    assert_eq!(
+        delta.ops,
+        vec![OpBuilder::insert("123456").attributes(bold_italic).build()]
+    );
+}
+
+#[test]
+fn operation_split_retain_keep_attributes() {
+    let attributes = AttributeBuilder::new()
+        .add_attr(Attribute::Bold(true))
+        .add_attr(Attribute::Italic(true))
+        .build();
+    let retain = OpBuilder::retain(5).attributes(attributes.clone()).build();
+    let (left, right) = retain.split(2);
+    assert_eq!(left, Some(OpBuilder::retain(2).attributes(attributes.clone()).build()));
+    assert_eq!(right, Some(OpBuilder::retain(3).attributes(attributes).build()));
+
+    let (left, right) = OpBuilder::retain(5).build().split(1);
+    assert_eq!(left, Some(OpBuilder::retain(1).build()));
+    assert_eq!(right, Some(OpBuilder::retain(4).build()));
+}
+
+#[test]
+fn operation_split_delete_and_insert() {
+    let (left, right) = OpBuilder::delete(5).build().split(2);
+    assert_eq!(left, Some(OpBuilder::delete(2).build()));
+    assert_eq!(right, Some(OpBuilder::delete(3).build()));
+
+    let attributes: Attributes = Attribute::Bold(true).into();
+    let insert = OpBuilder::insert("12345").attributes(attributes.clone()).build();
+    let (left, right) = insert.split(2);
+    assert_eq!(
+        left,
+        Some(OpBuilder::insert("12").attributes(attributes.clone()).build())
+    );
+    assert_eq!(right, Some(OpBuilder::insert("345").attributes(attributes).build()));
+}
+
+#[test]
+fn operation_split_non_ascii_insert() {
+    let (left, right) = OpBuilder::insert("你好world").build().split(2);
+    assert_eq!(left, Some(OpBuilder::insert("你好").build()));
+    assert_eq!(right, Some(OpBuilder::insert("world").build()));
+
+    // The emoji takes two code units
+    let (left, right) = OpBuilder::insert("a😀b").build().split(3);
+    assert_eq!(left, Some(OpBuilder::insert("a😀").build()));
+    assert_eq!(right, Some(OpBuilder::insert("b").build()));
+}
+
+#[test]
+fn operation_split_and_shrink_delete_with_attributes() {
+    let attributes: Attributes = Attribute::Header(1).into();
+    let delete = OpBuilder::delete(5).attributes(attributes.clone()).build();
+    let (left, right) = delete.split(2);
+    assert_eq!(left, Some(OpBuilder::delete(2).attributes(attributes.clone()).build()));
+    assert_eq!(right, Some(OpBuilder::delete(3).attributes(attributes.clone()).build()));
+
+    let shrunk = delete.shrink(Interval::new(1, 4));
+    assert_eq!(shrunk, Some(OpBuilder::delete(3).attributes(attributes).build()));
+}
+
+#[test]
+fn delta_merge_delete_with_attributes() {
+    let attributes: Attributes = Attribute::Header(1).into();
+    let mut delta = Delta::default();
+    delta.delete(1);
+    delta.add(OpBuilder::delete(1).attributes(attributes.clone()).build());
+    delta.add(OpBuilder::delete(2).attributes(attributes.clone()).build());
+    assert_eq!(delta.ops.len(), 2);
+    assert_eq!(delta.ops[1], OpBuilder::delete(3).attributes(attributes).build());
+    assert_eq!(delta.base_len, 4);
+    assert_eq!(delta.apply("1234").unwrap(), "");
+}
+
+#[test]
+fn operation_split_at_boundary() {
+    let attributes: Attributes = Attribute::Bold(true).into();
+    let ops = vec![
+        OpBuilder::delete(3).build(),
+        OpBuilder::retain(3).attributes(attributes.clone()).build(),
+        OpBuilder::insert("123").attributes(attributes).build(),
+    ];
+    for op in ops {
+        assert_eq!(op.split(0), (None, Some(op.clone())));
+        assert_eq!(op.split(3), (Some(op.clone()), None));
+        assert_eq!(op.split(5), (Some(op.clone()), None));
+        let (left, right) = op.split(1);
+        assert_eq!((left.unwrap().len(), right.unwrap().len()), (1, 2));
+    }
+}
+
+#[test]
+fn operation_embed_split_and_compose() {
+    let embed = OpBuilder::embed("image", serde_json::Value::String("a.png".to_owned())).build();
+    assert_eq!(embed.len(), 1);
+    assert_eq!(embed.split(0), (None, Some(embed.clone())));
+    assert_eq!(embed.split(1), (Some(embed.clone()), None));
+    assert_eq!(embed.split(2), (Some(embed.clone()), None));
+    assert_eq!(embed.shrink(Interval::new(0, 1)), Some(embed.clone()));
+    assert_eq!(embed.shrink(Interval::new(1, 2)), None);
+
+    let mut delta = Delta::default();
+    delta.insert("12", Attributes::default());
+    delta.add(embed.clone());
+    delta.add(embed.clone());
+    delta.insert("\n", Attributes::default());
+    assert_eq!(delta.ops.len(), 4);
+
+    let bold: Attributes = Attribute::Bold(true).into();
+    let mut format = Delta::default();
+    format.retain(2, Attributes::default());
+    format.retain(1, bold.clone());
+    format.retain(2, Attributes::default());
+    let composed = delta.compose(&format).unwrap();
+    let mut bold_embed = embed.clone();
+    bold_embed.set_attributes(bold);
+    assert_eq!(composed.ops[1], bold_embed);
+    assert_eq!(composed.ops[2], embed);
+    assert_eq!(delta.compose(&format.invert(&delta)).unwrap(), delta);
+
+    let mut delete = Delta::default();
+    delete.retain(3, Attributes::default());
+    delete.delete(1);
+    delete.retain(1, Attributes::default());
+    assert_eq!(
+        delta.compose(&delete).unwrap().to_json(),
+        r#"[{"insert":"12"},{"insert":{"image":"a.png"}},{"insert":"\n"}]"#
+    );
+}
+
+#[test]
+fn operation_invert_restore_base() {
+    let bold: Attributes = Attribute::Bold(true).into();
+    let mut base = Delta::default();
+    base.insert("12", bold.clone());
+    base.insert("😀34", Attributes::default());
+    base.insert("\n", Attributes::default());
+
+    let italic: Attributes = Attribute::Italic(true).into();
+    let ops = vec![
+        OpBuilder::delete(4).build(),
+        OpBuilder::delete(1).build(),
+        OpBuilder::insert("👋ab").attributes(bold).build(),
+        OpBuilder::retain(5).attributes(italic).build(),
+        OpBuilder::retain(2).attributes(Attribute::Bold(false).into()).build(),
+    ];
+    for op in ops {
+        let rest = match op {
+            Operation::Insert(_) => base.target_len,
+            _ => base.target_len - op.len(),
+        };
+        let mut delta = Delta::default();
+        delta.add(op.clone());
+        delta.retain(rest, Attributes::default());
+        let applied = base.compose(&delta).unwrap();
+        let restored = applied.compose(&op.invert(&base)).unwrap();
+        assert_eq!(restored, base, "{:?}", op);
+        assert_eq!(restored.to_json(), base.to_json());
+    }
+}
+
 #[test]
 fn sequence() {
     let mut delta = Delta::default();
@@ -567,6 +964,75 @@ fn transform_two_conflict_non_seq_delta() {
     TestBuilder::new().run_script::<PlainDoc>(ops);
 }
 
+#[test]
+fn transform_cursor_with_insert_before() {
+    let delta = DeltaBuilder::new().retain(1).insert("abc").retain(5).build();
+    assert_eq!(transform_interval(Interval::new(3, 3), &delta), Interval::new(6, 6));
+}
+
+#[test]
+fn transform_cursor_with_insert_at_cursor() {
+    let delta = DeltaBuilder::new().retain(3).insert("abc").retain(3).build();
+    assert_eq!(transform_interval(Interval::new(3, 3), &delta), Interval::new(6, 6));
+}
+
+#[test]
+fn transform_cursor_with_insert_after() {
+    let delta = DeltaBuilder::new().retain(4).insert("abc").retain(2).build();
+    assert_eq!(transform_interval(Interval::new(3, 3), &delta), Interval::new(3, 3));
+}
+
+#[test]
+fn transform_cursor_with_delete_before() {
+    let delta = DeltaBuilder::new().delete(2).retain(4).build();
+    assert_eq!(transform_interval(Interval::new(3, 3), &delta), Interval::new(1, 1));
+}
+
+#[test]
+fn transform_cursor_with_delete_around_cursor() {
+    let delta = DeltaBuilder::new().retain(1).delete(4).retain(1).build();
+    assert_eq!(transform_interval(Interval::new(3, 3), &delta), Interval::new(1, 1));
+}
+
+#[test]
+fn transform_selection_with_insert_inside() {
+    let delta = DeltaBuilder::new().retain(3).insert("abc").retain(3).build();
+    assert_eq!(transform_interval(Interval::new(2, 5), &delta), Interval::new(2, 8));
+}
+
+#[test]
+fn transform_selection_with_insert_at_bounds() {
+    let delta = DeltaBuilder::new().retain(2).insert("abc").retain(4).build();
+    assert_eq!(transform_interval(Interval::new(2, 5), &delta), Interval::new(5, 8));
+
+    let delta = DeltaBuilder::new().retain(5).insert("abc").retain(1).build();
+    assert_eq!(transform_interval(Interval::new(2, 5), &delta), Interval::new(2, 5));
+}
+
+#[test]
+fn transform_selection_with_delete_inside() {
+    let delta = DeltaBuilder::new().retain(3).delete(1).retain(2).build();
+    assert_eq!(transform_interval(Interval::new(2, 5), &delta), Interval::new(2, 4));
+}
+
+#[test]
+fn transform_selection_with_delete_overlapping() {
+    let delta = DeltaBuilder::new().delete(3).retain(3).build();
+    assert_eq!(transform_interval(Interval::new(2, 5), &delta), Interval::new(0, 2));
+
+    let delta = DeltaBuilder::new().retain(4).delete(2).build();
+    assert_eq!(transform_interval(Interval::new(2, 5), &delta), Interval::new(2, 4));
+
+    let delta = DeltaBuilder::new().retain(1).delete(5).build();
+    assert_eq!(transform_interval(Interval::new(2, 5), &delta), Interval::new(1, 1));
+}
+
+#[test]
+fn transform_selection_with_edits_after() {
+    let delta = DeltaBuilder::new().retain(5).insert("abc").delete(1).build();
+    assert_eq!(transform_interval(Interval::new(2, 5), &delta), Interval::new(2, 5));
+}
+
 #[test]
 fn delta_invert_no_attribute_delta() {
     let mut delta = Delta::default();
@@ -715,6 +1181,56 @@ fn delta_invert_attribute_delta_with_attribute_delta() {
     TestBuilder::new().run_script::<PlainDoc>(ops);
 }
 
+fn invert_base() -> Delta {
+    let mut base = Delta::default();
+    base.insert("12", Attributes::default());
+    base.insert("345", Attribute::Bold(true).into());
+    base.insert("😀6", Attribute::Italic(true).into());
+    base.insert("\n", Attribute::Header(1).into());
+    base
+}
+
+fn assert_invert_restore_base(base: &Delta, delta: &Delta) {
+    let applied = base.compose(delta).unwrap();
+    let restored = applied.compose(&delta.invert(base)).unwrap();
+    assert_eq!(restored, *base);
+    assert_eq!(restored.to_bytes(), base.to_bytes());
+}
+
+#[test]
+fn delta_invert_format_change() {
+    let base = invert_base();
+    let mut delta = Delta::default();
+    delta.retain(1, Attributes::default());
+    delta.retain(6, Attribute::Underline(true).into());
+    delta.retain(1, Attribute::Bold(true).into());
+    assert_invert_restore_base(&base, &delta);
+
+    let mut delta = Delta::default();
+    delta.retain(2, Attributes::default());
+    delta.retain(5, Attribute::Bold(false).into());
+    delta.retain(2, Attribute::Header(2).into());
+    assert_invert_restore_base(&base, &delta);
+}
+
+#[test]
+fn delta_invert_multi_op_delete() {
+    let base = invert_base();
+    let mut delta = Delta::default();
+    delta.retain(1, Attributes::default());
+    delta.delete(7);
+    delta.insert("ab", Attribute::Underline(true).into());
+    assert_invert_restore_base(&base, &delta);
+
+    let mut delta = Delta::default();
+    delta.delete(2);
+    delta.retain(2, Attribute::Italic(true).into());
+    delta.delete(4);
+    delta.insert("c", Attributes::default());
+    delta.retain(1, Attributes::default());
+    assert_invert_restore_base(&base, &delta);
+}
+
 #[test]
 #[should_panic]
 fn delta_compose_with_missing_delta() {
@@ -727,3 +1243,316 @@ fn delta_compose_with_missing_delta() {
     ];
     TestBuilder::new().run_script::<FlowyDoc>(ops);
 }
+
+#[test]
+fn delta_append_test() {
+    let mut delta = DeltaBuilder::new()
+        .insert("123")
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .build();
+    let other = DeltaBuilder::new().insert("456\n").build();
+    delta.append(other);
+
+    assert_eq!(delta.apply("").unwrap(), "123\n456\n");
+    assert_eq!(
+        delta.to_json(),
+        r#"[{"insert":"123"},{"insert":"\n","attributes":{"header":1}},{"insert":"456\n"}]"#
+    );
+}
+
+#[test]
+fn delta_append_without_trailing_newline_test() {
+    let mut delta = DeltaBuilder::new().insert("123").build();
+    let other = DeltaBuilder::new()
+        .insert("456")
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .build();
+    delta.append(other);
+
+    // The blocks are separated by a plain newline instead of being merged
+    assert_eq!(delta.apply("").unwrap(), "123\n456\n");
+    assert_eq!(delta.target_len, 8);
+    assert_eq!(
+        delta.to_json(),
+        r#"[{"insert":"123\n456"},{"insert":"\n","attributes":{"list":"bullet"}}]"#
+    );
+}
+
+#[test]
+fn delta_prepend_test() {
+    let mut delta = DeltaBuilder::new().insert("456\n").build();
+    let other = DeltaBuilder::new()
+        .insert_with_attributes("123", Attribute::Bold(true).into())
+        .build();
+    delta.prepend(other);
+
+    assert_eq!(delta.apply("").unwrap(), "123\n456\n");
+    assert_eq!(
+        delta.to_json(),
+        r#"[{"insert":"123","attributes":{"bold":true}},{"insert":"\n456\n"}]"#
+    );
+
+    let mut empty = Delta::default();
+    empty.prepend(delta.clone());
+    assert_eq!(empty, delta);
+}
+
+#[test]
+fn delta_text_structure_eq() {
+    let delta = DeltaBuilder::new()
+        .insert("Hello ")
+        .insert_with_attributes("world", Attribute::Bold(true).into())
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .insert("123\n")
+        .build();
+    let reformatted = DeltaBuilder::new()
+        .insert_with_attributes("Hello", Attribute::Italic(true).into())
+        .insert(" world\n123")
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .build();
+    assert!(delta.text_structure_eq(&reformatted));
+    assert!(reformatted.text_structure_eq(&delta));
+
+    let changed = DeltaBuilder::new().insert("Hello world\n124\n").build();
+    assert!(!delta.text_structure_eq(&changed));
+
+    let split = DeltaBuilder::new().insert("Hello world\n12\n3\n").build();
+    assert!(!delta.text_structure_eq(&split));
+
+    let retain = DeltaBuilder::new().retain(5).insert("!").build();
+    let retain_bold = DeltaBuilder::new()
+        .retain_with_attributes(5, Attribute::Bold(true).into())
+        .insert("!")
+        .build();
+    assert!(retain.text_structure_eq(&retain_bold));
+    assert!(!retain.text_structure_eq(&DeltaBuilder::new().retain(5).delete(1).build()));
+}
+
+#[test]
+fn delta_compose_checked_insert_then_format() {
+    let base = "123";
+    let mut insert = Delta::default();
+    insert.retain(3, Attributes::default());
+    insert.insert("456", Attributes::default());
+    insert.insert("7", Attributes::default());
+
+    let mut format = Delta::default();
+    format.retain(2, Attributes::default());
+    format.retain(3, Attribute::Bold(true).into());
+    format.retain(2, Attributes::default());
+
+    let composed = insert.compose_checked(&format).unwrap();
+    assert_eq!(
+        composed.apply(base).unwrap(),
+        format.apply(&insert.apply(base).unwrap()).unwrap()
+    );
+    assert_eq!(
+        composed.to_json(),
+        r#"[{"retain":2},{"retain":1,"attributes":{"bold":true}},{"insert":"45","attributes":{"bold":true}},{"insert":"67"}]"#
+    );
+}
+
+#[test]
+fn delta_compose_checked_delete_then_insert() {
+    let base = "123456";
+    let mut delete = Delta::default();
+    delete.retain(1, Attributes::default());
+    delete.delete(3);
+    delete.retain(2, Attributes::default());
+
+    let mut insert = Delta::default();
+    insert.retain(1, Attributes::default());
+    insert.insert("a", Attributes::default());
+    insert.insert("b", Attributes::default());
+    insert.retain(2, Attributes::default());
+
+    let composed = delete.compose_checked(&insert).unwrap();
+    assert_eq!(composed.apply(base).unwrap(), "1ab56");
+    assert_eq!(
+        composed.apply(base).unwrap(),
+        insert.apply(&delete.apply(base).unwrap()).unwrap()
+    );
+    assert_eq!(composed.ops.len(), 4);
+
+    let mut missing_retain = Delta::default();
+    missing_retain.retain(1, Attributes::default());
+    missing_retain.insert("a", Attributes::default());
+    assert_eq!(delete.compose_checked(&missing_retain).is_err(), true);
+    assert_eq!(delete.compose(&missing_retain).is_ok(), true);
+}
+
+#[test]
+fn delta_is_content_change() {
+    let bold = DeltaBuilder::new()
+        .retain(2)
+        .retain_with_attributes(3, Attribute::Bold(true).into())
+        .build();
+    assert!(!bold.is_content_change());
+    assert!(!DeltaBuilder::new().retain(5).build().is_content_change());
+
+    let insert = DeltaBuilder::new().retain(2).insert("abc").build();
+    assert!(insert.is_content_change());
+    let delete = DeltaBuilder::new().retain(2).delete(1).build();
+    assert!(delete.is_content_change());
+}
+
+#[test]
+fn delta_from_plain_text() {
+    let text = "Hello world\n\nfoo bar\nbaz\n";
+    let delta = Delta::from_plain_text(text);
+    assert_eq!(delta, DeltaBuilder::new().insert(text).build());
+    assert_eq!(delta.to_plain_text(), text);
+    assert_eq!(delta.base_len, 0);
+    assert_eq!(delta.target_len, text.len());
+
+    // The trailing newline is appended if missing
+    assert_eq!(Delta::from_plain_text("Hello\nworld").to_plain_text(), "Hello\nworld\n");
+    assert_eq!(Delta::from_plain_text("").to_plain_text(), "\n");
+}
+
+#[test]
+fn delta_to_plain_text_drop_attributes() {
+    let delta = DeltaBuilder::new()
+        .insert_with_attributes("Hello", Attribute::Bold(true).into())
+        .insert(" world")
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .build();
+    assert_eq!(delta.to_plain_text(), "Hello world\n");
+    assert_eq!(
+        Delta::from_plain_text(&delta.to_plain_text()).text_structure_eq(&delta),
+        true
+    );
+}
+
+#[test]
+fn delta_to_plain_text_keep_block_newlines() {
+    let mut delta = DeltaBuilder::new()
+        .insert("Title")
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .insert_with_attributes("one", Attribute::Italic(true).into())
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .build();
+    delta.add(OpBuilder::embed("image", serde_json::Value::String("a.png".to_owned())).build());
+    delta.insert("two", Attribute::Link("https://appflowy.io").into());
+    delta.insert("\n", Attribute::Bullet(true).into());
+    delta.insert("\n", Attributes::default());
+    assert_eq!(delta.to_plain_text(), "Title\none\ntwo\n\n");
+}
+
+#[test]
+fn delta_to_plain_text_skip_footnotes() {
+    let delta = DeltaBuilder::new()
+        .insert("Rust")
+        .insert_footnote(&Footnote::new("rust", "A systems language."))
+        .insert(" is fast\n")
+        .build();
+    assert_eq!(delta.to_plain_text(), "Rust is fast\n");
+}
+
+#[test]
+fn delta_normalize_messy_delta() {
+    let doc = DeltaBuilder::new().insert("12345678\n").build();
+    let mut delta = Delta::new();
+    delta.ops = vec![
+        OpBuilder::retain(0).build(),
+        OpBuilder::retain(1).build(),
+        OpBuilder::retain(1).build(),
+        OpBuilder::insert("").build(),
+        OpBuilder::retain(1).attributes(Attribute::Bold(true).into()).build(),
+        OpBuilder::retain(1).attributes(Attribute::Bold(true).into()).build(),
+        OpBuilder::insert("a").build(),
+        OpBuilder::insert("b").build(),
+        OpBuilder::delete(1).build(),
+        OpBuilder::delete(0).build(),
+        OpBuilder::delete(1).build(),
+        OpBuilder::retain(1).build(),
+        OpBuilder::retain(2).build(),
+    ];
+    delta.base_len = 9;
+    delta.target_len = 9;
+    let applied = delta.apply("12345678\n").unwrap();
+
+    delta.normalize();
+    assert_eq!(
+        delta.ops,
+        vec![
+            OpBuilder::retain(2).build(),
+            OpBuilder::retain(2).attributes(Attribute::Bold(true).into()).build(),
+            OpBuilder::insert("ab").build(),
+            OpBuilder::delete(2).build(),
+            OpBuilder::retain(3).build(),
+        ]
+    );
+    assert_eq!((delta.base_len, delta.target_len), (9, 9));
+    assert_eq!(delta.apply("12345678\n").unwrap(), applied);
+    let other = DeltaBuilder::new().retain(8).insert("c").retain(1).build();
+    assert!(delta.transform(&other).is_ok());
+    let composed = doc.compose(&delta).unwrap();
+    assert_eq!(composed.to_plain_text(), applied);
+    assert_eq!(
+        composed.to_json(),
+        r#"[{"insert":"12"},{"insert":"34","attributes":{"bold":true}},{"insert":"ab78\n"}]"#
+    );
+
+    // Normalizing a normalized delta changes nothing
+    let normalized = delta.clone();
+    delta.normalize();
+    assert_eq!(delta, normalized);
+}
+
+#[test]
+fn delta_diff_test() {
+    assert_eq!(Delta::diff("abc", "abc"), DeltaBuilder::new().retain(3).build());
+    assert_eq!(
+        Delta::diff("abc", "xyz"),
+        DeltaBuilder::new().insert("xyz").delete(3).build()
+    );
+    assert_eq!(
+        Delta::diff("hello world", "hello brave new world"),
+        DeltaBuilder::new().retain(6).insert("brave new ").retain(5).build()
+    );
+    assert_eq!(
+        Delta::diff("abcdef", "abdf"),
+        DeltaBuilder::new()
+            .retain(2)
+            .delete(1)
+            .retain(1)
+            .delete(1)
+            .retain(1)
+            .build()
+    );
+    assert_eq!(Delta::diff("", ""), Delta::new());
+    assert_eq!(Delta::diff("", "ab"), DeltaBuilder::new().insert("ab").build());
+}
+
+#[test]
+fn delta_diff_utf16_test() {
+    let delta = Delta::diff("a😀b", "a😀c👋b");
+    assert_eq!(delta, DeltaBuilder::new().retain(3).insert("c👋").retain(1).build());
+    assert_eq!(delta.base_len, 4);
+    assert_eq!(delta.apply("a😀b").unwrap(), "a😀c👋b");
+
+    // The retained text keeps its attributes when composed with the document
+    let doc = DeltaBuilder::new()
+        .insert_with_attributes("a😀", Attribute::Bold(true).into())
+        .insert("b\n")
+        .build();
+    let doc = doc.compose(&Delta::diff("a😀b\n", "a😀c👋b\n")).unwrap();
+    assert_eq!(
+        doc.to_json(),
+        r#"[{"insert":"a😀","attributes":{"bold":true}},{"insert":"c👋b\n"}]"#
+    );
+}
+
+#[test]
+fn delta_diff_apply_random_test() {
+    let mut rng = Rng::default();
+    for _ in 0..1000 {
+        let old = rng.gen_unicode_string(50);
+        let new = rng.gen_unicode_edit(&old);
+        let delta = Delta::diff(&old, &new);
+        assert_eq!(delta.apply(&old).unwrap(), new);
+        assert_eq!(delta.base_len, old.encode_utf16().count());
+        assert_eq!(delta.target_len, new.encode_utf16().count());
+    }
+}
@@ -0,0 +1,42 @@
+use flowy_document_infra::core::{line_count, plain_text_to_delta, PlainTextImport};
+use lib_ot::core::*;
+
+const LONG_LINE: &str =
+    "The quick brown fox jumps over the lazy dog. It barks! Does the fox care? Not at all, it keeps running.";
+
+#[test]
+fn plain_text_to_delta_test() {
+    let delta = plain_text_to_delta("Hello\nworld\n", &PlainTextImport::new());
+    assert_eq!(delta, DeltaBuilder::new().insert("Hello\nworld\n").build());
+
+    let delta = plain_text_to_delta("Hello\nworld", &PlainTextImport::new());
+    assert_eq!(delta, DeltaBuilder::new().insert("Hello\nworld\n").build());
+
+    let delta = plain_text_to_delta(LONG_LINE, &PlainTextImport::new());
+    assert_eq!(line_count(&delta), 1);
+}
+
+#[test]
+fn plain_text_to_delta_split_long_line_test() {
+    let delta = plain_text_to_delta(LONG_LINE, &PlainTextImport::new().max_block_len(60));
+    assert_eq!(
+        delta,
+        DeltaBuilder::new()
+            .insert("The quick brown fox jumps over the lazy dog. It barks! \nDoes the fox care? Not at all, it keeps running.\n")
+            .build()
+    );
+
+    let delta = plain_text_to_delta(LONG_LINE, &PlainTextImport::new().max_block_len(20));
+    assert!(line_count(&delta) > 1);
+    let text = delta.apply("").unwrap();
+    for line in text.lines() {
+        assert!(line.chars().count() <= 20);
+    }
+    assert_eq!(text.replace('\n', ""), LONG_LINE);
+}
+
+#[test]
+fn plain_text_to_delta_split_without_whitespace_test() {
+    let delta = plain_text_to_delta("一二三四五六七", &PlainTextImport::new().max_block_len(3));
+    assert_eq!(delta, DeltaBuilder::new().insert("一二三\n四五六\n七\n").build());
+}
@@ -0,0 +1,90 @@
+use flowy_document_infra::entities::doc::{replay_revisions, RevType, Revision};
+use lib_ot::core::*;
+
+fn revision(base_rev_id: i64, rev_id: i64, delta: Delta, ty: RevType) -> Revision {
+    Revision::new(base_rev_id, rev_id, delta.to_bytes().to_vec(), "doc_id", ty)
+}
+
+// Local and remote revisions interleaved as they are saved by a client that
+// edits while receiving the server pushes.
+fn interleaved_revisions() -> Vec<Revision> {
+    vec![
+        revision(0, 1, DeltaBuilder::new().insert("abc\n").build(), RevType::Local),
+        revision(
+            1,
+            2,
+            DeltaBuilder::new().insert("123").retain(4).build(),
+            RevType::Remote,
+        ),
+        revision(
+            2,
+            3,
+            DeltaBuilder::new().retain(6).insert("def").retain(1).build(),
+            RevType::Local,
+        ),
+        revision(
+            3,
+            4,
+            DeltaBuilder::new()
+                .retain_with_attributes(3, Attribute::Bold(true).into())
+                .retain(7)
+                .build(),
+            RevType::Remote,
+        ),
+        revision(
+            4,
+            5,
+            DeltaBuilder::new().retain(9).delete(1).insert("!\n").build(),
+            RevType::Local,
+        ),
+    ]
+}
+
+#[test]
+fn replay_interleaved_revisions_test() {
+    let expected = DeltaBuilder::new()
+        .insert_with_attributes("123", Attribute::Bold(true).into())
+        .insert("abcdef!\n")
+        .build();
+    assert_eq!(replay_revisions(&interleaved_revisions()).unwrap(), expected);
+}
+
+#[test]
+fn replay_revisions_orders_by_rev_id_test() {
+    let revisions = interleaved_revisions();
+    let mut shuffled = revisions.clone();
+    shuffled.reverse();
+    shuffled.swap(0, 2);
+    assert_eq!(
+        replay_revisions(&shuffled).unwrap(),
+        replay_revisions(&revisions).unwrap()
+    );
+}
+
+#[test]
+fn replay_revisions_ignores_rev_type_test() {
+    // The other sender saved its own revisions as local and this client's ones
+    // as remote, so it replays the same revisions with the opposite types.
+    let revisions = interleaved_revisions();
+    let other_sender_revisions = revisions
+        .iter()
+        .cloned()
+        .map(|mut revision| {
+            revision.ty = match revision.ty {
+                RevType::Local => RevType::Remote,
+                RevType::Remote => RevType::Local,
+            };
+            revision
+        })
+        .collect::<Vec<Revision>>();
+
+    let expected = DeltaBuilder::new()
+        .insert_with_attributes("123", Attribute::Bold(true).into())
+        .insert("abcdef!\n")
+        .build();
+    assert_eq!(replay_revisions(&other_sender_revisions).unwrap(), expected);
+    assert_eq!(
+        replay_revisions(&other_sender_revisions).unwrap(),
+        replay_revisions(&revisions).unwrap()
+    );
+}
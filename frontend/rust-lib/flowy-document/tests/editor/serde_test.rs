@@ -26,12 +26,62 @@ fn operation_retain_serialize_test() {
 
 #[test]
 fn operation_delete_serialize_test() {
-    let operation = Operation::Delete(2);
+    let operation = Operation::Delete(2.into());
     let json = serde_json::to_string(&operation).unwrap();
+    assert_eq!(json, r#"{"delete":2}"#);
     let insert_op: Operation = serde_json::from_str(&json).unwrap();
     assert_eq!(insert_op, operation);
 }
 
+#[test]
+fn operation_delete_with_attributes_serialize_test() {
+    let operation = OpBuilder::delete(1).attributes(Attribute::Header(1).into()).build();
+    let json = serde_json::to_string(&operation).unwrap();
+    assert_eq!(json, r#"{"delete":1,"attributes":{"header":1}}"#);
+
+    let delete_op: Operation = serde_json::from_str(&json).unwrap();
+    assert_eq!(delete_op, operation);
+    assert_eq!(delete_op.get_attributes(), Attribute::Header(1).into());
+}
+
+#[test]
+fn delta_delete_without_attributes_deserialize_test() {
+    // The deltas serialized before the deletes had attributes
+    let delta = Delta::from_json(r#"[{"retain":2},{"delete":3}]"#).unwrap();
+    assert_eq!(delta.ops[1], OpBuilder::delete(3).build());
+    assert_eq!(delta.ops[1].get_attributes().is_empty(), true);
+}
+
+#[test]
+fn operation_embed_serialize_test() {
+    let operation = OpBuilder::embed("image", serde_json::Value::String("a.png".to_owned()))
+        .attributes(Attribute::Bold(true).into())
+        .build();
+    let json = serde_json::to_string(&operation).unwrap();
+    assert_eq!(json, r#"{"insert":{"image":"a.png"},"attributes":{"bold":true}}"#);
+
+    let embed_op: Operation = serde_json::from_str(&json).unwrap();
+    assert_eq!(embed_op, operation);
+    assert_eq!(embed_op.get_data(), "");
+    assert_eq!(embed_op.get_attributes(), Attribute::Bold(true).into());
+}
+
+#[test]
+fn delta_embed_serde_test() {
+    let json = r#"[{"insert":"12"},{"insert":{"divider":true}},{"insert":{"divider":true}},{"insert":"\n"}]"#;
+    let delta = Delta::from_json(json).unwrap();
+    assert_eq!(delta.ops.len(), 4);
+    assert_eq!(delta.target_len, 5);
+    assert_eq!(delta.ops[1].is_embed(), true);
+    assert_eq!(delta.to_json(), json);
+    assert_eq!(delta.apply("").unwrap(), format!("12{}{}\n", EMBED, EMBED));
+
+    assert_eq!(
+        Delta::from_json(r#"[{"insert":{"image":"a.png","video":"b.mp4"}}]"#).is_err(),
+        true
+    );
+}
+
 #[test]
 fn attributes_serialize_test() {
     let attributes = AttributeBuilder::new()
@@ -56,7 +106,7 @@ fn delta_serialize_multi_attribute_test() {
 
     delta.add(retain);
     delta.add(Operation::Retain(5.into()));
-    delta.add(Operation::Delete(3));
+    delta.add(Operation::Delete(3.into()));
 
     let json = serde_json::to_string(&delta).unwrap();
     eprintln!("{}", json);
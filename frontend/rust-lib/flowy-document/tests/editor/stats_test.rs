@@ -0,0 +1,43 @@
+use flowy_document_infra::core::{char_count, line_count, word_count};
+use lib_ot::core::*;
+
+#[test]
+fn stats_of_empty_doc_test() {
+    let delta = Delta::new();
+    assert_eq!(word_count(&delta), 0);
+    assert_eq!(char_count(&delta), 0);
+    assert_eq!(line_count(&delta), 0);
+}
+
+#[test]
+fn stats_of_formatted_doc_test() {
+    let delta = DeltaBuilder::new()
+        .insert("Hello wor")
+        .insert_with_attributes("ld", Attribute::Bold(true).into())
+        .insert("\n")
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .insert("foo  bar\tbaz\n")
+        .build();
+    assert_eq!(word_count(&delta), 5);
+    assert_eq!(char_count(&delta), 23);
+    assert_eq!(line_count(&delta), 3);
+}
+
+#[test]
+fn stats_of_doc_without_trailing_newline_test() {
+    let delta = Delta::from_json(r#"[{"insert":"123\n"},{"insert":"456","attributes":{"bold":true}}]"#).unwrap();
+    assert_eq!(word_count(&delta), 2);
+    assert_eq!(char_count(&delta), 6);
+    assert_eq!(line_count(&delta), 2);
+}
+
+#[test]
+fn stats_of_doc_with_footnote_test() {
+    let delta = DeltaBuilder::new()
+        .insert("Rust ")
+        .insert_footnote(&Footnote::new("rust", "A systems language."))
+        .insert(" is fast\n")
+        .build();
+    assert_eq!(word_count(&delta), 3);
+    assert_eq!(char_count(&delta), 13);
+}
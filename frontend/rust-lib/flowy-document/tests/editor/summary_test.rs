@@ -0,0 +1,82 @@
+use flowy_document_infra::core::{change_summary, ChangeSummary};
+use lib_ot::core::*;
+
+fn plan() -> Delta {
+    DeltaBuilder::new()
+        .insert("Project Plan")
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .insert("Ship the editor this week.\n")
+        .insert("design")
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .insert("review")
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .build()
+}
+
+#[test]
+fn change_summary_of_same_doc_test() {
+    let summary = change_summary(&plan(), &plan());
+    assert_eq!(summary.is_empty(), true);
+}
+
+#[test]
+fn change_summary_of_edited_text_test() {
+    let to = DeltaBuilder::new()
+        .insert("Project Plan")
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .insert("Ship the new editor next week.\n")
+        .insert("design")
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .insert("review")
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .build();
+    assert_eq!(
+        change_summary(&plan(), &to),
+        ChangeSummary {
+            inserted_words: 2,
+            deleted_words: 1,
+            // "new " inserted, "this" replaced by "next" sharing the "t"
+            inserted_chars: 7,
+            deleted_chars: 3,
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn change_summary_of_blocks_test() {
+    let to = DeltaBuilder::new()
+        .insert("Project Plan")
+        .insert_with_attributes("\n", Attribute::Header(1).into())
+        .insert("Ship the editor this week.\n")
+        .insert("Tasks")
+        .insert_with_attributes("\n", Attribute::Header(2).into())
+        .insert("design")
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .insert("test")
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .insert("release")
+        .insert_with_attributes("\n", Attribute::Bullet(true).into())
+        .build();
+    let summary = change_summary(&plan(), &to);
+    assert_eq!(summary.headings_added, 1);
+    assert_eq!(summary.headings_removed, 0);
+    assert_eq!(summary.list_items_added, 2);
+    assert_eq!(summary.list_items_removed, 1);
+    assert_eq!(summary.inserted_words, 3);
+    assert_eq!(summary.deleted_words, 1);
+
+    // Formatting a line as a heading changes the structure but not the text
+    let from = DeltaBuilder::new().insert("Tasks\n").build();
+    let to = DeltaBuilder::new()
+        .insert("Tasks")
+        .insert_with_attributes("\n", Attribute::Header(2).into())
+        .build();
+    assert_eq!(
+        change_summary(&from, &to),
+        ChangeSummary {
+            headings_added: 1,
+            ..Default::default()
+        }
+    );
+}
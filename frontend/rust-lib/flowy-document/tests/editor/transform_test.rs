@@ -0,0 +1,381 @@
+#![allow(clippy::all)]
+use crate::editor::Rng;
+use lib_ot::core::*;
+
+// Applies the concurrent deltas `a` and `b` to the same `base` document, each
+// side composing the transformed delta of the other one, and checks that both
+// sides converge to `expected`. The delta `a` wins the tie when both deltas
+// insert at the same position.
+fn assert_transform(base: &str, a: &str, b: &str, expected: &str) {
+    assert_transform_with(base, a, b, &TransformPolicy::default(), expected);
+}
+
+// The same as assert_transform, except that the conflicting attributes are
+// resolved by `policy`.
+fn assert_transform_with(base: &str, a: &str, b: &str, policy: &TransformPolicy, expected: &str) {
+    let base = Delta::from_json(base).unwrap();
+    let a = Delta::from_json(a).unwrap();
+    let b = Delta::from_json(b).unwrap();
+    let expected = Delta::from_json(expected).unwrap();
+
+    let (a_prime, b_prime) = a.transform_with(&b, policy).unwrap();
+    let left = base.compose(&a).unwrap().compose(&b_prime).unwrap();
+    let right = base.compose(&b).unwrap().compose(&a_prime).unwrap();
+
+    assert_eq!(left, right, "a: {}, b: {} don't converge", a.to_json(), b.to_json());
+    assert_eq!(left, expected, "got: {}", left.to_json());
+}
+
+// base: "abc"
+// a:    insert "x" at 1
+// b:    insert "y" at 1
+// =>    "axybc"
+#[test]
+fn transform_insert_insert_at_same_position() {
+    assert_transform(
+        r#"[{"insert":"abc"}]"#,
+        r#"[{"retain":1},{"insert":"x"},{"retain":2}]"#,
+        r#"[{"retain":1},{"insert":"y"},{"retain":2}]"#,
+        r#"[{"insert":"axybc"}]"#,
+    );
+}
+
+// base: "abc"
+// a:    insert "x" at 0
+// b:    insert "y" at 3
+// =>    "xabcy"
+#[test]
+fn transform_insert_insert_at_different_position() {
+    assert_transform(
+        r#"[{"insert":"abc"}]"#,
+        r#"[{"insert":"x"},{"retain":3}]"#,
+        r#"[{"retain":3},{"insert":"y"}]"#,
+        r#"[{"insert":"xabcy"}]"#,
+    );
+}
+
+// base: "abc"
+// a:    insert "x" at 1
+// b:    insert "yz" at 1
+// =>    "axyzbc"
+#[test]
+fn transform_insert_insert_with_different_length() {
+    assert_transform(
+        r#"[{"insert":"abc"}]"#,
+        r#"[{"retain":1},{"insert":"x"},{"retain":2}]"#,
+        r#"[{"retain":1},{"insert":"yz"},{"retain":2}]"#,
+        r#"[{"insert":"axyzbc"}]"#,
+    );
+}
+
+// base: "abc"
+// a:    insert "x" at 1
+// b:    delete "b"
+// =>    "axc"
+#[test]
+fn transform_insert_delete_at_same_position() {
+    assert_transform(
+        r#"[{"insert":"abc"}]"#,
+        r#"[{"retain":1},{"insert":"x"},{"retain":2}]"#,
+        r#"[{"retain":1},{"delete":1},{"retain":1}]"#,
+        r#"[{"insert":"axc"}]"#,
+    );
+}
+
+// base: "abcd"
+// a:    insert "x" at 2
+// b:    delete "bc"
+// =>    "axd"
+#[test]
+fn transform_insert_inside_deleted_range() {
+    assert_transform(
+        r#"[{"insert":"abcd"}]"#,
+        r#"[{"retain":2},{"insert":"x"},{"retain":2}]"#,
+        r#"[{"retain":1},{"delete":2},{"retain":1}]"#,
+        r#"[{"insert":"axd"}]"#,
+    );
+}
+
+// base: "abc"
+// a:    delete "b"
+// b:    insert "x" at 1
+// =>    "axc"
+#[test]
+fn transform_delete_insert_at_same_position() {
+    assert_transform(
+        r#"[{"insert":"abc"}]"#,
+        r#"[{"retain":1},{"delete":1},{"retain":1}]"#,
+        r#"[{"retain":1},{"insert":"x"},{"retain":2}]"#,
+        r#"[{"insert":"axc"}]"#,
+    );
+}
+
+// base: "abc"
+// a:    delete "b"
+// b:    delete "b"
+// =>    "ac"
+#[test]
+fn transform_delete_delete_same_range() {
+    assert_transform(
+        r#"[{"insert":"abc"}]"#,
+        r#"[{"retain":1},{"delete":1},{"retain":1}]"#,
+        r#"[{"retain":1},{"delete":1},{"retain":1}]"#,
+        r#"[{"insert":"ac"}]"#,
+    );
+}
+
+// base: "abcd"
+// a:    delete "ab"
+// b:    delete "bc"
+// =>    "d"
+#[test]
+fn transform_delete_delete_overlapping_range() {
+    assert_transform(
+        r#"[{"insert":"abcd"}]"#,
+        r#"[{"delete":2},{"retain":2}]"#,
+        r#"[{"retain":1},{"delete":2},{"retain":1}]"#,
+        r#"[{"insert":"d"}]"#,
+    );
+}
+
+// base: "abcd"
+// a:    delete "abcd"
+// b:    delete "bc"
+// =>    ""
+#[test]
+fn transform_delete_delete_nested_range() {
+    assert_transform(
+        r#"[{"insert":"abcd"}]"#,
+        r#"[{"delete":4}]"#,
+        r#"[{"retain":1},{"delete":2},{"retain":1}]"#,
+        r#"[]"#,
+    );
+}
+
+// base: "abc"
+// a:    bold "abc"
+// b:    italic "abc"
+// =>    bold and italic "abc"
+#[test]
+fn transform_attribute_attribute_with_different_keys() {
+    assert_transform(
+        r#"[{"insert":"abc"}]"#,
+        r#"[{"retain":3,"attributes":{"bold":true}}]"#,
+        r#"[{"retain":3,"attributes":{"italic":true}}]"#,
+        r#"[{"insert":"abc","attributes":{"bold":true,"italic":true}}]"#,
+    );
+}
+
+// base: "abc"
+// a:    bold "ab"
+// b:    italic "bc"
+// =>    bold "a", bold and italic "b", italic "c"
+#[test]
+fn transform_attribute_attribute_overlapping_range() {
+    assert_transform(
+        r#"[{"insert":"abc"}]"#,
+        r#"[{"retain":2,"attributes":{"bold":true}},{"retain":1}]"#,
+        r#"[{"retain":1},{"retain":2,"attributes":{"italic":true}}]"#,
+        r#"[{"insert":"a","attributes":{"bold":true}},{"insert":"b","attributes":{"bold":true,"italic":true}},{"insert":"c","attributes":{"italic":true}}]"#,
+    );
+}
+
+// base: "abc"
+// a:    bold "abc"
+// b:    bold "abc"
+// =>    bold "abc"
+#[test]
+fn transform_attribute_attribute_with_same_key() {
+    assert_transform(
+        r#"[{"insert":"abc"}]"#,
+        r#"[{"retain":3,"attributes":{"bold":true}}]"#,
+        r#"[{"retain":3,"attributes":{"bold":true}}]"#,
+        r#"[{"insert":"abc","attributes":{"bold":true}}]"#,
+    );
+}
+
+// base: "abc"
+// a:    bold "abc"
+// b:    delete "b"
+// =>    bold "ac"
+#[test]
+fn transform_attribute_delete_same_range() {
+    assert_transform(
+        r#"[{"insert":"abc"}]"#,
+        r#"[{"retain":3,"attributes":{"bold":true}}]"#,
+        r#"[{"retain":1},{"delete":1},{"retain":1}]"#,
+        r#"[{"insert":"ac","attributes":{"bold":true}}]"#,
+    );
+}
+
+// base: "abc"
+// a:    delete "ab"
+// b:    bold "bc"
+// =>    bold "c"
+#[test]
+fn transform_delete_attribute_overlapping_range() {
+    assert_transform(
+        r#"[{"insert":"abc"}]"#,
+        r#"[{"delete":2},{"retain":1}]"#,
+        r#"[{"retain":1},{"retain":2,"attributes":{"bold":true}}]"#,
+        r#"[{"insert":"c","attributes":{"bold":true}}]"#,
+    );
+}
+
+// base: "abc\n"
+// a:    header 1 on the line
+// b:    insert "x" at 0
+// =>    "xabc" with header 1
+#[test]
+fn transform_line_attribute_insert() {
+    assert_transform(
+        r#"[{"insert":"abc\n"}]"#,
+        r#"[{"retain":3},{"retain":1,"attributes":{"header":1}}]"#,
+        r#"[{"insert":"x"},{"retain":4}]"#,
+        r#"[{"insert":"xabc"},{"insert":"\n","attributes":{"header":1}}]"#,
+    );
+}
+
+// base: "abc\n"
+// a:    header 1 on the line
+// b:    header 2 on the line
+// =>    "abc" with header 1
+#[test]
+fn transform_line_attribute_with_conflicting_values() {
+    assert_transform(
+        r#"[{"insert":"abc\n"}]"#,
+        r#"[{"retain":3},{"retain":1,"attributes":{"header":1}}]"#,
+        r#"[{"retain":3},{"retain":1,"attributes":{"header":2}}]"#,
+        r#"[{"insert":"abc"},{"insert":"\n","attributes":{"header":1}}]"#,
+    );
+}
+
+// base: "abc"
+// a:    bold and link "x.com" on "abc" at time 1
+// b:    unbold and link "y.com" on "abc" at time 2
+// =>    bold "abc" linking to "y.com", the bold conflict falls back to a wins
+#[test]
+fn transform_attribute_with_last_writer_wins_policy() {
+    let policy = TransformPolicy::new()
+        .precedence(AttributeKey::Link, Precedence::LastWriterWins)
+        .timestamps(1, 2);
+    assert_transform_with(
+        r#"[{"insert":"abc"}]"#,
+        r#"[{"retain":3,"attributes":{"bold":true,"link":"x.com"}}]"#,
+        r#"[{"retain":3,"attributes":{"bold":null,"link":"y.com"}}]"#,
+        &policy,
+        r#"[{"insert":"abc","attributes":{"bold":true,"link":"y.com"}}]"#,
+    );
+
+    // a is the last writer
+    let policy = policy.timestamps(2, 1);
+    assert_transform_with(
+        r#"[{"insert":"abc"}]"#,
+        r#"[{"retain":3,"attributes":{"bold":true,"link":"x.com"}}]"#,
+        r#"[{"retain":3,"attributes":{"bold":null,"link":"y.com"}}]"#,
+        &policy,
+        r#"[{"insert":"abc","attributes":{"bold":true,"link":"x.com"}}]"#,
+    );
+}
+
+// base: "abc"
+// a:    bold and link "x.com" on "ab"
+// b:    unbold and link "y.com" on "bc"
+// =>    "a" keeps a's, "b" is unbolded by b but links to "x.com", "c" keeps b's
+#[test]
+fn transform_attribute_with_other_wins_policy() {
+    let policy = TransformPolicy::new().precedence(AttributeKey::Bold, Precedence::OtherWins);
+    assert_transform_with(
+        r#"[{"insert":"abc","attributes":{"bold":true}}]"#,
+        r#"[{"retain":2,"attributes":{"bold":true,"link":"x.com"}},{"retain":1}]"#,
+        r#"[{"retain":1},{"retain":2,"attributes":{"bold":null,"link":"y.com"}}]"#,
+        &policy,
+        r#"[{"insert":"a","attributes":{"bold":true,"link":"x.com"}},{"insert":"b","attributes":{"link":"x.com"}},{"insert":"c","attributes":{"link":"y.com"}}]"#,
+    );
+}
+
+// base: "abc"
+// a:    insert "x" at 1 by site "site-b"
+// b:    insert "y" at 1 by site "site-a"
+// =>    "ayxbc" on both sites, the lower site id inserts first
+#[test]
+fn transform_insert_at_same_position_with_site_ids() {
+    let base = Delta::from_json(r#"[{"insert":"abc"}]"#).unwrap();
+    let a = Delta::from_json(r#"[{"retain":1},{"insert":"x"},{"retain":2}]"#).unwrap();
+    let b = Delta::from_json(r#"[{"retain":1},{"insert":"y"},{"retain":2}]"#).unwrap();
+
+    // Each site transforms its own delta against the other one
+    let (a_prime, b_prime) = a
+        .transform_with(&b, &TransformPolicy::new().site_ids("site-b", "site-a"))
+        .unwrap();
+    let (b_prime_2, a_prime_2) = b
+        .transform_with(&a, &TransformPolicy::new().site_ids("site-a", "site-b"))
+        .unwrap();
+    assert_eq!(a_prime, a_prime_2);
+    assert_eq!(b_prime, b_prime_2);
+
+    let left = base.compose(&a).unwrap().compose(&b_prime).unwrap();
+    let right = base.compose(&b).unwrap().compose(&a_prime).unwrap();
+    let expected = Delta::from_json(r#"[{"insert":"ayxbc"}]"#).unwrap();
+    assert_eq!(left, expected);
+    assert_eq!(right, expected);
+
+    // Without the site ids, the delta being transformed inserts first
+    let (a_prime, _) = a.transform(&b).unwrap();
+    let (_, a_prime_2) = b.transform(&a).unwrap();
+    assert_ne!(a_prime, a_prime_2);
+}
+
+// Retains the rest of the text of `len` code units after the delta.
+fn retain_rest(mut delta: Delta, len: usize) -> Delta {
+    let rest = len - delta.base_len;
+    delta.retain(rest, Attributes::default());
+    delta
+}
+
+fn op_delta(op: &Operation, len: usize) -> Delta {
+    let mut delta = Delta::new();
+    delta.add(op.clone());
+    retain_rest(delta, len)
+}
+
+#[test]
+fn operation_transform_converge() {
+    let mut rng = Rng::default();
+    for _ in 0..1000 {
+        let s = rng.gen_string(10);
+        let base = Delta::from_plain_text(&s);
+        let (a, b) = (rng.gen_operation(s.len()), rng.gen_operation(s.len()));
+        let (a_delta, b_delta) = (op_delta(&a, base.target_len), op_delta(&b, base.target_len));
+        for priority in vec![true, false] {
+            let (a_prime, b_prime) = a.transform(&b, priority).unwrap();
+            let after_a = base.compose(&a_delta).unwrap();
+            let after_b = base.compose(&b_delta).unwrap();
+            let left = after_a.compose(&retain_rest(b_prime, after_a.target_len)).unwrap();
+            let right = after_b.compose(&retain_rest(a_prime, after_b.target_len)).unwrap();
+            assert_eq!(left, right, "a: {:?}, b: {:?}, priority: {}", a, b, priority);
+        }
+    }
+}
+
+#[test]
+fn operation_transform_insert_priority() {
+    let a = OpBuilder::insert("x").build();
+    let b = OpBuilder::insert("y").build();
+    let base = Delta::from_plain_text("abc");
+    for (priority, expected) in vec![(true, "xyabc\n"), (false, "yxabc\n")] {
+        let (a_prime, b_prime) = a.transform(&b, priority).unwrap();
+        let left = base
+            .compose(&op_delta(&a, base.target_len))
+            .unwrap()
+            .compose(&retain_rest(b_prime, base.target_len + 1))
+            .unwrap();
+        let right = base
+            .compose(&op_delta(&b, base.target_len))
+            .unwrap()
+            .compose(&retain_rest(a_prime, base.target_len + 1))
+            .unwrap();
+        assert_eq!(left.to_plain_text(), expected);
+        assert_eq!(right.to_plain_text(), expected);
+    }
+}
@@ -57,6 +57,7 @@ impl AppTest {
             desc: None,
             color_style: None,
             is_trash: Some(true),
+            parent_id: None,
         };
         update_app(&self.sdk, request).await;
     }
@@ -161,6 +162,7 @@ pub async fn create_app(sdk: &FlowyTestSDK, name: &str, desc: &str, workspace_id
         name: name.to_string(),
         desc: desc.to_string(),
         color_style: Default::default(),
+        parent_id: None,
     };
 
     let app = FlowyWorkspaceTest::new(sdk.clone())
@@ -220,6 +222,7 @@ pub async fn create_view(sdk: &FlowyTestSDK, app_id: &str) -> View {
         desc: "".to_string(),
         thumbnail: Some("http://1.png".to_string()),
         view_type: ViewType::Doc,
+        parent_view_id: None,
     };
 
     create_view_with_request(sdk, request).await
@@ -31,6 +31,7 @@ impl UserServerAPI for UserServerMock {
                 name: params.name,
                 email: params.email,
                 token: user_id,
+                refresh_token: "".to_owned(),
             })
         })
     }
@@ -35,7 +35,10 @@ impl WorkspaceServerAPI for WorkspaceServerMock {
         _params: WorkspaceIdentifier,
     ) -> ResultFuture<RepeatedWorkspace, WorkspaceError> {
         ResultFuture::new(async {
-            let repeated_workspace = RepeatedWorkspace { items: vec![] };
+            let repeated_workspace = RepeatedWorkspace {
+                items: vec![],
+                next_cursor: None,
+            };
             Ok(repeated_workspace)
         })
     }
@@ -60,6 +63,7 @@ impl WorkspaceServerAPI for WorkspaceServerMock {
             belongings: RepeatedView::default(),
             modified_time: time,
             create_time: time,
+            parent_view_id: params.parent_view_id,
         };
         ResultFuture::new(async { Ok(view) })
     }
@@ -84,6 +88,8 @@ impl WorkspaceServerAPI for WorkspaceServerMock {
             name: params.name,
             desc: params.desc,
             belongings: RepeatedView::default(),
+            apps: RepeatedApp::default(),
+            parent_id: params.parent_id,
             version: 0,
             modified_time: time,
             create_time: time,
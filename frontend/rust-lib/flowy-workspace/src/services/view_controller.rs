@@ -146,6 +146,7 @@ impl ViewController {
             thumbnail: "".to_owned(),
             view_type: view.view_type.clone(),
             data: delta_data.data,
+            parent_view_id: view.parent_view_id.clone(),
         };
 
         let _ = self.create_view_from_params(duplicate_params).await?;
@@ -118,6 +118,7 @@ impl WorkspaceController {
         let token = self.user.token()?;
         let repeated_workspace = RepeatedWorkspace {
             items: vec![cloned_workspace],
+            next_cursor: None,
         };
 
         send_dart_notification(&token, WorkspaceNotification::UserCreateWorkspace)
@@ -270,7 +271,10 @@ impl WorkspaceController {
             workspace.apps.items = apps;
             workspaces.push(workspace);
         }
-        Ok(RepeatedWorkspace { items: workspaces })
+        Ok(RepeatedWorkspace {
+            items: workspaces,
+            next_cursor: None,
+        })
     }
 
     fn read_local_workspace(
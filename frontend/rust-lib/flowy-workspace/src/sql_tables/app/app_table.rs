@@ -7,7 +7,7 @@ use flowy_database::schema::app_table;
 
 use crate::{
     entities::{
-        app::{App, ColorStyle, UpdateAppParams},
+        app::{App, ColorStyle, RepeatedApp, UpdateAppParams},
         trash::{Trash, TrashType},
         view::RepeatedView,
     },
@@ -125,6 +125,8 @@ impl std::convert::From<AppTable> for App {
             name: table.name,
             desc: table.desc,
             belongings: RepeatedView::default(),
+            apps: RepeatedApp::default(),
+            parent_id: None,
             version: table.version,
             modified_time: table.modified_time,
             create_time: table.create_time,
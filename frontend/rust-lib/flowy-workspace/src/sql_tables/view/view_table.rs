@@ -66,6 +66,7 @@ impl std::convert::From<ViewTable> for View {
             modified_time: table.modified_time,
             version: table.version,
             create_time: table.create_time,
+            parent_view_id: None,
         }
     }
 }
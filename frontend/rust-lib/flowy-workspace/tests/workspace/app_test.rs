@@ -55,6 +55,7 @@ async fn app_create_with_view() {
         desc: "".to_string(),
         thumbnail: Some("http://1.png".to_string()),
         view_type: ViewType::Doc,
+        parent_view_id: None,
     };
 
     let request_b = CreateViewRequest {
@@ -63,6 +64,7 @@ async fn app_create_with_view() {
         desc: "".to_string(),
         thumbnail: Some("http://1.png".to_string()),
         view_type: ViewType::Doc,
+        parent_view_id: None,
     };
 
     let view_a = create_view_with_request(&test.sdk, request_a).await;
@@ -11,6 +11,17 @@ pub type Result<T> = std::result::Result<T, ServerError>;
 pub struct ServerError {
     pub code: ErrorCode,
     pub msg: String,
+
+    /// Set for [ErrorCode::QuotaExceeded] only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quota: Option<QuotaUsage>,
+}
+
+/// The number of the records that count toward the quota and its limit.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct QuotaUsage {
+    pub count: i64,
+    pub limit: i64,
 }
 
 macro_rules! static_error {
@@ -20,6 +31,7 @@ macro_rules! static_error {
             ServerError {
                 code: $status,
                 msg: format!("{}", $status),
+                quota: None,
             }
         }
     };
@@ -29,16 +41,29 @@ impl ServerError {
     static_error!(internal, ErrorCode::InternalError);
     static_error!(http, ErrorCode::HttpError);
     static_error!(payload_none, ErrorCode::PayloadUnexpectedNone);
+    static_error!(payload_overflow, ErrorCode::PayloadOverflow);
     static_error!(unauthorized, ErrorCode::UserUnauthorized);
+    static_error!(permission_denied, ErrorCode::PermissionDenied);
     static_error!(password_not_match, ErrorCode::PasswordNotMatch);
+    static_error!(user_unverified, ErrorCode::UserUnverified);
     static_error!(params_invalid, ErrorCode::ParamsInvalid);
     static_error!(connect_timeout, ErrorCode::ConnectTimeout);
     static_error!(connect_close, ErrorCode::ConnectClose);
     static_error!(connect_cancel, ErrorCode::ConnectCancel);
     static_error!(connect_refused, ErrorCode::ConnectRefused);
     static_error!(record_not_found, ErrorCode::RecordNotFound);
+    static_error!(request_timeout, ErrorCode::RequestTimeout);
+    static_error!(too_many_requests, ErrorCode::TooManyRequests);
 
-    pub fn new(msg: String, code: ErrorCode) -> Self { Self { code, msg } }
+    pub fn new(msg: String, code: ErrorCode) -> Self { Self { code, msg, quota: None } }
+
+    pub fn quota_exceeded(count: i64, limit: i64) -> Self {
+        Self {
+            code: ErrorCode::QuotaExceeded,
+            msg: format!("count: {}, limit: {}", count, limit),
+            quota: Some(QuotaUsage { count, limit }),
+        }
+    }
 
     pub fn context<T: Debug>(mut self, error: T) -> Self {
         self.msg = format!("{:?}", error);
@@ -48,6 +73,18 @@ impl ServerError {
     pub fn is_record_not_found(&self) -> bool { self.code == ErrorCode::RecordNotFound }
 
     pub fn is_unauthorized(&self) -> bool { self.code == ErrorCode::UserUnauthorized }
+
+    pub fn is_permission_denied(&self) -> bool { self.code == ErrorCode::PermissionDenied }
+
+    pub fn is_quota_exceeded(&self) -> bool { self.code == ErrorCode::QuotaExceeded }
+
+    pub fn is_request_timeout(&self) -> bool { self.code == ErrorCode::RequestTimeout }
+
+    pub fn is_too_many_requests(&self) -> bool { self.code == ErrorCode::TooManyRequests }
+
+    pub fn is_payload_overflow(&self) -> bool { self.code == ErrorCode::PayloadOverflow }
+
+    pub fn is_user_unverified(&self) -> bool { self.code == ErrorCode::UserUnverified }
 }
 
 pub fn internal_error<T>(e: T) -> ServerError
@@ -88,6 +125,8 @@ pub enum ErrorCode {
     PayloadUnexpectedNone = 4,
     #[display(fmt = "Params is invalid")]
     ParamsInvalid      = 5,
+    #[display(fmt = "Permission denied")]
+    PermissionDenied   = 6,
 
     #[display(fmt = "Protobuf serde error")]
     ProtobufError      = 10,
@@ -100,6 +139,12 @@ pub enum ErrorCode {
     #[display(fmt = "Username and password do not match")]
     PasswordNotMatch   = 51,
 
+    #[display(fmt = "Email address is not verified")]
+    UserUnverified     = 52,
+
+    #[display(fmt = "Quota exceeded")]
+    QuotaExceeded      = 60,
+
     #[display(fmt = "Connect refused")]
     ConnectRefused     = 100,
 
@@ -117,6 +162,10 @@ pub enum ErrorCode {
 
     #[display(fmt = "Http request error")]
     HttpError          = 300,
+    #[display(fmt = "Request timeout")]
+    RequestTimeout     = 301,
+    #[display(fmt = "Too many requests")]
+    TooManyRequests    = 302,
 
     #[display(fmt = "Internal error")]
     InternalError      = 1000,
@@ -173,10 +173,21 @@ fn unexpected_empty_payload(url: &str) -> ServerError {
     ServerError::payload_none().context(msg)
 }
 
+// The errors are responded as a ServerError with the status of its code, they
+// are put in a FlowyResponse so the middlewares receive them as before.
 async fn flowy_response_from(original: Response) -> Result<FlowyResponse, ServerError> {
+    let status = original.status();
     let bytes = original.bytes().await?;
-    let response: FlowyResponse = serde_json::from_slice(&bytes)?;
-    Ok(response)
+    if status.is_success() {
+        let response: FlowyResponse = serde_json::from_slice(&bytes)?;
+        return Ok(response);
+    }
+
+    let error = match serde_json::from_slice::<ServerError>(&bytes) {
+        Ok(error) => error,
+        Err(_) => ServerError::http().context(format!("{}: {}", status, String::from_utf8_lossy(&bytes))),
+    };
+    Ok((&error).into())
 }
 
 #[allow(dead_code)]
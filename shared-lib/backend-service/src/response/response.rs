@@ -72,7 +72,7 @@ impl std::convert::From<reqwest::Error> for ServerError {
 
                     if hyper_error.is_timeout() {}
 
-                    ServerError { code, msg }
+                    ServerError::new(msg, code)
                 },
             };
         }
@@ -1,14 +1,37 @@
 use crate::response::*;
-use actix_web::{error::ResponseError, HttpResponse};
+use actix_web::{error::ResponseError, http::StatusCode, HttpResponse};
 
-use crate::errors::ServerError;
+use crate::errors::{ErrorCode, ServerError};
 use actix_web::body::AnyBody;
 
+// The errors are responded as `{ "code": <int>, "msg": <string> }` with the
+// status of their code.
 impl ResponseError for ServerError {
-    fn error_response(&self) -> HttpResponse {
-        let response: FlowyResponse = self.into();
-        response.into()
+    fn status_code(&self) -> StatusCode {
+        match self.code {
+            ErrorCode::ParamsInvalid
+            | ErrorCode::PayloadSerdeFail
+            | ErrorCode::PayloadUnexpectedNone
+            | ErrorCode::ProtobufError
+            | ErrorCode::SerdeError => StatusCode::BAD_REQUEST,
+            ErrorCode::UserUnauthorized | ErrorCode::PasswordNotMatch => StatusCode::UNAUTHORIZED,
+            ErrorCode::PermissionDenied | ErrorCode::UserUnverified | ErrorCode::QuotaExceeded => StatusCode::FORBIDDEN,
+            ErrorCode::RecordNotFound => StatusCode::NOT_FOUND,
+            ErrorCode::EmailAlreadyExists => StatusCode::CONFLICT,
+            ErrorCode::PayloadOverflow => StatusCode::PAYLOAD_TOO_LARGE,
+            ErrorCode::TooManyRequests => StatusCode::TOO_MANY_REQUESTS,
+            ErrorCode::RequestTimeout => StatusCode::GATEWAY_TIMEOUT,
+            ErrorCode::ConnectRefused
+            | ErrorCode::ConnectTimeout
+            | ErrorCode::ConnectClose
+            | ErrorCode::ConnectCancel
+            | ErrorCode::SqlError
+            | ErrorCode::HttpError
+            | ErrorCode::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
     }
+
+    fn error_response(&self) -> HttpResponse { HttpResponse::build(self.status_code()).json(self) }
 }
 impl std::convert::Into<HttpResponse> for FlowyResponse {
     fn into(self) -> HttpResponse { HttpResponse::Ok().json(self) }
@@ -23,6 +23,30 @@ pub async fn user_sign_in_request(params: SignInParams, url: &str) -> Result<Sig
     Ok(response)
 }
 
+pub async fn refresh_token_request(params: RefreshTokenParams, url: &str) -> Result<RefreshTokenResponse, ServerError> {
+    let response = request_builder()
+        .post(&url.to_owned())
+        .protobuf(params)?
+        .response()
+        .await?;
+    Ok(response)
+}
+
+pub async fn reset_password_request(params: ResetPasswordRequestParams, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder().post(&url.to_owned()).protobuf(params)?.send().await?;
+    Ok(())
+}
+
+pub async fn resend_verification_request(params: ResendVerificationParams, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder().post(&url.to_owned()).protobuf(params)?.send().await?;
+    Ok(())
+}
+
+pub async fn reset_password_confirm_request(params: ResetPasswordConfirmParams, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder().post(&url.to_owned()).protobuf(params)?.send().await?;
+    Ok(())
+}
+
 pub async fn user_sign_out_request(token: &str, url: &str) -> Result<(), ServerError> {
     let _ = request_builder()
         .delete(&url.to_owned())
@@ -32,6 +56,15 @@ pub async fn user_sign_out_request(token: &str, url: &str) -> Result<(), ServerE
     Ok(())
 }
 
+pub async fn user_logout_all_request(token: &str, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .send()
+        .await?;
+    Ok(())
+}
+
 pub async fn get_user_profile_request(token: &str, url: &str) -> Result<UserProfile, ServerError> {
     let user_profile = request_builder()
         .get(&url.to_owned())
@@ -34,6 +34,16 @@ pub async fn read_workspaces_request(
     Ok(repeated_workspace)
 }
 
+pub async fn read_workspace_list_request(token: &str, url: &str) -> Result<RepeatedWorkspace, ServerError> {
+    let repeated_workspace = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .response::<RepeatedWorkspace>()
+        .await?;
+
+    Ok(repeated_workspace)
+}
+
 pub async fn update_workspace_request(
     token: &str,
     params: UpdateWorkspaceParams,
@@ -58,6 +68,34 @@ pub async fn delete_workspace_request(token: &str, params: WorkspaceIdentifier,
     Ok(())
 }
 
+pub async fn add_workspace_member_request(
+    token: &str,
+    params: AddWorkspaceMemberParams,
+    url: &str,
+) -> Result<WorkspaceMember, ServerError> {
+    let member = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response::<WorkspaceMember>()
+        .await?;
+    Ok(member)
+}
+
+pub async fn remove_workspace_member_request(
+    token: &str,
+    params: RemoveWorkspaceMemberParams,
+    url: &str,
+) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .delete(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
 // App
 pub async fn create_app_request(token: &str, params: CreateAppParams, url: &str) -> Result<App, ServerError> {
     let app = request_builder()
@@ -111,6 +149,16 @@ pub async fn create_view_request(token: &str, params: CreateViewParams, url: &st
     Ok(view)
 }
 
+pub async fn duplicate_view_request(token: &str, params: ViewIdentifier, url: &str) -> Result<View, ServerError> {
+    let view = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .response()
+        .await?;
+    Ok(view)
+}
+
 pub async fn read_view_request(token: &str, params: ViewIdentifier, url: &str) -> Result<Option<View>, ServerError> {
     let view = request_builder()
         .get(&url.to_owned())
@@ -132,6 +180,64 @@ pub async fn update_view_request(token: &str, params: UpdateViewParams, url: &st
     Ok(())
 }
 
+pub async fn move_view_request(token: &str, params: MoveViewParams, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .patch(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn reorder_view_request(token: &str, params: ReorderViewParams, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .patch(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn touch_view_request(token: &str, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn create_favorite_request(token: &str, params: ViewIdentifier, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn delete_favorite_request(token: &str, params: ViewIdentifier, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .delete(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
+pub async fn read_favorites_request(token: &str, url: &str) -> Result<RepeatedView, ServerError> {
+    let views = request_builder()
+        .get(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .response::<RepeatedView>()
+        .await?;
+    Ok(views)
+}
+
 pub async fn delete_view_request(token: &str, params: ViewIdentifiers, url: &str) -> Result<(), ServerError> {
     let _ = request_builder()
         .delete(&url.to_owned())
@@ -162,6 +268,16 @@ pub async fn delete_trash_request(token: &str, params: TrashIdentifiers, url: &s
     Ok(())
 }
 
+pub async fn restore_trash_request(token: &str, params: TrashIdentifiers, url: &str) -> Result<(), ServerError> {
+    let _ = request_builder()
+        .post(&url.to_owned())
+        .header(HEADER_TOKEN, token)
+        .protobuf(params)?
+        .send()
+        .await?;
+    Ok(())
+}
+
 pub async fn read_trash_request(token: &str, url: &str) -> Result<RepeatedTrash, ServerError> {
     let repeated_trash = request_builder()
         .get(&url.to_owned())
@@ -39,6 +39,9 @@ pub fn category_from_str(type_str: &str) -> TypeCategory {
         | "CreateWorkspaceParams"
         | "Workspace"
         | "RepeatedWorkspace"
+        | "AddWorkspaceMemberParams"
+        | "RemoveWorkspaceMemberParams"
+        | "WorkspaceMember"
         | "QueryWorkspaceRequest"
         | "WorkspaceIdentifier"
         | "TrashIdentifiers"
@@ -47,6 +50,9 @@ pub fn category_from_str(type_str: &str) -> TypeCategory {
         | "RepeatedTrash"
         | "UpdateViewRequest"
         | "UpdateViewParams"
+        | "MoveViewRequest"
+        | "MoveViewParams"
+        | "ReorderViewParams"
         | "QueryViewRequest"
         | "ViewIdentifier"
         | "ViewIdentifiers"
@@ -56,13 +62,25 @@ pub fn category_from_str(type_str: &str) -> TypeCategory {
         | "RepeatedView"
         | "ExportRequest"
         | "ExportData"
+        | "SearchMatch"
+        | "SearchResult"
+        | "RepeatedSearchResult"
+        | "SearchReindexResult"
         | "CreateDocParams"
         | "Doc"
         | "UpdateDocParams"
         | "DocDelta"
         | "NewDocUser"
+        | "DocAwareness"
         | "DocIdentifier"
+        | "DocSession"
+        | "RepeatedDocSession"
+        | "DocStats"
+        | "CreateNamedVersionParams"
+        | "NamedVersion"
+        | "RepeatedNamedVersion"
         | "RevId"
+        | "RevAck"
         | "Revision"
         | "RevisionRange"
         | "WsDocumentData"
@@ -74,6 +92,11 @@ pub fn category_from_str(type_str: &str) -> TypeCategory {
         | "SignUpRequest"
         | "SignUpParams"
         | "SignUpResponse"
+        | "RefreshTokenParams"
+        | "RefreshTokenResponse"
+        | "ResetPasswordRequestParams"
+        | "ResetPasswordConfirmParams"
+        | "ResendVerificationParams"
         | "UserToken"
         | "UserProfile"
         | "UpdateUserRequest"
@@ -86,6 +109,7 @@ pub fn category_from_str(type_str: &str) -> TypeCategory {
         | "UserEvent"
         | "UserNotification"
         | "TrashType"
+        | "WorkspaceRole"
         | "ViewType"
         | "ExportType"
         | "RevType"
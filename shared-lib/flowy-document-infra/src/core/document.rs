@@ -1,5 +1,6 @@
 use crate::{
     core::{
+        extensions::ExtensionRegistry,
         history::{History, UndoResult},
         view::{View, RECORD_THRESHOLD},
     },
@@ -44,6 +45,19 @@ impl Document {
         }
     }
 
+    /// Creates the document whose inserts run the extensions of the registry,
+    /// failing if the registry is strict and its extensions conflict.
+    pub fn from_delta_with_registry(delta: Delta, registry: ExtensionRegistry) -> Result<Self, DocumentError> {
+        let _ = registry.validate()?;
+        Ok(Document {
+            delta,
+            history: History::new(),
+            view: View::from_registry(registry),
+            last_edit_time: 0,
+            notify: None,
+        })
+    }
+
     pub fn from_json(json: &str) -> Result<Self, DocumentError> {
         let delta = Delta::from_json(json)?;
         Ok(Self::from_delta(delta))
@@ -132,19 +146,45 @@ impl Document {
         Ok(format_delta)
     }
 
+    /// Formats the interval like [Document::format], except that the inline
+    /// attribute is removed if all the text in the interval has it already.
+    /// The block attributes are applied as they are, and a collapsed interval
+    /// leaves the document unchanged.
+    pub fn toggle_format(&mut self, interval: Interval, attribute: Attribute) -> Result<Delta, DocumentError> {
+        let _ = validate_interval(&self.delta, &interval)?;
+        if interval.is_empty() {
+            return Ok(Delta::new());
+        }
+
+        match self.view.toggle_format(&self.delta, &attribute, interval) {
+            None => self.format(interval, attribute),
+            Some(format_delta) => {
+                tracing::trace!("👉 receive change: {}", format_delta);
+                self.compose_delta(format_delta.clone())?;
+                Ok(format_delta)
+            },
+        }
+    }
+
+    /// Replaces the text in `interval` with `data`. The selected text is
+    /// deleted first, so the insert extensions run against the document
+    /// without it and the inserted text inherits the attributes at the
+    /// start of the interval.
     pub fn replace<T: ToString>(&mut self, interval: Interval, data: T) -> Result<Delta, DocumentError> {
         let _ = validate_interval(&self.delta, &interval)?;
         let mut delta = Delta::default();
-        let text = data.to_string();
-        if !text.is_empty() {
-            delta = self.view.insert(&self.delta, &text, interval)?;
-            tracing::trace!("👉 receive change: {}", delta);
-            self.compose_delta(delta.clone())?;
+        if !interval.is_empty() {
+            delta = self.delete(interval)?;
         }
 
-        if !interval.is_empty() {
-            let delete = self.delete(interval)?;
-            delta = delta.compose(&delete)?;
+        let text = data.to_string();
+        if !text.is_empty() {
+            let insert = self
+                .view
+                .insert(&self.delta, &text, Interval::new(interval.start, interval.start))?;
+            tracing::trace!("👉 receive change: {}", insert);
+            self.compose_delta(insert.clone())?;
+            delta = delta.compose(&insert)?;
         }
 
         Ok(delta)
@@ -2,7 +2,9 @@ mod format_at_position;
 mod helper;
 mod resolve_block_format;
 mod resolve_inline_format;
+mod toggle_inline_format;
 
 pub use format_at_position::*;
 pub use resolve_block_format::*;
 pub use resolve_inline_format::*;
+pub use toggle_inline_format::*;
@@ -3,6 +3,7 @@ use crate::{
     util::find_newline,
 };
 use lib_ot::core::{Attribute, AttributeScope, Delta, DeltaBuilder, DeltaIter, Interval};
+use std::cmp::min;
 
 pub struct ResolveInlineFormat {}
 impl FormatExt for ResolveInlineFormat {
@@ -18,7 +19,10 @@ impl FormatExt for ResolveInlineFormat {
         let end = interval.size();
 
         while start < end && iter.has_next() {
-            let next_op = iter.next_op_with_len(end - start).unwrap();
+            // Take one op at a time, DeltaIter::next_op_with_len returns the last
+            // op only if the length crosses the ops.
+            let len = min(end - start, iter.next_op_len().unwrap_or(end - start));
+            let next_op = iter.next_op_with_len(len).unwrap();
             match find_newline(next_op.get_data()) {
                 None => new_delta.retain(next_op.len(), attribute.clone().into()),
                 Some(_) => {
@@ -0,0 +1,52 @@
+use crate::{
+    core::extensions::{FormatExt, ResolveInlineFormat},
+    util::is_newline,
+};
+use lib_ot::core::{Attribute, AttributeScope, AttributeValue, Delta, DeltaIter, Interval};
+use std::cmp::min;
+
+/// Toggles the inline attribute over the interval: the attribute is removed if
+/// all the text in the interval has it already, otherwise the whole interval
+/// gets it, even if only some of the text had it. The newlines are skipped as
+/// they don't take the inline attributes. Returns `None` for a collapsed
+/// interval or a block attribute.
+pub struct ToggleInlineFormat {}
+impl FormatExt for ToggleInlineFormat {
+    fn ext_name(&self) -> &str { std::any::type_name::<ToggleInlineFormat>() }
+
+    fn apply(&self, delta: &Delta, interval: Interval, attribute: &Attribute) -> Option<Delta> {
+        if attribute.scope != AttributeScope::Inline || interval.is_empty() {
+            return None;
+        }
+
+        let attribute = match is_formatted(delta, interval, attribute) {
+            true => Attribute {
+                value: AttributeValue(None),
+                ..attribute.clone()
+            },
+            false => attribute.clone(),
+        };
+        ResolveInlineFormat {}.apply(delta, interval, &attribute)
+    }
+}
+
+fn is_formatted(delta: &Delta, interval: Interval, attribute: &Attribute) -> bool {
+    let mut iter = DeltaIter::from_offset(delta, interval.start);
+    let mut start = 0;
+    let end = interval.size();
+    while start < end && iter.has_next() {
+        let len = min(end - start, iter.next_op_len().unwrap_or(end - start));
+        let next_op = match iter.next_op_with_len(len) {
+            None => break,
+            Some(op) => op,
+        };
+        start += next_op.len();
+        if is_newline(next_op.get_data()) {
+            continue;
+        }
+        if next_op.get_attributes().get(&attribute.key) != Some(&attribute.value) {
+            return false;
+        }
+    }
+    true
+}
@@ -0,0 +1,69 @@
+use crate::{
+    core::extensions::InsertExt,
+    util::{is_newline, is_whitespace},
+};
+use lib_ot::core::{count_utf16_code_units, Attribute, AttributeKey, Delta, DeltaBuilder, DeltaIter};
+use std::cmp::min;
+use url::Url;
+
+/// Links the http(s) URL right before the whitespace or the newline being
+/// inserted, e.g. typing a whitespace after "see https://appflowy.io" links
+/// "https://appflowy.io" to the URL parsed from it. The URL linked already is left as it is.
+/// The text itself is inserted by the next extension that applies, see
+/// [InsertExt::is_format_only].
+pub struct AutoFormatLink {}
+impl InsertExt for AutoFormatLink {
+    fn ext_name(&self) -> &str { std::any::type_name::<AutoFormatLink>() }
+
+    fn is_format_only(&self) -> bool { true }
+
+    fn apply(&self, delta: &Delta, _replace_len: usize, text: &str, index: usize) -> Option<Delta> {
+        if !is_whitespace(text) && !is_newline(text) {
+            return None;
+        }
+
+        let (start, len, url) = find_url_before(delta, index)?;
+        Some(
+            DeltaBuilder::new()
+                .retain(start)
+                .retain_with_attributes(len, Attribute::Link(url.as_str()).into())
+                .build(),
+        )
+    }
+}
+
+// Returns the start and the length of the word right before `index` along with
+// the URL parsed from it, if it's an http(s) URL that isn't linked.
+fn find_url_before(delta: &Delta, index: usize) -> Option<(usize, usize, Url)> {
+    let mut iter = DeltaIter::new(delta);
+    let mut offset = 0;
+    let mut word_start = 0;
+    let mut word = String::new();
+    let mut linked = false;
+    while offset < index {
+        let len = min(index - offset, iter.next_op_len()?);
+        let op = iter.next_op_with_len(len)?;
+        let is_link = op.get_attributes().contains_key(&AttributeKey::Link);
+        let data = op.get_data();
+        if op.is_embed() {
+            word.clear();
+            word_start = offset + len;
+            linked = false;
+        } else if let Some(i) = data.rfind(char::is_whitespace) {
+            let split = i + data[i..].chars().next()?.len_utf8();
+            word = data[split..].to_owned();
+            word_start = offset + count_utf16_code_units(&data[..split]);
+            linked = is_link;
+        } else {
+            word.push_str(data);
+            linked |= is_link;
+        }
+        offset += len;
+    }
+
+    if linked || !(word.starts_with("http://") || word.starts_with("https://")) {
+        return None;
+    }
+    let url = Url::parse(&word).ok()?;
+    Some((word_start, count_utf16_code_units(&word), url))
+}
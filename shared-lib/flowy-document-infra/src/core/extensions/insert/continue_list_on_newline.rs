@@ -0,0 +1,81 @@
+use crate::{core::extensions::InsertExt, util::is_newline};
+use lib_ot::core::{
+    attributes_except_header,
+    count_utf16_code_units,
+    AttributeKey,
+    Delta,
+    DeltaBuilder,
+    DeltaIter,
+    Operation,
+    NEW_LINE,
+};
+
+/// Continues the list when a newline is inserted into a list item: the line is
+/// split into two items of the same list. The items of an ordered list are
+/// numbered by their position rather than by an attribute, so the new item
+/// takes the next ordinal, see [list_ordinal_at]. Inserting a newline into an
+/// empty item exits the list instead, see
+/// [AutoExitBlock](super::AutoExitBlock).
+pub struct ContinueListOnNewline {}
+
+impl InsertExt for ContinueListOnNewline {
+    fn ext_name(&self) -> &str { std::any::type_name::<ContinueListOnNewline>() }
+
+    fn apply(&self, delta: &Delta, replace_len: usize, text: &str, index: usize) -> Option<Delta> {
+        if !is_newline(text) {
+            return None;
+        }
+
+        let mut iter = DeltaIter::from_offset(delta, index);
+        let (newline_op, _) = iter.next_op_with_newline()?;
+        newline_op
+            .get_attributes()
+            .get(&AttributeKey::List)
+            .filter(|value| value.0.is_some())?;
+
+        // The new item keeps the indent and the alignment of the split one
+        let block_attributes = attributes_except_header(&newline_op);
+        Some(
+            DeltaBuilder::new()
+                .retain(index + replace_len)
+                .insert_with_attributes(NEW_LINE, block_attributes)
+                .build(),
+        )
+    }
+}
+
+/// Returns the ordinal, starting from 1, of the ordered list item containing
+/// `index` among the consecutive ordered list items, or `None` if the line
+/// isn't an ordered list item.
+pub fn list_ordinal_at(delta: &Delta, index: usize) -> Option<usize> {
+    let mut offset = 0;
+    let mut ordinal = 0;
+    for op in &delta.ops {
+        let insert = match op {
+            Operation::Insert(insert) => insert,
+            _ => {
+                offset += op.len();
+                continue;
+            },
+        };
+        let is_ordered = matches!(
+            insert
+                .attributes
+                .get(&AttributeKey::List)
+                .and_then(|value| value.0.as_deref()),
+            Some("ordered")
+        );
+        for s in insert.s.split_inclusive(NEW_LINE) {
+            offset += count_utf16_code_units(s);
+            if !s.ends_with(NEW_LINE) {
+                continue;
+            }
+
+            ordinal = if is_ordered { ordinal + 1 } else { 0 };
+            if index < offset {
+                return Some(ordinal).filter(|ordinal| *ordinal > 0);
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,65 @@
+use crate::{core::extensions::InsertExt, util::is_whitespace};
+use lib_ot::core::{Attribute, AttributeKey, Delta, DeltaBuilder, DeltaIter, EMBED, NEW_LINE};
+use std::cmp::min;
+
+const MAX_HEADER_LEVEL: usize = 6;
+
+/// Converts the line into a header when a whitespace is typed after the "#"
+/// markers at the start of the line, e.g. typing a whitespace after "##"
+/// deletes the markers and formats the line as header 2. The whitespace
+/// itself isn't inserted. The line that is a header already is left as it is.
+pub struct FormatHeaderOnSpace {}
+impl InsertExt for FormatHeaderOnSpace {
+    fn ext_name(&self) -> &str { std::any::type_name::<FormatHeaderOnSpace>() }
+
+    fn apply(&self, delta: &Delta, replace_len: usize, text: &str, index: usize) -> Option<Delta> {
+        if !is_whitespace(text) {
+            return None;
+        }
+
+        let level = header_markers_before(delta, index)?;
+        let mut iter = DeltaIter::from_offset(delta, index + replace_len);
+        let (newline_op, offset) = iter.next_op_with_newline()?;
+        if newline_op.get_attributes().contains_key(&AttributeKey::Header) {
+            return None;
+        }
+
+        let newline_index = offset + newline_op.get_data().find(NEW_LINE)?;
+        Some(
+            DeltaBuilder::new()
+                .retain(index - level)
+                .delete(level + replace_len)
+                .retain(newline_index)
+                .retain_with_attributes(1, Attribute::Header(level).into())
+                .build(),
+        )
+    }
+}
+
+// Returns the number of the "#" between the start of the line and `index` if
+// there is nothing else and it's a valid header level.
+fn header_markers_before(delta: &Delta, index: usize) -> Option<usize> {
+    let mut iter = DeltaIter::new(delta);
+    let mut offset = 0;
+    let mut line = String::new();
+    while offset < index {
+        let len = min(index - offset, iter.next_op_len()?);
+        let op = iter.next_op_with_len(len)?;
+        if op.is_embed() {
+            line.push_str(EMBED);
+        } else {
+            let data = op.get_data();
+            match data.rfind(NEW_LINE) {
+                None => line.push_str(data),
+                Some(i) => line = data[i + 1..].to_owned(),
+            }
+        }
+        offset += len;
+    }
+
+    let level = line.len();
+    if level == 0 || level > MAX_HEADER_LEVEL || line.chars().any(|c| c != '#') {
+        return None;
+    }
+    Some(level)
+}
@@ -1,15 +1,19 @@
 use crate::core::extensions::InsertExt;
 pub use auto_exit_block::*;
-pub use auto_format::*;
+pub use auto_format_link::*;
+pub use continue_list_on_newline::*;
 pub use default_insert::*;
+pub use format_header_on_space::*;
 use lib_ot::core::Delta;
 pub use preserve_block_format::*;
 pub use preserve_inline_format::*;
 pub use reset_format_on_new_line::*;
 
 mod auto_exit_block;
-mod auto_format;
+mod auto_format_link;
+mod continue_list_on_newline;
 mod default_insert;
+mod format_header_on_space;
 mod preserve_block_format;
 mod preserve_inline_format;
 mod reset_format_on_new_line;
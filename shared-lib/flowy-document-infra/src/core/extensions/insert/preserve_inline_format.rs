@@ -20,6 +20,8 @@ impl InsertExt for PreserveInlineFormat {
         }
 
         let mut attributes = prev.get_attributes();
+        // The text typed after a footnote must not become another footnote.
+        attributes.remove(AttributeKey::Footnote);
         if attributes.is_empty() || !attributes.contains_key(&AttributeKey::Link) {
             return Some(
                 DeltaBuilder::new()
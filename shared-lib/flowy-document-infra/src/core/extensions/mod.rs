@@ -1,12 +1,14 @@
 pub use delete::*;
 pub use format::*;
 pub use insert::*;
+pub use registry::*;
 
 use lib_ot::core::{Attribute, Delta, Interval};
 
 mod delete;
 mod format;
 mod insert;
+mod registry;
 
 pub type InsertExtension = Box<dyn InsertExt + Send + Sync>;
 pub type FormatExtension = Box<dyn FormatExt + Send + Sync>;
@@ -14,6 +16,17 @@ pub type DeleteExtension = Box<dyn DeleteExt + Send + Sync>;
 
 pub trait InsertExt {
     fn ext_name(&self) -> &str;
+
+    /// The text that the extension reacts to, e.g. "\n". The order of the
+    /// extensions claiming the same trigger decides the outcome, see
+    /// [ExtensionRegistry::validate].
+    fn trigger(&self) -> Option<&str> { None }
+
+    /// Whether the extension only formats the text around the insert instead
+    /// of inserting the text, e.g. linking the URL typed before a whitespace.
+    /// Its delta is composed with the delta of the next extension that applies.
+    fn is_format_only(&self) -> bool { false }
+
     fn apply(&self, delta: &Delta, replace_len: usize, text: &str, index: usize) -> Option<Delta>;
 }
 
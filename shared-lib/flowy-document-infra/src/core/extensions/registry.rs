@@ -0,0 +1,232 @@
+use crate::{
+    core::extensions::{
+        AutoExitBlock,
+        AutoFormatLink,
+        ContinueListOnNewline,
+        DefaultInsertAttribute,
+        ForceNewlineForInsertsAroundEmbedExt,
+        FormatHeaderOnSpace,
+        InsertEmbedsExt,
+        InsertExt,
+        InsertExtension,
+        PreserveBlockFormatOnInsert,
+        PreserveInlineFormat,
+        PreserveLineFormatOnSplit,
+        ResetLineFormatOnNewLine,
+    },
+    errors::{DocumentError, DocumentResult},
+};
+use lib_ot::core::{Delta, OperationTransformable};
+use std::collections::HashMap;
+
+/// The extensions that claim the same trigger without an explicit order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtConflict {
+    pub trigger: String,
+    pub ext_names: Vec<String>,
+}
+
+/// Holds the insert extensions of a document. The registered extensions run
+/// before the built-in ones, the higher priority first and the same priority
+/// in the order of registration, and the first one that returns `Some` wins.
+/// Extensions claiming the same trigger with the same priority therefore
+/// depend on the order silently, so [ExtensionRegistry::validate] surfaces
+/// them unless their order is resolved by [ExtensionRegistry::order].
+pub struct ExtensionRegistry {
+    insert_exts: Vec<(i32, InsertExtension)>,
+    built_in_insert_exts: Vec<InsertExtension>,
+    orders: HashMap<String, Vec<String>>,
+    strict: bool,
+}
+
+impl std::default::Default for ExtensionRegistry {
+    fn default() -> Self {
+        Self {
+            insert_exts: vec![],
+            built_in_insert_exts: construct_insert_exts(),
+            orders: HashMap::new(),
+            strict: false,
+        }
+    }
+}
+
+impl ExtensionRegistry {
+    pub fn new() -> Self { ExtensionRegistry::default() }
+
+    /// Fails the validation on conflicts instead of warning.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    pub fn register(&mut self, ext: InsertExtension, priority: i32) {
+        let index = self
+            .insert_exts
+            .iter()
+            .position(|(other, _)| *other < priority)
+            .unwrap_or_else(|| self.insert_exts.len());
+        self.insert_exts.insert(index, (priority, ext));
+    }
+
+    /// Resolves the conflict of the extensions claiming the `trigger` by
+    /// running them in the order of `ext_names`.
+    pub fn order(&mut self, trigger: &str, ext_names: Vec<&str>) {
+        let ext_names = ext_names.into_iter().map(|name| name.to_owned()).collect();
+        self.orders.insert(trigger.to_owned(), ext_names);
+    }
+
+    pub fn conflicts(&self) -> Vec<ExtConflict> {
+        let built_in_insert_exts = self.built_in_insert_exts.iter().map(|ext| (None, ext));
+        let insert_exts = self
+            .insert_exts
+            .iter()
+            .map(|(priority, ext)| (Some(*priority), ext))
+            .chain(built_in_insert_exts);
+
+        let mut claims: Vec<(String, Option<i32>, Vec<String>)> = vec![];
+        for (priority, ext) in insert_exts {
+            if let Some(trigger) = ext.trigger() {
+                match claims
+                    .iter_mut()
+                    .find(|(claimed, claimed_priority, _)| claimed == trigger && *claimed_priority == priority)
+                {
+                    None => claims.push((trigger.to_owned(), priority, vec![ext.ext_name().to_owned()])),
+                    Some((_, _, ext_names)) => ext_names.push(ext.ext_name().to_owned()),
+                }
+            }
+        }
+
+        claims
+            .into_iter()
+            .map(|(trigger, _, ext_names)| (trigger, ext_names))
+            .filter(|(trigger, ext_names)| {
+                let is_resolved = match self.orders.get(trigger) {
+                    None => false,
+                    Some(order) => ext_names.iter().all(|name| order.contains(name)),
+                };
+                ext_names.len() > 1 && !is_resolved
+            })
+            .map(|(trigger, ext_names)| ExtConflict { trigger, ext_names })
+            .collect()
+    }
+
+    /// Warns on each conflict, or fails with the first one in strict mode.
+    pub fn validate(&self) -> DocumentResult<()> {
+        for conflict in self.conflicts() {
+            if self.strict {
+                return Err(DocumentError::extension_conflict().context(conflict));
+            }
+            tracing::warn!(
+                "{:?} claim the same trigger {:?}, the first registered one wins",
+                conflict.ext_names,
+                conflict.trigger
+            );
+        }
+        Ok(())
+    }
+
+    /// Runs the extensions in order and returns the delta of the first one
+    /// that applies to the insert.
+    pub fn apply_insert(&self, delta: &Delta, replace_len: usize, text: &str, index: usize) -> Option<Delta> {
+        let mut insert_exts = self
+            .insert_exts
+            .iter()
+            .map(|(_, ext)| ext)
+            .chain(self.built_in_insert_exts.iter())
+            .collect::<Vec<&InsertExtension>>();
+        apply_orders(&self.orders, &mut insert_exts);
+        apply_insert_exts(&insert_exts, delta, replace_len, text, index)
+    }
+
+    pub(crate) fn into_insert_exts(self) -> Vec<InsertExtension> {
+        let ExtensionRegistry {
+            insert_exts,
+            built_in_insert_exts,
+            orders,
+            ..
+        } = self;
+        let mut insert_exts = insert_exts
+            .into_iter()
+            .map(|(_, ext)| ext)
+            .chain(built_in_insert_exts)
+            .collect::<Vec<InsertExtension>>();
+        apply_orders(&orders, &mut insert_exts);
+        insert_exts
+    }
+}
+
+fn apply_orders<T: AsRef<dyn InsertExt + Send + Sync>>(
+    orders: &HashMap<String, Vec<String>>,
+    insert_exts: &mut Vec<T>,
+) {
+    for (trigger, order) in orders {
+        let rank = |ext: &T| order.iter().position(|name| name == ext.as_ref().ext_name());
+        let indexes = insert_exts
+            .iter()
+            .enumerate()
+            .filter(|(_, ext)| ext.as_ref().trigger() == Some(trigger.as_str()) && rank(ext).is_some())
+            .map(|(index, _)| index)
+            .collect::<Vec<usize>>();
+
+        // Takes the ordered extensions out and puts them back sorted into the same
+        // slots, so the others keep their positions.
+        let mut ordered_exts = indexes
+            .iter()
+            .rev()
+            .map(|index| insert_exts.remove(*index))
+            .collect::<Vec<T>>();
+        ordered_exts.sort_by_key(|ext| rank(ext));
+        for (index, ext) in indexes.into_iter().zip(ordered_exts) {
+            insert_exts.insert(index, ext);
+        }
+    }
+}
+
+/// Returns the delta of the first extension that inserts the text, composed
+/// with the deltas of the format only extensions applied before it.
+pub(crate) fn apply_insert_exts<T: AsRef<dyn InsertExt + Send + Sync>>(
+    insert_exts: &[T],
+    delta: &Delta,
+    replace_len: usize,
+    text: &str,
+    index: usize,
+) -> Option<Delta> {
+    let mut format_delta: Option<Delta> = None;
+    for ext in insert_exts {
+        let ext = ext.as_ref();
+        let new_delta = match ext.apply(delta, replace_len, text, index) {
+            None => continue,
+            Some(new_delta) => new_delta,
+        };
+        tracing::trace!("[{}]: applied, delta: {}", ext.ext_name(), new_delta);
+        if ext.is_format_only() {
+            format_delta = match format_delta {
+                None => Some(new_delta),
+                Some(format_delta) => Some(format_delta.compose(&new_delta).ok()?),
+            };
+            continue;
+        }
+
+        return match format_delta {
+            None => Some(new_delta),
+            Some(format_delta) => format_delta.compose(&new_delta).ok(),
+        };
+    }
+    None
+}
+
+fn construct_insert_exts() -> Vec<InsertExtension> {
+    vec![
+        Box::new(InsertEmbedsExt {}),
+        Box::new(ForceNewlineForInsertsAroundEmbedExt {}),
+        Box::new(AutoExitBlock {}),
+        Box::new(AutoFormatLink {}),
+        Box::new(FormatHeaderOnSpace {}),
+        Box::new(ContinueListOnNewline {}),
+        Box::new(PreserveBlockFormatOnInsert {}),
+        Box::new(PreserveLineFormatOnSplit {}),
+        Box::new(ResetLineFormatOnNewLine {}),
+        Box::new(PreserveInlineFormat {}),
+        Box::new(DefaultInsertAttribute {}),
+    ]
+}
@@ -0,0 +1,24 @@
+use lib_ot::core::Footnote;
+
+/// Collects the footnotes referenced while rendering a document, so their
+/// definitions can be emitted after it in the order of the first reference.
+#[derive(Default)]
+pub(crate) struct FootnoteCollector {
+    footnotes: Vec<Footnote>,
+}
+
+impl FootnoteCollector {
+    /// Returns the 1-based number of the footnote. A footnote referenced more
+    /// than once keeps the number of its first reference.
+    pub(crate) fn reference(&mut self, footnote: Footnote) -> usize {
+        match self.footnotes.iter().position(|exist| exist.id == footnote.id) {
+            Some(index) => index + 1,
+            None => {
+                self.footnotes.push(footnote);
+                self.footnotes.len()
+            },
+        }
+    }
+
+    pub(crate) fn into_footnotes(self) -> Vec<Footnote> { self.footnotes }
+}
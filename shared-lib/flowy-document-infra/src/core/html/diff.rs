@@ -0,0 +1,50 @@
+use crate::core::html::{render_html, DiffMark};
+use lib_ot::core::{diff_slices, Attributes, Delta, DiffEdit, Operation};
+
+/// Renders the document `to` as HTML with the changes from the document `from`
+/// marked: the inserted text is wrapped by `<ins>`, the deleted text is kept
+/// in place and wrapped by `<del>`, and the text whose format changed only is
+/// wrapped by `<span class="format-changed">`. The characters are diffed by
+/// the Myers diff of lib-ot.
+pub fn diff_to_html(from: &Delta, to: &Delta) -> String {
+    let from_chars = chars(from);
+    let to_chars = chars(to);
+
+    let mut runs: Vec<(String, Attributes, Option<DiffMark>)> = vec![];
+    let from_text = from_chars.iter().map(|(c, _)| *c).collect::<Vec<char>>();
+    let to_text = to_chars.iter().map(|(c, _)| *c).collect::<Vec<char>>();
+    for edit in diff_slices(&from_text, &to_text) {
+        let (c, attributes, mark) = match edit {
+            DiffEdit::Equal(from_index, to_index) => {
+                let (c, attributes) = to_chars[to_index];
+                match from_chars[from_index].1 == attributes {
+                    true => (c, attributes, None),
+                    false => (c, attributes, Some(DiffMark::Formatted)),
+                }
+            },
+            DiffEdit::Delete(from_index) => (
+                from_chars[from_index].0,
+                from_chars[from_index].1,
+                Some(DiffMark::Deleted),
+            ),
+            DiffEdit::Insert(to_index) => (to_chars[to_index].0, to_chars[to_index].1, Some(DiffMark::Inserted)),
+        };
+        match runs.last_mut() {
+            Some((s, last_attributes, last_mark)) if last_attributes == attributes && *last_mark == mark => s.push(c),
+            _ => runs.push((c.to_string(), attributes.clone(), mark)),
+        }
+    }
+    render_html(runs.iter().map(|(s, attributes, mark)| (s.as_str(), attributes, *mark)))
+}
+
+fn chars(delta: &Delta) -> Vec<(char, &Attributes)> {
+    delta
+        .ops
+        .iter()
+        .filter_map(|op| match op {
+            Operation::Insert(insert) => Some(insert),
+            _ => None,
+        })
+        .flat_map(|insert| insert.s.chars().map(move |c| (c, &insert.attributes)))
+        .collect()
+}
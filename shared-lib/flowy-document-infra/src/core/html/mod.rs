@@ -0,0 +1,69 @@
+mod diff;
+mod parser;
+mod render;
+mod standalone;
+
+pub use diff::*;
+pub use parser::*;
+pub use render::*;
+pub use standalone::*;
+
+pub(crate) fn escape_html(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// Only these link schemes survive the import and the export, so a `javascript:`
+// href can't sneak into the rendered html.
+pub(crate) fn is_safe_href(href: &str) -> bool {
+    let href = href.trim_start().to_ascii_lowercase();
+    href.starts_with('#')
+        || ["http:", "https:", "mailto:"]
+            .iter()
+            .any(|scheme| href.starts_with(scheme))
+}
+
+pub(crate) fn unescape_html(s: &str) -> String {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest.find(';').filter(|end| *end <= 10).map(|end| &rest[1..end]);
+        let c = match entity {
+            Some("amp") => Some('&'),
+            Some("lt") => Some('<'),
+            Some("gt") => Some('>'),
+            Some("quot") => Some('"'),
+            Some("apos") => Some('\''),
+            Some("nbsp") => Some('\u{a0}'),
+            Some(code) if code.starts_with("#x") || code.starts_with("#X") => {
+                u32::from_str_radix(&code[2..], 16).ok().and_then(std::char::from_u32)
+            },
+            Some(code) if code.starts_with('#') => code[1..].parse::<u32>().ok().and_then(std::char::from_u32),
+            _ => None,
+        };
+        match (c, entity) {
+            (Some(c), Some(entity)) => {
+                unescaped.push(c);
+                rest = &rest[entity.len() + 2..];
+            },
+            _ => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            },
+        }
+    }
+    unescaped.push_str(rest);
+    unescaped
+}
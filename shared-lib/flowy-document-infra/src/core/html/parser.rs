@@ -0,0 +1,334 @@
+use crate::core::html::{is_safe_href, unescape_html};
+use lib_ot::{
+    core::{plain_attributes, Attribute, Attributes, Delta, NEW_LINE},
+    errors::OTError,
+};
+
+/// Converts a subset of HTML into a delta. Headings, paragraphs, lists, links,
+/// code blocks and the common inline formatting tags are mapped to their
+/// attributes; any other tag is dropped and only its text content is kept.
+pub fn html_to_delta(html: &str) -> Result<Delta, OTError> {
+    let mut converter = HtmlConverter::default();
+    for token in tokenize(html) {
+        match token {
+            HtmlToken::Text(text) => converter.text(&unescape_html(text)),
+            HtmlToken::Open {
+                name,
+                attrs,
+                self_closing,
+            } => {
+                converter.open(&name, attrs);
+                if self_closing {
+                    converter.close(&name);
+                }
+            },
+            HtmlToken::Close(name) => converter.close(&name),
+        }
+    }
+    Ok(converter.finish())
+}
+
+enum HtmlToken<'a> {
+    Text(&'a str),
+    Open {
+        name: String,
+        attrs: &'a str,
+        self_closing: bool,
+    },
+    Close(String),
+}
+
+fn tokenize(html: &str) -> Vec<HtmlToken> {
+    let mut tokens = vec![];
+    let mut rest = html;
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = match comment.find("-->") {
+                None => "",
+                Some(end) => &comment[end + 3..],
+            };
+            continue;
+        }
+
+        let tag = rest
+            .strip_prefix('<')
+            .filter(|s| s.starts_with(|c: char| c.is_ascii_alphabetic() || c == '/' || c == '!'))
+            .and_then(|s| tag_end(s).map(|end| &s[..end]));
+
+        match tag {
+            None => {
+                let first_len = rest.chars().next().map_or(0, char::len_utf8);
+                let end = rest[first_len..]
+                    .find('<')
+                    .map(|end| end + first_len)
+                    .unwrap_or_else(|| rest.len());
+                tokens.push(HtmlToken::Text(&rest[..end]));
+                rest = &rest[end..];
+            },
+            Some(tag) => {
+                rest = &rest[tag.len() + 2..];
+                if tag.starts_with('!') {
+                    continue;
+                }
+                if let Some(name) = tag.strip_prefix('/') {
+                    tokens.push(HtmlToken::Close(name.trim().to_lowercase()));
+                    continue;
+                }
+                let self_closing = tag.ends_with('/');
+                let tag = tag.trim_end_matches('/');
+                let name_end = tag.find(|c: char| c.is_whitespace()).unwrap_or_else(|| tag.len());
+                tokens.push(HtmlToken::Open {
+                    name: tag[..name_end].to_lowercase(),
+                    attrs: &tag[name_end..],
+                    self_closing,
+                });
+            },
+        }
+    }
+    tokens
+}
+
+// Finds the `>` that closes the tag, skipping the ones inside quoted attribute
+// values.
+fn tag_end(s: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (None, '"') | (None, '\'') => quote = Some(c),
+            (None, '>') => return Some(i),
+            (Some(q), c) if q == c => quote = None,
+            _ => {},
+        }
+    }
+    None
+}
+
+fn attr_value(attrs: &str, name: &str) -> Option<String> {
+    let lower = attrs.to_ascii_lowercase();
+    let mut offset = 0;
+    while let Some(pos) = lower[offset..].find(name) {
+        let start = offset + pos;
+        offset = start + name.len();
+        let preceded_by_space = start == 0 || lower[..start].ends_with(char::is_whitespace);
+        let rest = lower[offset..].trim_start();
+        if !preceded_by_space || !rest.starts_with('=') {
+            continue;
+        }
+
+        let value = attrs[attrs.len() - rest.len() + 1..].trim_start();
+        let value = match value.chars().next() {
+            Some(quote @ '"') | Some(quote @ '\'') => {
+                let value = &value[1..];
+                &value[..value.find(quote).unwrap_or_else(|| value.len())]
+            },
+            _ => &value[..value.find(char::is_whitespace).unwrap_or_else(|| value.len())],
+        };
+        return Some(unescape_html(value));
+    }
+    None
+}
+
+fn header_level(name: &str) -> Option<usize> {
+    match name.as_bytes() {
+        [b'h', level @ b'1'..=b'6'] => Some((level - b'0') as usize),
+        _ => None,
+    }
+}
+
+fn is_block_tag(name: &str) -> bool {
+    header_level(name).is_some()
+        || matches!(
+            name,
+            "p" | "div" | "li" | "pre" | "blockquote" | "ul" | "ol" | "section" | "article" | "table" | "tr"
+        )
+}
+
+#[derive(Default)]
+struct HtmlConverter {
+    delta: Delta,
+    line: Vec<(String, Attributes)>,
+    inline_attributes: Vec<(String, Attribute)>,
+    block_tags: Vec<(String, Option<Attribute>)>,
+    lists: Vec<&'static str>,
+    in_pre: bool,
+    skip_depth: usize,
+}
+
+impl HtmlConverter {
+    fn open(&mut self, name: &str, attrs: &str) {
+        if self.skip_depth > 0 || matches!(name, "script" | "style" | "head" | "title") {
+            self.skip_depth += 1;
+            return;
+        }
+
+        if name == "br" {
+            self.flush_line(true);
+            return;
+        }
+
+        if is_block_tag(name) {
+            self.flush_line(false);
+            let block_attribute = match name {
+                "ul" => {
+                    self.lists.push("bullet");
+                    None
+                },
+                "ol" => {
+                    self.lists.push("ordered");
+                    None
+                },
+                "li" => Some(Attribute::List(self.lists.last().cloned().unwrap_or("bullet"))),
+                "pre" => {
+                    self.in_pre = true;
+                    Some(Attribute::CodeBlock(true))
+                },
+                "blockquote" => Some(Attribute::BlockQuote(true)),
+                _ => header_level(name).map(Attribute::Header),
+            };
+            self.block_tags.push((name.to_owned(), block_attribute));
+            return;
+        }
+
+        let inline_attribute = match name {
+            "strong" | "b" => Some(Attribute::Bold(true)),
+            "em" | "i" => Some(Attribute::Italic(true)),
+            "u" => Some(Attribute::Underline(true)),
+            "s" | "strike" | "del" => Some(Attribute::StrikeThrough(true)),
+            "code" if !self.in_pre => Some(Attribute::InlineCode(true)),
+            "a" => attr_value(attrs, "href")
+                .filter(|href| is_safe_href(href))
+                .map(|href| Attribute::Link(&href)),
+            _ => None,
+        };
+        if let Some(attribute) = inline_attribute {
+            self.inline_attributes.push((name.to_owned(), attribute));
+        }
+    }
+
+    fn close(&mut self, name: &str) {
+        if self.skip_depth > 0 {
+            if matches!(name, "script" | "style" | "head" | "title") {
+                self.skip_depth -= 1;
+            }
+            return;
+        }
+
+        if is_block_tag(name) {
+            self.flush_line(false);
+            if let Some(pos) = self.block_tags.iter().rposition(|(tag, _)| tag == name) {
+                self.block_tags.truncate(pos);
+            }
+            match name {
+                "ul" | "ol" => {
+                    let _ = self.lists.pop();
+                },
+                "pre" => self.in_pre = false,
+                _ => {},
+            }
+            return;
+        }
+
+        if let Some(pos) = self.inline_attributes.iter().rposition(|(tag, _)| tag == name) {
+            self.inline_attributes.remove(pos);
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.skip_depth > 0 {
+            return;
+        }
+
+        if self.in_pre {
+            let mut lines = text.split(NEW_LINE).peekable();
+            while let Some(line) = lines.next() {
+                self.push_text(line.to_owned());
+                if lines.peek().is_some() {
+                    self.flush_line(true);
+                }
+            }
+            return;
+        }
+
+        let mut collapsed = String::with_capacity(text.len());
+        for c in text.chars() {
+            if c.is_whitespace() && c != '\u{a0}' {
+                let at_line_start = collapsed.is_empty() && self.line_is_empty();
+                if !at_line_start && !collapsed.ends_with(' ') && !self.line_ends_with_space(&collapsed) {
+                    collapsed.push(' ');
+                }
+            } else {
+                collapsed.push(c);
+            }
+        }
+        self.push_text(collapsed);
+    }
+
+    fn push_text(&mut self, text: String) {
+        if text.is_empty() {
+            return;
+        }
+        let attributes = self
+            .inline_attributes
+            .iter()
+            .fold(plain_attributes(), |mut attributes, (_, attribute)| {
+                attributes.add(attribute.clone());
+                attributes
+            });
+        match self.line.last_mut() {
+            Some((last, last_attributes)) if last_attributes == &attributes => last.push_str(&text),
+            _ => self.line.push((text, attributes)),
+        }
+    }
+
+    fn line_is_empty(&self) -> bool { self.line.iter().all(|(text, _)| text.is_empty()) }
+
+    fn line_ends_with_space(&self, pending: &str) -> bool {
+        pending.is_empty() && self.line.last().map(|(text, _)| text.ends_with(' ')).unwrap_or(false)
+    }
+
+    fn block_attributes(&self) -> Attributes {
+        self.block_tags
+            .iter()
+            .filter_map(|(_, attribute)| attribute.clone())
+            .fold(plain_attributes(), |mut attributes, attribute| {
+                attributes.add(attribute);
+                attributes
+            })
+    }
+
+    /// Writes the pending line into the delta followed by a newline carrying
+    /// the block attributes. An empty line is only written when `force` is set.
+    fn flush_line(&mut self, force: bool) {
+        if !self.in_pre {
+            if let Some((last, _)) = self.line.last_mut() {
+                let trimmed_len = last.trim_end_matches(' ').len();
+                last.truncate(trimmed_len);
+            }
+        }
+
+        if self.line_is_empty() && !force {
+            self.line.clear();
+            return;
+        }
+
+        for (text, attributes) in self.line.drain(..) {
+            self.delta.insert(&text, attributes);
+        }
+        let block_attributes = self.block_attributes();
+        self.delta.insert(NEW_LINE, block_attributes);
+    }
+
+    fn finish(mut self) -> Delta {
+        self.flush_line(false);
+        if !self
+            .delta
+            .ops
+            .last()
+            .map(|op| op.get_data().ends_with(NEW_LINE))
+            .unwrap_or(false)
+        {
+            self.delta.insert(NEW_LINE, plain_attributes());
+        }
+        self.delta
+    }
+}
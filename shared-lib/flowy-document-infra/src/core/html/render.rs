@@ -0,0 +1,215 @@
+use crate::core::{
+    footnote::FootnoteCollector,
+    html::{escape_html, is_safe_href},
+};
+use lib_ot::core::{AttributeKey, Attributes, Delta, Footnote, Operation, NEW_LINE};
+
+/// Renders the inserts of a delta as HTML. Each line becomes a block element
+/// chosen by the attributes of its trailing newline; consecutive list items and
+/// code block lines are grouped into a single `<ul>`/`<ol>` or `<pre>` element.
+/// Footnotes render as numbered superscript links to a notes section appended
+/// after the document.
+pub fn delta_to_html(delta: &Delta) -> String {
+    let runs = delta.ops.iter().filter_map(|op| match op {
+        Operation::Insert(insert) => Some((insert.s.as_str(), &insert.attributes, None)),
+        _ => None,
+    });
+    render_html(runs)
+}
+
+/// Marks the changed text of the diff rendered by
+/// [diff_to_html](crate::core::diff_to_html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DiffMark {
+    Inserted,
+    Deleted,
+    Formatted,
+}
+
+impl DiffMark {
+    fn wrap(&self, html: String) -> String {
+        match self {
+            DiffMark::Inserted => format!("<ins>{}</ins>", html),
+            DiffMark::Deleted => format!("<del>{}</del>", html),
+            DiffMark::Formatted => format!("<span class=\"format-changed\">{}</span>", html),
+        }
+    }
+}
+
+/// Renders the runs of text, each formatted by its attributes and wrapped by
+/// its mark if any.
+pub(crate) fn render_html<'a, I>(runs: I) -> String
+where
+    I: Iterator<Item = (&'a str, &'a Attributes, Option<DiffMark>)>,
+{
+    let mut html = String::new();
+    let mut group: Option<BlockGroup> = None;
+    let mut collector = FootnoteCollector::default();
+    for (content, block_attributes, block_mark) in lines(runs, &mut collector) {
+        // Only the format of the block can change, the newline itself is invisible.
+        let content = match block_mark {
+            Some(DiffMark::Formatted) if !content.is_empty() => DiffMark::Formatted.wrap(content),
+            _ => content,
+        };
+        let next_group = BlockGroup::from_attributes(&block_attributes);
+        if group != next_group {
+            if let Some(group) = group.take() {
+                html.push_str(group.close_tag());
+            }
+            if let Some(next_group) = &next_group {
+                html.push_str(next_group.open_tag());
+            }
+            group = next_group;
+        } else if group == Some(BlockGroup::CodeBlock) {
+            html.push_str(NEW_LINE);
+        }
+
+        match group {
+            Some(BlockGroup::CodeBlock) => html.push_str(&content),
+            Some(_) => html.push_str(&format!("<li>{}</li>", content)),
+            None => {
+                let content = if content.is_empty() { "<br>".to_owned() } else { content };
+                match value_of(&block_attributes, &AttributeKey::Header) {
+                    Some(level) => html.push_str(&format!("<h{level}>{}</h{level}>", content, level = level)),
+                    None if value_of(&block_attributes, &AttributeKey::BlockQuote).is_some() => {
+                        html.push_str(&format!("<blockquote>{}</blockquote>", content))
+                    },
+                    None => html.push_str(&format!("<p>{}</p>", content)),
+                }
+            },
+        }
+    }
+    if let Some(group) = group {
+        html.push_str(group.close_tag());
+    }
+
+    let footnotes = collector.into_footnotes();
+    if !footnotes.is_empty() {
+        html.push_str("<section class=\"footnotes\"><ol>");
+        for footnote in footnotes {
+            let id = escape_html(&footnote.id);
+            html.push_str(&format!(
+                "<li id=\"fn-{id}\">{} <a href=\"#fnref-{id}\">&#8617;</a></li>",
+                escape_html(&footnote.text),
+                id = id
+            ));
+        }
+        html.push_str("</ol></section>");
+    }
+    html
+}
+
+#[derive(PartialEq)]
+enum BlockGroup {
+    Bullet,
+    Ordered,
+    CodeBlock,
+}
+
+impl BlockGroup {
+    fn from_attributes(attributes: &Attributes) -> Option<Self> {
+        if value_of(attributes, &AttributeKey::CodeBlock).is_some() {
+            return Some(BlockGroup::CodeBlock);
+        }
+        match value_of(attributes, &AttributeKey::List) {
+            Some("ordered") => Some(BlockGroup::Ordered),
+            Some(_) => Some(BlockGroup::Bullet),
+            None => None,
+        }
+    }
+
+    fn open_tag(&self) -> &'static str {
+        match self {
+            BlockGroup::Bullet => "<ul>",
+            BlockGroup::Ordered => "<ol>",
+            BlockGroup::CodeBlock => "<pre><code>",
+        }
+    }
+
+    fn close_tag(&self) -> &'static str {
+        match self {
+            BlockGroup::Bullet => "</ul>",
+            BlockGroup::Ordered => "</ol>",
+            BlockGroup::CodeBlock => "</code></pre>",
+        }
+    }
+}
+
+fn value_of<'a>(attributes: &'a Attributes, key: &AttributeKey) -> Option<&'a str> {
+    attributes.get(key).and_then(|value| value.0.as_deref())
+}
+
+/// Splits the runs into lines of rendered inline HTML paired with the block
+/// attributes and the mark of the newline that ends them.
+fn lines<'a, I>(runs: I, collector: &mut FootnoteCollector) -> Vec<(String, Attributes, Option<DiffMark>)>
+where
+    I: Iterator<Item = (&'a str, &'a Attributes, Option<DiffMark>)>,
+{
+    let mut lines = vec![];
+    let mut content = String::new();
+    for (s, attributes, mark) in runs {
+        if let Some(footnote) = Footnote::from_attributes(attributes) {
+            // Adjacent references to the same footnote are merged into one insert.
+            for _ in s.chars() {
+                let html = render_footnote_ref(footnote.clone(), collector);
+                content.push_str(&mark_inline(html, mark));
+            }
+            continue;
+        }
+
+        let mut segments = s.split(NEW_LINE).peekable();
+        while let Some(segment) = segments.next() {
+            content.push_str(&mark_inline(render_inline(segment, attributes), mark));
+            if segments.peek().is_some() {
+                lines.push((std::mem::take(&mut content), attributes.clone(), mark));
+            }
+        }
+    }
+    if !content.is_empty() {
+        lines.push((content, Attributes::default(), None));
+    }
+    lines
+}
+
+fn mark_inline(html: String, mark: Option<DiffMark>) -> String {
+    match mark {
+        Some(mark) if !html.is_empty() => mark.wrap(html),
+        _ => html,
+    }
+}
+
+fn render_footnote_ref(footnote: Footnote, collector: &mut FootnoteCollector) -> String {
+    let id = escape_html(&footnote.id);
+    let number = collector.reference(footnote);
+    format!(
+        "<sup><a href=\"#fn-{id}\" id=\"fnref-{id}\">{}</a></sup>",
+        number,
+        id = id
+    )
+}
+
+fn render_inline(text: &str, attributes: &Attributes) -> String {
+    if text.is_empty() {
+        return String::new();
+    }
+    let mut html = escape_html(text);
+    if value_of(attributes, &AttributeKey::InlineCode).is_some() {
+        html = format!("<code>{}</code>", html);
+    }
+    if value_of(attributes, &AttributeKey::StrikeThrough).is_some() {
+        html = format!("<s>{}</s>", html);
+    }
+    if value_of(attributes, &AttributeKey::Underline).is_some() {
+        html = format!("<u>{}</u>", html);
+    }
+    if value_of(attributes, &AttributeKey::Italic).is_some() {
+        html = format!("<em>{}</em>", html);
+    }
+    if value_of(attributes, &AttributeKey::Bold).is_some() {
+        html = format!("<strong>{}</strong>", html);
+    }
+    if let Some(href) = value_of(attributes, &AttributeKey::Link).filter(|href| is_safe_href(href)) {
+        html = format!("<a href=\"{}\">{}</a>", escape_html(href), html);
+    }
+    html
+}
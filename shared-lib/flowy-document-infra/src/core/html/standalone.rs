@@ -0,0 +1,79 @@
+use crate::core::html::{delta_to_html, escape_html};
+use lib_ot::core::Delta;
+
+const BODY_STYLE: &str =
+    "margin: 0 auto; max-width: 800px; padding: 32px; font-family: Helvetica, Arial, sans-serif; font-size: 14px; \
+     line-height: 1.5; color: #333333;";
+
+// The inline styles of each tag rendered by delta_to_html. The rendered text is
+// escaped, so every '<' in the body starts one of these tags.
+const TAG_STYLES: &[(&str, &str)] = &[
+    ("p", "margin: 0 0 8px 0;"),
+    ("h1", "margin: 0 0 8px 0; font-size: 2em; font-weight: bold;"),
+    ("h2", "margin: 0 0 8px 0; font-size: 1.5em; font-weight: bold;"),
+    ("h3", "margin: 0 0 8px 0; font-size: 1.17em; font-weight: bold;"),
+    ("h4", "margin: 0 0 8px 0; font-size: 1em; font-weight: bold;"),
+    ("h5", "margin: 0 0 8px 0; font-size: 0.83em; font-weight: bold;"),
+    ("h6", "margin: 0 0 8px 0; font-size: 0.67em; font-weight: bold;"),
+    (
+        "blockquote",
+        "margin: 0 0 8px 0; padding-left: 16px; border-left: 4px solid #cccccc; color: #666666;",
+    ),
+    ("ul", "margin: 0 0 8px 0; padding-left: 24px; list-style-type: disc;"),
+    ("ol", "margin: 0 0 8px 0; padding-left: 24px; list-style-type: decimal;"),
+    ("li", "margin: 0;"),
+    (
+        "pre",
+        "margin: 0 0 8px 0; padding: 8px; background-color: #f5f5f5; white-space: pre-wrap;",
+    ),
+    (
+        "code",
+        "font-family: Menlo, Consolas, monospace; background-color: #f5f5f5;",
+    ),
+    ("strong", "font-weight: bold;"),
+    ("em", "font-style: italic;"),
+    ("u", "text-decoration: underline;"),
+    ("s", "text-decoration: line-through;"),
+    ("a", "color: #00b5ff; text-decoration: underline;"),
+    ("sup", "font-size: 0.75em; line-height: 0;"),
+    (
+        "section",
+        "margin-top: 16px; padding-top: 8px; border-top: 1px solid #cccccc; font-size: 0.9em;",
+    ),
+];
+
+/// Renders the delta as a complete HTML document titled `title`. Unlike
+/// [delta_to_html], each element carries its style inline, so the document
+/// needs no stylesheet and can be printed to PDF by a headless browser.
+pub fn delta_to_standalone_html(delta: &Delta, title: &str) -> String {
+    let body = inline_styles(&delta_to_html(delta));
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body \
+         style=\"{}\">\n{}\n</body>\n</html>\n",
+        escape_html(title),
+        BODY_STYLE,
+        body
+    )
+}
+
+fn inline_styles(html: &str) -> String {
+    let mut styled = String::with_capacity(html.len() * 2);
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        styled.push_str(&rest[..start]);
+        rest = &rest[start + 1..];
+        styled.push('<');
+
+        let name_len = rest
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or_else(|| rest.len());
+        let name = &rest[..name_len];
+        styled.push_str(name);
+        rest = &rest[name_len..];
+        if let Some((_, style)) = TAG_STYLES.iter().find(|(tag, _)| *tag == name) {
+            styled.push_str(&format!(" style=\"{}\"", style));
+        }
+    }
+    styled.push_str(rest);
+    styled
+}
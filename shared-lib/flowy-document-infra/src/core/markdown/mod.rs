@@ -0,0 +1,16 @@
+mod parser;
+mod render;
+
+pub use parser::*;
+pub use render::*;
+
+pub(crate) fn escape_markdown(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '\\' | '*' | '_' | '~' | '`' | '[' | ']') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
@@ -0,0 +1,241 @@
+use lib_ot::{
+    core::{plain_attributes, Attribute, Attributes, Delta, NEW_LINE},
+    errors::OTError,
+};
+
+/// Converts a subset of markdown into a delta, the inverse of
+/// [delta_to_markdown](crate::core::delta_to_markdown). Headings, lists, block
+/// quotes, fenced code blocks, links and the common inline formatting are
+/// mapped to their attributes, anything else is kept as text. Consecutive text
+/// lines are joined into one paragraph.
+pub fn markdown_to_delta(markdown: &str) -> Result<Delta, OTError> {
+    let mut delta = Delta::new();
+    let mut paragraph: Vec<&str> = vec![];
+    let mut code_block: Option<Vec<&str>> = None;
+    for line in markdown.lines() {
+        if let Some(code_lines) = code_block.as_mut() {
+            if !line.trim_start().starts_with("```") {
+                code_lines.push(line);
+            } else {
+                insert_code_block(&mut delta, code_block.take().unwrap_or_default());
+            }
+            continue;
+        }
+
+        match parse_block(line) {
+            None => paragraph.push(line.trim()),
+            Some(block) => {
+                flush_paragraph(&mut delta, &mut paragraph);
+                match block {
+                    Block::Blank => {},
+                    Block::Fence => code_block = Some(vec![]),
+                    Block::Line(text, attribute) => {
+                        insert_inline(&mut delta, text);
+                        delta.insert(NEW_LINE, attribute.into());
+                    },
+                }
+            },
+        }
+    }
+    flush_paragraph(&mut delta, &mut paragraph);
+    // An unclosed fence runs to the end of the document.
+    insert_code_block(&mut delta, code_block.unwrap_or_default());
+
+    if delta.is_empty() {
+        delta.insert(NEW_LINE, plain_attributes());
+    }
+    Ok(delta)
+}
+
+enum Block<'a> {
+    Blank,
+    Fence,
+    Line(&'a str, Attribute),
+}
+
+// Returns `None` for the text lines of a paragraph.
+fn parse_block(line: &str) -> Option<Block> {
+    let line = line.trim();
+    if line.is_empty() {
+        return Some(Block::Blank);
+    }
+    if line.starts_with("```") {
+        return Some(Block::Fence);
+    }
+
+    let markers = line.chars().take_while(|c| *c == '#').count();
+    if (1..=6).contains(&markers) {
+        if let Some(text) = line[markers..].strip_prefix(' ') {
+            return Some(Block::Line(text.trim_start(), Attribute::Header(markers)));
+        }
+    }
+
+    if let Some(text) = line.strip_prefix("- [x] ").or_else(|| line.strip_prefix("- [X] ")) {
+        return Some(Block::Line(text, Attribute::Checked(true)));
+    }
+    if let Some(text) = line.strip_prefix("- [ ] ") {
+        return Some(Block::Line(text, Attribute::UnChecked(true)));
+    }
+    if let Some(text) = ["- ", "* ", "+ "].iter().find_map(|marker| line.strip_prefix(marker)) {
+        return Some(Block::Line(text, Attribute::Bullet(true)));
+    }
+
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 {
+        if let Some(text) = line[digits..].strip_prefix(". ") {
+            return Some(Block::Line(text, Attribute::Ordered(true)));
+        }
+    }
+
+    if let Some(text) = line.strip_prefix('>') {
+        return Some(Block::Line(text.trim_start(), Attribute::BlockQuote(true)));
+    }
+    None
+}
+
+fn flush_paragraph(delta: &mut Delta, paragraph: &mut Vec<&str>) {
+    if paragraph.is_empty() {
+        return;
+    }
+    insert_inline(delta, &paragraph.join(" "));
+    delta.insert(NEW_LINE, plain_attributes());
+    paragraph.clear();
+}
+
+fn insert_code_block(delta: &mut Delta, code_lines: Vec<&str>) {
+    for code_line in code_lines {
+        delta.insert(code_line, plain_attributes());
+        delta.insert(NEW_LINE, Attribute::CodeBlock(true).into());
+    }
+}
+
+fn insert_inline(delta: &mut Delta, text: &str) {
+    let mut runs = vec![];
+    parse_inline(text, &plain_attributes(), &mut runs);
+    for (text, attributes) in runs {
+        delta.insert(&text, attributes);
+    }
+}
+
+/// Splits the inline markdown into the runs of text with their attributes.
+/// A delimiter without its closing one is kept as text, and so is one that
+/// doesn't flank the text, e.g. the `*` in "2 * 3 * 4" or the `_` inside
+/// "snake_case_name".
+fn parse_inline(text: &str, attributes: &Attributes, runs: &mut Vec<(String, Attributes)>) {
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        let prev = text[..text.len() - rest.len()].chars().next_back();
+        if c == '\\' {
+            let escaped = rest[1..].chars().next().filter(|c| c.is_ascii_punctuation());
+            if let Some(escaped) = escaped {
+                push_run(runs, &escaped.to_string(), attributes);
+                rest = &rest[1 + escaped.len_utf8()..];
+                continue;
+            }
+        }
+
+        if c == '`' {
+            if let Some(end) = rest[1..].find('`') {
+                push_run(runs, &rest[1..end + 1], &with(attributes, Attribute::InlineCode(true)));
+                rest = &rest[end + 2..];
+                continue;
+            }
+        }
+
+        if c == '[' {
+            if let Some((label, href, len)) = parse_link(rest) {
+                parse_inline(label, &with(attributes, Attribute::Link(href)), runs);
+                rest = &rest[len..];
+                continue;
+            }
+        }
+
+        let delimited = [
+            ("**", Attribute::Bold(true)),
+            ("__", Attribute::Bold(true)),
+            ("~~", Attribute::StrikeThrough(true)),
+            ("*", Attribute::Italic(true)),
+            ("_", Attribute::Italic(true)),
+        ]
+        .iter()
+        .find_map(|(delimiter, attribute)| {
+            let inner = rest.strip_prefix(delimiter)?;
+            if inner.starts_with(char::is_whitespace)
+                || (is_word_bound(delimiter) && prev.map_or(false, char::is_alphanumeric))
+            {
+                return None;
+            }
+            let end = find_closing_delimiter(inner, delimiter)?;
+            Some((&inner[..end], attribute.clone(), delimiter.len() * 2 + end))
+        });
+        match delimited {
+            Some((inner, attribute, len)) => {
+                parse_inline(inner, &with(attributes, attribute), runs);
+                rest = &rest[len..];
+            },
+            None => {
+                push_run(runs, &rest[..c.len_utf8()], attributes);
+                rest = &rest[c.len_utf8()..];
+            },
+        }
+    }
+}
+
+// Returns the label, the href and the length of the link at the start of the
+// text, e.g. `[docs](https://appflowy.io)`.
+fn parse_link(text: &str) -> Option<(&str, &str, usize)> {
+    let label_end = find_closing(&text[1..], "]")? + 1;
+    let href = text[label_end + 1..].strip_prefix('(')?;
+    let href_end = href.find(')')?;
+    Some((&text[1..label_end], &href[..href_end], label_end + href_end + 3))
+}
+
+// Returns the byte index of the first unescaped `delimiter` in the text.
+fn find_closing(text: &str, delimiter: &str) -> Option<usize> {
+    let mut chars = text.char_indices();
+    while let Some((i, c)) = chars.next() {
+        if c == '\\' {
+            let _ = chars.next();
+            continue;
+        }
+        if text[i..].starts_with(delimiter) {
+            return Some(i);
+        }
+    }
+    None
+}
+
+// Returns the byte index of the first unescaped `delimiter` that closes the
+// emphasis: one right after a non-whitespace character and, for `_`, not
+// followed by an alphanumeric one.
+fn find_closing_delimiter(text: &str, delimiter: &str) -> Option<usize> {
+    let mut offset = 0;
+    while let Some(i) = find_closing(&text[offset..], delimiter) {
+        let end = offset + i;
+        let before = text[..end].chars().next_back();
+        let after = text[end + delimiter.len()..].chars().next();
+        if before.map_or(false, |c| !c.is_whitespace())
+            && !(is_word_bound(delimiter) && after.map_or(false, char::is_alphanumeric))
+        {
+            return Some(end);
+        }
+        offset = end + delimiter.len();
+    }
+    None
+}
+
+// Whether the delimiter emphasizes whole words only, like `_` unlike `*`.
+fn is_word_bound(delimiter: &str) -> bool { delimiter.starts_with('_') }
+
+fn with(attributes: &Attributes, attribute: Attribute) -> Attributes {
+    let mut attributes = attributes.clone();
+    attributes.add(attribute);
+    attributes
+}
+
+fn push_run(runs: &mut Vec<(String, Attributes)>, text: &str, attributes: &Attributes) {
+    match runs.last_mut() {
+        Some((last, last_attributes)) if last_attributes == attributes => last.push_str(text),
+        _ => runs.push((text.to_owned(), attributes.clone())),
+    }
+}
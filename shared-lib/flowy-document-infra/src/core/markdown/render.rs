@@ -0,0 +1,147 @@
+use crate::core::{footnote::FootnoteCollector, markdown::escape_markdown};
+use lib_ot::core::{AttributeKey, Attributes, Delta, Footnote, Operation, NEW_LINE};
+
+/// Renders the inserts of a delta as markdown. Each line becomes a block
+/// chosen by the attributes of its trailing newline, separated by a blank line
+/// except between list items; consecutive code block lines are fenced together.
+/// Footnotes render as `[^id]` references whose definitions are appended after
+/// the document in the order of reference.
+pub fn delta_to_markdown(delta: &Delta) -> String {
+    let mut markdown = String::new();
+    let mut code_block: Option<Vec<String>> = None;
+    let mut ordered_number = 0;
+    let mut prev_is_list = false;
+    let mut collector = FootnoteCollector::default();
+    for line in lines(delta, &mut collector) {
+        let block_attributes = &line.block_attributes;
+        if value_of(block_attributes, &AttributeKey::CodeBlock).is_some() {
+            code_block.get_or_insert_with(Vec::new).push(line.text);
+            continue;
+        }
+        if let Some(code_lines) = code_block.take() {
+            push_block(&mut markdown, &fenced(code_lines), false, &mut prev_is_list);
+        }
+        let content = line.content;
+
+        let list = value_of(block_attributes, &AttributeKey::List);
+        ordered_number = match list {
+            Some("ordered") => ordered_number + 1,
+            _ => 0,
+        };
+        let block = match list {
+            Some("ordered") => format!("{}. {}", ordered_number, content),
+            Some("checked") => format!("- [x] {}", content),
+            Some("unchecked") => format!("- [ ] {}", content),
+            Some(_) => format!("- {}", content),
+            None => match value_of(block_attributes, &AttributeKey::Header) {
+                Some(level) => {
+                    let level = level.parse::<usize>().unwrap_or(1);
+                    format!("{} {}", "#".repeat(level), content)
+                },
+                None if value_of(block_attributes, &AttributeKey::BlockQuote).is_some() => {
+                    format!("> {}", content)
+                },
+                None => content,
+            },
+        };
+        push_block(&mut markdown, &block, list.is_some(), &mut prev_is_list);
+    }
+    if let Some(code_lines) = code_block.take() {
+        push_block(&mut markdown, &fenced(code_lines), false, &mut prev_is_list);
+    }
+
+    let footnotes = collector.into_footnotes();
+    if !footnotes.is_empty() {
+        markdown.push_str(NEW_LINE);
+        for footnote in footnotes {
+            markdown.push_str(&format!("\n[^{}]: {}", footnote.id, escape_markdown(&footnote.text)));
+        }
+    }
+    markdown
+}
+
+fn push_block(markdown: &mut String, block: &str, is_list: bool, prev_is_list: &mut bool) {
+    if !markdown.is_empty() {
+        markdown.push_str(if is_list && *prev_is_list { "\n" } else { "\n\n" });
+    }
+    markdown.push_str(block);
+    *prev_is_list = is_list;
+}
+
+fn fenced(code_lines: Vec<String>) -> String { format!("```\n{}\n```", code_lines.join(NEW_LINE)) }
+
+fn value_of<'a>(attributes: &'a Attributes, key: &AttributeKey) -> Option<&'a str> {
+    attributes.get(key).and_then(|value| value.0.as_deref())
+}
+
+struct Line {
+    // The rendered inline markdown.
+    content: String,
+    // The unformatted text, used inside code blocks.
+    text: String,
+    block_attributes: Attributes,
+}
+
+/// Splits the delta into lines paired with the block attributes of the
+/// newline that ends them.
+fn lines(delta: &Delta, collector: &mut FootnoteCollector) -> Vec<Line> {
+    let mut lines = vec![];
+    let mut content = String::new();
+    let mut text = String::new();
+    for op in &delta.ops {
+        if let Operation::Insert(insert) = op {
+            if let Some(footnote) = Footnote::from_attributes(&insert.attributes) {
+                // Adjacent references to the same footnote are merged into one insert.
+                for _ in insert.s.chars() {
+                    content.push_str(&format!("[^{}]", footnote.id));
+                }
+                collector.reference(footnote);
+                continue;
+            }
+
+            let mut segments = insert.s.split(NEW_LINE).peekable();
+            while let Some(segment) = segments.next() {
+                content.push_str(&render_inline(segment, &insert.attributes));
+                text.push_str(segment);
+                if segments.peek().is_some() {
+                    lines.push(Line {
+                        content: std::mem::take(&mut content),
+                        text: std::mem::take(&mut text),
+                        block_attributes: insert.attributes.clone(),
+                    });
+                }
+            }
+        }
+    }
+    if !content.is_empty() {
+        lines.push(Line {
+            content,
+            text,
+            block_attributes: Attributes::default(),
+        });
+    }
+    lines
+}
+
+fn render_inline(text: &str, attributes: &Attributes) -> String {
+    if text.is_empty() {
+        return String::new();
+    }
+    let mut markdown = match value_of(attributes, &AttributeKey::InlineCode) {
+        Some(_) => format!("`{}`", text),
+        None => escape_markdown(text),
+    };
+    if value_of(attributes, &AttributeKey::StrikeThrough).is_some() {
+        markdown = format!("~~{}~~", markdown);
+    }
+    if value_of(attributes, &AttributeKey::Italic).is_some() {
+        markdown = format!("_{}_", markdown);
+    }
+    if value_of(attributes, &AttributeKey::Bold).is_some() {
+        markdown = format!("**{}**", markdown);
+    }
+    if let Some(href) = value_of(attributes, &AttributeKey::Link) {
+        markdown = format!("[{}]({})", markdown, href);
+    }
+    markdown
+}
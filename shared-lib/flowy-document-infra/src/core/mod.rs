@@ -1,8 +1,20 @@
 mod data;
 mod document;
 mod extensions;
+mod footnote;
 pub mod history;
+mod html;
+mod markdown;
+mod plain;
+mod stats;
+mod summary;
 mod view;
 
 pub use document::*;
+pub use extensions::{list_ordinal_at, ExtConflict, ExtensionRegistry, InsertExt};
+pub use html::*;
+pub use markdown::*;
+pub use plain::*;
+pub use stats::*;
+pub use summary::*;
 pub use view::RECORD_THRESHOLD;
@@ -0,0 +1,65 @@
+use lib_ot::core::{Delta, NEW_LINE};
+
+#[derive(Debug, Clone, Default)]
+pub struct PlainTextImport {
+    max_block_len: Option<usize>,
+}
+
+impl PlainTextImport {
+    pub fn new() -> Self { PlainTextImport::default() }
+
+    /// Splits the lines longer than `max_block_len` characters into blocks,
+    /// preferring the ends of sentences, then the ends of words. Only the
+    /// newlines are inserted, the text itself is kept as it is.
+    pub fn max_block_len(mut self, max_block_len: usize) -> Self {
+        self.max_block_len = Some(max_block_len).filter(|len| *len > 0);
+        self
+    }
+}
+
+/// Converts plain text into a delta, each line of the text becomes a block.
+pub fn plain_text_to_delta(text: &str, import: &PlainTextImport) -> Delta {
+    let mut blocks = vec![];
+    for line in text.strip_suffix(NEW_LINE).unwrap_or(text).split(NEW_LINE) {
+        match import.max_block_len {
+            None => blocks.push(line),
+            Some(max_block_len) => blocks.extend(split_line(line, max_block_len)),
+        }
+    }
+
+    Delta::from_plain_text(&blocks.join(NEW_LINE))
+}
+
+fn split_line(line: &str, max_block_len: usize) -> Vec<&str> {
+    let mut blocks = vec![];
+    let mut rest = line;
+    while rest.chars().count() > max_block_len {
+        let index = break_index(rest, max_block_len);
+        blocks.push(&rest[..index]);
+        rest = &rest[index..];
+    }
+    blocks.push(rest);
+    blocks
+}
+
+// Returns the byte index to break the text at, right after the last whitespace
+// within the first `max_block_len` characters, or at `max_block_len` characters
+// if there is no whitespace.
+fn break_index(text: &str, max_block_len: usize) -> usize {
+    let chars = text
+        .char_indices()
+        .take(max_block_len + 1)
+        .collect::<Vec<(usize, char)>>();
+    let mut sentence_end = None;
+    let mut word_end = None;
+    for window in chars.windows(3) {
+        let (prev, c, (index, _)) = (window[0].1, window[1].1, window[2]);
+        if c.is_whitespace() {
+            word_end = Some(index);
+            if matches!(prev, '.' | '!' | '?') {
+                sentence_end = Some(index);
+            }
+        }
+    }
+    sentence_end.or(word_end).unwrap_or(chars[max_block_len].0)
+}
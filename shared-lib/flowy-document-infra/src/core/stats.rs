@@ -0,0 +1,20 @@
+use lib_ot::core::{Delta, NEW_LINE};
+
+/// Counts the words of the document, the runs of characters separated by
+/// whitespaces. A word formatted partially is still one word.
+pub fn word_count(delta: &Delta) -> usize { delta.to_plain_text().split_whitespace().count() }
+
+/// Counts the characters of the document, excluding the newlines that end
+/// its lines.
+pub fn char_count(delta: &Delta) -> usize { delta.to_plain_text().chars().filter(|c| *c != '\n').count() }
+
+/// Counts the lines of the document. Each newline ends a line, and the text
+/// following the last newline counts as a line too.
+pub fn line_count(delta: &Delta) -> usize {
+    let text = delta.to_plain_text();
+    let newline_count = text.matches(NEW_LINE).count();
+    match text.is_empty() || text.ends_with(NEW_LINE) {
+        true => newline_count,
+        false => newline_count + 1,
+    }
+}
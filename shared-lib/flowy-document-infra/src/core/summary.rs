@@ -0,0 +1,107 @@
+use lib_ot::core::{diff_slices, AttributeKey, Attributes, Delta, DiffEdit, Operation, NEW_LINE};
+
+/// The changes between two versions of a document, counted like the
+/// [word_count](crate::core::word_count) and the
+/// [char_count](crate::core::char_count) of the document.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangeSummary {
+    pub inserted_words: usize,
+    pub deleted_words: usize,
+    pub inserted_chars: usize,
+    pub deleted_chars: usize,
+    pub headings_added: usize,
+    pub headings_removed: usize,
+    pub list_items_added: usize,
+    pub list_items_removed: usize,
+}
+
+impl ChangeSummary {
+    pub fn is_empty(&self) -> bool { self == &ChangeSummary::default() }
+}
+
+/// Summarizes the changes from the document `from` to the document `to`. The
+/// words and the characters are diffed by their longest common subsequence,
+/// so a word edited partially counts as a deleted word and an inserted one.
+/// A heading or a list item whose text changes counts as removed and added.
+pub fn change_summary(from: &Delta, to: &Delta) -> ChangeSummary {
+    let from_text = from.to_plain_text();
+    let to_text = to.to_plain_text();
+
+    let from_words = from_text.split_whitespace().collect::<Vec<&str>>();
+    let to_words = to_text.split_whitespace().collect::<Vec<&str>>();
+    let common_words = common_len(&from_words, &to_words);
+
+    let from_chars = from_text.chars().filter(|c| *c != '\n').collect::<Vec<char>>();
+    let to_chars = to_text.chars().filter(|c| *c != '\n').collect::<Vec<char>>();
+    let common_chars = common_len(&from_chars, &to_chars);
+
+    let from_blocks = blocks(from);
+    let to_blocks = blocks(to);
+    let (headings_added, headings_removed) = count_changes(&from_blocks, &to_blocks, &AttributeKey::Header);
+    let (list_items_added, list_items_removed) = count_changes(&from_blocks, &to_blocks, &AttributeKey::List);
+
+    ChangeSummary {
+        inserted_words: to_words.len() - common_words,
+        deleted_words: from_words.len() - common_words,
+        inserted_chars: to_chars.len() - common_chars,
+        deleted_chars: from_chars.len() - common_chars,
+        headings_added,
+        headings_removed,
+        list_items_added,
+        list_items_removed,
+    }
+}
+
+// The number of items left unchanged by the diff.
+fn common_len<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    diff_slices(a, b)
+        .iter()
+        .filter(|edit| matches!(edit, DiffEdit::Equal(..)))
+        .count()
+}
+
+// The text of each line paired with the attributes of the newline ending it.
+fn blocks(delta: &Delta) -> Vec<(String, Attributes)> {
+    let mut blocks = vec![];
+    let mut text = String::new();
+    for op in &delta.ops {
+        if let Operation::Insert(insert) = op {
+            let mut segments = insert.s.split(NEW_LINE).peekable();
+            while let Some(segment) = segments.next() {
+                text.push_str(segment);
+                if segments.peek().is_some() {
+                    blocks.push((std::mem::take(&mut text), insert.attributes.clone()));
+                }
+            }
+        }
+    }
+    blocks
+}
+
+// Counts the blocks formatted with `key` that are added and removed, matched
+// by their text and the value of `key`.
+fn count_changes(from: &[(String, Attributes)], to: &[(String, Attributes)], key: &AttributeKey) -> (usize, usize) {
+    let select = |blocks: &[(String, Attributes)]| {
+        blocks
+            .iter()
+            .filter_map(
+                |(text, attributes)| match attributes.get(key).and_then(|value| value.0.clone()) {
+                    Some(value) if !value.is_empty() => Some((text.clone(), value)),
+                    _ => None,
+                },
+            )
+            .collect::<Vec<(String, String)>>()
+    };
+    let from = select(from);
+    let mut unmatched = select(to);
+    let mut removed = 0;
+    for block in from {
+        match unmatched.iter().position(|other| other == &block) {
+            Some(index) => {
+                unmatched.remove(index);
+            },
+            None => removed += 1,
+        }
+    }
+    (unmatched.len(), removed)
+}
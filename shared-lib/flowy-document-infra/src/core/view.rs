@@ -13,28 +13,23 @@ pub struct View {
 }
 
 impl View {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new() -> Self { Self::from_registry(ExtensionRegistry::default()) }
+
+    pub(crate) fn from_registry(registry: ExtensionRegistry) -> Self {
         Self {
-            insert_exts: construct_insert_exts(),
+            insert_exts: registry.into_insert_exts(),
             format_exts: construct_format_exts(),
             delete_exts: construct_delete_exts(),
         }
     }
 
     pub(crate) fn insert(&self, delta: &Delta, text: &str, interval: Interval) -> Result<Delta, OTError> {
-        let mut new_delta = None;
-        for ext in &self.insert_exts {
-            if let Some(mut delta) = ext.apply(delta, interval.size(), text, interval.start) {
-                trim(&mut delta);
-                tracing::trace!("[{}]: applied, delta: {}", ext.ext_name(), delta);
-                new_delta = Some(delta);
-                break;
-            }
-        }
-
-        match new_delta {
+        match apply_insert_exts(&self.insert_exts, delta, interval.size(), text, interval.start) {
             None => Err(ErrorBuilder::new(OTErrorCode::ApplyInsertFail).build()),
-            Some(new_delta) => Ok(new_delta),
+            Some(mut new_delta) => {
+                trim(&mut new_delta);
+                Ok(new_delta)
+            },
         }
     }
 
@@ -71,20 +66,14 @@ impl View {
             Some(new_delta) => Ok(new_delta),
         }
     }
-}
 
-fn construct_insert_exts() -> Vec<InsertExtension> {
-    vec![
-        Box::new(InsertEmbedsExt {}),
-        Box::new(ForceNewlineForInsertsAroundEmbedExt {}),
-        Box::new(AutoExitBlock {}),
-        Box::new(PreserveBlockFormatOnInsert {}),
-        Box::new(PreserveLineFormatOnSplit {}),
-        Box::new(ResetLineFormatOnNewLine {}),
-        Box::new(AutoFormatExt {}),
-        Box::new(PreserveInlineFormat {}),
-        Box::new(DefaultInsertAttribute {}),
-    ]
+    pub(crate) fn toggle_format(&self, delta: &Delta, attribute: &Attribute, interval: Interval) -> Option<Delta> {
+        let ext = ToggleInlineFormat {};
+        let mut delta = ext.apply(delta, interval, attribute)?;
+        trim(&mut delta);
+        tracing::trace!("[{}]: applied, delta: {}", ext.ext_name(), delta);
+        Some(delta)
+    }
 }
 
 fn construct_format_exts() -> Vec<FormatExtension> {
@@ -74,6 +74,19 @@ pub struct NewDocUser {
     pub doc_id: String,
 }
 
+// Whether the user has local revisions that the server didn't acknowledge yet.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DocAwareness {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub user_id: String,
+
+    #[pb(index = 3)]
+    pub has_unsynced: bool,
+}
+
 #[derive(ProtoBuf, Default, Debug, Clone)]
 pub struct DocIdentifier {
     #[pb(index = 1)]
@@ -91,3 +104,78 @@ impl std::convert::From<&String> for DocIdentifier {
         }
     }
 }
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DocSession {
+    #[pb(index = 1)]
+    pub session_id: String,
+
+    #[pb(index = 2)]
+    pub user_id: String,
+
+    #[pb(index = 3)]
+    pub last_activity_time: i64,
+
+    #[pb(index = 4)]
+    pub has_unsynced: bool,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedDocSession {
+    #[pb(index = 1)]
+    pub items: Vec<DocSession>,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct DocStats {
+    #[pb(index = 1)]
+    pub doc_id: String,
+
+    #[pb(index = 2)]
+    pub word_count: i64,
+
+    #[pb(index = 3)]
+    pub char_count: i64,
+
+    #[pb(index = 4)]
+    pub line_count: i64,
+
+    #[pb(index = 5)]
+    pub rev_count: i64,
+
+    #[pb(index = 6)]
+    pub modified_time: i64,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct CreateNamedVersionParams {
+    #[pb(index = 1)]
+    pub name: String,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct NamedVersion {
+    #[pb(index = 1)]
+    pub id: String,
+
+    #[pb(index = 2)]
+    pub doc_id: String,
+
+    #[pb(index = 3)]
+    pub rev_id: i64,
+
+    #[pb(index = 4)]
+    pub name: String,
+
+    #[pb(index = 5)]
+    pub created_by: String,
+
+    #[pb(index = 6)]
+    pub created_at: i64,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedNamedVersion {
+    #[pb(index = 1)]
+    pub items: Vec<NamedVersion>,
+}
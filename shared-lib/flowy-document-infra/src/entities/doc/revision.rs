@@ -1,6 +1,9 @@
 use crate::{entities::doc::Doc, util::md5};
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
-use lib_ot::core::Delta;
+use lib_ot::{
+    core::{Delta, OperationTransformable},
+    errors::OTError,
+};
 use std::{fmt::Formatter, ops::RangeInclusive};
 
 #[derive(Debug, ProtoBuf_Enum, Clone, Eq, PartialEq)]
@@ -57,6 +60,31 @@ impl std::fmt::Display for RevId {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result { f.write_fmt(format_args!("{}", self.value)) }
 }
 
+// Sent by the server once the revision is applied and saved, the md5 is the
+// one of the revision it received. The rev_id takes the index of RevId's value,
+// so the acks can still be read as RevId.
+#[derive(Clone, Debug, ProtoBuf, Default)]
+pub struct RevAck {
+    #[pb(index = 1)]
+    pub rev_id: i64,
+
+    #[pb(index = 2)]
+    pub base_rev_id: i64,
+
+    #[pb(index = 3)]
+    pub md5: String,
+}
+
+impl std::convert::From<&Revision> for RevAck {
+    fn from(revision: &Revision) -> Self {
+        RevAck {
+            rev_id: revision.rev_id,
+            base_rev_id: revision.base_rev_id,
+            md5: revision.md5.clone(),
+        }
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Default, ProtoBuf)]
 pub struct Revision {
     #[pb(index = 1)]
@@ -76,6 +104,11 @@ pub struct Revision {
 
     #[pb(index = 6)]
     pub ty: RevType,
+
+    // The time the revision is stored locally, in seconds since the epoch, or
+    // 0 before that.
+    #[pb(index = 7)]
+    pub created_at: i64,
 }
 
 impl Revision {
@@ -123,6 +156,7 @@ impl Revision {
             md5,
             doc_id,
             ty,
+            created_at: 0,
         }
     }
 }
@@ -132,6 +166,27 @@ pub fn revision_from_doc(doc: Doc, ty: RevType) -> Revision {
     Revision::new(doc.base_rev_id, doc.rev_id, delta_data.to_owned(), &doc.id, ty)
 }
 
+/// Reconstructs the document by composing the revisions in the order of
+/// rev_id. The local and the remote revisions are composed alike, both are
+/// applied to the document already.
+pub fn replay_revisions(revisions: &[Revision]) -> Result<Delta, OTError> {
+    let mut revisions = revisions.iter().collect::<Vec<&Revision>>();
+    revisions.sort_by_key(|revision| revision.rev_id);
+
+    let mut delta = Delta::new();
+    for revision in revisions {
+        match Delta::from_bytes(&revision.delta_data) {
+            Ok(revision_delta) => {
+                delta = delta.compose(&revision_delta)?;
+            },
+            Err(e) => {
+                log::error!("Deserialize delta from revision failed: {}", e);
+            },
+        }
+    }
+    Ok(delta)
+}
+
 #[derive(Debug, Clone, Default, ProtoBuf)]
 pub struct RevisionRange {
     #[pb(index = 1)]
@@ -1,5 +1,5 @@
 use crate::{
-    entities::doc::{NewDocUser, Revision},
+    entities::doc::{DocAwareness, NewDocUser, Revision},
     errors::DocumentError,
 };
 use bytes::Bytes;
@@ -8,11 +8,12 @@ use std::convert::{TryFrom, TryInto};
 
 #[derive(Debug, Clone, ProtoBuf_Enum, Eq, PartialEq, Hash)]
 pub enum WsDataType {
-    Acked      = 0,
+    Acked      = 0, // data should be RevAck
     PushRev    = 1,
     PullRev    = 2, // data should be Revision
-    Conflict   = 3,
+    Conflict   = 3, // data should be the Revision of the document snapshot
     NewDocUser = 4,
+    Awareness  = 5, // data should be DocAwareness
 }
 
 impl WsDataType {
@@ -64,3 +65,15 @@ impl std::convert::From<NewDocUser> for WsDocumentData {
         }
     }
 }
+
+impl std::convert::From<DocAwareness> for WsDocumentData {
+    fn from(awareness: DocAwareness) -> Self {
+        let doc_id = awareness.doc_id.clone();
+        let bytes: Bytes = awareness.try_into().unwrap();
+        Self {
+            doc_id,
+            ty: WsDataType::Awareness,
+            data: bytes.to_vec(),
+        }
+    }
+}
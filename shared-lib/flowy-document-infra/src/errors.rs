@@ -38,6 +38,7 @@ impl DocumentError {
     static_doc_error!(undo, ErrorCode::UndoFail);
     static_doc_error!(redo, ErrorCode::RedoFail);
     static_doc_error!(out_of_bound, ErrorCode::OutOfBound);
+    static_doc_error!(extension_conflict, ErrorCode::ExtensionConflict);
 }
 
 impl fmt::Display for DocumentError {
@@ -46,12 +47,13 @@ impl fmt::Display for DocumentError {
 
 #[derive(Debug, Clone, Display, PartialEq, Eq)]
 pub enum ErrorCode {
-    DocIdInvalid  = 0,
-    DocNotfound   = 1,
-    UndoFail      = 200,
-    RedoFail      = 201,
-    OutOfBound    = 202,
-    InternalError = 1000,
+    DocIdInvalid      = 0,
+    DocNotfound       = 1,
+    UndoFail          = 200,
+    RedoFail          = 201,
+    OutOfBound        = 202,
+    ExtensionConflict = 203,
+    InternalError     = 1000,
 }
 
 impl std::convert::From<lib_ot::errors::OTError> for DocumentError {
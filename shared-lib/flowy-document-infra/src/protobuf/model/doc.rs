@@ -1168,6 +1168,242 @@ impl ::protobuf::reflect::ProtobufValue for NewDocUser {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct DocAwareness {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub user_id: ::std::string::String,
+    pub has_unsynced: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocAwareness {
+    fn default() -> &'a DocAwareness {
+        <DocAwareness as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocAwareness {
+    pub fn new() -> DocAwareness {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // string user_id = 2;
+
+
+    pub fn get_user_id(&self) -> &str {
+        &self.user_id
+    }
+    pub fn clear_user_id(&mut self) {
+        self.user_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: ::std::string::String) {
+        self.user_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user_id(&mut self) -> &mut ::std::string::String {
+        &mut self.user_id
+    }
+
+    // Take field
+    pub fn take_user_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
+    }
+
+    // bool has_unsynced = 3;
+
+
+    pub fn get_has_unsynced(&self) -> bool {
+        self.has_unsynced
+    }
+    pub fn clear_has_unsynced(&mut self) {
+        self.has_unsynced = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_has_unsynced(&mut self, v: bool) {
+        self.has_unsynced = v;
+    }
+}
+
+impl ::protobuf::Message for DocAwareness {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.has_unsynced = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        if !self.user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.user_id);
+        }
+        if self.has_unsynced != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        if !self.user_id.is_empty() {
+            os.write_string(2, &self.user_id)?;
+        }
+        if self.has_unsynced != false {
+            os.write_bool(3, self.has_unsynced)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocAwareness {
+        DocAwareness::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &DocAwareness| { &m.doc_id },
+                |m: &mut DocAwareness| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "user_id",
+                |m: &DocAwareness| { &m.user_id },
+                |m: &mut DocAwareness| { &mut m.user_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "has_unsynced",
+                |m: &DocAwareness| { &m.has_unsynced },
+                |m: &mut DocAwareness| { &mut m.has_unsynced },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocAwareness>(
+                "DocAwareness",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocAwareness {
+        static instance: ::protobuf::rt::LazyV2<DocAwareness> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocAwareness::new)
+    }
+}
+
+impl ::protobuf::Clear for DocAwareness {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.user_id.clear();
+        self.has_unsynced = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocAwareness {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocAwareness {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct DocIdentifier {
     // message fields
@@ -1327,6 +1563,1457 @@ impl ::protobuf::reflect::ProtobufValue for DocIdentifier {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct DocSession {
+    // message fields
+    pub session_id: ::std::string::String,
+    pub user_id: ::std::string::String,
+    pub last_activity_time: i64,
+    pub has_unsynced: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocSession {
+    fn default() -> &'a DocSession {
+        <DocSession as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocSession {
+    pub fn new() -> DocSession {
+        ::std::default::Default::default()
+    }
+
+    // string session_id = 1;
+
+
+    pub fn get_session_id(&self) -> &str {
+        &self.session_id
+    }
+    pub fn clear_session_id(&mut self) {
+        self.session_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_session_id(&mut self, v: ::std::string::String) {
+        self.session_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_session_id(&mut self) -> &mut ::std::string::String {
+        &mut self.session_id
+    }
+
+    // Take field
+    pub fn take_session_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.session_id, ::std::string::String::new())
+    }
+
+    // string user_id = 2;
+
+
+    pub fn get_user_id(&self) -> &str {
+        &self.user_id
+    }
+    pub fn clear_user_id(&mut self) {
+        self.user_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: ::std::string::String) {
+        self.user_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user_id(&mut self) -> &mut ::std::string::String {
+        &mut self.user_id
+    }
+
+    // Take field
+    pub fn take_user_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
+    }
+
+    // int64 last_activity_time = 3;
+
+
+    pub fn get_last_activity_time(&self) -> i64 {
+        self.last_activity_time
+    }
+    pub fn clear_last_activity_time(&mut self) {
+        self.last_activity_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_activity_time(&mut self, v: i64) {
+        self.last_activity_time = v;
+    }
+
+    // bool has_unsynced = 4;
+
+
+    pub fn get_has_unsynced(&self) -> bool {
+        self.has_unsynced
+    }
+    pub fn clear_has_unsynced(&mut self) {
+        self.has_unsynced = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_has_unsynced(&mut self, v: bool) {
+        self.has_unsynced = v;
+    }
+}
+
+impl ::protobuf::Message for DocSession {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.session_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.last_activity_time = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.has_unsynced = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.session_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.session_id);
+        }
+        if !self.user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.user_id);
+        }
+        if self.last_activity_time != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.last_activity_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.has_unsynced != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.session_id.is_empty() {
+            os.write_string(1, &self.session_id)?;
+        }
+        if !self.user_id.is_empty() {
+            os.write_string(2, &self.user_id)?;
+        }
+        if self.last_activity_time != 0 {
+            os.write_int64(3, self.last_activity_time)?;
+        }
+        if self.has_unsynced != false {
+            os.write_bool(4, self.has_unsynced)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocSession {
+        DocSession::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "session_id",
+                |m: &DocSession| { &m.session_id },
+                |m: &mut DocSession| { &mut m.session_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "user_id",
+                |m: &DocSession| { &m.user_id },
+                |m: &mut DocSession| { &mut m.user_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "last_activity_time",
+                |m: &DocSession| { &m.last_activity_time },
+                |m: &mut DocSession| { &mut m.last_activity_time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "has_unsynced",
+                |m: &DocSession| { &m.has_unsynced },
+                |m: &mut DocSession| { &mut m.has_unsynced },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocSession>(
+                "DocSession",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocSession {
+        static instance: ::protobuf::rt::LazyV2<DocSession> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocSession::new)
+    }
+}
+
+impl ::protobuf::Clear for DocSession {
+    fn clear(&mut self) {
+        self.session_id.clear();
+        self.user_id.clear();
+        self.last_activity_time = 0;
+        self.has_unsynced = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocSession {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocSession {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedDocSession {
+    // message fields
+    pub items: ::protobuf::RepeatedField<DocSession>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedDocSession {
+    fn default() -> &'a RepeatedDocSession {
+        <RepeatedDocSession as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedDocSession {
+    pub fn new() -> RepeatedDocSession {
+        ::std::default::Default::default()
+    }
+
+    // repeated .DocSession items = 1;
+
+
+    pub fn get_items(&self) -> &[DocSession] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<DocSession>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<DocSession> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<DocSession> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedDocSession {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedDocSession {
+        RepeatedDocSession::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DocSession>>(
+                "items",
+                |m: &RepeatedDocSession| { &m.items },
+                |m: &mut RepeatedDocSession| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedDocSession>(
+                "RepeatedDocSession",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedDocSession {
+        static instance: ::protobuf::rt::LazyV2<RepeatedDocSession> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedDocSession::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedDocSession {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedDocSession {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedDocSession {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DocStats {
+    // message fields
+    pub doc_id: ::std::string::String,
+    pub word_count: i64,
+    pub char_count: i64,
+    pub line_count: i64,
+    pub rev_count: i64,
+    pub modified_time: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DocStats {
+    fn default() -> &'a DocStats {
+        <DocStats as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DocStats {
+    pub fn new() -> DocStats {
+        ::std::default::Default::default()
+    }
+
+    // string doc_id = 1;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // int64 word_count = 2;
+
+
+    pub fn get_word_count(&self) -> i64 {
+        self.word_count
+    }
+    pub fn clear_word_count(&mut self) {
+        self.word_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_word_count(&mut self, v: i64) {
+        self.word_count = v;
+    }
+
+    // int64 char_count = 3;
+
+
+    pub fn get_char_count(&self) -> i64 {
+        self.char_count
+    }
+    pub fn clear_char_count(&mut self) {
+        self.char_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_char_count(&mut self, v: i64) {
+        self.char_count = v;
+    }
+
+    // int64 line_count = 4;
+
+
+    pub fn get_line_count(&self) -> i64 {
+        self.line_count
+    }
+    pub fn clear_line_count(&mut self) {
+        self.line_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_line_count(&mut self, v: i64) {
+        self.line_count = v;
+    }
+
+    // int64 rev_count = 5;
+
+
+    pub fn get_rev_count(&self) -> i64 {
+        self.rev_count
+    }
+    pub fn clear_rev_count(&mut self) {
+        self.rev_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rev_count(&mut self, v: i64) {
+        self.rev_count = v;
+    }
+
+    // int64 modified_time = 6;
+
+
+    pub fn get_modified_time(&self) -> i64 {
+        self.modified_time
+    }
+    pub fn clear_modified_time(&mut self) {
+        self.modified_time = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_modified_time(&mut self, v: i64) {
+        self.modified_time = v;
+    }
+}
+
+impl ::protobuf::Message for DocStats {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.word_count = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.char_count = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.line_count = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.rev_count = tmp;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.modified_time = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.doc_id);
+        }
+        if self.word_count != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.word_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.char_count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.char_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.line_count != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.line_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.rev_count != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.rev_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.modified_time != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.modified_time, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.doc_id.is_empty() {
+            os.write_string(1, &self.doc_id)?;
+        }
+        if self.word_count != 0 {
+            os.write_int64(2, self.word_count)?;
+        }
+        if self.char_count != 0 {
+            os.write_int64(3, self.char_count)?;
+        }
+        if self.line_count != 0 {
+            os.write_int64(4, self.line_count)?;
+        }
+        if self.rev_count != 0 {
+            os.write_int64(5, self.rev_count)?;
+        }
+        if self.modified_time != 0 {
+            os.write_int64(6, self.modified_time)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DocStats {
+        DocStats::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &DocStats| { &m.doc_id },
+                |m: &mut DocStats| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "word_count",
+                |m: &DocStats| { &m.word_count },
+                |m: &mut DocStats| { &mut m.word_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "char_count",
+                |m: &DocStats| { &m.char_count },
+                |m: &mut DocStats| { &mut m.char_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "line_count",
+                |m: &DocStats| { &m.line_count },
+                |m: &mut DocStats| { &mut m.line_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "rev_count",
+                |m: &DocStats| { &m.rev_count },
+                |m: &mut DocStats| { &mut m.rev_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "modified_time",
+                |m: &DocStats| { &m.modified_time },
+                |m: &mut DocStats| { &mut m.modified_time },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DocStats>(
+                "DocStats",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DocStats {
+        static instance: ::protobuf::rt::LazyV2<DocStats> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DocStats::new)
+    }
+}
+
+impl ::protobuf::Clear for DocStats {
+    fn clear(&mut self) {
+        self.doc_id.clear();
+        self.word_count = 0;
+        self.char_count = 0;
+        self.line_count = 0;
+        self.rev_count = 0;
+        self.modified_time = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DocStats {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DocStats {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateNamedVersionParams {
+    // message fields
+    pub name: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateNamedVersionParams {
+    fn default() -> &'a CreateNamedVersionParams {
+        <CreateNamedVersionParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateNamedVersionParams {
+    pub fn new() -> CreateNamedVersionParams {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for CreateNamedVersionParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateNamedVersionParams {
+        CreateNamedVersionParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &CreateNamedVersionParams| { &m.name },
+                |m: &mut CreateNamedVersionParams| { &mut m.name },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateNamedVersionParams>(
+                "CreateNamedVersionParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CreateNamedVersionParams {
+        static instance: ::protobuf::rt::LazyV2<CreateNamedVersionParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CreateNamedVersionParams::new)
+    }
+}
+
+impl ::protobuf::Clear for CreateNamedVersionParams {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateNamedVersionParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateNamedVersionParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct NamedVersion {
+    // message fields
+    pub id: ::std::string::String,
+    pub doc_id: ::std::string::String,
+    pub rev_id: i64,
+    pub name: ::std::string::String,
+    pub created_by: ::std::string::String,
+    pub created_at: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a NamedVersion {
+    fn default() -> &'a NamedVersion {
+        <NamedVersion as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl NamedVersion {
+    pub fn new() -> NamedVersion {
+        ::std::default::Default::default()
+    }
+
+    // string id = 1;
+
+
+    pub fn get_id(&self) -> &str {
+        &self.id
+    }
+    pub fn clear_id(&mut self) {
+        self.id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: ::std::string::String) {
+        self.id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_id(&mut self) -> &mut ::std::string::String {
+        &mut self.id
+    }
+
+    // Take field
+    pub fn take_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.id, ::std::string::String::new())
+    }
+
+    // string doc_id = 2;
+
+
+    pub fn get_doc_id(&self) -> &str {
+        &self.doc_id
+    }
+    pub fn clear_doc_id(&mut self) {
+        self.doc_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_doc_id(&mut self, v: ::std::string::String) {
+        self.doc_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_doc_id(&mut self) -> &mut ::std::string::String {
+        &mut self.doc_id
+    }
+
+    // Take field
+    pub fn take_doc_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.doc_id, ::std::string::String::new())
+    }
+
+    // int64 rev_id = 3;
+
+
+    pub fn get_rev_id(&self) -> i64 {
+        self.rev_id
+    }
+    pub fn clear_rev_id(&mut self) {
+        self.rev_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rev_id(&mut self, v: i64) {
+        self.rev_id = v;
+    }
+
+    // string name = 4;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+
+    // string created_by = 5;
+
+
+    pub fn get_created_by(&self) -> &str {
+        &self.created_by
+    }
+    pub fn clear_created_by(&mut self) {
+        self.created_by.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_by(&mut self, v: ::std::string::String) {
+        self.created_by = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_created_by(&mut self) -> &mut ::std::string::String {
+        &mut self.created_by
+    }
+
+    // Take field
+    pub fn take_created_by(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.created_by, ::std::string::String::new())
+    }
+
+    // int64 created_at = 6;
+
+
+    pub fn get_created_at(&self) -> i64 {
+        self.created_at
+    }
+    pub fn clear_created_at(&mut self) {
+        self.created_at = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_at(&mut self, v: i64) {
+        self.created_at = v;
+    }
+}
+
+impl ::protobuf::Message for NamedVersion {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.doc_id)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.rev_id = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.created_by)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.created_at = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.id);
+        }
+        if !self.doc_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.doc_id);
+        }
+        if self.rev_id != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.rev_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.name);
+        }
+        if !self.created_by.is_empty() {
+            my_size += ::protobuf::rt::string_size(5, &self.created_by);
+        }
+        if self.created_at != 0 {
+            my_size += ::protobuf::rt::value_size(6, self.created_at, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.id.is_empty() {
+            os.write_string(1, &self.id)?;
+        }
+        if !self.doc_id.is_empty() {
+            os.write_string(2, &self.doc_id)?;
+        }
+        if self.rev_id != 0 {
+            os.write_int64(3, self.rev_id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(4, &self.name)?;
+        }
+        if !self.created_by.is_empty() {
+            os.write_string(5, &self.created_by)?;
+        }
+        if self.created_at != 0 {
+            os.write_int64(6, self.created_at)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> NamedVersion {
+        NamedVersion::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "id",
+                |m: &NamedVersion| { &m.id },
+                |m: &mut NamedVersion| { &mut m.id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "doc_id",
+                |m: &NamedVersion| { &m.doc_id },
+                |m: &mut NamedVersion| { &mut m.doc_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "rev_id",
+                |m: &NamedVersion| { &m.rev_id },
+                |m: &mut NamedVersion| { &mut m.rev_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &NamedVersion| { &m.name },
+                |m: &mut NamedVersion| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "created_by",
+                |m: &NamedVersion| { &m.created_by },
+                |m: &mut NamedVersion| { &mut m.created_by },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "created_at",
+                |m: &NamedVersion| { &m.created_at },
+                |m: &mut NamedVersion| { &mut m.created_at },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<NamedVersion>(
+                "NamedVersion",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static NamedVersion {
+        static instance: ::protobuf::rt::LazyV2<NamedVersion> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(NamedVersion::new)
+    }
+}
+
+impl ::protobuf::Clear for NamedVersion {
+    fn clear(&mut self) {
+        self.id.clear();
+        self.doc_id.clear();
+        self.rev_id = 0;
+        self.name.clear();
+        self.created_by.clear();
+        self.created_at = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for NamedVersion {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NamedVersion {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RepeatedNamedVersion {
+    // message fields
+    pub items: ::protobuf::RepeatedField<NamedVersion>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RepeatedNamedVersion {
+    fn default() -> &'a RepeatedNamedVersion {
+        <RepeatedNamedVersion as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RepeatedNamedVersion {
+    pub fn new() -> RepeatedNamedVersion {
+        ::std::default::Default::default()
+    }
+
+    // repeated .NamedVersion items = 1;
+
+
+    pub fn get_items(&self) -> &[NamedVersion] {
+        &self.items
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_items(&mut self, v: ::protobuf::RepeatedField<NamedVersion>) {
+        self.items = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_items(&mut self) -> &mut ::protobuf::RepeatedField<NamedVersion> {
+        &mut self.items
+    }
+
+    // Take field
+    pub fn take_items(&mut self) -> ::protobuf::RepeatedField<NamedVersion> {
+        ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RepeatedNamedVersion {
+    fn is_initialized(&self) -> bool {
+        for v in &self.items {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.items {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.items {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RepeatedNamedVersion {
+        RepeatedNamedVersion::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<NamedVersion>>(
+                "items",
+                |m: &RepeatedNamedVersion| { &m.items },
+                |m: &mut RepeatedNamedVersion| { &mut m.items },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedNamedVersion>(
+                "RepeatedNamedVersion",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RepeatedNamedVersion {
+        static instance: ::protobuf::rt::LazyV2<RepeatedNamedVersion> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RepeatedNamedVersion::new)
+    }
+}
+
+impl ::protobuf::Clear for RepeatedNamedVersion {
+    fn clear(&mut self) {
+        self.items.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RepeatedNamedVersion {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RepeatedNamedVersion {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\tdoc.proto\"5\n\x0fCreateDocParams\x12\x0e\n\x02id\x18\x01\x20\x01(\t\
     R\x02id\x12\x12\n\x04data\x18\x02\x20\x01(\tR\x04data\"`\n\x03Doc\x12\
@@ -1339,57 +3026,142 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     cId\x12\x12\n\x04data\x18\x02\x20\x01(\tR\x04data\"S\n\nNewDocUser\x12\
     \x17\n\x07user_id\x18\x01\x20\x01(\tR\x06userId\x12\x15\n\x06rev_id\x18\
     \x02\x20\x01(\x03R\x05revId\x12\x15\n\x06doc_id\x18\x03\x20\x01(\tR\x05d\
-    ocId\"&\n\rDocIdentifier\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docI\
-    dJ\xdb\x07\n\x06\x12\x04\0\0\x1c\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\
-    \n\x02\x04\0\x12\x04\x02\0\x05\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\
-    \x17\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x12\n\x0c\n\x05\x04\0\x02\0\
-    \x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\r\n\
-    \x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x10\x11\n\x0b\n\x04\x04\0\x02\x01\
-    \x12\x03\x04\x04\x14\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x04\n\n\
-    \x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x0b\x0f\n\x0c\n\x05\x04\0\x02\
-    \x01\x03\x12\x03\x04\x12\x13\n\n\n\x02\x04\x01\x12\x04\x06\0\x0b\x01\n\n\
-    \n\x03\x04\x01\x01\x12\x03\x06\x08\x0b\n\x0b\n\x04\x04\x01\x02\0\x12\x03\
-    \x07\x04\x12\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x07\x04\n\n\x0c\n\x05\
-    \x04\x01\x02\0\x01\x12\x03\x07\x0b\r\n\x0c\n\x05\x04\x01\x02\0\x03\x12\
-    \x03\x07\x10\x11\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x08\x04\x14\n\x0c\n\
-    \x05\x04\x01\x02\x01\x05\x12\x03\x08\x04\n\n\x0c\n\x05\x04\x01\x02\x01\
-    \x01\x12\x03\x08\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x08\x12\
-    \x13\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\t\x04\x15\n\x0c\n\x05\x04\x01\
-    \x02\x02\x05\x12\x03\t\x04\t\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\t\n\
-    \x10\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\t\x13\x14\n\x0b\n\x04\x04\
-    \x01\x02\x03\x12\x03\n\x04\x1a\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03\n\
-    \x04\t\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\n\n\x15\n\x0c\n\x05\x04\
-    \x01\x02\x03\x03\x12\x03\n\x18\x19\n\n\n\x02\x04\x02\x12\x04\x0c\0\x10\
-    \x01\n\n\n\x03\x04\x02\x01\x12\x03\x0c\x08\x17\n\x0b\n\x04\x04\x02\x02\0\
-    \x12\x03\r\x04\x16\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\r\x04\n\n\x0c\n\
-    \x05\x04\x02\x02\0\x01\x12\x03\r\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x03\
-    \x12\x03\r\x14\x15\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0e\x04\x14\n\x0c\
-    \n\x05\x04\x02\x02\x01\x05\x12\x03\x0e\x04\n\n\x0c\n\x05\x04\x02\x02\x01\
-    \x01\x12\x03\x0e\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0e\x12\
-    \x13\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x0f\x04\x15\n\x0c\n\x05\x04\x02\
-    \x02\x02\x05\x12\x03\x0f\x04\t\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\
-    \x0f\n\x10\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x0f\x13\x14\n\n\n\x02\
-    \x04\x03\x12\x04\x11\0\x14\x01\n\n\n\x03\x04\x03\x01\x12\x03\x11\x08\x10\
-    \n\x0b\n\x04\x04\x03\x02\0\x12\x03\x12\x04\x16\n\x0c\n\x05\x04\x03\x02\0\
-    \x05\x12\x03\x12\x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x12\x0b\x11\
-    \n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x12\x14\x15\n\x0b\n\x04\x04\x03\
-    \x02\x01\x12\x03\x13\x04\x14\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x13\
-    \x04\n\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\x13\x0b\x0f\n\x0c\n\x05\
-    \x04\x03\x02\x01\x03\x12\x03\x13\x12\x13\n\n\n\x02\x04\x04\x12\x04\x15\0\
-    \x19\x01\n\n\n\x03\x04\x04\x01\x12\x03\x15\x08\x12\n\x0b\n\x04\x04\x04\
-    \x02\0\x12\x03\x16\x04\x17\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03\x16\x04\
-    \n\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x16\x0b\x12\n\x0c\n\x05\x04\x04\
-    \x02\0\x03\x12\x03\x16\x15\x16\n\x0b\n\x04\x04\x04\x02\x01\x12\x03\x17\
-    \x04\x15\n\x0c\n\x05\x04\x04\x02\x01\x05\x12\x03\x17\x04\t\n\x0c\n\x05\
-    \x04\x04\x02\x01\x01\x12\x03\x17\n\x10\n\x0c\n\x05\x04\x04\x02\x01\x03\
-    \x12\x03\x17\x13\x14\n\x0b\n\x04\x04\x04\x02\x02\x12\x03\x18\x04\x16\n\
-    \x0c\n\x05\x04\x04\x02\x02\x05\x12\x03\x18\x04\n\n\x0c\n\x05\x04\x04\x02\
-    \x02\x01\x12\x03\x18\x0b\x11\n\x0c\n\x05\x04\x04\x02\x02\x03\x12\x03\x18\
-    \x14\x15\n\n\n\x02\x04\x05\x12\x04\x1a\0\x1c\x01\n\n\n\x03\x04\x05\x01\
-    \x12\x03\x1a\x08\x15\n\x0b\n\x04\x04\x05\x02\0\x12\x03\x1b\x04\x16\n\x0c\
-    \n\x05\x04\x05\x02\0\x05\x12\x03\x1b\x04\n\n\x0c\n\x05\x04\x05\x02\0\x01\
-    \x12\x03\x1b\x0b\x11\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03\x1b\x14\x15b\
-    \x06proto3\
+    ocId\"a\n\x0cDocAwareness\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05doc\
+    Id\x12\x17\n\x07user_id\x18\x02\x20\x01(\tR\x06userId\x12!\n\x0chas_unsy\
+    nced\x18\x03\x20\x01(\x08R\x0bhasUnsynced\"&\n\rDocIdentifier\x12\x15\n\
+    \x06doc_id\x18\x01\x20\x01(\tR\x05docId\"\x95\x01\n\nDocSession\x12\x1d\
+    \n\nsession_id\x18\x01\x20\x01(\tR\tsessionId\x12\x17\n\x07user_id\x18\
+    \x02\x20\x01(\tR\x06userId\x12,\n\x12last_activity_time\x18\x03\x20\x01(\
+    \x03R\x10lastActivityTime\x12!\n\x0chas_unsynced\x18\x04\x20\x01(\x08R\
+    \x0bhasUnsynced\"7\n\x12RepeatedDocSession\x12!\n\x05items\x18\x01\x20\
+    \x03(\x0b2\x0b.DocSessionR\x05items\"\xc0\x01\n\x08DocStats\x12\x15\n\
+    \x06doc_id\x18\x01\x20\x01(\tR\x05docId\x12\x1d\n\nword_count\x18\x02\
+    \x20\x01(\x03R\twordCount\x12\x1d\n\nchar_count\x18\x03\x20\x01(\x03R\tc\
+    harCount\x12\x1d\n\nline_count\x18\x04\x20\x01(\x03R\tlineCount\x12\x1b\
+    \n\trev_count\x18\x05\x20\x01(\x03R\x08revCount\x12#\n\rmodified_time\
+    \x18\x06\x20\x01(\x03R\x0cmodifiedTime\".\n\x18CreateNamedVersionParams\
+    \x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\"\x9e\x01\n\x0cNamedVersi\
+    on\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12\x15\n\x06doc_id\x18\x02\
+    \x20\x01(\tR\x05docId\x12\x15\n\x06rev_id\x18\x03\x20\x01(\x03R\x05revId\
+    \x12\x12\n\x04name\x18\x04\x20\x01(\tR\x04name\x12\x1d\n\ncreated_by\x18\
+    \x05\x20\x01(\tR\tcreatedBy\x12\x1d\n\ncreated_at\x18\x06\x20\x01(\x03R\
+    \tcreatedAt\";\n\x14RepeatedNamedVersion\x12#\n\x05items\x18\x01\x20\x03\
+    (\x0b2\r.NamedVersionR\x05itemsJ\xd9\x12\n\x06\x12\x04\0\0@\x01\n\x08\n\
+    \x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x05\x01\n\n\n\x03\
+    \x04\0\x01\x12\x03\x02\x08\x17\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\
+    \x12\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\
+    \0\x01\x12\x03\x03\x0b\r\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x10\x11\
+    \n\x0b\n\x04\x04\0\x02\x01\x12\x03\x04\x04\x14\n\x0c\n\x05\x04\0\x02\x01\
+    \x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x0b\x0f\
+    \n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x04\x12\x13\n\n\n\x02\x04\x01\x12\
+    \x04\x06\0\x0b\x01\n\n\n\x03\x04\x01\x01\x12\x03\x06\x08\x0b\n\x0b\n\x04\
+    \x04\x01\x02\0\x12\x03\x07\x04\x12\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\
+    \x07\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x07\x0b\r\n\x0c\n\x05\
+    \x04\x01\x02\0\x03\x12\x03\x07\x10\x11\n\x0b\n\x04\x04\x01\x02\x01\x12\
+    \x03\x08\x04\x14\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\x08\x04\n\n\x0c\
+    \n\x05\x04\x01\x02\x01\x01\x12\x03\x08\x0b\x0f\n\x0c\n\x05\x04\x01\x02\
+    \x01\x03\x12\x03\x08\x12\x13\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\t\x04\
+    \x15\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\t\x04\t\n\x0c\n\x05\x04\x01\
+    \x02\x02\x01\x12\x03\t\n\x10\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\t\
+    \x13\x14\n\x0b\n\x04\x04\x01\x02\x03\x12\x03\n\x04\x1a\n\x0c\n\x05\x04\
+    \x01\x02\x03\x05\x12\x03\n\x04\t\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\
+    \n\n\x15\n\x0c\n\x05\x04\x01\x02\x03\x03\x12\x03\n\x18\x19\n\n\n\x02\x04\
+    \x02\x12\x04\x0c\0\x10\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0c\x08\x17\n\
+    \x0b\n\x04\x04\x02\x02\0\x12\x03\r\x04\x16\n\x0c\n\x05\x04\x02\x02\0\x05\
+    \x12\x03\r\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\r\x0b\x11\n\x0c\n\
+    \x05\x04\x02\x02\0\x03\x12\x03\r\x14\x15\n\x0b\n\x04\x04\x02\x02\x01\x12\
+    \x03\x0e\x04\x14\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x0e\x04\n\n\x0c\
+    \n\x05\x04\x02\x02\x01\x01\x12\x03\x0e\x0b\x0f\n\x0c\n\x05\x04\x02\x02\
+    \x01\x03\x12\x03\x0e\x12\x13\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x0f\x04\
+    \x15\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x0f\x04\t\n\x0c\n\x05\x04\
+    \x02\x02\x02\x01\x12\x03\x0f\n\x10\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\
+    \x03\x0f\x13\x14\n\n\n\x02\x04\x03\x12\x04\x11\0\x14\x01\n\n\n\x03\x04\
+    \x03\x01\x12\x03\x11\x08\x10\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x12\x04\
+    \x16\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x12\x04\n\n\x0c\n\x05\x04\x03\
+    \x02\0\x01\x12\x03\x12\x0b\x11\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x12\
+    \x14\x15\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x13\x04\x14\n\x0c\n\x05\x04\
+    \x03\x02\x01\x05\x12\x03\x13\x04\n\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\
+    \x03\x13\x0b\x0f\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x13\x12\x13\n\n\
+    \n\x02\x04\x04\x12\x04\x15\0\x19\x01\n\n\n\x03\x04\x04\x01\x12\x03\x15\
+    \x08\x12\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x16\x04\x17\n\x0c\n\x05\x04\
+    \x04\x02\0\x05\x12\x03\x16\x04\n\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\
+    \x16\x0b\x12\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x16\x15\x16\n\x0b\n\
+    \x04\x04\x04\x02\x01\x12\x03\x17\x04\x15\n\x0c\n\x05\x04\x04\x02\x01\x05\
+    \x12\x03\x17\x04\t\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03\x17\n\x10\n\
+    \x0c\n\x05\x04\x04\x02\x01\x03\x12\x03\x17\x13\x14\n\x0b\n\x04\x04\x04\
+    \x02\x02\x12\x03\x18\x04\x16\n\x0c\n\x05\x04\x04\x02\x02\x05\x12\x03\x18\
+    \x04\n\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\x03\x18\x0b\x11\n\x0c\n\x05\
+    \x04\x04\x02\x02\x03\x12\x03\x18\x14\x15\n\n\n\x02\x04\x05\x12\x04\x1a\0\
+    \x1e\x01\n\n\n\x03\x04\x05\x01\x12\x03\x1a\x08\x14\n\x0b\n\x04\x04\x05\
+    \x02\0\x12\x03\x1b\x04\x16\n\x0c\n\x05\x04\x05\x02\0\x05\x12\x03\x1b\x04\
+    \n\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03\x1b\x0b\x11\n\x0c\n\x05\x04\x05\
+    \x02\0\x03\x12\x03\x1b\x14\x15\n\x0b\n\x04\x04\x05\x02\x01\x12\x03\x1c\
+    \x04\x17\n\x0c\n\x05\x04\x05\x02\x01\x05\x12\x03\x1c\x04\n\n\x0c\n\x05\
+    \x04\x05\x02\x01\x01\x12\x03\x1c\x0b\x12\n\x0c\n\x05\x04\x05\x02\x01\x03\
+    \x12\x03\x1c\x15\x16\n\x0b\n\x04\x04\x05\x02\x02\x12\x03\x1d\x04\x1a\n\
+    \x0c\n\x05\x04\x05\x02\x02\x05\x12\x03\x1d\x04\x08\n\x0c\n\x05\x04\x05\
+    \x02\x02\x01\x12\x03\x1d\t\x15\n\x0c\n\x05\x04\x05\x02\x02\x03\x12\x03\
+    \x1d\x18\x19\n\n\n\x02\x04\x06\x12\x04\x1f\0!\x01\n\n\n\x03\x04\x06\x01\
+    \x12\x03\x1f\x08\x15\n\x0b\n\x04\x04\x06\x02\0\x12\x03\x20\x04\x16\n\x0c\
+    \n\x05\x04\x06\x02\0\x05\x12\x03\x20\x04\n\n\x0c\n\x05\x04\x06\x02\0\x01\
+    \x12\x03\x20\x0b\x11\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03\x20\x14\x15\n\
+    \n\n\x02\x04\x07\x12\x04\"\0'\x01\n\n\n\x03\x04\x07\x01\x12\x03\"\x08\
+    \x12\n\x0b\n\x04\x04\x07\x02\0\x12\x03#\x04\x1a\n\x0c\n\x05\x04\x07\x02\
+    \0\x05\x12\x03#\x04\n\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x03#\x0b\x15\n\
+    \x0c\n\x05\x04\x07\x02\0\x03\x12\x03#\x18\x19\n\x0b\n\x04\x04\x07\x02\
+    \x01\x12\x03$\x04\x17\n\x0c\n\x05\x04\x07\x02\x01\x05\x12\x03$\x04\n\n\
+    \x0c\n\x05\x04\x07\x02\x01\x01\x12\x03$\x0b\x12\n\x0c\n\x05\x04\x07\x02\
+    \x01\x03\x12\x03$\x15\x16\n\x0b\n\x04\x04\x07\x02\x02\x12\x03%\x04!\n\
+    \x0c\n\x05\x04\x07\x02\x02\x05\x12\x03%\x04\t\n\x0c\n\x05\x04\x07\x02\
+    \x02\x01\x12\x03%\n\x1c\n\x0c\n\x05\x04\x07\x02\x02\x03\x12\x03%\x1f\x20\
+    \n\x0b\n\x04\x04\x07\x02\x03\x12\x03&\x04\x1a\n\x0c\n\x05\x04\x07\x02\
+    \x03\x05\x12\x03&\x04\x08\n\x0c\n\x05\x04\x07\x02\x03\x01\x12\x03&\t\x15\
+    \n\x0c\n\x05\x04\x07\x02\x03\x03\x12\x03&\x18\x19\n\n\n\x02\x04\x08\x12\
+    \x04(\0*\x01\n\n\n\x03\x04\x08\x01\x12\x03(\x08\x1a\n\x0b\n\x04\x04\x08\
+    \x02\0\x12\x03)\x04\"\n\x0c\n\x05\x04\x08\x02\0\x04\x12\x03)\x04\x0c\n\
+    \x0c\n\x05\x04\x08\x02\0\x06\x12\x03)\r\x17\n\x0c\n\x05\x04\x08\x02\0\
+    \x01\x12\x03)\x18\x1d\n\x0c\n\x05\x04\x08\x02\0\x03\x12\x03)\x20!\n\n\n\
+    \x02\x04\t\x12\x04+\02\x01\n\n\n\x03\x04\t\x01\x12\x03+\x08\x10\n\x0b\n\
+    \x04\x04\t\x02\0\x12\x03,\x04\x16\n\x0c\n\x05\x04\t\x02\0\x05\x12\x03,\
+    \x04\n\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03,\x0b\x11\n\x0c\n\x05\x04\t\
+    \x02\0\x03\x12\x03,\x14\x15\n\x0b\n\x04\x04\t\x02\x01\x12\x03-\x04\x19\n\
+    \x0c\n\x05\x04\t\x02\x01\x05\x12\x03-\x04\t\n\x0c\n\x05\x04\t\x02\x01\
+    \x01\x12\x03-\n\x14\n\x0c\n\x05\x04\t\x02\x01\x03\x12\x03-\x17\x18\n\x0b\
+    \n\x04\x04\t\x02\x02\x12\x03.\x04\x19\n\x0c\n\x05\x04\t\x02\x02\x05\x12\
+    \x03.\x04\t\n\x0c\n\x05\x04\t\x02\x02\x01\x12\x03.\n\x14\n\x0c\n\x05\x04\
+    \t\x02\x02\x03\x12\x03.\x17\x18\n\x0b\n\x04\x04\t\x02\x03\x12\x03/\x04\
+    \x19\n\x0c\n\x05\x04\t\x02\x03\x05\x12\x03/\x04\t\n\x0c\n\x05\x04\t\x02\
+    \x03\x01\x12\x03/\n\x14\n\x0c\n\x05\x04\t\x02\x03\x03\x12\x03/\x17\x18\n\
+    \x0b\n\x04\x04\t\x02\x04\x12\x030\x04\x18\n\x0c\n\x05\x04\t\x02\x04\x05\
+    \x12\x030\x04\t\n\x0c\n\x05\x04\t\x02\x04\x01\x12\x030\n\x13\n\x0c\n\x05\
+    \x04\t\x02\x04\x03\x12\x030\x16\x17\n\x0b\n\x04\x04\t\x02\x05\x12\x031\
+    \x04\x1c\n\x0c\n\x05\x04\t\x02\x05\x05\x12\x031\x04\t\n\x0c\n\x05\x04\t\
+    \x02\x05\x01\x12\x031\n\x17\n\x0c\n\x05\x04\t\x02\x05\x03\x12\x031\x1a\
+    \x1b\n\n\n\x02\x04\n\x12\x043\05\x01\n\n\n\x03\x04\n\x01\x12\x033\x08\
+    \x20\n\x0b\n\x04\x04\n\x02\0\x12\x034\x04\x14\n\x0c\n\x05\x04\n\x02\0\
+    \x05\x12\x034\x04\n\n\x0c\n\x05\x04\n\x02\0\x01\x12\x034\x0b\x0f\n\x0c\n\
+    \x05\x04\n\x02\0\x03\x12\x034\x12\x13\n\n\n\x02\x04\x0b\x12\x046\0=\x01\
+    \n\n\n\x03\x04\x0b\x01\x12\x036\x08\x14\n\x0b\n\x04\x04\x0b\x02\0\x12\
+    \x037\x04\x12\n\x0c\n\x05\x04\x0b\x02\0\x05\x12\x037\x04\n\n\x0c\n\x05\
+    \x04\x0b\x02\0\x01\x12\x037\x0b\r\n\x0c\n\x05\x04\x0b\x02\0\x03\x12\x037\
+    \x10\x11\n\x0b\n\x04\x04\x0b\x02\x01\x12\x038\x04\x16\n\x0c\n\x05\x04\
+    \x0b\x02\x01\x05\x12\x038\x04\n\n\x0c\n\x05\x04\x0b\x02\x01\x01\x12\x038\
+    \x0b\x11\n\x0c\n\x05\x04\x0b\x02\x01\x03\x12\x038\x14\x15\n\x0b\n\x04\
+    \x04\x0b\x02\x02\x12\x039\x04\x15\n\x0c\n\x05\x04\x0b\x02\x02\x05\x12\
+    \x039\x04\t\n\x0c\n\x05\x04\x0b\x02\x02\x01\x12\x039\n\x10\n\x0c\n\x05\
+    \x04\x0b\x02\x02\x03\x12\x039\x13\x14\n\x0b\n\x04\x04\x0b\x02\x03\x12\
+    \x03:\x04\x14\n\x0c\n\x05\x04\x0b\x02\x03\x05\x12\x03:\x04\n\n\x0c\n\x05\
+    \x04\x0b\x02\x03\x01\x12\x03:\x0b\x0f\n\x0c\n\x05\x04\x0b\x02\x03\x03\
+    \x12\x03:\x12\x13\n\x0b\n\x04\x04\x0b\x02\x04\x12\x03;\x04\x1a\n\x0c\n\
+    \x05\x04\x0b\x02\x04\x05\x12\x03;\x04\n\n\x0c\n\x05\x04\x0b\x02\x04\x01\
+    \x12\x03;\x0b\x15\n\x0c\n\x05\x04\x0b\x02\x04\x03\x12\x03;\x18\x19\n\x0b\
+    \n\x04\x04\x0b\x02\x05\x12\x03<\x04\x19\n\x0c\n\x05\x04\x0b\x02\x05\x05\
+    \x12\x03<\x04\t\n\x0c\n\x05\x04\x0b\x02\x05\x01\x12\x03<\n\x14\n\x0c\n\
+    \x05\x04\x0b\x02\x05\x03\x12\x03<\x17\x18\n\n\n\x02\x04\x0c\x12\x04>\0@\
+    \x01\n\n\n\x03\x04\x0c\x01\x12\x03>\x08\x1c\n\x0b\n\x04\x04\x0c\x02\0\
+    \x12\x03?\x04$\n\x0c\n\x05\x04\x0c\x02\0\x04\x12\x03?\x04\x0c\n\x0c\n\
+    \x05\x04\x0c\x02\0\x06\x12\x03?\r\x19\n\x0c\n\x05\x04\x0c\x02\0\x01\x12\
+    \x03?\x1a\x1f\n\x0c\n\x05\x04\x0c\x02\0\x03\x12\x03?\"#b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -175,6 +175,235 @@ impl ::protobuf::reflect::ProtobufValue for RevId {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct RevAck {
+    // message fields
+    pub rev_id: i64,
+    pub base_rev_id: i64,
+    pub md5: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RevAck {
+    fn default() -> &'a RevAck {
+        <RevAck as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RevAck {
+    pub fn new() -> RevAck {
+        ::std::default::Default::default()
+    }
+
+    // int64 rev_id = 1;
+
+
+    pub fn get_rev_id(&self) -> i64 {
+        self.rev_id
+    }
+    pub fn clear_rev_id(&mut self) {
+        self.rev_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rev_id(&mut self, v: i64) {
+        self.rev_id = v;
+    }
+
+    // int64 base_rev_id = 2;
+
+
+    pub fn get_base_rev_id(&self) -> i64 {
+        self.base_rev_id
+    }
+    pub fn clear_base_rev_id(&mut self) {
+        self.base_rev_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_base_rev_id(&mut self, v: i64) {
+        self.base_rev_id = v;
+    }
+
+    // string md5 = 3;
+
+
+    pub fn get_md5(&self) -> &str {
+        &self.md5
+    }
+    pub fn clear_md5(&mut self) {
+        self.md5.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_md5(&mut self, v: ::std::string::String) {
+        self.md5 = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_md5(&mut self) -> &mut ::std::string::String {
+        &mut self.md5
+    }
+
+    // Take field
+    pub fn take_md5(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.md5, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RevAck {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.rev_id = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.base_rev_id = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.md5)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.rev_id != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.rev_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.base_rev_id != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.base_rev_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.md5.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.md5);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.rev_id != 0 {
+            os.write_int64(1, self.rev_id)?;
+        }
+        if self.base_rev_id != 0 {
+            os.write_int64(2, self.base_rev_id)?;
+        }
+        if !self.md5.is_empty() {
+            os.write_string(3, &self.md5)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RevAck {
+        RevAck::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "rev_id",
+                |m: &RevAck| { &m.rev_id },
+                |m: &mut RevAck| { &mut m.rev_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "base_rev_id",
+                |m: &RevAck| { &m.base_rev_id },
+                |m: &mut RevAck| { &mut m.base_rev_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "md5",
+                |m: &RevAck| { &m.md5 },
+                |m: &mut RevAck| { &mut m.md5 },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RevAck>(
+                "RevAck",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RevAck {
+        static instance: ::protobuf::rt::LazyV2<RevAck> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RevAck::new)
+    }
+}
+
+impl ::protobuf::Clear for RevAck {
+    fn clear(&mut self) {
+        self.rev_id = 0;
+        self.base_rev_id = 0;
+        self.md5.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RevAck {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RevAck {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct Revision {
     // message fields
@@ -184,6 +413,7 @@ pub struct Revision {
     pub md5: ::std::string::String,
     pub doc_id: ::std::string::String,
     pub ty: RevType,
+    pub created_at: i64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -322,6 +552,21 @@ impl Revision {
     pub fn set_ty(&mut self, v: RevType) {
         self.ty = v;
     }
+
+    // int64 created_at = 7;
+
+
+    pub fn get_created_at(&self) -> i64 {
+        self.created_at
+    }
+    pub fn clear_created_at(&mut self) {
+        self.created_at = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_at(&mut self, v: i64) {
+        self.created_at = v;
+    }
 }
 
 impl ::protobuf::Message for Revision {
@@ -359,6 +604,13 @@ impl ::protobuf::Message for Revision {
                 6 => {
                     ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.ty, 6, &mut self.unknown_fields)?
                 },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.created_at = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -389,6 +641,9 @@ impl ::protobuf::Message for Revision {
         if self.ty != RevType::Local {
             my_size += ::protobuf::rt::enum_size(6, self.ty);
         }
+        if self.created_at != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.created_at, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -413,6 +668,9 @@ impl ::protobuf::Message for Revision {
         if self.ty != RevType::Local {
             os.write_enum(6, ::protobuf::ProtobufEnum::value(&self.ty))?;
         }
+        if self.created_at != 0 {
+            os.write_int64(7, self.created_at)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -481,6 +739,11 @@ impl ::protobuf::Message for Revision {
                 |m: &Revision| { &m.ty },
                 |m: &mut Revision| { &mut m.ty },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "created_at",
+                |m: &Revision| { &m.created_at },
+                |m: &mut Revision| { &mut m.created_at },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<Revision>(
                 "Revision",
                 fields,
@@ -503,6 +766,7 @@ impl ::protobuf::Clear for Revision {
         self.md5.clear();
         self.doc_id.clear();
         self.ty = RevType::Local;
+        self.created_at = 0;
         self.unknown_fields.clear();
     }
 }
@@ -800,52 +1064,67 @@ impl ::protobuf::reflect::ProtobufValue for RevType {
 
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x0erevision.proto\"\x1d\n\x05RevId\x12\x14\n\x05value\x18\x01\x20\x01\
-    (\x03R\x05value\"\xa3\x01\n\x08Revision\x12\x1e\n\x0bbase_rev_id\x18\x01\
-    \x20\x01(\x03R\tbaseRevId\x12\x15\n\x06rev_id\x18\x02\x20\x01(\x03R\x05r\
-    evId\x12\x1d\n\ndelta_data\x18\x03\x20\x01(\x0cR\tdeltaData\x12\x10\n\
-    \x03md5\x18\x04\x20\x01(\tR\x03md5\x12\x15\n\x06doc_id\x18\x05\x20\x01(\
-    \tR\x05docId\x12\x18\n\x02ty\x18\x06\x20\x01(\x0e2\x08.RevTypeR\x02ty\"N\
-    \n\rRevisionRange\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05docId\x12\
-    \x14\n\x05start\x18\x02\x20\x01(\x03R\x05start\x12\x10\n\x03end\x18\x03\
-    \x20\x01(\x03R\x03end*\x20\n\x07RevType\x12\t\n\x05Local\x10\0\x12\n\n\
-    \x06Remote\x10\x01J\xea\x05\n\x06\x12\x04\0\0\x15\x01\n\x08\n\x01\x0c\
-    \x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x04\x01\n\n\n\x03\x04\0\
-    \x01\x12\x03\x02\x08\r\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x14\n\x0c\
-    \n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\t\n\x0c\n\x05\x04\0\x02\0\x01\x12\
-    \x03\x03\n\x0f\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x12\x13\n\n\n\x02\
-    \x04\x01\x12\x04\x05\0\x0c\x01\n\n\n\x03\x04\x01\x01\x12\x03\x05\x08\x10\
-    \n\x0b\n\x04\x04\x01\x02\0\x12\x03\x06\x04\x1a\n\x0c\n\x05\x04\x01\x02\0\
-    \x05\x12\x03\x06\x04\t\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x06\n\x15\n\
-    \x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x06\x18\x19\n\x0b\n\x04\x04\x01\x02\
-    \x01\x12\x03\x07\x04\x15\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\x07\x04\
-    \t\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x07\n\x10\n\x0c\n\x05\x04\x01\
-    \x02\x01\x03\x12\x03\x07\x13\x14\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\x08\
-    \x04\x19\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\x08\x04\t\n\x0c\n\x05\
-    \x04\x01\x02\x02\x01\x12\x03\x08\n\x14\n\x0c\n\x05\x04\x01\x02\x02\x03\
-    \x12\x03\x08\x17\x18\n\x0b\n\x04\x04\x01\x02\x03\x12\x03\t\x04\x13\n\x0c\
-    \n\x05\x04\x01\x02\x03\x05\x12\x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\x03\
-    \x01\x12\x03\t\x0b\x0e\n\x0c\n\x05\x04\x01\x02\x03\x03\x12\x03\t\x11\x12\
-    \n\x0b\n\x04\x04\x01\x02\x04\x12\x03\n\x04\x16\n\x0c\n\x05\x04\x01\x02\
-    \x04\x05\x12\x03\n\x04\n\n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03\n\x0b\
-    \x11\n\x0c\n\x05\x04\x01\x02\x04\x03\x12\x03\n\x14\x15\n\x0b\n\x04\x04\
-    \x01\x02\x05\x12\x03\x0b\x04\x13\n\x0c\n\x05\x04\x01\x02\x05\x06\x12\x03\
-    \x0b\x04\x0b\n\x0c\n\x05\x04\x01\x02\x05\x01\x12\x03\x0b\x0c\x0e\n\x0c\n\
-    \x05\x04\x01\x02\x05\x03\x12\x03\x0b\x11\x12\n\n\n\x02\x04\x02\x12\x04\r\
-    \0\x11\x01\n\n\n\x03\x04\x02\x01\x12\x03\r\x08\x15\n\x0b\n\x04\x04\x02\
-    \x02\0\x12\x03\x0e\x04\x16\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0e\x04\
-    \n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0e\x0b\x11\n\x0c\n\x05\x04\x02\
-    \x02\0\x03\x12\x03\x0e\x14\x15\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0f\
-    \x04\x14\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x0f\x04\t\n\x0c\n\x05\
-    \x04\x02\x02\x01\x01\x12\x03\x0f\n\x0f\n\x0c\n\x05\x04\x02\x02\x01\x03\
-    \x12\x03\x0f\x12\x13\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x10\x04\x12\n\
-    \x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x10\x04\t\n\x0c\n\x05\x04\x02\x02\
-    \x02\x01\x12\x03\x10\n\r\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x10\x10\
-    \x11\n\n\n\x02\x05\0\x12\x04\x12\0\x15\x01\n\n\n\x03\x05\0\x01\x12\x03\
-    \x12\x05\x0c\n\x0b\n\x04\x05\0\x02\0\x12\x03\x13\x04\x0e\n\x0c\n\x05\x05\
-    \0\x02\0\x01\x12\x03\x13\x04\t\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x13\
-    \x0c\r\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x14\x04\x0f\n\x0c\n\x05\x05\0\
-    \x02\x01\x01\x12\x03\x14\x04\n\n\x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x14\
-    \r\x0eb\x06proto3\
+    (\x03R\x05value\"Q\n\x06RevAck\x12\x15\n\x06rev_id\x18\x01\x20\x01(\x03R\
+    \x05revId\x12\x1e\n\x0bbase_rev_id\x18\x02\x20\x01(\x03R\tbaseRevId\x12\
+    \x10\n\x03md5\x18\x03\x20\x01(\tR\x03md5\"\xc2\x01\n\x08Revision\x12\x1e\
+    \n\x0bbase_rev_id\x18\x01\x20\x01(\x03R\tbaseRevId\x12\x15\n\x06rev_id\
+    \x18\x02\x20\x01(\x03R\x05revId\x12\x1d\n\ndelta_data\x18\x03\x20\x01(\
+    \x0cR\tdeltaData\x12\x10\n\x03md5\x18\x04\x20\x01(\tR\x03md5\x12\x15\n\
+    \x06doc_id\x18\x05\x20\x01(\tR\x05docId\x12\x18\n\x02ty\x18\x06\x20\x01(\
+    \x0e2\x08.RevTypeR\x02ty\x12\x1d\n\ncreated_at\x18\x07\x20\x01(\x03R\tcr\
+    eatedAt\"N\n\rRevisionRange\x12\x15\n\x06doc_id\x18\x01\x20\x01(\tR\x05d\
+    ocId\x12\x14\n\x05start\x18\x02\x20\x01(\x03R\x05start\x12\x10\n\x03end\
+    \x18\x03\x20\x01(\x03R\x03end*\x20\n\x07RevType\x12\t\n\x05Local\x10\0\
+    \x12\n\n\x06Remote\x10\x01J\xde\x07\n\x06\x12\x04\0\0\x1b\x01\n\x08\n\
+    \x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\x04\x01\n\n\n\x03\
+    \x04\0\x01\x12\x03\x02\x08\r\n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x14\
+    \n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\t\n\x0c\n\x05\x04\0\x02\0\
+    \x01\x12\x03\x03\n\x0f\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x03\x12\x13\n\
+    \n\n\x02\x04\x01\x12\x04\x05\0\t\x01\n\n\n\x03\x04\x01\x01\x12\x03\x05\
+    \x08\x0e\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x06\x04\x15\n\x0c\n\x05\x04\
+    \x01\x02\0\x05\x12\x03\x06\x04\t\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\
+    \x06\n\x10\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x06\x13\x14\n\x0b\n\x04\
+    \x04\x01\x02\x01\x12\x03\x07\x04\x1a\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\
+    \x03\x07\x04\t\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x07\n\x15\n\x0c\n\
+    \x05\x04\x01\x02\x01\x03\x12\x03\x07\x18\x19\n\x0b\n\x04\x04\x01\x02\x02\
+    \x12\x03\x08\x04\x13\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\x08\x04\n\n\
+    \x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x08\x0b\x0e\n\x0c\n\x05\x04\x01\
+    \x02\x02\x03\x12\x03\x08\x11\x12\n\n\n\x02\x04\x02\x12\x04\n\0\x12\x01\n\
+    \n\n\x03\x04\x02\x01\x12\x03\n\x08\x10\n\x0b\n\x04\x04\x02\x02\0\x12\x03\
+    \x0b\x04\x1a\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0b\x04\t\n\x0c\n\x05\
+    \x04\x02\x02\0\x01\x12\x03\x0b\n\x15\n\x0c\n\x05\x04\x02\x02\0\x03\x12\
+    \x03\x0b\x18\x19\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0c\x04\x15\n\x0c\n\
+    \x05\x04\x02\x02\x01\x05\x12\x03\x0c\x04\t\n\x0c\n\x05\x04\x02\x02\x01\
+    \x01\x12\x03\x0c\n\x10\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0c\x13\
+    \x14\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\r\x04\x19\n\x0c\n\x05\x04\x02\
+    \x02\x02\x05\x12\x03\r\x04\t\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\r\n\
+    \x14\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\r\x17\x18\n\x0b\n\x04\x04\
+    \x02\x02\x03\x12\x03\x0e\x04\x13\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\
+    \x0e\x04\n\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x0e\x0b\x0e\n\x0c\n\
+    \x05\x04\x02\x02\x03\x03\x12\x03\x0e\x11\x12\n\x0b\n\x04\x04\x02\x02\x04\
+    \x12\x03\x0f\x04\x16\n\x0c\n\x05\x04\x02\x02\x04\x05\x12\x03\x0f\x04\n\n\
+    \x0c\n\x05\x04\x02\x02\x04\x01\x12\x03\x0f\x0b\x11\n\x0c\n\x05\x04\x02\
+    \x02\x04\x03\x12\x03\x0f\x14\x15\n\x0b\n\x04\x04\x02\x02\x05\x12\x03\x10\
+    \x04\x13\n\x0c\n\x05\x04\x02\x02\x05\x06\x12\x03\x10\x04\x0b\n\x0c\n\x05\
+    \x04\x02\x02\x05\x01\x12\x03\x10\x0c\x0e\n\x0c\n\x05\x04\x02\x02\x05\x03\
+    \x12\x03\x10\x11\x12\n\x0b\n\x04\x04\x02\x02\x06\x12\x03\x11\x04\x19\n\
+    \x0c\n\x05\x04\x02\x02\x06\x05\x12\x03\x11\x04\t\n\x0c\n\x05\x04\x02\x02\
+    \x06\x01\x12\x03\x11\n\x14\n\x0c\n\x05\x04\x02\x02\x06\x03\x12\x03\x11\
+    \x17\x18\n\n\n\x02\x04\x03\x12\x04\x13\0\x17\x01\n\n\n\x03\x04\x03\x01\
+    \x12\x03\x13\x08\x15\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x14\x04\x16\n\x0c\
+    \n\x05\x04\x03\x02\0\x05\x12\x03\x14\x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\
+    \x12\x03\x14\x0b\x11\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x14\x14\x15\n\
+    \x0b\n\x04\x04\x03\x02\x01\x12\x03\x15\x04\x14\n\x0c\n\x05\x04\x03\x02\
+    \x01\x05\x12\x03\x15\x04\t\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\x15\n\
+    \x0f\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x15\x12\x13\n\x0b\n\x04\x04\
+    \x03\x02\x02\x12\x03\x16\x04\x12\n\x0c\n\x05\x04\x03\x02\x02\x05\x12\x03\
+    \x16\x04\t\n\x0c\n\x05\x04\x03\x02\x02\x01\x12\x03\x16\n\r\n\x0c\n\x05\
+    \x04\x03\x02\x02\x03\x12\x03\x16\x10\x11\n\n\n\x02\x05\0\x12\x04\x18\0\
+    \x1b\x01\n\n\n\x03\x05\0\x01\x12\x03\x18\x05\x0c\n\x0b\n\x04\x05\0\x02\0\
+    \x12\x03\x19\x04\x0e\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x19\x04\t\n\x0c\
+    \n\x05\x05\0\x02\0\x02\x12\x03\x19\x0c\r\n\x0b\n\x04\x05\0\x02\x01\x12\
+    \x03\x1a\x04\x0f\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x1a\x04\n\n\x0c\n\
+    \x05\x05\0\x02\x01\x02\x12\x03\x1a\r\x0eb\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -41,6 +41,11 @@ pub struct SignInResponse {
 
     #[pb(index = 4)]
     pub token: String,
+
+    // Only issued by the server signing in with the short-lived access tokens,
+    // exchanged for a new pair of tokens once the access token expires.
+    #[pb(index = 5)]
+    pub refresh_token: String,
 }
 
 impl TryInto<SignInParams> for SignInRequest {
@@ -111,3 +116,39 @@ pub struct SignUpResponse {
     #[pb(index = 4)]
     pub token: String,
 }
+
+#[derive(ProtoBuf, Default, Debug)]
+pub struct RefreshTokenParams {
+    #[pb(index = 1)]
+    pub refresh_token: String,
+}
+
+#[derive(ProtoBuf, Default, Debug)]
+pub struct RefreshTokenResponse {
+    #[pb(index = 1)]
+    pub token: String,
+
+    #[pb(index = 2)]
+    pub refresh_token: String,
+}
+
+#[derive(ProtoBuf, Default, Debug)]
+pub struct ResetPasswordRequestParams {
+    #[pb(index = 1)]
+    pub email: String,
+}
+
+#[derive(ProtoBuf, Default, Debug)]
+pub struct ResetPasswordConfirmParams {
+    #[pb(index = 1)]
+    pub token: String,
+
+    #[pb(index = 2)]
+    pub password: String,
+}
+
+#[derive(ProtoBuf, Default, Debug)]
+pub struct ResendVerificationParams {
+    #[pb(index = 1)]
+    pub email: String,
+}
@@ -3,7 +3,7 @@ use crate::{
     errors::*,
     impl_def_and_def_mut,
     parser::{
-        app::{AppColorStyle, AppName},
+        app::{AppColorStyle, AppId, AppName},
         workspace::WorkspaceId,
     },
 };
@@ -23,6 +23,10 @@ pub struct CreateAppRequest {
 
     #[pb(index = 4)]
     pub color_style: ColorStyle,
+
+    // The app is nested under the parent app if provided.
+    #[pb(index = 5, one_of)]
+    pub parent_id: Option<String>,
 }
 
 #[derive(ProtoBuf, Default, Debug, Clone)]
@@ -44,6 +48,9 @@ pub struct CreateAppParams {
 
     #[pb(index = 4)]
     pub color_style: ColorStyle,
+
+    #[pb(index = 5, one_of)]
+    pub parent_id: Option<String>,
 }
 
 impl TryInto<CreateAppParams> for CreateAppRequest {
@@ -53,12 +60,17 @@ impl TryInto<CreateAppParams> for CreateAppRequest {
         let name = AppName::parse(self.name)?;
         let id = WorkspaceId::parse(self.workspace_id)?;
         let color_style = AppColorStyle::parse(self.color_style.theme_color.clone())?;
+        let parent_id = match self.parent_id {
+            None => None,
+            Some(parent_id) => Some(AppId::parse(parent_id)?.0),
+        };
 
         Ok(CreateAppParams {
             workspace_id: id.0,
             name: name.0,
             desc: self.desc,
             color_style: color_style.into(),
+            parent_id,
         })
     }
 }
@@ -96,6 +108,13 @@ pub struct App {
 
     #[pb(index = 8)]
     pub create_time: i64,
+
+    // The apps nested under the app.
+    #[pb(index = 9)]
+    pub apps: RepeatedApp,
+
+    #[pb(index = 10, one_of)]
+    pub parent_id: Option<String>,
 }
 
 impl App {
@@ -22,6 +22,9 @@ pub struct UpdateAppRequest {
 
     #[pb(index = 5, one_of)]
     pub is_trash: Option<bool>,
+
+    #[pb(index = 6, one_of)]
+    pub parent_id: Option<String>,
 }
 
 #[derive(ProtoBuf, Default, Clone, Debug)]
@@ -40,6 +43,11 @@ pub struct UpdateAppParams {
 
     #[pb(index = 5, one_of)]
     pub is_trash: Option<bool>,
+
+    // Moves the app under the parent app, or to the top of its workspace if
+    // it's empty.
+    #[pb(index = 6, one_of)]
+    pub parent_id: Option<String>,
 }
 
 impl UpdateAppParams {
@@ -64,6 +72,11 @@ impl UpdateAppParams {
         self.is_trash = Some(true);
         self
     }
+
+    pub fn parent_id(mut self, parent_id: &str) -> Self {
+        self.parent_id = Some(parent_id.to_string());
+        self
+    }
 }
 
 impl TryInto<UpdateAppParams> for UpdateAppRequest {
@@ -82,12 +95,18 @@ impl TryInto<UpdateAppParams> for UpdateAppRequest {
             Some(color_style) => Some(AppColorStyle::parse(color_style.theme_color)?.into()),
         };
 
+        let parent_id = match self.parent_id {
+            Some(parent_id) if !parent_id.is_empty() => Some(AppId::parse(parent_id)?.0),
+            parent_id => parent_id,
+        };
+
         Ok(UpdateAppParams {
             app_id,
             name,
             desc: self.desc,
             color_style,
             is_trash: self.is_trash,
+            parent_id,
         })
     }
 }
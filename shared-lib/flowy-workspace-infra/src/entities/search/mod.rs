@@ -0,0 +1,2 @@
+mod search_query;
+pub use search_query::*;
@@ -0,0 +1,43 @@
+use crate::impl_def_and_def_mut;
+use flowy_derive::ProtoBuf;
+
+// The range of a match in the plain text of the document, counted in UTF-16
+// code units like the intervals of the delta.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct SearchMatch {
+    #[pb(index = 1)]
+    pub start: i64,
+
+    #[pb(index = 2)]
+    pub end: i64,
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct SearchResult {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub view_name: String,
+
+    #[pb(index = 3)]
+    pub snippet: String,
+
+    #[pb(index = 4)]
+    pub matches: Vec<SearchMatch>,
+}
+
+#[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
+pub struct RepeatedSearchResult {
+    #[pb(index = 1)]
+    pub items: Vec<SearchResult>,
+}
+
+impl_def_and_def_mut!(RepeatedSearchResult, SearchResult);
+
+// The number of the documents whose search index is rebuilt.
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct SearchReindexResult {
+    #[pb(index = 1)]
+    pub indexed_count: i64,
+}
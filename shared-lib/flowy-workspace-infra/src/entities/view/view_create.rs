@@ -4,7 +4,7 @@ use crate::{
     impl_def_and_def_mut,
     parser::{
         app::AppId,
-        view::{ViewName, ViewThumbnail},
+        view::{ViewId, ViewName, ViewThumbnail},
     },
 };
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
@@ -50,6 +50,9 @@ pub struct CreateViewRequest {
 
     #[pb(index = 5)]
     pub view_type: ViewType,
+
+    #[pb(index = 6, one_of)]
+    pub parent_view_id: Option<String>,
 }
 
 #[derive(Default, ProtoBuf, Debug, Clone)]
@@ -71,6 +74,9 @@ pub struct CreateViewParams {
 
     #[pb(index = 6)]
     pub data: String,
+
+    #[pb(index = 7, one_of)]
+    pub parent_view_id: Option<String>,
 }
 
 impl CreateViewParams {
@@ -82,8 +88,14 @@ impl CreateViewParams {
             thumbnail,
             view_type,
             data: doc_initial_string(),
+            parent_view_id: None,
         }
     }
+
+    pub fn parent_view_id(mut self, parent_view_id: &str) -> Self {
+        self.parent_view_id = Some(parent_view_id.to_owned());
+        self
+    }
 }
 
 impl TryInto<CreateViewParams> for CreateViewRequest {
@@ -98,13 +110,14 @@ impl TryInto<CreateViewParams> for CreateViewRequest {
             Some(thumbnail) => ViewThumbnail::parse(thumbnail)?.0,
         };
 
-        Ok(CreateViewParams::new(
-            belong_to_id,
-            name,
-            self.desc,
-            self.view_type,
-            thumbnail,
-        ))
+        let parent_view_id = match self.parent_view_id {
+            None => None,
+            Some(parent_view_id) => Some(ViewId::parse(parent_view_id)?.0),
+        };
+
+        let mut params = CreateViewParams::new(belong_to_id, name, self.desc, self.view_type, thumbnail);
+        params.parent_view_id = parent_view_id;
+        Ok(params)
     }
 }
 
@@ -136,6 +149,9 @@ pub struct View {
 
     #[pb(index = 9)]
     pub create_time: i64,
+
+    #[pb(index = 10, one_of)]
+    pub parent_view_id: Option<String>,
 }
 
 #[derive(PartialEq, Debug, Default, ProtoBuf, Clone)]
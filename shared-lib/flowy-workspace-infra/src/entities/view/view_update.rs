@@ -103,3 +103,74 @@ impl TryInto<UpdateViewParams> for UpdateViewRequest {
 //         Ok(DocDelta { doc_id: view_id, data: self.data })
 //     }
 // }
+
+#[derive(Default, ProtoBuf)]
+pub struct MoveViewRequest {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2, one_of)]
+    pub parent_view_id: Option<String>,
+}
+
+// Moving a view without a parent_view_id puts it back at the top of its app.
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct MoveViewParams {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2, one_of)]
+    pub parent_view_id: Option<String>,
+}
+
+impl MoveViewParams {
+    pub fn new(view_id: &str) -> Self {
+        Self {
+            view_id: view_id.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    pub fn parent_view_id(mut self, parent_view_id: &str) -> Self {
+        self.parent_view_id = Some(parent_view_id.to_owned());
+        self
+    }
+}
+
+impl TryInto<MoveViewParams> for MoveViewRequest {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<MoveViewParams, Self::Error> {
+        let view_id = ViewId::parse(self.view_id)?.0;
+
+        let parent_view_id = match self.parent_view_id {
+            None => None,
+            Some(parent_view_id) => Some(ViewId::parse(parent_view_id)?.0),
+        };
+
+        Ok(MoveViewParams {
+            view_id,
+            parent_view_id,
+        })
+    }
+}
+
+// Moves the view to the index among the views sharing its parent, the other
+// views keep their order. An index past the last view moves it to the end.
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct ReorderViewParams {
+    #[pb(index = 1)]
+    pub view_id: String,
+
+    #[pb(index = 2)]
+    pub target_index: i64,
+}
+
+impl ReorderViewParams {
+    pub fn new(view_id: &str, target_index: i64) -> Self {
+        Self {
+            view_id: view_id.to_owned(),
+            target_index,
+        }
+    }
+}
@@ -1,9 +1,11 @@
 pub use workspace_create::*;
+pub use workspace_member::*;
 pub use workspace_query::*;
 pub use workspace_setting::*;
 pub use workspace_update::*;
 
 mod workspace_create;
+mod workspace_member;
 mod workspace_query;
 mod workspace_setting;
 mod workspace_update;
@@ -67,6 +67,11 @@ impl Workspace {
 pub struct RepeatedWorkspace {
     #[pb(index = 1)]
     pub items: Vec<Workspace>,
+
+    // The cursor reading the next page of the workspace list, None on the last
+    // page.
+    #[pb(index = 2, one_of)]
+    pub next_cursor: Option<String>,
 }
 
 impl_def_and_def_mut!(RepeatedWorkspace, Workspace);
@@ -0,0 +1,97 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+// The roles are ordered by what they allow: the Viewers can only read the
+// workspace, the Editors can also change its views, and the Owners can also
+// manage its members.
+#[derive(PartialEq, Debug, ProtoBuf_Enum, Clone)]
+pub enum WorkspaceRole {
+    Viewer = 0,
+    Editor = 1,
+    Owner  = 2,
+}
+
+impl std::convert::TryFrom<i32> for WorkspaceRole {
+    type Error = String;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(WorkspaceRole::Viewer),
+            1 => Ok(WorkspaceRole::Editor),
+            2 => Ok(WorkspaceRole::Owner),
+            _ => Err(format!("Invalid workspace role: {}", value)),
+        }
+    }
+}
+
+impl std::default::Default for WorkspaceRole {
+    fn default() -> Self { WorkspaceRole::Viewer }
+}
+
+// The member is invited by either the user_id or the email
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct AddWorkspaceMemberParams {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2, one_of)]
+    pub user_id: Option<String>,
+
+    #[pb(index = 3, one_of)]
+    pub email: Option<String>,
+
+    #[pb(index = 4)]
+    pub role: WorkspaceRole,
+}
+
+impl AddWorkspaceMemberParams {
+    pub fn with_user_id(workspace_id: &str, user_id: &str, role: WorkspaceRole) -> Self {
+        Self {
+            workspace_id: workspace_id.to_owned(),
+            user_id: Some(user_id.to_owned()),
+            email: None,
+            role,
+        }
+    }
+
+    pub fn with_email(workspace_id: &str, email: &str, role: WorkspaceRole) -> Self {
+        Self {
+            workspace_id: workspace_id.to_owned(),
+            user_id: None,
+            email: Some(email.to_owned()),
+            role,
+        }
+    }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RemoveWorkspaceMemberParams {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub user_id: String,
+}
+
+impl RemoveWorkspaceMemberParams {
+    pub fn new(workspace_id: &str, user_id: &str) -> Self {
+        Self {
+            workspace_id: workspace_id.to_owned(),
+            user_id: user_id.to_owned(),
+        }
+    }
+}
+
+#[derive(PartialEq, ProtoBuf, Default, Debug, Clone)]
+pub struct WorkspaceMember {
+    #[pb(index = 1)]
+    pub workspace_id: String,
+
+    #[pb(index = 2)]
+    pub user_id: String,
+
+    #[pb(index = 3)]
+    pub email: String,
+
+    #[pb(index = 4)]
+    pub role: WorkspaceRole,
+}
@@ -30,6 +30,8 @@ pub struct CreateAppRequest {
     pub name: ::std::string::String,
     pub desc: ::std::string::String,
     pub color_style: ::protobuf::SingularPtrField<ColorStyle>,
+    // message oneof groups
+    pub one_of_parent_id: ::std::option::Option<CreateAppRequest_oneof_one_of_parent_id>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -41,6 +43,11 @@ impl<'a> ::std::default::Default for &'a CreateAppRequest {
     }
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum CreateAppRequest_oneof_one_of_parent_id {
+    parent_id(::std::string::String),
+}
+
 impl CreateAppRequest {
     pub fn new() -> CreateAppRequest {
         ::std::default::Default::default()
@@ -156,6 +163,55 @@ impl CreateAppRequest {
     pub fn take_color_style(&mut self) -> ColorStyle {
         self.color_style.take().unwrap_or_else(|| ColorStyle::new())
     }
+
+    // string parent_id = 5;
+
+
+    pub fn get_parent_id(&self) -> &str {
+        match self.one_of_parent_id {
+            ::std::option::Option::Some(CreateAppRequest_oneof_one_of_parent_id::parent_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_parent_id(&mut self) {
+        self.one_of_parent_id = ::std::option::Option::None;
+    }
+
+    pub fn has_parent_id(&self) -> bool {
+        match self.one_of_parent_id {
+            ::std::option::Option::Some(CreateAppRequest_oneof_one_of_parent_id::parent_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parent_id(&mut self, v: ::std::string::String) {
+        self.one_of_parent_id = ::std::option::Option::Some(CreateAppRequest_oneof_one_of_parent_id::parent_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_parent_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(CreateAppRequest_oneof_one_of_parent_id::parent_id(_)) = self.one_of_parent_id {
+        } else {
+            self.one_of_parent_id = ::std::option::Option::Some(CreateAppRequest_oneof_one_of_parent_id::parent_id(::std::string::String::new()));
+        }
+        match self.one_of_parent_id {
+            ::std::option::Option::Some(CreateAppRequest_oneof_one_of_parent_id::parent_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_parent_id(&mut self) -> ::std::string::String {
+        if self.has_parent_id() {
+            match self.one_of_parent_id.take() {
+                ::std::option::Option::Some(CreateAppRequest_oneof_one_of_parent_id::parent_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for CreateAppRequest {
@@ -184,6 +240,12 @@ impl ::protobuf::Message for CreateAppRequest {
                 4 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.color_style)?;
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_parent_id = ::std::option::Option::Some(CreateAppRequest_oneof_one_of_parent_id::parent_id(is.read_string()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -209,6 +271,13 @@ impl ::protobuf::Message for CreateAppRequest {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_parent_id {
+            match v {
+                &CreateAppRequest_oneof_one_of_parent_id::parent_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(5, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -229,6 +298,13 @@ impl ::protobuf::Message for CreateAppRequest {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_parent_id {
+            match v {
+                &CreateAppRequest_oneof_one_of_parent_id::parent_id(ref v) => {
+                    os.write_string(5, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -287,6 +363,11 @@ impl ::protobuf::Message for CreateAppRequest {
                 |m: &CreateAppRequest| { &m.color_style },
                 |m: &mut CreateAppRequest| { &mut m.color_style },
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "parent_id",
+                CreateAppRequest::has_parent_id,
+                CreateAppRequest::get_parent_id,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateAppRequest>(
                 "CreateAppRequest",
                 fields,
@@ -307,6 +388,7 @@ impl ::protobuf::Clear for CreateAppRequest {
         self.name.clear();
         self.desc.clear();
         self.color_style.clear();
+        self.one_of_parent_id = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -489,6 +571,8 @@ pub struct CreateAppParams {
     pub name: ::std::string::String,
     pub desc: ::std::string::String,
     pub color_style: ::protobuf::SingularPtrField<ColorStyle>,
+    // message oneof groups
+    pub one_of_parent_id: ::std::option::Option<CreateAppParams_oneof_one_of_parent_id>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -500,6 +584,11 @@ impl<'a> ::std::default::Default for &'a CreateAppParams {
     }
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum CreateAppParams_oneof_one_of_parent_id {
+    parent_id(::std::string::String),
+}
+
 impl CreateAppParams {
     pub fn new() -> CreateAppParams {
         ::std::default::Default::default()
@@ -615,6 +704,55 @@ impl CreateAppParams {
     pub fn take_color_style(&mut self) -> ColorStyle {
         self.color_style.take().unwrap_or_else(|| ColorStyle::new())
     }
+
+    // string parent_id = 5;
+
+
+    pub fn get_parent_id(&self) -> &str {
+        match self.one_of_parent_id {
+            ::std::option::Option::Some(CreateAppParams_oneof_one_of_parent_id::parent_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_parent_id(&mut self) {
+        self.one_of_parent_id = ::std::option::Option::None;
+    }
+
+    pub fn has_parent_id(&self) -> bool {
+        match self.one_of_parent_id {
+            ::std::option::Option::Some(CreateAppParams_oneof_one_of_parent_id::parent_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parent_id(&mut self, v: ::std::string::String) {
+        self.one_of_parent_id = ::std::option::Option::Some(CreateAppParams_oneof_one_of_parent_id::parent_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_parent_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(CreateAppParams_oneof_one_of_parent_id::parent_id(_)) = self.one_of_parent_id {
+        } else {
+            self.one_of_parent_id = ::std::option::Option::Some(CreateAppParams_oneof_one_of_parent_id::parent_id(::std::string::String::new()));
+        }
+        match self.one_of_parent_id {
+            ::std::option::Option::Some(CreateAppParams_oneof_one_of_parent_id::parent_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_parent_id(&mut self) -> ::std::string::String {
+        if self.has_parent_id() {
+            match self.one_of_parent_id.take() {
+                ::std::option::Option::Some(CreateAppParams_oneof_one_of_parent_id::parent_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for CreateAppParams {
@@ -643,6 +781,12 @@ impl ::protobuf::Message for CreateAppParams {
                 4 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.color_style)?;
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_parent_id = ::std::option::Option::Some(CreateAppParams_oneof_one_of_parent_id::parent_id(is.read_string()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -668,6 +812,13 @@ impl ::protobuf::Message for CreateAppParams {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_parent_id {
+            match v {
+                &CreateAppParams_oneof_one_of_parent_id::parent_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(5, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -688,6 +839,13 @@ impl ::protobuf::Message for CreateAppParams {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_parent_id {
+            match v {
+                &CreateAppParams_oneof_one_of_parent_id::parent_id(ref v) => {
+                    os.write_string(5, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -746,6 +904,11 @@ impl ::protobuf::Message for CreateAppParams {
                 |m: &CreateAppParams| { &m.color_style },
                 |m: &mut CreateAppParams| { &mut m.color_style },
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "parent_id",
+                CreateAppParams::has_parent_id,
+                CreateAppParams::get_parent_id,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateAppParams>(
                 "CreateAppParams",
                 fields,
@@ -766,6 +929,7 @@ impl ::protobuf::Clear for CreateAppParams {
         self.name.clear();
         self.desc.clear();
         self.color_style.clear();
+        self.one_of_parent_id = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -793,6 +957,9 @@ pub struct App {
     pub version: i64,
     pub modified_time: i64,
     pub create_time: i64,
+    pub apps: ::protobuf::SingularPtrField<RepeatedApp>,
+    // message oneof groups
+    pub one_of_parent_id: ::std::option::Option<App_oneof_one_of_parent_id>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -804,6 +971,11 @@ impl<'a> ::std::default::Default for &'a App {
     }
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum App_oneof_one_of_parent_id {
+    parent_id(::std::string::String),
+}
+
 impl App {
     pub fn new() -> App {
         ::std::default::Default::default()
@@ -990,6 +1162,88 @@ impl App {
     pub fn set_create_time(&mut self, v: i64) {
         self.create_time = v;
     }
+
+    // .RepeatedApp apps = 9;
+
+
+    pub fn get_apps(&self) -> &RepeatedApp {
+        self.apps.as_ref().unwrap_or_else(|| <RepeatedApp as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_apps(&mut self) {
+        self.apps.clear();
+    }
+
+    pub fn has_apps(&self) -> bool {
+        self.apps.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_apps(&mut self, v: RepeatedApp) {
+        self.apps = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_apps(&mut self) -> &mut RepeatedApp {
+        if self.apps.is_none() {
+            self.apps.set_default();
+        }
+        self.apps.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_apps(&mut self) -> RepeatedApp {
+        self.apps.take().unwrap_or_else(|| RepeatedApp::new())
+    }
+
+    // string parent_id = 10;
+
+
+    pub fn get_parent_id(&self) -> &str {
+        match self.one_of_parent_id {
+            ::std::option::Option::Some(App_oneof_one_of_parent_id::parent_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_parent_id(&mut self) {
+        self.one_of_parent_id = ::std::option::Option::None;
+    }
+
+    pub fn has_parent_id(&self) -> bool {
+        match self.one_of_parent_id {
+            ::std::option::Option::Some(App_oneof_one_of_parent_id::parent_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parent_id(&mut self, v: ::std::string::String) {
+        self.one_of_parent_id = ::std::option::Option::Some(App_oneof_one_of_parent_id::parent_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_parent_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(App_oneof_one_of_parent_id::parent_id(_)) = self.one_of_parent_id {
+        } else {
+            self.one_of_parent_id = ::std::option::Option::Some(App_oneof_one_of_parent_id::parent_id(::std::string::String::new()));
+        }
+        match self.one_of_parent_id {
+            ::std::option::Option::Some(App_oneof_one_of_parent_id::parent_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_parent_id(&mut self) -> ::std::string::String {
+        if self.has_parent_id() {
+            match self.one_of_parent_id.take() {
+                ::std::option::Option::Some(App_oneof_one_of_parent_id::parent_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for App {
@@ -999,6 +1253,11 @@ impl ::protobuf::Message for App {
                 return false;
             }
         };
+        for v in &self.apps {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -1042,6 +1301,15 @@ impl ::protobuf::Message for App {
                     let tmp = is.read_int64()?;
                     self.create_time = tmp;
                 },
+                9 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.apps)?;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_parent_id = ::std::option::Option::Some(App_oneof_one_of_parent_id::parent_id(is.read_string()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1079,6 +1347,17 @@ impl ::protobuf::Message for App {
         if self.create_time != 0 {
             my_size += ::protobuf::rt::value_size(8, self.create_time, ::protobuf::wire_format::WireTypeVarint);
         }
+        if let Some(ref v) = self.apps.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_parent_id {
+            match v {
+                &App_oneof_one_of_parent_id::parent_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(10, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1111,6 +1390,18 @@ impl ::protobuf::Message for App {
         if self.create_time != 0 {
             os.write_int64(8, self.create_time)?;
         }
+        if let Some(ref v) = self.apps.as_ref() {
+            os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_parent_id {
+            match v {
+                &App_oneof_one_of_parent_id::parent_id(ref v) => {
+                    os.write_string(10, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1189,6 +1480,16 @@ impl ::protobuf::Message for App {
                 |m: &App| { &m.create_time },
                 |m: &mut App| { &mut m.create_time },
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RepeatedApp>>(
+                "apps",
+                |m: &App| { &m.apps },
+                |m: &mut App| { &mut m.apps },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "parent_id",
+                App::has_parent_id,
+                App::get_parent_id,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<App>(
                 "App",
                 fields,
@@ -1213,6 +1514,8 @@ impl ::protobuf::Clear for App {
         self.version = 0;
         self.modified_time = 0;
         self.create_time = 0;
+        self.apps.clear();
+        self.one_of_parent_id = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -1396,80 +1699,99 @@ impl ::protobuf::reflect::ProtobufValue for RepeatedApp {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x10app_create.proto\x1a\x11view_create.proto\"\x8b\x01\n\x10CreateApp\
+    \n\x10app_create.proto\x1a\x11view_create.proto\"\xbe\x01\n\x10CreateApp\
     Request\x12!\n\x0cworkspace_id\x18\x01\x20\x01(\tR\x0bworkspaceId\x12\
     \x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x12\n\x04desc\x18\x03\x20\
     \x01(\tR\x04desc\x12,\n\x0bcolor_style\x18\x04\x20\x01(\x0b2\x0b.ColorSt\
-    yleR\ncolorStyle\"-\n\nColorStyle\x12\x1f\n\x0btheme_color\x18\x01\x20\
-    \x01(\tR\nthemeColor\"\x8a\x01\n\x0fCreateAppParams\x12!\n\x0cworkspace_\
-    id\x18\x01\x20\x01(\tR\x0bworkspaceId\x12\x12\n\x04name\x18\x02\x20\x01(\
-    \tR\x04name\x12\x12\n\x04desc\x18\x03\x20\x01(\tR\x04desc\x12,\n\x0bcolo\
-    r_style\x18\x04\x20\x01(\x0b2\x0b.ColorStyleR\ncolorStyle\"\xef\x01\n\
-    \x03App\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12!\n\x0cworkspace_id\
-    \x18\x02\x20\x01(\tR\x0bworkspaceId\x12\x12\n\x04name\x18\x03\x20\x01(\t\
-    R\x04name\x12\x12\n\x04desc\x18\x04\x20\x01(\tR\x04desc\x12-\n\nbelongin\
-    gs\x18\x05\x20\x01(\x0b2\r.RepeatedViewR\nbelongings\x12\x18\n\x07versio\
-    n\x18\x06\x20\x01(\x03R\x07version\x12#\n\rmodified_time\x18\x07\x20\x01\
-    (\x03R\x0cmodifiedTime\x12\x1f\n\x0bcreate_time\x18\x08\x20\x01(\x03R\nc\
-    reateTime\")\n\x0bRepeatedApp\x12\x1a\n\x05items\x18\x01\x20\x03(\x0b2\
-    \x04.AppR\x05itemsJ\x81\t\n\x06\x12\x04\0\0\x1e\x01\n\x08\n\x01\x0c\x12\
-    \x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x01\0\x1b\n\n\n\x02\x04\0\x12\x04\
-    \x03\0\x08\x01\n\n\n\x03\x04\0\x01\x12\x03\x03\x08\x18\n\x0b\n\x04\x04\0\
-    \x02\0\x12\x03\x04\x04\x1c\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x04\x04\n\
-    \n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x04\x0b\x17\n\x0c\n\x05\x04\0\x02\0\
-    \x03\x12\x03\x04\x1a\x1b\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x05\x04\x14\n\
-    \x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\x01\
-    \x01\x12\x03\x05\x0b\x0f\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x05\x12\
-    \x13\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x06\x04\x14\n\x0c\n\x05\x04\0\x02\
-    \x02\x05\x12\x03\x06\x04\n\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x06\x0b\
-    \x0f\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x06\x12\x13\n\x0b\n\x04\x04\0\
-    \x02\x03\x12\x03\x07\x04\x1f\n\x0c\n\x05\x04\0\x02\x03\x06\x12\x03\x07\
-    \x04\x0e\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x07\x0f\x1a\n\x0c\n\x05\
-    \x04\0\x02\x03\x03\x12\x03\x07\x1d\x1e\n\n\n\x02\x04\x01\x12\x04\t\0\x0b\
-    \x01\n\n\n\x03\x04\x01\x01\x12\x03\t\x08\x12\n\x0b\n\x04\x04\x01\x02\0\
-    \x12\x03\n\x04\x1b\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\n\x04\n\n\x0c\n\
-    \x05\x04\x01\x02\0\x01\x12\x03\n\x0b\x16\n\x0c\n\x05\x04\x01\x02\0\x03\
-    \x12\x03\n\x19\x1a\n\n\n\x02\x04\x02\x12\x04\x0c\0\x11\x01\n\n\n\x03\x04\
-    \x02\x01\x12\x03\x0c\x08\x17\n\x0b\n\x04\x04\x02\x02\0\x12\x03\r\x04\x1c\
-    \n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\r\x04\n\n\x0c\n\x05\x04\x02\x02\0\
-    \x01\x12\x03\r\x0b\x17\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\r\x1a\x1b\n\
-    \x0b\n\x04\x04\x02\x02\x01\x12\x03\x0e\x04\x14\n\x0c\n\x05\x04\x02\x02\
-    \x01\x05\x12\x03\x0e\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x0e\
-    \x0b\x0f\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0e\x12\x13\n\x0b\n\x04\
-    \x04\x02\x02\x02\x12\x03\x0f\x04\x14\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\
-    \x03\x0f\x04\n\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x0f\x0b\x0f\n\x0c\
-    \n\x05\x04\x02\x02\x02\x03\x12\x03\x0f\x12\x13\n\x0b\n\x04\x04\x02\x02\
-    \x03\x12\x03\x10\x04\x1f\n\x0c\n\x05\x04\x02\x02\x03\x06\x12\x03\x10\x04\
-    \x0e\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x10\x0f\x1a\n\x0c\n\x05\x04\
-    \x02\x02\x03\x03\x12\x03\x10\x1d\x1e\n\n\n\x02\x04\x03\x12\x04\x12\0\x1b\
-    \x01\n\n\n\x03\x04\x03\x01\x12\x03\x12\x08\x0b\n\x0b\n\x04\x04\x03\x02\0\
-    \x12\x03\x13\x04\x12\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x13\x04\n\n\
-    \x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x13\x0b\r\n\x0c\n\x05\x04\x03\x02\0\
-    \x03\x12\x03\x13\x10\x11\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x14\x04\x1c\
-    \n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x14\x04\n\n\x0c\n\x05\x04\x03\
-    \x02\x01\x01\x12\x03\x14\x0b\x17\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\
-    \x14\x1a\x1b\n\x0b\n\x04\x04\x03\x02\x02\x12\x03\x15\x04\x14\n\x0c\n\x05\
-    \x04\x03\x02\x02\x05\x12\x03\x15\x04\n\n\x0c\n\x05\x04\x03\x02\x02\x01\
-    \x12\x03\x15\x0b\x0f\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03\x15\x12\x13\
-    \n\x0b\n\x04\x04\x03\x02\x03\x12\x03\x16\x04\x14\n\x0c\n\x05\x04\x03\x02\
-    \x03\x05\x12\x03\x16\x04\n\n\x0c\n\x05\x04\x03\x02\x03\x01\x12\x03\x16\
-    \x0b\x0f\n\x0c\n\x05\x04\x03\x02\x03\x03\x12\x03\x16\x12\x13\n\x0b\n\x04\
-    \x04\x03\x02\x04\x12\x03\x17\x04\x20\n\x0c\n\x05\x04\x03\x02\x04\x06\x12\
-    \x03\x17\x04\x10\n\x0c\n\x05\x04\x03\x02\x04\x01\x12\x03\x17\x11\x1b\n\
-    \x0c\n\x05\x04\x03\x02\x04\x03\x12\x03\x17\x1e\x1f\n\x0b\n\x04\x04\x03\
-    \x02\x05\x12\x03\x18\x04\x16\n\x0c\n\x05\x04\x03\x02\x05\x05\x12\x03\x18\
-    \x04\t\n\x0c\n\x05\x04\x03\x02\x05\x01\x12\x03\x18\n\x11\n\x0c\n\x05\x04\
-    \x03\x02\x05\x03\x12\x03\x18\x14\x15\n\x0b\n\x04\x04\x03\x02\x06\x12\x03\
-    \x19\x04\x1c\n\x0c\n\x05\x04\x03\x02\x06\x05\x12\x03\x19\x04\t\n\x0c\n\
-    \x05\x04\x03\x02\x06\x01\x12\x03\x19\n\x17\n\x0c\n\x05\x04\x03\x02\x06\
-    \x03\x12\x03\x19\x1a\x1b\n\x0b\n\x04\x04\x03\x02\x07\x12\x03\x1a\x04\x1a\
-    \n\x0c\n\x05\x04\x03\x02\x07\x05\x12\x03\x1a\x04\t\n\x0c\n\x05\x04\x03\
-    \x02\x07\x01\x12\x03\x1a\n\x15\n\x0c\n\x05\x04\x03\x02\x07\x03\x12\x03\
-    \x1a\x18\x19\n\n\n\x02\x04\x04\x12\x04\x1c\0\x1e\x01\n\n\n\x03\x04\x04\
-    \x01\x12\x03\x1c\x08\x13\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x1d\x04\x1b\n\
-    \x0c\n\x05\x04\x04\x02\0\x04\x12\x03\x1d\x04\x0c\n\x0c\n\x05\x04\x04\x02\
-    \0\x06\x12\x03\x1d\r\x10\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x1d\x11\
-    \x16\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x1d\x19\x1ab\x06proto3\
+    yleR\ncolorStyle\x12\x1d\n\tparent_id\x18\x05\x20\x01(\tH\0R\x08parentId\
+    B\x12\n\x10one_of_parent_id\"-\n\nColorStyle\x12\x1f\n\x0btheme_color\
+    \x18\x01\x20\x01(\tR\nthemeColor\"\xbd\x01\n\x0fCreateAppParams\x12!\n\
+    \x0cworkspace_id\x18\x01\x20\x01(\tR\x0bworkspaceId\x12\x12\n\x04name\
+    \x18\x02\x20\x01(\tR\x04name\x12\x12\n\x04desc\x18\x03\x20\x01(\tR\x04de\
+    sc\x12,\n\x0bcolor_style\x18\x04\x20\x01(\x0b2\x0b.ColorStyleR\ncolorSty\
+    le\x12\x1d\n\tparent_id\x18\x05\x20\x01(\tH\0R\x08parentIdB\x12\n\x10one\
+    _of_parent_id\"\xc4\x02\n\x03App\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02\
+    id\x12!\n\x0cworkspace_id\x18\x02\x20\x01(\tR\x0bworkspaceId\x12\x12\n\
+    \x04name\x18\x03\x20\x01(\tR\x04name\x12\x12\n\x04desc\x18\x04\x20\x01(\
+    \tR\x04desc\x12-\n\nbelongings\x18\x05\x20\x01(\x0b2\r.RepeatedViewR\nbe\
+    longings\x12\x18\n\x07version\x18\x06\x20\x01(\x03R\x07version\x12#\n\rm\
+    odified_time\x18\x07\x20\x01(\x03R\x0cmodifiedTime\x12\x1f\n\x0bcreate_t\
+    ime\x18\x08\x20\x01(\x03R\ncreateTime\x12\x20\n\x04apps\x18\t\x20\x01(\
+    \x0b2\x0c.RepeatedAppR\x04apps\x12\x1d\n\tparent_id\x18\n\x20\x01(\tH\0R\
+    \x08parentIdB\x12\n\x10one_of_parent_id\")\n\x0bRepeatedApp\x12\x1a\n\
+    \x05items\x18\x01\x20\x03(\x0b2\x04.AppR\x05itemsJ\xae\x0b\n\x06\x12\x04\
+    \0\0\"\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x01\0\
+    \x1b\n\n\n\x02\x04\0\x12\x04\x03\0\t\x01\n\n\n\x03\x04\0\x01\x12\x03\x03\
+    \x08\x18\n\x0b\n\x04\x04\0\x02\0\x12\x03\x04\x04\x1c\n\x0c\n\x05\x04\0\
+    \x02\0\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x04\x0b\
+    \x17\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x04\x1a\x1b\n\x0b\n\x04\x04\0\
+    \x02\x01\x12\x03\x05\x04\x14\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x05\
+    \x04\n\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x05\x0b\x0f\n\x0c\n\x05\x04\
+    \0\x02\x01\x03\x12\x03\x05\x12\x13\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x06\
+    \x04\x14\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x06\x04\n\n\x0c\n\x05\x04\
+    \0\x02\x02\x01\x12\x03\x06\x0b\x0f\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\
+    \x06\x12\x13\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x07\x04\x1f\n\x0c\n\x05\
+    \x04\0\x02\x03\x06\x12\x03\x07\x04\x0e\n\x0c\n\x05\x04\0\x02\x03\x01\x12\
+    \x03\x07\x0f\x1a\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x07\x1d\x1e\n\x0b\
+    \n\x04\x04\0\x08\0\x12\x03\x08\x044\n\x0c\n\x05\x04\0\x08\0\x01\x12\x03\
+    \x08\n\x1a\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x08\x1d2\n\x0c\n\x05\x04\0\
+    \x02\x04\x05\x12\x03\x08\x1d#\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x08$\
+    -\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x0801\n\n\n\x02\x04\x01\x12\x04\
+    \n\0\x0c\x01\n\n\n\x03\x04\x01\x01\x12\x03\n\x08\x12\n\x0b\n\x04\x04\x01\
+    \x02\0\x12\x03\x0b\x04\x1b\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x0b\x04\
+    \n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x0b\x0b\x16\n\x0c\n\x05\x04\x01\
+    \x02\0\x03\x12\x03\x0b\x19\x1a\n\n\n\x02\x04\x02\x12\x04\r\0\x13\x01\n\n\
+    \n\x03\x04\x02\x01\x12\x03\r\x08\x17\n\x0b\n\x04\x04\x02\x02\0\x12\x03\
+    \x0e\x04\x1c\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0e\x04\n\n\x0c\n\x05\
+    \x04\x02\x02\0\x01\x12\x03\x0e\x0b\x17\n\x0c\n\x05\x04\x02\x02\0\x03\x12\
+    \x03\x0e\x1a\x1b\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x0f\x04\x14\n\x0c\n\
+    \x05\x04\x02\x02\x01\x05\x12\x03\x0f\x04\n\n\x0c\n\x05\x04\x02\x02\x01\
+    \x01\x12\x03\x0f\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0f\x12\
+    \x13\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x10\x04\x14\n\x0c\n\x05\x04\x02\
+    \x02\x02\x05\x12\x03\x10\x04\n\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\
+    \x10\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x10\x12\x13\n\x0b\n\
+    \x04\x04\x02\x02\x03\x12\x03\x11\x04\x1f\n\x0c\n\x05\x04\x02\x02\x03\x06\
+    \x12\x03\x11\x04\x0e\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x11\x0f\x1a\
+    \n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03\x11\x1d\x1e\n\x0b\n\x04\x04\x02\
+    \x08\0\x12\x03\x12\x044\n\x0c\n\x05\x04\x02\x08\0\x01\x12\x03\x12\n\x1a\
+    \n\x0b\n\x04\x04\x02\x02\x04\x12\x03\x12\x1d2\n\x0c\n\x05\x04\x02\x02\
+    \x04\x05\x12\x03\x12\x1d#\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03\x12$-\
+    \n\x0c\n\x05\x04\x02\x02\x04\x03\x12\x03\x1201\n\n\n\x02\x04\x03\x12\x04\
+    \x14\0\x1f\x01\n\n\n\x03\x04\x03\x01\x12\x03\x14\x08\x0b\n\x0b\n\x04\x04\
+    \x03\x02\0\x12\x03\x15\x04\x12\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x15\
+    \x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x15\x0b\r\n\x0c\n\x05\x04\
+    \x03\x02\0\x03\x12\x03\x15\x10\x11\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\
+    \x16\x04\x1c\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x16\x04\n\n\x0c\n\
+    \x05\x04\x03\x02\x01\x01\x12\x03\x16\x0b\x17\n\x0c\n\x05\x04\x03\x02\x01\
+    \x03\x12\x03\x16\x1a\x1b\n\x0b\n\x04\x04\x03\x02\x02\x12\x03\x17\x04\x14\
+    \n\x0c\n\x05\x04\x03\x02\x02\x05\x12\x03\x17\x04\n\n\x0c\n\x05\x04\x03\
+    \x02\x02\x01\x12\x03\x17\x0b\x0f\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03\
+    \x17\x12\x13\n\x0b\n\x04\x04\x03\x02\x03\x12\x03\x18\x04\x14\n\x0c\n\x05\
+    \x04\x03\x02\x03\x05\x12\x03\x18\x04\n\n\x0c\n\x05\x04\x03\x02\x03\x01\
+    \x12\x03\x18\x0b\x0f\n\x0c\n\x05\x04\x03\x02\x03\x03\x12\x03\x18\x12\x13\
+    \n\x0b\n\x04\x04\x03\x02\x04\x12\x03\x19\x04\x20\n\x0c\n\x05\x04\x03\x02\
+    \x04\x06\x12\x03\x19\x04\x10\n\x0c\n\x05\x04\x03\x02\x04\x01\x12\x03\x19\
+    \x11\x1b\n\x0c\n\x05\x04\x03\x02\x04\x03\x12\x03\x19\x1e\x1f\n\x0b\n\x04\
+    \x04\x03\x02\x05\x12\x03\x1a\x04\x16\n\x0c\n\x05\x04\x03\x02\x05\x05\x12\
+    \x03\x1a\x04\t\n\x0c\n\x05\x04\x03\x02\x05\x01\x12\x03\x1a\n\x11\n\x0c\n\
+    \x05\x04\x03\x02\x05\x03\x12\x03\x1a\x14\x15\n\x0b\n\x04\x04\x03\x02\x06\
+    \x12\x03\x1b\x04\x1c\n\x0c\n\x05\x04\x03\x02\x06\x05\x12\x03\x1b\x04\t\n\
+    \x0c\n\x05\x04\x03\x02\x06\x01\x12\x03\x1b\n\x17\n\x0c\n\x05\x04\x03\x02\
+    \x06\x03\x12\x03\x1b\x1a\x1b\n\x0b\n\x04\x04\x03\x02\x07\x12\x03\x1c\x04\
+    \x1a\n\x0c\n\x05\x04\x03\x02\x07\x05\x12\x03\x1c\x04\t\n\x0c\n\x05\x04\
+    \x03\x02\x07\x01\x12\x03\x1c\n\x15\n\x0c\n\x05\x04\x03\x02\x07\x03\x12\
+    \x03\x1c\x18\x19\n\x0b\n\x04\x04\x03\x02\x08\x12\x03\x1d\x04\x19\n\x0c\n\
+    \x05\x04\x03\x02\x08\x06\x12\x03\x1d\x04\x0f\n\x0c\n\x05\x04\x03\x02\x08\
+    \x01\x12\x03\x1d\x10\x14\n\x0c\n\x05\x04\x03\x02\x08\x03\x12\x03\x1d\x17\
+    \x18\n\x0b\n\x04\x04\x03\x08\0\x12\x03\x1e\x045\n\x0c\n\x05\x04\x03\x08\
+    \0\x01\x12\x03\x1e\n\x1a\n\x0b\n\x04\x04\x03\x02\t\x12\x03\x1e\x1d3\n\
+    \x0c\n\x05\x04\x03\x02\t\x05\x12\x03\x1e\x1d#\n\x0c\n\x05\x04\x03\x02\t\
+    \x01\x12\x03\x1e$-\n\x0c\n\x05\x04\x03\x02\t\x03\x12\x03\x1e02\n\n\n\x02\
+    \x04\x04\x12\x04\x20\0\"\x01\n\n\n\x03\x04\x04\x01\x12\x03\x20\x08\x13\n\
+    \x0b\n\x04\x04\x04\x02\0\x12\x03!\x04\x1b\n\x0c\n\x05\x04\x04\x02\0\x04\
+    \x12\x03!\x04\x0c\n\x0c\n\x05\x04\x04\x02\0\x06\x12\x03!\r\x10\n\x0c\n\
+    \x05\x04\x04\x02\0\x01\x12\x03!\x11\x16\n\x0c\n\x05\x04\x04\x02\0\x03\
+    \x12\x03!\x19\x1ab\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -32,6 +32,7 @@ pub struct UpdateAppRequest {
     pub one_of_desc: ::std::option::Option<UpdateAppRequest_oneof_one_of_desc>,
     pub one_of_color_style: ::std::option::Option<UpdateAppRequest_oneof_one_of_color_style>,
     pub one_of_is_trash: ::std::option::Option<UpdateAppRequest_oneof_one_of_is_trash>,
+    pub one_of_parent_id: ::std::option::Option<UpdateAppRequest_oneof_one_of_parent_id>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -63,6 +64,11 @@ pub enum UpdateAppRequest_oneof_one_of_is_trash {
     is_trash(bool),
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateAppRequest_oneof_one_of_parent_id {
+    parent_id(::std::string::String),
+}
+
 impl UpdateAppRequest {
     pub fn new() -> UpdateAppRequest {
         ::std::default::Default::default()
@@ -265,6 +271,55 @@ impl UpdateAppRequest {
     pub fn set_is_trash(&mut self, v: bool) {
         self.one_of_is_trash = ::std::option::Option::Some(UpdateAppRequest_oneof_one_of_is_trash::is_trash(v))
     }
+
+    // string parent_id = 6;
+
+
+    pub fn get_parent_id(&self) -> &str {
+        match self.one_of_parent_id {
+            ::std::option::Option::Some(UpdateAppRequest_oneof_one_of_parent_id::parent_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_parent_id(&mut self) {
+        self.one_of_parent_id = ::std::option::Option::None;
+    }
+
+    pub fn has_parent_id(&self) -> bool {
+        match self.one_of_parent_id {
+            ::std::option::Option::Some(UpdateAppRequest_oneof_one_of_parent_id::parent_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parent_id(&mut self, v: ::std::string::String) {
+        self.one_of_parent_id = ::std::option::Option::Some(UpdateAppRequest_oneof_one_of_parent_id::parent_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_parent_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateAppRequest_oneof_one_of_parent_id::parent_id(_)) = self.one_of_parent_id {
+        } else {
+            self.one_of_parent_id = ::std::option::Option::Some(UpdateAppRequest_oneof_one_of_parent_id::parent_id(::std::string::String::new()));
+        }
+        match self.one_of_parent_id {
+            ::std::option::Option::Some(UpdateAppRequest_oneof_one_of_parent_id::parent_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_parent_id(&mut self) -> ::std::string::String {
+        if self.has_parent_id() {
+            match self.one_of_parent_id.take() {
+                ::std::option::Option::Some(UpdateAppRequest_oneof_one_of_parent_id::parent_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for UpdateAppRequest {
@@ -308,6 +363,12 @@ impl ::protobuf::Message for UpdateAppRequest {
                     }
                     self.one_of_is_trash = ::std::option::Option::Some(UpdateAppRequest_oneof_one_of_is_trash::is_trash(is.read_bool()?));
                 },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_parent_id = ::std::option::Option::Some(UpdateAppRequest_oneof_one_of_parent_id::parent_id(is.read_string()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -352,6 +413,13 @@ impl ::protobuf::Message for UpdateAppRequest {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_parent_id {
+            match v {
+                &UpdateAppRequest_oneof_one_of_parent_id::parent_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(6, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -391,6 +459,13 @@ impl ::protobuf::Message for UpdateAppRequest {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_parent_id {
+            match v {
+                &UpdateAppRequest_oneof_one_of_parent_id::parent_id(ref v) => {
+                    os.write_string(6, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -454,6 +529,11 @@ impl ::protobuf::Message for UpdateAppRequest {
                 UpdateAppRequest::has_is_trash,
                 UpdateAppRequest::get_is_trash,
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "parent_id",
+                UpdateAppRequest::has_parent_id,
+                UpdateAppRequest::get_parent_id,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateAppRequest>(
                 "UpdateAppRequest",
                 fields,
@@ -475,6 +555,7 @@ impl ::protobuf::Clear for UpdateAppRequest {
         self.one_of_desc = ::std::option::Option::None;
         self.one_of_color_style = ::std::option::Option::None;
         self.one_of_is_trash = ::std::option::Option::None;
+        self.one_of_parent_id = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -500,6 +581,7 @@ pub struct UpdateAppParams {
     pub one_of_desc: ::std::option::Option<UpdateAppParams_oneof_one_of_desc>,
     pub one_of_color_style: ::std::option::Option<UpdateAppParams_oneof_one_of_color_style>,
     pub one_of_is_trash: ::std::option::Option<UpdateAppParams_oneof_one_of_is_trash>,
+    pub one_of_parent_id: ::std::option::Option<UpdateAppParams_oneof_one_of_parent_id>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -531,6 +613,11 @@ pub enum UpdateAppParams_oneof_one_of_is_trash {
     is_trash(bool),
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum UpdateAppParams_oneof_one_of_parent_id {
+    parent_id(::std::string::String),
+}
+
 impl UpdateAppParams {
     pub fn new() -> UpdateAppParams {
         ::std::default::Default::default()
@@ -733,6 +820,55 @@ impl UpdateAppParams {
     pub fn set_is_trash(&mut self, v: bool) {
         self.one_of_is_trash = ::std::option::Option::Some(UpdateAppParams_oneof_one_of_is_trash::is_trash(v))
     }
+
+    // string parent_id = 6;
+
+
+    pub fn get_parent_id(&self) -> &str {
+        match self.one_of_parent_id {
+            ::std::option::Option::Some(UpdateAppParams_oneof_one_of_parent_id::parent_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_parent_id(&mut self) {
+        self.one_of_parent_id = ::std::option::Option::None;
+    }
+
+    pub fn has_parent_id(&self) -> bool {
+        match self.one_of_parent_id {
+            ::std::option::Option::Some(UpdateAppParams_oneof_one_of_parent_id::parent_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parent_id(&mut self, v: ::std::string::String) {
+        self.one_of_parent_id = ::std::option::Option::Some(UpdateAppParams_oneof_one_of_parent_id::parent_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_parent_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(UpdateAppParams_oneof_one_of_parent_id::parent_id(_)) = self.one_of_parent_id {
+        } else {
+            self.one_of_parent_id = ::std::option::Option::Some(UpdateAppParams_oneof_one_of_parent_id::parent_id(::std::string::String::new()));
+        }
+        match self.one_of_parent_id {
+            ::std::option::Option::Some(UpdateAppParams_oneof_one_of_parent_id::parent_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_parent_id(&mut self) -> ::std::string::String {
+        if self.has_parent_id() {
+            match self.one_of_parent_id.take() {
+                ::std::option::Option::Some(UpdateAppParams_oneof_one_of_parent_id::parent_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for UpdateAppParams {
@@ -776,6 +912,12 @@ impl ::protobuf::Message for UpdateAppParams {
                     }
                     self.one_of_is_trash = ::std::option::Option::Some(UpdateAppParams_oneof_one_of_is_trash::is_trash(is.read_bool()?));
                 },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_parent_id = ::std::option::Option::Some(UpdateAppParams_oneof_one_of_parent_id::parent_id(is.read_string()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -820,6 +962,13 @@ impl ::protobuf::Message for UpdateAppParams {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_parent_id {
+            match v {
+                &UpdateAppParams_oneof_one_of_parent_id::parent_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(6, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -859,6 +1008,13 @@ impl ::protobuf::Message for UpdateAppParams {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_parent_id {
+            match v {
+                &UpdateAppParams_oneof_one_of_parent_id::parent_id(ref v) => {
+                    os.write_string(6, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -922,6 +1078,11 @@ impl ::protobuf::Message for UpdateAppParams {
                 UpdateAppParams::has_is_trash,
                 UpdateAppParams::get_is_trash,
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "parent_id",
+                UpdateAppParams::has_parent_id,
+                UpdateAppParams::get_parent_id,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<UpdateAppParams>(
                 "UpdateAppParams",
                 fields,
@@ -943,6 +1104,7 @@ impl ::protobuf::Clear for UpdateAppParams {
         self.one_of_desc = ::std::option::Option::None;
         self.one_of_color_style = ::std::option::Option::None;
         self.one_of_is_trash = ::std::option::Option::None;
+        self.one_of_parent_id = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -960,59 +1122,69 @@ impl ::protobuf::reflect::ProtobufValue for UpdateAppParams {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x10app_update.proto\x1a\x10app_create.proto\"\xe9\x01\n\x10UpdateAppR\
+    \n\x10app_update.proto\x1a\x10app_create.proto\"\x9c\x02\n\x10UpdateAppR\
     equest\x12\x15\n\x06app_id\x18\x01\x20\x01(\tR\x05appId\x12\x14\n\x04nam\
     e\x18\x02\x20\x01(\tH\0R\x04name\x12\x14\n\x04desc\x18\x03\x20\x01(\tH\
     \x01R\x04desc\x12.\n\x0bcolor_style\x18\x04\x20\x01(\x0b2\x0b.ColorStyle\
     H\x02R\ncolorStyle\x12\x1b\n\x08is_trash\x18\x05\x20\x01(\x08H\x03R\x07i\
-    sTrashB\r\n\x0bone_of_nameB\r\n\x0bone_of_descB\x14\n\x12one_of_color_st\
-    yleB\x11\n\x0fone_of_is_trash\"\xe8\x01\n\x0fUpdateAppParams\x12\x15\n\
-    \x06app_id\x18\x01\x20\x01(\tR\x05appId\x12\x14\n\x04name\x18\x02\x20\
-    \x01(\tH\0R\x04name\x12\x14\n\x04desc\x18\x03\x20\x01(\tH\x01R\x04desc\
-    \x12.\n\x0bcolor_style\x18\x04\x20\x01(\x0b2\x0b.ColorStyleH\x02R\ncolor\
-    Style\x12\x1b\n\x08is_trash\x18\x05\x20\x01(\x08H\x03R\x07isTrashB\r\n\
+    sTrash\x12\x1d\n\tparent_id\x18\x06\x20\x01(\tH\x04R\x08parentIdB\r\n\
+    \x0bone_of_nameB\r\n\x0bone_of_descB\x14\n\x12one_of_color_styleB\x11\n\
+    \x0fone_of_is_trashB\x12\n\x10one_of_parent_id\"\x9b\x02\n\x0fUpdateAppP\
+    arams\x12\x15\n\x06app_id\x18\x01\x20\x01(\tR\x05appId\x12\x14\n\x04name\
+    \x18\x02\x20\x01(\tH\0R\x04name\x12\x14\n\x04desc\x18\x03\x20\x01(\tH\
+    \x01R\x04desc\x12.\n\x0bcolor_style\x18\x04\x20\x01(\x0b2\x0b.ColorStyle\
+    H\x02R\ncolorStyle\x12\x1b\n\x08is_trash\x18\x05\x20\x01(\x08H\x03R\x07i\
+    sTrash\x12\x1d\n\tparent_id\x18\x06\x20\x01(\tH\x04R\x08parentIdB\r\n\
     \x0bone_of_nameB\r\n\x0bone_of_descB\x14\n\x12one_of_color_styleB\x11\n\
-    \x0fone_of_is_trashJ\xcb\x06\n\x06\x12\x04\0\0\x10\x01\n\x08\n\x01\x0c\
-    \x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x01\0\x1a\n\n\n\x02\x04\0\x12\
-    \x04\x03\0\t\x01\n\n\n\x03\x04\0\x01\x12\x03\x03\x08\x18\n\x0b\n\x04\x04\
-    \0\x02\0\x12\x03\x04\x04\x16\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x04\x04\
-    \n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x04\x0b\x11\n\x0c\n\x05\x04\0\x02\
-    \0\x03\x12\x03\x04\x14\x15\n\x0b\n\x04\x04\0\x08\0\x12\x03\x05\x04*\n\
-    \x0c\n\x05\x04\0\x08\0\x01\x12\x03\x05\n\x15\n\x0b\n\x04\x04\0\x02\x01\
-    \x12\x03\x05\x18(\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x05\x18\x1e\n\
-    \x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x05\x1f#\n\x0c\n\x05\x04\0\x02\x01\
-    \x03\x12\x03\x05&'\n\x0b\n\x04\x04\0\x08\x01\x12\x03\x06\x04*\n\x0c\n\
-    \x05\x04\0\x08\x01\x01\x12\x03\x06\n\x15\n\x0b\n\x04\x04\0\x02\x02\x12\
-    \x03\x06\x18(\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x06\x18\x1e\n\x0c\n\
-    \x05\x04\0\x02\x02\x01\x12\x03\x06\x1f#\n\x0c\n\x05\x04\0\x02\x02\x03\
-    \x12\x03\x06&'\n\x0b\n\x04\x04\0\x08\x02\x12\x03\x07\x04<\n\x0c\n\x05\
-    \x04\0\x08\x02\x01\x12\x03\x07\n\x1c\n\x0b\n\x04\x04\0\x02\x03\x12\x03\
-    \x07\x1f:\n\x0c\n\x05\x04\0\x02\x03\x06\x12\x03\x07\x1f)\n\x0c\n\x05\x04\
-    \0\x02\x03\x01\x12\x03\x07*5\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x0789\
-    \n\x0b\n\x04\x04\0\x08\x03\x12\x03\x08\x040\n\x0c\n\x05\x04\0\x08\x03\
-    \x01\x12\x03\x08\n\x19\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x08\x1c.\n\x0c\
-    \n\x05\x04\0\x02\x04\x05\x12\x03\x08\x1c\x20\n\x0c\n\x05\x04\0\x02\x04\
-    \x01\x12\x03\x08!)\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x08,-\n\n\n\x02\
-    \x04\x01\x12\x04\n\0\x10\x01\n\n\n\x03\x04\x01\x01\x12\x03\n\x08\x17\n\
-    \x0b\n\x04\x04\x01\x02\0\x12\x03\x0b\x04\x16\n\x0c\n\x05\x04\x01\x02\0\
-    \x05\x12\x03\x0b\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x0b\x0b\x11\
-    \n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0b\x14\x15\n\x0b\n\x04\x04\x01\
-    \x08\0\x12\x03\x0c\x04*\n\x0c\n\x05\x04\x01\x08\0\x01\x12\x03\x0c\n\x15\
-    \n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x0c\x18(\n\x0c\n\x05\x04\x01\x02\
-    \x01\x05\x12\x03\x0c\x18\x1e\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x0c\
-    \x1f#\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0c&'\n\x0b\n\x04\x04\x01\
-    \x08\x01\x12\x03\r\x04*\n\x0c\n\x05\x04\x01\x08\x01\x01\x12\x03\r\n\x15\
-    \n\x0b\n\x04\x04\x01\x02\x02\x12\x03\r\x18(\n\x0c\n\x05\x04\x01\x02\x02\
-    \x05\x12\x03\r\x18\x1e\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\r\x1f#\n\
-    \x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\r&'\n\x0b\n\x04\x04\x01\x08\x02\
-    \x12\x03\x0e\x04<\n\x0c\n\x05\x04\x01\x08\x02\x01\x12\x03\x0e\n\x1c\n\
-    \x0b\n\x04\x04\x01\x02\x03\x12\x03\x0e\x1f:\n\x0c\n\x05\x04\x01\x02\x03\
-    \x06\x12\x03\x0e\x1f)\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\x0e*5\n\
-    \x0c\n\x05\x04\x01\x02\x03\x03\x12\x03\x0e89\n\x0b\n\x04\x04\x01\x08\x03\
-    \x12\x03\x0f\x040\n\x0c\n\x05\x04\x01\x08\x03\x01\x12\x03\x0f\n\x19\n\
-    \x0b\n\x04\x04\x01\x02\x04\x12\x03\x0f\x1c.\n\x0c\n\x05\x04\x01\x02\x04\
-    \x05\x12\x03\x0f\x1c\x20\n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03\x0f!)\n\
-    \x0c\n\x05\x04\x01\x02\x04\x03\x12\x03\x0f,-b\x06proto3\
+    \x0fone_of_is_trashB\x12\n\x10one_of_parent_idJ\xef\x07\n\x06\x12\x04\0\
+    \0\x12\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x01\0\
+    \x1a\n\n\n\x02\x04\0\x12\x04\x03\0\n\x01\n\n\n\x03\x04\0\x01\x12\x03\x03\
+    \x08\x18\n\x0b\n\x04\x04\0\x02\0\x12\x03\x04\x04\x16\n\x0c\n\x05\x04\0\
+    \x02\0\x05\x12\x03\x04\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x04\x0b\
+    \x11\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\x04\x14\x15\n\x0b\n\x04\x04\0\
+    \x08\0\x12\x03\x05\x04*\n\x0c\n\x05\x04\0\x08\0\x01\x12\x03\x05\n\x15\n\
+    \x0b\n\x04\x04\0\x02\x01\x12\x03\x05\x18(\n\x0c\n\x05\x04\0\x02\x01\x05\
+    \x12\x03\x05\x18\x1e\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x05\x1f#\n\
+    \x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x05&'\n\x0b\n\x04\x04\0\x08\x01\x12\
+    \x03\x06\x04*\n\x0c\n\x05\x04\0\x08\x01\x01\x12\x03\x06\n\x15\n\x0b\n\
+    \x04\x04\0\x02\x02\x12\x03\x06\x18(\n\x0c\n\x05\x04\0\x02\x02\x05\x12\
+    \x03\x06\x18\x1e\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x06\x1f#\n\x0c\n\
+    \x05\x04\0\x02\x02\x03\x12\x03\x06&'\n\x0b\n\x04\x04\0\x08\x02\x12\x03\
+    \x07\x04<\n\x0c\n\x05\x04\0\x08\x02\x01\x12\x03\x07\n\x1c\n\x0b\n\x04\
+    \x04\0\x02\x03\x12\x03\x07\x1f:\n\x0c\n\x05\x04\0\x02\x03\x06\x12\x03\
+    \x07\x1f)\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x07*5\n\x0c\n\x05\x04\0\
+    \x02\x03\x03\x12\x03\x0789\n\x0b\n\x04\x04\0\x08\x03\x12\x03\x08\x040\n\
+    \x0c\n\x05\x04\0\x08\x03\x01\x12\x03\x08\n\x19\n\x0b\n\x04\x04\0\x02\x04\
+    \x12\x03\x08\x1c.\n\x0c\n\x05\x04\0\x02\x04\x05\x12\x03\x08\x1c\x20\n\
+    \x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x08!)\n\x0c\n\x05\x04\0\x02\x04\x03\
+    \x12\x03\x08,-\n\x0b\n\x04\x04\0\x08\x04\x12\x03\t\x044\n\x0c\n\x05\x04\
+    \0\x08\x04\x01\x12\x03\t\n\x1a\n\x0b\n\x04\x04\0\x02\x05\x12\x03\t\x1d2\
+    \n\x0c\n\x05\x04\0\x02\x05\x05\x12\x03\t\x1d#\n\x0c\n\x05\x04\0\x02\x05\
+    \x01\x12\x03\t$-\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03\t01\n\n\n\x02\x04\
+    \x01\x12\x04\x0b\0\x12\x01\n\n\n\x03\x04\x01\x01\x12\x03\x0b\x08\x17\n\
+    \x0b\n\x04\x04\x01\x02\0\x12\x03\x0c\x04\x16\n\x0c\n\x05\x04\x01\x02\0\
+    \x05\x12\x03\x0c\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x0c\x0b\x11\
+    \n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0c\x14\x15\n\x0b\n\x04\x04\x01\
+    \x08\0\x12\x03\r\x04*\n\x0c\n\x05\x04\x01\x08\0\x01\x12\x03\r\n\x15\n\
+    \x0b\n\x04\x04\x01\x02\x01\x12\x03\r\x18(\n\x0c\n\x05\x04\x01\x02\x01\
+    \x05\x12\x03\r\x18\x1e\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\r\x1f#\n\
+    \x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\r&'\n\x0b\n\x04\x04\x01\x08\x01\
+    \x12\x03\x0e\x04*\n\x0c\n\x05\x04\x01\x08\x01\x01\x12\x03\x0e\n\x15\n\
+    \x0b\n\x04\x04\x01\x02\x02\x12\x03\x0e\x18(\n\x0c\n\x05\x04\x01\x02\x02\
+    \x05\x12\x03\x0e\x18\x1e\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x0e\x1f\
+    #\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x0e&'\n\x0b\n\x04\x04\x01\x08\
+    \x02\x12\x03\x0f\x04<\n\x0c\n\x05\x04\x01\x08\x02\x01\x12\x03\x0f\n\x1c\
+    \n\x0b\n\x04\x04\x01\x02\x03\x12\x03\x0f\x1f:\n\x0c\n\x05\x04\x01\x02\
+    \x03\x06\x12\x03\x0f\x1f)\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\x0f*5\
+    \n\x0c\n\x05\x04\x01\x02\x03\x03\x12\x03\x0f89\n\x0b\n\x04\x04\x01\x08\
+    \x03\x12\x03\x10\x040\n\x0c\n\x05\x04\x01\x08\x03\x01\x12\x03\x10\n\x19\
+    \n\x0b\n\x04\x04\x01\x02\x04\x12\x03\x10\x1c.\n\x0c\n\x05\x04\x01\x02\
+    \x04\x05\x12\x03\x10\x1c\x20\n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03\x10\
+    !)\n\x0c\n\x05\x04\x01\x02\x04\x03\x12\x03\x10,-\n\x0b\n\x04\x04\x01\x08\
+    \x04\x12\x03\x11\x044\n\x0c\n\x05\x04\x01\x08\x04\x01\x12\x03\x11\n\x1a\
+    \n\x0b\n\x04\x04\x01\x02\x05\x12\x03\x11\x1d2\n\x0c\n\x05\x04\x01\x02\
+    \x05\x05\x12\x03\x11\x1d#\n\x0c\n\x05\x04\x01\x02\x05\x01\x12\x03\x11$-\
+    \n\x0c\n\x05\x04\x01\x02\x05\x03\x12\x03\x1101b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -38,3 +38,9 @@ pub use trash_create::*;
 
 mod export;
 pub use export::*;
+
+mod search_query;
+pub use search_query::*;
+
+mod workspace_member;
+pub use workspace_member::*;
@@ -32,6 +32,7 @@ pub struct CreateViewRequest {
     pub view_type: ViewType,
     // message oneof groups
     pub one_of_thumbnail: ::std::option::Option<CreateViewRequest_oneof_one_of_thumbnail>,
+    pub one_of_parent_view_id: ::std::option::Option<CreateViewRequest_oneof_one_of_parent_view_id>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -48,6 +49,11 @@ pub enum CreateViewRequest_oneof_one_of_thumbnail {
     thumbnail(::std::string::String),
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum CreateViewRequest_oneof_one_of_parent_view_id {
+    parent_view_id(::std::string::String),
+}
+
 impl CreateViewRequest {
     pub fn new() -> CreateViewRequest {
         ::std::default::Default::default()
@@ -194,6 +200,55 @@ impl CreateViewRequest {
     pub fn set_view_type(&mut self, v: ViewType) {
         self.view_type = v;
     }
+
+    // string parent_view_id = 6;
+
+
+    pub fn get_parent_view_id(&self) -> &str {
+        match self.one_of_parent_view_id {
+            ::std::option::Option::Some(CreateViewRequest_oneof_one_of_parent_view_id::parent_view_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_parent_view_id(&mut self) {
+        self.one_of_parent_view_id = ::std::option::Option::None;
+    }
+
+    pub fn has_parent_view_id(&self) -> bool {
+        match self.one_of_parent_view_id {
+            ::std::option::Option::Some(CreateViewRequest_oneof_one_of_parent_view_id::parent_view_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parent_view_id(&mut self, v: ::std::string::String) {
+        self.one_of_parent_view_id = ::std::option::Option::Some(CreateViewRequest_oneof_one_of_parent_view_id::parent_view_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_parent_view_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(CreateViewRequest_oneof_one_of_parent_view_id::parent_view_id(_)) = self.one_of_parent_view_id {
+        } else {
+            self.one_of_parent_view_id = ::std::option::Option::Some(CreateViewRequest_oneof_one_of_parent_view_id::parent_view_id(::std::string::String::new()));
+        }
+        match self.one_of_parent_view_id {
+            ::std::option::Option::Some(CreateViewRequest_oneof_one_of_parent_view_id::parent_view_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_parent_view_id(&mut self) -> ::std::string::String {
+        if self.has_parent_view_id() {
+            match self.one_of_parent_view_id.take() {
+                ::std::option::Option::Some(CreateViewRequest_oneof_one_of_parent_view_id::parent_view_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for CreateViewRequest {
@@ -223,6 +278,12 @@ impl ::protobuf::Message for CreateViewRequest {
                 5 => {
                     ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.view_type, 5, &mut self.unknown_fields)?
                 },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_parent_view_id = ::std::option::Option::Some(CreateViewRequest_oneof_one_of_parent_view_id::parent_view_id(is.read_string()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -254,6 +315,13 @@ impl ::protobuf::Message for CreateViewRequest {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_parent_view_id {
+            match v {
+                &CreateViewRequest_oneof_one_of_parent_view_id::parent_view_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(6, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -279,6 +347,13 @@ impl ::protobuf::Message for CreateViewRequest {
                 },
             };
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_parent_view_id {
+            match v {
+                &CreateViewRequest_oneof_one_of_parent_view_id::parent_view_id(ref v) => {
+                    os.write_string(6, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -342,6 +417,11 @@ impl ::protobuf::Message for CreateViewRequest {
                 |m: &CreateViewRequest| { &m.view_type },
                 |m: &mut CreateViewRequest| { &mut m.view_type },
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "parent_view_id",
+                CreateViewRequest::has_parent_view_id,
+                CreateViewRequest::get_parent_view_id,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateViewRequest>(
                 "CreateViewRequest",
                 fields,
@@ -363,6 +443,7 @@ impl ::protobuf::Clear for CreateViewRequest {
         self.desc.clear();
         self.one_of_thumbnail = ::std::option::Option::None;
         self.view_type = ViewType::Blank;
+        self.one_of_parent_view_id = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -388,6 +469,8 @@ pub struct CreateViewParams {
     pub thumbnail: ::std::string::String,
     pub view_type: ViewType,
     pub data: ::std::string::String,
+    // message oneof groups
+    pub one_of_parent_view_id: ::std::option::Option<CreateViewParams_oneof_one_of_parent_view_id>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -399,6 +482,11 @@ impl<'a> ::std::default::Default for &'a CreateViewParams {
     }
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum CreateViewParams_oneof_one_of_parent_view_id {
+    parent_view_id(::std::string::String),
+}
+
 impl CreateViewParams {
     pub fn new() -> CreateViewParams {
         ::std::default::Default::default()
@@ -548,6 +636,55 @@ impl CreateViewParams {
     pub fn take_data(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.data, ::std::string::String::new())
     }
+
+    // string parent_view_id = 7;
+
+
+    pub fn get_parent_view_id(&self) -> &str {
+        match self.one_of_parent_view_id {
+            ::std::option::Option::Some(CreateViewParams_oneof_one_of_parent_view_id::parent_view_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_parent_view_id(&mut self) {
+        self.one_of_parent_view_id = ::std::option::Option::None;
+    }
+
+    pub fn has_parent_view_id(&self) -> bool {
+        match self.one_of_parent_view_id {
+            ::std::option::Option::Some(CreateViewParams_oneof_one_of_parent_view_id::parent_view_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parent_view_id(&mut self, v: ::std::string::String) {
+        self.one_of_parent_view_id = ::std::option::Option::Some(CreateViewParams_oneof_one_of_parent_view_id::parent_view_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_parent_view_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(CreateViewParams_oneof_one_of_parent_view_id::parent_view_id(_)) = self.one_of_parent_view_id {
+        } else {
+            self.one_of_parent_view_id = ::std::option::Option::Some(CreateViewParams_oneof_one_of_parent_view_id::parent_view_id(::std::string::String::new()));
+        }
+        match self.one_of_parent_view_id {
+            ::std::option::Option::Some(CreateViewParams_oneof_one_of_parent_view_id::parent_view_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_parent_view_id(&mut self) -> ::std::string::String {
+        if self.has_parent_view_id() {
+            match self.one_of_parent_view_id.take() {
+                ::std::option::Option::Some(CreateViewParams_oneof_one_of_parent_view_id::parent_view_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for CreateViewParams {
@@ -577,6 +714,12 @@ impl ::protobuf::Message for CreateViewParams {
                 6 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.data)?;
                 },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_parent_view_id = ::std::option::Option::Some(CreateViewParams_oneof_one_of_parent_view_id::parent_view_id(is.read_string()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -607,6 +750,13 @@ impl ::protobuf::Message for CreateViewParams {
         if !self.data.is_empty() {
             my_size += ::protobuf::rt::string_size(6, &self.data);
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_parent_view_id {
+            match v {
+                &CreateViewParams_oneof_one_of_parent_view_id::parent_view_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(7, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -631,6 +781,13 @@ impl ::protobuf::Message for CreateViewParams {
         if !self.data.is_empty() {
             os.write_string(6, &self.data)?;
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_parent_view_id {
+            match v {
+                &CreateViewParams_oneof_one_of_parent_view_id::parent_view_id(ref v) => {
+                    os.write_string(7, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -699,6 +856,11 @@ impl ::protobuf::Message for CreateViewParams {
                 |m: &CreateViewParams| { &m.data },
                 |m: &mut CreateViewParams| { &mut m.data },
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "parent_view_id",
+                CreateViewParams::has_parent_view_id,
+                CreateViewParams::get_parent_view_id,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<CreateViewParams>(
                 "CreateViewParams",
                 fields,
@@ -721,6 +883,7 @@ impl ::protobuf::Clear for CreateViewParams {
         self.thumbnail.clear();
         self.view_type = ViewType::Blank;
         self.data.clear();
+        self.one_of_parent_view_id = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -749,6 +912,8 @@ pub struct View {
     pub belongings: ::protobuf::SingularPtrField<RepeatedView>,
     pub modified_time: i64,
     pub create_time: i64,
+    // message oneof groups
+    pub one_of_parent_view_id: ::std::option::Option<View_oneof_one_of_parent_view_id>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -760,6 +925,11 @@ impl<'a> ::std::default::Default for &'a View {
     }
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum View_oneof_one_of_parent_view_id {
+    parent_view_id(::std::string::String),
+}
+
 impl View {
     pub fn new() -> View {
         ::std::default::Default::default()
@@ -961,6 +1131,55 @@ impl View {
     pub fn set_create_time(&mut self, v: i64) {
         self.create_time = v;
     }
+
+    // string parent_view_id = 10;
+
+
+    pub fn get_parent_view_id(&self) -> &str {
+        match self.one_of_parent_view_id {
+            ::std::option::Option::Some(View_oneof_one_of_parent_view_id::parent_view_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_parent_view_id(&mut self) {
+        self.one_of_parent_view_id = ::std::option::Option::None;
+    }
+
+    pub fn has_parent_view_id(&self) -> bool {
+        match self.one_of_parent_view_id {
+            ::std::option::Option::Some(View_oneof_one_of_parent_view_id::parent_view_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parent_view_id(&mut self, v: ::std::string::String) {
+        self.one_of_parent_view_id = ::std::option::Option::Some(View_oneof_one_of_parent_view_id::parent_view_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_parent_view_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(View_oneof_one_of_parent_view_id::parent_view_id(_)) = self.one_of_parent_view_id {
+        } else {
+            self.one_of_parent_view_id = ::std::option::Option::Some(View_oneof_one_of_parent_view_id::parent_view_id(::std::string::String::new()));
+        }
+        match self.one_of_parent_view_id {
+            ::std::option::Option::Some(View_oneof_one_of_parent_view_id::parent_view_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_parent_view_id(&mut self) -> ::std::string::String {
+        if self.has_parent_view_id() {
+            match self.one_of_parent_view_id.take() {
+                ::std::option::Option::Some(View_oneof_one_of_parent_view_id::parent_view_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for View {
@@ -1016,6 +1235,12 @@ impl ::protobuf::Message for View {
                     let tmp = is.read_int64()?;
                     self.create_time = tmp;
                 },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_parent_view_id = ::std::option::Option::Some(View_oneof_one_of_parent_view_id::parent_view_id(is.read_string()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1056,6 +1281,13 @@ impl ::protobuf::Message for View {
         if self.create_time != 0 {
             my_size += ::protobuf::rt::value_size(9, self.create_time, ::protobuf::wire_format::WireTypeVarint);
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_parent_view_id {
+            match v {
+                &View_oneof_one_of_parent_view_id::parent_view_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(10, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1091,6 +1323,13 @@ impl ::protobuf::Message for View {
         if self.create_time != 0 {
             os.write_int64(9, self.create_time)?;
         }
+        if let ::std::option::Option::Some(ref v) = self.one_of_parent_view_id {
+            match v {
+                &View_oneof_one_of_parent_view_id::parent_view_id(ref v) => {
+                    os.write_string(10, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1174,6 +1413,11 @@ impl ::protobuf::Message for View {
                 |m: &View| { &m.create_time },
                 |m: &mut View| { &mut m.create_time },
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "parent_view_id",
+                View::has_parent_view_id,
+                View::get_parent_view_id,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<View>(
                 "View",
                 fields,
@@ -1199,6 +1443,7 @@ impl ::protobuf::Clear for View {
         self.belongings.clear();
         self.modified_time = 0;
         self.create_time = 0;
+        self.one_of_parent_view_id = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -1432,27 +1677,31 @@ impl ::protobuf::reflect::ProtobufValue for ViewType {
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x11view_create.proto\"\xb9\x01\n\x11CreateViewRequest\x12\x20\n\x0cbe\
+    \n\x11view_create.proto\"\xfa\x01\n\x11CreateViewRequest\x12\x20\n\x0cbe\
     long_to_id\x18\x01\x20\x01(\tR\nbelongToId\x12\x12\n\x04name\x18\x02\x20\
     \x01(\tR\x04name\x12\x12\n\x04desc\x18\x03\x20\x01(\tR\x04desc\x12\x1e\n\
     \tthumbnail\x18\x04\x20\x01(\tH\0R\tthumbnail\x12&\n\tview_type\x18\x05\
-    \x20\x01(\x0e2\t.ViewTypeR\x08viewTypeB\x12\n\x10one_of_thumbnail\"\xb6\
-    \x01\n\x10CreateViewParams\x12\x20\n\x0cbelong_to_id\x18\x01\x20\x01(\tR\
-    \nbelongToId\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x12\n\x04\
-    desc\x18\x03\x20\x01(\tR\x04desc\x12\x1c\n\tthumbnail\x18\x04\x20\x01(\t\
-    R\tthumbnail\x12&\n\tview_type\x18\x05\x20\x01(\x0e2\t.ViewTypeR\x08view\
-    Type\x12\x12\n\x04data\x18\x06\x20\x01(\tR\x04data\"\x97\x02\n\x04View\
-    \x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12\x20\n\x0cbelong_to_id\x18\
-    \x02\x20\x01(\tR\nbelongToId\x12\x12\n\x04name\x18\x03\x20\x01(\tR\x04na\
-    me\x12\x12\n\x04desc\x18\x04\x20\x01(\tR\x04desc\x12&\n\tview_type\x18\
-    \x05\x20\x01(\x0e2\t.ViewTypeR\x08viewType\x12\x18\n\x07version\x18\x06\
-    \x20\x01(\x03R\x07version\x12-\n\nbelongings\x18\x07\x20\x01(\x0b2\r.Rep\
-    eatedViewR\nbelongings\x12#\n\rmodified_time\x18\x08\x20\x01(\x03R\x0cmo\
-    difiedTime\x12\x1f\n\x0bcreate_time\x18\t\x20\x01(\x03R\ncreateTime\"+\n\
-    \x0cRepeatedView\x12\x1b\n\x05items\x18\x01\x20\x03(\x0b2\x05.ViewR\x05i\
-    tems*\x1e\n\x08ViewType\x12\t\n\x05Blank\x10\0\x12\x07\n\x03Doc\x10\x01J\
-    \x88\x0b\n\x06\x12\x04\0\0\"\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\
-    \x02\x04\0\x12\x04\x02\0\x08\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x19\
+    \x20\x01(\x0e2\t.ViewTypeR\x08viewType\x12&\n\x0eparent_view_id\x18\x06\
+    \x20\x01(\tH\x01R\x0cparentViewIdB\x12\n\x10one_of_thumbnailB\x17\n\x15o\
+    ne_of_parent_view_id\"\xf7\x01\n\x10CreateViewParams\x12\x20\n\x0cbelong\
+    _to_id\x18\x01\x20\x01(\tR\nbelongToId\x12\x12\n\x04name\x18\x02\x20\x01\
+    (\tR\x04name\x12\x12\n\x04desc\x18\x03\x20\x01(\tR\x04desc\x12\x1c\n\tth\
+    umbnail\x18\x04\x20\x01(\tR\tthumbnail\x12&\n\tview_type\x18\x05\x20\x01\
+    (\x0e2\t.ViewTypeR\x08viewType\x12\x12\n\x04data\x18\x06\x20\x01(\tR\x04\
+    data\x12&\n\x0eparent_view_id\x18\x07\x20\x01(\tH\0R\x0cparentViewIdB\
+    \x17\n\x15one_of_parent_view_id\"\xd8\x02\n\x04View\x12\x0e\n\x02id\x18\
+    \x01\x20\x01(\tR\x02id\x12\x20\n\x0cbelong_to_id\x18\x02\x20\x01(\tR\nbe\
+    longToId\x12\x12\n\x04name\x18\x03\x20\x01(\tR\x04name\x12\x12\n\x04desc\
+    \x18\x04\x20\x01(\tR\x04desc\x12&\n\tview_type\x18\x05\x20\x01(\x0e2\t.V\
+    iewTypeR\x08viewType\x12\x18\n\x07version\x18\x06\x20\x01(\x03R\x07versi\
+    on\x12-\n\nbelongings\x18\x07\x20\x01(\x0b2\r.RepeatedViewR\nbelongings\
+    \x12#\n\rmodified_time\x18\x08\x20\x01(\x03R\x0cmodifiedTime\x12\x1f\n\
+    \x0bcreate_time\x18\t\x20\x01(\x03R\ncreateTime\x12&\n\x0eparent_view_id\
+    \x18\n\x20\x01(\tH\0R\x0cparentViewIdB\x17\n\x15one_of_parent_view_id\"+\
+    \n\x0cRepeatedView\x12\x1b\n\x05items\x18\x01\x20\x03(\x0b2\x05.ViewR\
+    \x05items*\x1e\n\x08ViewType\x12\t\n\x05Blank\x10\0\x12\x07\n\x03Doc\x10\
+    \x01J\xfe\x0c\n\x06\x12\x04\0\0%\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\
+    \n\x02\x04\0\x12\x04\x02\0\t\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x19\
     \n\x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x1c\n\x0c\n\x05\x04\0\x02\0\x05\
     \x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x17\n\x0c\
     \n\x05\x04\0\x02\0\x03\x12\x03\x03\x1a\x1b\n\x0b\n\x04\x04\0\x02\x01\x12\
@@ -1467,61 +1716,72 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x03\x06$-\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x0601\n\x0b\n\x04\x04\0\
     \x02\x04\x12\x03\x07\x04\x1b\n\x0c\n\x05\x04\0\x02\x04\x06\x12\x03\x07\
     \x04\x0c\n\x0c\n\x05\x04\0\x02\x04\x01\x12\x03\x07\r\x16\n\x0c\n\x05\x04\
-    \0\x02\x04\x03\x12\x03\x07\x19\x1a\n\n\n\x02\x04\x01\x12\x04\t\0\x10\x01\
-    \n\n\n\x03\x04\x01\x01\x12\x03\t\x08\x18\n\x0b\n\x04\x04\x01\x02\0\x12\
-    \x03\n\x04\x1c\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\n\x04\n\n\x0c\n\x05\
-    \x04\x01\x02\0\x01\x12\x03\n\x0b\x17\n\x0c\n\x05\x04\x01\x02\0\x03\x12\
-    \x03\n\x1a\x1b\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x0b\x04\x14\n\x0c\n\
-    \x05\x04\x01\x02\x01\x05\x12\x03\x0b\x04\n\n\x0c\n\x05\x04\x01\x02\x01\
-    \x01\x12\x03\x0b\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0b\x12\
-    \x13\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\x0c\x04\x14\n\x0c\n\x05\x04\x01\
-    \x02\x02\x05\x12\x03\x0c\x04\n\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\
-    \x0c\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x0c\x12\x13\n\x0b\n\
-    \x04\x04\x01\x02\x03\x12\x03\r\x04\x19\n\x0c\n\x05\x04\x01\x02\x03\x05\
-    \x12\x03\r\x04\n\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\r\x0b\x14\n\x0c\
-    \n\x05\x04\x01\x02\x03\x03\x12\x03\r\x17\x18\n\x0b\n\x04\x04\x01\x02\x04\
-    \x12\x03\x0e\x04\x1b\n\x0c\n\x05\x04\x01\x02\x04\x06\x12\x03\x0e\x04\x0c\
-    \n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03\x0e\r\x16\n\x0c\n\x05\x04\x01\
-    \x02\x04\x03\x12\x03\x0e\x19\x1a\n\x0b\n\x04\x04\x01\x02\x05\x12\x03\x0f\
-    \x04\x14\n\x0c\n\x05\x04\x01\x02\x05\x05\x12\x03\x0f\x04\n\n\x0c\n\x05\
-    \x04\x01\x02\x05\x01\x12\x03\x0f\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x05\x03\
-    \x12\x03\x0f\x12\x13\n\n\n\x02\x04\x02\x12\x04\x11\0\x1b\x01\n\n\n\x03\
-    \x04\x02\x01\x12\x03\x11\x08\x0c\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x12\
-    \x04\x12\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x12\x04\n\n\x0c\n\x05\x04\
-    \x02\x02\0\x01\x12\x03\x12\x0b\r\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\
-    \x12\x10\x11\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\x13\x04\x1c\n\x0c\n\x05\
-    \x04\x02\x02\x01\x05\x12\x03\x13\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\
-    \x12\x03\x13\x0b\x17\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x13\x1a\x1b\
-    \n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x14\x04\x14\n\x0c\n\x05\x04\x02\x02\
-    \x02\x05\x12\x03\x14\x04\n\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x14\
-    \x0b\x0f\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x14\x12\x13\n\x0b\n\x04\
-    \x04\x02\x02\x03\x12\x03\x15\x04\x14\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\
-    \x03\x15\x04\n\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x15\x0b\x0f\n\x0c\
-    \n\x05\x04\x02\x02\x03\x03\x12\x03\x15\x12\x13\n\x0b\n\x04\x04\x02\x02\
-    \x04\x12\x03\x16\x04\x1b\n\x0c\n\x05\x04\x02\x02\x04\x06\x12\x03\x16\x04\
-    \x0c\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03\x16\r\x16\n\x0c\n\x05\x04\
-    \x02\x02\x04\x03\x12\x03\x16\x19\x1a\n\x0b\n\x04\x04\x02\x02\x05\x12\x03\
-    \x17\x04\x16\n\x0c\n\x05\x04\x02\x02\x05\x05\x12\x03\x17\x04\t\n\x0c\n\
-    \x05\x04\x02\x02\x05\x01\x12\x03\x17\n\x11\n\x0c\n\x05\x04\x02\x02\x05\
-    \x03\x12\x03\x17\x14\x15\n\x0b\n\x04\x04\x02\x02\x06\x12\x03\x18\x04\x20\
-    \n\x0c\n\x05\x04\x02\x02\x06\x06\x12\x03\x18\x04\x10\n\x0c\n\x05\x04\x02\
-    \x02\x06\x01\x12\x03\x18\x11\x1b\n\x0c\n\x05\x04\x02\x02\x06\x03\x12\x03\
-    \x18\x1e\x1f\n\x0b\n\x04\x04\x02\x02\x07\x12\x03\x19\x04\x1c\n\x0c\n\x05\
-    \x04\x02\x02\x07\x05\x12\x03\x19\x04\t\n\x0c\n\x05\x04\x02\x02\x07\x01\
-    \x12\x03\x19\n\x17\n\x0c\n\x05\x04\x02\x02\x07\x03\x12\x03\x19\x1a\x1b\n\
-    \x0b\n\x04\x04\x02\x02\x08\x12\x03\x1a\x04\x1a\n\x0c\n\x05\x04\x02\x02\
-    \x08\x05\x12\x03\x1a\x04\t\n\x0c\n\x05\x04\x02\x02\x08\x01\x12\x03\x1a\n\
-    \x15\n\x0c\n\x05\x04\x02\x02\x08\x03\x12\x03\x1a\x18\x19\n\n\n\x02\x04\
-    \x03\x12\x04\x1c\0\x1e\x01\n\n\n\x03\x04\x03\x01\x12\x03\x1c\x08\x14\n\
-    \x0b\n\x04\x04\x03\x02\0\x12\x03\x1d\x04\x1c\n\x0c\n\x05\x04\x03\x02\0\
-    \x04\x12\x03\x1d\x04\x0c\n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03\x1d\r\x11\
-    \n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x1d\x12\x17\n\x0c\n\x05\x04\x03\
-    \x02\0\x03\x12\x03\x1d\x1a\x1b\n\n\n\x02\x05\0\x12\x04\x1f\0\"\x01\n\n\n\
-    \x03\x05\0\x01\x12\x03\x1f\x05\r\n\x0b\n\x04\x05\0\x02\0\x12\x03\x20\x04\
-    \x0e\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x20\x04\t\n\x0c\n\x05\x05\0\x02\
-    \0\x02\x12\x03\x20\x0c\r\n\x0b\n\x04\x05\0\x02\x01\x12\x03!\x04\x0c\n\
-    \x0c\n\x05\x05\0\x02\x01\x01\x12\x03!\x04\x07\n\x0c\n\x05\x05\0\x02\x01\
-    \x02\x12\x03!\n\x0bb\x06proto3\
+    \0\x02\x04\x03\x12\x03\x07\x19\x1a\n\x0b\n\x04\x04\0\x08\x01\x12\x03\x08\
+    \x04>\n\x0c\n\x05\x04\0\x08\x01\x01\x12\x03\x08\n\x1f\n\x0b\n\x04\x04\0\
+    \x02\x05\x12\x03\x08\"<\n\x0c\n\x05\x04\0\x02\x05\x05\x12\x03\x08\"(\n\
+    \x0c\n\x05\x04\0\x02\x05\x01\x12\x03\x08)7\n\x0c\n\x05\x04\0\x02\x05\x03\
+    \x12\x03\x08:;\n\n\n\x02\x04\x01\x12\x04\n\0\x12\x01\n\n\n\x03\x04\x01\
+    \x01\x12\x03\n\x08\x18\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x0b\x04\x1c\n\
+    \x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x0b\x04\n\n\x0c\n\x05\x04\x01\x02\0\
+    \x01\x12\x03\x0b\x0b\x17\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0b\x1a\
+    \x1b\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x0c\x04\x14\n\x0c\n\x05\x04\x01\
+    \x02\x01\x05\x12\x03\x0c\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\
+    \x0c\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x0c\x12\x13\n\x0b\n\
+    \x04\x04\x01\x02\x02\x12\x03\r\x04\x14\n\x0c\n\x05\x04\x01\x02\x02\x05\
+    \x12\x03\r\x04\n\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\r\x0b\x0f\n\x0c\
+    \n\x05\x04\x01\x02\x02\x03\x12\x03\r\x12\x13\n\x0b\n\x04\x04\x01\x02\x03\
+    \x12\x03\x0e\x04\x19\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03\x0e\x04\n\n\
+    \x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\x0e\x0b\x14\n\x0c\n\x05\x04\x01\
+    \x02\x03\x03\x12\x03\x0e\x17\x18\n\x0b\n\x04\x04\x01\x02\x04\x12\x03\x0f\
+    \x04\x1b\n\x0c\n\x05\x04\x01\x02\x04\x06\x12\x03\x0f\x04\x0c\n\x0c\n\x05\
+    \x04\x01\x02\x04\x01\x12\x03\x0f\r\x16\n\x0c\n\x05\x04\x01\x02\x04\x03\
+    \x12\x03\x0f\x19\x1a\n\x0b\n\x04\x04\x01\x02\x05\x12\x03\x10\x04\x14\n\
+    \x0c\n\x05\x04\x01\x02\x05\x05\x12\x03\x10\x04\n\n\x0c\n\x05\x04\x01\x02\
+    \x05\x01\x12\x03\x10\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x05\x03\x12\x03\x10\
+    \x12\x13\n\x0b\n\x04\x04\x01\x08\0\x12\x03\x11\x04>\n\x0c\n\x05\x04\x01\
+    \x08\0\x01\x12\x03\x11\n\x1f\n\x0b\n\x04\x04\x01\x02\x06\x12\x03\x11\"<\
+    \n\x0c\n\x05\x04\x01\x02\x06\x05\x12\x03\x11\"(\n\x0c\n\x05\x04\x01\x02\
+    \x06\x01\x12\x03\x11)7\n\x0c\n\x05\x04\x01\x02\x06\x03\x12\x03\x11:;\n\n\
+    \n\x02\x04\x02\x12\x04\x13\0\x1e\x01\n\n\n\x03\x04\x02\x01\x12\x03\x13\
+    \x08\x0c\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x14\x04\x12\n\x0c\n\x05\x04\
+    \x02\x02\0\x05\x12\x03\x14\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\
+    \x14\x0b\r\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x14\x10\x11\n\x0b\n\x04\
+    \x04\x02\x02\x01\x12\x03\x15\x04\x1c\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\
+    \x03\x15\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x15\x0b\x17\n\x0c\
+    \n\x05\x04\x02\x02\x01\x03\x12\x03\x15\x1a\x1b\n\x0b\n\x04\x04\x02\x02\
+    \x02\x12\x03\x16\x04\x14\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x16\x04\
+    \n\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x16\x0b\x0f\n\x0c\n\x05\x04\
+    \x02\x02\x02\x03\x12\x03\x16\x12\x13\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\
+    \x17\x04\x14\n\x0c\n\x05\x04\x02\x02\x03\x05\x12\x03\x17\x04\n\n\x0c\n\
+    \x05\x04\x02\x02\x03\x01\x12\x03\x17\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x03\
+    \x03\x12\x03\x17\x12\x13\n\x0b\n\x04\x04\x02\x02\x04\x12\x03\x18\x04\x1b\
+    \n\x0c\n\x05\x04\x02\x02\x04\x06\x12\x03\x18\x04\x0c\n\x0c\n\x05\x04\x02\
+    \x02\x04\x01\x12\x03\x18\r\x16\n\x0c\n\x05\x04\x02\x02\x04\x03\x12\x03\
+    \x18\x19\x1a\n\x0b\n\x04\x04\x02\x02\x05\x12\x03\x19\x04\x16\n\x0c\n\x05\
+    \x04\x02\x02\x05\x05\x12\x03\x19\x04\t\n\x0c\n\x05\x04\x02\x02\x05\x01\
+    \x12\x03\x19\n\x11\n\x0c\n\x05\x04\x02\x02\x05\x03\x12\x03\x19\x14\x15\n\
+    \x0b\n\x04\x04\x02\x02\x06\x12\x03\x1a\x04\x20\n\x0c\n\x05\x04\x02\x02\
+    \x06\x06\x12\x03\x1a\x04\x10\n\x0c\n\x05\x04\x02\x02\x06\x01\x12\x03\x1a\
+    \x11\x1b\n\x0c\n\x05\x04\x02\x02\x06\x03\x12\x03\x1a\x1e\x1f\n\x0b\n\x04\
+    \x04\x02\x02\x07\x12\x03\x1b\x04\x1c\n\x0c\n\x05\x04\x02\x02\x07\x05\x12\
+    \x03\x1b\x04\t\n\x0c\n\x05\x04\x02\x02\x07\x01\x12\x03\x1b\n\x17\n\x0c\n\
+    \x05\x04\x02\x02\x07\x03\x12\x03\x1b\x1a\x1b\n\x0b\n\x04\x04\x02\x02\x08\
+    \x12\x03\x1c\x04\x1a\n\x0c\n\x05\x04\x02\x02\x08\x05\x12\x03\x1c\x04\t\n\
+    \x0c\n\x05\x04\x02\x02\x08\x01\x12\x03\x1c\n\x15\n\x0c\n\x05\x04\x02\x02\
+    \x08\x03\x12\x03\x1c\x18\x19\n\x0b\n\x04\x04\x02\x08\0\x12\x03\x1d\x04?\
+    \n\x0c\n\x05\x04\x02\x08\0\x01\x12\x03\x1d\n\x1f\n\x0b\n\x04\x04\x02\x02\
+    \t\x12\x03\x1d\"=\n\x0c\n\x05\x04\x02\x02\t\x05\x12\x03\x1d\"(\n\x0c\n\
+    \x05\x04\x02\x02\t\x01\x12\x03\x1d)7\n\x0c\n\x05\x04\x02\x02\t\x03\x12\
+    \x03\x1d:<\n\n\n\x02\x04\x03\x12\x04\x1f\0!\x01\n\n\n\x03\x04\x03\x01\
+    \x12\x03\x1f\x08\x14\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x20\x04\x1c\n\x0c\
+    \n\x05\x04\x03\x02\0\x04\x12\x03\x20\x04\x0c\n\x0c\n\x05\x04\x03\x02\0\
+    \x06\x12\x03\x20\r\x11\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03\x20\x12\x17\
+    \n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x20\x1a\x1b\n\n\n\x02\x05\0\x12\
+    \x04\"\0%\x01\n\n\n\x03\x05\0\x01\x12\x03\"\x05\r\n\x0b\n\x04\x05\0\x02\
+    \0\x12\x03#\x04\x0e\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03#\x04\t\n\x0c\n\
+    \x05\x05\0\x02\0\x02\x12\x03#\x0c\r\n\x0b\n\x04\x05\0\x02\x01\x12\x03$\
+    \x04\x0c\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03$\x04\x07\n\x0c\n\x05\x05\
+    \0\x02\x01\x02\x12\x03$\n\x0bb\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -829,6 +829,682 @@ impl ::protobuf::reflect::ProtobufValue for UpdateViewParams {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct MoveViewRequest {
+    // message fields
+    pub view_id: ::std::string::String,
+    // message oneof groups
+    pub one_of_parent_view_id: ::std::option::Option<MoveViewRequest_oneof_one_of_parent_view_id>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MoveViewRequest {
+    fn default() -> &'a MoveViewRequest {
+        <MoveViewRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum MoveViewRequest_oneof_one_of_parent_view_id {
+    parent_view_id(::std::string::String),
+}
+
+impl MoveViewRequest {
+    pub fn new() -> MoveViewRequest {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // string parent_view_id = 2;
+
+
+    pub fn get_parent_view_id(&self) -> &str {
+        match self.one_of_parent_view_id {
+            ::std::option::Option::Some(MoveViewRequest_oneof_one_of_parent_view_id::parent_view_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_parent_view_id(&mut self) {
+        self.one_of_parent_view_id = ::std::option::Option::None;
+    }
+
+    pub fn has_parent_view_id(&self) -> bool {
+        match self.one_of_parent_view_id {
+            ::std::option::Option::Some(MoveViewRequest_oneof_one_of_parent_view_id::parent_view_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parent_view_id(&mut self, v: ::std::string::String) {
+        self.one_of_parent_view_id = ::std::option::Option::Some(MoveViewRequest_oneof_one_of_parent_view_id::parent_view_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_parent_view_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(MoveViewRequest_oneof_one_of_parent_view_id::parent_view_id(_)) = self.one_of_parent_view_id {
+        } else {
+            self.one_of_parent_view_id = ::std::option::Option::Some(MoveViewRequest_oneof_one_of_parent_view_id::parent_view_id(::std::string::String::new()));
+        }
+        match self.one_of_parent_view_id {
+            ::std::option::Option::Some(MoveViewRequest_oneof_one_of_parent_view_id::parent_view_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_parent_view_id(&mut self) -> ::std::string::String {
+        if self.has_parent_view_id() {
+            match self.one_of_parent_view_id.take() {
+                ::std::option::Option::Some(MoveViewRequest_oneof_one_of_parent_view_id::parent_view_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for MoveViewRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_parent_view_id = ::std::option::Option::Some(MoveViewRequest_oneof_one_of_parent_view_id::parent_view_id(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_parent_view_id {
+            match v {
+                &MoveViewRequest_oneof_one_of_parent_view_id::parent_view_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(2, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_parent_view_id {
+            match v {
+                &MoveViewRequest_oneof_one_of_parent_view_id::parent_view_id(ref v) => {
+                    os.write_string(2, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MoveViewRequest {
+        MoveViewRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &MoveViewRequest| { &m.view_id },
+                |m: &mut MoveViewRequest| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "parent_view_id",
+                MoveViewRequest::has_parent_view_id,
+                MoveViewRequest::get_parent_view_id,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MoveViewRequest>(
+                "MoveViewRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MoveViewRequest {
+        static instance: ::protobuf::rt::LazyV2<MoveViewRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MoveViewRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for MoveViewRequest {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.one_of_parent_view_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MoveViewRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MoveViewRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct MoveViewParams {
+    // message fields
+    pub view_id: ::std::string::String,
+    // message oneof groups
+    pub one_of_parent_view_id: ::std::option::Option<MoveViewParams_oneof_one_of_parent_view_id>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a MoveViewParams {
+    fn default() -> &'a MoveViewParams {
+        <MoveViewParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum MoveViewParams_oneof_one_of_parent_view_id {
+    parent_view_id(::std::string::String),
+}
+
+impl MoveViewParams {
+    pub fn new() -> MoveViewParams {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // string parent_view_id = 2;
+
+
+    pub fn get_parent_view_id(&self) -> &str {
+        match self.one_of_parent_view_id {
+            ::std::option::Option::Some(MoveViewParams_oneof_one_of_parent_view_id::parent_view_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_parent_view_id(&mut self) {
+        self.one_of_parent_view_id = ::std::option::Option::None;
+    }
+
+    pub fn has_parent_view_id(&self) -> bool {
+        match self.one_of_parent_view_id {
+            ::std::option::Option::Some(MoveViewParams_oneof_one_of_parent_view_id::parent_view_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_parent_view_id(&mut self, v: ::std::string::String) {
+        self.one_of_parent_view_id = ::std::option::Option::Some(MoveViewParams_oneof_one_of_parent_view_id::parent_view_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_parent_view_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(MoveViewParams_oneof_one_of_parent_view_id::parent_view_id(_)) = self.one_of_parent_view_id {
+        } else {
+            self.one_of_parent_view_id = ::std::option::Option::Some(MoveViewParams_oneof_one_of_parent_view_id::parent_view_id(::std::string::String::new()));
+        }
+        match self.one_of_parent_view_id {
+            ::std::option::Option::Some(MoveViewParams_oneof_one_of_parent_view_id::parent_view_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_parent_view_id(&mut self) -> ::std::string::String {
+        if self.has_parent_view_id() {
+            match self.one_of_parent_view_id.take() {
+                ::std::option::Option::Some(MoveViewParams_oneof_one_of_parent_view_id::parent_view_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for MoveViewParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_parent_view_id = ::std::option::Option::Some(MoveViewParams_oneof_one_of_parent_view_id::parent_view_id(is.read_string()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_parent_view_id {
+            match v {
+                &MoveViewParams_oneof_one_of_parent_view_id::parent_view_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(2, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_parent_view_id {
+            match v {
+                &MoveViewParams_oneof_one_of_parent_view_id::parent_view_id(ref v) => {
+                    os.write_string(2, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> MoveViewParams {
+        MoveViewParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &MoveViewParams| { &m.view_id },
+                |m: &mut MoveViewParams| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "parent_view_id",
+                MoveViewParams::has_parent_view_id,
+                MoveViewParams::get_parent_view_id,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<MoveViewParams>(
+                "MoveViewParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static MoveViewParams {
+        static instance: ::protobuf::rt::LazyV2<MoveViewParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(MoveViewParams::new)
+    }
+}
+
+impl ::protobuf::Clear for MoveViewParams {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.one_of_parent_view_id = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for MoveViewParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for MoveViewParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ReorderViewParams {
+    // message fields
+    pub view_id: ::std::string::String,
+    pub target_index: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ReorderViewParams {
+    fn default() -> &'a ReorderViewParams {
+        <ReorderViewParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ReorderViewParams {
+    pub fn new() -> ReorderViewParams {
+        ::std::default::Default::default()
+    }
+
+    // string view_id = 1;
+
+
+    pub fn get_view_id(&self) -> &str {
+        &self.view_id
+    }
+    pub fn clear_view_id(&mut self) {
+        self.view_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_view_id(&mut self, v: ::std::string::String) {
+        self.view_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_view_id(&mut self) -> &mut ::std::string::String {
+        &mut self.view_id
+    }
+
+    // Take field
+    pub fn take_view_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.view_id, ::std::string::String::new())
+    }
+
+    // int64 target_index = 2;
+
+
+    pub fn get_target_index(&self) -> i64 {
+        self.target_index
+    }
+    pub fn clear_target_index(&mut self) {
+        self.target_index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_index(&mut self, v: i64) {
+        self.target_index = v;
+    }
+}
+
+impl ::protobuf::Message for ReorderViewParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.view_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.target_index = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.view_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.view_id);
+        }
+        if self.target_index != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.target_index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.view_id.is_empty() {
+            os.write_string(1, &self.view_id)?;
+        }
+        if self.target_index != 0 {
+            os.write_int64(2, self.target_index)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ReorderViewParams {
+        ReorderViewParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "view_id",
+                |m: &ReorderViewParams| { &m.view_id },
+                |m: &mut ReorderViewParams| { &mut m.view_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "target_index",
+                |m: &ReorderViewParams| { &m.target_index },
+                |m: &mut ReorderViewParams| { &mut m.target_index },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ReorderViewParams>(
+                "ReorderViewParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ReorderViewParams {
+        static instance: ::protobuf::rt::LazyV2<ReorderViewParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ReorderViewParams::new)
+    }
+}
+
+impl ::protobuf::Clear for ReorderViewParams {
+    fn clear(&mut self) {
+        self.view_id.clear();
+        self.target_index = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ReorderViewParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ReorderViewParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x11view_update.proto\"\xaa\x01\n\x11UpdateViewRequest\x12\x17\n\x07vi\
     ew_id\x18\x01\x20\x01(\tR\x06viewId\x12\x14\n\x04name\x18\x02\x20\x01(\t\
@@ -838,39 +1514,68 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     rams\x12\x17\n\x07view_id\x18\x01\x20\x01(\tR\x06viewId\x12\x14\n\x04nam\
     e\x18\x02\x20\x01(\tH\0R\x04name\x12\x14\n\x04desc\x18\x03\x20\x01(\tH\
     \x01R\x04desc\x12\x1e\n\tthumbnail\x18\x04\x20\x01(\tH\x02R\tthumbnailB\
-    \r\n\x0bone_of_nameB\r\n\x0bone_of_descB\x12\n\x10one_of_thumbnailJ\x9c\
-    \x05\n\x06\x12\x04\0\0\r\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\
-    \x04\0\x12\x04\x02\0\x07\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x19\n\
-    \x0b\n\x04\x04\0\x02\0\x12\x03\x03\x04\x17\n\x0c\n\x05\x04\0\x02\0\x05\
-    \x12\x03\x03\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x12\n\x0c\
-    \n\x05\x04\0\x02\0\x03\x12\x03\x03\x15\x16\n\x0b\n\x04\x04\0\x08\0\x12\
-    \x03\x04\x04*\n\x0c\n\x05\x04\0\x08\0\x01\x12\x03\x04\n\x15\n\x0b\n\x04\
-    \x04\0\x02\x01\x12\x03\x04\x18(\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\
-    \x04\x18\x1e\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x04\x1f#\n\x0c\n\x05\
-    \x04\0\x02\x01\x03\x12\x03\x04&'\n\x0b\n\x04\x04\0\x08\x01\x12\x03\x05\
-    \x04*\n\x0c\n\x05\x04\0\x08\x01\x01\x12\x03\x05\n\x15\n\x0b\n\x04\x04\0\
-    \x02\x02\x12\x03\x05\x18(\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x18\
-    \x1e\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x03\x05\x1f#\n\x0c\n\x05\x04\0\
-    \x02\x02\x03\x12\x03\x05&'\n\x0b\n\x04\x04\0\x08\x02\x12\x03\x06\x044\n\
-    \x0c\n\x05\x04\0\x08\x02\x01\x12\x03\x06\n\x1a\n\x0b\n\x04\x04\0\x02\x03\
-    \x12\x03\x06\x1d2\n\x0c\n\x05\x04\0\x02\x03\x05\x12\x03\x06\x1d#\n\x0c\n\
-    \x05\x04\0\x02\x03\x01\x12\x03\x06$-\n\x0c\n\x05\x04\0\x02\x03\x03\x12\
-    \x03\x0601\n\n\n\x02\x04\x01\x12\x04\x08\0\r\x01\n\n\n\x03\x04\x01\x01\
-    \x12\x03\x08\x08\x18\n\x0b\n\x04\x04\x01\x02\0\x12\x03\t\x04\x17\n\x0c\n\
-    \x05\x04\x01\x02\0\x05\x12\x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\
-    \x03\t\x0b\x12\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\t\x15\x16\n\x0b\n\
-    \x04\x04\x01\x08\0\x12\x03\n\x04*\n\x0c\n\x05\x04\x01\x08\0\x01\x12\x03\
-    \n\n\x15\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\n\x18(\n\x0c\n\x05\x04\x01\
-    \x02\x01\x05\x12\x03\n\x18\x1e\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\n\
-    \x1f#\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\n&'\n\x0b\n\x04\x04\x01\
-    \x08\x01\x12\x03\x0b\x04*\n\x0c\n\x05\x04\x01\x08\x01\x01\x12\x03\x0b\n\
-    \x15\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\x0b\x18(\n\x0c\n\x05\x04\x01\
-    \x02\x02\x05\x12\x03\x0b\x18\x1e\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\
-    \x0b\x1f#\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x0b&'\n\x0b\n\x04\x04\
-    \x01\x08\x02\x12\x03\x0c\x044\n\x0c\n\x05\x04\x01\x08\x02\x01\x12\x03\
-    \x0c\n\x1a\n\x0b\n\x04\x04\x01\x02\x03\x12\x03\x0c\x1d2\n\x0c\n\x05\x04\
-    \x01\x02\x03\x05\x12\x03\x0c\x1d#\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\
-    \x03\x0c$-\n\x0c\n\x05\x04\x01\x02\x03\x03\x12\x03\x0c01b\x06proto3\
+    \r\n\x0bone_of_nameB\r\n\x0bone_of_descB\x12\n\x10one_of_thumbnail\"k\n\
+    \x0fMoveViewRequest\x12\x17\n\x07view_id\x18\x01\x20\x01(\tR\x06viewId\
+    \x12&\n\x0eparent_view_id\x18\x02\x20\x01(\tH\0R\x0cparentViewIdB\x17\n\
+    \x15one_of_parent_view_id\"j\n\x0eMoveViewParams\x12\x17\n\x07view_id\
+    \x18\x01\x20\x01(\tR\x06viewId\x12&\n\x0eparent_view_id\x18\x02\x20\x01(\
+    \tH\0R\x0cparentViewIdB\x17\n\x15one_of_parent_view_id\"O\n\x11ReorderVi\
+    ewParams\x12\x17\n\x07view_id\x18\x01\x20\x01(\tR\x06viewId\x12!\n\x0cta\
+    rget_index\x18\x02\x20\x01(\x03R\x0btargetIndexJ\xe4\x08\n\x06\x12\x04\0\
+    \0\x19\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\x02\0\
+    \x07\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x19\n\x0b\n\x04\x04\0\x02\0\
+    \x12\x03\x03\x04\x17\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\n\x0c\
+    \n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x12\n\x0c\n\x05\x04\0\x02\0\x03\
+    \x12\x03\x03\x15\x16\n\x0b\n\x04\x04\0\x08\0\x12\x03\x04\x04*\n\x0c\n\
+    \x05\x04\0\x08\0\x01\x12\x03\x04\n\x15\n\x0b\n\x04\x04\0\x02\x01\x12\x03\
+    \x04\x18(\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x18\x1e\n\x0c\n\x05\
+    \x04\0\x02\x01\x01\x12\x03\x04\x1f#\n\x0c\n\x05\x04\0\x02\x01\x03\x12\
+    \x03\x04&'\n\x0b\n\x04\x04\0\x08\x01\x12\x03\x05\x04*\n\x0c\n\x05\x04\0\
+    \x08\x01\x01\x12\x03\x05\n\x15\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x18\
+    (\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x18\x1e\n\x0c\n\x05\x04\0\
+    \x02\x02\x01\x12\x03\x05\x1f#\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05&\
+    '\n\x0b\n\x04\x04\0\x08\x02\x12\x03\x06\x044\n\x0c\n\x05\x04\0\x08\x02\
+    \x01\x12\x03\x06\n\x1a\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x1d2\n\x0c\
+    \n\x05\x04\0\x02\x03\x05\x12\x03\x06\x1d#\n\x0c\n\x05\x04\0\x02\x03\x01\
+    \x12\x03\x06$-\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x0601\n\n\n\x02\x04\
+    \x01\x12\x04\x08\0\r\x01\n\n\n\x03\x04\x01\x01\x12\x03\x08\x08\x18\n\x0b\
+    \n\x04\x04\x01\x02\0\x12\x03\t\x04\x17\n\x0c\n\x05\x04\x01\x02\0\x05\x12\
+    \x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\t\x0b\x12\n\x0c\n\x05\
+    \x04\x01\x02\0\x03\x12\x03\t\x15\x16\n\x0b\n\x04\x04\x01\x08\0\x12\x03\n\
+    \x04*\n\x0c\n\x05\x04\x01\x08\0\x01\x12\x03\n\n\x15\n\x0b\n\x04\x04\x01\
+    \x02\x01\x12\x03\n\x18(\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\n\x18\
+    \x1e\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\n\x1f#\n\x0c\n\x05\x04\x01\
+    \x02\x01\x03\x12\x03\n&'\n\x0b\n\x04\x04\x01\x08\x01\x12\x03\x0b\x04*\n\
+    \x0c\n\x05\x04\x01\x08\x01\x01\x12\x03\x0b\n\x15\n\x0b\n\x04\x04\x01\x02\
+    \x02\x12\x03\x0b\x18(\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\x0b\x18\
+    \x1e\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x0b\x1f#\n\x0c\n\x05\x04\
+    \x01\x02\x02\x03\x12\x03\x0b&'\n\x0b\n\x04\x04\x01\x08\x02\x12\x03\x0c\
+    \x044\n\x0c\n\x05\x04\x01\x08\x02\x01\x12\x03\x0c\n\x1a\n\x0b\n\x04\x04\
+    \x01\x02\x03\x12\x03\x0c\x1d2\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03\
+    \x0c\x1d#\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\x0c$-\n\x0c\n\x05\x04\
+    \x01\x02\x03\x03\x12\x03\x0c01\n\n\n\x02\x04\x02\x12\x04\x0e\0\x11\x01\n\
+    \n\n\x03\x04\x02\x01\x12\x03\x0e\x08\x17\n\x0b\n\x04\x04\x02\x02\0\x12\
+    \x03\x0f\x04\x17\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0f\x04\n\n\x0c\n\
+    \x05\x04\x02\x02\0\x01\x12\x03\x0f\x0b\x12\n\x0c\n\x05\x04\x02\x02\0\x03\
+    \x12\x03\x0f\x15\x16\n\x0b\n\x04\x04\x02\x08\0\x12\x03\x10\x04>\n\x0c\n\
+    \x05\x04\x02\x08\0\x01\x12\x03\x10\n\x1f\n\x0b\n\x04\x04\x02\x02\x01\x12\
+    \x03\x10\"<\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\x10\"(\n\x0c\n\x05\
+    \x04\x02\x02\x01\x01\x12\x03\x10)7\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\
+    \x03\x10:;\n\n\n\x02\x04\x03\x12\x04\x12\0\x15\x01\n\n\n\x03\x04\x03\x01\
+    \x12\x03\x12\x08\x16\n\x0b\n\x04\x04\x03\x02\0\x12\x03\x13\x04\x17\n\x0c\
+    \n\x05\x04\x03\x02\0\x05\x12\x03\x13\x04\n\n\x0c\n\x05\x04\x03\x02\0\x01\
+    \x12\x03\x13\x0b\x12\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x13\x15\x16\n\
+    \x0b\n\x04\x04\x03\x08\0\x12\x03\x14\x04>\n\x0c\n\x05\x04\x03\x08\0\x01\
+    \x12\x03\x14\n\x1f\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\x14\"<\n\x0c\n\
+    \x05\x04\x03\x02\x01\x05\x12\x03\x14\"(\n\x0c\n\x05\x04\x03\x02\x01\x01\
+    \x12\x03\x14)7\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\x14:;\n\n\n\x02\
+    \x04\x04\x12\x04\x16\0\x19\x01\n\n\n\x03\x04\x04\x01\x12\x03\x16\x08\x19\
+    \n\x0b\n\x04\x04\x04\x02\0\x12\x03\x17\x04\x17\n\x0c\n\x05\x04\x04\x02\0\
+    \x05\x12\x03\x17\x04\n\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x17\x0b\x12\
+    \n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x17\x15\x16\n\x0b\n\x04\x04\x04\
+    \x02\x01\x12\x03\x18\x04\x1b\n\x0c\n\x05\x04\x04\x02\x01\x05\x12\x03\x18\
+    \x04\t\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03\x18\n\x16\n\x0c\n\x05\x04\
+    \x04\x02\x01\x03\x12\x03\x18\x19\x1ab\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -799,6 +799,8 @@ impl ::protobuf::reflect::ProtobufValue for Workspace {
 pub struct RepeatedWorkspace {
     // message fields
     pub items: ::protobuf::RepeatedField<Workspace>,
+    // message oneof groups
+    pub one_of_next_cursor: ::std::option::Option<RepeatedWorkspace_oneof_one_of_next_cursor>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -810,6 +812,11 @@ impl<'a> ::std::default::Default for &'a RepeatedWorkspace {
     }
 }
 
+#[derive(Clone,PartialEq,Debug)]
+pub enum RepeatedWorkspace_oneof_one_of_next_cursor {
+    next_cursor(::std::string::String),
+}
+
 impl RepeatedWorkspace {
     pub fn new() -> RepeatedWorkspace {
         ::std::default::Default::default()
@@ -839,6 +846,55 @@ impl RepeatedWorkspace {
     pub fn take_items(&mut self) -> ::protobuf::RepeatedField<Workspace> {
         ::std::mem::replace(&mut self.items, ::protobuf::RepeatedField::new())
     }
+
+    // string next_cursor = 2;
+
+
+    pub fn get_next_cursor(&self) -> &str {
+        match self.one_of_next_cursor {
+            ::std::option::Option::Some(RepeatedWorkspace_oneof_one_of_next_cursor::next_cursor(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_next_cursor(&mut self) {
+        self.one_of_next_cursor = ::std::option::Option::None;
+    }
+
+    pub fn has_next_cursor(&self) -> bool {
+        match self.one_of_next_cursor {
+            ::std::option::Option::Some(RepeatedWorkspace_oneof_one_of_next_cursor::next_cursor(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_next_cursor(&mut self, v: ::std::string::String) {
+        self.one_of_next_cursor = ::std::option::Option::Some(RepeatedWorkspace_oneof_one_of_next_cursor::next_cursor(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_next_cursor(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(RepeatedWorkspace_oneof_one_of_next_cursor::next_cursor(_)) = self.one_of_next_cursor {
+        } else {
+            self.one_of_next_cursor = ::std::option::Option::Some(RepeatedWorkspace_oneof_one_of_next_cursor::next_cursor(::std::string::String::new()));
+        }
+        match self.one_of_next_cursor {
+            ::std::option::Option::Some(RepeatedWorkspace_oneof_one_of_next_cursor::next_cursor(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_next_cursor(&mut self) -> ::std::string::String {
+        if self.has_next_cursor() {
+            match self.one_of_next_cursor.take() {
+                ::std::option::Option::Some(RepeatedWorkspace_oneof_one_of_next_cursor::next_cursor(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
 }
 
 impl ::protobuf::Message for RepeatedWorkspace {
@@ -858,6 +914,12 @@ impl ::protobuf::Message for RepeatedWorkspace {
                 1 => {
                     ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.items)?;
                 },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_next_cursor = ::std::option::Option::Some(RepeatedWorkspace_oneof_one_of_next_cursor::next_cursor(is.read_string()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -874,6 +936,13 @@ impl ::protobuf::Message for RepeatedWorkspace {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        if let ::std::option::Option::Some(ref v) = self.one_of_next_cursor {
+            match v {
+                &RepeatedWorkspace_oneof_one_of_next_cursor::next_cursor(ref v) => {
+                    my_size += ::protobuf::rt::string_size(2, &v);
+                },
+            };
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -885,6 +954,13 @@ impl ::protobuf::Message for RepeatedWorkspace {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         };
+        if let ::std::option::Option::Some(ref v) = self.one_of_next_cursor {
+            match v {
+                &RepeatedWorkspace_oneof_one_of_next_cursor::next_cursor(ref v) => {
+                    os.write_string(2, v)?;
+                },
+            };
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -928,6 +1004,11 @@ impl ::protobuf::Message for RepeatedWorkspace {
                 |m: &RepeatedWorkspace| { &m.items },
                 |m: &mut RepeatedWorkspace| { &mut m.items },
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "next_cursor",
+                RepeatedWorkspace::has_next_cursor,
+                RepeatedWorkspace::get_next_cursor,
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<RepeatedWorkspace>(
                 "RepeatedWorkspace",
                 fields,
@@ -945,6 +1026,7 @@ impl ::protobuf::Message for RepeatedWorkspace {
 impl ::protobuf::Clear for RepeatedWorkspace {
     fn clear(&mut self) {
         self.items.clear();
+        self.one_of_next_cursor = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -970,46 +1052,51 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x02id\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x12\n\x04desc\
     \x18\x03\x20\x01(\tR\x04desc\x12\x20\n\x04apps\x18\x04\x20\x01(\x0b2\x0c\
     .RepeatedAppR\x04apps\x12#\n\rmodified_time\x18\x05\x20\x01(\x03R\x0cmod\
-    ifiedTime\x12\x1f\n\x0bcreate_time\x18\x06\x20\x01(\x03R\ncreateTime\"5\
+    ifiedTime\x12\x1f\n\x0bcreate_time\x18\x06\x20\x01(\x03R\ncreateTime\"n\
     \n\x11RepeatedWorkspace\x12\x20\n\x05items\x18\x01\x20\x03(\x0b2\n.Works\
-    paceR\x05itemsJ\xe8\x05\n\x06\x12\x04\0\0\x15\x01\n\x08\n\x01\x0c\x12\
-    \x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x01\0\x1a\n\n\n\x02\x04\0\x12\x04\
-    \x03\0\x06\x01\n\n\n\x03\x04\0\x01\x12\x03\x03\x08\x1e\n\x0b\n\x04\x04\0\
-    \x02\0\x12\x03\x04\x04\x14\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x04\x04\n\
-    \n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x04\x0b\x0f\n\x0c\n\x05\x04\0\x02\0\
-    \x03\x12\x03\x04\x12\x13\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x05\x04\x14\n\
-    \x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x05\x04\n\n\x0c\n\x05\x04\0\x02\x01\
-    \x01\x12\x03\x05\x0b\x0f\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x05\x12\
-    \x13\n\n\n\x02\x04\x01\x12\x04\x07\0\n\x01\n\n\n\x03\x04\x01\x01\x12\x03\
-    \x07\x08\x1d\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x08\x04\x14\n\x0c\n\x05\
-    \x04\x01\x02\0\x05\x12\x03\x08\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\
-    \x03\x08\x0b\x0f\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x08\x12\x13\n\x0b\
-    \n\x04\x04\x01\x02\x01\x12\x03\t\x04\x14\n\x0c\n\x05\x04\x01\x02\x01\x05\
-    \x12\x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\t\x0b\x0f\n\x0c\
-    \n\x05\x04\x01\x02\x01\x03\x12\x03\t\x12\x13\n\n\n\x02\x04\x02\x12\x04\
-    \x0b\0\x12\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0b\x08\x11\n\x0b\n\x04\x04\
-    \x02\x02\0\x12\x03\x0c\x04\x12\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x0c\
-    \x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0c\x0b\r\n\x0c\n\x05\x04\
-    \x02\x02\0\x03\x12\x03\x0c\x10\x11\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\r\
-    \x04\x14\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\r\x04\n\n\x0c\n\x05\x04\
-    \x02\x02\x01\x01\x12\x03\r\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\
-    \x03\r\x12\x13\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x0e\x04\x14\n\x0c\n\
-    \x05\x04\x02\x02\x02\x05\x12\x03\x0e\x04\n\n\x0c\n\x05\x04\x02\x02\x02\
-    \x01\x12\x03\x0e\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\x0e\x12\
-    \x13\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\x0f\x04\x19\n\x0c\n\x05\x04\x02\
-    \x02\x03\x06\x12\x03\x0f\x04\x0f\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\
-    \x0f\x10\x14\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03\x0f\x17\x18\n\x0b\n\
-    \x04\x04\x02\x02\x04\x12\x03\x10\x04\x1c\n\x0c\n\x05\x04\x02\x02\x04\x05\
-    \x12\x03\x10\x04\t\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03\x10\n\x17\n\
-    \x0c\n\x05\x04\x02\x02\x04\x03\x12\x03\x10\x1a\x1b\n\x0b\n\x04\x04\x02\
-    \x02\x05\x12\x03\x11\x04\x1a\n\x0c\n\x05\x04\x02\x02\x05\x05\x12\x03\x11\
-    \x04\t\n\x0c\n\x05\x04\x02\x02\x05\x01\x12\x03\x11\n\x15\n\x0c\n\x05\x04\
-    \x02\x02\x05\x03\x12\x03\x11\x18\x19\n\n\n\x02\x04\x03\x12\x04\x13\0\x15\
-    \x01\n\n\n\x03\x04\x03\x01\x12\x03\x13\x08\x19\n\x0b\n\x04\x04\x03\x02\0\
-    \x12\x03\x14\x04!\n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03\x14\x04\x0c\n\
-    \x0c\n\x05\x04\x03\x02\0\x06\x12\x03\x14\r\x16\n\x0c\n\x05\x04\x03\x02\0\
-    \x01\x12\x03\x14\x17\x1c\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x14\x1f\
-    \x20b\x06proto3\
+    paceR\x05items\x12!\n\x0bnext_cursor\x18\x02\x20\x01(\tH\0R\nnextCursorB\
+    \x14\n\x12one_of_next_cursorJ\xba\x06\n\x06\x12\x04\0\0\x16\x01\n\x08\n\
+    \x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x01\0\x1a\n\n\n\x02\x04\
+    \0\x12\x04\x03\0\x06\x01\n\n\n\x03\x04\0\x01\x12\x03\x03\x08\x1e\n\x0b\n\
+    \x04\x04\0\x02\0\x12\x03\x04\x04\x14\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\
+    \x04\x04\n\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x04\x0b\x0f\n\x0c\n\x05\
+    \x04\0\x02\0\x03\x12\x03\x04\x12\x13\n\x0b\n\x04\x04\0\x02\x01\x12\x03\
+    \x05\x04\x14\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x05\x04\n\n\x0c\n\x05\
+    \x04\0\x02\x01\x01\x12\x03\x05\x0b\x0f\n\x0c\n\x05\x04\0\x02\x01\x03\x12\
+    \x03\x05\x12\x13\n\n\n\x02\x04\x01\x12\x04\x07\0\n\x01\n\n\n\x03\x04\x01\
+    \x01\x12\x03\x07\x08\x1d\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x08\x04\x14\n\
+    \x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x08\x04\n\n\x0c\n\x05\x04\x01\x02\0\
+    \x01\x12\x03\x08\x0b\x0f\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x08\x12\
+    \x13\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\t\x04\x14\n\x0c\n\x05\x04\x01\
+    \x02\x01\x05\x12\x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\t\
+    \x0b\x0f\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\t\x12\x13\n\n\n\x02\x04\
+    \x02\x12\x04\x0b\0\x12\x01\n\n\n\x03\x04\x02\x01\x12\x03\x0b\x08\x11\n\
+    \x0b\n\x04\x04\x02\x02\0\x12\x03\x0c\x04\x12\n\x0c\n\x05\x04\x02\x02\0\
+    \x05\x12\x03\x0c\x04\n\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x0c\x0b\r\n\
+    \x0c\n\x05\x04\x02\x02\0\x03\x12\x03\x0c\x10\x11\n\x0b\n\x04\x04\x02\x02\
+    \x01\x12\x03\r\x04\x14\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03\r\x04\n\n\
+    \x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\r\x0b\x0f\n\x0c\n\x05\x04\x02\x02\
+    \x01\x03\x12\x03\r\x12\x13\n\x0b\n\x04\x04\x02\x02\x02\x12\x03\x0e\x04\
+    \x14\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03\x0e\x04\n\n\x0c\n\x05\x04\
+    \x02\x02\x02\x01\x12\x03\x0e\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\
+    \x03\x0e\x12\x13\n\x0b\n\x04\x04\x02\x02\x03\x12\x03\x0f\x04\x19\n\x0c\n\
+    \x05\x04\x02\x02\x03\x06\x12\x03\x0f\x04\x0f\n\x0c\n\x05\x04\x02\x02\x03\
+    \x01\x12\x03\x0f\x10\x14\n\x0c\n\x05\x04\x02\x02\x03\x03\x12\x03\x0f\x17\
+    \x18\n\x0b\n\x04\x04\x02\x02\x04\x12\x03\x10\x04\x1c\n\x0c\n\x05\x04\x02\
+    \x02\x04\x05\x12\x03\x10\x04\t\n\x0c\n\x05\x04\x02\x02\x04\x01\x12\x03\
+    \x10\n\x17\n\x0c\n\x05\x04\x02\x02\x04\x03\x12\x03\x10\x1a\x1b\n\x0b\n\
+    \x04\x04\x02\x02\x05\x12\x03\x11\x04\x1a\n\x0c\n\x05\x04\x02\x02\x05\x05\
+    \x12\x03\x11\x04\t\n\x0c\n\x05\x04\x02\x02\x05\x01\x12\x03\x11\n\x15\n\
+    \x0c\n\x05\x04\x02\x02\x05\x03\x12\x03\x11\x18\x19\n\n\n\x02\x04\x03\x12\
+    \x04\x13\0\x16\x01\n\n\n\x03\x04\x03\x01\x12\x03\x13\x08\x19\n\x0b\n\x04\
+    \x04\x03\x02\0\x12\x03\x14\x04!\n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03\
+    \x14\x04\x0c\n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03\x14\r\x16\n\x0c\n\x05\
+    \x04\x03\x02\0\x01\x12\x03\x14\x17\x1c\n\x0c\n\x05\x04\x03\x02\0\x03\x12\
+    \x03\x14\x1f\x20\n\x0b\n\x04\x04\x03\x08\0\x12\x03\x15\x048\n\x0c\n\x05\
+    \x04\x03\x08\0\x01\x12\x03\x15\n\x1c\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\
+    \x15\x1f6\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03\x15\x1f%\n\x0c\n\x05\
+    \x04\x03\x02\x01\x01\x12\x03\x15&1\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\
+    \x03\x1545b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
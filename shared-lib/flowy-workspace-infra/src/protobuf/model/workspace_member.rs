@@ -0,0 +1,973 @@
+// This file is generated by rust-protobuf 2.22.1. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![cfg_attr(rustfmt, rustfmt::skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `workspace_member.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_22_1;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AddWorkspaceMemberParams {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub role: WorkspaceRole,
+    // message oneof groups
+    pub one_of_user_id: ::std::option::Option<AddWorkspaceMemberParams_oneof_one_of_user_id>,
+    pub one_of_email: ::std::option::Option<AddWorkspaceMemberParams_oneof_one_of_email>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AddWorkspaceMemberParams {
+    fn default() -> &'a AddWorkspaceMemberParams {
+        <AddWorkspaceMemberParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum AddWorkspaceMemberParams_oneof_one_of_user_id {
+    user_id(::std::string::String),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum AddWorkspaceMemberParams_oneof_one_of_email {
+    email(::std::string::String),
+}
+
+impl AddWorkspaceMemberParams {
+    pub fn new() -> AddWorkspaceMemberParams {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string user_id = 2;
+
+
+    pub fn get_user_id(&self) -> &str {
+        match self.one_of_user_id {
+            ::std::option::Option::Some(AddWorkspaceMemberParams_oneof_one_of_user_id::user_id(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_user_id(&mut self) {
+        self.one_of_user_id = ::std::option::Option::None;
+    }
+
+    pub fn has_user_id(&self) -> bool {
+        match self.one_of_user_id {
+            ::std::option::Option::Some(AddWorkspaceMemberParams_oneof_one_of_user_id::user_id(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: ::std::string::String) {
+        self.one_of_user_id = ::std::option::Option::Some(AddWorkspaceMemberParams_oneof_one_of_user_id::user_id(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_user_id(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(AddWorkspaceMemberParams_oneof_one_of_user_id::user_id(_)) = self.one_of_user_id {
+        } else {
+            self.one_of_user_id = ::std::option::Option::Some(AddWorkspaceMemberParams_oneof_one_of_user_id::user_id(::std::string::String::new()));
+        }
+        match self.one_of_user_id {
+            ::std::option::Option::Some(AddWorkspaceMemberParams_oneof_one_of_user_id::user_id(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_user_id(&mut self) -> ::std::string::String {
+        if self.has_user_id() {
+            match self.one_of_user_id.take() {
+                ::std::option::Option::Some(AddWorkspaceMemberParams_oneof_one_of_user_id::user_id(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // string email = 3;
+
+
+    pub fn get_email(&self) -> &str {
+        match self.one_of_email {
+            ::std::option::Option::Some(AddWorkspaceMemberParams_oneof_one_of_email::email(ref v)) => v,
+            _ => "",
+        }
+    }
+    pub fn clear_email(&mut self) {
+        self.one_of_email = ::std::option::Option::None;
+    }
+
+    pub fn has_email(&self) -> bool {
+        match self.one_of_email {
+            ::std::option::Option::Some(AddWorkspaceMemberParams_oneof_one_of_email::email(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_email(&mut self, v: ::std::string::String) {
+        self.one_of_email = ::std::option::Option::Some(AddWorkspaceMemberParams_oneof_one_of_email::email(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_email(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(AddWorkspaceMemberParams_oneof_one_of_email::email(_)) = self.one_of_email {
+        } else {
+            self.one_of_email = ::std::option::Option::Some(AddWorkspaceMemberParams_oneof_one_of_email::email(::std::string::String::new()));
+        }
+        match self.one_of_email {
+            ::std::option::Option::Some(AddWorkspaceMemberParams_oneof_one_of_email::email(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_email(&mut self) -> ::std::string::String {
+        if self.has_email() {
+            match self.one_of_email.take() {
+                ::std::option::Option::Some(AddWorkspaceMemberParams_oneof_one_of_email::email(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    // .WorkspaceRole role = 4;
+
+
+    pub fn get_role(&self) -> WorkspaceRole {
+        self.role
+    }
+    pub fn clear_role(&mut self) {
+        self.role = WorkspaceRole::Viewer;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: WorkspaceRole) {
+        self.role = v;
+    }
+}
+
+impl ::protobuf::Message for AddWorkspaceMemberParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_user_id = ::std::option::Option::Some(AddWorkspaceMemberParams_oneof_one_of_user_id::user_id(is.read_string()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.one_of_email = ::std::option::Option::Some(AddWorkspaceMemberParams_oneof_one_of_email::email(is.read_string()?));
+                },
+                4 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.role, 4, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if self.role != WorkspaceRole::Viewer {
+            my_size += ::protobuf::rt::enum_size(4, self.role);
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_user_id {
+            match v {
+                &AddWorkspaceMemberParams_oneof_one_of_user_id::user_id(ref v) => {
+                    my_size += ::protobuf::rt::string_size(2, &v);
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_email {
+            match v {
+                &AddWorkspaceMemberParams_oneof_one_of_email::email(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if self.role != WorkspaceRole::Viewer {
+            os.write_enum(4, ::protobuf::ProtobufEnum::value(&self.role))?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_user_id {
+            match v {
+                &AddWorkspaceMemberParams_oneof_one_of_user_id::user_id(ref v) => {
+                    os.write_string(2, v)?;
+                },
+            };
+        }
+        if let ::std::option::Option::Some(ref v) = self.one_of_email {
+            match v {
+                &AddWorkspaceMemberParams_oneof_one_of_email::email(ref v) => {
+                    os.write_string(3, v)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AddWorkspaceMemberParams {
+        AddWorkspaceMemberParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &AddWorkspaceMemberParams| { &m.workspace_id },
+                |m: &mut AddWorkspaceMemberParams| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "user_id",
+                AddWorkspaceMemberParams::has_user_id,
+                AddWorkspaceMemberParams::get_user_id,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "email",
+                AddWorkspaceMemberParams::has_email,
+                AddWorkspaceMemberParams::get_email,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<WorkspaceRole>>(
+                "role",
+                |m: &AddWorkspaceMemberParams| { &m.role },
+                |m: &mut AddWorkspaceMemberParams| { &mut m.role },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AddWorkspaceMemberParams>(
+                "AddWorkspaceMemberParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AddWorkspaceMemberParams {
+        static instance: ::protobuf::rt::LazyV2<AddWorkspaceMemberParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AddWorkspaceMemberParams::new)
+    }
+}
+
+impl ::protobuf::Clear for AddWorkspaceMemberParams {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.one_of_user_id = ::std::option::Option::None;
+        self.one_of_email = ::std::option::Option::None;
+        self.role = WorkspaceRole::Viewer;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AddWorkspaceMemberParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AddWorkspaceMemberParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RemoveWorkspaceMemberParams {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub user_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RemoveWorkspaceMemberParams {
+    fn default() -> &'a RemoveWorkspaceMemberParams {
+        <RemoveWorkspaceMemberParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RemoveWorkspaceMemberParams {
+    pub fn new() -> RemoveWorkspaceMemberParams {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string user_id = 2;
+
+
+    pub fn get_user_id(&self) -> &str {
+        &self.user_id
+    }
+    pub fn clear_user_id(&mut self) {
+        self.user_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: ::std::string::String) {
+        self.user_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user_id(&mut self) -> &mut ::std::string::String {
+        &mut self.user_id
+    }
+
+    // Take field
+    pub fn take_user_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for RemoveWorkspaceMemberParams {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if !self.user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.user_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if !self.user_id.is_empty() {
+            os.write_string(2, &self.user_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RemoveWorkspaceMemberParams {
+        RemoveWorkspaceMemberParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &RemoveWorkspaceMemberParams| { &m.workspace_id },
+                |m: &mut RemoveWorkspaceMemberParams| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "user_id",
+                |m: &RemoveWorkspaceMemberParams| { &m.user_id },
+                |m: &mut RemoveWorkspaceMemberParams| { &mut m.user_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RemoveWorkspaceMemberParams>(
+                "RemoveWorkspaceMemberParams",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RemoveWorkspaceMemberParams {
+        static instance: ::protobuf::rt::LazyV2<RemoveWorkspaceMemberParams> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RemoveWorkspaceMemberParams::new)
+    }
+}
+
+impl ::protobuf::Clear for RemoveWorkspaceMemberParams {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.user_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RemoveWorkspaceMemberParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RemoveWorkspaceMemberParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkspaceMember {
+    // message fields
+    pub workspace_id: ::std::string::String,
+    pub user_id: ::std::string::String,
+    pub email: ::std::string::String,
+    pub role: WorkspaceRole,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a WorkspaceMember {
+    fn default() -> &'a WorkspaceMember {
+        <WorkspaceMember as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl WorkspaceMember {
+    pub fn new() -> WorkspaceMember {
+        ::std::default::Default::default()
+    }
+
+    // string workspace_id = 1;
+
+
+    pub fn get_workspace_id(&self) -> &str {
+        &self.workspace_id
+    }
+    pub fn clear_workspace_id(&mut self) {
+        self.workspace_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workspace_id(&mut self, v: ::std::string::String) {
+        self.workspace_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_workspace_id(&mut self) -> &mut ::std::string::String {
+        &mut self.workspace_id
+    }
+
+    // Take field
+    pub fn take_workspace_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.workspace_id, ::std::string::String::new())
+    }
+
+    // string user_id = 2;
+
+
+    pub fn get_user_id(&self) -> &str {
+        &self.user_id
+    }
+    pub fn clear_user_id(&mut self) {
+        self.user_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_user_id(&mut self, v: ::std::string::String) {
+        self.user_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_user_id(&mut self) -> &mut ::std::string::String {
+        &mut self.user_id
+    }
+
+    // Take field
+    pub fn take_user_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.user_id, ::std::string::String::new())
+    }
+
+    // string email = 3;
+
+
+    pub fn get_email(&self) -> &str {
+        &self.email
+    }
+    pub fn clear_email(&mut self) {
+        self.email.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_email(&mut self, v: ::std::string::String) {
+        self.email = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_email(&mut self) -> &mut ::std::string::String {
+        &mut self.email
+    }
+
+    // Take field
+    pub fn take_email(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.email, ::std::string::String::new())
+    }
+
+    // .WorkspaceRole role = 4;
+
+
+    pub fn get_role(&self) -> WorkspaceRole {
+        self.role
+    }
+    pub fn clear_role(&mut self) {
+        self.role = WorkspaceRole::Viewer;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: WorkspaceRole) {
+        self.role = v;
+    }
+}
+
+impl ::protobuf::Message for WorkspaceMember {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.workspace_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.user_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.email)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.role, 4, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.workspace_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.workspace_id);
+        }
+        if !self.user_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.user_id);
+        }
+        if !self.email.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.email);
+        }
+        if self.role != WorkspaceRole::Viewer {
+            my_size += ::protobuf::rt::enum_size(4, self.role);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.workspace_id.is_empty() {
+            os.write_string(1, &self.workspace_id)?;
+        }
+        if !self.user_id.is_empty() {
+            os.write_string(2, &self.user_id)?;
+        }
+        if !self.email.is_empty() {
+            os.write_string(3, &self.email)?;
+        }
+        if self.role != WorkspaceRole::Viewer {
+            os.write_enum(4, ::protobuf::ProtobufEnum::value(&self.role))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> WorkspaceMember {
+        WorkspaceMember::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "workspace_id",
+                |m: &WorkspaceMember| { &m.workspace_id },
+                |m: &mut WorkspaceMember| { &mut m.workspace_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "user_id",
+                |m: &WorkspaceMember| { &m.user_id },
+                |m: &mut WorkspaceMember| { &mut m.user_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "email",
+                |m: &WorkspaceMember| { &m.email },
+                |m: &mut WorkspaceMember| { &mut m.email },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<WorkspaceRole>>(
+                "role",
+                |m: &WorkspaceMember| { &m.role },
+                |m: &mut WorkspaceMember| { &mut m.role },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<WorkspaceMember>(
+                "WorkspaceMember",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static WorkspaceMember {
+        static instance: ::protobuf::rt::LazyV2<WorkspaceMember> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(WorkspaceMember::new)
+    }
+}
+
+impl ::protobuf::Clear for WorkspaceMember {
+    fn clear(&mut self) {
+        self.workspace_id.clear();
+        self.user_id.clear();
+        self.email.clear();
+        self.role = WorkspaceRole::Viewer;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkspaceMember {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkspaceMember {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum WorkspaceRole {
+    Viewer = 0,
+    Editor = 1,
+    Owner = 2,
+}
+
+impl ::protobuf::ProtobufEnum for WorkspaceRole {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<WorkspaceRole> {
+        match value {
+            0 => ::std::option::Option::Some(WorkspaceRole::Viewer),
+            1 => ::std::option::Option::Some(WorkspaceRole::Editor),
+            2 => ::std::option::Option::Some(WorkspaceRole::Owner),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [WorkspaceRole] = &[
+            WorkspaceRole::Viewer,
+            WorkspaceRole::Editor,
+            WorkspaceRole::Owner,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<WorkspaceRole>("WorkspaceRole", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for WorkspaceRole {
+}
+
+impl ::std::default::Default for WorkspaceRole {
+    fn default() -> Self {
+        WorkspaceRole::Viewer
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkspaceRole {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x16workspace_member.proto\"\xb6\x01\n\x18AddWorkspaceMemberParams\x12\
+    !\n\x0cworkspace_id\x18\x01\x20\x01(\tR\x0bworkspaceId\x12\x19\n\x07user\
+    _id\x18\x02\x20\x01(\tH\0R\x06userId\x12\x16\n\x05email\x18\x03\x20\x01(\
+    \tH\x01R\x05email\x12\"\n\x04role\x18\x04\x20\x01(\x0e2\x0e.WorkspaceRol\
+    eR\x04roleB\x10\n\x0eone_of_user_idB\x0e\n\x0cone_of_email\"Y\n\x1bRemov\
+    eWorkspaceMemberParams\x12!\n\x0cworkspace_id\x18\x01\x20\x01(\tR\x0bwor\
+    kspaceId\x12\x17\n\x07user_id\x18\x02\x20\x01(\tR\x06userId\"\x87\x01\n\
+    \x0fWorkspaceMember\x12!\n\x0cworkspace_id\x18\x01\x20\x01(\tR\x0bworksp\
+    aceId\x12\x17\n\x07user_id\x18\x02\x20\x01(\tR\x06userId\x12\x14\n\x05em\
+    ail\x18\x03\x20\x01(\tR\x05email\x12\"\n\x04role\x18\x04\x20\x01(\x0e2\
+    \x0e.WorkspaceRoleR\x04role*2\n\rWorkspaceRole\x12\n\n\x06Viewer\x10\0\
+    \x12\n\n\x06Editor\x10\x01\x12\t\n\x05Owner\x10\x02J\xc9\x06\n\x06\x12\
+    \x04\0\0\x16\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\n\n\x02\x04\0\x12\x04\
+    \x02\0\x07\x01\n\n\n\x03\x04\0\x01\x12\x03\x02\x08\x20\n\x0b\n\x04\x04\0\
+    \x02\0\x12\x03\x03\x04\x1c\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x03\x04\n\
+    \n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x03\x0b\x17\n\x0c\n\x05\x04\0\x02\0\
+    \x03\x12\x03\x03\x1a\x1b\n\x0b\n\x04\x04\0\x08\0\x12\x03\x04\x040\n\x0c\
+    \n\x05\x04\0\x08\0\x01\x12\x03\x04\n\x18\n\x0b\n\x04\x04\0\x02\x01\x12\
+    \x03\x04\x1b.\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x03\x04\x1b!\n\x0c\n\x05\
+    \x04\0\x02\x01\x01\x12\x03\x04\")\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\
+    \x04,-\n\x0b\n\x04\x04\0\x08\x01\x12\x03\x05\x04,\n\x0c\n\x05\x04\0\x08\
+    \x01\x01\x12\x03\x05\n\x16\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x05\x19*\n\
+    \x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x05\x19\x1f\n\x0c\n\x05\x04\0\x02\
+    \x02\x01\x12\x03\x05\x20%\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x05()\n\
+    \x0b\n\x04\x04\0\x02\x03\x12\x03\x06\x04\x1b\n\x0c\n\x05\x04\0\x02\x03\
+    \x06\x12\x03\x06\x04\x11\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x06\x12\
+    \x16\n\x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x06\x19\x1a\n\n\n\x02\x04\x01\
+    \x12\x04\x08\0\x0b\x01\n\n\n\x03\x04\x01\x01\x12\x03\x08\x08#\n\x0b\n\
+    \x04\x04\x01\x02\0\x12\x03\t\x04\x1c\n\x0c\n\x05\x04\x01\x02\0\x05\x12\
+    \x03\t\x04\n\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\t\x0b\x17\n\x0c\n\x05\
+    \x04\x01\x02\0\x03\x12\x03\t\x1a\x1b\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\
+    \n\x04\x17\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\n\x04\n\n\x0c\n\x05\
+    \x04\x01\x02\x01\x01\x12\x03\n\x0b\x12\n\x0c\n\x05\x04\x01\x02\x01\x03\
+    \x12\x03\n\x15\x16\n\n\n\x02\x04\x02\x12\x04\x0c\0\x11\x01\n\n\n\x03\x04\
+    \x02\x01\x12\x03\x0c\x08\x17\n\x0b\n\x04\x04\x02\x02\0\x12\x03\r\x04\x1c\
+    \n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\r\x04\n\n\x0c\n\x05\x04\x02\x02\0\
+    \x01\x12\x03\r\x0b\x17\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03\r\x1a\x1b\n\
+    \x0b\n\x04\x04\x02\x02\x01\x12\x03\x0e\x04\x17\n\x0c\n\x05\x04\x02\x02\
+    \x01\x05\x12\x03\x0e\x04\n\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\x0e\
+    \x0b\x12\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\x0e\x15\x16\n\x0b\n\x04\
+    \x04\x02\x02\x02\x12\x03\x0f\x04\x15\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\
+    \x03\x0f\x04\n\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03\x0f\x0b\x10\n\x0c\
+    \n\x05\x04\x02\x02\x02\x03\x12\x03\x0f\x13\x14\n\x0b\n\x04\x04\x02\x02\
+    \x03\x12\x03\x10\x04\x1b\n\x0c\n\x05\x04\x02\x02\x03\x06\x12\x03\x10\x04\
+    \x11\n\x0c\n\x05\x04\x02\x02\x03\x01\x12\x03\x10\x12\x16\n\x0c\n\x05\x04\
+    \x02\x02\x03\x03\x12\x03\x10\x19\x1a\n\n\n\x02\x05\0\x12\x04\x12\0\x16\
+    \x01\n\n\n\x03\x05\0\x01\x12\x03\x12\x05\x12\n\x0b\n\x04\x05\0\x02\0\x12\
+    \x03\x13\x04\x0f\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x13\x04\n\n\x0c\n\
+    \x05\x05\0\x02\0\x02\x12\x03\x13\r\x0e\n\x0b\n\x04\x05\0\x02\x01\x12\x03\
+    \x14\x04\x0f\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x14\x04\n\n\x0c\n\x05\
+    \x05\0\x02\x01\x02\x12\x03\x14\r\x0e\n\x0b\n\x04\x05\0\x02\x02\x12\x03\
+    \x15\x04\x0e\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x15\x04\t\n\x0c\n\x05\
+    \x05\0\x02\x02\x02\x12\x03\x15\x0c\rb\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -39,6 +39,8 @@ fn create_default_app(workspace_id: String, time: chrono::DateTime<Utc>) -> App
         name,
         desc,
         belongings: views,
+        apps: RepeatedApp::default(),
+        parent_id: None,
         version: 0,
         modified_time: time.timestamp(),
         create_time: time.timestamp(),
@@ -61,5 +63,6 @@ fn create_default_view(app_id: String, time: chrono::DateTime<Utc>) -> View {
         belongings: Default::default(),
         modified_time: time.timestamp(),
         create_time: time.timestamp(),
+        parent_view_id: None,
     }
 }
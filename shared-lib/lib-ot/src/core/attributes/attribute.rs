@@ -1,6 +1,13 @@
 #![allow(non_snake_case)]
 
-use crate::{block_attribute, core::Attributes, ignore_attribute, inline_attribute, list_attribute};
+use crate::{
+    block_attribute,
+    core::{Attributes, Footnote},
+    embeds_attribute,
+    ignore_attribute,
+    inline_attribute,
+    list_attribute,
+};
 use lazy_static::lazy_static;
 
 use std::{collections::HashSet, fmt, fmt::Formatter, iter::FromIterator};
@@ -34,6 +41,9 @@ impl Attribute {
     block_attribute!(CodeBlock, bool);
     block_attribute!(BlockQuote, bool);
 
+    // embeds
+    embeds_attribute!(Footnote, &Footnote);
+
     // ignore
     ignore_attribute!(Width, usize);
     ignore_attribute!(Height, usize);
@@ -70,7 +80,7 @@ impl std::convert::From<Attribute> for Attributes {
     }
 }
 
-#[derive(Clone, Debug, Display, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(Clone, Debug, Display, Hash, Eq, PartialEq, Ord, PartialOrd, serde::Serialize, serde::Deserialize)]
 // serde.rs/variant-attrs.html
 // #[serde(rename_all = "snake_case")]
 pub enum AttributeKey {
@@ -110,6 +120,8 @@ pub enum AttributeKey {
     Height,
     #[serde(rename = "header")]
     Header,
+    #[serde(rename = "footnote")]
+    Footnote,
 }
 
 // pub trait AttributeValueData<'a>: Serialize + Deserialize<'a> {}
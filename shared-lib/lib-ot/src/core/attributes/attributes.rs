@@ -1,20 +1,18 @@
 use crate::{
-    core::{Attribute, AttributeKey, AttributeValue, Operation, OperationTransformable},
+    core::{Attribute, AttributeKey, AttributeValue, Operation, OperationTransformable, TransformPolicy},
     errors::OTError,
 };
-use std::{collections::HashMap, fmt};
+use std::{collections::BTreeMap, fmt};
 
+/// The attributes are kept sorted by their keys, so the attributes added in any
+/// order compare, iterate and serialize the same.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Attributes {
-    pub(crate) inner: HashMap<AttributeKey, AttributeValue>,
+    pub(crate) inner: BTreeMap<AttributeKey, AttributeValue>,
 }
 
 impl std::default::Default for Attributes {
-    fn default() -> Self {
-        Self {
-            inner: HashMap::with_capacity(0),
-        }
-    }
+    fn default() -> Self { Self { inner: BTreeMap::new() } }
 }
 
 impl fmt::Display for Attributes {
@@ -24,7 +22,7 @@ impl fmt::Display for Attributes {
 pub fn plain_attributes() -> Attributes { Attributes::default() }
 
 impl Attributes {
-    pub fn new() -> Self { Attributes { inner: HashMap::new() } }
+    pub fn new() -> Self { Attributes { inner: BTreeMap::new() } }
 
     pub fn is_empty(&self) -> bool { self.inner.is_empty() }
 
@@ -83,6 +81,46 @@ impl Attributes {
         });
         self.inner = new_attributes;
     }
+
+    /// Returns the attributes turning self into the other when composed with
+    /// it: the keys whose value changed or that were added, and the keys
+    /// missing from the other marked as removed, as
+    /// [Attributes::mark_all_as_removed_except] does. The unchanged keys are
+    /// left out.
+    pub fn diff(&self, other: &Attributes) -> Attributes {
+        let mut attributes = other.iter().fold(Attributes::new(), |mut attributes, (k, v)| {
+            if self.get(k) != Some(v) {
+                attributes.insert(k.clone(), v.clone());
+            }
+            attributes
+        });
+
+        self.iter()
+            .filter(|(k, v)| v.0.is_some() && !other.contains_key(k))
+            .for_each(|(k, _)| attributes.delete(k));
+        attributes
+    }
+
+    /// Transforms the attributes like [OperationTransformable::transform],
+    /// except that the attributes formatted by both sides are kept by the
+    /// winner chosen by the `policy`.
+    pub fn transform_with(&self, other: &Self, policy: &TransformPolicy) -> (Self, Self) {
+        let a = self.iter().fold(Attributes::new(), |mut new_attributes, (k, v)| {
+            if !other.contains_key(k) || policy.self_wins(k) {
+                new_attributes.insert(k.clone(), v.clone());
+            }
+            new_attributes
+        });
+
+        let b = other.iter().fold(Attributes::new(), |mut new_attributes, (k, v)| {
+            if !self.contains_key(k) || !policy.self_wins(k) {
+                new_attributes.insert(k.clone(), v.clone());
+            }
+            new_attributes
+        });
+
+        (a, b)
+    }
 }
 
 impl OperationTransformable for Attributes {
@@ -136,7 +174,7 @@ impl OperationTransformable for Attributes {
 }
 
 impl std::ops::Deref for Attributes {
-    type Target = HashMap<AttributeKey, AttributeValue>;
+    type Target = BTreeMap<AttributeKey, AttributeValue>;
 
     fn deref(&self) -> &Self::Target { &self.inner }
 }
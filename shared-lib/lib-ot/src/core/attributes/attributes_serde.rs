@@ -72,7 +72,8 @@ where
             | AttributeKey::Color
             | AttributeKey::Background
             | AttributeKey::Align
-            | AttributeKey::List => {
+            | AttributeKey::List
+            | AttributeKey::Footnote => {
                 map_serializer.serialize_entry(&key, v)?;
             },
         }
@@ -0,0 +1,42 @@
+use crate::core::{AttributeKey, AttributeValue, Attributes};
+
+/// The footnote referenced by an embed. The embed is inserted as a single
+/// [EMBED](crate::core::EMBED) carrying the footnote attribute, so it counts as
+/// length 1 in OT, the same as a character.
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Footnote {
+    pub id: String,
+    pub text: String,
+}
+
+impl Footnote {
+    pub fn new(id: &str, text: &str) -> Self {
+        Self {
+            id: id.to_owned(),
+            text: text.to_owned(),
+        }
+    }
+
+    pub fn from_attributes(attributes: &Attributes) -> Option<Footnote> {
+        let value = attributes.get(&AttributeKey::Footnote)?.0.as_ref()?;
+        match serde_json::from_str(value) {
+            Ok(footnote) => Some(footnote),
+            Err(e) => {
+                log::error!("Deserialize footnote {} failed: {:?}", value, e);
+                None
+            },
+        }
+    }
+}
+
+impl std::convert::From<&Footnote> for AttributeValue {
+    fn from(footnote: &Footnote) -> Self {
+        match serde_json::to_string(footnote) {
+            Ok(json) => AttributeValue(Some(json)),
+            Err(e) => {
+                log::error!("Footnote serialize to str failed: {}", e);
+                AttributeValue(None)
+            },
+        }
+    }
+}
@@ -30,6 +30,22 @@ macro_rules! block_attribute {
     };
 }
 
+#[macro_export]
+macro_rules! embeds_attribute {
+    (
+        $key: ident,
+        $value: ty
+    ) => {
+        pub fn $key(value: $value) -> Self {
+            Self {
+                key: AttributeKey::$key,
+                value: value.into(),
+                scope: AttributeScope::Embeds,
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! list_attribute {
     (
@@ -3,6 +3,8 @@ mod attribute;
 mod attributes;
 mod attributes_serde;
 mod builder;
+mod footnote;
+mod policy;
 
 #[macro_use]
 mod macros;
@@ -10,3 +12,5 @@ mod macros;
 pub use attribute::*;
 pub use attributes::*;
 pub use builder::*;
+pub use footnote::*;
+pub use policy::*;
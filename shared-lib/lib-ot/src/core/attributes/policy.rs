@@ -0,0 +1,79 @@
+use crate::core::AttributeKey;
+use std::collections::HashMap;
+
+/// Decides which side keeps its value when two concurrent deltas format the
+/// same text with the same attribute key.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Precedence {
+    /// The delta being transformed wins, the same as it wins the inserts at the
+    /// same position.
+    SelfWins,
+    /// The other delta wins.
+    OtherWins,
+    /// The delta with the greater timestamp wins. The delta being transformed
+    /// wins the tie.
+    LastWriterWins,
+}
+
+/// The per-key [Precedence] consulted when transforming the attributes.
+/// Keys without a configured precedence fall back to [Precedence::SelfWins].
+#[derive(Debug, Clone)]
+pub struct TransformPolicy {
+    precedences: HashMap<AttributeKey, Precedence>,
+    self_timestamp: i64,
+    other_timestamp: i64,
+    site_ids: Option<(String, String)>,
+}
+
+impl std::default::Default for TransformPolicy {
+    fn default() -> Self {
+        Self {
+            precedences: HashMap::with_capacity(0),
+            self_timestamp: 0,
+            other_timestamp: 0,
+            site_ids: None,
+        }
+    }
+}
+
+impl TransformPolicy {
+    pub fn new() -> Self { TransformPolicy::default() }
+
+    pub fn precedence(mut self, key: AttributeKey, precedence: Precedence) -> Self {
+        self.precedences.insert(key, precedence);
+        self
+    }
+
+    /// The timestamps of the delta being transformed and the other delta, used
+    /// by [Precedence::LastWriterWins].
+    pub fn timestamps(mut self, self_timestamp: i64, other_timestamp: i64) -> Self {
+        self.self_timestamp = self_timestamp;
+        self.other_timestamp = other_timestamp;
+        self
+    }
+
+    /// The stable ids of the sites, e.g. the clients, that made the delta being
+    /// transformed and the other delta. The inserts at the same position are
+    /// ordered by the site ids, the lower one first, so both sites agree on the
+    /// order whichever side runs the transform. Without the site ids the delta
+    /// being transformed inserts first.
+    pub fn site_ids(mut self, self_site_id: &str, other_site_id: &str) -> Self {
+        self.site_ids = Some((self_site_id.to_owned(), other_site_id.to_owned()));
+        self
+    }
+
+    pub fn self_inserts_first(&self) -> bool {
+        match &self.site_ids {
+            None => true,
+            Some((self_site_id, other_site_id)) => self_site_id <= other_site_id,
+        }
+    }
+
+    pub fn self_wins(&self, key: &AttributeKey) -> bool {
+        match self.precedences.get(key).unwrap_or(&Precedence::SelfWins) {
+            Precedence::SelfWins => true,
+            Precedence::OtherWins => false,
+            Precedence::LastWriterWins => self.self_timestamp >= self.other_timestamp,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::core::{plain_attributes, Attributes, Delta, Operation};
+use crate::core::{plain_attributes, Attribute, Attributes, Delta, Footnote, Operation, EMBED};
 
 pub struct DeltaBuilder {
     delta: Delta,
@@ -36,6 +36,11 @@ impl DeltaBuilder {
         self
     }
 
+    pub fn insert_footnote(mut self, footnote: &Footnote) -> Self {
+        self.delta.insert(EMBED, Attribute::Footnote(footnote).into());
+        self
+    }
+
     pub fn trim(mut self) -> Self {
         trim(&mut self.delta);
         self
@@ -50,7 +55,7 @@ pub fn trim(delta: &mut Delta) {
         Some(op) => match op {
             Operation::Delete(_) => false,
             Operation::Retain(retain) => retain.is_plain(),
-            Operation::Insert(_) => false,
+            Operation::Insert(_) | Operation::Embed(_) => false,
         },
     };
     if remove_last {
@@ -99,7 +99,7 @@ impl<'a> OpCursor<'a> {
             let start = self.consume_count;
             let end = start + op.len();
             let intersect = Interval::new(start, end).intersect(self.consume_iv);
-            if intersect.is_empty() {
+            if intersect.is_none() {
                 self.consume_count += op.len();
             } else {
                 self.next_op = Some(op.clone());
@@ -116,8 +116,10 @@ impl<'a> OpCursor<'a> {
             Some(index) => self.consume_count + min(index, op.len()),
         };
 
-        let intersect = Interval::new(start, end).intersect(self.consume_iv);
-        let interval = intersect.translate_neg(start);
+        let interval = match Interval::new(start, end).intersect(self.consume_iv) {
+            None => Interval::default(),
+            Some(intersect) => intersect.translate_neg(start),
+        };
         Some(interval)
     }
 
@@ -172,10 +174,11 @@ impl Metric for OpMetric {
 
 pub struct CharMetric {}
 
+// Advances by `index` code units from the current position, splitting the op
+// it stops in the middle of.
 impl Metric for CharMetric {
     fn seek(cursor: &mut OpCursor, index: usize) -> SeekResult {
         if index > 0 {
-            let _ = check_bound(cursor.consume_count, index)?;
             let _ = cursor.next_with_len(Some(index));
         }
 
@@ -1,13 +1,22 @@
 use crate::{
-    core::{attributes::*, operation::*, DeltaIter, FlowyStr, Interval, OperationTransformable, MAX_IV_LEN},
+    core::{
+        attributes::*,
+        operation::*,
+        DeltaIter,
+        FlowyStr,
+        Interval,
+        OperationTransformable,
+        EMBED,
+        MAX_IV_LEN,
+        NEW_LINE,
+    },
     errors::{ErrorBuilder, OTError, OTErrorCode},
 };
 use bytes::Bytes;
 use std::{
-    cmp::{min, Ordering},
+    cmp::{max, min, Ordering},
     fmt,
     iter::FromIterator,
-    str,
     str::FromStr,
 };
 
@@ -87,8 +96,8 @@ impl Delta {
     pub fn to_json(&self) -> String { serde_json::to_string(self).unwrap_or_else(|_| "".to_owned()) }
 
     pub fn from_bytes<T: AsRef<[u8]>>(bytes: T) -> Result<Self, OTError> {
-        let json = str::from_utf8(bytes.as_ref())?;
-        Self::from_json(json)
+        let json = FlowyStr::from_utf8_lossy(bytes.as_ref());
+        Self::from_json(&json)
     }
 
     pub fn to_bytes(&self) -> Bytes {
@@ -107,21 +116,28 @@ impl Delta {
 
     pub fn add(&mut self, op: Operation) {
         match op {
-            Operation::Delete(i) => self.delete(i),
+            Operation::Delete(d) => self.delete_with_attributes(d.n, d.attributes),
             Operation::Insert(i) => self.insert(&i.s, i.attributes),
             Operation::Retain(r) => self.retain(r.n, r.attributes),
+            Operation::Embed(e) => self.embed(e.data, e.attributes),
         }
     }
 
-    pub fn delete(&mut self, n: usize) {
+    pub fn delete(&mut self, n: usize) { self.delete_with_attributes(n, Attributes::default()) }
+
+    // The deletes merge with the previous one if they describe the deleted
+    // text with the same attributes.
+    fn delete_with_attributes(&mut self, n: usize, attributes: Attributes) {
         if n == 0 {
             return;
         }
         self.base_len += n as usize;
-        if let Some(Operation::Delete(n_last)) = self.ops.last_mut() {
-            *n_last += n;
-        } else {
-            self.ops.push(OpBuilder::delete(n).build());
+        let new_last = match self.ops.last_mut() {
+            Some(Operation::Delete(delete)) => delete.merge_or_new(n, attributes),
+            _ => Some(OpBuilder::delete(n).attributes(attributes).build()),
+        };
+        if let Some(new_last) = new_last {
+            self.ops.push(new_last);
         }
     }
 
@@ -155,6 +171,20 @@ impl Delta {
         }
     }
 
+    /// Inserts an embed. Like the text, it's inserted before the trailing
+    /// delete, but it never merges with the previous insert.
+    pub fn embed(&mut self, data: EmbedData, attributes: Attributes) {
+        let embed = Operation::Embed(Embed { data, attributes });
+        self.target_len += embed.len();
+        match self.ops.last_mut() {
+            Some(op_last @ Operation::Delete(_)) => {
+                let delete = std::mem::replace(op_last, embed);
+                self.ops.push(delete);
+            },
+            _ => self.ops.push(embed),
+        }
+    }
+
     pub fn retain(&mut self, n: usize, attributes: Attributes) {
         if n == 0 {
             return;
@@ -171,6 +201,74 @@ impl Delta {
         }
     }
 
+    /// Drops the empty ops and merges the adjacent ops that can be merged,
+    /// which takes the fewest ops. The trailing retain is kept, as it carries
+    /// the base length that applying and transforming the delta check, so the
+    /// normalized delta applies to the same text as the original one.
+    pub fn normalize(&mut self) {
+        let mut delta = Delta::with_capacity(self.ops.len());
+        for op in self.ops.drain(..) {
+            delta.add(op);
+        }
+        *self = delta;
+    }
+
+    /// Rebuilds the delta with the attributes of every op mapped by `f`, which
+    /// returns the new key and value of an attribute or `None` to drop it.
+    /// Used to migrate the documents when an attribute is renamed or its value
+    /// encoding changes.
+    pub fn map_attributes<F>(&self, f: F) -> Delta
+    where
+        F: Fn(&AttributeKey, &AttributeValue) -> Option<(AttributeKey, AttributeValue)>,
+    {
+        let mut delta = Delta::with_capacity(self.ops.len());
+        for op in &self.ops {
+            let mut op = op.clone();
+            let mut attributes = Attributes::new();
+            for (key, value) in op.get_attributes().iter() {
+                if let Some((key, value)) = f(key, value) {
+                    attributes.add_kv(key, value);
+                }
+            }
+            op.set_attributes(attributes);
+            delta.add(op);
+        }
+        delta
+    }
+
+    /// Builds the document containing the plain text. A newline is appended
+    /// unless the text ends with one, as every document ends with a newline.
+    pub fn from_plain_text(text: &str) -> Delta {
+        let mut delta = Delta::new();
+        delta.insert(text, plain_attributes());
+        if !text.ends_with(NEW_LINE) {
+            delta.insert(NEW_LINE, plain_attributes());
+        }
+        delta
+    }
+
+    /// The text of the inserts without their attributes, the inverse of
+    /// [Delta::from_plain_text]. The embeds have no text so they are skipped,
+    /// the footnotes inserted as [EMBED] too, and the newlines ending the
+    /// blocks, including the last one, are kept.
+    pub fn to_plain_text(&self) -> String {
+        let mut text = String::new();
+        for op in &self.ops {
+            if let Operation::Insert(insert) = op {
+                insert.s.split(EMBED).for_each(|s| text.push_str(s));
+            }
+        }
+        text
+    }
+
+    /// Compares the deltas ignoring the attributes, so two documents that
+    /// differ only in formatting are equal. Used to assert that a
+    /// formatting-only migration didn't change the content.
+    pub fn text_structure_eq(&self, other: &Delta) -> bool {
+        let strip = |delta: &Delta| delta.map_attributes(|_, _| None);
+        strip(self) == strip(other)
+    }
+
     /// Applies an operation to a string, returning a new string.
     pub fn apply(&self, s: &str) -> Result<String, OTError> {
         let s: FlowyStr = s.into();
@@ -181,19 +279,16 @@ impl Delta {
         let chars = &mut s.chars();
         for op in &self.ops {
             match &op {
-                Operation::Retain(retain) => {
-                    for c in chars.take(retain.n as usize) {
-                        new_s.push(c);
-                    }
-                },
+                Operation::Retain(retain) => new_s += &take_utf16(chars, retain.n),
                 Operation::Delete(delete) => {
-                    for _ in 0..*delete {
-                        chars.next();
-                    }
+                    let _ = take_utf16(chars, delete.n);
                 },
                 Operation::Insert(insert) => {
                     new_s += &insert.s;
                 },
+                Operation::Embed(_) => {
+                    new_s += EMBED;
+                },
             }
         }
         Ok(new_s)
@@ -214,11 +309,11 @@ impl Delta {
                         chars.next();
                     }
                 },
-                Operation::Insert(insert) => {
-                    inverted.delete(insert.count_of_code_units());
+                Operation::Insert(_) | Operation::Embed(_) => {
+                    inverted.delete(op.len());
                 },
                 Operation::Delete(delete) => {
-                    inverted.insert(&chars.take(*delete as usize).collect::<String>(), op.get_attributes());
+                    inverted.insert(&chars.take(delete.n as usize).collect::<String>(), op.get_attributes());
                 },
             }
         }
@@ -231,72 +326,93 @@ impl Delta {
 
     pub fn is_empty(&self) -> bool { self.ops.is_empty() }
 
-    pub fn extend(&mut self, other: Self) { other.ops.into_iter().for_each(|op| self.add(op)); }
-}
+    /// Checks if this delta changes the text, i.e. it inserts or deletes
+    /// something. A delta that only retains with attributes changes the format
+    /// only.
+    pub fn is_content_change(&self) -> bool { self.ops.iter().any(|op| !op.is_retain()) }
+
+    /// The number of UTF-16 code units retained by this delta.
+    pub fn retain_len(&self) -> usize {
+        self.ops
+            .iter()
+            .filter(|op| op.is_retain())
+            .fold(0, |len, op| len + op.len())
+    }
 
-impl OperationTransformable for Delta {
-    fn compose(&self, other: &Self) -> Result<Self, OTError>
-    where
-        Self: Sized,
-    {
-        let mut new_delta = Delta::default();
-        let mut iter = DeltaIter::new(self);
-        let mut other_iter = DeltaIter::new(other);
+    /// The number of UTF-16 code units inserted by this delta.
+    pub fn insert_len(&self) -> usize {
+        self.ops
+            .iter()
+            .filter(|op| op.is_insert())
+            .fold(0, |len, op| len + op.len())
+    }
 
-        while iter.has_next() || other_iter.has_next() {
-            if other_iter.is_next_insert() {
-                new_delta.add(other_iter.next_op().unwrap());
-                continue;
-            }
+    /// The number of UTF-16 code units deleted by this delta.
+    pub fn delete_len(&self) -> usize {
+        self.ops
+            .iter()
+            .filter(|op| op.is_delete())
+            .fold(0, |len, op| len + op.len())
+    }
 
-            if iter.is_next_delete() {
-                new_delta.add(iter.next_op().unwrap());
-                continue;
-            }
+    /// Checks if the last operation inserts a newline. A document delta must
+    /// end with one, otherwise its last block has nowhere to keep its
+    /// attributes.
+    pub fn has_trailing_newline(&self) -> bool {
+        match self.ops.last() {
+            Some(Operation::Insert(insert)) => insert.s.ends_with(NEW_LINE),
+            _ => false,
+        }
+    }
 
-            let length = min(
-                iter.next_op_len().unwrap_or(MAX_IV_LEN),
-                other_iter.next_op_len().unwrap_or(MAX_IV_LEN),
-            );
+    pub fn extend(&mut self, other: Self) { other.ops.into_iter().for_each(|op| self.add(op)); }
 
-            let op = iter
-                .next_op_with_len(length)
-                .unwrap_or_else(|| OpBuilder::retain(length).build());
-            let other_op = other_iter
-                .next_op_with_len(length)
-                .unwrap_or_else(|| OpBuilder::retain(length).build());
+    /// Concatenates the document `other` after this one. Unlike compose, both
+    /// deltas are treated as documents, so a newline is inserted between them
+    /// when this one doesn't end with one, keeping the last block of this
+    /// document from being merged into the first block of `other`.
+    pub fn append(&mut self, other: Self) {
+        if other.is_empty() {
+            return;
+        }
 
-            debug_assert_eq!(op.len(), other_op.len());
+        if !self.is_empty() && !self.has_trailing_newline() {
+            self.insert(NEW_LINE, Attributes::default());
+        }
+        self.extend(other);
+    }
 
-            match (&op, &other_op) {
-                (Operation::Retain(retain), Operation::Retain(other_retain)) => {
-                    let composed_attrs = retain.attributes.compose(&other_retain.attributes)?;
+    /// Concatenates the document `other` before this one. See [Delta::append].
+    pub fn prepend(&mut self, other: Self) {
+        let mut new_delta = other;
+        new_delta.append(std::mem::take(self));
+        *self = new_delta;
+    }
 
-                    new_delta.add(OpBuilder::retain(retain.n).attributes(composed_attrs).build())
-                },
-                (Operation::Insert(insert), Operation::Retain(other_retain)) => {
-                    let mut composed_attrs = insert.attributes.compose(&other_retain.attributes)?;
-                    composed_attrs.remove_empty();
-                    new_delta.add(OpBuilder::insert(op.get_data()).attributes(composed_attrs).build())
-                },
-                (Operation::Retain(_), Operation::Delete(_)) => {
-                    new_delta.add(other_op);
-                },
-                (a, b) => {
-                    debug_assert_eq!(a.is_insert(), true);
-                    debug_assert_eq!(b.is_delete(), true);
-                    continue;
-                },
-            }
+    /// Composes like [OperationTransformable::compose], but requires the base
+    /// length of `other` to be the target length of this delta. The compose
+    /// retains whatever either delta leaves out, which lets the editor omit
+    /// the trailing retains, but squashing the revisions must not paper over
+    /// a revision based on another version of the document.
+    pub fn compose_checked(&self, other: &Self) -> Result<Self, OTError> {
+        if self.target_len != other.base_len {
+            return Err(ErrorBuilder::new(OTErrorCode::IncompatibleLength)
+                .msg(format!(
+                    "cur target length: {}, other base length: {}",
+                    self.target_len, other.base_len
+                ))
+                .build());
         }
-
-        Ok(new_delta)
+        self.compose(other)
     }
 
-    fn transform(&self, other: &Self) -> Result<(Self, Self), OTError>
-    where
-        Self: Sized,
-    {
+    /// Transforms like [OperationTransformable::transform], resolving the
+    /// attributes that both deltas format by the `policy` rather than letting
+    /// self always win.
+    pub fn transform_with(&self, other: &Self, policy: &TransformPolicy) -> Result<(Self, Self), OTError> {
+        #[cfg(feature = "metrics")]
+        let _timer = crate::metrics::LatencyTimer::new(crate::metrics::TRANSFORM, self.ops.len() + other.ops.len());
+
         if self.base_len != other.base_len {
             return Err(ErrorBuilder::new(OTErrorCode::IncompatibleLength)
                 .msg(format!(
@@ -317,16 +433,24 @@ impl OperationTransformable for Delta {
         loop {
             match (&next_op1, &next_op2) {
                 (None, None) => break,
-                (Some(Operation::Insert(insert)), _) => {
+                (Some(insert), Some(o_insert))
+                    if insert.is_insert() && o_insert.is_insert() && !policy.self_inserts_first() =>
+                {
+                    // Mirrors the other delta transforming this one, so both sites end up with the
+                    // same order of the inserts.
+                    a_prime.retain(o_insert.len(), o_insert.get_attributes());
+                    b_prime.add(o_insert.clone());
+                    next_op2 = ops2.next();
+                }
+                (Some(insert), _) if insert.is_insert() => {
                     // let composed_attrs = transform_attributes(&next_op1, &next_op2, true);
-                    a_prime.insert(&insert.s, insert.attributes.clone());
-                    b_prime.retain(insert.count_of_code_units(), insert.attributes.clone());
+                    a_prime.add(insert.clone());
+                    b_prime.retain(insert.len(), insert.get_attributes());
                     next_op1 = ops1.next();
                 },
-                (_, Some(Operation::Insert(o_insert))) => {
-                    let composed_attrs = transform_op_attribute(&next_op1, &next_op2);
-                    a_prime.retain(o_insert.count_of_code_units(), composed_attrs.clone());
-                    b_prime.insert(&o_insert.s, composed_attrs);
+                (_, Some(o_insert)) if o_insert.is_insert() => {
+                    a_prime.retain(o_insert.len(), o_insert.get_attributes());
+                    b_prime.add(o_insert.clone());
                     next_op2 = ops2.next();
                 },
                 (None, _) => {
@@ -336,31 +460,41 @@ impl OperationTransformable for Delta {
                     return Err(ErrorBuilder::new(OTErrorCode::IncompatibleLength).build());
                 },
                 (Some(Operation::Retain(retain)), Some(Operation::Retain(o_retain))) => {
-                    let composed_attrs = transform_op_attribute(&next_op1, &next_op2);
+                    // The winner of the conflicting attributes keeps them in its prime, whereas
+                    // the loser drops the ones that the winner formats too.
+                    let (a_attrs, b_attrs) = retain.attributes.transform_with(&o_retain.attributes, policy);
                     match retain.cmp(&o_retain) {
                         Ordering::Less => {
-                            a_prime.retain(retain.n, composed_attrs.clone());
-                            b_prime.retain(retain.n, composed_attrs.clone());
-                            next_op2 = Some(OpBuilder::retain(o_retain.n - retain.n).build());
+                            a_prime.retain(retain.n, a_attrs);
+                            b_prime.retain(retain.n, b_attrs);
+                            next_op2 = Some(
+                                OpBuilder::retain(o_retain.n - retain.n)
+                                    .attributes(o_retain.attributes.clone())
+                                    .build(),
+                            );
                             next_op1 = ops1.next();
                         },
                         Ordering::Equal => {
-                            a_prime.retain(retain.n, composed_attrs.clone());
-                            b_prime.retain(retain.n, composed_attrs.clone());
+                            a_prime.retain(retain.n, a_attrs);
+                            b_prime.retain(retain.n, b_attrs);
                             next_op1 = ops1.next();
                             next_op2 = ops2.next();
                         },
                         Ordering::Greater => {
-                            a_prime.retain(o_retain.n, composed_attrs.clone());
-                            b_prime.retain(o_retain.n, composed_attrs.clone());
-                            next_op1 = Some(OpBuilder::retain(retain.n - o_retain.n).build());
+                            a_prime.retain(o_retain.n, a_attrs);
+                            b_prime.retain(o_retain.n, b_attrs);
+                            next_op1 = Some(
+                                OpBuilder::retain(retain.n - o_retain.n)
+                                    .attributes(retain.attributes.clone())
+                                    .build(),
+                            );
                             next_op2 = ops2.next();
                         },
                     };
                 },
                 (Some(Operation::Delete(i)), Some(Operation::Delete(j))) => match i.cmp(&j) {
                     Ordering::Less => {
-                        next_op2 = Some(OpBuilder::delete(*j - *i).build());
+                        next_op2 = Some(OpBuilder::delete(j.n - i.n).attributes(j.attributes.clone()).build());
                         next_op1 = ops1.next();
                     },
                     Ordering::Equal => {
@@ -368,25 +502,33 @@ impl OperationTransformable for Delta {
                         next_op2 = ops2.next();
                     },
                     Ordering::Greater => {
-                        next_op1 = Some(OpBuilder::delete(*i - *j).build());
+                        next_op1 = Some(OpBuilder::delete(i.n - j.n).attributes(i.attributes.clone()).build());
                         next_op2 = ops2.next();
                     },
                 },
                 (Some(Operation::Delete(i)), Some(Operation::Retain(o_retain))) => {
                     match i.cmp(&o_retain) {
                         Ordering::Less => {
-                            a_prime.delete(*i);
-                            next_op2 = Some(OpBuilder::retain(o_retain.n - *i).build());
+                            a_prime.add(Operation::Delete(i.clone()));
+                            next_op2 = Some(
+                                OpBuilder::retain(o_retain.n - i.n)
+                                    .attributes(o_retain.attributes.clone())
+                                    .build(),
+                            );
                             next_op1 = ops1.next();
                         },
                         Ordering::Equal => {
-                            a_prime.delete(*i);
+                            a_prime.add(Operation::Delete(i.clone()));
                             next_op1 = ops1.next();
                             next_op2 = ops2.next();
                         },
                         Ordering::Greater => {
-                            a_prime.delete(o_retain.n);
-                            next_op1 = Some(OpBuilder::delete(*i - o_retain.n).build());
+                            a_prime.delete_with_attributes(o_retain.n, i.attributes.clone());
+                            next_op1 = Some(
+                                OpBuilder::delete(i.n - o_retain.n)
+                                    .attributes(i.attributes.clone())
+                                    .build(),
+                            );
                             next_op2 = ops2.next();
                         },
                     };
@@ -394,26 +536,111 @@ impl OperationTransformable for Delta {
                 (Some(Operation::Retain(retain)), Some(Operation::Delete(j))) => {
                     match retain.cmp(&j) {
                         Ordering::Less => {
-                            b_prime.delete(retain.n);
-                            next_op2 = Some(OpBuilder::delete(*j - retain.n).build());
+                            b_prime.delete_with_attributes(retain.n, j.attributes.clone());
+                            next_op2 = Some(
+                                OpBuilder::delete(j.n - retain.n)
+                                    .attributes(j.attributes.clone())
+                                    .build(),
+                            );
                             next_op1 = ops1.next();
                         },
                         Ordering::Equal => {
-                            b_prime.delete(retain.n);
+                            b_prime.add(Operation::Delete(j.clone()));
                             next_op1 = ops1.next();
                             next_op2 = ops2.next();
                         },
                         Ordering::Greater => {
-                            b_prime.delete(*j);
-                            next_op1 = Some(OpBuilder::retain(retain.n - *j).build());
+                            b_prime.add(Operation::Delete(j.clone()));
+                            next_op1 = Some(
+                                OpBuilder::retain(retain.n - j.n)
+                                    .attributes(retain.attributes.clone())
+                                    .build(),
+                            );
                             next_op2 = ops2.next();
                         },
                     };
                 },
+                (Some(Operation::Insert(_)), _)
+                | (Some(Operation::Embed(_)), _)
+                | (_, Some(Operation::Insert(_)))
+                | (_, Some(Operation::Embed(_))) => unreachable!("The inserts are transformed above"),
             }
         }
         Ok((a_prime, b_prime))
     }
+}
+
+impl OperationTransformable for Delta {
+    fn compose(&self, other: &Self) -> Result<Self, OTError>
+    where
+        Self: Sized,
+    {
+        #[cfg(feature = "metrics")]
+        let _timer = crate::metrics::LatencyTimer::new(crate::metrics::COMPOSE, self.ops.len() + other.ops.len());
+
+        let mut new_delta = Delta::default();
+        let mut iter = DeltaIter::new(self);
+        let mut other_iter = DeltaIter::new(other);
+
+        while iter.has_next() || other_iter.has_next() {
+            if other_iter.is_next_insert() {
+                new_delta.add(other_iter.next_op().unwrap());
+                continue;
+            }
+
+            if iter.is_next_delete() {
+                new_delta.add(iter.next_op().unwrap());
+                continue;
+            }
+
+            let length = min(
+                iter.next_op_len().unwrap_or(MAX_IV_LEN),
+                other_iter.next_op_len().unwrap_or(MAX_IV_LEN),
+            );
+
+            let op = iter
+                .next_op_with_len(length)
+                .unwrap_or_else(|| OpBuilder::retain(length).build());
+            let other_op = other_iter
+                .next_op_with_len(length)
+                .unwrap_or_else(|| OpBuilder::retain(length).build());
+
+            debug_assert_eq!(op.len(), other_op.len());
+
+            match (&op, &other_op) {
+                (Operation::Retain(retain), Operation::Retain(other_retain)) => {
+                    let composed_attrs = retain.attributes.compose(&other_retain.attributes)?;
+
+                    new_delta.add(OpBuilder::retain(retain.n).attributes(composed_attrs).build())
+                },
+                (Operation::Insert(_), Operation::Retain(other_retain))
+                | (Operation::Embed(_), Operation::Retain(other_retain)) => {
+                    let mut composed_attrs = op.get_attributes().compose(&other_retain.attributes)?;
+                    composed_attrs.remove_empty();
+                    let mut new_op = op.clone();
+                    new_op.set_attributes(composed_attrs);
+                    new_delta.add(new_op)
+                },
+                (Operation::Retain(_), Operation::Delete(_)) => {
+                    new_delta.add(other_op);
+                },
+                (a, b) => {
+                    debug_assert_eq!(a.is_insert(), true);
+                    debug_assert_eq!(b.is_delete(), true);
+                    continue;
+                },
+            }
+        }
+
+        Ok(new_delta)
+    }
+
+    fn transform(&self, other: &Self) -> Result<(Self, Self), OTError>
+    where
+        Self: Sized,
+    {
+        self.transform_with(other, &TransformPolicy::default())
+    }
 
     fn invert(&self, other: &Self) -> Self {
         let mut inverted = Delta::default();
@@ -423,25 +650,22 @@ impl OperationTransformable for Delta {
         tracing::trace!("🌜Calculate invert delta");
         tracing::trace!("current: {}", self);
         tracing::trace!("other: {}", other);
-        let mut index = 0;
+        // Walks the other delta along with this one, which consumes the other
+        // delta at the deletes and the retains.
+        let mut other_iter = DeltaIter::new(other);
         for op in &self.ops {
             let len: usize = op.len() as usize;
             match op {
-                Operation::Delete(n) => {
-                    invert_from_other(&mut inverted, other, op, index, index + *n);
-                    index += len;
-                },
-                Operation::Retain(_) => {
-                    match op.has_attribute() {
-                        true => invert_from_other(&mut inverted, other, op, index, index + len),
-                        false => {
-                            tracing::trace!("invert retain: {} by retain {} {}", op, len, op.get_attributes());
-                            inverted.retain(len as usize, op.get_attributes())
-                        },
-                    }
-                    index += len;
+                Operation::Delete(_) => invert_from_other(&mut inverted, &mut other_iter, op, len),
+                Operation::Retain(_) => match op.has_attribute() {
+                    true => invert_from_other(&mut inverted, &mut other_iter, op, len),
+                    false => {
+                        tracing::trace!("invert retain: {} by retain {} {}", op, len, op.get_attributes());
+                        inverted.retain(len as usize, op.get_attributes());
+                        skip_other(&mut other_iter, len);
+                    },
                 },
-                Operation::Insert(_) => {
+                Operation::Insert(_) | Operation::Embed(_) => {
                     tracing::trace!("invert insert: {} by delete {}", op, len);
                     inverted.delete(len as usize);
                 },
@@ -453,12 +677,88 @@ impl OperationTransformable for Delta {
     }
 }
 
-fn invert_from_other(base: &mut Delta, other: &Delta, operation: &Operation, start: usize, end: usize) {
-    tracing::trace!("invert op: {} [{}:{}]", operation, start, end);
-    let other_ops = DeltaIter::from_interval(other, Interval::new(start, end)).ops();
+/// Shifts the interval, e.g. the local cursor or selection, through the delta
+/// that is applied to the document. Inserts before the interval push it right
+/// and deletes before it pull it left, while the edits inside the interval
+/// adjust its size. A cursor is pushed right by an insert at its position, but
+/// an insert at the end of a selection doesn't extend the selection.
+pub fn transform_interval(interval: Interval, delta: &Delta) -> Interval {
+    let start = transform_position(interval.start, delta, true);
+    let end = transform_position(interval.end, delta, interval.is_empty());
+    Interval::new(start, max(start, end))
+}
+
+fn transform_position(position: usize, delta: &Delta, push_on_insert: bool) -> usize {
+    let mut index = 0;
+    let mut new_position = position;
+    for op in &delta.ops {
+        if index > position {
+            break;
+        }
+        match op {
+            Operation::Retain(retain) => index += retain.n,
+            Operation::Delete(delete) => {
+                if index < position {
+                    new_position -= min(delete.n, position - index);
+                }
+                index += delete.n;
+            },
+            Operation::Insert(_) | Operation::Embed(_) => {
+                if index < position || (index == position && push_on_insert) {
+                    new_position += op.len();
+                }
+            },
+        }
+    }
+    new_position
+}
+
+// Takes the chars of `n` UTF-16 code units, a char may take two.
+fn take_utf16(chars: &mut std::str::Chars, n: usize) -> String {
+    let mut taken = String::new();
+    let mut len = 0;
+    while len < n {
+        match chars.next() {
+            None => break,
+            Some(c) => {
+                len += c.len_utf16();
+                taken.push(c);
+            },
+        }
+    }
+    taken
+}
+
+// Takes the ops of the other delta covering the next `len` code units. Unlike
+// DeltaIter::next_op_with_len, which returns the last one only, every op
+// crossed is returned.
+fn next_other_ops(other_iter: &mut DeltaIter, len: usize) -> Vec<Operation> {
+    let mut ops = vec![];
+    let mut remaining = len;
+    while remaining > 0 {
+        let next_len = min(remaining, other_iter.next_op_len().unwrap_or(0));
+        if next_len == 0 {
+            break;
+        }
+        match other_iter.next_op_with_len(next_len) {
+            Some(op) => {
+                remaining -= op.len();
+                ops.push(op);
+            },
+            None => break,
+        }
+    }
+    ops
+}
+
+fn skip_other(other_iter: &mut DeltaIter, len: usize) { let _ = next_other_ops(other_iter, len); }
+
+fn invert_from_other(base: &mut Delta, other_iter: &mut DeltaIter, operation: &Operation, len: usize) {
+    tracing::trace!("invert op: {} by {} of other", operation, len);
+    let other_ops = next_other_ops(other_iter, len);
     other_ops.into_iter().for_each(|other_op| match operation {
-        Operation::Delete(n) => {
-            tracing::trace!("invert delete: {} by add {}", n, other_op);
+        Operation::Delete(delete) => {
+            tracing::trace!("invert delete: {} by add {}", delete, other_op);
             base.add(other_op);
         },
         Operation::Retain(_retain) => {
@@ -470,21 +770,8 @@ fn invert_from_other(base: &mut Delta, other: &Delta, operation: &Operation, sta
             let inverted_attrs = operation.get_attributes().invert(&other_op.get_attributes());
             base.retain(other_op.len(), inverted_attrs);
         },
-        Operation::Insert(_) => {
+        Operation::Insert(_) | Operation::Embed(_) => {
             log::error!("Impossible to here. Insert operation should be treated as delete")
         },
     });
 }
-
-fn transform_op_attribute(left: &Option<Operation>, right: &Option<Operation>) -> Attributes {
-    if left.is_none() {
-        if right.is_none() {
-            return Attributes::default();
-        }
-        return right.as_ref().unwrap().get_attributes();
-    }
-    let left = left.as_ref().unwrap().get_attributes();
-    let right = right.as_ref().unwrap().get_attributes();
-    // TODO: It's ok to unwrap?
-    left.transform(&right).unwrap().0
-}
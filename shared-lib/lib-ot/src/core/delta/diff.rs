@@ -0,0 +1,150 @@
+use crate::core::{plain_attributes, Delta};
+
+/// An edit of the script turning a slice into another, see [diff_slices]. The
+/// edits hold the indices of the items in the old and the new slice.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DiffEdit {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+impl Delta {
+    /// Computes the delta turning the text `old` into `new`, with the fewest
+    /// inserted and deleted characters found by the Myers diff. The unchanged
+    /// text is retained, so applying the delta to a document keeps its
+    /// attributes, and the lengths are counted in UTF-16 code units like the
+    /// other deltas. The trailing retain is kept, so the delta applies to
+    /// `old` with [Delta::apply].
+    pub fn diff(old: &str, new: &str) -> Delta {
+        let old = old.chars().collect::<Vec<char>>();
+        let new = new.chars().collect::<Vec<char>>();
+        let mut delta = Delta::new();
+        for edit in diff_slices(&old, &new) {
+            match edit {
+                DiffEdit::Equal(index, _) => delta.retain(old[index].len_utf16(), plain_attributes()),
+                DiffEdit::Delete(index) => delta.delete(old[index].len_utf16()),
+                DiffEdit::Insert(index) => delta.insert(&new[index].to_string(), plain_attributes()),
+            }
+        }
+        delta
+    }
+}
+
+/// The shortest edit script turning `old` into `new`, see "An O(ND) Difference
+/// Algorithm and Its Variations" by Eugene W. Myers. The linear space variant
+/// is used, so long texts can be diffed too. The deletions of each change come
+/// before its insertions.
+pub fn diff_slices<T: PartialEq>(old: &[T], new: &[T]) -> Vec<DiffEdit> {
+    let mut edits = vec![];
+    diff_into(old, new, (0, 0), &mut edits);
+    // The edits of a change interleave when it's split by the middle snake
+    for change in edits.split_mut(|edit| matches!(edit, DiffEdit::Equal(..))) {
+        change.sort_by_key(|edit| matches!(edit, DiffEdit::Insert(_)));
+    }
+    edits
+}
+
+// The middle snake splits the slices in two halves that are diffed on their
+// own, so only the furthest points of the current d are kept. `offset` is the
+// position of the slices in the ones being diffed.
+fn diff_into<T: PartialEq>(old: &[T], new: &[T], offset: (usize, usize), edits: &mut Vec<DiffEdit>) {
+    let prefix = old.iter().zip(new.iter()).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let (old_start, new_start) = (offset.0 + prefix, offset.1 + prefix);
+    edits.extend((0..prefix).map(|i| DiffEdit::Equal(offset.0 + i, offset.1 + i)));
+    let (old_middle, new_middle) = (&old[prefix..old.len() - suffix], &new[prefix..new.len() - suffix]);
+    if old_middle.is_empty() || new_middle.is_empty() {
+        edits.extend((0..old_middle.len()).map(|i| DiffEdit::Delete(old_start + i)));
+        edits.extend((0..new_middle.len()).map(|i| DiffEdit::Insert(new_start + i)));
+    } else {
+        let (x, y) = middle_snake(old_middle, new_middle);
+        diff_into(&old_middle[..x], &new_middle[..y], (old_start, new_start), edits);
+        diff_into(
+            &old_middle[x..],
+            &new_middle[y..],
+            (old_start + x, new_start + y),
+            edits,
+        );
+    }
+    let (old_end, new_end) = (old_start + old_middle.len(), new_start + new_middle.len());
+    edits.extend((0..suffix).map(|i| DiffEdit::Equal(old_end + i, new_end + i)));
+}
+
+// Walks the paths from both ends at once, `forward[k]` and `backward[k]` being
+// the furthest x reached on the diagonal k = x - y from the start and from the
+// end. Returns the point where they overlap, that lies on a shortest path. The
+// diagonals whose path left the grid are skipped from then on. The texts must
+// differ at both ends, so the point is neither the start nor the end and the
+// recursion always makes progress.
+fn middle_snake<T: PartialEq>(old: &[T], new: &[T]) -> (usize, usize) {
+    let (old_len, new_len) = (old.len() as isize, new.len() as isize);
+    let max_d = (old_len + new_len + 1) / 2;
+    let offset = max_d;
+    let len = 2 * max_d + 2;
+    let mut forward = vec![-1_isize; len as usize];
+    let mut backward = forward.clone();
+    forward[offset as usize + 1] = 0;
+    backward[offset as usize + 1] = 0;
+    let delta = old_len - new_len;
+    let odd = delta % 2 != 0;
+    let in_range = |i: isize| i >= 0 && i < len;
+    let (mut forward_start, mut forward_end, mut backward_start, mut backward_end) = (0, 0, 0, 0);
+    for d in 0..=max_d {
+        for k in (-d + forward_start..=d - forward_end).step_by(2) {
+            let i = (offset + k) as usize;
+            let mut x = match k == -d || (k != d && forward[i - 1] < forward[i + 1]) {
+                true => forward[i + 1],
+                false => forward[i - 1] + 1,
+            };
+            let mut y = x - k;
+            while x < old_len && y < new_len {
+                if old[x as usize] != new[y as usize] {
+                    break;
+                }
+                x += 1;
+                y += 1;
+            }
+            forward[i] = x;
+            let j = offset + delta - k;
+            if x > old_len {
+                forward_end += 2;
+            } else if y > new_len {
+                forward_start += 2;
+            } else if odd && in_range(j) && backward[j as usize] != -1 && x >= old_len - backward[j as usize] {
+                return (x as usize, y as usize);
+            }
+        }
+        for k in (-d + backward_start..=d - backward_end).step_by(2) {
+            let i = (offset + k) as usize;
+            let mut x = match k == -d || (k != d && backward[i - 1] < backward[i + 1]) {
+                true => backward[i + 1],
+                false => backward[i - 1] + 1,
+            };
+            let mut y = x - k;
+            while x < old_len && y < new_len {
+                if old[(old_len - x - 1) as usize] != new[(new_len - y - 1) as usize] {
+                    break;
+                }
+                x += 1;
+                y += 1;
+            }
+            backward[i] = x;
+            let j = offset + delta - k;
+            if x > old_len {
+                backward_end += 2;
+            } else if y > new_len {
+                backward_start += 2;
+            } else if !odd && in_range(j) && forward[j as usize] != -1 && forward[j as usize] >= old_len - x {
+                let forward_x = forward[j as usize];
+                return (forward_x as usize, (forward_x - (j - offset)) as usize);
+            }
+        }
+    }
+    unreachable!("The paths always overlap once (n + m + 1) / 2 edits are walked from both ends")
+}
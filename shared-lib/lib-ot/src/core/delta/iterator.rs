@@ -39,6 +39,15 @@ impl<'a> DeltaIter<'a> {
 
     pub fn next_op(&mut self) -> Option<Operation> { self.cursor.next_op() }
 
+    /// Returns the op that [DeltaIter::next_op] would return, without
+    /// consuming it. The op may be the rest of an op split by a seek or a
+    /// [DeltaIter::next_op_with_len], which isn't stored in the delta, so it's
+    /// returned by value.
+    pub fn peek(&self) -> Option<Operation> {
+        let op = self.cursor.next_iter_op()?;
+        op.shrink(self.cursor.next_iv())
+    }
+
     pub fn next_op_with_len(&mut self, len: usize) -> Option<Operation> { self.cursor.next_with_len(Some(len)) }
 
     // find next op contains NEW_LINE
@@ -162,6 +171,10 @@ impl<'a> Iterator for AttributesIter<'a> {
                 attributes.extend(insert.attributes.clone());
                 length = insert.count_of_code_units();
             },
+            Operation::Embed(embed) => {
+                attributes.extend(embed.attributes);
+                length = 1;
+            },
         }
 
         Some((length, attributes))
@@ -3,12 +3,18 @@ mod builder;
 mod cursor;
 mod delta;
 mod delta_serde;
+mod diff;
 mod iterator;
+mod portable;
 
 pub use builder::*;
 pub use cursor::*;
 pub use delta::*;
+pub use diff::*;
 pub use iterator::*;
+pub use portable::*;
 
 pub const NEW_LINE: &str = "\n";
 pub const WHITESPACE: &str = " ";
+// The object replacement character that stands for an embed in the text.
+pub const EMBED: &str = "\u{fffc}";
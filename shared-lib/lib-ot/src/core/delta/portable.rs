@@ -0,0 +1,105 @@
+use crate::{
+    core::{AttributeKey, AttributeValue, Delta, Operation},
+    errors::{ErrorBuilder, OTError, OTErrorCode},
+};
+use serde::{Deserialize, Serialize};
+
+/// The schema version written by [Delta::export_portable]. Bump it when the
+/// attributes change their keys or the encoding of their values, and pass the
+/// [PortableMigration] from the previous version to
+/// [Delta::import_portable_with_migrations].
+pub const PORTABLE_SCHEMA_VERSION: u32 = 1;
+
+/// Migrates the attributes of a delta exported with a version older than
+/// `version` to the schema of `version`, see [Delta::map_attributes].
+pub struct PortableMigration {
+    pub version: u32,
+    pub map: fn(&AttributeKey, &AttributeValue) -> Option<(AttributeKey, AttributeValue)>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PortableDelta {
+    version: u32,
+    attribute_keys: Vec<String>,
+    checksum: String,
+    delta: String,
+}
+
+impl Delta {
+    /// Wraps the delta with the schema version, the attribute keys it uses and
+    /// the checksum of its json, for archiving it beyond the current version.
+    pub fn export_portable(&self) -> Vec<u8> {
+        let delta = self.to_json();
+        let portable = PortableDelta {
+            version: PORTABLE_SCHEMA_VERSION,
+            attribute_keys: attribute_keys(self),
+            checksum: checksum(&delta),
+            delta,
+        };
+        serde_json::to_vec(&portable).unwrap_or_else(|_| b"".to_vec())
+    }
+
+    pub fn import_portable(bytes: &[u8]) -> Result<Delta, OTError> { Self::import_portable_with_migrations(bytes, &[]) }
+
+    /// Imports a delta exported by [Delta::export_portable]. The delta exported
+    /// with an older version runs through the migrations of the newer versions
+    /// in order, the delta exported with a version newer than
+    /// [PORTABLE_SCHEMA_VERSION] is rejected.
+    pub fn import_portable_with_migrations(bytes: &[u8], migrations: &[PortableMigration]) -> Result<Delta, OTError> {
+        let portable: PortableDelta = serde_json::from_slice(bytes)?;
+        if portable.version > PORTABLE_SCHEMA_VERSION {
+            return Err(ErrorBuilder::new(OTErrorCode::SerdeError)
+                .msg(format!(
+                    "Unsupported portable schema version {}, expect {} or older",
+                    portable.version, PORTABLE_SCHEMA_VERSION
+                ))
+                .build());
+        }
+
+        if checksum(&portable.delta) != portable.checksum {
+            return Err(ErrorBuilder::new(OTErrorCode::SerdeError)
+                .msg("The checksum of the portable delta mismatched")
+                .build());
+        }
+
+        let mut delta = Delta::from_json(&portable.delta)?;
+        let mut migrations = migrations
+            .iter()
+            .filter(|migration| migration.version > portable.version && migration.version <= PORTABLE_SCHEMA_VERSION)
+            .collect::<Vec<&PortableMigration>>();
+        migrations.sort_by_key(|migration| migration.version);
+        for migration in migrations {
+            delta = delta.map_attributes(migration.map);
+        }
+        Ok(delta)
+    }
+}
+
+fn attribute_keys(delta: &Delta) -> Vec<String> {
+    let mut keys = delta
+        .ops
+        .iter()
+        .filter(|op| !matches!(op, Operation::Delete(_)))
+        .flat_map(|op| {
+            op.get_attributes()
+                .iter()
+                .filter_map(|(key, _)| match serde_json::to_value(key) {
+                    Ok(serde_json::Value::String(key)) => Some(key),
+                    _ => None,
+                })
+                .collect::<Vec<String>>()
+        })
+        .collect::<Vec<String>>();
+    keys.sort();
+    keys.dedup();
+    keys
+}
+
+// The 64-bit FNV-1a hash, which stays the same across the Rust versions unlike
+// the std hashers.
+fn checksum(s: &str) -> String {
+    let hash = s.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
@@ -1,40 +1,60 @@
 use serde::{de, de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
-use std::{fmt, fmt::Formatter, slice};
+use std::{borrow::Cow, cmp::min, fmt, fmt::Formatter, slice};
+use unicode_segmentation::{Graphemes, UnicodeSegmentation};
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct FlowyStr(pub String);
 
 impl FlowyStr {
+    /// Decodes the stored bytes, replacing the invalid UTF-8 sequences left by
+    /// the legacy data with U+FFFD instead of failing.
+    pub fn from_utf8_lossy(bytes: &[u8]) -> FlowyStr {
+        let s = String::from_utf8_lossy(bytes);
+        if let Cow::Owned(_) = s {
+            tracing::warn!(
+                "Replace the invalid UTF-8 sequences of {} bytes with U+FFFD",
+                bytes.len()
+            );
+        }
+        s.into_owned().into()
+    }
+
     pub fn count_utf16_code_units(&self) -> usize { count_utf16_code_units(&self.0) }
 
     pub fn utf16_iter(&self) -> FlowyUtf16Iterator { FlowyUtf16Iterator::new(self, 0) }
 
     pub fn code_point_iter(&self) -> CodePointIterator { CodePointIterator::new(self) }
 
-    pub fn sub_str(&self, interval: Interval) -> String {
-        match self.with_interval(interval) {
-            None => "".to_owned(),
-            Some(s) => s.0,
-        }
-    }
+    /// Iterates over the extended grapheme clusters, i.e. the characters as
+    /// the user sees them, with their offsets in UTF-16 code units. The caret
+    /// moves from cluster to cluster, so a flag or a ZWJ emoji sequence is
+    /// passed over as one character, although it takes several code units.
+    pub fn graphemes(&self) -> GraphemeIterator { GraphemeIterator::new(self) }
 
-    pub fn with_interval(&self, interval: Interval) -> Option<FlowyStr> {
-        let mut iter = CodePointIterator::new(self);
-        let mut buf = vec![];
-        while let Some((byte, _len)) = iter.next() {
-            if interval.start < iter.code_point_offset && interval.end >= iter.code_point_offset {
-                buf.extend_from_slice(byte);
-            }
-        }
+    pub fn grapheme_count(&self) -> usize { self.0.graphemes(true).count() }
 
-        if buf.is_empty() {
-            return None;
-        }
+    pub fn sub_str(&self, interval: Interval) -> FlowyStr { self.with_interval(interval).unwrap_or_default() }
 
-        match str::from_utf8(&buf) {
-            Ok(item) => Some(item.into()),
-            Err(_e) => None,
+    /// Returns the code points within the interval, which is counted in UTF-16
+    /// code units. The end of the interval is clamped to the length of the
+    /// string, and the surrogate pair cut by either end of the interval is
+    /// left out rather than split. Returns `None` if nothing is left.
+    pub fn with_interval(&self, interval: Interval) -> Option<FlowyStr> {
+        let end = min(interval.end, self.count_utf16_code_units());
+        let mut offset = 0;
+        let mut bytes: Option<(usize, usize)> = None;
+        for (index, c) in self.char_indices() {
+            let next_offset = offset + c.len_utf16();
+            if offset >= interval.start && next_offset <= end {
+                let start = bytes.map_or(index, |(start, _)| start);
+                bytes = Some((start, index + c.len_utf8()));
+            }
+            if next_offset >= end {
+                break;
+            }
+            offset = next_offset;
         }
+        bytes.map(|(start, end)| self[start..end].into())
     }
 }
 
@@ -163,6 +183,13 @@ impl<'de> Deserialize<'de> for FlowyStr {
             {
                 Ok(s.into())
             }
+
+            fn visit_bytes<E>(self, bytes: &[u8]) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(FlowyStr::from_utf8_lossy(bytes))
+            }
         }
         deserializer.deserialize_str(FlowyStrVisitor)
     }
@@ -199,6 +226,31 @@ impl<'a> Iterator for FlowyUtf16Iterator<'a> {
     }
 }
 
+pub struct GraphemeIterator<'a> {
+    graphemes: Graphemes<'a>,
+    offset: usize,
+}
+
+impl<'a> GraphemeIterator<'a> {
+    pub fn new(s: &'a FlowyStr) -> Self {
+        GraphemeIterator {
+            graphemes: s.0.graphemes(true),
+            offset: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for GraphemeIterator<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let grapheme = self.graphemes.next()?;
+        let offset = self.offset;
+        self.offset += count_utf16_code_units(grapheme);
+        Some((offset, grapheme))
+    }
+}
+
 pub fn count_utf16_code_units(s: &str) -> usize {
     // bytecount::num_chars(s.as_bytes())
     let mut utf16_count = 0;
@@ -227,7 +279,7 @@ pub fn len_utf8_from_first_byte(b: u8) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use crate::core::{FlowyStr, Interval};
+    use crate::core::{Delta, FlowyStr, Interval, OpBuilder, Operation};
 
     #[test]
     fn flowy_str_utf16_test() {
@@ -263,4 +315,56 @@ mod tests {
         let output = s.with_interval(Interval::new(4, 6)).unwrap().0;
         assert_eq!(output, "👋");
     }
+
+    #[test]
+    fn flowy_str_sub_str_keep_surrogate_pair_test() {
+        let s: FlowyStr = "a😀b".into();
+        assert_eq!(s.sub_str(Interval::new(0, 2)).0, "a");
+        assert_eq!(s.sub_str(Interval::new(2, 4)).0, "b");
+        assert_eq!(s.sub_str(Interval::new(1, 3)).0, "😀");
+        assert_eq!(s.sub_str(Interval::new(2, 3)).0, "");
+        assert_eq!(s.sub_str(Interval::new(1, 10)).0, "😀b");
+        assert_eq!(s.sub_str(Interval::new(5, 10)).0, "");
+    }
+
+    #[test]
+    fn operation_shrink_insert_in_the_middle_of_emoji_test() {
+        let insert = OpBuilder::insert("a😀b").build();
+        let shrunk = insert.shrink(Interval::new(0, 2)).unwrap();
+        assert_eq!(shrunk.get_data(), "a");
+        assert_eq!(shrunk.len(), 1);
+
+        let shrunk = insert.shrink(Interval::new(1, 10)).unwrap();
+        assert_eq!(shrunk.get_data(), "😀b");
+        assert_eq!(insert.shrink(Interval::new(2, 3)), None);
+    }
+
+    #[test]
+    fn flowy_str_grapheme_test() {
+        let s: FlowyStr = "a👨‍👩‍👧👋🏽🇨🇳e\u{301}".into();
+        assert_eq!(s.grapheme_count(), 5);
+        assert_eq!(s.count_utf16_code_units(), 19);
+        let graphemes = s.graphemes().collect::<Vec<(usize, &str)>>();
+        assert_eq!(
+            graphemes,
+            vec![(0, "a"), (1, "👨‍👩‍👧"), (9, "👋🏽"), (13, "🇨🇳"), (17, "e\u{301}"),]
+        );
+    }
+
+    #[test]
+    fn flowy_str_from_invalid_utf8_test() {
+        let s = FlowyStr::from_utf8_lossy(b"ab\xF0\x90\x80c\xFF");
+        assert_eq!(s.0, "ab\u{FFFD}c\u{FFFD}");
+        assert_eq!(s.count_utf16_code_units(), 5);
+    }
+
+    #[test]
+    fn delta_from_invalid_utf8_bytes_test() {
+        let delta = Delta::from_bytes(b"[{\"insert\":\"ab\xFFc\"}]").unwrap();
+        match &delta.ops[0] {
+            Operation::Insert(insert) => assert_eq!(insert.s.0, "ab\u{FFFD}c"),
+            op => panic!("Expected insert, got: {}", op),
+        }
+        assert_eq!(delta.target_len, 4);
+    }
 }
@@ -31,22 +31,27 @@ impl Interval {
 
     pub fn is_before(&self, val: usize) -> bool { self.end <= val }
 
+    /// Whether the point is in the interval, its end excluded. An empty
+    /// interval contains no point.
     pub fn contains(&self, val: usize) -> bool { self.start <= val && val < self.end }
 
     pub fn contains_range(&self, start: usize, end: usize) -> bool {
-        !self.intersect(Interval::new(start, end)).is_empty()
+        self.intersect(Interval::new(start, end)).is_some()
     }
 
     pub fn is_after(&self, val: usize) -> bool { self.start > val }
 
     pub fn is_empty(&self) -> bool { self.end <= self.start }
 
-    pub fn intersect(&self, other: Interval) -> Interval {
+    /// The part of the interval that is in the other one, or `None` if they
+    /// don't overlap. The adjacent intervals, like [1, 3) and [3, 5), don't
+    /// overlap, and neither does an empty interval with anything.
+    pub fn intersect(&self, other: Interval) -> Option<Interval> {
         let start = max(self.start, other.start);
         let end = min(self.end, other.end);
-        Interval {
-            start,
-            end: max(start, end),
+        match start < end {
+            true => Some(Interval { start, end }),
+            false => None,
         }
     }
 
@@ -81,6 +86,8 @@ impl Interval {
         }
     }
 
+    /// The smallest interval covering both intervals, so the gap between the
+    /// disjoint intervals is covered as well. The empty intervals are ignored.
     pub fn union(&self, other: Interval) -> Interval {
         if self.is_empty() {
             return other;
@@ -178,10 +185,72 @@ mod tests {
         assert!(!Interval::new(1, 2).is_empty());
     }
 
+    #[test]
+    fn contains_in_empty() {
+        assert!(!Interval::new(2, 2).contains(2));
+        assert!(!Interval::new(0, 0).contains(0));
+    }
+
     #[test]
     fn intersect() {
-        assert_eq!(Interval::new(2, 3), Interval::new(1, 3).intersect(Interval::new(2, 4)));
-        assert!(Interval::new(1, 2).intersect(Interval::new(2, 43)).is_empty());
+        assert_eq!(
+            Some(Interval::new(2, 3)),
+            Interval::new(1, 3).intersect(Interval::new(2, 4))
+        );
+        assert_eq!(None, Interval::new(1, 2).intersect(Interval::new(2, 43)));
+    }
+
+    #[test]
+    fn intersect_disjoint() {
+        assert_eq!(None, Interval::new(1, 3).intersect(Interval::new(5, 8)));
+        assert_eq!(None, Interval::new(5, 8).intersect(Interval::new(1, 3)));
+    }
+
+    #[test]
+    fn intersect_adjacent() {
+        assert_eq!(None, Interval::new(1, 3).intersect(Interval::new(3, 5)));
+        assert_eq!(None, Interval::new(3, 5).intersect(Interval::new(1, 3)));
+    }
+
+    #[test]
+    fn intersect_nested() {
+        assert_eq!(
+            Some(Interval::new(2, 4)),
+            Interval::new(1, 6).intersect(Interval::new(2, 4))
+        );
+        assert_eq!(
+            Some(Interval::new(2, 4)),
+            Interval::new(2, 4).intersect(Interval::new(1, 6))
+        );
+        assert_eq!(
+            Some(Interval::new(1, 6)),
+            Interval::new(1, 6).intersect(Interval::new(1, 6))
+        );
+    }
+
+    #[test]
+    fn intersect_empty() {
+        assert_eq!(None, Interval::new(3, 3).intersect(Interval::new(1, 6)));
+        assert_eq!(None, Interval::new(1, 6).intersect(Interval::new(3, 3)));
+    }
+
+    #[test]
+    fn union() {
+        assert_eq!(Interval::new(1, 4), Interval::new(1, 3).union(Interval::new(2, 4)));
+        assert_eq!(Interval::new(1, 6), Interval::new(1, 6).union(Interval::new(2, 4)));
+        assert_eq!(Interval::new(1, 5), Interval::new(3, 5).union(Interval::new(1, 3)));
+    }
+
+    #[test]
+    fn union_disjoint() {
+        assert_eq!(Interval::new(1, 8), Interval::new(1, 3).union(Interval::new(5, 8)));
+    }
+
+    #[test]
+    fn union_empty() {
+        assert_eq!(Interval::new(5, 8), Interval::new(1, 1).union(Interval::new(5, 8)));
+        assert_eq!(Interval::new(5, 8), Interval::new(5, 8).union(Interval::new(1, 1)));
+        assert!(Interval::new(1, 1).union(Interval::new(3, 3)).is_empty());
     }
 
     #[test]
@@ -1,4 +1,4 @@
-use crate::core::{Attributes, Operation};
+use crate::core::{Attributes, EmbedData, Operation};
 
 pub struct OpBuilder {
     ty: Operation,
@@ -15,10 +15,24 @@ impl OpBuilder {
 
     pub fn retain(n: usize) -> OpBuilder { OpBuilder::new(Operation::Retain(n.into())) }
 
-    pub fn delete(n: usize) -> OpBuilder { OpBuilder::new(Operation::Delete(n)) }
+    pub fn delete(n: usize) -> OpBuilder { OpBuilder::new(Operation::Delete(n.into())) }
 
     pub fn insert(s: &str) -> OpBuilder { OpBuilder::new(Operation::Insert(s.into())) }
 
+    pub fn retain_with_attributes(n: usize, attrs: Attributes) -> OpBuilder { OpBuilder::retain(n).attributes(attrs) }
+
+    pub fn delete_with_attributes(n: usize, attrs: Attributes) -> OpBuilder { OpBuilder::delete(n).attributes(attrs) }
+
+    pub fn insert_with_attributes(s: &str, attrs: Attributes) -> OpBuilder { OpBuilder::insert(s).attributes(attrs) }
+
+    pub fn embed(key: &str, value: serde_json::Value) -> OpBuilder {
+        let data = EmbedData {
+            key: key.to_owned(),
+            value,
+        };
+        OpBuilder::new(Operation::Embed(data.into()))
+    }
+
     pub fn attributes(mut self, attrs: Attributes) -> OpBuilder {
         self.attrs = attrs;
         self
@@ -27,10 +41,52 @@ impl OpBuilder {
     pub fn build(self) -> Operation {
         let mut operation = self.ty;
         match &mut operation {
-            Operation::Delete(_) => {},
+            Operation::Delete(delete) => delete.attributes = self.attrs,
             Operation::Retain(retain) => retain.attributes = self.attrs,
             Operation::Insert(insert) => insert.attributes = self.attrs,
+            Operation::Embed(embed) => embed.attributes = self.attrs,
         }
         operation
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::core::{Attribute, Attributes, OpBuilder, Operation};
+
+    #[test]
+    fn retain_with_attributes() {
+        let attributes: Attributes = Attribute::Bold(true).into();
+        match OpBuilder::retain_with_attributes(3, attributes.clone()).build() {
+            Operation::Retain(retain) => {
+                assert_eq!(retain.n, 3);
+                assert_eq!(retain.attributes, attributes);
+            },
+            op => panic!("Expected retain, got {:?}", op),
+        }
+    }
+
+    #[test]
+    fn insert_with_attributes() {
+        let attributes: Attributes = Attribute::Italic(true).into();
+        match OpBuilder::insert_with_attributes("abc", attributes.clone()).build() {
+            Operation::Insert(insert) => {
+                assert_eq!(insert.s.as_str(), "abc");
+                assert_eq!(insert.attributes, attributes);
+            },
+            op => panic!("Expected insert, got {:?}", op),
+        }
+    }
+
+    #[test]
+    fn delete_with_attributes() {
+        let attributes: Attributes = Attribute::Header(1).into();
+        match OpBuilder::delete_with_attributes(2, attributes.clone()).build() {
+            Operation::Delete(delete) => {
+                assert_eq!(delete.n, 2);
+                assert_eq!(delete.attributes, attributes);
+            },
+            op => panic!("Expected delete, got {:?}", op),
+        }
+    }
+}
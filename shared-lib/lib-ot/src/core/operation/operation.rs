@@ -1,4 +1,7 @@
-use crate::core::{Attribute, Attributes, FlowyStr, Interval, OpBuilder};
+use crate::{
+    core::{Attribute, Attributes, Delta, FlowyStr, Interval, OpBuilder, OperationTransformable},
+    errors::OTError,
+};
 use serde::__private::Formatter;
 use std::{
     cmp::min,
@@ -8,9 +11,10 @@ use std::{
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum Operation {
-    Delete(usize),
+    Delete(Delete),
     Retain(Retain),
     Insert(Insert),
+    Embed(Embed),
 }
 
 impl Operation {
@@ -19,22 +23,25 @@ impl Operation {
             Operation::Delete(_) => "",
             Operation::Retain(_) => "",
             Operation::Insert(insert) => &insert.s,
+            Operation::Embed(_) => "",
         }
     }
 
     pub fn get_attributes(&self) -> Attributes {
         match self {
-            Operation::Delete(_) => Attributes::default(),
+            Operation::Delete(delete) => delete.attributes.clone(),
             Operation::Retain(retain) => retain.attributes.clone(),
             Operation::Insert(insert) => insert.attributes.clone(),
+            Operation::Embed(embed) => embed.attributes.clone(),
         }
     }
 
     pub fn set_attributes(&mut self, attributes: Attributes) {
         match self {
-            Operation::Delete(_) => log::error!("Delete should not contains attributes"),
+            Operation::Delete(delete) => delete.attributes = attributes,
             Operation::Retain(retain) => retain.attributes = attributes,
             Operation::Insert(insert) => insert.attributes = attributes,
+            Operation::Embed(embed) => embed.attributes = attributes,
         }
     }
 
@@ -46,41 +53,99 @@ impl Operation {
 
     pub fn len(&self) -> usize {
         match self {
-            Operation::Delete(n) => *n,
+            Operation::Delete(d) => d.n,
             Operation::Retain(r) => r.n,
             Operation::Insert(i) => i.count_of_code_units(),
+            Operation::Embed(_) => 1,
         }
     }
 
     pub fn is_empty(&self) -> bool { self.len() == 0 }
 
+    /// Transforms two concurrent operations made at the same position of the
+    /// same text, which both retain the rest of. Returns the deltas `(a', b')`
+    /// that applying after the other operation converges: self then `b'` is
+    /// the same as other then `a'`. If `priority` is true, self inserts first
+    /// at the same position and wins the conflicting attributes, otherwise the
+    /// other operation does.
+    pub fn transform(&self, other: &Operation, priority: bool) -> Result<(Delta, Delta), OTError> {
+        let base_len = std::cmp::max(self.base_len(), other.base_len());
+        let (a, b) = (self.to_delta(base_len), other.to_delta(base_len));
+        if priority {
+            a.transform(&b)
+        } else {
+            let (b_prime, a_prime) = b.transform(&a)?;
+            Ok((a_prime, b_prime))
+        }
+    }
+
+    /// Returns the delta that reverts applying the operation at the start of
+    /// the document `base`: the deleted text is inserted back with its
+    /// attributes, the inserted text is deleted, and the formatted text gets
+    /// the attributes it had in `base` back.
+    pub fn invert(&self, base: &Delta) -> Delta { self.to_delta(base.target_len).invert(base) }
+
+    // The length of the text the operation is applied to.
+    fn base_len(&self) -> usize {
+        match self {
+            Operation::Insert(_) | Operation::Embed(_) => 0,
+            _ => self.len(),
+        }
+    }
+
+    fn to_delta(&self, base_len: usize) -> Delta {
+        let mut delta = Delta::new();
+        delta.add(self.clone());
+        delta.retain(base_len - self.base_len(), Attributes::default());
+        delta
+    }
+
+    /// Splits the operation at `index`, counted like [Operation::len]. Returns
+    /// `(None, Some(self))` if `index` is 0, `(Some(self), None)` if `index` is
+    /// the length of the operation or beyond, and the two halves otherwise.
     #[allow(dead_code)]
     pub fn split(&self, index: usize) -> (Option<Operation>, Option<Operation>) {
-        debug_assert!(index < self.len());
+        if index == 0 {
+            return (None, Some(self.clone()));
+        }
+        if index >= self.len() {
+            return (Some(self.clone()), None);
+        }
+
         let left;
         let right;
         match self {
-            Operation::Delete(n) => {
-                left = Some(OpBuilder::delete(index).build());
-                right = Some(OpBuilder::delete(*n - index).build());
+            Operation::Delete(delete) => {
+                left = Some(OpBuilder::delete(index).attributes(delete.attributes.clone()).build());
+                right = Some(
+                    OpBuilder::delete(delete.n - index)
+                        .attributes(delete.attributes.clone())
+                        .build(),
+                );
             },
             Operation::Retain(retain) => {
-                left = Some(OpBuilder::delete(index).build());
-                right = Some(OpBuilder::delete(retain.n - index).build());
+                left = Some(OpBuilder::retain(index).attributes(retain.attributes.clone()).build());
+                right = Some(
+                    OpBuilder::retain(retain.n - index)
+                        .attributes(retain.attributes.clone())
+                        .build(),
+                );
             },
             Operation::Insert(insert) => {
                 let attributes = self.get_attributes();
                 left = Some(
-                    OpBuilder::insert(&insert.s[0..index])
+                    OpBuilder::insert(&insert.s.sub_str(Interval::new(0, index)))
                         .attributes(attributes.clone())
                         .build(),
                 );
                 right = Some(
-                    OpBuilder::insert(&insert.s[index..insert.count_of_code_units()])
+                    OpBuilder::insert(&insert.s.sub_str(Interval::new(index, insert.count_of_code_units())))
                         .attributes(attributes)
                         .build(),
                 );
             },
+            // An embed is one code unit long, so it can't be split in the middle
+            Operation::Embed(_) => return (Some(self.clone()), None),
         }
 
         (left, right)
@@ -88,7 +153,9 @@ impl Operation {
 
     pub fn shrink(&self, interval: Interval) -> Option<Operation> {
         let op = match self {
-            Operation::Delete(n) => OpBuilder::delete(min(*n, interval.size())).build(),
+            Operation::Delete(delete) => OpBuilder::delete(min(delete.n, interval.size()))
+                .attributes(delete.attributes.clone())
+                .build(),
             Operation::Retain(retain) => OpBuilder::retain(min(retain.n, interval.size()))
                 .attributes(retain.attributes.clone())
                 .build(),
@@ -107,6 +174,10 @@ impl Operation {
                     OpBuilder::insert(&s).attributes(insert.attributes.clone()).build()
                 }
             },
+            Operation::Embed(_) => match interval.start == 0 && !interval.is_empty() {
+                true => self.clone(),
+                false => return None,
+            },
         };
 
         match op.is_empty() {
@@ -122,8 +193,11 @@ impl Operation {
         false
     }
 
-    pub fn is_insert(&self) -> bool {
-        if let Operation::Insert(_) = self {
+    /// Checks if the operation inserts something, either text or an embed.
+    pub fn is_insert(&self) -> bool { matches!(self, Operation::Insert(_) | Operation::Embed(_)) }
+
+    pub fn is_embed(&self) -> bool {
+        if let Operation::Embed(_) = self {
             return true;
         }
         false
@@ -138,9 +212,10 @@ impl Operation {
 
     pub fn is_plain(&self) -> bool {
         match self {
-            Operation::Delete(_) => true,
+            Operation::Delete(delete) => delete.is_plain(),
             Operation::Retain(retain) => retain.is_plain(),
             Operation::Insert(insert) => insert.is_plain(),
+            Operation::Embed(embed) => embed.is_plain(),
         }
     }
 }
@@ -149,8 +224,8 @@ impl fmt::Display for Operation {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("{")?;
         match self {
-            Operation::Delete(n) => {
-                f.write_fmt(format_args!("delete: {}", n))?;
+            Operation::Delete(d) => {
+                f.write_fmt(format_args!("{}", d))?;
             },
             Operation::Retain(r) => {
                 f.write_fmt(format_args!("{}", r))?;
@@ -158,12 +233,64 @@ impl fmt::Display for Operation {
             Operation::Insert(i) => {
                 f.write_fmt(format_args!("{}", i))?;
             },
+            Operation::Embed(e) => {
+                f.write_fmt(format_args!("{}", e))?;
+            },
         }
         f.write_str("}")?;
         Ok(())
     }
 }
 
+/// Deletes `n` code units. The attributes describe the deleted text, e.g. the
+/// block attributes of a deleted newline, so the undo and the merging of the
+/// blocks know what was removed. They don't affect the document.
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Delete {
+    #[serde(rename(serialize = "delete", deserialize = "delete"))]
+    pub n: usize,
+    #[serde(default, skip_serializing_if = "is_empty")]
+    pub attributes: Attributes,
+}
+
+impl fmt::Display for Delete {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.attributes.is_empty() {
+            f.write_fmt(format_args!("delete: {}", self.n))
+        } else {
+            f.write_fmt(format_args!("delete: {}, attributes: {}", self.n, self.attributes))
+        }
+    }
+}
+
+impl Delete {
+    pub fn merge_or_new(&mut self, n: usize, attributes: Attributes) -> Option<Operation> {
+        if self.attributes == attributes {
+            self.n += n;
+            None
+        } else {
+            Some(OpBuilder::delete(n).attributes(attributes).build())
+        }
+    }
+
+    pub fn is_plain(&self) -> bool { self.attributes.is_empty() }
+}
+
+impl std::convert::From<usize> for Delete {
+    fn from(n: usize) -> Self {
+        Delete {
+            n,
+            attributes: Attributes::default(),
+        }
+    }
+}
+
+impl Deref for Delete {
+    type Target = usize;
+
+    fn deref(&self) -> &Self::Target { &self.n }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Retain {
     #[serde(rename(serialize = "retain", deserialize = "retain"))]
@@ -284,4 +411,47 @@ impl std::convert::From<FlowyStr> for Insert {
     }
 }
 
+/// Inserts non-text inline content, e.g. an image, a divider or a mention. It
+/// counts as one code unit, like the [EMBED](crate::core::EMBED) that stands
+/// for it in the text, and never merges with its neighbours.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Embed {
+    pub data: EmbedData,
+    pub attributes: Attributes,
+}
+
+/// The payload of an embed, serialized as the single entry of the object
+/// under `insert` like the Quill embeds, e.g. `{"insert":{"image":"a.png"}}`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmbedData {
+    pub key: String,
+    pub value: serde_json::Value,
+}
+
+impl fmt::Display for Embed {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.attributes.is_empty() {
+            f.write_fmt(format_args!("embed: {}: {}", self.data.key, self.data.value))
+        } else {
+            f.write_fmt(format_args!(
+                "embed: {}: {}, attributes: {}",
+                self.data.key, self.data.value, self.attributes
+            ))
+        }
+    }
+}
+
+impl Embed {
+    pub fn is_plain(&self) -> bool { self.attributes.is_empty() }
+}
+
+impl std::convert::From<EmbedData> for Embed {
+    fn from(data: EmbedData) -> Self {
+        Embed {
+            data,
+            attributes: Attributes::default(),
+        }
+    }
+}
+
 fn is_empty(attributes: &Attributes) -> bool { attributes.is_empty() }
@@ -1,4 +1,4 @@
-use crate::core::{Attributes, Operation};
+use crate::core::{Attributes, Embed, EmbedData, Operation};
 use serde::{
     de,
     de::{MapAccess, Visitor},
@@ -17,16 +17,39 @@ impl Serialize for Operation {
     {
         match self {
             Operation::Retain(retain) => retain.serialize(serializer),
-            Operation::Delete(i) => {
-                let mut map = serializer.serialize_map(Some(1))?;
-                map.serialize_entry("delete", i)?;
-                map.end()
-            },
+            Operation::Delete(delete) => delete.serialize(serializer),
             Operation::Insert(insert) => insert.serialize(serializer),
+            Operation::Embed(embed) => embed.serialize(serializer),
         }
     }
 }
 
+impl Serialize for Embed {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let len = if self.attributes.is_empty() { 1 } else { 2 };
+        let mut map = serializer.serialize_map(Some(len))?;
+        map.serialize_entry("insert", &self.data)?;
+        if !self.attributes.is_empty() {
+            map.serialize_entry("attributes", &self.attributes)?;
+        }
+        map.end()
+    }
+}
+
+impl Serialize for EmbedData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(&self.key, &self.value)?;
+        map.end()
+    }
+}
+
 impl<'de> Deserialize<'de> for Operation {
     fn deserialize<D>(deserializer: D) -> Result<Operation, D::Error>
     where
@@ -53,7 +76,8 @@ impl<'de> Deserialize<'de> for Operation {
                             if operation.is_some() {
                                 return Err(de::Error::duplicate_field("operation"));
                             }
-                            operation = Some(Operation::Delete(map.next_value()?));
+                            let n: usize = map.next_value()?;
+                            operation = Some(Operation::Delete(n.into()));
                         },
                         "retain" => {
                             if operation.is_some() {
@@ -66,8 +90,8 @@ impl<'de> Deserialize<'de> for Operation {
                             if operation.is_some() {
                                 return Err(de::Error::duplicate_field("operation"));
                             }
-                            let i: String = map.next_value()?;
-                            operation = Some(Operation::Insert(i.into()));
+                            let value: serde_json::Value = map.next_value()?;
+                            operation = Some(insert_from_value(value)?);
                         },
                         "attributes" => {
                             if attributes.is_some() {
@@ -82,9 +106,9 @@ impl<'de> Deserialize<'de> for Operation {
                 match operation {
                     None => Err(de::Error::missing_field("operation")),
                     Some(mut operation) => {
-                        if !operation.is_delete() {
-                            operation.set_attributes(attributes.unwrap_or_default());
-                        }
+                        // The attributes are optional, the deltas serialized
+                        // before the deletes had them still parse.
+                        operation.set_attributes(attributes.unwrap_or_default());
                         Ok(operation)
                     },
                 }
@@ -94,3 +118,19 @@ impl<'de> Deserialize<'de> for Operation {
         deserializer.deserialize_any(OperationVisitor)
     }
 }
+
+// The text is inserted as a string, whereas the embed is inserted as an object
+// with a single entry.
+fn insert_from_value<E: de::Error>(value: serde_json::Value) -> Result<Operation, E> {
+    match value {
+        serde_json::Value::String(s) => Ok(Operation::Insert(s.into())),
+        serde_json::Value::Object(object) => {
+            let mut entries = object.into_iter();
+            match (entries.next(), entries.next()) {
+                (Some((key, value)), None) => Ok(Operation::Embed(EmbedData { key, value }.into())),
+                _ => Err(de::Error::custom("an embed should contain exactly one entry")),
+            }
+        },
+        _ => Err(de::Error::custom("the insert should be a string or an embed")),
+    }
+}
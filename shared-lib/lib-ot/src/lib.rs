@@ -1,2 +1,4 @@
 pub mod core;
 pub mod errors;
+#[cfg(feature = "metrics")]
+pub mod metrics;
@@ -0,0 +1,174 @@
+use lazy_static::lazy_static;
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+pub const COMPOSE: &str = "compose";
+pub const TRANSFORM: &str = "transform";
+pub const RECONSTRUCT: &str = "reconstruct";
+
+/// Upper bounds of the latency buckets in microseconds. Samples above the last
+/// bound fall into the overflow bucket.
+pub const LATENCY_BOUNDS_US: [u64; 8] = [10, 50, 100, 500, 1_000, 5_000, 10_000, 50_000];
+
+lazy_static! {
+    static ref REGISTRY: MetricsRegistry = MetricsRegistry::default();
+}
+
+pub fn registry() -> &'static MetricsRegistry { &REGISTRY }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OpCountBucket {
+    Tiny,
+    Small,
+    Medium,
+    Large,
+}
+
+impl OpCountBucket {
+    pub fn from_count(count: usize) -> Self {
+        match count {
+            0..=10 => OpCountBucket::Tiny,
+            11..=100 => OpCountBucket::Small,
+            101..=1000 => OpCountBucket::Medium,
+            _ => OpCountBucket::Large,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            OpCountBucket::Tiny => "0-10",
+            OpCountBucket::Small => "11-100",
+            OpCountBucket::Medium => "101-1000",
+            OpCountBucket::Large => "1000+",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Histogram {
+    buckets: [u64; LATENCY_BOUNDS_US.len() + 1],
+    count: u64,
+    sum: Duration,
+}
+
+impl std::default::Default for Histogram {
+    fn default() -> Self {
+        Histogram {
+            buckets: [0; LATENCY_BOUNDS_US.len() + 1],
+            count: 0,
+            sum: Duration::default(),
+        }
+    }
+}
+
+impl Histogram {
+    pub fn record(&mut self, elapsed: Duration) {
+        let micros = elapsed.as_micros() as u64;
+        let index = LATENCY_BOUNDS_US
+            .iter()
+            .position(|bound| micros <= *bound)
+            .unwrap_or(LATENCY_BOUNDS_US.len());
+        self.buckets[index] += 1;
+        self.count += 1;
+        self.sum += elapsed;
+    }
+
+    pub fn count(&self) -> u64 { self.count }
+
+    pub fn sum(&self) -> Duration { self.sum }
+
+    /// The number of samples in each latency bucket, the last one being the
+    /// overflow bucket.
+    pub fn buckets(&self) -> &[u64] { &self.buckets }
+}
+
+#[derive(Debug, Default)]
+pub struct MetricsRegistry {
+    histograms: Mutex<HashMap<(&'static str, OpCountBucket), Histogram>>,
+}
+
+impl MetricsRegistry {
+    pub fn observe(&self, name: &'static str, op_count: usize, elapsed: Duration) {
+        let bucket = OpCountBucket::from_count(op_count);
+        match self.histograms.lock() {
+            Ok(mut histograms) => histograms.entry((name, bucket)).or_default().record(elapsed),
+            Err(e) => log::error!("Record {} latency failed: {:?}", name, e),
+        }
+    }
+
+    pub fn histogram(&self, name: &'static str, bucket: OpCountBucket) -> Option<Histogram> {
+        let histograms = self.histograms.lock().ok()?;
+        histograms.get(&(name, bucket)).cloned()
+    }
+
+    pub fn snapshot(&self) -> Vec<(&'static str, OpCountBucket, Histogram)> {
+        match self.histograms.lock() {
+            Ok(histograms) => histograms
+                .iter()
+                .map(|((name, bucket), histogram)| (*name, *bucket, histogram.clone()))
+                .collect(),
+            Err(_) => vec![],
+        }
+    }
+}
+
+/// Records the time elapsed between its creation and drop into the global
+/// registry.
+pub struct LatencyTimer {
+    name: &'static str,
+    op_count: usize,
+    start: Instant,
+}
+
+impl LatencyTimer {
+    pub fn new(name: &'static str, op_count: usize) -> Self {
+        LatencyTimer {
+            name,
+            op_count,
+            start: Instant::now(),
+        }
+    }
+}
+
+impl std::ops::Drop for LatencyTimer {
+    fn drop(&mut self) { registry().observe(self.name, self.op_count, self.start.elapsed()); }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        core::{Delta, DeltaBuilder, OperationTransformable},
+        metrics::{registry, OpCountBucket, COMPOSE, TRANSFORM},
+    };
+
+    fn count_of(name: &'static str) -> u64 {
+        registry()
+            .histogram(name, OpCountBucket::Tiny)
+            .map(|histogram| histogram.count())
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn compose_and_transform_record_samples() {
+        let compose_count = count_of(COMPOSE);
+        let transform_count = count_of(TRANSFORM);
+
+        let mut delta = Delta::new();
+        for s in ["a", "b", "c"].iter() {
+            let other = DeltaBuilder::new().retain(delta.target_len).insert(s).build();
+            delta = delta.compose(&other).unwrap();
+        }
+        let a = DeltaBuilder::new().retain(3).insert("d").build();
+        let b = DeltaBuilder::new().insert("e").retain(3).build();
+        let _ = a.transform(&b).unwrap();
+
+        assert!(count_of(COMPOSE) >= compose_count + 3);
+        assert!(count_of(TRANSFORM) > transform_count);
+
+        let histogram = registry().histogram(COMPOSE, OpCountBucket::Tiny).unwrap();
+        assert_eq!(histogram.buckets().iter().sum::<u64>(), histogram.count());
+    }
+}